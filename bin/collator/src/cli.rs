@@ -40,6 +40,28 @@ pub struct Cli {
     #[clap(name = "enable-evm-rpc", long)]
     pub enable_evm_rpc: bool,
 
+    /// Enable the built-in event indexer and the `astar_indexedEvents` RPC (disabled by default).
+    ///
+    /// Transfer, dApp staking & XCM events of the finalized blocks are stored
+    /// in the offchain database, starting from the block at which the indexer was enabled.
+    #[clap(name = "enable-event-indexer", long)]
+    pub enable_event_indexer: bool,
+
+    /// Enable the developer state manipulation RPC methods (unsafe).
+    ///
+    /// Exposes `dev_setStorage`, `dev_setBalance` & `dev_timeTravel`, which modify the chain state via
+    /// the development sudo key. Enabled by default with `--dev`. Only supported by the local development node.
+    #[clap(name = "enable-dev-rpc", long)]
+    pub enable_dev_rpc: bool,
+
+    /// Comma separated list of RPC namespaces whose methods are not exposed, e.g. `debug,txpool`.
+    ///
+    /// Applies to the node specific RPC extensions (e.g. `eth`, `debug`, `trace`, `txpool`), on every listening address.
+    /// Unsafe Substrate methods are controlled with `--rpc-methods` instead.
+    /// Request rate isn't limited per method; use `--rpc-rate-limit` to limit all the calls of each connection.
+    #[clap(long, value_delimiter = ',')]
+    pub rpc_disabled_namespaces: Vec<String>,
+
     /// Relaychain arguments
     #[clap(raw = true)]
     pub relaychain_args: Vec<String>,
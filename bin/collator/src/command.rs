@@ -469,11 +469,14 @@ pub fn run() -> Result<()> {
                 tracing_raw_max_memory_usage: cli.eth_api_options.tracing_raw_max_memory_usage,
             };
 
+            let enable_dev_rpc = cli.run.base.shared_params.dev || cli.enable_dev_rpc;
+
             runner.run_node_until_exit(|config| async move {
                 if config.chain_spec.is_dev() {
                     return local::start_node::<sc_network::NetworkWorker<_, _>>(
                         config,
                         evm_tracing_config,
+                        enable_dev_rpc,
                     )
                     .map_err(Into::into);
                 }
@@ -524,6 +527,8 @@ pub fn run() -> Result<()> {
                 let additional_config = AdditionalConfig {
                     evm_tracing_config,
                     enable_evm_rpc: cli.enable_evm_rpc,
+                    enable_event_indexer: cli.enable_event_indexer,
+                    rpc_disabled_namespaces: cli.rpc_disabled_namespaces.clone(),
                     proposer_block_size_limit: cli.proposer_block_size_limit,
                     proposer_soft_deadline_percent: cli.proposer_soft_deadline_percent,
                     hwbench,
@@ -0,0 +1,196 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM pool & fee dynamics Prometheus metrics.
+
+use astar_primitives::Block;
+use fc_storage::StorageOverride;
+use fp_rpc::EthereumRuntimeRPCApi;
+use futures::StreamExt;
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{
+    register, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
+
+/// Priority fee percentiles reported for each block.
+const PRIORITY_FEE_PERCENTILES: [u64; 3] = [50, 90, 99];
+
+/// EVM related metrics, updated on every new best block.
+pub struct EvmMetrics {
+    pending_transactions: Gauge<U64>,
+    base_fee: Gauge<U64>,
+    block_gas_used: Gauge<U64>,
+    priority_fee: GaugeVec<U64>,
+    mapping_sync_lag: Gauge<U64>,
+}
+
+impl EvmMetrics {
+    /// Create & register the metrics.
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            pending_transactions: register(
+                Gauge::new(
+                    "astar_evm_pending_transactions",
+                    "Number of EVM transactions in the ready queue of the transaction pool",
+                )?,
+                registry,
+            )?,
+            base_fee: register(
+                Gauge::new("astar_evm_base_fee", "EVM base fee per gas, in wei")?,
+                registry,
+            )?,
+            block_gas_used: register(
+                Gauge::new(
+                    "astar_evm_block_gas_used",
+                    "Gas used by the EVM transactions of the best block",
+                )?,
+                registry,
+            )?,
+            priority_fee: register(
+                GaugeVec::new(
+                    Opts::new(
+                        "astar_evm_priority_fee",
+                        "Priority fee per gas paid by the EVM transactions of the best block, in wei",
+                    ),
+                    &["percentile"],
+                )?,
+                registry,
+            )?,
+            mapping_sync_lag: register(
+                Gauge::new(
+                    "astar_evm_mapping_sync_lag",
+                    "Number of blocks the Frontier mapping-sync is behind the best block",
+                )?,
+                registry,
+            )?,
+        })
+    }
+}
+
+/// Update the EVM metrics on every new best block.
+pub async fn evm_metrics_task<C, P>(
+    client: Arc<C>,
+    pool: Arc<P>,
+    storage_override: Arc<dyn StorageOverride<Block>>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    metrics: EvmMetrics,
+) where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block> + 'static,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    let mut notifications = client.import_notification_stream();
+
+    while let Some(notification) = notifications.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        update_block_metrics(
+            &*client,
+            &*pool,
+            &*storage_override,
+            &metrics,
+            notification.hash,
+            &notification.header,
+        );
+
+        if let Ok(Ok(Some(mapped))) = frontier_backend
+            .latest_block_hash()
+            .await
+            .map(|latest| client.number(latest))
+        {
+            let best = *notification.header.number();
+            metrics
+                .mapping_sync_lag
+                .set(best.saturating_sub(mapped).into());
+        }
+    }
+}
+
+/// Update the metrics derived from the block & the transaction pool.
+fn update_block_metrics<C, P>(
+    client: &C,
+    pool: &P,
+    storage_override: &dyn StorageOverride<Block>,
+    metrics: &EvmMetrics,
+    hash: <Block as BlockT>::Hash,
+    header: &<Block as BlockT>::Header,
+) where
+    C: ProvideRuntimeApi<Block>,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+    P: TransactionPool<Block = Block>,
+{
+    let api = client.runtime_api();
+
+    // Base fee the transactions of the block were charged with.
+    let parent_base_fee = api.gas_price(*header.parent_hash()).ok();
+    if let Ok(base_fee) = api.gas_price(hash) {
+        metrics.base_fee.set(base_fee.unique_saturated_into());
+    }
+
+    if let Some(block) = storage_override.current_block(hash) {
+        metrics
+            .block_gas_used
+            .set(block.header.gas_used.unique_saturated_into());
+
+        let base_fee = parent_base_fee.unwrap_or_default();
+        let mut priority_fees: Vec<U256> = block
+            .transactions
+            .iter()
+            .map(|tx| priority_fee(tx, base_fee))
+            .collect();
+        priority_fees.sort();
+
+        for percentile in PRIORITY_FEE_PERCENTILES {
+            let fee = if priority_fees.is_empty() {
+                U256::zero()
+            } else {
+                let index = (priority_fees.len() - 1) * percentile as usize / 100;
+                priority_fees[index]
+            };
+            metrics
+                .priority_fee
+                .with_label_values(&[&percentile.to_string()])
+                .set(fee.unique_saturated_into());
+        }
+    }
+
+    let ready: Vec<<Block as BlockT>::Extrinsic> =
+        pool.ready().map(|tx| tx.data().clone()).collect();
+    if let Ok(pending) = api.extrinsic_filter(hash, ready) {
+        metrics.pending_transactions.set(pending.len() as u64);
+    }
+}
+
+/// Priority fee per gas effectively paid by the transaction.
+fn priority_fee(tx: &pallet_ethereum::Transaction, base_fee: U256) -> U256 {
+    match tx {
+        pallet_ethereum::Transaction::Legacy(t) => t.gas_price.saturating_sub(base_fee),
+        pallet_ethereum::Transaction::EIP2930(t) => t.gas_price.saturating_sub(base_fee),
+        pallet_ethereum::Transaction::EIP1559(t) => t
+            .max_priority_fee_per_gas
+            .min(t.max_fee_per_gas.saturating_sub(base_fee)),
+    }
+}
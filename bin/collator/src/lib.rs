@@ -31,6 +31,7 @@ mod benchmarking;
 
 mod cli;
 mod command;
+mod evm_metrics;
 mod evm_tracing_types;
 mod rpc;
 
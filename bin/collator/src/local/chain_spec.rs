@@ -33,7 +33,7 @@ pub fn development_config() -> ChainSpec {
         .with_id("dev")
         .with_chain_type(ChainType::Development)
         .with_properties(properties)
-        .with_genesis_config(local_runtime::genesis_config::default_config())
+        .with_genesis_config_preset_name("development")
         .build()
 }
 
@@ -198,6 +198,7 @@ pub fn new_partial(
 pub fn start_node<N>(
     config: Configuration,
     evm_tracing_config: EvmTracingConfig,
+    enable_dev_rpc: bool,
 ) -> Result<TaskManager, ServiceError>
 where
     N: NetworkBackend<Block, <Block as BlockT>::Hash>,
@@ -354,6 +355,21 @@ where
         ),
     );
 
+    // EVM pool & fee dynamics metrics, only when Prometheus is enabled.
+    if let Some(registry) = config.prometheus_registry() {
+        task_manager.spawn_handle().spawn(
+            "evm-metrics",
+            Some("frontier"),
+            crate::evm_metrics::evm_metrics_task(
+                client.clone(),
+                transaction_pool.clone(),
+                storage_override.clone(),
+                frontier_backend.clone(),
+                crate::evm_metrics::EvmMetrics::register(registry)?,
+            ),
+        );
+    }
+
     #[cfg(not(feature = "manual-seal"))]
     let force_authoring = config.force_authoring;
     #[cfg(not(feature = "manual-seal"))]
@@ -400,6 +416,10 @@ where
                 block_data_cache: block_data_cache.clone(),
                 storage_override: storage_override.clone(),
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
+                event_index_storage: None,
+                enable_dev_rpc,
+                max_past_logs: evm_tracing_config.max_past_logs,
+                disabled_namespaces: Vec::new(),
                 #[cfg(feature = "manual-seal")]
                 command_sink: Some(command_sink.clone()),
             };
@@ -44,6 +44,6 @@ pub fn get_chain_spec() -> AstarChainSpec {
     .with_id("astar")
     .with_chain_type(ChainType::Development)
     .with_properties(properties)
-    .with_genesis_config(astar_runtime::genesis_config::default_config(ASTAR_ID))
+    .with_genesis_config_preset_name("development")
     .build()
 }
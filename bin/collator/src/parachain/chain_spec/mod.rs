@@ -49,3 +49,43 @@ impl Extensions {
         sc_chain_spec::get_extension(chain_spec.extensions())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astar_primitives::parachain::{ASTAR_ID, SHIBUYA_ID, SHIDEN_ID};
+    use sp_runtime::BuildStorage;
+
+    const PRESETS: [&str; 3] = ["development", "local", "staging"];
+
+    fn extensions(para_id: u32) -> Extensions {
+        Extensions {
+            para_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn genesis_presets_build_storage() {
+        for preset in PRESETS {
+            AstarChainSpec::builder(astar_runtime::wasm_binary_unwrap(), extensions(ASTAR_ID))
+                .with_genesis_config_preset_name(preset)
+                .build()
+                .build_storage()
+                .unwrap();
+            ShidenChainSpec::builder(shiden_runtime::wasm_binary_unwrap(), extensions(SHIDEN_ID))
+                .with_genesis_config_preset_name(preset)
+                .build()
+                .build_storage()
+                .unwrap();
+            ShibuyaChainSpec::builder(
+                shibuya_runtime::wasm_binary_unwrap(),
+                extensions(SHIBUYA_ID),
+            )
+            .with_genesis_config_preset_name(preset)
+            .build()
+            .build_storage()
+            .unwrap();
+        }
+    }
+}
@@ -44,6 +44,6 @@ pub fn get_chain_spec() -> ShibuyaChainSpec {
     .with_id("shibuya")
     .with_chain_type(ChainType::Development)
     .with_properties(properties)
-    .with_genesis_config(shibuya_runtime::genesis_config::default_config(SHIBUYA_ID))
+    .with_genesis_config_preset_name("development")
     .build()
 }
@@ -44,6 +44,6 @@ pub fn get_chain_spec() -> ShidenChainSpec {
     .with_id("shiden")
     .with_chain_type(ChainType::Development)
     .with_properties(properties)
-    .with_genesis_config(shiden_runtime::genesis_config::default_config(SHIDEN_ID))
+    .with_genesis_config_preset_name("development")
     .build()
 }
@@ -279,6 +279,12 @@ impl_runtime_apis! {
         }
     }
 
+    impl event_index_runtime_api::EventIndexApi<Block, AccountId> for Runtime {
+        fn indexed_events() -> Vec<event_index_runtime_api::IndexedEvent<AccountId>> {
+            unimplemented!()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(_extra: bool) -> (Vec<frame_benchmarking::BenchmarkList>, Vec<frame_support::traits::StorageInfo>) {
@@ -37,7 +37,7 @@ use cumulus_relay_chain_minimal_node::build_minimal_relay_chain_node_with_rpc;
 use fc_consensus::FrontierBlockImport;
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use fc_storage::StorageOverrideHandler;
-use futures::StreamExt;
+use futures::{FutureExt, StreamExt};
 use sc_client_api::BlockchainEvents;
 use sc_consensus::{import_queue::BasicQueue, ImportQueue};
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
@@ -45,6 +45,7 @@ use sc_network::{config::NetworkBackendType, NetworkBackend, NetworkBlock};
 use sc_network_sync::SyncingService;
 use sc_service::{Configuration, PartialComponents, TFullBackend, TFullClient, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_consensus_aura::{
     sr25519::AuthorityId as AuraId, sr25519::AuthorityPair as AuraPair, AuraApi,
@@ -219,6 +220,12 @@ pub struct AdditionalConfig {
     /// Whether EVM RPC be enabled
     pub enable_evm_rpc: bool,
 
+    /// Whether the event indexer be enabled
+    pub enable_event_indexer: bool,
+
+    /// RPC namespaces which are not exposed
+    pub rpc_disabled_namespaces: Vec<String>,
+
     /// Maxium allowed block size limit to propose
     pub proposer_block_size_limit: usize,
 
@@ -287,6 +294,27 @@ where
         })
         .await?;
 
+    if parachain_config.offchain_worker.enabled {
+        task_manager.spawn_handle().spawn(
+            "offchain-workers-runner",
+            "offchain-work",
+            sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+                runtime_api_provider: client.clone(),
+                keystore: Some(keystore_container.keystore()),
+                offchain_db: backend.offchain_storage(),
+                transaction_pool: Some(OffchainTransactionPoolFactory::new(
+                    transaction_pool.clone(),
+                )),
+                network_provider: Arc::new(network.clone()),
+                is_validator: is_authority,
+                enable_http_requests: true,
+                custom_extensions: move |_| vec![],
+            })
+            .run(client.clone(), task_manager.spawn_handle())
+            .boxed(),
+        );
+    }
+
     let filter_pool: FilterPool = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
     let fee_history_cache: FeeHistoryCache = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
     let storage_override = Arc::new(StorageOverrideHandler::new(client.clone()));
@@ -368,6 +396,21 @@ where
         ),
     );
 
+    // EVM pool & fee dynamics metrics, only when Prometheus is enabled.
+    if let Some(registry) = prometheus_registry.as_ref() {
+        task_manager.spawn_handle().spawn(
+            "evm-metrics",
+            Some("frontier"),
+            crate::evm_metrics::evm_metrics_task(
+                client.clone(),
+                transaction_pool.clone(),
+                storage_override.clone(),
+                frontier_backend.clone(),
+                crate::evm_metrics::EvmMetrics::register(registry)?,
+            ),
+        );
+    }
+
     let block_data_cache = Arc::new(fc_rpc::EthBlockDataCacheTask::new(
         task_manager.spawn_handle(),
         storage_override.clone(),
@@ -376,6 +419,21 @@ where
         prometheus_registry.clone(),
     ));
 
+    // Event indexer task. Stores the notable events of the finalized blocks into the offchain DB.
+    let event_index_storage = if additional_config.enable_event_indexer {
+        let storage = backend.offchain_storage().ok_or_else(|| {
+            sc_service::Error::Other("Event indexer requires the offchain storage".into())
+        })?;
+        task_manager.spawn_handle().spawn(
+            "event-indexer",
+            None,
+            crate::rpc::event_index::index_finalized_blocks(client.clone(), storage.clone()),
+        );
+        Some(storage)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -404,6 +462,10 @@ where
                 block_data_cache: block_data_cache.clone(),
                 storage_override: storage_override.clone(),
                 enable_evm_rpc: additional_config.enable_evm_rpc,
+                event_index_storage: event_index_storage.clone(),
+                enable_dev_rpc: false,
+                max_past_logs: additional_config.evm_tracing_config.max_past_logs,
+                disabled_namespaces: additional_config.rpc_disabled_namespaces.clone(),
                 #[cfg(feature = "manual-seal")]
                 command_sink: None,
             };
@@ -53,6 +53,8 @@ use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
 
+pub mod dev;
+pub mod event_index;
 pub mod tracing;
 
 #[derive(Clone)]
@@ -64,6 +66,10 @@ pub struct EvmTracingConfig {
 
 // TODO This is copied from frontier. It should be imported instead after
 // https://github.com/paritytech/frontier/issues/333 is solved
+//
+// The key-value backend only stores the Ethereum block & transaction hash mappings, it's never pruned
+// since `fc_db::kv` provides no way to remove them. EVM logs aren't stored in it, they're read from
+// the runtime state on request, and are therefore pruned together with the state.
 pub fn open_frontier_backend<C>(
     client: Arc<C>,
     config: &sc_service::Configuration,
@@ -130,6 +136,15 @@ pub struct FullDeps<C, P, A: ChainApi> {
     pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
     /// Enable EVM RPC servers
     pub enable_evm_rpc: bool,
+    /// Offchain storage of the event indexer, if enabled.
+    pub event_index_storage: Option<sc_client_db::offchain::LocalStorage>,
+    /// Enable the developer state manipulation RPC (`dev_setStorage`, `dev_setBalance`, `dev_timeTravel`).
+    /// Only supported by the local development node.
+    pub enable_dev_rpc: bool,
+    /// Maximum number of logs returned by a single `eth_getLogs` query.
+    pub max_past_logs: u32,
+    /// RPC namespaces (e.g. `debug`, `txpool`) whose methods are not exposed.
+    pub disabled_namespaces: Vec<String>,
     /// Command sink for manual sealing
     #[cfg(feature = "manual-seal")]
     pub command_sink:
@@ -176,6 +191,7 @@ where
 {
     let client = Arc::clone(&deps.client);
     let graph = Arc::clone(&deps.graph);
+    let disabled_namespaces = deps.disabled_namespaces.clone();
 
     let mut io = create_full_rpc(deps, subscription_task_executor, pubsub_notification_sinks)?;
 
@@ -198,9 +214,34 @@ where
         io.merge(Debug::new(debug_requester).into_rpc())?;
     }
 
+    disable_namespaces(&mut io, &disabled_namespaces);
+
     Ok(io)
 }
 
+/// Remove the methods of the disabled namespaces, e.g. `debug_traceTransaction` for `debug`.
+///
+/// Both the subscribe & unsubscribe methods of a subscription belong to the same namespace,
+/// so they are removed together.
+fn disable_namespaces(io: &mut RpcModule<()>, namespaces: &[String]) {
+    if namespaces.is_empty() {
+        return;
+    }
+
+    let disabled: Vec<&'static str> = io
+        .method_names()
+        .filter(|name| {
+            name.split_once('_').map_or(false, |(namespace, _)| {
+                namespaces.iter().any(|n| n == namespace)
+            })
+        })
+        .collect();
+
+    for name in disabled {
+        io.remove(name);
+    }
+}
+
 fn create_full_rpc<C, P, BE, A>(
     deps: FullDeps<C, P, A>,
     subscription_task_executor: SubscriptionTaskExecutor,
@@ -251,6 +292,10 @@ where
         storage_override,
         block_data_cache,
         enable_evm_rpc,
+        event_index_storage,
+        enable_dev_rpc,
+        max_past_logs,
+        disabled_namespaces: _,
         #[cfg(feature = "manual-seal")]
         command_sink,
     } = deps;
@@ -259,6 +304,25 @@ where
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
+    if let Some(storage) = event_index_storage {
+        use event_index::{EventIndex, EventIndexApiServer};
+        io.merge(EventIndex::new(storage).into_rpc())?;
+    }
+
+    if enable_dev_rpc {
+        use dev::{DevState, DevStateApiServer};
+        io.merge(
+            DevState::new(
+                client.clone(),
+                pool.clone(),
+                deny_unsafe,
+                #[cfg(feature = "manual-seal")]
+                command_sink.clone(),
+            )
+            .into_rpc(),
+        )?;
+    }
+
     #[cfg(feature = "manual-seal")]
     if let Some(command_sink) = command_sink {
         use sc_consensus_manual_seal::rpc::ManualSealApiServer;
@@ -297,7 +361,6 @@ where
         .into_rpc(),
     )?;
 
-    let max_past_logs: u32 = 10_000;
     let max_stored_filters: usize = 500;
     io.merge(
         EthFilter::new(
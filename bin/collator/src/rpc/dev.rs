@@ -0,0 +1,323 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Developer RPC methods for manipulating the state of the local development chain.
+//!
+//! State changes are applied through `sudo` calls, signed with the development sudo key (`//Alice`)
+//! and submitted to the transaction pool, so they take effect once included in a block.
+//! This allows reproducing e.g. dApp staking era & period scenarios locally.
+
+use astar_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+};
+use local_runtime::{BalancesCall, RuntimeCall, SystemCall};
+use parity_scale_codec::Encode;
+use sc_rpc::DenyUnsafe;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TransactionSource, TxHash};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, Pair, U256};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{generic::Era, OpaqueExtrinsic};
+use std::sync::Arc;
+use substrate_frame_rpc_system::AccountNonceApi;
+
+/// Maximum number of blocks which can be produced by a single `dev_timeTravel` request.
+pub const MAX_TIME_TRAVEL_BLOCKS: u32 = 10_000;
+
+/// Developer state manipulation RPC methods.
+#[rpc(server)]
+pub trait DevStateApi<Hash> {
+    /// Set the raw storage values, or remove them if the value is `None`.
+    ///
+    /// Returns the hash of the submitted extrinsic.
+    #[method(name = "dev_setStorage")]
+    async fn set_storage(&self, items: Vec<(Bytes, Option<Bytes>)>) -> RpcResult<Hash>;
+
+    /// Set the free balance of the `account`.
+    ///
+    /// Returns the hash of the submitted extrinsic.
+    #[method(name = "dev_setBalance")]
+    async fn set_balance(&self, account: AccountId, free: U256) -> RpcResult<Hash>;
+
+    /// Produce the specified number of blocks, and return the new best block number.
+    ///
+    /// Requires the node to be built with the `manual-seal` feature.
+    #[method(name = "dev_timeTravel")]
+    async fn time_travel(&self, blocks: u32) -> RpcResult<BlockNumber>;
+}
+
+/// Implementation of the developer state manipulation RPC methods.
+pub struct DevState<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    deny_unsafe: DenyUnsafe,
+    #[cfg(feature = "manual-seal")]
+    command_sink:
+        Option<futures::channel::mpsc::Sender<sc_consensus_manual_seal::EngineCommand<Hash>>>,
+}
+
+impl<C, P> DevState<C, P> {
+    pub fn new(
+        client: Arc<C>,
+        pool: Arc<P>,
+        deny_unsafe: DenyUnsafe,
+        #[cfg(feature = "manual-seal")] command_sink: Option<
+            futures::channel::mpsc::Sender<sc_consensus_manual_seal::EngineCommand<Hash>>,
+        >,
+    ) -> Self {
+        Self {
+            client,
+            pool,
+            deny_unsafe,
+            #[cfg(feature = "manual-seal")]
+            command_sink,
+        }
+    }
+}
+
+impl<C, P> DevState<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    /// Submit the `call`, to be dispatched with the `Root` origin via the development sudo key.
+    async fn submit_as_root(&self, call: RuntimeCall) -> RpcResult<TxHash<P>> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let signer = Sr25519Keyring::Alice;
+        let info = self.client.info();
+
+        let nonce = self
+            .client
+            .runtime_api()
+            .account_nonce(info.best_hash, signer.to_account_id())
+            .map_err(|e| fc_rpc::internal_err(format!("failed to fetch the sudo nonce: {e}")))?;
+        let nonce = pending_nonce(&*self.pool, &signer.to_account_id(), nonce);
+
+        let call = RuntimeCall::Sudo(pallet_sudo::Call::sudo {
+            call: Box::new(call),
+        });
+        let extrinsic = sign_call(call, nonce, info.genesis_hash, signer.pair());
+
+        self.pool
+            .submit_one(info.best_hash, TransactionSource::Local, extrinsic)
+            .await
+            .map_err(|e| fc_rpc::internal_err(format!("failed to submit the extrinsic: {e}")))
+    }
+
+    /// Instantly seal the specified number of blocks, one after another.
+    #[cfg(feature = "manual-seal")]
+    async fn seal_blocks(&self, blocks: u32) -> RpcResult<()> {
+        use futures::SinkExt;
+        use sc_consensus_manual_seal::EngineCommand;
+
+        let mut command_sink = self
+            .command_sink
+            .clone()
+            .ok_or_else(|| fc_rpc::internal_err("manual sealing is not enabled"))?;
+
+        for _ in 0..blocks {
+            let (sender, receiver) = futures::channel::oneshot::channel();
+            command_sink
+                .send(EngineCommand::SealNewBlock {
+                    create_empty: true,
+                    finalize: true,
+                    parent_hash: None,
+                    sender: Some(sender),
+                })
+                .await
+                .map_err(|e| fc_rpc::internal_err(format!("failed to request a block: {e}")))?;
+
+            receiver
+                .await
+                .map_err(|e| fc_rpc::internal_err(format!("block authoring stopped: {e}")))?
+                .map_err(|e| fc_rpc::internal_err(format!("failed to seal a block: {e}")))?;
+        }
+
+        Ok(())
+    }
+
+    /// Blocks can only be produced on demand with manual sealing.
+    #[cfg(not(feature = "manual-seal"))]
+    async fn seal_blocks(&self, _blocks: u32) -> RpcResult<()> {
+        Err(fc_rpc::internal_err(
+            "`dev_timeTravel` requires the node to be built with the `manual-seal` feature",
+        ))
+    }
+}
+
+#[async_trait]
+impl<C, P> DevStateApiServer<TxHash<P>> for DevState<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    async fn set_storage(&self, items: Vec<(Bytes, Option<Bytes>)>) -> RpcResult<TxHash<P>> {
+        let mut insert = Vec::new();
+        let mut remove = Vec::new();
+        for (key, value) in items {
+            match value {
+                Some(value) => insert.push((key.0, value.0)),
+                None => remove.push(key.0),
+            }
+        }
+
+        let mut calls = Vec::new();
+        if !insert.is_empty() {
+            calls.push(RuntimeCall::System(SystemCall::set_storage {
+                items: insert,
+            }));
+        }
+        if !remove.is_empty() {
+            calls.push(RuntimeCall::System(SystemCall::kill_storage {
+                keys: remove,
+            }));
+        }
+
+        let call = match calls.len() {
+            0 => return Err(fc_rpc::internal_err("no storage items provided")),
+            1 => calls.remove(0),
+            _ => RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }),
+        };
+
+        self.submit_as_root(call).await
+    }
+
+    async fn set_balance(&self, account: AccountId, free: U256) -> RpcResult<TxHash<P>> {
+        let free: Balance = free
+            .try_into()
+            .map_err(|_| fc_rpc::internal_err("balance exceeds the maximum value"))?;
+
+        self.submit_as_root(RuntimeCall::Balances(BalancesCall::force_set_balance {
+            who: account.into(),
+            new_free: free,
+        }))
+        .await
+    }
+
+    async fn time_travel(&self, blocks: u32) -> RpcResult<BlockNumber> {
+        self.deny_unsafe.check_if_safe()?;
+
+        if blocks > MAX_TIME_TRAVEL_BLOCKS {
+            return Err(fc_rpc::internal_err(format!(
+                "at most {MAX_TIME_TRAVEL_BLOCKS} blocks can be produced at once"
+            )));
+        }
+
+        self.seal_blocks(blocks).await?;
+
+        Ok(self.client.info().best_number)
+    }
+}
+
+/// Account `nonce`, including the transactions of the `account` which are already in the pool.
+fn pending_nonce<P: TransactionPool>(pool: &P, account: &AccountId, nonce: Nonce) -> Nonce {
+    let mut nonce = nonce;
+    let mut tag = (account, nonce).encode();
+
+    for tx in pool.ready() {
+        if tx.provides().first() == Some(&tag) {
+            nonce += 1;
+            tag = (account, nonce).encode();
+        }
+    }
+
+    nonce
+}
+
+/// Sign the `call` with the `signer` key, together with the signed extensions of the local runtime.
+///
+/// Transaction is immortal, so the genesis hash is used as the mortality checkpoint.
+fn sign_call(
+    call: RuntimeCall,
+    nonce: Nonce,
+    genesis: Hash,
+    signer: sp_core::sr25519::Pair,
+) -> OpaqueExtrinsic {
+    use local_runtime as runtime;
+
+    let extra: runtime::SignedExtra = (
+        frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+        frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+        frame_system::CheckGenesis::<runtime::Runtime>::new(),
+        frame_system::CheckMortality::<runtime::Runtime>::from(Era::Immortal),
+        frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+        frame_system::CheckWeight::<runtime::Runtime>::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+        frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+    );
+
+    let payload = runtime::SignedPayload::from_raw(
+        call.clone(),
+        extra.clone(),
+        (
+            runtime::VERSION.spec_version,
+            runtime::VERSION.transaction_version,
+            genesis,
+            genesis,
+            (),
+            (),
+            (),
+            None,
+        ),
+    );
+
+    let signature = payload.using_encoded(|p| signer.sign(p));
+    runtime::UncheckedExtrinsic::new_signed(
+        call,
+        AccountId::from(signer.public()).into(),
+        runtime::Signature::Sr25519(signature),
+        extra,
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Decode;
+
+    #[test]
+    fn sign_call_is_decodable_by_local_runtime() {
+        let call = RuntimeCall::System(SystemCall::remark {
+            remark: vec![1, 2, 3],
+        });
+        let extrinsic = sign_call(
+            call.clone(),
+            7,
+            Hash::repeat_byte(1),
+            Sr25519Keyring::Alice.pair(),
+        );
+
+        let decoded = local_runtime::UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..])
+            .expect("Must be a valid local runtime extrinsic.");
+        assert_eq!(decoded.0.function, call);
+
+        let (address, _, extra) = decoded.0.signature.expect("Must be signed.");
+        assert_eq!(address, Sr25519Keyring::Alice.to_account_id().into());
+        assert_eq!(
+            extra.4,
+            frame_system::CheckNonce::<local_runtime::Runtime>::from(7)
+        );
+    }
+}
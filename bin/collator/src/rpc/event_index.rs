@@ -0,0 +1,206 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Built-in event indexer.
+//!
+//! When enabled, the indexer task fetches the notable events of every finalized block via
+//! `EventIndexApi` and stores them into the node's offchain database. They can then be
+//! queried by block range, category & account through the `astar_indexedEvents` RPC.
+
+use astar_primitives::{AccountId, Block, BlockNumber, Hash};
+use event_index_runtime_api::{EventCategory, EventIndexApi as EventIndexRuntimeApi, IndexedEvent};
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{offchain::OffchainStorage, Bytes};
+use std::sync::Arc;
+
+/// Offchain database prefix under which the indexed events are stored.
+const STORAGE_PREFIX: &[u8] = b"astar-event-index";
+/// Key of the last indexed block number.
+const LAST_INDEXED_KEY: &[u8] = b"last-indexed";
+/// Maximum number of blocks which can be queried in a single request.
+pub const MAX_BLOCK_RANGE: BlockNumber = 1000;
+
+const LOG_TARGET: &str = "event-index";
+
+/// Events stored for a single block, together with the block hash.
+type IndexedBlock = (Hash, Vec<IndexedEvent<AccountId>>);
+
+/// Index the events of every finalized block, including the ones finalized implicitly.
+pub async fn index_finalized_blocks<C, S>(client: Arc<C>, mut storage: S)
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block> + 'static,
+    C::Api: EventIndexRuntimeApi<Block, AccountId>,
+    S: OffchainStorage + 'static,
+{
+    let mut notifications = client.finality_notification_stream();
+
+    while let Some(notification) = notifications.next().await {
+        for hash in notification
+            .tree_route
+            .iter()
+            .chain(std::iter::once(&notification.hash))
+        {
+            if let Err(err) = index_block(&*client, &mut storage, *hash) {
+                log::warn!(target: LOG_TARGET, "Failed to index events of block {hash:?}: {err}");
+            }
+        }
+    }
+}
+
+fn index_block<C, S>(client: &C, storage: &mut S, hash: Hash) -> Result<(), String>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EventIndexRuntimeApi<Block, AccountId>,
+    S: OffchainStorage,
+{
+    let number = client
+        .number(hash)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "unknown block".to_string())?;
+
+    let api = client.runtime_api();
+    // Blocks produced before the runtime API was introduced have nothing to index.
+    if !api
+        .has_api::<dyn EventIndexRuntimeApi<Block, AccountId>>(hash)
+        .map_err(|e| e.to_string())?
+    {
+        return Ok(());
+    }
+
+    let events = api.indexed_events(hash).map_err(|e| e.to_string())?;
+    let indexed_block: IndexedBlock = (hash, events);
+
+    storage.set(STORAGE_PREFIX, &number.encode(), &indexed_block.encode());
+    storage.set(STORAGE_PREFIX, LAST_INDEXED_KEY, &number.encode());
+
+    Ok(())
+}
+
+/// Indexed event, as returned by the RPC.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEventResponse {
+    /// Number of the block which emitted the event.
+    pub block_number: BlockNumber,
+    /// Hash of the block which emitted the event.
+    pub block_hash: Hash,
+    /// Index of the event in the block events.
+    pub event_index: u32,
+    /// Index of the extrinsic which emitted the event, if any.
+    pub extrinsic_index: Option<u32>,
+    /// Category of the event.
+    pub category: EventCategory,
+    /// Accounts involved in the event.
+    pub accounts: Vec<AccountId>,
+    /// SCALE encoded runtime event.
+    pub event: Bytes,
+}
+
+/// Event index RPC interface.
+#[rpc(server)]
+pub trait EventIndexApi {
+    /// Get the indexed events of the finalized blocks in the `[from, to]` range,
+    /// optionally filtered by category and involved account.
+    #[method(name = "astar_indexedEvents")]
+    fn indexed_events(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        category: Option<EventCategory>,
+        account: Option<AccountId>,
+    ) -> RpcResult<Vec<IndexedEventResponse>>;
+
+    /// Get the number of the last indexed block.
+    #[method(name = "astar_lastIndexedBlock")]
+    fn last_indexed_block(&self) -> RpcResult<Option<BlockNumber>>;
+}
+
+/// Event index RPC implementation, reading from the offchain database.
+pub struct EventIndex<S> {
+    storage: S,
+}
+
+impl<S> EventIndex<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S> EventIndexApiServer for EventIndex<S>
+where
+    S: OffchainStorage + 'static,
+{
+    fn indexed_events(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        category: Option<EventCategory>,
+        account: Option<AccountId>,
+    ) -> RpcResult<Vec<IndexedEventResponse>> {
+        if from > to {
+            return Err(fc_rpc::internal_err(
+                "`from` block is greater than `to` block",
+            ));
+        }
+        if to - from >= MAX_BLOCK_RANGE {
+            return Err(fc_rpc::internal_err(format!(
+                "block range is too large, maximum is {MAX_BLOCK_RANGE} blocks"
+            )));
+        }
+
+        let mut result = Vec::new();
+        for number in from..=to {
+            let Some(raw) = self.storage.get(STORAGE_PREFIX, &number.encode()) else {
+                continue;
+            };
+            let (block_hash, events) = IndexedBlock::decode(&mut &raw[..])
+                .map_err(|e| fc_rpc::internal_err(format!("corrupted index entry: {e}")))?;
+
+            result.extend(
+                events
+                    .into_iter()
+                    .filter(|e| category.map_or(true, |c| e.category == c))
+                    .filter(|e| account.as_ref().map_or(true, |a| e.accounts.contains(a)))
+                    .map(|e| IndexedEventResponse {
+                        block_number: number,
+                        block_hash,
+                        event_index: e.event_index,
+                        extrinsic_index: e.extrinsic_index,
+                        category: e.category,
+                        accounts: e.accounts,
+                        event: e.event.into(),
+                    }),
+            );
+        }
+
+        Ok(result)
+    }
+
+    fn last_indexed_block(&self) -> RpcResult<Option<BlockNumber>> {
+        Ok(self
+            .storage
+            .get(STORAGE_PREFIX, LAST_INDEXED_KEY)
+            .and_then(|raw| BlockNumber::decode(&mut &raw[..]).ok()))
+    }
+}
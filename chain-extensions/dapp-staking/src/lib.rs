@@ -0,0 +1,241 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::DispatchError;
+
+use astar_primitives::Balance;
+pub use dapp_staking_chain_extension_types::Command::{self, *};
+use dapp_staking_chain_extension_types::{
+    EraInfo, LedgerInfo, Outcome, ProtocolStateInfo, StakeInfo, Subperiod, LOG_TARGET,
+};
+use frame_support::{traits::Get, DefaultNoBound};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
+};
+// Storage items are referred to by their full path, since some share the name with the commands.
+use pallet_dapp_staking::{ActiveProtocolState, Pallet as DappStaking, WeightInfo};
+use parity_scale_codec::Encode;
+
+type DappStakingWeight<T> = <T as pallet_dapp_staking::Config>::WeightInfo;
+
+/// dApp Staking chain extension.
+///
+/// Calls are executed on behalf of the calling contract's own account, so contracts
+/// can lock, stake & claim rewards the same way as any other staker.
+#[derive(DefaultNoBound)]
+pub struct DappStakingExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for DappStakingExtension<T>
+where
+    T: pallet_contracts::Config + pallet_dapp_staking::Config,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in dApp Staking Chain Extension")
+        })? {
+            ProtocolState => {
+                env.charge_weight(T::DbWeight::get().reads(1_u64))?;
+
+                let protocol_state = ActiveProtocolState::<T>::get();
+                ProtocolStateInfo {
+                    era: protocol_state.era(),
+                    period: protocol_state.period_number(),
+                    subperiod: match protocol_state.subperiod() {
+                        pallet_dapp_staking::Subperiod::Voting => Subperiod::Voting,
+                        pallet_dapp_staking::Subperiod::BuildAndEarn => Subperiod::BuildAndEarn,
+                    },
+                    next_era_start: protocol_state.next_era_start(),
+                }
+                .using_encoded(|r| env.write(r, false, None))?;
+            }
+            CurrentEraInfo => {
+                env.charge_weight(T::DbWeight::get().reads(1_u64))?;
+
+                let era_info = pallet_dapp_staking::CurrentEraInfo::<T>::get();
+                EraInfo {
+                    total_locked: era_info.total_locked(),
+                    unlocking: era_info.unlocking(),
+                    current_stake_amount: era_info.current_stake_amount().total(),
+                    next_stake_amount: era_info.next_stake_amount().total(),
+                }
+                .using_encoded(|r| env.write(r, false, None))?;
+            }
+            Ledger => {
+                env.charge_weight(T::DbWeight::get().reads(2_u64))?;
+
+                let account: T::AccountId = env.read_as()?;
+
+                let ledger = pallet_dapp_staking::Ledger::<T>::get(&account);
+                let period = ActiveProtocolState::<T>::get().period_number();
+                LedgerInfo {
+                    locked: ledger.active_locked_amount(),
+                    unlocking: ledger.unlocking_amount(),
+                    staked: ledger.staked_amount(period),
+                }
+                .using_encoded(|r| env.write(r, false, None))?;
+            }
+            StakerInfo => {
+                env.charge_weight(T::DbWeight::get().reads(1_u64))?;
+
+                let (account, smart_contract): (T::AccountId, T::SmartContract) = env.read_as()?;
+
+                pallet_dapp_staking::StakerInfo::<T>::get(&account, &smart_contract)
+                    .map(|staking_info| StakeInfo {
+                        period: staking_info.period_number(),
+                        voting: staking_info.staked_amount(pallet_dapp_staking::Subperiod::Voting),
+                        build_and_earn: staking_info
+                            .staked_amount(pallet_dapp_staking::Subperiod::BuildAndEarn),
+                        loyal_staker: staking_info.is_loyal(),
+                    })
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            Lock => {
+                env.charge_weight(
+                    DappStakingWeight::<T>::lock_new_account()
+                        .max(DappStakingWeight::<T>::lock_existing_account()),
+                )?;
+
+                let amount: Balance = env.read_as()?;
+                log::trace!(target: LOG_TARGET, "lock: raw arguments: amount: {:?}", amount);
+
+                let call_result = DappStaking::<T>::lock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                )
+                .map(|_| ())
+                .map_err(|e| e.error);
+                return Ok(Self::handle_result(call_result));
+            }
+            Unlock => {
+                env.charge_weight(DappStakingWeight::<T>::unlock())?;
+
+                let amount: Balance = env.read_as()?;
+                log::trace!(target: LOG_TARGET, "unlock: raw arguments: amount: {:?}", amount);
+
+                let call_result = DappStaking::<T>::unlock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            ClaimUnlocked => {
+                env.charge_weight(DappStakingWeight::<T>::claim_unlocked(
+                    <T as pallet_dapp_staking::Config>::MaxNumberOfStakedContracts::get(),
+                ))?;
+
+                let call_result = DappStaking::<T>::claim_unlocked(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                )
+                .map(|_| ())
+                .map_err(|e| e.error);
+                return Ok(Self::handle_result(call_result));
+            }
+            Stake => {
+                env.charge_weight(DappStakingWeight::<T>::stake())?;
+
+                let (smart_contract, amount): (T::SmartContract, Balance) = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "stake: raw arguments: smart_contract: {:?}, amount: {:?}",
+                    smart_contract,
+                    amount
+                );
+
+                let call_result = DappStaking::<T>::stake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                    amount,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            Unstake => {
+                env.charge_weight(DappStakingWeight::<T>::unstake())?;
+
+                let (smart_contract, amount): (T::SmartContract, Balance) = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "unstake: raw arguments: smart_contract: {:?}, amount: {:?}",
+                    smart_contract,
+                    amount
+                );
+
+                let call_result = DappStaking::<T>::unstake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                    amount,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            ClaimStakerRewards => {
+                let max_span_length =
+                    <T as pallet_dapp_staking::Config>::EraRewardSpanLength::get();
+                env.charge_weight(
+                    DappStakingWeight::<T>::claim_staker_rewards_ongoing_period(max_span_length)
+                        .max(DappStakingWeight::<T>::claim_staker_rewards_past_period(
+                            max_span_length,
+                        )),
+                )?;
+
+                let call_result = DappStaking::<T>::claim_staker_rewards(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                )
+                .map(|_| ())
+                .map_err(|e| e.error);
+                return Ok(Self::handle_result(call_result));
+            }
+            ClaimBonusReward => {
+                env.charge_weight(DappStakingWeight::<T>::claim_bonus_reward())?;
+
+                let smart_contract: T::SmartContract = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "claim_bonus_reward: raw arguments: smart_contract: {:?}",
+                    smart_contract
+                );
+
+                let call_result = DappStaking::<T>::claim_bonus_reward(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+        };
+        Ok(RetVal::Converging(Outcome::Success as u32))
+    }
+}
+
+impl<T> DappStakingExtension<T> {
+    /// Map the result of the dispatched call into the chain-extension return value.
+    fn handle_result(call_result: Result<(), DispatchError>) -> RetVal {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                RetVal::Converging(Outcome::from(e) as u32)
+            }
+            Ok(_) => RetVal::Converging(Outcome::Success as u32),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_core::H160;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+use astar_primitives::{
+    ethereum_checked::{CheckedEthereumTx, CheckedEthereumTxAction, EthereumTxInput},
+    evm::UnifiedAddressMapper,
+};
+pub use evm_chain_extension_types::Command::{self, *};
+use evm_chain_extension_types::{Outcome, LOG_TARGET};
+use fp_evm::{CallOrCreateInfo, ExitReason};
+use frame_support::DefaultNoBound;
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
+};
+use pallet_ethereum_checked::{WeightInfo, WeightInfoOf};
+use pallet_evm::GasWeightMapping;
+use parity_scale_codec::Encode;
+
+/// EVM call chain extension.
+///
+/// Allows contracts to call the EVM contracts through the checked Ethereum transactions,
+/// on behalf of the calling contract's unified (or default) H160 address.
+/// The call is limited by the given gas limit, and value transfers aren't supported.
+#[derive(DefaultNoBound)]
+pub struct EvmExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for EvmExtension<T>
+where
+    T: pallet_contracts::Config + pallet_ethereum_checked::Config,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env
+            .func_id()
+            .try_into()
+            .map_err(|_| DispatchError::Other("Unsupported func id in EVM Chain Extension"))?
+        {
+            Call => {
+                let (target, input, gas_limit): (H160, Vec<u8>, u64) =
+                    env.read_as_unbounded(env.in_len())?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "call: raw arguments: target: {:?}, input length: {:?}, gas_limit: {:?}",
+                    target,
+                    input.len(),
+                    gas_limit
+                );
+
+                let Ok(input) = EthereumTxInput::try_from(input) else {
+                    return Ok(RetVal::Converging(Outcome::InputTooLarge as u32));
+                };
+
+                // Charge the worst case upfront, the unused weight is refunded after the call.
+                let overhead = WeightInfoOf::<T>::transact_without_apply();
+                let charged = env.charge_weight(
+                    T::GasWeightMapping::gas_to_weight(gas_limit, true).saturating_add(overhead),
+                )?;
+
+                let source =
+                    T::AddressMapper::to_h160_or_default(env.ext().address()).into_address();
+                let checked_tx = CheckedEthereumTx {
+                    gas_limit: gas_limit.into(),
+                    action: CheckedEthereumTxAction::Call(target),
+                    value: Default::default(),
+                    input,
+                    maybe_access_list: None,
+                    maybe_fee: None,
+                    maybe_nonce: None,
+                };
+
+                match pallet_ethereum_checked::Pallet::<T>::chain_extension_transact(
+                    source, checked_tx,
+                ) {
+                    Ok((post_info, info)) => {
+                        // A `Call` transaction never results in a `Create` info.
+                        let CallOrCreateInfo::Call(call_info) = info else {
+                            return Ok(RetVal::Converging(Outcome::RuntimeError as u32));
+                        };
+                        if let Some(actual_weight) = post_info.actual_weight {
+                            env.adjust_weight(charged, actual_weight.saturating_add(overhead));
+                        }
+
+                        let outcome = match call_info.exit_reason {
+                            ExitReason::Succeed(_) => Outcome::Success,
+                            ExitReason::Revert(_) => Outcome::Reverted,
+                            ExitReason::Error(_) => Outcome::ExecutionFailed,
+                            ExitReason::Fatal(_) => Outcome::Fatal,
+                        };
+                        log::trace!(
+                            target: LOG_TARGET,
+                            "call: exit reason: {:?}",
+                            call_info.exit_reason
+                        );
+
+                        call_info
+                            .value
+                            .using_encoded(|r| env.write(r, false, None))?;
+                        return Ok(RetVal::Converging(outcome as u32));
+                    }
+                    Err(e) => {
+                        log::trace!(target: LOG_TARGET, "err: {:?}", e.error);
+                        if let Some(actual_weight) = e.post_info.actual_weight {
+                            env.adjust_weight(charged, actual_weight);
+                        }
+                        return Ok(RetVal::Converging(Outcome::from(e.error) as u32));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::{traits::StaticLookup, DispatchError};
+
+use frame_support::{
+    dispatch::DispatchResultWithPostInfo,
+    traits::{Contains, Currency, Get},
+    DefaultNoBound,
+};
+use frame_system::RawOrigin;
+pub use governance_chain_extension_types::Command::{self, *};
+use governance_chain_extension_types::{Outcome, LOG_TARGET};
+use pallet_contracts::chain_extension::{
+    BufInBufOutState, ChainExtension, ChargedAmount, Environment, Ext, InitState,
+    Result as DispatchResult, RetVal, SysConfig,
+};
+use pallet_democracy::{
+    AccountVote, Conviction, Pallet as Democracy, ReferendumIndex, Vote as DemocracyVote,
+    WeightInfo,
+};
+
+type DemocracyWeight<T> = <T as pallet_democracy::Config>::WeightInfo;
+type BalanceOf<T> =
+    <<T as pallet_democracy::Config>::Currency as Currency<<T as SysConfig>::AccountId>>::Balance;
+
+/// Governance voting chain extension.
+///
+/// Allows contracts to vote on the referenda & delegate their voting power, on behalf of
+/// the calling contract's own account, so DAOs implemented as contracts can take part in the governance.
+/// Only the contracts accepted by `ContractFilter` are allowed to do so.
+#[derive(DefaultNoBound)]
+pub struct GovernanceExtension<T, ContractFilter>(PhantomData<(T, ContractFilter)>);
+
+impl<T, ContractFilter> ChainExtension<T> for GovernanceExtension<T, ContractFilter>
+where
+    T: pallet_contracts::Config + pallet_democracy::Config,
+    <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
+    ContractFilter: Contains<T::AccountId>,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        let command: Command = env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in Governance Chain Extension")
+        })?;
+
+        let contract = env.ext().address().clone();
+        if !ContractFilter::contains(&contract) {
+            log::trace!(
+                target: LOG_TARGET,
+                "contract {:?} isn't allowed to take part in the governance",
+                contract
+            );
+            return Ok(RetVal::Converging(Outcome::Filtered as u32));
+        }
+        let max_votes = <T as pallet_democracy::Config>::MaxVotes::get();
+
+        match command {
+            Vote => {
+                env.charge_weight(
+                    DemocracyWeight::<T>::vote_new(max_votes)
+                        .max(DemocracyWeight::<T>::vote_existing(max_votes)),
+                )?;
+
+                let (ref_index, aye, conviction, balance): (
+                    ReferendumIndex,
+                    bool,
+                    Conviction,
+                    BalanceOf<T>,
+                ) = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "vote: raw arguments: ref_index: {:?}, aye: {:?}, conviction: {:?}, balance: {:?}",
+                    ref_index,
+                    aye,
+                    conviction,
+                    balance
+                );
+
+                let call_result = Democracy::<T>::vote(
+                    RawOrigin::Signed(contract).into(),
+                    ref_index,
+                    AccountVote::Standard {
+                        vote: DemocracyVote { aye, conviction },
+                        balance,
+                    },
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            RemoveVote => {
+                env.charge_weight(DemocracyWeight::<T>::remove_vote(max_votes))?;
+
+                let ref_index: ReferendumIndex = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "remove_vote: raw arguments: ref_index: {:?}",
+                    ref_index
+                );
+
+                let call_result =
+                    Democracy::<T>::remove_vote(RawOrigin::Signed(contract).into(), ref_index);
+                return Ok(Self::handle_result(call_result));
+            }
+            Delegate => {
+                let charged = env.charge_weight(DemocracyWeight::<T>::delegate(max_votes))?;
+
+                let (to, conviction, balance): (T::AccountId, Conviction, BalanceOf<T>) =
+                    env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "delegate: raw arguments: to: {:?}, conviction: {:?}, balance: {:?}",
+                    to,
+                    conviction,
+                    balance
+                );
+
+                let call_result = Democracy::<T>::delegate(
+                    RawOrigin::Signed(contract).into(),
+                    to.into(),
+                    conviction,
+                    balance,
+                );
+                return Ok(Self::handle_post_dispatch_result(
+                    &mut env,
+                    charged,
+                    call_result,
+                ));
+            }
+            Undelegate => {
+                let charged = env.charge_weight(DemocracyWeight::<T>::undelegate(max_votes))?;
+
+                let call_result = Democracy::<T>::undelegate(RawOrigin::Signed(contract).into());
+                return Ok(Self::handle_post_dispatch_result(
+                    &mut env,
+                    charged,
+                    call_result,
+                ));
+            }
+            Unlock => {
+                env.charge_weight(
+                    DemocracyWeight::<T>::unlock_set(max_votes)
+                        .max(DemocracyWeight::<T>::unlock_remove(max_votes)),
+                )?;
+
+                let call_result = Democracy::<T>::unlock(
+                    RawOrigin::Signed(contract.clone()).into(),
+                    contract.into(),
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+        }
+    }
+}
+
+impl<T, ContractFilter> GovernanceExtension<T, ContractFilter>
+where
+    T: pallet_contracts::Config,
+{
+    /// Refund the unused weight of the dispatched call, if its actual weight is known,
+    /// and map its result into the chain-extension return value.
+    fn handle_post_dispatch_result<E: Ext<T = T>>(
+        env: &mut Environment<E, BufInBufOutState>,
+        charged: ChargedAmount,
+        call_result: DispatchResultWithPostInfo,
+    ) -> RetVal {
+        let post_info = match &call_result {
+            Ok(post_info) => *post_info,
+            Err(e) => e.post_info,
+        };
+        if let Some(actual_weight) = post_info.actual_weight {
+            env.adjust_weight(charged, actual_weight);
+        }
+
+        Self::handle_result(call_result.map(|_| ()).map_err(|e| e.error))
+    }
+
+    /// Map the result of the dispatched call into the chain-extension return value.
+    fn handle_result(call_result: Result<(), DispatchError>) -> RetVal {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                RetVal::Converging(Outcome::from(e) as u32)
+            }
+            Ok(_) => RetVal::Converging(Outcome::Success as u32),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::{traits::StaticLookup, DispatchError};
+
+use frame_support::{
+    traits::{tokens::nonfungibles_v2::Inspect, Get, Incrementable, StorageInfoTrait},
+    weights::Weight,
+    DefaultNoBound,
+};
+use frame_system::RawOrigin;
+pub use nfts_chain_extension_types::Command::{self, *};
+use nfts_chain_extension_types::{Outcome, LOG_TARGET};
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal, SysConfig,
+};
+use pallet_nfts::{CollectionConfigFor, Pallet as Nfts, WeightInfo};
+use parity_scale_codec::Encode;
+use sp_std::vec::Vec;
+
+type NftsWeight<T> = <T as pallet_nfts::Config>::WeightInfo;
+
+/// NFTs chain extension.
+///
+/// Allows contracts to create collections, mint, transfer & burn items of `pallet-nfts`,
+/// on behalf of the calling contract's own account, and to query the owners & attributes.
+#[derive(DefaultNoBound)]
+pub struct NftsExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for NftsExtension<T>
+where
+    T: pallet_contracts::Config + pallet_nfts::Config,
+    <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env
+            .func_id()
+            .try_into()
+            .map_err(|_| DispatchError::Other("Unsupported func id in NFTs Chain Extension"))?
+        {
+            Create => {
+                env.charge_weight(NftsWeight::<T>::create())?;
+
+                let (admin, config): (T::AccountId, CollectionConfigFor<T>) = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "create: raw arguments: admin: {:?}, config: {:?}",
+                    admin,
+                    config
+                );
+
+                // Id which is going to be assigned to the new collection.
+                let collection = pallet_nfts::NextCollectionId::<T>::get()
+                    .or(<T as pallet_nfts::Config>::CollectionId::initial_value());
+
+                let call_result = Nfts::<T>::create(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    admin.into(),
+                    config,
+                );
+                if call_result.is_ok() {
+                    collection.using_encoded(|r| env.write(r, false, None))?;
+                }
+                return Ok(Self::handle_result(call_result));
+            }
+            Mint => {
+                env.charge_weight(NftsWeight::<T>::mint())?;
+
+                let (collection, item, mint_to): (T::CollectionId, T::ItemId, T::AccountId) =
+                    env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "mint: raw arguments: collection: {:?}, item: {:?}, mint_to: {:?}",
+                    collection,
+                    item,
+                    mint_to
+                );
+
+                let call_result = Nfts::<T>::mint(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    collection,
+                    item,
+                    mint_to.into(),
+                    None,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            Transfer => {
+                env.charge_weight(NftsWeight::<T>::transfer())?;
+
+                let (collection, item, dest): (T::CollectionId, T::ItemId, T::AccountId) =
+                    env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "transfer: raw arguments: collection: {:?}, item: {:?}, dest: {:?}",
+                    collection,
+                    item,
+                    dest
+                );
+
+                let call_result = Nfts::<T>::transfer(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    collection,
+                    item,
+                    dest.into(),
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            Burn => {
+                env.charge_weight(NftsWeight::<T>::burn())?;
+
+                let (collection, item): (T::CollectionId, T::ItemId) = env.read_as()?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "burn: raw arguments: collection: {:?}, item: {:?}",
+                    collection,
+                    item
+                );
+
+                let call_result = Nfts::<T>::burn(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    collection,
+                    item,
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+            Owner => {
+                env.charge_weight(Self::read_weight::<pallet_nfts::Item<T>>())?;
+
+                let (collection, item): (T::CollectionId, T::ItemId) = env.read_as()?;
+
+                <Nfts<T> as Inspect<T::AccountId>>::owner(&collection, &item)
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            CollectionOwner => {
+                env.charge_weight(Self::read_weight::<pallet_nfts::Collection<T>>())?;
+
+                let collection: T::CollectionId = env.read_as()?;
+
+                <Nfts<T> as Inspect<T::AccountId>>::collection_owner(&collection)
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            Attribute => {
+                env.charge_weight(Self::read_weight::<pallet_nfts::Attribute<T>>())?;
+
+                let (collection, item, key): (T::CollectionId, T::ItemId, Vec<u8>) =
+                    env.read_as_unbounded(env.in_len())?;
+
+                <Nfts<T> as Inspect<T::AccountId>>::attribute(&collection, &item, &key)
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            CollectionAttribute => {
+                env.charge_weight(Self::read_weight::<pallet_nfts::Attribute<T>>())?;
+
+                let (collection, key): (T::CollectionId, Vec<u8>) =
+                    env.read_as_unbounded(env.in_len())?;
+
+                <Nfts<T> as Inspect<T::AccountId>>::collection_attribute(&collection, &key)
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+        }
+        Ok(RetVal::Converging(Outcome::Success as u32))
+    }
+}
+
+impl<T> NftsExtension<T>
+where
+    T: pallet_nfts::Config,
+{
+    /// Weight of reading a single value of the storage `S`, with the proof size of the largest value.
+    fn read_weight<S: StorageInfoTrait>() -> Weight {
+        let max_size = S::storage_info()
+            .first()
+            .and_then(|info| info.max_size)
+            .unwrap_or_default();
+        T::DbWeight::get()
+            .reads(1)
+            .saturating_add(Weight::from_parts(0, max_size.into()))
+    }
+
+    /// Map the result of the dispatched call into the chain-extension return value.
+    fn handle_result(call_result: Result<(), DispatchError>) -> RetVal {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                RetVal::Converging(Outcome::from(e) as u32)
+            }
+            Ok(_) => RetVal::Converging(Outcome::Success as u32),
+        }
+    }
+}
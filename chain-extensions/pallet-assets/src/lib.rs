@@ -37,7 +37,7 @@ use pallet_contracts::chain_extension::{
 use parity_scale_codec::Encode;
 use sp_runtime::traits::{Get, StaticLookup};
 use sp_runtime::DispatchError;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 type Weight<T> = <T as pallet_assets::Config>::WeightInfo;
 
 /// Pallet Assets chain extension.
@@ -244,6 +244,121 @@ where
                 <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(id)
                     .using_encoded(|r| env.write(r, false, None))?;
             }
+            Create => {
+                env.charge_weight(Weight::<T>::create())?;
+
+                let (id, admin, min_balance): (
+                    <T as pallet_assets::Config>::AssetId,
+                    T::AccountId,
+                    T::Balance,
+                ) = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "create: raw arguments: id: {:?}, admin: {:?}, min_balance: {:?}",
+                    id,
+                    admin,
+                    min_balance
+                );
+
+                let call_result = pallet_assets::Pallet::<T>::create(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    id.into(),
+                    admin.into(),
+                    min_balance,
+                );
+                handle_result!(call_result);
+            }
+            SetMetadata => {
+                // charge for the longest metadata allowed, input length is limited by the pallet
+                let string_limit = <T as pallet_assets::Config>::StringLimit::get();
+                env.charge_weight(Weight::<T>::set_metadata(string_limit, string_limit))?;
+
+                let (id, name, symbol, decimals): (
+                    <T as pallet_assets::Config>::AssetId,
+                    Vec<u8>,
+                    Vec<u8>,
+                    u8,
+                ) = env.read_as_unbounded(env.in_len())?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "set_metadata: raw arguments: id: {:?}, name: {:?}, symbol: {:?}, decimals: {:?}",
+                    id,
+                    name,
+                    symbol,
+                    decimals
+                );
+
+                let call_result = pallet_assets::Pallet::<T>::set_metadata(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    id.into(),
+                    name,
+                    symbol,
+                    decimals,
+                );
+                handle_result!(call_result);
+            }
+            Freeze => {
+                env.charge_weight(Weight::<T>::freeze())?;
+
+                let (id, who): (<T as pallet_assets::Config>::AssetId, T::AccountId) =
+                    env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "freeze: raw arguments: id: {:?}, who: {:?}",
+                    id,
+                    who
+                );
+
+                let call_result = pallet_assets::Pallet::<T>::freeze(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    id.into(),
+                    who.into(),
+                );
+                handle_result!(call_result);
+            }
+            Thaw => {
+                env.charge_weight(Weight::<T>::thaw())?;
+
+                let (id, who): (<T as pallet_assets::Config>::AssetId, T::AccountId) =
+                    env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "thaw: raw arguments: id: {:?}, who: {:?}",
+                    id,
+                    who
+                );
+
+                let call_result = pallet_assets::Pallet::<T>::thaw(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    id.into(),
+                    who.into(),
+                );
+                handle_result!(call_result);
+            }
+            TransferOwnership => {
+                env.charge_weight(Weight::<T>::transfer_ownership())?;
+
+                let (id, owner): (<T as pallet_assets::Config>::AssetId, T::AccountId) =
+                    env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "transfer_ownership: raw arguments: id: {:?}, owner: {:?}",
+                    id,
+                    owner
+                );
+
+                let call_result = pallet_assets::Pallet::<T>::transfer_ownership(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    id.into(),
+                    owner.into(),
+                );
+                handle_result!(call_result);
+            }
         }
 
         Ok(RetVal::Converging(Outcome::Success as u32))
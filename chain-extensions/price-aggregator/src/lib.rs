@@ -0,0 +1,112 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use core::marker::PhantomData;
+use sp_runtime::{DispatchError, FixedPointNumber};
+
+use frame_support::{pallet_prelude::MaxEncodedLen, traits::Get, weights::Weight, DefaultNoBound};
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
+};
+use pallet_price_aggregator::{CircularBuffer, Pallet as PriceAggregator};
+use parity_scale_codec::Encode;
+pub use price_aggregator_chain_extension_types::Command::{self, *};
+use price_aggregator_chain_extension_types::PriceInfo;
+
+const LOG_TARGET: &str = "pallet-chain-extension-price-aggregator";
+
+/// Price Aggregator chain extension.
+#[derive(DefaultNoBound)]
+pub struct PriceAggregatorExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for PriceAggregatorExtension<T>
+where
+    T: pallet_contracts::Config + pallet_price_aggregator::Config,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in Price Aggregator Chain Extension")
+        })? {
+            GetPrice => {
+                // charge weight
+                env.charge_weight(Self::price_read_weight())?;
+
+                let currency: CurrencyId = env.read_as()?;
+                let price = PriceAggregator::<T>::spot_price(currency);
+                log::trace!(
+                    target: LOG_TARGET,
+                    "get_price: currency: {:?}, price: {:?}",
+                    currency,
+                    price
+                );
+
+                // write to buffer
+                Self::price_info(currency, price).using_encoded(|r| env.write(r, false, None))?;
+            }
+            GetTwap => {
+                // charge weight
+                env.charge_weight(Self::price_read_weight())?;
+
+                let (currency, window): (CurrencyId, u32) = env.read_as()?;
+                let price = PriceAggregator::<T>::twap_price_over(currency, window);
+                log::trace!(
+                    target: LOG_TARGET,
+                    "get_twap: currency: {:?}, window: {:?}, price: {:?}",
+                    currency,
+                    window,
+                    price
+                );
+
+                // write to buffer
+                Self::price_info(currency, price).using_encoded(|r| env.write(r, false, None))?;
+            }
+        };
+        Ok(RetVal::Converging(0))
+    }
+}
+
+impl<T> PriceAggregatorExtension<T>
+where
+    T: pallet_price_aggregator::Config,
+{
+    /// Weight of reading the block values buffer, the last processed block & the stale mark of a currency.
+    fn price_read_weight() -> Weight {
+        T::DbWeight::get()
+            .reads(3)
+            .saturating_add(Weight::from_parts(
+                0,
+                CircularBuffer::<T::TwapWindow>::max_encoded_len() as u64,
+            ))
+    }
+
+    /// Combine the `price` of the `currency` with its staleness info.
+    fn price_info(currency: CurrencyId, price: Option<CurrencyAmount>) -> Option<PriceInfo> {
+        price.map(|price| PriceInfo {
+            price: price.into_inner(),
+            staleness: PriceAggregator::<T>::staleness(currency),
+            is_stale: PriceAggregator::<T>::is_stale(currency),
+        })
+    }
+}
@@ -0,0 +1,190 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::{
+    traits::{StaticLookup, Zero},
+    DispatchError,
+};
+
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    traits::{
+        schedule::{v3::Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY},
+        Bounded, ConstU32, Get,
+    },
+    weights::Weight,
+    DefaultNoBound,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal, SysConfig,
+};
+use pallet_scheduler::WeightInfo;
+use parity_scale_codec::Encode;
+pub use scheduler_chain_extension_types::Command::{self, *};
+use scheduler_chain_extension_types::{Outcome, LOG_TARGET};
+use sp_io::hashing::blake2_256;
+use sp_std::vec::Vec;
+
+/// Scheduler chain extension.
+///
+/// Allows contracts to schedule calls of their own messages, which are dispatched by `pallet-scheduler`
+/// as `pallet-contracts` `call`, on behalf of the contract account itself, either once or periodically.
+/// This way protocols can implement autonomous actions (e.g. epoch transitions) without relying on off-chain bots.
+///
+/// Since scheduled calls aren't charged any fee once dispatched, their weight, including the gas limit,
+/// is charged upfront, for every execution. Periodic calls are executed at most `MaxPeriodicExecutions` times.
+/// Calls must be small enough to be stored inline.
+#[derive(DefaultNoBound)]
+pub struct SchedulerExtension<T, MaxPeriodicExecutions = ConstU32<100>>(
+    PhantomData<(T, MaxPeriodicExecutions)>,
+);
+
+impl<T, MaxPeriodicExecutions> ChainExtension<T> for SchedulerExtension<T, MaxPeriodicExecutions>
+where
+    T: pallet_contracts::Config + pallet_scheduler::Config,
+    BlockNumberFor<T>: From<u32>,
+    <T as pallet_scheduler::Config>::RuntimeCall: From<pallet_contracts::Call<T>>,
+    <<T as SysConfig>::Lookup as StaticLookup>::Source: From<<T as SysConfig>::AccountId>,
+    MaxPeriodicExecutions: Get<u32>,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env
+            .func_id()
+            .try_into()
+            .map_err(|_| DispatchError::Other("Unsupported func id in Scheduler Chain Extension"))?
+        {
+            Schedule => {
+                let (when, maybe_periodic, gas_limit, data): (u32, u32, Weight, Vec<u8>) =
+                    env.read_as_unbounded(env.in_len())?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "schedule: raw arguments: when: {:?}, maybe_periodic: {:?}, gas_limit: {:?}, data: {:?}",
+                    when,
+                    maybe_periodic,
+                    gas_limit,
+                    data
+                );
+
+                let contract = env.ext().address().clone();
+                let call: <T as pallet_scheduler::Config>::RuntimeCall =
+                    pallet_contracts::Call::<T>::call {
+                        dest: contract.clone().into(),
+                        value: Zero::zero(),
+                        gas_limit,
+                        storage_deposit_limit: None,
+                        data: data.clone(),
+                    }
+                    .into();
+
+                // Scheduled call is dispatched without any fee, so its weight is charged upfront, for every execution.
+                let (maybe_periodic, executions) = if maybe_periodic == 0 {
+                    (None, 1)
+                } else {
+                    let executions = MaxPeriodicExecutions::get();
+                    (Some((maybe_periodic.into(), executions)), executions)
+                };
+                env.charge_weight(
+                    call.get_dispatch_info()
+                        .weight
+                        .saturating_mul(executions.into())
+                        .saturating_add(
+                            <T as pallet_scheduler::Config>::WeightInfo::schedule_named(
+                                <T as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+                            ),
+                        ),
+                )?;
+
+                let Ok(bounded_call) = call.encode().try_into().map(Bounded::Inline) else {
+                    return Ok(RetVal::Converging(Outcome::CallTooLarge as u32));
+                };
+
+                let id: [u8; 32] = blake2_256(
+                    &(
+                        &contract,
+                        frame_system::Pallet::<T>::block_number(),
+                        when,
+                        maybe_periodic,
+                        &data,
+                    )
+                        .encode(),
+                );
+
+                // Trait function is used explicitly, since the dispatchable with the same name requires the privileged origin.
+                let call_result =
+                    <pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::schedule_named(
+                        Self::task_name(&contract, id),
+                        DispatchTime::At(when.into()),
+                        maybe_periodic,
+                        LOWEST_PRIORITY,
+                        RawOrigin::Signed(contract).into(),
+                        bounded_call,
+                    );
+                if call_result.is_ok() {
+                    id.using_encoded(|r| env.write(r, false, None))?;
+                }
+                return Ok(Self::handle_result(call_result.map(|_| ())));
+            }
+            Cancel => {
+                env.charge_weight(<T as pallet_scheduler::Config>::WeightInfo::cancel_named(
+                    <T as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+                ))?;
+
+                let id: [u8; 32] = env.read_as()?;
+                log::trace!(target: LOG_TARGET, "cancel: raw arguments: id: {:?}", id);
+
+                // The weight charged upfront for the remaining executions isn't refunded.
+                let contract = env.ext().address().clone();
+                let call_result =
+                    <pallet_scheduler::Pallet<T> as ScheduleNamed<_, _, _>>::cancel_named(
+                        Self::task_name(&contract, id),
+                    );
+                return Ok(Self::handle_result(call_result));
+            }
+        }
+    }
+}
+
+impl<T, MaxPeriodicExecutions> SchedulerExtension<T, MaxPeriodicExecutions>
+where
+    T: pallet_contracts::Config,
+{
+    /// Scheduler task name of the call with the specified `id`, scheduled by the `contract`.
+    fn task_name(contract: &T::AccountId, id: [u8; 32]) -> [u8; 32] {
+        blake2_256(&(b"wasm_scheduler", contract, id).encode())
+    }
+
+    /// Map the result of the scheduler operation into the chain-extension return value.
+    fn handle_result(call_result: Result<(), DispatchError>) -> RetVal {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                RetVal::Converging(Outcome::from(e) as u32)
+            }
+            Ok(_) => RetVal::Converging(Outcome::Success as u32),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::DispatchError;
+
+use frame_support::{weights::Weight, DefaultNoBound};
+use pallet_contracts::chain_extension::{
+    ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
+};
+use parity_scale_codec::Encode;
+pub use signature_verification_chain_extension_types::Command::{self, *};
+use signature_verification_chain_extension_types::{Outcome, LOG_TARGET};
+use sp_core::{ecdsa, sr25519};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// Base weight of the sr25519 signature verification.
+const SR25519_BASE_WEIGHT: Weight = Weight::from_parts(50_000_000, 0);
+/// Base weight of the secp256k1 signature verification or public key recovery.
+const SECP256K1_BASE_WEIGHT: Weight = Weight::from_parts(50_000_000, 0);
+/// Weight of hashing a single byte of the message, before the signature verification.
+const PER_BYTE_WEIGHT: Weight = Weight::from_parts(1_100, 0);
+
+/// Signature verification chain extension.
+///
+/// Allows contracts to verify sr25519 & ECDSA signatures, and to recover the Ethereum address of the signer,
+/// without bundling cryptographic libraries into the contract code.
+#[derive(DefaultNoBound)]
+pub struct SignatureVerificationExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for SignatureVerificationExtension<T>
+where
+    T: pallet_contracts::Config,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in Signature Verification Chain Extension")
+        })? {
+            VerifySr25519 => {
+                env.charge_weight(Self::sr25519_weight(env.in_len()))?;
+
+                let (public, signature, message): ([u8; 32], [u8; 64], Vec<u8>) =
+                    env.read_as_unbounded(env.in_len())?;
+
+                let is_valid = sp_io::crypto::sr25519_verify(
+                    &sr25519::Signature::from_raw(signature),
+                    &message[..],
+                    &sr25519::Public::from_raw(public),
+                );
+                log::trace!(
+                    target: LOG_TARGET,
+                    "verify_sr25519: public: {:?}, message: {:?}, valid: {:?}",
+                    public,
+                    message,
+                    is_valid
+                );
+
+                return Ok(Self::handle_result(is_valid));
+            }
+            VerifyEcdsa => {
+                env.charge_weight(Self::secp256k1_weight(env.in_len()))?;
+
+                let (public, signature, message): ([u8; 33], [u8; 65], Vec<u8>) =
+                    env.read_as_unbounded(env.in_len())?;
+
+                let is_valid = sp_io::crypto::ecdsa_verify(
+                    &ecdsa::Signature::from_raw(signature),
+                    &message[..],
+                    &ecdsa::Public::from_raw(public),
+                );
+                log::trace!(
+                    target: LOG_TARGET,
+                    "verify_ecdsa: public: {:?}, message: {:?}, valid: {:?}",
+                    public,
+                    message,
+                    is_valid
+                );
+
+                return Ok(Self::handle_result(is_valid));
+            }
+            EthRecover => {
+                // Recovered public key is hashed with keccak, 64 bytes.
+                env.charge_weight(Self::secp256k1_weight(64))?;
+
+                let (signature, message_hash): ([u8; 65], [u8; 32]) = env.read_as()?;
+
+                let maybe_address =
+                    sp_io::crypto::secp256k1_ecdsa_recover(&signature, &message_hash)
+                        .ok()
+                        .map(|public| {
+                            let mut address = [0_u8; 20];
+                            address.copy_from_slice(&keccak_256(&public)[12..]);
+                            address
+                        });
+                log::trace!(
+                    target: LOG_TARGET,
+                    "eth_recover: message hash: {:?}, recovered address: {:?}",
+                    message_hash,
+                    maybe_address
+                );
+
+                if let Some(address) = maybe_address {
+                    address.using_encoded(|r| env.write(r, false, None))?;
+                }
+                return Ok(Self::handle_result(maybe_address.is_some()));
+            }
+        }
+    }
+}
+
+impl<T> SignatureVerificationExtension<T> {
+    /// Weight of the sr25519 verification of the message of the given length.
+    fn sr25519_weight(len: u32) -> Weight {
+        SR25519_BASE_WEIGHT.saturating_add(PER_BYTE_WEIGHT.saturating_mul(len.into()))
+    }
+
+    /// Weight of the secp256k1 operation on the message of the given length.
+    fn secp256k1_weight(len: u32) -> Weight {
+        SECP256K1_BASE_WEIGHT.saturating_add(PER_BYTE_WEIGHT.saturating_mul(len.into()))
+    }
+
+    /// Map the result of the signature verification into the chain-extension return value.
+    fn handle_result(is_valid: bool) -> RetVal {
+        if is_valid {
+            RetVal::Converging(Outcome::Success as u32)
+        } else {
+            RetVal::Converging(Outcome::InvalidSignature as u32)
+        }
+    }
+}
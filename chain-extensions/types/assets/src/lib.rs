@@ -39,6 +39,11 @@ pub enum Command {
     MetadataSymbol = 10,
     MetadataDecimals = 11,
     MinimumBalance = 12,
+    Create = 13,
+    SetMetadata = 14,
+    Freeze = 15,
+    Thaw = 16,
+    TransferOwnership = 17,
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
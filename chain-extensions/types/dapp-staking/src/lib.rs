@@ -0,0 +1,196 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-dapp-staking";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Get the current protocol state
+    ProtocolState = 0,
+    /// Get the locked, unlocking & staked amounts of the ongoing era
+    CurrentEraInfo = 1,
+    /// Get the locked, unlocking & staked amounts of the given account
+    Ledger = 2,
+    /// Get the stake of the given account on the given smart contract
+    StakerInfo = 3,
+    /// Lock the given amount into dApp staking, for the calling contract
+    Lock = 4,
+    /// Start unlocking the given amount, for the calling contract
+    Unlock = 5,
+    /// Claim the unlocked chunks, for the calling contract
+    ClaimUnlocked = 6,
+    /// Stake the given amount on the given smart contract, for the calling contract
+    Stake = 7,
+    /// Unstake the given amount from the given smart contract, for the calling contract
+    Unstake = 8,
+    /// Claim the pending staker rewards, for the calling contract
+    ClaimStakerRewards = 9,
+    /// Claim the bonus reward for the given smart contract, for the calling contract
+    ClaimBonusReward = 10,
+}
+
+/// Subperiod of the dApp staking period.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Subperiod {
+    /// Stakers vote for the dApps, no rewards are earned.
+    #[codec(index = 0)]
+    Voting,
+    /// dApps and stakers earn rewards.
+    #[codec(index = 1)]
+    BuildAndEarn,
+}
+
+/// Current state of the dApp staking protocol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ProtocolStateInfo {
+    /// Ongoing era number.
+    pub era: u32,
+    /// Ongoing period number.
+    pub period: u32,
+    /// Ongoing subperiod.
+    pub subperiod: Subperiod,
+    /// Block number at which the next era starts.
+    pub next_era_start: u32,
+}
+
+/// Locked, unlocking & staked amounts of the ongoing era.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct EraInfo {
+    /// Total amount locked in dApp staking, which is not being unlocked.
+    pub total_locked: u128,
+    /// Total amount in the process of being unlocked.
+    pub unlocking: u128,
+    /// Amount staked, and eligible for rewards in the ongoing era.
+    pub current_stake_amount: u128,
+    /// Amount staked, and eligible for rewards from the next era onwards.
+    pub next_stake_amount: u128,
+}
+
+/// Locked, unlocking & staked amounts of a single account.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct LedgerInfo {
+    /// Amount locked in dApp staking, which is not being unlocked. It can be used for staking.
+    pub locked: u128,
+    /// Amount in the process of being unlocked.
+    pub unlocking: u128,
+    /// Amount staked in the ongoing period, including the stake only eligible from the next era.
+    pub staked: u128,
+}
+
+/// Stake of an account on a single smart contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct StakeInfo {
+    /// Period to which the stake belongs, stake has expired if it isn't the ongoing period.
+    pub period: u32,
+    /// Amount staked during the voting subperiod.
+    pub voting: u128,
+    /// Amount staked during the build & earn subperiod.
+    pub build_and_earn: u128,
+    /// `true` if the staker is eligible for the bonus reward, `false` otherwise.
+    pub loyal_staker: bool,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// dApp staking is disabled, e.g. due to maintenance.
+    Disabled = 1,
+    /// Smart contract isn't registered in dApp staking.
+    ContractNotFound = 2,
+    /// Amount must be greater than zero.
+    ZeroAmount = 3,
+    /// Total locked amount would be below the minimum threshold.
+    LockedAmountBelowThreshold = 4,
+    /// Account isn't allowed to participate in dApp staking.
+    AccountNotAvailableForDappStaking = 5,
+    /// Maximum number of unlocking chunks has been reached.
+    TooManyUnlockingChunks = 6,
+    /// Remaining stake prevents the entire locked amount from being unlocked.
+    RemainingStakePreventsFullUnlock = 7,
+    /// There are no unlocked chunks to claim.
+    NoUnlockedChunksToClaim = 8,
+    /// Not enough locked funds available for staking.
+    UnavailableStakeFunds = 9,
+    /// Unclaimed rewards must be claimed before the operation.
+    UnclaimedRewards = 10,
+    /// Total stake on the smart contract would be below the minimum required value.
+    InsufficientStakeAmount = 11,
+    /// Stake can't be done since the period ends in the next era.
+    PeriodEndsInNextEra = 12,
+    /// Unstaking is rejected since the stake belongs to a past period.
+    UnstakeFromPastPeriod = 13,
+    /// Unstake amount is greater than the staked amount.
+    UnstakeAmountTooLarge = 14,
+    /// There is no stake on the smart contract.
+    NoStakingInfo = 15,
+    /// Rewards are no longer claimable since they have expired.
+    RewardExpired = 16,
+    /// There are no claimable rewards.
+    NoClaimableRewards = 17,
+    /// Staker isn't eligible for the bonus reward.
+    NotEligibleForBonusReward = 18,
+    /// Maximum number of staked contracts has been reached.
+    TooManyStakedContracts = 19,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("Disabled") => Outcome::Disabled,
+            Some("ContractNotFound") => Outcome::ContractNotFound,
+            Some("ZeroAmount") => Outcome::ZeroAmount,
+            Some("LockedAmountBelowThreshold") => Outcome::LockedAmountBelowThreshold,
+            Some("AccountNotAvailableForDappStaking") => Outcome::AccountNotAvailableForDappStaking,
+            Some("TooManyUnlockingChunks") => Outcome::TooManyUnlockingChunks,
+            Some("RemainingStakePreventsFullUnlock") => Outcome::RemainingStakePreventsFullUnlock,
+            Some("NoUnlockedChunksToClaim") => Outcome::NoUnlockedChunksToClaim,
+            Some("UnavailableStakeFunds") => Outcome::UnavailableStakeFunds,
+            Some("UnclaimedRewards") => Outcome::UnclaimedRewards,
+            Some("InsufficientStakeAmount") => Outcome::InsufficientStakeAmount,
+            Some("PeriodEndsInNextEra") => Outcome::PeriodEndsInNextEra,
+            Some("UnstakeFromPastPeriod") => Outcome::UnstakeFromPastPeriod,
+            Some("UnstakeAmountTooLarge") => Outcome::UnstakeAmountTooLarge,
+            Some("NoStakingInfo") => Outcome::NoStakingInfo,
+            Some("RewardExpired") => Outcome::RewardExpired,
+            Some("NoClaimableRewards") => Outcome::NoClaimableRewards,
+            Some("NotEligibleForBonusReward") => Outcome::NotEligibleForBonusReward,
+            Some("TooManyStakedContracts") => Outcome::TooManyStakedContracts,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
@@ -0,0 +1,61 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::DispatchError;
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-evm";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Call the EVM contract, on behalf of the calling contract's H160 address.
+    /// Writes the return or revert data to the output buffer
+    Call = 0,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success, the EVM call returned
+    Success = 0,
+    /// The EVM call reverted, the revert data is in the output buffer
+    Reverted = 1,
+    /// The EVM call failed with an execution error, e.g. out of gas
+    ExecutionFailed = 2,
+    /// The EVM call failed with a fatal error
+    Fatal = 3,
+    /// The Ethereum transaction is invalid, e.g. the gas limit is too high
+    InvalidTransaction = 4,
+    /// The call input exceeds the maximum Ethereum transaction input size
+    InputTooLarge = 5,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        match input {
+            DispatchError::Other("Failed to validate Ethereum tx") => Outcome::InvalidTransaction,
+            _ => Outcome::RuntimeError,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-governance";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Vote on the referendum with the given conviction & balance
+    Vote = 0,
+    /// Remove the vote on the referendum
+    RemoveVote = 1,
+    /// Delegate the voting power, with the given conviction & balance, to the given account
+    Delegate = 2,
+    /// Undelegate the voting power
+    Undelegate = 3,
+    /// Unlock the tokens which are no longer locked by the votes
+    Unlock = 4,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// The calling contract isn't allowed to take part in the governance.
+    Filtered = 1,
+    /// The referendum doesn't exist or has already ended.
+    ReferendumInvalid = 2,
+    /// The account isn't voting on the referendum.
+    NotVoter = 3,
+    /// The account is already delegating.
+    AlreadyDelegating = 4,
+    /// The account isn't delegating.
+    NotDelegating = 5,
+    /// The account is voting directly, so it can't delegate.
+    VotesExist = 6,
+    /// Not enough free balance to vote or delegate with.
+    InsufficientFunds = 7,
+    /// The account has reached the maximum number of votes.
+    MaxVotesReached = 8,
+    /// Delegation to oneself makes no sense.
+    Nonsense = 9,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("ReferendumInvalid") => Outcome::ReferendumInvalid,
+            Some("NotVoter") => Outcome::NotVoter,
+            Some("AlreadyDelegating") => Outcome::AlreadyDelegating,
+            Some("NotDelegating") => Outcome::NotDelegating,
+            Some("VotesExist") => Outcome::VotesExist,
+            Some("InsufficientFunds") => Outcome::InsufficientFunds,
+            Some("MaxVotesReached") => Outcome::MaxVotesReached,
+            Some("Nonsense") => Outcome::Nonsense,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
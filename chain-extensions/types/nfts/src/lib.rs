@@ -0,0 +1,91 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-nfts";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Create a new collection with the given admin & config. Writes the collection Id to the output buffer
+    Create = 0,
+    /// Mint an item of the collection to the given account
+    Mint = 1,
+    /// Transfer an item owned by the calling contract to the given account
+    Transfer = 2,
+    /// Burn an item owned by the calling contract
+    Burn = 3,
+    /// Owner of the item, if any
+    Owner = 4,
+    /// Owner of the collection, if any
+    CollectionOwner = 5,
+    /// Attribute of the item with the given key, if any
+    Attribute = 6,
+    /// Attribute of the collection with the given key, if any
+    CollectionAttribute = 7,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// The signing account has no permission to do the operation.
+    NoPermission = 1,
+    /// The given collection doesn't exist.
+    UnknownCollection = 2,
+    /// The given item doesn't exist.
+    UnknownItem = 3,
+    /// The item already exists.
+    AlreadyExists = 4,
+    /// Items within the collection are non-transferable.
+    ItemsNonTransferable = 5,
+    /// The item is locked.
+    ItemLocked = 6,
+    /// All items of the collection have been minted.
+    MaxSupplyReached = 7,
+    /// Account balance must be greater than or equal to the deposit.
+    InsufficientBalance = 8,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("NoPermission") => Outcome::NoPermission,
+            Some("UnknownCollection") => Outcome::UnknownCollection,
+            Some("UnknownItem") => Outcome::UnknownItem,
+            Some("AlreadyExists") => Outcome::AlreadyExists,
+            Some("ItemsNonTransferable") => Outcome::ItemsNonTransferable,
+            Some("ItemLocked") => Outcome::ItemLocked,
+            Some("MaxSupplyReached") => Outcome::MaxSupplyReached,
+            Some("InsufficientBalance") => Outcome::InsufficientBalance,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
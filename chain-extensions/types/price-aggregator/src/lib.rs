@@ -0,0 +1,45 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Get the latest (spot) price of the given currency
+    GetPrice = 0,
+    /// Get the time-weighted average price of the given currency over the given number of recent blocks
+    GetTwap = 1,
+}
+
+/// Price of a currency, quoted in USD, as provided to the contracts.
+///
+/// Currency is identified by its SCALE encoded oracle currency ID, e.g. `0` for `ASTR`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PriceInfo {
+    /// Price with 18 decimals of precision.
+    pub price: u128,
+    /// Number of blocks since the latest price update, if any.
+    pub staleness: Option<u32>,
+    /// `true` if no price update has been received for too long, `false` otherwise.
+    pub is_stale: bool,
+}
@@ -0,0 +1,70 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-scheduler";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Schedule a call of the calling contract with the given input data, at the given block,
+    /// optionally repeated periodically. Writes the Id of the scheduled call to the output buffer
+    Schedule = 0,
+    /// Cancel the call with the given Id, previously scheduled by the calling contract
+    Cancel = 1,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// The call is too large to be stored inline.
+    CallTooLarge = 1,
+    /// The given block number is in the past.
+    TargetBlockNumberInPast = 2,
+    /// The call couldn't be scheduled, e.g. the agenda is full.
+    FailedToSchedule = 3,
+    /// The call with the same Id is already scheduled.
+    AlreadyScheduled = 4,
+    /// The scheduled call with the given Id doesn't exist.
+    NotFound = 5,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("TargetBlockNumberInPast") => Outcome::TargetBlockNumberInPast,
+            Some("FailedToSchedule") => Outcome::FailedToSchedule,
+            Some("Named") => Outcome::AlreadyScheduled,
+            Some("NotFound") => Outcome::NotFound,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
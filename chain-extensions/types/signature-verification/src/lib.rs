@@ -0,0 +1,45 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-signature-verification";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Verify the sr25519 signature of the message for the given public key
+    VerifySr25519 = 0,
+    /// Verify the Substrate ECDSA signature (blake2 hashed message) for the given compressed public key
+    VerifyEcdsa = 1,
+    /// Recover the Ethereum address which signed the given 32 bytes message hash.
+    /// Writes the address to the output buffer
+    EthRecover = 2,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success, signature is valid
+    Success = 0,
+    /// The signature is invalid or doesn't match the public key and message
+    InvalidSignature = 1,
+}
@@ -20,6 +20,7 @@
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{DispatchError, ModuleError};
 
 #[repr(u16)]
 #[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
@@ -32,6 +33,47 @@ pub enum Command {
     GetNativeAddress = 2,
     /// Get the mapped Native address if any otheriwse default associated Native address
     GetNativeAddressOrDefault = 3,
+    /// Claim the given Evm address for the given account, using the signature of the Evm address owner.
+    /// The account must be the caller of the contract.
+    ClaimEvmAddress = 4,
+    /// Claim the default associated Evm address for the given account.
+    /// The account must be the caller of the contract.
+    ClaimDefaultEvmAddress = 5,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// AccountId or EvmAddress already mapped
+    AlreadyMapped = 1,
+    /// The signature is malformed
+    UnexpectedSignatureFormat = 2,
+    /// The signature verification failed due to mismatch evm address
+    InvalidSignature = 3,
+    /// Funds unavailable to claim account
+    FundsUnavailable = 4,
+    /// The account the claim is made for isn't the caller of the contract
+    CallerMismatch = 5,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("AlreadyMapped") => Outcome::AlreadyMapped,
+            Some("UnexpectedSignatureFormat") => Outcome::UnexpectedSignatureFormat,
+            Some("InvalidSignature") => Outcome::InvalidSignature,
+            Some("FundsUnavailable") => Outcome::FundsUnavailable,
+            _ => Outcome::RuntimeError,
+        };
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
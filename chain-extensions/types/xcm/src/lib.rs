@@ -0,0 +1,75 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-xcm";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Execute the given versioned XCM message locally, with the calling contract as the origin,
+    /// up to the given maximum weight
+    Execute = 0,
+    /// Send the given versioned XCM message to the given destination, with the calling contract as the origin
+    Send = 1,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// The message or the destination version isn't supported.
+    BadVersion = 1,
+    /// The message isn't allowed to be executed by the calling contract.
+    Filtered = 2,
+    /// The message couldn't be weighed.
+    UnweighableMessage = 3,
+    /// The message weight exceeds the given maximum weight.
+    WeightLimitExceeded = 4,
+    /// The message execution failed, or was only partially completed.
+    ExecutionFailed = 5,
+    /// The destination is unreachable.
+    Unreachable = 6,
+    /// The message couldn't be sent to the destination.
+    SendFailure = 7,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        return match error_text {
+            Some("BadVersion") => Outcome::BadVersion,
+            Some("Filtered") => Outcome::Filtered,
+            Some("UnweighableMessage") => Outcome::UnweighableMessage,
+            Some("Unreachable") => Outcome::Unreachable,
+            Some("SendFailure") => Outcome::SendFailure,
+            _ => Outcome::RuntimeError,
+        };
+    }
+}
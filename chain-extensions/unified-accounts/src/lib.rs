@@ -23,12 +23,21 @@ use core::marker::PhantomData;
 use sp_runtime::DispatchError;
 
 use frame_support::DefaultNoBound;
+use frame_system::RawOrigin;
 use pallet_contracts::chain_extension::{
     ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
 };
 use pallet_unified_accounts::WeightInfo;
 use parity_scale_codec::Encode;
 pub use unified_accounts_chain_extension_types::Command::{self, *};
+use unified_accounts_chain_extension_types::Outcome;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+const LOG_TARGET: &str = "pallet-chain-extension-unified-accounts";
 
 type UAWeight<T> = <T as pallet_unified_accounts::Config>::WeightInfo;
 
@@ -79,7 +88,90 @@ where
                 UA::to_account_id_or_default(&evm_address)
                     .using_encoded(|r| env.write(r, false, None))?;
             }
+            ClaimEvmAddress => {
+                // charge weight
+                env.charge_weight(UAWeight::<T>::claim_evm_address())?;
+
+                let (account_id, evm_address, signature): (T::AccountId, EvmAddress, [u8; 65]) =
+                    env.read_as()?;
+                let caller = env.ext().caller();
+
+                let outcome = Self::claim_evm_address_for(
+                    caller.account_id().ok(),
+                    account_id,
+                    evm_address,
+                    signature,
+                );
+                return Ok(RetVal::Converging(outcome as u32));
+            }
+            ClaimDefaultEvmAddress => {
+                // charge weight
+                env.charge_weight(UAWeight::<T>::claim_default_evm_address())?;
+
+                let account_id: T::AccountId = env.read_as()?;
+                let caller = env.ext().caller();
+
+                let outcome =
+                    Self::claim_default_evm_address_for(caller.account_id().ok(), account_id);
+                return Ok(RetVal::Converging(outcome as u32));
+            }
         };
         Ok(RetVal::Converging(0))
     }
 }
+
+impl<T, UA> UnifiedAccountsExtension<T, UA>
+where
+    T: pallet_unified_accounts::Config,
+{
+    /// Claim `evm_address` for `account_id`, on behalf of the contract caller.
+    ///
+    /// The mapping is created for `account_id`, not for the contract, and `signature` must be
+    /// generated by the evm address owner for `account_id`. The claim is only accepted if the contract
+    /// was called directly by `account_id`, so a contract can never bind an account without its consent.
+    pub(crate) fn claim_evm_address_for(
+        caller: Option<&T::AccountId>,
+        account_id: T::AccountId,
+        evm_address: EvmAddress,
+        signature: [u8; 65],
+    ) -> Outcome {
+        if caller != Some(&account_id) {
+            return Outcome::CallerMismatch;
+        }
+
+        Self::handle_result(pallet_unified_accounts::Pallet::<T>::claim_evm_address(
+            RawOrigin::Signed(account_id).into(),
+            evm_address,
+            signature,
+        ))
+    }
+
+    /// Claim the default evm address for `account_id`, on behalf of the contract caller.
+    ///
+    /// Same as for [`Self::claim_evm_address_for`], the contract must be called directly by `account_id`.
+    pub(crate) fn claim_default_evm_address_for(
+        caller: Option<&T::AccountId>,
+        account_id: T::AccountId,
+    ) -> Outcome {
+        if caller != Some(&account_id) {
+            return Outcome::CallerMismatch;
+        }
+
+        Self::handle_result(
+            pallet_unified_accounts::Pallet::<T>::claim_default_evm_address(
+                RawOrigin::Signed(account_id).into(),
+            ),
+        )
+    }
+
+    /// Map the result of the claim call into the chain-extension outcome.
+    fn handle_result(call_result: DispatchResult<()>) -> Outcome {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                Outcome::from(e)
+            }
+            Ok(_) => Outcome::Success,
+        }
+    }
+}
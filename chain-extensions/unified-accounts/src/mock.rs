@@ -0,0 +1,135 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use crate::UnifiedAccountsExtension;
+
+use astar_primitives::evm::HashedDefaultMappings;
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64},
+};
+use sp_core::{keccak_256, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{AccountIdLookup, BlakeTwo256},
+    AccountId32, BuildStorage,
+};
+
+pub(crate) type AccountId = AccountId32;
+pub(crate) type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+
+impl frame_system::Config for TestRuntime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = AccountIdLookup<Self::AccountId, ()>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ConstU32<4>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<100>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = ();
+    type RuntimeFreezeReason = ();
+    type MaxFreezes = ConstU32<0>;
+}
+
+parameter_types! {
+    pub ChainId: u64 = 1024;
+    pub const AccountMappingStorageFee: u128 = 100_000_000;
+}
+
+impl pallet_unified_accounts::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
+    type ChainId = ChainId;
+    type AccountMappingStorageFee = AccountMappingStorageFee;
+    type MaxSecondaryAddresses = ConstU32<2>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub struct TestRuntime {
+        System: frame_system,
+        Balances: pallet_balances,
+        UnifiedAccounts: pallet_unified_accounts,
+    }
+);
+
+pub(crate) type Extension = UnifiedAccountsExtension<TestRuntime, ()>;
+
+pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([1u8; 32]);
+
+pub fn alice_secret() -> libsecp256k1::SecretKey {
+    libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap()
+}
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let mut t = frame_system::GenesisConfig::<TestRuntime>::default()
+            .build_storage()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<TestRuntime> {
+            balances: vec![(ALICE, 1_000_000_000_000), (BOB, 1_000_000_000_000)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext = TestExternalities::from(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,108 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use crate::mock::*;
+use unified_accounts_chain_extension_types::Outcome;
+
+use astar_primitives::evm::UnifiedAddressMapper;
+use pallet_unified_accounts::{EvmToNative, NativeToEvm};
+
+/// Signature of the evm address owner, generated for the given native account.
+fn get_evm_signature(who: &AccountId, secret: &libsecp256k1::SecretKey) -> [u8; 65] {
+    UnifiedAccounts::eth_sign_prehash(&UnifiedAccounts::build_signing_payload(who), secret)
+}
+
+#[test]
+fn claim_evm_address_for_caller_works() {
+    ExtBuilder::build().execute_with(|| {
+        let evm_address = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+
+        assert_eq!(
+            Extension::claim_evm_address_for(Some(&ALICE), ALICE, evm_address, signature),
+            Outcome::Success
+        );
+
+        // mapping is created for the user account, not for the contract
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), Some(evm_address));
+        assert_eq!(EvmToNative::<TestRuntime>::get(&evm_address), Some(ALICE));
+    });
+}
+
+#[test]
+fn claim_evm_address_requires_account_to_be_caller() {
+    ExtBuilder::build().execute_with(|| {
+        let evm_address = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = get_evm_signature(&BOB, &alice_secret());
+
+        // contract can't claim for an account which didn't call it, even with a valid signature
+        assert_eq!(
+            Extension::claim_evm_address_for(Some(&ALICE), BOB, evm_address, signature),
+            Outcome::CallerMismatch
+        );
+        assert_eq!(
+            Extension::claim_evm_address_for(None, BOB, evm_address, signature),
+            Outcome::CallerMismatch
+        );
+        assert!(!NativeToEvm::<TestRuntime>::contains_key(&BOB));
+    });
+}
+
+#[test]
+fn claim_evm_address_verifies_signature_against_account() {
+    ExtBuilder::build().execute_with(|| {
+        let evm_address = UnifiedAccounts::eth_address(&alice_secret());
+
+        // signature generated for a different account must be rejected
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+        assert_eq!(
+            Extension::claim_evm_address_for(Some(&BOB), BOB, evm_address, signature),
+            Outcome::InvalidSignature
+        );
+        assert!(!EvmToNative::<TestRuntime>::contains_key(&evm_address));
+    });
+}
+
+#[test]
+fn claim_default_evm_address_for_caller_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            Extension::claim_default_evm_address_for(Some(&BOB), ALICE),
+            Outcome::CallerMismatch
+        );
+        assert!(!NativeToEvm::<TestRuntime>::contains_key(&ALICE));
+
+        assert_eq!(
+            Extension::claim_default_evm_address_for(Some(&ALICE), ALICE),
+            Outcome::Success
+        );
+        assert_eq!(
+            NativeToEvm::<TestRuntime>::get(&ALICE),
+            Some(
+                <TestRuntime as pallet_unified_accounts::Config>::DefaultMappings::to_default_h160(
+                    &ALICE
+                )
+            )
+        );
+
+        // already mapped
+        assert_eq!(
+            Extension::claim_default_evm_address_for(Some(&ALICE), ALICE),
+            Outcome::AlreadyMapped
+        );
+    });
+}
@@ -0,0 +1,168 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use sp_runtime::DispatchError;
+
+use frame_support::{
+    traits::{Contains, EnsureOrigin},
+    weights::Weight,
+    DefaultNoBound,
+};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+    BufInBufOutState, ChainExtension, Environment, Ext, InitState, Result as DispatchResult,
+    RetVal, SysConfig,
+};
+use pallet_xcm::WeightInfo;
+use parity_scale_codec::{Decode, DecodeLimit, Encode};
+use sp_std::boxed::Box;
+use xcm::{
+    latest::{ExecuteXcm, Location, Xcm},
+    VersionedLocation, VersionedXcm, MAX_XCM_DECODE_DEPTH,
+};
+pub use xcm_chain_extension_types::Command::{self, *};
+use xcm_chain_extension_types::{Outcome, LOG_TARGET};
+use xcm_executor::traits::WeightBounds;
+
+type XcmCallOf<T> = <T as pallet_xcm::Config>::RuntimeCall;
+
+/// XCM chain extension.
+///
+/// Messages are executed & sent with the calling contract's account as the origin.
+/// Local execution is only allowed for the origin & message pairs accepted by `ExecuteFilter`,
+/// same as `pallet-xcm` `XcmExecuteFilter`, and is bounded by the weight limit given by the contract.
+#[derive(DefaultNoBound)]
+pub struct XcmExtension<T, ExecuteFilter>(PhantomData<(T, ExecuteFilter)>);
+
+impl<T, ExecuteFilter> ChainExtension<T> for XcmExtension<T, ExecuteFilter>
+where
+    T: pallet_contracts::Config + pallet_xcm::Config,
+    ExecuteFilter: Contains<(Location, Xcm<XcmCallOf<T>>)>,
+{
+    fn call<E>(&mut self, env: Environment<E, InitState>) -> DispatchResult<RetVal>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env
+            .func_id()
+            .try_into()
+            .map_err(|_| DispatchError::Other("Unsupported func id in XCM Chain Extension"))?
+        {
+            Execute => {
+                let (message, max_weight): (VersionedXcm<XcmCallOf<T>>, Weight) =
+                    Self::read_input(&mut env)?;
+
+                let origin: <T as pallet_xcm::Config>::RuntimeOrigin =
+                    <T as SysConfig>::RuntimeOrigin::from(RawOrigin::Signed(
+                        env.ext().address().clone(),
+                    ))
+                    .into();
+                let Ok(origin_location) =
+                    <T as pallet_xcm::Config>::ExecuteXcmOrigin::ensure_origin(origin)
+                else {
+                    return Ok(RetVal::Converging(Outcome::Filtered as u32));
+                };
+
+                let Ok(mut message) = Xcm::<XcmCallOf<T>>::try_from(message) else {
+                    return Ok(RetVal::Converging(Outcome::BadVersion as u32));
+                };
+                if !ExecuteFilter::contains(&(origin_location.clone(), message.clone())) {
+                    return Ok(RetVal::Converging(Outcome::Filtered as u32));
+                }
+
+                let Ok(weight) = <T as pallet_xcm::Config>::Weigher::weight(&mut message) else {
+                    return Ok(RetVal::Converging(Outcome::UnweighableMessage as u32));
+                };
+                if weight.any_gt(max_weight) {
+                    return Ok(RetVal::Converging(Outcome::WeightLimitExceeded as u32));
+                }
+
+                // charge the worst case weight, and refund the unused part after the execution
+                let charged = env.charge_weight(weight)?;
+
+                let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+                let outcome = <T as pallet_xcm::Config>::XcmExecutor::prepare_and_execute(
+                    origin_location,
+                    message,
+                    &mut hash,
+                    weight,
+                    Weight::zero(),
+                );
+                log::trace!(target: LOG_TARGET, "execute: outcome: {:?}", outcome);
+
+                env.adjust_weight(charged, outcome.weight_used());
+
+                let result = match outcome.ensure_complete() {
+                    Ok(()) => Outcome::Success,
+                    Err(_) => Outcome::ExecutionFailed,
+                };
+                return Ok(RetVal::Converging(result as u32));
+            }
+            Send => {
+                env.charge_weight(<T as pallet_xcm::Config>::WeightInfo::send())?;
+
+                let (dest, message): (VersionedLocation, VersionedXcm<()>) =
+                    Self::read_input(&mut env)?;
+                log::trace!(
+                    target: LOG_TARGET,
+                    "send: raw arguments: dest: {:?}, message: {:?}",
+                    dest,
+                    message
+                );
+
+                let call_result = pallet_xcm::Pallet::<T>::send(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    Box::new(dest),
+                    Box::new(message),
+                );
+                return Ok(Self::handle_result(call_result));
+            }
+        }
+    }
+}
+
+impl<T, ExecuteFilter> XcmExtension<T, ExecuteFilter>
+where
+    T: pallet_contracts::Config,
+{
+    /// Read the input buffer, limiting the depth of the nested XCM instructions while decoding.
+    fn read_input<E, I>(env: &mut Environment<E, BufInBufOutState>) -> DispatchResult<I>
+    where
+        E: Ext<T = T>,
+        I: Decode,
+    {
+        let input = env.read(env.in_len())?;
+        I::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &input[..])
+            .map_err(|_| DispatchError::Other("Failed to decode XCM chain extension input"))
+    }
+
+    /// Map the result of the dispatched call into the chain-extension return value.
+    fn handle_result(call_result: Result<(), DispatchError>) -> RetVal {
+        match call_result {
+            Err(e) => {
+                log::trace!(target: LOG_TARGET, "err: {:?}", e);
+                RetVal::Converging(Outcome::from(e) as u32)
+            }
+            Ok(_) => RetVal::Converging(Outcome::Success as u32),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{
+    fungibles::{
+        approvals::Inspect as ApprovalInspect, metadata::Inspect as MetadataInspect, Inspect,
+    },
+    EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use pallet_assets::BenchmarkHelper as _;
+use pallet_dapp_staking::{ActiveProtocolState, BenchmarkHelper as _, StakerInfo};
+use pallet_price_aggregator::{
+    BlockValuesCircularBuffer, CircularBuffer, LastProcessedBlock, StalePrices,
+};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::traits::{StaticLookup, Zero};
+use sp_std::{vec, vec::Vec};
+
+/// Key type used to generate the keys in the benchmarks.
+const BENCH_KEY_TYPE: KeyTypeId = KeyTypeId(*b"cebn");
+
+/// Maximum message length used for the signature verification benchmarks.
+const MAX_MESSAGE_LEN: u32 = 65_536;
+
+/// Creates a sufficient asset, owned by the returned account.
+fn create_asset<T: pallet_assets::Config>() -> (T::AssetIdParameter, T::AccountId) {
+    let owner: T::AccountId = account("owner", 0, 0);
+    let asset_id = <T as pallet_assets::Config>::BenchmarkHelper::create_asset_id_parameter(0);
+    let origin = <T as pallet_assets::Config>::ForceOrigin::try_successful_origin()
+        .expect("Must be able to create force origin for benchmarks.");
+
+    pallet_assets::Pallet::<T>::force_create(
+        origin,
+        asset_id.clone(),
+        T::Lookup::unlookup(owner.clone()),
+        true,
+        1_u32.into(),
+    )
+    .expect("Asset must be created.");
+
+    (asset_id, owner)
+}
+
+/// Creates a sufficient asset with the metadata of the maximum length.
+fn create_asset_with_metadata<T: pallet_assets::Config>() -> Result<T::AssetId, &'static str> {
+    let (asset_id, _) = create_asset::<T>();
+    let origin = <T as pallet_assets::Config>::ForceOrigin::try_successful_origin()
+        .expect("Must be able to create force origin for benchmarks.");
+    let limit = <T as pallet_assets::Config>::StringLimit::get() as usize;
+
+    pallet_assets::Pallet::<T>::force_set_metadata(
+        origin,
+        asset_id.clone(),
+        vec![b'n'; limit],
+        vec![b's'; limit],
+        18,
+        false,
+    )
+    .map_err(|_| "Metadata must be set.")?;
+
+    Ok(asset_id.into())
+}
+
+benchmarks! {
+    where_clause {
+        where T: pallet_assets::Config + pallet_dapp_staking::Config + pallet_price_aggregator::Config
+    }
+
+    assets_balance_of {
+        let (asset_id, owner) = create_asset::<T>();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+
+        pallet_assets::Pallet::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            asset_id.clone(),
+            T::Lookup::unlookup(beneficiary.clone()),
+            1_000_u32.into(),
+        )?;
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        assert!(!pallet_assets::Pallet::<T>::balance(asset_id, beneficiary).is_zero());
+    }
+
+    assets_total_supply {
+        let (asset_id, _) = create_asset::<T>();
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        let _ = pallet_assets::Pallet::<T>::total_supply(asset_id);
+    }
+
+    assets_allowance {
+        let (asset_id, owner) = create_asset::<T>();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        let _ = <pallet_assets::Pallet<T> as ApprovalInspect<T::AccountId>>::allowance(
+            asset_id, &owner, &delegate,
+        );
+    }
+
+    assets_metadata_name {
+        let asset_id = create_asset_with_metadata::<T>()?;
+    }: {
+        assert!(!<pallet_assets::Pallet<T> as MetadataInspect<T::AccountId>>::name(asset_id).is_empty());
+    }
+
+    assets_metadata_symbol {
+        let asset_id = create_asset_with_metadata::<T>()?;
+    }: {
+        assert!(!<pallet_assets::Pallet<T> as MetadataInspect<T::AccountId>>::symbol(asset_id).is_empty());
+    }
+
+    assets_metadata_decimals {
+        let asset_id = create_asset_with_metadata::<T>()?;
+    }: {
+        assert_eq!(<pallet_assets::Pallet<T> as MetadataInspect<T::AccountId>>::decimals(asset_id), 18);
+    }
+
+    assets_minimum_balance {
+        let (asset_id, _) = create_asset::<T>();
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        assert!(!<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(asset_id).is_zero());
+    }
+
+    dapp_staking_protocol_state {
+    }: {
+        let _ = ActiveProtocolState::<T>::get();
+    }
+
+    dapp_staking_current_era_info {
+    }: {
+        let _ = pallet_dapp_staking::CurrentEraInfo::<T>::get();
+    }
+
+    dapp_staking_ledger {
+        let staker: T::AccountId = account("staker", 0, 0);
+    }: {
+        let _ = pallet_dapp_staking::Ledger::<T>::get(&staker);
+        let _ = ActiveProtocolState::<T>::get().period_number();
+    }
+
+    dapp_staking_staker_info {
+        let staker: T::AccountId = account("staker", 0, 0);
+        let smart_contract =
+            <T as pallet_dapp_staking::Config>::BenchmarkHelper::get_smart_contract(1);
+    }: {
+        let _ = StakerInfo::<T>::get(&staker, &smart_contract);
+    }
+
+    price_aggregator_read_price {
+        let currency = CurrencyId::ASTR;
+
+        // Full buffer, so the TWAP is computed over the maximum number of values.
+        let mut buffer = CircularBuffer::<<T as pallet_price_aggregator::Config>::TwapWindow>::default();
+        for _ in 0..<T as pallet_price_aggregator::Config>::TwapWindow::get() {
+            buffer.add(CurrencyAmount::from_rational(1, 10));
+        }
+        BlockValuesCircularBuffer::<T>::insert(currency, buffer);
+        LastProcessedBlock::<T>::insert(currency, 1);
+        StalePrices::<T>::insert(currency, 1);
+    }: {
+        let window = <T as pallet_price_aggregator::Config>::TwapWindow::get();
+        assert!(pallet_price_aggregator::Pallet::<T>::twap_price_over(currency, window).is_some());
+        assert!(pallet_price_aggregator::Pallet::<T>::staleness(currency).is_some());
+        assert!(pallet_price_aggregator::Pallet::<T>::is_stale(currency));
+    }
+
+    sr25519_verify {
+        let m in 0 .. MAX_MESSAGE_LEN;
+
+        let public = sp_io::crypto::sr25519_generate(BENCH_KEY_TYPE, None);
+        let message: Vec<u8> = vec![1_u8; m as usize];
+        let signature = sp_io::crypto::sr25519_sign(BENCH_KEY_TYPE, &public, &message)
+            .expect("Key was just generated, signing must succeed.");
+    }: {
+        assert!(sp_io::crypto::sr25519_verify(&signature, &message, &public));
+    }
+
+    ecdsa_verify {
+        let m in 0 .. MAX_MESSAGE_LEN;
+
+        let public = sp_io::crypto::ecdsa_generate(BENCH_KEY_TYPE, None);
+        let message: Vec<u8> = vec![1_u8; m as usize];
+        let signature = sp_io::crypto::ecdsa_sign(BENCH_KEY_TYPE, &public, &message)
+            .expect("Key was just generated, signing must succeed.");
+    }: {
+        assert!(sp_io::crypto::ecdsa_verify(&signature, &message, &public));
+    }
+
+    eth_recover {
+        let public = sp_io::crypto::ecdsa_generate(BENCH_KEY_TYPE, None);
+        let message_hash = sp_io::hashing::keccak_256(b"chain extension benchmark");
+        let signature = sp_io::crypto::ecdsa_sign_prehashed(BENCH_KEY_TYPE, &public, &message_hash)
+            .expect("Key was just generated, signing must succeed.");
+    }: {
+        let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message_hash)
+            .expect("Signature is valid, recovery must succeed.");
+        let _ = sp_io::hashing::keccak_256(&public);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::benchmarking::tests::new_test_ext(),
+    crate::mock::Test
+);
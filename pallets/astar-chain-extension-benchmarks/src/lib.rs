@@ -0,0 +1,56 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Astar Chain Extension Benchmarks
+//!
+//! Benchmarks for the storage accesses & computations performed by Astar chain extensions which
+//! are not covered by dispatchable weights, e.g. storage queries or signature verification.
+//!
+//! The resulting weights are exposed through [`WeightInfo`], to be charged by the chain extensions
+//! via `charge_weight`, the same way dispatchable weights are.
+//! Until the weights are generated on reference hardware, the chain extensions keep their current
+//! charging, based on the database read weights & the fixed signature verification costs.
+//!
+//! Benchmarks are executed like any other pallet benchmark:
+//! ```text
+//! astar-collator benchmark pallet --pallet=astar_chain_extension_benchmarks --extrinsic=* ...
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Configuration of the chain extension benchmarks.
+pub trait Config: frame_system::Config {
+    /// Weight information for the chain extension functions.
+    type WeightInfo: WeightInfo;
+}
+
+/// Shorthand for the benchmarked chain extension weights of the given runtime.
+pub type WeightOf<T> = <T as Config>::WeightInfo;
+
+/// Benchmark entry point, used in the runtime's `define_benchmarks!`.
+pub struct Pallet<T>(PhantomData<T>);
@@ -0,0 +1,274 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::Config;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, SortedMembers},
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_arithmetic::fixed_point::FixedU128;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Perbill,
+};
+
+use astar_primitives::{
+    dapp_staking::{CycleConfiguration, SmartContract, StakingRewardHandler, StandardTierSlots},
+    oracle::{CurrencyId, PriceProvider, StalePriceBehavior},
+    AccountId, Balance, BlockNumber,
+};
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Block = Block;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU32<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<4>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type MaxFreezes = ConstU32<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u128;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<0>;
+    type AssetIdParameter = u128;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+type MockSmartContract = SmartContract<AccountId>;
+
+pub struct DummyPriceProvider;
+impl PriceProvider for DummyPriceProvider {
+    fn average_price() -> FixedU128 {
+        FixedU128::from_rational(1, 10)
+    }
+}
+
+pub struct DummyStakingRewardHandler;
+impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
+    fn staker_and_dapp_reward_pools(_total_staked_value: Balance) -> (Balance, Balance) {
+        (1_000_000, 1_000)
+    }
+
+    fn bonus_reward_pool() -> Balance {
+        3_000
+    }
+
+    fn payout_reward(_beneficiary: &AccountId, _reward: Balance) -> Result<(), ()> {
+        Ok(())
+    }
+}
+
+pub struct DummyCycleConfiguration;
+impl CycleConfiguration for DummyCycleConfiguration {
+    fn periods_per_cycle() -> u32 {
+        4
+    }
+
+    fn eras_per_voting_subperiod() -> u32 {
+        8
+    }
+
+    fn eras_per_build_and_earn_subperiod() -> u32 {
+        16
+    }
+
+    fn blocks_per_era() -> u32 {
+        10
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DappStakingBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_dapp_staking::BenchmarkHelper<MockSmartContract, AccountId>
+    for DappStakingBenchmarkHelper
+{
+    fn get_smart_contract(id: u32) -> MockSmartContract {
+        MockSmartContract::evm(sp_core::H160::from_low_u64_be(id as u64))
+    }
+
+    fn set_balance(_account: &AccountId, _amount: Balance) {}
+}
+
+parameter_types! {
+    pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(0);
+}
+
+impl pallet_dapp_staking::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type Currency = Balances;
+    type SmartContract = MockSmartContract;
+    type ContractRegisterOrigin = EnsureRoot<AccountId>;
+    type ContractUnregisterOrigin = EnsureRoot<AccountId>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type NativePriceProvider = DummyPriceProvider;
+    type StakingRewardHandler = DummyStakingRewardHandler;
+    type CycleConfiguration = DummyCycleConfiguration;
+    type Observers = ();
+    type AccountCheck = ();
+    type TierSlots = StandardTierSlots;
+    type SponsorshipPool = ();
+    type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
+    type EraRewardSpanLength = ConstU32<8>;
+    type RewardRetentionInPeriods = ConstU32<2>;
+    type MaxNumberOfContracts = ConstU32<10>;
+    type MaxUnlockingChunks = ConstU32<5>;
+    type MinimumLockedAmount = ConstU128<10>;
+    type UnlockingPeriod = ConstU32<2>;
+    type MaxNumberOfStakedContracts = ConstU32<5>;
+    type MinimumStakeAmount = ConstU128<3>;
+    type NumberOfTiers = ConstU32<4>;
+    type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = ConstU32<0>;
+    type OwnerLoyaltyMinimumStake = ConstU128<0>;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = DappStakingBenchmarkHelper;
+}
+
+parameter_types! {
+    pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    pub const AggregationDuration: BlockNumber = 16;
+    pub const OutlierThreshold: Perbill = Perbill::from_percent(100);
+    pub const StalenessThreshold: BlockNumber = 100;
+    pub const OnStalePrice: StalePriceBehavior = StalePriceBehavior::Error;
+    pub TrackedCurrencies: BoundedVec<CurrencyId, ConstU32<2>> =
+        BoundedVec::truncate_from(vec![CurrencyId::ASTR, CurrencyId::DOT]);
+}
+
+pub struct NoFeeders;
+impl SortedMembers<AccountId> for NoFeeders {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![]
+    }
+}
+
+impl pallet_price_aggregator::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxValuesPerBlock = ConstU32<4>;
+    type ProcessBlockValues = pallet_price_aggregator::AverageBlockValue;
+    type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<2>;
+    type TrackedCurrencies = TrackedCurrencies;
+    type CircularBufferLength = ConstU32<7>;
+    type AggregationDuration = AggregationDuration;
+    type TwapWindow = ConstU32<5>;
+    type PriceHistoryLength = ConstU32<4>;
+    type OutlierThreshold = OutlierThreshold;
+    type StalenessThreshold = StalenessThreshold;
+    type OnStalePrice = OnStalePrice;
+    type MaxOutlierStrikes = ConstU32<3>;
+    type OnStruckOut = ();
+    type Feeders = NoFeeders;
+    type ReinstateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+impl Config for Test {
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        DappStaking: pallet_dapp_staking,
+        PriceAggregator: pallet_price_aggregator,
+    }
+);
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
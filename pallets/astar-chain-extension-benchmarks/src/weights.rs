@@ -0,0 +1,368 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for astar_chain_extension_benchmarks
+//!
+//! These values were estimated by hand from the storage accesses of each benchmarked function, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=astar_chain_extension_benchmarks --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/astar-chain-extension-benchmarks/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for astar_chain_extension_benchmarks.
+pub trait WeightInfo {
+	fn assets_balance_of() -> Weight;
+	fn assets_total_supply() -> Weight;
+	fn assets_allowance() -> Weight;
+	fn assets_metadata_name() -> Weight;
+	fn assets_metadata_symbol() -> Weight;
+	fn assets_metadata_decimals() -> Weight;
+	fn assets_minimum_balance() -> Weight;
+	fn dapp_staking_protocol_state() -> Weight;
+	fn dapp_staking_current_era_info() -> Weight;
+	fn dapp_staking_ledger() -> Weight;
+	fn dapp_staking_staker_info() -> Weight;
+	fn price_aggregator_read_price() -> Weight;
+	fn sr25519_verify(m: u32, ) -> Weight;
+	fn ecdsa_verify(m: u32, ) -> Weight;
+	fn eth_recover() -> Weight;
+}
+
+/// Weights for astar_chain_extension_benchmarks using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Assets::Account` (r:1 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn assets_balance_of() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `3599`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3599)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn assets_total_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3687`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_200_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Approvals` (r:1 w:0)
+	/// Proof: `Assets::Approvals` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	fn assets_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3613`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_300_000, 3613)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_400_000, 3605)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_symbol() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_400_000, 3605)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3605)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn assets_minimum_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3687`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_200_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	fn dapp_staking_protocol_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1486`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_200_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:0)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	fn dapp_staking_current_era_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1597`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_300_000, 1597)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	fn dapp_staking_ledger() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `43`
+		//  Estimated: `3775`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(7_600_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:1 w:0)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	fn dapp_staking_staker_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3603`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3603)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `PriceAggregator::BlockValuesCircularBuffer` (r:1 w:0)
+	/// Proof: `PriceAggregator::BlockValuesCircularBuffer` (`max_values`: None, `max_size`: Some(1614), added: 4089, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::LastProcessedBlock` (r:1 w:0)
+	/// Proof: `PriceAggregator::LastProcessedBlock` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::StalePrices` (r:1 w:0)
+	/// Proof: `PriceAggregator::StalePrices` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn price_aggregator_read_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1662`
+		//  Estimated: `5079`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_800_000, 5079)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// The range of component `m` is `[0, 65536]`.
+	fn sr25519_verify(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(44_000_000, 0)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 65536]`.
+	fn ecdsa_verify(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 46_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 0)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(1_200, 0).saturating_mul(m.into()))
+	}
+	fn eth_recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Assets::Account` (r:1 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn assets_balance_of() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `3599`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3599)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn assets_total_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3687`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_200_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Approvals` (r:1 w:0)
+	/// Proof: `Assets::Approvals` (`max_values`: None, `max_size`: Some(148), added: 2623, mode: `MaxEncodedLen`)
+	fn assets_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3613`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_300_000, 3613)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_400_000, 3605)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_symbol() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_400_000, 3605)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Metadata` (r:1 w:0)
+	/// Proof: `Assets::Metadata` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	fn assets_metadata_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3605`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3605)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn assets_minimum_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3687`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_200_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	fn dapp_staking_protocol_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1486`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_200_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:0)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	fn dapp_staking_current_era_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1597`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_300_000, 1597)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	fn dapp_staking_ledger() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `43`
+		//  Estimated: `3775`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(7_600_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:1 w:0)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
+	fn dapp_staking_staker_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3603`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_300_000, 3603)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `PriceAggregator::BlockValuesCircularBuffer` (r:1 w:0)
+	/// Proof: `PriceAggregator::BlockValuesCircularBuffer` (`max_values`: None, `max_size`: Some(1614), added: 4089, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::LastProcessedBlock` (r:1 w:0)
+	/// Proof: `PriceAggregator::LastProcessedBlock` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::StalePrices` (r:1 w:0)
+	/// Proof: `PriceAggregator::StalePrices` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn price_aggregator_read_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1662`
+		//  Estimated: `5079`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_800_000, 5079)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// The range of component `m` is `[0, 65536]`.
+	fn sr25519_verify(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 43_000_000 picoseconds.
+		Weight::from_parts(44_000_000, 0)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(1_100, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 65536]`.
+	fn ecdsa_verify(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 46_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 0)
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(1_200, 0).saturating_mul(m.into()))
+	}
+	fn eth_recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 0)
+	}
+}
@@ -0,0 +1,185 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::{
+    fungibles::{
+        approvals::Inspect as ApprovalInspect, metadata::Inspect as MetadataInspect, Inspect,
+    },
+    EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use pallet_assets::BenchmarkHelper as _;
+use pallet_dapp_staking::{
+    ActiveProtocolState, BenchmarkHelper as _, ContractStake, CurrentEraInfo, EraRewards,
+    IntegratedDApps, Ledger, StakerInfo,
+};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::traits::{StaticLookup, Zero};
+use sp_std::{vec, vec::Vec};
+
+/// Key type used to generate the sr25519 keys in the benchmarks.
+const BENCH_KEY_TYPE: KeyTypeId = KeyTypeId(*b"pcbn");
+
+/// Maximum message length used for the signature verification benchmark.
+const MAX_MESSAGE_LEN: u32 = 65_536;
+
+/// Creates a sufficient asset, owned by the returned account.
+fn create_asset<T: pallet_assets::Config>() -> (T::AssetIdParameter, T::AccountId) {
+    let owner: T::AccountId = account("owner", 0, 0);
+    let asset_id = <T as pallet_assets::Config>::BenchmarkHelper::create_asset_id_parameter(0);
+    let origin = <T as pallet_assets::Config>::ForceOrigin::try_successful_origin()
+        .expect("Must be able to create force origin for benchmarks.");
+
+    pallet_assets::Pallet::<T>::force_create(
+        origin,
+        asset_id.clone(),
+        T::Lookup::unlookup(owner.clone()),
+        true,
+        1_u32.into(),
+    )
+    .expect("Asset must be created.");
+
+    (asset_id, owner)
+}
+
+benchmarks! {
+    where_clause {
+        where T: pallet_assets::Config + pallet_dapp_staking::Config
+    }
+
+    assets_read_details {
+        let (asset_id, _) = create_asset::<T>();
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        let _ = pallet_assets::Pallet::<T>::total_issuance(asset_id.clone());
+        let _ = pallet_assets::Pallet::<T>::minimum_balance(asset_id);
+    }
+
+    assets_read_account {
+        let (asset_id, owner) = create_asset::<T>();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+
+        pallet_assets::Pallet::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            asset_id.clone(),
+            T::Lookup::unlookup(beneficiary.clone()),
+            1_000_u32.into(),
+        )?;
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        assert!(!pallet_assets::Pallet::<T>::balance(asset_id, &beneficiary).is_zero());
+    }
+
+    assets_read_approval {
+        let (asset_id, owner) = create_asset::<T>();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        let _ = pallet_assets::Pallet::<T>::allowance(asset_id, &owner, &delegate);
+    }
+
+    assets_read_metadata {
+        let (asset_id, _) = create_asset::<T>();
+        let origin = <T as pallet_assets::Config>::ForceOrigin::try_successful_origin()
+            .expect("Must be able to create force origin for benchmarks.");
+        let limit = <T as pallet_assets::Config>::StringLimit::get() as usize;
+
+        pallet_assets::Pallet::<T>::force_set_metadata(
+            origin,
+            asset_id.clone(),
+            vec![b'n'; limit],
+            vec![b's'; limit],
+            18,
+            false,
+        )?;
+        let asset_id: T::AssetId = asset_id.into();
+    }: {
+        assert_eq!(pallet_assets::Pallet::<T>::name(asset_id).len(), limit);
+    }
+
+    dapp_staking_read_protocol_state {
+    }: {
+        let _ = ActiveProtocolState::<T>::get();
+    }
+
+    dapp_staking_read_era_rewards {
+        let era = ActiveProtocolState::<T>::get().era();
+        let span_index = pallet_dapp_staking::Pallet::<T>::era_reward_span_index(era);
+    }: {
+        let _ = EraRewards::<T>::get(span_index);
+    }
+
+    dapp_staking_read_current_era_info {
+    }: {
+        let _ = CurrentEraInfo::<T>::get();
+    }
+
+    dapp_staking_read_ledger {
+        let staker: T::AccountId = account("staker", 0, 0);
+    }: {
+        let _ = Ledger::<T>::get(&staker);
+    }
+
+    dapp_staking_read_staker_info {
+        let staker: T::AccountId = account("staker", 0, 0);
+        let smart_contract =
+            <T as pallet_dapp_staking::Config>::BenchmarkHelper::get_smart_contract(1);
+    }: {
+        let _ = StakerInfo::<T>::get(&staker, &smart_contract);
+    }
+
+    dapp_staking_read_contract_stake {
+        let smart_contract =
+            <T as pallet_dapp_staking::Config>::BenchmarkHelper::get_smart_contract(1);
+    }: {
+        let dapp_id = IntegratedDApps::<T>::get(&smart_contract).map_or(0, |info| info.id());
+        let _ = ContractStake::<T>::get(&dapp_id);
+    }
+
+    sr25519_verify {
+        let m in 0 .. MAX_MESSAGE_LEN;
+
+        let public = sp_io::crypto::sr25519_generate(BENCH_KEY_TYPE, None);
+        let message: Vec<u8> = vec![1_u8; m as usize];
+        let signature = sp_io::crypto::sr25519_sign(BENCH_KEY_TYPE, &public, &message)
+            .expect("Key was just generated, signing must succeed.");
+    }: {
+        assert!(sp_io::crypto::sr25519_verify(&signature, &message, &public));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::benchmarking::tests::new_test_ext(),
+    crate::mock::Test
+);
@@ -0,0 +1,76 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Astar Precompile Benchmarks
+//!
+//! Benchmarks for the storage accesses & computations performed by Astar precompiles which
+//! are not covered by dispatchable weights, e.g. view functions or signature verification.
+//!
+//! The resulting weights are exposed through [`WeightInfo`], and can be converted to gas using the
+//! runtime's `GasWeightMapping` (i.e. `WeightPerGas`), via [`charge_weight`].
+//! Until the weights are generated on reference hardware, the precompiles keep charging their
+//! storage accesses with `record_db_read`, derived from the `max_encoded_len` of the read values.
+//!
+//! Benchmarks are executed like any other pallet benchmark:
+//! ```text
+//! astar-collator benchmark pallet --pallet=astar_precompile_benchmarks --extrinsic=* ...
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{ExitError, PrecompileHandle};
+use frame_support::weights::Weight;
+use pallet_evm::GasWeightMapping;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Configuration of the precompile benchmarks.
+pub trait Config: frame_system::Config {
+    /// Weight information for the precompile functions.
+    type WeightInfo: WeightInfo;
+}
+
+/// Shorthand for the benchmarked precompile weights of the given runtime.
+pub type WeightOf<T> = <T as Config>::WeightInfo;
+
+/// Benchmark entry point, used in the runtime's `define_benchmarks!`.
+pub struct Pallet<T>(PhantomData<T>);
+
+/// Converts the benchmarked `weight` into gas, using the runtime's `WeightPerGas`.
+pub fn weight_to_gas<T: pallet_evm::Config>(weight: Weight) -> u64 {
+    T::GasWeightMapping::weight_to_gas(weight)
+}
+
+/// Charges the benchmarked `weight` to the precompile handle.
+///
+/// Ref time is charged as gas, while both ref time & proof size are recorded as external cost,
+/// the same way it's done for the dispatched calls.
+pub fn charge_weight<T: pallet_evm::Config>(
+    handle: &mut impl PrecompileHandle,
+    weight: Weight,
+) -> Result<(), ExitError> {
+    handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()), None)?;
+    handle.record_cost(weight_to_gas::<T>(weight))
+}
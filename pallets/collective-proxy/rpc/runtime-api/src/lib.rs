@@ -0,0 +1,60 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Calls of a single pallet, identified by their metadata names.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PalletCalls {
+    /// Name of the pallet, as registered in the runtime.
+    pub pallet: Vec<u8>,
+    /// Names of the pallet's calls.
+    pub calls: Vec<Vec<u8>>,
+}
+
+/// Effective permissions of a collective proxy, as resolved against the live runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProxyPermissions<AccountId> {
+    /// Account on behalf of which the collective dispatches calls.
+    pub proxy_account: AccountId,
+    /// Calls permitted by the proxy's call filter.
+    pub permitted: Vec<PalletCalls>,
+    /// Calls which couldn't be instantiated for probing, hence their permission is unknown.
+    pub unprobed: Vec<PalletCalls>,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Collective Proxy Api.
+    ///
+    /// Used to audit the operational authority of collectives without reading the runtime source.
+    pub trait CollectiveProxyApi<AccountId> where AccountId: Codec {
+
+        /// Effective permissions of each registered collective proxy.
+        ///
+        /// Every call known to the runtime metadata is instantiated with default-encoded arguments
+        /// and probed against the proxy's call filter. Filters which inspect call arguments are
+        /// therefore only evaluated for the default argument values.
+        fn proxy_permissions() -> Vec<ProxyPermissions<AccountId>>;
+    }
+}
@@ -21,11 +21,14 @@
 use frame_support::{
     dispatch::GetDispatchInfo,
     pallet_prelude::*,
-    traits::{InstanceFilter, IsType, OriginTrait},
+    traits::{GetCallMetadata, InstanceFilter, IsType, OriginTrait},
 };
 use frame_system::pallet_prelude::*;
+use parity_scale_codec::DecodeLimit;
 use sp_runtime::traits::Dispatchable;
-use sp_std::prelude::*;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+pub use collective_proxy_runtime_api::{PalletCalls, ProxyPermissions};
 
 pub use pallet::*;
 
@@ -40,6 +43,13 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Number of zero bytes appended to the call index when instantiating a call for probing.
+/// Must be large enough for decoding of any call with default-encoded arguments.
+const PROBE_ARGS_LEN: usize = 256;
+
+/// Maximum depth of nested calls when instantiating a call for probing.
+const PROBE_DECODE_DEPTH: u32 = 8;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -62,6 +72,7 @@ pub mod pallet {
         type RuntimeCall: Parameter
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
             + GetDispatchInfo
+            + GetCallMetadata
             + From<frame_system::Call<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeCall>;
 
@@ -122,4 +133,75 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// Resolve the effective permissions of the collective proxy.
+        ///
+        /// Each call is instantiated by decoding its pallet & call index, followed by zero bytes for the arguments,
+        /// and is then probed against the configured `CallFilter`. Calls which cannot be decoded that way
+        /// are reported as _unprobed_.
+        pub fn permissions() -> ProxyPermissions<T::AccountId> {
+            let filter = T::CallFilter::default();
+
+            let mut probed = BTreeSet::new();
+            let mut permitted: Vec<PalletCalls> = Vec::new();
+
+            let mut encoded = [0_u8; PROBE_ARGS_LEN + 2];
+            for pallet_index in 0..=u8::MAX {
+                for call_index in 0..=u8::MAX {
+                    encoded[0] = pallet_index;
+                    encoded[1] = call_index;
+
+                    let call = match <T as Config>::RuntimeCall::decode_with_depth_limit(
+                        PROBE_DECODE_DEPTH,
+                        &mut &encoded[..],
+                    ) {
+                        Ok(call) => call,
+                        Err(_) => continue,
+                    };
+
+                    let metadata = call.get_call_metadata();
+                    if !probed.insert((metadata.pallet_name, metadata.function_name)) {
+                        continue;
+                    }
+
+                    if filter.filter(&call) {
+                        Self::push_call(
+                            &mut permitted,
+                            metadata.pallet_name,
+                            metadata.function_name,
+                        );
+                    }
+                }
+            }
+
+            let mut unprobed: Vec<PalletCalls> = Vec::new();
+            for pallet_name in <T as Config>::RuntimeCall::get_module_names() {
+                for call_name in <T as Config>::RuntimeCall::get_call_names(pallet_name) {
+                    if !probed.contains(&(*pallet_name, *call_name)) {
+                        Self::push_call(&mut unprobed, pallet_name, call_name);
+                    }
+                }
+            }
+
+            ProxyPermissions {
+                proxy_account: T::ProxyAccountId::get(),
+                permitted,
+                unprobed,
+            }
+        }
+
+        /// Append the call to the list, grouping consecutive calls of the same pallet together.
+        fn push_call(list: &mut Vec<PalletCalls>, pallet_name: &str, call_name: &str) {
+            match list.last_mut() {
+                Some(entry) if entry.pallet == pallet_name.as_bytes() => {
+                    entry.calls.push(call_name.as_bytes().to_vec())
+                }
+                _ => list.push(PalletCalls {
+                    pallet: pallet_name.as_bytes().to_vec(),
+                    calls: vec![call_name.as_bytes().to_vec()],
+                }),
+            }
+        }
+    }
 }
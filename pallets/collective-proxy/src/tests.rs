@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Event};
+use crate::{mock::*, Event, PalletCalls};
 
 use frame_support::{assert_noop, assert_ok, error::BadOrigin};
 use pallet_balances::Call as BalancesCall;
@@ -94,3 +94,29 @@ fn execute_call_succeeds() {
         assert_eq!(init_balance, after_balance + transfer_value,);
     });
 }
+
+#[test]
+fn permissions_are_resolved_from_call_filter() {
+    ExtBuilder::build().execute_with(|| {
+        let permissions = CollectiveProxy::permissions();
+
+        assert_eq!(permissions.proxy_account, COMMUNITY_ACCOUNT);
+        assert_eq!(
+            permissions.permitted,
+            vec![
+                PalletCalls {
+                    pallet: b"System".to_vec(),
+                    calls: vec![b"remark".to_vec()],
+                },
+                PalletCalls {
+                    pallet: b"Balances".to_vec(),
+                    calls: vec![b"transfer_allow_death".to_vec()],
+                },
+            ]
+        );
+        assert!(
+            permissions.unprobed.is_empty(),
+            "All mock runtime calls can be instantiated with default arguments."
+        );
+    });
+}
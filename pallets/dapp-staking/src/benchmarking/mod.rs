@@ -1007,6 +1007,33 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn update_owner_loyalty(x: Linear<0, { max_number_of_contracts::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, to prepare for loyalty update.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+
+        let era = ActiveProtocolState::<T>::get().era;
+        let period = ActiveProtocolState::<T>::get().period_number();
+
+        // Worst case is when each dApp already has an ongoing streak, which is extended.
+        for dapp_id in 0..x {
+            OwnerLoyalty::<T>::insert(
+                dapp_id as DAppId,
+                OwnerLoyaltyInfo::new(period.saturating_sub(1)),
+            );
+        }
+
+        #[block]
+        {
+            let counter = Pallet::<T>::update_owner_loyalty(era, period);
+            assert_eq!(counter as u32, x);
+        }
+    }
+
     #[benchmark]
     fn on_idle_cleanup() {
         // Prepare init config (protocol state, tier params & config, etc.)
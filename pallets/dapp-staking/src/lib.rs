@@ -54,8 +54,8 @@ pub use sp_std::vec::Vec;
 use astar_primitives::{
     dapp_staking::{
         AccountCheck, CycleConfiguration, DAppId, EraNumber, Observer as DAppStakingObserver,
-        PeriodNumber, Rank, RankedTier, SmartContractHandle, StakingRewardHandler, TierId,
-        TierSlots as TierSlotFunc,
+        PeriodNumber, Rank, RankedTier, SmartContractHandle, SponsorshipPool, StakingRewardHandler,
+        TierId, TierSlots as TierSlotFunc,
     },
     oracle::PriceProvider,
     Balance, BlockNumber,
@@ -141,6 +141,8 @@ pub mod pallet {
         type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
         /// Used to provide price information about the native token.
+        ///
+        /// Tier configuration isn't re-calculated while the provider reports the price as unreliable.
         type NativePriceProvider: PriceProvider;
 
         /// Used to handle reward payouts & reward pool amount fetching.
@@ -158,6 +160,11 @@ pub mod pallet {
         /// Used to calculate total number of tier slots for some price.
         type TierSlots: TierSlotFunc;
 
+        /// Fee sponsorship pool, which dApps can fund with a share of their dApp rewards.
+        ///
+        /// Opting in, and the share of the rewards, are managed by the sponsorship pool itself.
+        type SponsorshipPool: SponsorshipPool<Self::AccountId, Self::SmartContract>;
+
         /// Base native currency price used to calculate base number of slots.
         /// This is used to adjust tier configuration, tier thresholds specifically, based on the native token price changes.
         ///
@@ -211,6 +218,21 @@ pub mod pallet {
         #[pallet::constant]
         type RankingEnabled: Get<bool>;
 
+        /// Number of consecutive periods a dApp must remain registered, with at least `OwnerLoyaltyMinimumStake`
+        /// staked on it at the end of each period, before its dApp rewards become eligible for the owner loyalty bonus.
+        ///
+        /// Zero value disables the owner loyalty bonus.
+        #[pallet::constant]
+        type OwnerLoyaltyPeriods: Get<PeriodNumber>;
+
+        /// Minimum amount which must be staked on a dApp at the end of a period for the period to count towards the owner loyalty streak.
+        #[pallet::constant]
+        type OwnerLoyaltyMinimumStake: Get<Balance>;
+
+        /// Owner loyalty bonus, relative to the dApp reward, which is paid out on top of the dApp reward to loyal dApps.
+        #[pallet::constant]
+        type OwnerLoyaltyBonus: Get<Perbill>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -316,6 +338,25 @@ pub mod pallet {
         ExpiredEntriesRemoved { account: T::AccountId, count: u16 },
         /// Privileged origin has forced a new era and possibly a subperiod to start from next block.
         Force { forcing_type: ForcingType },
+        /// dApp has completed the required owner loyalty streak, and its future dApp rewards are eligible for the loyalty bonus.
+        DAppLoyaltyReached {
+            dapp_id: DAppId,
+            period: PeriodNumber,
+        },
+        /// Owner loyalty bonus has been paid out to a dApp beneficiary, on top of the dApp reward.
+        DAppLoyaltyBonus {
+            beneficiary: T::AccountId,
+            smart_contract: T::SmartContract,
+            era: EraNumber,
+            amount: Balance,
+        },
+        /// Share of the dApp reward has been routed into the fee sponsorship pool.
+        DAppRewardSponsored {
+            smart_contract: T::SmartContract,
+            pool: T::AccountId,
+            era: EraNumber,
+            amount: Balance,
+        },
     }
 
     #[pallet::error]
@@ -487,6 +528,31 @@ pub mod pallet {
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
 
+    /// Owner loyalty streak of each registered dApp.
+    ///
+    /// Entry is removed when the dApp is unregistered.
+    #[pallet::storage]
+    pub type OwnerLoyalty<T: Config> = StorageMap<
+        Hasher = Twox64Concat,
+        Key = DAppId,
+        Value = OwnerLoyaltyInfo,
+        QueryKind = OptionQuery,
+        MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
+    >;
+
+    /// Owner loyalty bonus of each eligible dApp, recorded when the era's tier assignment is calculated.
+    ///
+    /// Bonuses are funded from the part of the era's dApp reward pool which wasn't allocated to any tier slot.
+    /// Entry is consumed when the dApp reward is claimed, and cleaned up together with the `DAppTiers` entry.
+    #[pallet::storage]
+    pub type LoyaltyBonuses<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EraNumber,
+        BoundedBTreeMap<DAppId, Balance, T::MaxNumberOfContracts>,
+        OptionQuery,
+    >;
+
     #[pallet::type_value]
     pub fn DefaultSafeguard<T: Config>() -> bool {
         // In production, safeguard is enabled by default.
@@ -831,6 +897,7 @@ pub mod pallet {
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
             ContractStake::<T>::remove(&dapp_info.id);
+            OwnerLoyalty::<T>::remove(&dapp_info.id);
             IntegratedDApps::<T>::remove(&smart_contract);
 
             let current_era = ActiveProtocolState::<T>::get().era;
@@ -1302,23 +1369,71 @@ pub mod pallet {
 
             let (tier_id, rank) = ranked_tier.deconstruct();
 
+            // Loyal dApps receive the bonus recorded for them when the era ended, on top of the regular dApp reward.
+            let loyalty_bonus = LoyaltyBonuses::<T>::mutate_exists(&era, |maybe_bonuses| {
+                let bonuses = maybe_bonuses.as_mut()?;
+                let bonus = bonuses.remove(&dapp_info.id);
+                if bonuses.is_empty() {
+                    *maybe_bonuses = None;
+                }
+                bonus
+            })
+            .unwrap_or_default();
+
+            // Route the opted-in share of the reward into the fee sponsorship pool, if it's available.
+            let total_reward = amount.saturating_add(loyalty_bonus);
+            let sponsorship = match T::SponsorshipPool::sponsorship(&smart_contract) {
+                Some((pool, share)) if !(share * total_reward).is_zero() => {
+                    Some((pool, share * total_reward))
+                }
+                _ => None,
+            };
+            let sponsored_amount = sponsorship
+                .as_ref()
+                .map_or(Balance::zero(), |(_, amount)| *amount);
+
             // Get reward destination, and deposit the reward.
             let beneficiary = dapp_info.reward_beneficiary();
-            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            T::StakingRewardHandler::payout_reward(
+                &beneficiary,
+                total_reward.saturating_sub(sponsored_amount),
+            )
+            .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            if let Some((pool, sponsored_amount)) = sponsorship {
+                T::StakingRewardHandler::payout_reward(&pool, sponsored_amount)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+                T::SponsorshipPool::on_funded(&smart_contract, sponsored_amount);
+
+                Self::deposit_event(Event::<T>::DAppRewardSponsored {
+                    smart_contract: smart_contract.clone(),
+                    pool,
+                    era,
+                    amount: sponsored_amount,
+                });
+            }
 
             // Write back updated struct to prevent double reward claims
             DAppTiers::<T>::insert(&era, dapp_tiers);
 
             Self::deposit_event(Event::<T>::DAppReward {
                 beneficiary: beneficiary.clone(),
-                smart_contract,
+                smart_contract: smart_contract.clone(),
                 tier_id,
                 rank,
                 era,
                 amount,
             });
 
+            if !loyalty_bonus.is_zero() {
+                Self::deposit_event(Event::<T>::DAppLoyaltyBonus {
+                    beneficiary: beneficiary.clone(),
+                    smart_contract,
+                    era,
+                    amount: loyalty_bonus,
+                });
+            }
+
             Ok(())
         }
 
@@ -1745,6 +1860,86 @@ pub mod pallet {
             )
         }
 
+        /// Calculate the owner loyalty bonus of each loyal dApp in the provided tier assignment.
+        ///
+        /// A dApp is eligible if its loyalty streak covers the period of the tier assignment.
+        /// Bonuses are paid out of the part of `dapp_reward_pool` which wasn't allocated to any dApp,
+        /// and are reduced proportionally in case it doesn't suffice to cover all of them.
+        pub(crate) fn calculate_loyalty_bonuses(
+            dapp_tiers: &DAppTierRewardsFor<T>,
+            dapp_reward_pool: Balance,
+        ) -> BoundedBTreeMap<DAppId, Balance, T::MaxNumberOfContracts> {
+            let required_periods = T::OwnerLoyaltyPeriods::get();
+
+            let mut allocated = Balance::zero();
+            let mut bonuses = BTreeMap::new();
+            for (dapp_id, reward) in dapp_tiers.unclaimed_rewards() {
+                allocated.saturating_accrue(reward);
+
+                let is_eligible = OwnerLoyalty::<T>::get(dapp_id).map_or(false, |loyalty| {
+                    loyalty.is_eligible_for(dapp_tiers.period, required_periods)
+                });
+                let bonus = T::OwnerLoyaltyBonus::get() * reward;
+                if is_eligible && !bonus.is_zero() {
+                    bonuses.insert(dapp_id, bonus);
+                }
+            }
+
+            // Scale the bonuses down if they exceed the remaining dApp reward budget.
+            let budget = dapp_reward_pool.saturating_sub(allocated);
+            let total_bonus = bonuses
+                .values()
+                .fold(Balance::zero(), |acc, bonus| acc.saturating_add(*bonus));
+            if total_bonus > budget {
+                let ratio = Perbill::from_rational(budget, total_bonus);
+                bonuses
+                    .values_mut()
+                    .for_each(|bonus| *bonus = ratio * *bonus);
+                bonuses.retain(|_, bonus| !bonus.is_zero());
+            }
+
+            // Number of bonuses is bounded by the number of dApps in the tier assignment.
+            BoundedBTreeMap::try_from(bonuses).unwrap_or_default()
+        }
+
+        /// Update owner loyalty streaks of all dApps at the end of the specified `period`.
+        ///
+        /// Each dApp which has at least `OwnerLoyaltyMinimumStake` staked on it in the final era of the period
+        /// has its streak extended (or a new one started). dApps which don't satisfy the condition will have their streak
+        /// restarted the next time they do.
+        ///
+        /// Returns the number of read DB contract stake entries.
+        pub(crate) fn update_owner_loyalty(era: EraNumber, period: PeriodNumber) -> DAppId {
+            let required_periods = T::OwnerLoyaltyPeriods::get();
+            let minimum_stake = T::OwnerLoyaltyMinimumStake::get();
+
+            // Iterate over all staked dApps.
+            // This is bounded by max amount of dApps we allow to be registered.
+            let mut counter = 0;
+            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
+                counter.saturating_inc();
+
+                let staked = stake_amount
+                    .get(era, period)
+                    .map_or(Balance::zero(), |stake| stake.total());
+                if staked.is_zero() || staked < minimum_stake {
+                    continue;
+                }
+
+                let loyalty = OwnerLoyalty::<T>::mutate(dapp_id, |maybe_loyalty| {
+                    let loyalty = maybe_loyalty.get_or_insert(OwnerLoyaltyInfo::new(period));
+                    loyalty.note_period(period);
+                    *loyalty
+                });
+
+                if loyalty.streak_length() == required_periods {
+                    Self::deposit_event(Event::<T>::DAppLoyaltyReached { dapp_id, period });
+                }
+            }
+
+            counter
+        }
+
         /// Used to handle era & period transitions.
         pub(crate) fn era_and_period_handler(
             now: BlockNumber,
@@ -1833,6 +2028,20 @@ pub mod pallet {
                         #[cfg(feature = "runtime-benchmarks")]
                         TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
                     };
+
+                    // Record the loyalty bonuses earned in this era, funded by the unallocated dApp rewards.
+                    if !T::OwnerLoyaltyPeriods::get().is_zero() {
+                        let bonuses =
+                            Self::calculate_loyalty_bonuses(&dapp_tier_rewards, dapp_reward_pool);
+                        consumed_weight.saturating_accrue(
+                            T::DbWeight::get()
+                                .reads_writes(dapp_tier_rewards.dapps.len() as u64, 1),
+                        );
+                        if !bonuses.is_empty() {
+                            LoyaltyBonuses::<T>::insert(&current_era, bonuses);
+                        }
+                    }
+
                     DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
 
                     consumed_weight
@@ -1840,6 +2049,17 @@ pub mod pallet {
 
                     // Switch to `Voting` period if conditions are met.
                     if protocol_state.period_info.is_next_period(next_era) {
+                        // Update owner loyalty streaks, based on the final stake of the ending period.
+                        if !T::OwnerLoyaltyPeriods::get().is_zero() {
+                            let counter = Self::update_owner_loyalty(
+                                current_era,
+                                protocol_state.period_number(),
+                            );
+                            consumed_weight.saturating_accrue(T::WeightInfo::update_owner_loyalty(
+                                counter.into(),
+                            ));
+                        }
+
                         // Store info about period end
                         let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
                         PeriodEnd::<T>::insert(
@@ -1913,23 +2133,37 @@ pub mod pallet {
             }
             EraRewards::<T>::insert(&era_span_index, span);
 
-            // Re-calculate tier configuration for the upcoming new era
-            let tier_params = StaticTierParams::<T>::get();
-            let average_price = T::NativePriceProvider::average_price();
-            let total_issuance = T::Currency::total_issuance();
+            // Re-calculate tier configuration for the upcoming new era, unless the native price is unreliable
+            match T::NativePriceProvider::try_average_price() {
+                Ok(average_price) => {
+                    let tier_params = StaticTierParams::<T>::get();
+                    let total_issuance = T::Currency::total_issuance();
 
-            let new_tier_config =
-                TierConfig::<T>::get().calculate_new(&tier_params, average_price, total_issuance);
+                    let new_tier_config = TierConfig::<T>::get().calculate_new(
+                        &tier_params,
+                        average_price,
+                        total_issuance,
+                    );
 
-            // Validate new tier configuration
-            if new_tier_config.is_valid() {
-                TierConfig::<T>::put(new_tier_config);
-            } else {
-                log::warn!(
-                    target: LOG_TARGET,
-                    "New tier configuration is invalid for era {}, preserving old one.",
-                    next_era
-                );
+                    // Validate new tier configuration
+                    if new_tier_config.is_valid() {
+                        TierConfig::<T>::put(new_tier_config);
+                    } else {
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "New tier configuration is invalid for era {}, preserving old one.",
+                            next_era
+                        );
+                    }
+                }
+                Err(error) => {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Native price is unreliable ({:?}) for era {}, preserving old tier configuration.",
+                        error,
+                        next_era
+                    );
+                }
             }
 
             Self::deposit_event(Event::<T>::NewEra { era: next_era });
@@ -2023,6 +2257,7 @@ pub mod pallet {
             // 2. Attempt to cleanup one expired `DAppTiers` entry.
             if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
                 DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
+                LoyaltyBonuses::<T>::remove(cleanup_marker.dapp_tiers_index);
                 cleanup_marker.dapp_tiers_index.saturating_inc();
             }
 
@@ -2477,6 +2712,35 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> Event<T> {
+        /// Accounts involved in the event, e.g. the staker, the dApp owner or the reward beneficiary.
+        pub fn accounts(&self) -> Vec<T::AccountId> {
+            match self {
+                Self::DAppRegistered { owner, .. } => vec![owner.clone()],
+                Self::DAppRewardDestinationUpdated { beneficiary, .. } => {
+                    beneficiary.iter().cloned().collect()
+                }
+                Self::DAppOwnerChanged { new_owner, .. } => vec![new_owner.clone()],
+                Self::Locked { account, .. }
+                | Self::Unlocking { account, .. }
+                | Self::ClaimedUnlocked { account, .. }
+                | Self::Relock { account, .. }
+                | Self::Stake { account, .. }
+                | Self::Unstake { account, .. }
+                | Self::Reward { account, .. }
+                | Self::BonusReward { account, .. }
+                | Self::UnstakeFromUnregistered { account, .. }
+                | Self::ExpiredEntriesRemoved { account, .. } => vec![account.clone()],
+                Self::DAppReward { beneficiary, .. }
+                | Self::DAppLoyaltyBonus { beneficiary, .. } => {
+                    vec![beneficiary.clone()]
+                }
+                Self::DAppRewardSponsored { pool, .. } => vec![pool.clone()],
+                _ => Vec::new(),
+            }
+        }
+    }
+
     /// Implementation of the `SafeModeNotify` trait for the `DappStaking` pallet.
     /// This integration ensures that the dApp staking protocol transitions to and from
     /// maintenance mode when the runtime enters or exits safe mode.
@@ -34,12 +34,15 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Permill,
+    BuildStorage, Perbill, Permill,
 };
 use sp_std::cell::RefCell;
 
 use astar_primitives::{
-    dapp_staking::{Observer as DappStakingObserver, SmartContract, StandardTierSlots},
+    dapp_staking::{
+        Observer as DappStakingObserver, SmartContract, SponsorshipPool, StandardTierSlots,
+    },
+    oracle::PriceError,
     Balance, BlockNumber,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
@@ -134,12 +137,32 @@ impl PriceProvider for DummyPriceProvider {
     fn average_price() -> FixedU128 {
         NATIVE_PRICE.with(|v| v.borrow().clone())
     }
+
+    fn try_average_price() -> Result<FixedU128, PriceError> {
+        if IS_NATIVE_PRICE_STALE.with(|v| *v.borrow()) {
+            Err(PriceError::Stale)
+        } else {
+            Ok(Self::average_price())
+        }
+    }
 }
 
 thread_local! {
     pub(crate) static DOES_PAYOUT_SUCCEED: RefCell<bool> = RefCell::new(false);
     pub(crate) static BLOCK_BEFORE_NEW_ERA: RefCell<EraNumber> = RefCell::new(0);
     pub(crate) static NATIVE_PRICE: RefCell<FixedU128> = RefCell::new(BaseNativeCurrencyPrice::get());
+    pub(crate) static IS_NATIVE_PRICE_STALE: RefCell<bool> = RefCell::new(false);
+    pub(crate) static OWNER_LOYALTY_PERIODS: RefCell<PeriodNumber> = RefCell::new(0);
+    pub(crate) static SPONSORSHIP_SHARE: RefCell<Option<Perbill>> = RefCell::new(None);
+    pub(crate) static SPONSORSHIP_FUNDED: RefCell<Balance> = RefCell::new(0);
+}
+
+/// Owner loyalty is disabled by default, and can be enabled per test.
+pub struct OwnerLoyaltyPeriods;
+impl Get<PeriodNumber> for OwnerLoyaltyPeriods {
+    fn get() -> PeriodNumber {
+        OWNER_LOYALTY_PERIODS.with(|v| v.borrow().clone())
+    }
 }
 
 pub struct DummyStakingRewardHandler;
@@ -167,6 +190,24 @@ impl StakingRewardHandler<AccountId> for DummyStakingRewardHandler {
 
 pub(crate) type MockSmartContract = SmartContract<AccountId>;
 
+pub(crate) const SPONSORSHIP_POOL_ACCOUNT: AccountId = 555_666;
+/// Sponsorship is available only for Wasm smart contracts, with the share configured per test.
+pub struct DummySponsorshipPool;
+impl SponsorshipPool<AccountId, MockSmartContract> for DummySponsorshipPool {
+    fn sponsorship(smart_contract: &MockSmartContract) -> Option<(AccountId, Perbill)> {
+        match smart_contract {
+            SmartContract::Wasm(_) => SPONSORSHIP_SHARE
+                .with(|v| v.borrow().clone())
+                .map(|share| (SPONSORSHIP_POOL_ACCOUNT, share)),
+            SmartContract::Evm(_) => None,
+        }
+    }
+
+    fn on_funded(_smart_contract: &MockSmartContract, amount: Balance) {
+        SPONSORSHIP_FUNDED.with(|v| *v.borrow_mut() += amount);
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct BenchmarkHelper<SC, ACC>(sp_std::marker::PhantomData<(SC, ACC)>);
 #[cfg(feature = "runtime-benchmarks")]
@@ -221,6 +262,7 @@ impl AccountCheck<AccountId> for DummyAccountCheck {
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(10);
 }
 ord_parameter_types! {
     pub const ContractRegisterAccount: AccountId = 1337;
@@ -247,6 +289,7 @@ impl pallet_dapp_staking::Config for Test {
     type Observers = DummyDappStakingObserver;
     type AccountCheck = DummyAccountCheck;
     type TierSlots = StandardTierSlots;
+    type SponsorshipPool = DummySponsorshipPool;
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -258,6 +301,9 @@ impl pallet_dapp_staking::Config for Test {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = OwnerLoyaltyPeriods;
+    type OwnerLoyaltyMinimumStake = ConstU128<100>;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
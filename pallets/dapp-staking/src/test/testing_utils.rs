@@ -20,8 +20,8 @@ use crate::test::mock::*;
 use crate::types::*;
 use crate::{
     pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, LoyaltyBonuses,
+    NextDAppId, OwnerLoyalty, PeriodEnd, PeriodEndInfo, StakerInfo,
 };
 
 use frame_support::{
@@ -33,7 +33,7 @@ use sp_runtime::{traits::Zero, Perbill};
 use std::collections::HashMap;
 
 use astar_primitives::{
-    dapp_staking::{CycleConfiguration, EraNumber, PeriodNumber},
+    dapp_staking::{CycleConfiguration, EraNumber, PeriodNumber, SponsorshipPool},
     Balance, BlockNumber,
 };
 
@@ -202,6 +202,9 @@ pub(crate) fn assert_unregister(smart_contract: &MockSmartContract) {
     assert!(!ContractStake::<Test>::contains_key(
         &pre_snapshot.integrated_dapps[&smart_contract].id
     ));
+    assert!(!OwnerLoyalty::<Test>::contains_key(
+        &pre_snapshot.integrated_dapps[&smart_contract].id
+    ));
 }
 
 /// Lock funds into dApp staking and assert success.
@@ -1035,6 +1038,20 @@ pub(crate) fn assert_claim_dapp_reward(
         let mut info = pre_reward_info.clone();
         info.try_claim(dapp_info.id).unwrap()
     };
+    let expected_loyalty_bonus = LoyaltyBonuses::<Test>::get(&era)
+        .and_then(|bonuses| bonuses.get(&dapp_info.id).copied())
+        .unwrap_or_default();
+    let expected_total_reward = expected_reward + expected_loyalty_bonus;
+    let maybe_sponsorship = match <Test as Config>::SponsorshipPool::sponsorship(smart_contract) {
+        Some((pool, share)) if !(share * expected_total_reward).is_zero() => {
+            Some((pool, share * expected_total_reward))
+        }
+        _ => None,
+    };
+    let expected_sponsored = maybe_sponsorship.map_or(0, |(_, amount)| amount);
+    let pre_pool_balance = maybe_sponsorship.map_or(0, |(pool, _)| {
+        <Test as Config>::Currency::free_balance(pool)
+    });
 
     // Claim dApp reward & verify event
     assert_ok!(DappStaking::claim_dapp_reward(
@@ -1042,7 +1059,7 @@ pub(crate) fn assert_claim_dapp_reward(
         smart_contract.clone(),
         era,
     ));
-    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
+    System::assert_has_event(RuntimeEvent::DappStaking(Event::DAppReward {
         beneficiary: beneficiary.clone(),
         smart_contract: smart_contract.clone(),
         tier_id: expected_ranked_tier.tier(),
@@ -1050,23 +1067,47 @@ pub(crate) fn assert_claim_dapp_reward(
         era,
         amount: expected_reward,
     }));
+    if expected_loyalty_bonus > 0 {
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppLoyaltyBonus {
+            beneficiary: beneficiary.clone(),
+            smart_contract: smart_contract.clone(),
+            era,
+            amount: expected_loyalty_bonus,
+        }));
+    }
+    if let Some((pool, amount)) = maybe_sponsorship {
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::DAppRewardSponsored {
+            smart_contract: smart_contract.clone(),
+            pool,
+            era,
+            amount,
+        }));
+    }
 
     // Verify post-state
 
     let post_total_issuance = <Test as Config>::Currency::total_issuance();
     assert_eq!(
         post_total_issuance,
-        pre_total_issuance + expected_reward,
+        pre_total_issuance + expected_total_reward,
         "Total issuance must increase by the reward amount."
     );
 
     let post_free_balance = <Test as Config>::Currency::free_balance(beneficiary);
     assert_eq!(
         post_free_balance,
-        pre_free_balance + expected_reward,
-        "Free balance must increase by the reward amount."
+        pre_free_balance + expected_total_reward - expected_sponsored,
+        "Free balance must increase by the reward amount, reduced by the sponsored amount."
     );
 
+    if let Some((pool, amount)) = maybe_sponsorship {
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(pool),
+            pre_pool_balance + amount,
+            "Sponsorship pool balance must increase by the sponsored amount."
+        );
+    }
+
     let post_snapshot = MemorySnapshot::new();
     let mut post_reward_info = post_snapshot
         .dapp_tiers
@@ -1083,6 +1124,11 @@ pub(crate) fn assert_claim_dapp_reward(
         post_reward_info.dapps.len() + 1,
         "Entry must have been removed after successful reward claim."
     );
+    assert!(
+        LoyaltyBonuses::<Test>::get(&era)
+            .map_or(true, |bonuses| !bonuses.contains_key(&dapp_info.id)),
+        "Loyalty bonus must be consumed together with the reward."
+    );
 }
 
 /// Unstake some funds from the specified unregistered smart contract.
@@ -1512,6 +1558,9 @@ pub(crate) fn assert_on_idle_cleanup() {
         assert!(!DAppTiers::<Test>::contains_key(
             pre_cleanup_marker.dapp_tiers_index
         ));
+        assert!(!LoyaltyBonuses::<Test>::contains_key(
+            pre_cleanup_marker.dapp_tiers_index
+        ));
         assert_eq!(
             post_cleanup_marker.dapp_tiers_index,
             pre_cleanup_marker.dapp_tiers_index + 1
@@ -19,9 +19,9 @@
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
     pallet::Config, ActiveProtocolState, ContractStake, DAppId, DAppTierRewardsFor, DAppTiers,
-    EraRewards, Error, Event, ForcingType, GenesisConfig, IntegratedDApps, Ledger, NextDAppId,
-    Perbill, PeriodNumber, Permill, Safeguard, StakerInfo, StaticTierParams, Subperiod, TierConfig,
-    TierThreshold,
+    EraRewards, Error, Event, ForcingType, GenesisConfig, IntegratedDApps, Ledger, LoyaltyBonuses,
+    NextDAppId, OwnerLoyalty, OwnerLoyaltyInfo, Perbill, PeriodNumber, Permill, Safeguard,
+    StakerInfo, StaticTierParams, Subperiod, TierConfig, TierThreshold,
 };
 
 use frame_support::{
@@ -1887,6 +1887,214 @@ fn claim_dapp_reward_works() {
     })
 }
 
+#[test]
+fn claim_dapp_reward_with_owner_loyalty_bonus_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        OWNER_LOYALTY_PERIODS.with(|v| *v.borrow_mut() = 2);
+
+        // Register smart contract
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // 1st period - stake & finish the period, streak is started
+        assert_lock(2, 300);
+        assert_stake(2, &smart_contract, 300);
+        advance_to_next_period();
+        assert_eq!(
+            OwnerLoyalty::<Test>::get(dapp_id),
+            Some(OwnerLoyaltyInfo::new(1))
+        );
+
+        // 2nd period - rewards aren't eligible for the bonus yet
+        assert_lock(3, 300);
+        assert_stake(3, &smart_contract, 300);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_claim_dapp_reward(
+            dev_account,
+            &smart_contract,
+            ActiveProtocolState::<Test>::get().era - 1,
+        );
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::DappStaking(Event::DAppLoyaltyBonus { .. })
+        )));
+
+        // Finish the 2nd period, required streak is reached
+        advance_to_next_period();
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::DAppLoyaltyReached {
+            dapp_id,
+            period: 2,
+        }));
+
+        // 3rd period - rewards are eligible for the bonus
+        assert_lock(4, 300);
+        assert_stake(4, &smart_contract, 300);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        assert_claim_dapp_reward(dev_account, &smart_contract, era);
+        assert!(matches!(
+            System::events().last().unwrap().event,
+            RuntimeEvent::DappStaking(Event::DAppLoyaltyBonus { era: bonus_era, amount, .. })
+                if bonus_era == era && amount > 0
+        ));
+
+        // Unregistering the dApp removes the loyalty info
+        assert_unregister(&smart_contract);
+    })
+}
+
+#[test]
+fn owner_loyalty_bonus_is_recorded_per_era() {
+    ExtBuilder::default().build_and_execute(|| {
+        OWNER_LOYALTY_PERIODS.with(|v| *v.borrow_mut() = 1);
+
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // 1st period completes the required streak
+        assert_lock(2, 300);
+        assert_stake(2, &smart_contract, 300);
+        advance_to_next_period();
+
+        // 2nd period - bonus is recorded for the era, without claiming it
+        assert_lock(3, 300);
+        assert_stake(3, &smart_contract, 300);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let bonus = LoyaltyBonuses::<Test>::get(&era)
+            .and_then(|bonuses| bonuses.get(&dapp_id).copied())
+            .expect("Bonus must be recorded for the loyal dApp.");
+        assert!(bonus > 0);
+
+        // Break the streak by unstaking everything before the period ends
+        assert_unstake(3, &smart_contract, 300);
+        advance_to_next_period();
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+
+        // Bonus earned in the era is still paid out, since eligibility was recorded when the era ended.
+        assert_claim_dapp_reward(dev_account, &smart_contract, era);
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppLoyaltyBonus {
+            beneficiary: dev_account,
+            smart_contract,
+            era,
+            amount: bonus,
+        }));
+    })
+}
+
+#[test]
+fn owner_loyalty_bonus_is_limited_by_dapp_reward_pool() {
+    ExtBuilder::default().build_and_execute(|| {
+        OWNER_LOYALTY_PERIODS.with(|v| *v.borrow_mut() = 1);
+
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        assert_lock(2, 300);
+        assert_stake(2, &smart_contract, 300);
+        advance_to_next_period();
+        assert_lock(3, 300);
+        assert_stake(3, &smart_contract, 300);
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let dapp_tiers = DAppTiers::<Test>::get(&era).unwrap();
+        let (reward, _) = dapp_tiers.clone().try_claim(dapp_id).unwrap();
+
+        // Entire pool is allocated to the dApp, nothing is left for the bonus
+        let bonuses = DappStaking::calculate_loyalty_bonuses(&dapp_tiers, reward);
+        assert!(bonuses.is_empty());
+
+        // Bonus is scaled down to the unallocated part of the pool
+        let full_bonus = <Test as Config>::OwnerLoyaltyBonus::get() * reward;
+        let budget = full_bonus / 2;
+        let bonuses = DappStaking::calculate_loyalty_bonuses(&dapp_tiers, reward + budget);
+        assert_eq!(
+            bonuses.get(&dapp_id),
+            Some(&(Perbill::from_rational(budget, full_bonus) * full_bonus))
+        );
+
+        // Unallocated part covers the full bonus
+        let bonuses = DappStaking::calculate_loyalty_bonuses(&dapp_tiers, reward * 2);
+        assert_eq!(bonuses.get(&dapp_id), Some(&full_bonus));
+    })
+}
+
+#[test]
+fn owner_loyalty_streak_requires_minimum_stake() {
+    ExtBuilder::default().build_and_execute(|| {
+        OWNER_LOYALTY_PERIODS.with(|v| *v.borrow_mut() = 2);
+
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // Stake below the loyalty minimum
+        let amount = <Test as Config>::OwnerLoyaltyMinimumStake::get() - 1;
+        assert_lock(2, amount);
+        assert_stake(2, &smart_contract, amount);
+        advance_to_next_period();
+        assert!(!OwnerLoyalty::<Test>::contains_key(dapp_id));
+
+        // Nothing staked during the 2nd period
+        advance_to_next_period();
+        assert!(!OwnerLoyalty::<Test>::contains_key(dapp_id));
+    })
+}
+
+#[test]
+fn owner_loyalty_disabled_by_default() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        assert_lock(2, 300);
+        assert_stake(2, &smart_contract, 300);
+        advance_to_next_period();
+
+        assert_eq!(OwnerLoyalty::<Test>::iter().count(), 0);
+    })
+}
+
+#[test]
+fn claim_dapp_reward_with_sponsorship_share_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Route a share of the rewards into the sponsorship pool
+        SPONSORSHIP_SHARE.with(|v| *v.borrow_mut() = Some(Perbill::from_percent(40)));
+
+        // Advance 2 eras so we have an entry for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_claim_dapp_reward(
+            account,
+            &smart_contract,
+            ActiveProtocolState::<Test>::get().era - 1,
+        );
+
+        let funded = SPONSORSHIP_FUNDED.with(|v| v.borrow().clone());
+        assert!(funded > 0);
+        assert_eq!(
+            Balances::free_balance(SPONSORSHIP_POOL_ACCOUNT),
+            funded,
+            "Entire sponsored amount must be deposited into the pool account."
+        );
+    })
+}
+
 #[test]
 fn claim_dapp_reward_from_non_existing_contract_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -2490,6 +2698,39 @@ fn tier_config_recalculation_works() {
     })
 }
 
+#[test]
+fn tier_config_is_preserved_while_native_price_is_stale() {
+    ExtBuilder::default().build_and_execute(|| {
+        let init_price = NATIVE_PRICE.with(|v| v.borrow().clone());
+        let init_tier_config = TierConfig::<Test>::get();
+
+        // 1. Price changes, but it's stale, so the tier config must remain the same
+        NATIVE_PRICE.with(|v| *v.borrow_mut() = init_price * FixedU128::from(3));
+        IS_NATIVE_PRICE_STALE.with(|v| *v.borrow_mut() = true);
+
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+
+        assert_eq!(
+            init_tier_config,
+            TierConfig::<Test>::get(),
+            "Native price is stale so tier config should remain the same."
+        );
+
+        // 2. Price is no longer stale, tier config is re-calculated
+        IS_NATIVE_PRICE_STALE.with(|v| *v.borrow_mut() = false);
+
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+
+        assert!(
+            TierConfig::<Test>::get().total_number_of_slots()
+                > init_tier_config.total_number_of_slots(),
+            "Price has increased, therefore number of slots must increase."
+        );
+    })
+}
+
 #[test]
 fn get_dapp_tier_assignment_and_rewards_basic_example_works() {
     ExtBuilder::default().build_and_execute(|| {
@@ -3487,3 +3728,39 @@ fn claim_bonus_reward_for_works() {
         );
     })
 }
+
+#[test]
+fn event_accounts_are_correct() {
+    let smart_contract = MockSmartContract::Wasm(1);
+
+    assert_eq!(
+        Event::<Test>::Stake {
+            account: 1,
+            smart_contract: smart_contract.clone(),
+            amount: 100,
+        }
+        .accounts(),
+        vec![1]
+    );
+    assert_eq!(
+        Event::<Test>::DAppReward {
+            beneficiary: 2,
+            smart_contract: smart_contract.clone(),
+            tier_id: 0,
+            rank: 0,
+            era: 1,
+            amount: 100,
+        }
+        .accounts(),
+        vec![2]
+    );
+    assert_eq!(
+        Event::<Test>::DAppRewardDestinationUpdated {
+            smart_contract,
+            beneficiary: None,
+        }
+        .accounts(),
+        Vec::<AccountId>::new()
+    );
+    assert!(Event::<Test>::NewEra { era: 2 }.accounts().is_empty());
+}
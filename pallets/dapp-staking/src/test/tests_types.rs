@@ -3115,3 +3115,41 @@ fn tier_thresholds_conversion_test() {
     assert_eq!(tier_thresholds[0], 100_000); // 10% of total issuance
     assert_eq!(tier_thresholds[1], 50_000); // 5% of total issuance
 }
+
+#[test]
+fn owner_loyalty_info_streak_works() {
+    let mut loyalty = OwnerLoyaltyInfo::new(3);
+    assert_eq!(loyalty.streak_length(), 1);
+
+    // Noting the same period again has no effect
+    loyalty.note_period(3);
+    assert_eq!(loyalty, OwnerLoyaltyInfo::new(3));
+
+    // Consecutive periods extend the streak
+    loyalty.note_period(4);
+    loyalty.note_period(5);
+    assert_eq!(loyalty.streak_length(), 3);
+    assert_eq!(loyalty.streak_start, 3);
+    assert_eq!(loyalty.streak_end, 5);
+
+    // Gap in periods restarts the streak
+    loyalty.note_period(7);
+    assert_eq!(loyalty, OwnerLoyaltyInfo::new(7));
+}
+
+#[test]
+fn owner_loyalty_info_eligibility_works() {
+    let mut loyalty = OwnerLoyaltyInfo::new(3);
+    loyalty.note_period(4);
+
+    // Streak of 2 periods completed, covering periods 3 & 4
+    assert!(!loyalty.is_eligible_for(4, 2));
+    assert!(loyalty.is_eligible_for(5, 2));
+    assert!(!loyalty.is_eligible_for(6, 2));
+
+    // Streak isn't long enough
+    assert!(!loyalty.is_eligible_for(5, 3));
+
+    // Zero required periods means the bonus is disabled
+    assert!(!loyalty.is_eligible_for(5, 0));
+}
@@ -167,6 +167,59 @@ pub struct PeriodEndInfo {
     pub(crate) final_era: EraNumber,
 }
 
+/// Owner loyalty streak of a dApp.
+///
+/// Covers the consecutive periods, in which dApp remained registered and had at least the minimum required stake
+/// at the end of the period.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub struct OwnerLoyaltyInfo {
+    /// First period of the streak.
+    #[codec(compact)]
+    pub(crate) streak_start: PeriodNumber,
+    /// Last period of the streak, inclusive.
+    #[codec(compact)]
+    pub(crate) streak_end: PeriodNumber,
+}
+
+impl OwnerLoyaltyInfo {
+    /// Create a new streak, starting & ending with the specified period.
+    pub fn new(period: PeriodNumber) -> Self {
+        Self {
+            streak_start: period,
+            streak_end: period,
+        }
+    }
+
+    /// Note that dApp satisfied the loyalty conditions in the specified period.
+    ///
+    /// If the period directly follows the last period of the streak, streak is extended.
+    /// Otherwise, a new streak is started.
+    pub fn note_period(&mut self, period: PeriodNumber) {
+        if self.streak_end.saturating_add(1) == period {
+            self.streak_end = period;
+        } else if self.streak_end != period {
+            *self = Self::new(period);
+        }
+    }
+
+    /// Number of periods covered by the streak.
+    pub fn streak_length(&self) -> PeriodNumber {
+        self.streak_end
+            .saturating_sub(self.streak_start)
+            .saturating_add(1)
+    }
+
+    /// `true` if dApp rewards earned in the specified period are eligible for the loyalty bonus, `false` otherwise.
+    ///
+    /// This is the case if the streak of at least `required_periods` was completed before the period started,
+    /// and wasn't broken before the period started.
+    pub fn is_eligible_for(&self, period: PeriodNumber, required_periods: PeriodNumber) -> bool {
+        !required_periods.is_zero()
+            && self.streak_start.saturating_add(required_periods) <= period
+            && period <= self.streak_end.saturating_add(1)
+    }
+}
+
 /// Force types to speed up the next era, and even period.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum ForcingType {
@@ -913,6 +966,16 @@ pub struct EraInfo {
 }
 
 impl EraInfo {
+    /// How much balance is locked in dApp staking, excluding the amount undergoing the unlocking period.
+    pub fn total_locked(&self) -> Balance {
+        self.total_locked
+    }
+
+    /// How much balance is undergoing unlocking process.
+    pub fn unlocking(&self) -> Balance {
+        self.unlocking
+    }
+
     /// Stake amount valid for the ongoing era.
     pub fn current_stake_amount(&self) -> StakeAmount {
         self.current_stake_amount
@@ -1786,8 +1849,20 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
             .remove(&dapp_id)
             .ok_or(DAppTierError::NoDAppInTiers)?;
 
+        Ok((self.reward_for(ranked_tier), ranked_tier))
+    }
+
+    /// Iterate over all dApps which haven't claimed their reward yet, together with their reward amounts.
+    pub(crate) fn unclaimed_rewards(&self) -> impl Iterator<Item = (DAppId, Balance)> + '_ {
+        self.dapps
+            .iter()
+            .map(|(dapp_id, ranked_tier)| (*dapp_id, self.reward_for(*ranked_tier)))
+    }
+
+    /// Reward amount for a dApp in the specified ranked tier.
+    fn reward_for(&self, ranked_tier: RankedTier) -> Balance {
         let (tier_id, rank) = ranked_tier.deconstruct();
-        let mut amount = self
+        let amount = self
             .rewards
             .get(tier_id as usize)
             .map_or(Balance::zero(), |x| *x);
@@ -1798,9 +1873,7 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
             .map_or(Balance::zero(), |x| *x);
 
         let additional_reward = reward_per_rank.saturating_mul(rank.into());
-        amount = amount.saturating_add(additional_reward);
-
-        Ok((amount, ranked_tier))
+        amount.saturating_add(additional_reward)
     }
 }
 
@@ -74,6 +74,7 @@ pub trait WeightInfo {
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
 	fn step() -> Weight;
+	fn update_owner_loyalty(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_dapp_staking using the Substrate node and recommended hardware.
@@ -489,6 +490,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DappStaking ContractStake (r:101 w:0)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking OwnerLoyalty (r:100 w:100)
+	/// Proof: DappStaking OwnerLoyalty (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn update_owner_loyalty(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + x * (40 ±0)`
+		//  Estimated: `3061 + x * (2071 ±0)`
+		// Minimum execution time: 6_973_000 picoseconds.
+		Weight::from_parts(9_842_311, 3061)
+			// Standard Error: 3_102
+			.saturating_add(Weight::from_parts(4_915_360, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -903,4 +922,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: DappStaking ContractStake (r:101 w:0)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// Storage: DappStaking OwnerLoyalty (r:100 w:100)
+	/// Proof: DappStaking OwnerLoyalty (max_values: Some(65535), max_size: Some(20), added: 2000, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn update_owner_loyalty(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152 + x * (40 ±0)`
+		//  Estimated: `3061 + x * (2071 ±0)`
+		// Minimum execution time: 6_973_000 picoseconds.
+		Weight::from_parts(9_842_311, 3061)
+			// Standard Error: 3_102
+			.saturating_add(Weight::from_parts(4_915_360, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
 }
@@ -0,0 +1,40 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_core::U256;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Dynamic EVM Base Fee Api.
+    ///
+    /// Used by wallets to pre-compute fees of transactions which will be included a few blocks later.
+    pub trait DynamicEvmBaseFeeApi {
+
+        /// Base fee per gas which applies to the next block.
+        fn base_fee_per_gas() -> U256;
+
+        /// Projected base fee per gas for each of the `blocks` blocks following the next one,
+        /// assuming the current adjustment trajectory is kept.
+        ///
+        /// Number of projected blocks is capped by the runtime.
+        fn projected_base_fee_per_gas(blocks: u32) -> Vec<U256>;
+    }
+}
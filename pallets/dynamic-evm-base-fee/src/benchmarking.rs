@@ -20,7 +20,7 @@ use super::*;
 
 use fp_evm::FeeCalculator;
 use frame_benchmarking::v2::*;
-use frame_support::traits::Hooks;
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
 use frame_system::{pallet_prelude::*, RawOrigin};
 use sp_std::prelude::*;
 
@@ -67,6 +67,41 @@ mod benchmarks {
         assert_eq!(BaseFeePerGas::<T>::get(), new_bfpg);
     }
 
+    #[benchmark]
+    fn set_base_fee_bounds() {
+        let bounds = BaseFeeBounds {
+            min: T::MinBaseFeePerGas::get() + 1,
+            max: T::MaxBaseFeePerGas::get(),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, Some(bounds));
+
+        assert_eq!(BaseFeeBoundsOverride::<T>::get(), Some(bounds));
+    }
+
+    #[benchmark]
+    fn set_base_fee_burn_ratio() {
+        let ratio = Perbill::from_percent(33);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, ratio);
+
+        assert_eq!(BaseFeeBurnRatio::<T>::get(), ratio);
+    }
+
+    #[benchmark]
+    fn force_set_base_fee() {
+        let origin = T::ForceOrigin::try_successful_origin()
+            .expect("Must be able to create the force origin for benchmarking.");
+        let new_bfpg = T::MaxBaseFeePerGas::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_bfpg);
+
+        assert_eq!(BaseFeePerGas::<T>::get(), new_bfpg);
+    }
+
     #[benchmark]
     fn min_gas_price() {
         let first_block = 1u32.into();
@@ -39,6 +39,18 @@
 //!
 //! The implementation doesn't make any hard requirements on these values, and only requires that a type implementing `Get<_>` provides them.
 //!
+//! ## Block Fullness Mode
+//!
+//! As an alternative to the price-based formula above, an `EIP-1559`-style adjustment can be selected via
+//! the [`AdjustmentMode`](`crate::Config::AdjustmentMode`) config item. In this mode, `base fee per gas` is moved
+//! up or down in each block, depending on the gas used in the block compared to the target gas.
+//! If the block used exactly the target gas, `base fee per gas` doesn't change. If the block used twice the target gas
+//! (or more), `base fee per gas` is increased by the max allowed step. Likewise, if the block didn't use any gas,
+//! `base fee per gas` is decreased by the max allowed step. In between, the change is linear.
+//!
+//! It's also possible to blend the two approaches, in which case the ideal `base fee per gas` is calculated as
+//! a weighted average of the price-based and the block fullness based values.
+//!
 //! ## Implementation
 //!
 //! The core logic is implemented in `on_finalize` hook, which is called at the end of each block.
@@ -50,8 +62,24 @@
 //!
 //! Pallet provides an implementation of `FeeCalculator` trait. This makes it usable directly in `pallet-evm`.
 //!
+//! In case of emergency (e.g. congestion), `ForceOrigin` can instantly set the `base_fee_per_gas` value via an _operational_ extrinsic,
+//! without waiting for the value to be adjusted step by step.
+//!
+//! Other components can subscribe to `base fee per gas` changes via the [`OnBaseFeeChanged`] hook,
+//! instead of reading the storage value in each block.
+//!
 //! A _root-only_ extrinsic is provided to allow setting the `base_fee_per_gas` value manually.
 //!
+//! Another _root-only_ extrinsic allows overriding the min & max `base_fee_per_gas` bounds configured in the runtime.
+//! The new bounds are validated so that the lower bound can always be moved away from by the configured step limit.
+//!
+//! ## Base Fee Split
+//!
+//! [`BaseFeeSplitter`] can be used as the handler of the EVM base fee collected by `pallet-evm`.
+//! It burns the `BaseFeeBurnRatio` share of the base fee, and routes the rest to the configured beneficiary handler.
+//! The ratio can be changed via a _root-only_ extrinsic. Burned & distributed amounts are accumulated throughout the block,
+//! and reported via the `BaseFeeCollected` event at the end of the block.
+//!
 //! ## Practical Remarks
 //!
 //! According to the proposed **Tokenomics 2.0**, max amount that adjustment factor will be able to change on live networks in-between blocks is:
@@ -75,9 +103,18 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::weights::Weight;
+use core::marker::PhantomData;
+use frame_support::{
+    pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo},
+    traits::{
+        fungible::{Balanced, Credit},
+        Imbalance, OnUnbalanced,
+    },
+    weights::Weight,
+};
 use sp_core::U256;
-use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber, FixedU128, Perquintill};
+use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber, FixedU128, Perbill, Perquintill};
+use sp_std::vec::Vec;
 
 pub use self::pallet::*;
 
@@ -92,6 +129,69 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Maximum number of blocks for which the 'base fee per gas' can be projected.
+pub const MAX_PROJECTED_BLOCKS: u32 = 1024;
+
+/// Mode used to adjust the `base fee per gas` in-between the blocks.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BaseFeeAdjustmentMode {
+    /// Price-based formula, aligning the EVM fees with the native transaction fees.
+    PriceBased,
+    /// `EIP-1559`-style adjustment, based on the gas used in the block compared to the target gas.
+    BlockFullness,
+    /// Blend of the two approaches, where the ratio is the weight of the block fullness based value.
+    Blended(Perquintill),
+}
+
+/// Hook for subscribers of `base fee per gas` changes.
+pub trait OnBaseFeeChanged {
+    /// Called whenever the `base fee per gas` value changes, either by adjustment or manually.
+    ///
+    /// # Arguments
+    /// * `old` - Previous `base fee per gas` value.
+    /// * `new` - New `base fee per gas` value.
+    fn on_base_fee_changed(old: U256, new: U256);
+
+    /// Upper bound of the weight consumed by the `on_base_fee_changed` call.
+    /// It's accounted for in advance, since the adjustment happens in `on_finalize`.
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl OnBaseFeeChanged for Tuple {
+    fn on_base_fee_changed(old: U256, new: U256) {
+        for_tuples!( #( Tuple::on_base_fee_changed(old, new); )* );
+    }
+
+    fn weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight.saturating_accrue(Tuple::weight()); )* );
+        weight
+    }
+}
+
+/// Lower & upper bounds of the `base fee per gas`.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BaseFeeBounds {
+    /// Minimum value 'base fee per gas' can be adjusted to.
+    pub min: U256,
+    /// Maximum value 'base fee per gas' can be adjusted to.
+    pub max: U256,
+}
+
+/// Base fee collected in the current block.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo,
+)]
+pub struct CollectedBaseFee {
+    /// Amount of base fee burned.
+    pub burned: u128,
+    /// Amount of base fee routed to the beneficiary.
+    pub distributed: u128,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
@@ -124,6 +224,18 @@ pub mod pallet {
         /// It's expressed as percentage, and used to calculate the delta between the old and new value.
         /// E.g. if the current 'base fee per gas' is 100, and the limit is 10%, then the new base fee per gas can be between 90 and 110.
         type StepLimitRatio: Get<Perquintill>;
+        /// Mode used to adjust the 'base fee per gas' in-between the blocks.
+        type AdjustmentMode: Get<BaseFeeAdjustmentMode>;
+        /// Gas used in the current block. Only relevant for the block fullness based adjustment.
+        type BlockGasUsed: Get<U256>;
+        /// Target gas usage of a block. Only relevant for the block fullness based adjustment.
+        type TargetBlockGas: Get<U256>;
+        /// Default share of the collected base fee which is burned. Used if no other value was set by governance.
+        type DefaultBaseFeeBurnRatio: Get<Perbill>;
+        /// Subscribers of the `base fee per gas` changes.
+        type OnBaseFeeChanged: OnBaseFeeChanged;
+        /// Origin which can instantly set the 'base fee per gas' in case of emergency.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         /// Weight information for extrinsics & functions of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -136,65 +248,69 @@ pub mod pallet {
     #[pallet::storage]
     pub type BaseFeePerGas<T> = StorageValue<_, U256, ValueQuery, DefaultBaseFeePerGas<T>>;
 
+    /// Governance override of the `MinBaseFeePerGas` & `MaxBaseFeePerGas` configured in the runtime.
+    #[pallet::storage]
+    pub type BaseFeeBoundsOverride<T> = StorageValue<_, BaseFeeBounds, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultBaseFeeBurnRatio<T: Config>() -> Perbill {
+        T::DefaultBaseFeeBurnRatio::get()
+    }
+
+    /// Share of the collected base fee which is burned. The rest is routed to the beneficiary.
+    #[pallet::storage]
+    pub type BaseFeeBurnRatio<T> = StorageValue<_, Perbill, ValueQuery, DefaultBaseFeeBurnRatio<T>>;
+
+    /// Base fee collected in the current block. Cleared at the end of each block.
+    #[pallet::storage]
+    pub type BlockCollectedBaseFee<T> = StorageValue<_, CollectedBaseFee, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event {
         /// New `base fee per gas` value has been force-set.
         NewBaseFeePerGas { fee: U256 },
+        /// New `base fee per gas` bounds are in effect.
+        NewBaseFeeBounds { min: U256, max: U256 },
+        /// New share of the collected base fee which is burned.
+        NewBaseFeeBurnRatio { ratio: Perbill },
+        /// Base fee collected in the block, split into the burned & distributed amounts.
+        BaseFeeCollected { burned: u128, distributed: u128 },
+        /// `base fee per gas` has been instantly set as an emergency measure.
+        BaseFeeForceSet { old: U256, new: U256 },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Specified value is outside of the allowed range.
         ValueOutOfBounds,
+        /// Minimum is greater than maximum, or maximum exceeds `u128::MAX`.
+        InvalidBaseFeeBounds,
+        /// Step limit applied to the minimum is zero, so 'base fee per gas' would get pinned to it.
+        UnreachableBaseFeeBounds,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(_: BlockNumberFor<T>) -> Weight {
             T::WeightInfo::base_fee_per_gas_adjustment()
+                .saturating_add(T::OnBaseFeeChanged::weight())
         }
 
         fn on_finalize(_n: BlockNumberFor<T>) {
-            BaseFeePerGas::<T>::mutate(|base_fee_per_gas| {
-                let old_bfpg = *base_fee_per_gas;
-
-                // Maximum step we're allowed to move the base fee per gas by.
-                let max_step = {
-                    let old_bfpg_u128: u128 = old_bfpg.unique_saturated_into();
-                    let step = T::StepLimitRatio::get() * old_bfpg_u128;
-                    U256::from(step)
-                };
-
-                // It's possible current base fee per gas is outside of the allowed range.
-                // This can & will happen when this solution is deployed on live networks.
-                //
-                // In such scenario, we will discard the lower & upper bounds configured in the runtime.
-                // Once these bounds are reached ONCE, the runtime logic will prevent them from going out of bounds again.
-                let apply_configured_bounds = old_bfpg >= T::MinBaseFeePerGas::get()
-                    && old_bfpg <= T::MaxBaseFeePerGas::get();
-                let (lower_limit, upper_limit) = if apply_configured_bounds {
-                    (
-                        T::MinBaseFeePerGas::get().max(old_bfpg.saturating_sub(max_step)),
-                        T::MaxBaseFeePerGas::get().min(old_bfpg.saturating_add(max_step)),
-                    )
-                } else {
-                    (
-                        old_bfpg.saturating_sub(max_step),
-                        old_bfpg.saturating_add(max_step),
-                    )
-                };
-
-                // Calculate ideal new 'base_fee_per_gas' according to the formula
-                let ideal_new_bfpg = T::AdjustmentFactor::get()
-                    // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
-                    .saturating_mul_int(T::WeightFactor::get())
-                    .saturating_mul(25)
-                    .saturating_div(98974);
-
-                // Clamp the ideal value in between the allowed limits
-                *base_fee_per_gas = U256::from(ideal_new_bfpg).clamp(lower_limit, upper_limit);
-            })
+            let old_bfpg = BaseFeePerGas::<T>::get();
+            Self::update_base_fee_per_gas(old_bfpg, Self::next_base_fee_per_gas(old_bfpg));
+
+            let CollectedBaseFee {
+                burned,
+                distributed,
+            } = BlockCollectedBaseFee::<T>::take();
+            if burned > 0 || distributed > 0 {
+                Self::deposit_event(Event::BaseFeeCollected {
+                    burned,
+                    distributed,
+                });
+            }
         }
 
         fn integrity_test() {
@@ -214,20 +330,198 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// `root-only` extrinsic to set the `base_fee_per_gas` value manually.
-        /// The specified value has to respect min & max limits configured in the runtime.
+        /// The specified value has to respect the current min & max limits.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::set_base_fee_per_gas())]
+        #[pallet::weight(
+            T::WeightInfo::set_base_fee_per_gas().saturating_add(T::OnBaseFeeChanged::weight())
+        )]
         pub fn set_base_fee_per_gas(origin: OriginFor<T>, fee: U256) -> DispatchResult {
             ensure_root(origin)?;
-            ensure!(
-                fee >= T::MinBaseFeePerGas::get() && fee <= T::MaxBaseFeePerGas::get(),
-                Error::<T>::ValueOutOfBounds
-            );
+            let BaseFeeBounds { min, max } = Self::base_fee_bounds();
+            ensure!(fee >= min && fee <= max, Error::<T>::ValueOutOfBounds);
 
-            BaseFeePerGas::<T>::put(fee);
+            Self::update_base_fee_per_gas(BaseFeePerGas::<T>::get(), fee);
             Self::deposit_event(Event::NewBaseFeePerGas { fee });
             Ok(())
         }
+
+        /// `root-only` extrinsic to override the min & max `base_fee_per_gas` limits configured in the runtime.
+        /// Passing `None` removes the override, and the limits configured in the runtime are used again.
+        ///
+        /// Minimum must not be greater than maximum, and the step limit applied to the minimum must be non-zero.
+        /// Otherwise, once reached, the minimum could never be moved away from.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_base_fee_bounds())]
+        pub fn set_base_fee_bounds(
+            origin: OriginFor<T>,
+            bounds: Option<BaseFeeBounds>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(BaseFeeBounds { min, max }) = bounds {
+                ensure!(
+                    min <= max && max <= U256::from(u128::MAX),
+                    Error::<T>::InvalidBaseFeeBounds
+                );
+
+                let min_u128: u128 = min.unique_saturated_into();
+                ensure!(
+                    T::StepLimitRatio::get() * min_u128 > 0,
+                    Error::<T>::UnreachableBaseFeeBounds
+                );
+            }
+
+            BaseFeeBoundsOverride::<T>::set(bounds);
+
+            let BaseFeeBounds { min, max } = Self::base_fee_bounds();
+            Self::deposit_event(Event::NewBaseFeeBounds { min, max });
+            Ok(())
+        }
+
+        /// `root-only` extrinsic to set the share of the collected base fee which is burned.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_base_fee_burn_ratio())]
+        pub fn set_base_fee_burn_ratio(origin: OriginFor<T>, ratio: Perbill) -> DispatchResult {
+            ensure_root(origin)?;
+
+            BaseFeeBurnRatio::<T>::put(ratio);
+            Self::deposit_event(Event::NewBaseFeeBurnRatio { ratio });
+            Ok(())
+        }
+
+        /// Emergency extrinsic to instantly set the `base_fee_per_gas` value, bypassing the step limit.
+        /// The specified value has to respect the current min & max limits.
+        ///
+        /// Can only be called by the `ForceOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight((
+            T::WeightInfo::force_set_base_fee().saturating_add(T::OnBaseFeeChanged::weight()),
+            DispatchClass::Operational
+        ))]
+        pub fn force_set_base_fee(origin: OriginFor<T>, fee: U256) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let BaseFeeBounds { min, max } = Self::base_fee_bounds();
+            ensure!(fee >= min && fee <= max, Error::<T>::ValueOutOfBounds);
+
+            let old = BaseFeePerGas::<T>::get();
+            Self::update_base_fee_per_gas(old, fee);
+            Self::deposit_event(Event::BaseFeeForceSet { old, new: fee });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Store the new `base_fee_per_gas` value, and notify the subscribers if it has changed.
+        fn update_base_fee_per_gas(old_bfpg: U256, new_bfpg: U256) {
+            BaseFeePerGas::<T>::put(new_bfpg);
+            if old_bfpg != new_bfpg {
+                T::OnBaseFeeChanged::on_base_fee_changed(old_bfpg, new_bfpg);
+            }
+        }
+
+        /// Currently effective min & max `base_fee_per_gas` limits.
+        pub fn base_fee_bounds() -> BaseFeeBounds {
+            BaseFeeBoundsOverride::<T>::get().unwrap_or_else(|| BaseFeeBounds {
+                min: T::MinBaseFeePerGas::get(),
+                max: T::MaxBaseFeePerGas::get(),
+            })
+        }
+
+        /// Projection of the 'base fee per gas' for the next `blocks` blocks (at most `MAX_PROJECTED_BLOCKS`).
+        ///
+        /// Assumes the current adjustment trajectory is kept, i.e. that the adjustment factor
+        /// and the block gas usage remain unchanged.
+        pub fn projected_base_fee_per_gas(blocks: u32) -> Vec<U256> {
+            let mut bfpg = BaseFeePerGas::<T>::get();
+            (0..blocks.min(MAX_PROJECTED_BLOCKS))
+                .map(|_| {
+                    bfpg = Self::next_base_fee_per_gas(bfpg);
+                    bfpg
+                })
+                .collect()
+        }
+
+        /// New 'base fee per gas' value, calculated from the old one according to the configured mode & limits.
+        pub(crate) fn next_base_fee_per_gas(old_bfpg: U256) -> U256 {
+            // Maximum step we're allowed to move the base fee per gas by.
+            let max_step = {
+                let old_bfpg_u128: u128 = old_bfpg.unique_saturated_into();
+                let step = T::StepLimitRatio::get() * old_bfpg_u128;
+                U256::from(step)
+            };
+
+            // It's possible current base fee per gas is outside of the allowed range.
+            // This can & will happen when this solution is deployed on live networks.
+            //
+            // In such scenario, we will discard the lower & upper bounds configured in the runtime.
+            // Once these bounds are reached ONCE, the runtime logic will prevent them from going out of bounds again.
+            let BaseFeeBounds { min, max } = Self::base_fee_bounds();
+            let apply_configured_bounds = old_bfpg >= min && old_bfpg <= max;
+            let (lower_limit, upper_limit) = if apply_configured_bounds {
+                (
+                    min.max(old_bfpg.saturating_sub(max_step)),
+                    max.min(old_bfpg.saturating_add(max_step)),
+                )
+            } else {
+                (
+                    old_bfpg.saturating_sub(max_step),
+                    old_bfpg.saturating_add(max_step),
+                )
+            };
+
+            // Calculate ideal new 'base_fee_per_gas' according to the configured mode
+            let ideal_new_bfpg = match T::AdjustmentMode::get() {
+                BaseFeeAdjustmentMode::PriceBased => Self::price_based_bfpg(),
+                BaseFeeAdjustmentMode::BlockFullness => {
+                    Self::block_fullness_bfpg(old_bfpg, max_step)
+                }
+                BaseFeeAdjustmentMode::Blended(ratio) => {
+                    let price_based: u128 = Self::price_based_bfpg().unique_saturated_into();
+                    let block_fullness: u128 =
+                        Self::block_fullness_bfpg(old_bfpg, max_step).unique_saturated_into();
+                    U256::from(
+                        ratio
+                            .left_from_one()
+                            .mul_floor(price_based)
+                            .saturating_add(ratio.mul_floor(block_fullness)),
+                    )
+                }
+            };
+
+            // Clamp the ideal value in between the allowed limits
+            ideal_new_bfpg.clamp(lower_limit, upper_limit)
+        }
+
+        /// Ideal 'base fee per gas' according to the price-based formula.
+        fn price_based_bfpg() -> U256 {
+            let ideal_bfpg = T::AdjustmentFactor::get()
+                // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
+                .saturating_mul_int(T::WeightFactor::get())
+                .saturating_mul(25)
+                .saturating_div(98974);
+
+            U256::from(ideal_bfpg)
+        }
+
+        /// Ideal 'base fee per gas' according to the block fullness (`EIP-1559`-style).
+        ///
+        /// The change is proportional to the deviation of the used gas from the target gas,
+        /// reaching `max_step` when the block used no gas, or twice the target gas.
+        fn block_fullness_bfpg(old_bfpg: U256, max_step: U256) -> U256 {
+            let target_gas = T::TargetBlockGas::get();
+            if target_gas.is_zero() {
+                return old_bfpg;
+            }
+            let gas_used = T::BlockGasUsed::get();
+
+            if gas_used >= target_gas {
+                let excess_gas = gas_used.saturating_sub(target_gas).min(target_gas);
+                old_bfpg.saturating_add(max_step.saturating_mul(excess_gas) / target_gas)
+            } else {
+                let missing_gas = target_gas.saturating_sub(gas_used);
+                old_bfpg.saturating_sub(max_step.saturating_mul(missing_gas) / target_gas)
+            }
+        }
     }
 }
 
@@ -236,3 +530,33 @@ impl<T: Config> fp_evm::FeeCalculator for Pallet<T> {
         (BaseFeePerGas::<T>::get(), T::WeightInfo::min_gas_price())
     }
 }
+
+/// Handler of the EVM base fee, splitting it between burning and the `Beneficiary` handler.
+///
+/// The share which is burned is determined by the `BaseFeeBurnRatio` storage value.
+pub struct BaseFeeSplitter<T, F, Beneficiary>(PhantomData<(T, F, Beneficiary)>);
+impl<T, F, Beneficiary> OnUnbalanced<Credit<T::AccountId, F>> for BaseFeeSplitter<T, F, Beneficiary>
+where
+    T: Config,
+    F: Balanced<T::AccountId>,
+    Beneficiary: OnUnbalanced<Credit<T::AccountId, F>>,
+{
+    fn on_nonzero_unbalanced(amount: Credit<T::AccountId, F>) {
+        let burn_amount = BaseFeeBurnRatio::<T>::get().mul_floor(amount.peek());
+        let (to_burn, to_distribute) = amount.split(burn_amount);
+
+        BlockCollectedBaseFee::<T>::mutate(|collected| {
+            collected.burned = collected
+                .burned
+                .saturating_add(to_burn.peek().unique_saturated_into());
+            collected.distributed = collected
+                .distributed
+                .saturating_add(to_distribute.peek().unique_saturated_into());
+        });
+
+        // burn part of the base fee
+        drop(to_burn);
+
+        Beneficiary::on_unbalanced(to_distribute);
+    }
+}
@@ -22,8 +22,11 @@ use super::*;
 use crate as pallet_dynamic_evm_base_fee;
 
 use frame_support::{
-    construct_runtime, parameter_types, storage,
-    traits::{ConstU128, ConstU32, ConstU64, Get},
+    construct_runtime, ord_parameter_types, parameter_types, storage,
+    traits::{
+        fungible::{Balanced, Credit},
+        ConstU128, ConstU32, ConstU64, Get, OnUnbalanced,
+    },
     weights::constants::RocksDbWeight,
 };
 use parity_scale_codec::Encode;
@@ -31,12 +34,17 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup, One},
-    BuildStorage, FixedU128, Perquintill,
+    BuildStorage, FixedU128, Perbill, Perquintill,
 };
 
 pub(crate) type AccountId = u128;
 pub(crate) type Balance = u128;
 
+/// Account receiving the part of the base fee which isn't burned.
+pub(crate) const BENEFICIARY: AccountId = 0xBEEF;
+/// Account which can force set the base fee.
+pub(crate) const EMERGENCY_ACCOUNT: AccountId = 0x911;
+
 parameter_types! {
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
@@ -102,6 +110,12 @@ parameter_types! {
     pub MinBaseFeePerGas: U256 = U256::from(800_000_000_000_u128);
     pub MaxBaseFeePerGas: U256 = U256::from(80_000_000_000_000_u128);
     pub StepLimitRation: Perquintill = Perquintill::from_rational(30_u128, 1_000_000);
+    pub TargetBlockGas: U256 = U256::from(1_000_000_u128);
+    pub DefaultBaseFeeBurnRatio: Perbill = Perbill::from_percent(80);
+}
+
+ord_parameter_types! {
+    pub const EmergencyAccount: AccountId = EMERGENCY_ACCOUNT;
 }
 
 impl pallet_dynamic_evm_base_fee::Config for TestRuntime {
@@ -112,6 +126,12 @@ impl pallet_dynamic_evm_base_fee::Config for TestRuntime {
     type AdjustmentFactor = GetAdjustmentFactor;
     type WeightFactor = ConstU128<30_000_000_000_000_000>;
     type StepLimitRatio = StepLimitRation;
+    type AdjustmentMode = GetAdjustmentMode;
+    type BlockGasUsed = GetBlockGasUsed;
+    type TargetBlockGas = TargetBlockGas;
+    type DefaultBaseFeeBurnRatio = DefaultBaseFeeBurnRatio;
+    type OnBaseFeeChanged = RecordBaseFeeChange;
+    type ForceOrigin = frame_system::EnsureSignedBy<EmergencyAccount, AccountId>;
     type WeightInfo = ();
 }
 
@@ -140,6 +160,62 @@ impl Get<FixedU128> for GetAdjustmentFactor {
     }
 }
 
+const ADJUSTMENT_MODE: &[u8] = b":adj_mode_evm";
+
+/// Helper method to set the adjustment mode used by the pallet.
+pub fn set_adjustment_mode(mode: BaseFeeAdjustmentMode) {
+    storage::unhashed::put_raw(&ADJUSTMENT_MODE, &mode.encode());
+}
+
+pub struct GetAdjustmentMode;
+impl Get<BaseFeeAdjustmentMode> for GetAdjustmentMode {
+    fn get() -> BaseFeeAdjustmentMode {
+        storage::unhashed::get::<BaseFeeAdjustmentMode>(&ADJUSTMENT_MODE)
+            .unwrap_or(BaseFeeAdjustmentMode::PriceBased)
+    }
+}
+
+const BLOCK_GAS_USED: &[u8] = b":block_gas_used_evm";
+
+/// Helper method to set the gas used in the current block.
+pub fn set_block_gas_used(gas_used: U256) {
+    storage::unhashed::put_raw(&BLOCK_GAS_USED, &gas_used.encode());
+}
+
+pub struct GetBlockGasUsed;
+impl Get<U256> for GetBlockGasUsed {
+    fn get() -> U256 {
+        storage::unhashed::get::<U256>(&BLOCK_GAS_USED).unwrap_or_default()
+    }
+}
+
+const BASE_FEE_CHANGES: &[u8] = b":base_fee_changes_evm";
+
+/// Base fee changes reported to the subscriber, in the order they happened.
+pub fn base_fee_changes() -> Vec<(U256, U256)> {
+    storage::unhashed::get::<Vec<(U256, U256)>>(&BASE_FEE_CHANGES).unwrap_or_default()
+}
+
+pub struct RecordBaseFeeChange;
+impl OnBaseFeeChanged for RecordBaseFeeChange {
+    fn on_base_fee_changed(old: U256, new: U256) {
+        let mut changes = base_fee_changes();
+        changes.push((old, new));
+        storage::unhashed::put(&BASE_FEE_CHANGES, &changes);
+    }
+}
+
+/// Deposits the received base fee into the `BENEFICIARY` account.
+pub struct ToBeneficiary;
+impl OnUnbalanced<Credit<AccountId, Balances>> for ToBeneficiary {
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+        let _ = Balances::resolve(&BENEFICIARY, amount);
+    }
+}
+
+/// Base fee handler used in tests.
+pub type TestBaseFeeSplitter = BaseFeeSplitter<TestRuntime, Balances, ToBeneficiary>;
+
 pub struct ExtBuilder;
 impl ExtBuilder {
     pub fn build() -> TestExternalities {
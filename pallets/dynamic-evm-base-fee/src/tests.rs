@@ -23,12 +23,12 @@ use mock::*;
 
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{Get, OnFinalize},
+    traits::{fungible::Balanced, Get, OnFinalize, OnUnbalanced},
 };
 use num_traits::Bounded;
 use sp_runtime::{
     traits::{BadOrigin, One, Zero},
-    FixedU128,
+    FixedU128, Perbill,
 };
 
 use fp_evm::FeeCalculator;
@@ -323,3 +323,370 @@ fn lower_upper_bounds_ignored_if_bfpg_is_outside() {
         );
     });
 }
+
+#[test]
+fn block_fullness_mode_follows_gas_usage() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::BlockFullness);
+        let target_gas = <TestRuntime as pallet::Config>::TargetBlockGas::get();
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+
+        // Block used exactly the target gas, no change expected
+        set_block_gas_used(target_gas);
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg);
+
+        // Block used twice the target gas (or more), max step increase expected
+        for gas_used in [target_gas * 2, target_gas * 10] {
+            let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            let step_limit = get_max_step_limit();
+            set_block_gas_used(gas_used);
+            DynamicEvmBaseFee::on_finalize(2);
+            assert_eq!(BaseFeePerGas::<TestRuntime>::get(), old_bfpg + step_limit);
+        }
+
+        // Block used half of the excess gas, half of the max step increase expected
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_block_gas_used(target_gas * 3 / 2);
+        DynamicEvmBaseFee::on_finalize(3);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            old_bfpg + step_limit / 2
+        );
+
+        // Empty block, max step decrease expected
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_block_gas_used(U256::zero());
+        DynamicEvmBaseFee::on_finalize(4);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), old_bfpg - step_limit);
+    });
+}
+
+#[test]
+fn block_fullness_mode_respects_bounds() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_mode(BaseFeeAdjustmentMode::BlockFullness);
+
+        // Empty blocks shouldn't push the bfpg below the lower bound
+        let min_bfpg = <TestRuntime as pallet::Config>::MinBaseFeePerGas::get();
+        BaseFeePerGas::<TestRuntime>::set(min_bfpg);
+        set_block_gas_used(U256::zero());
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), min_bfpg);
+
+        // Full blocks shouldn't push the bfpg above the upper bound
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+        BaseFeePerGas::<TestRuntime>::set(max_bfpg);
+        set_block_gas_used(<TestRuntime as pallet::Config>::TargetBlockGas::get() * 2);
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), max_bfpg);
+    });
+}
+
+#[test]
+fn blended_mode_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Move bfpg away from the price-based ideal, so both approaches would move it upwards
+        let ideal_bfpg = get_ideal_bfpg();
+        let init_bfpg = ideal_bfpg / 2;
+        BaseFeePerGas::<TestRuntime>::set(init_bfpg);
+        let step_limit = get_max_step_limit();
+
+        // Block fullness based value is at max step, price-based value is far above it
+        set_block_gas_used(<TestRuntime as pallet::Config>::TargetBlockGas::get() * 2);
+        set_adjustment_mode(BaseFeeAdjustmentMode::Blended(Perquintill::from_percent(
+            50,
+        )));
+        DynamicEvmBaseFee::on_finalize(1);
+
+        // Blended value is clamped by the step limit
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), init_bfpg + step_limit);
+
+        // Zero block fullness share is equivalent to the price-based mode
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_adjustment_mode(BaseFeeAdjustmentMode::Blended(Perquintill::zero()));
+        set_block_gas_used(U256::zero());
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            ideal_bfpg.min(old_bfpg + step_limit)
+        );
+    });
+}
+
+#[test]
+fn set_base_fee_bounds_works() {
+    ExtBuilder::build().execute_with(|| {
+        let min_bfpg = <TestRuntime as pallet::Config>::MinBaseFeePerGas::get();
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+        let bounds = BaseFeeBounds {
+            min: min_bfpg * 2,
+            max: max_bfpg / 2,
+        };
+
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_bounds(
+            RuntimeOrigin::root(),
+            Some(bounds)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::NewBaseFeeBounds {
+                min: bounds.min,
+                max: bounds.max,
+            },
+        ));
+        assert_eq!(DynamicEvmBaseFee::base_fee_bounds(), bounds);
+
+        // Overridden bounds are enforced when setting the bfpg manually
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_per_gas(RuntimeOrigin::root(), min_bfpg),
+            Error::<TestRuntime>::ValueOutOfBounds
+        );
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            bounds.min
+        ));
+
+        // Overridden bounds are enforced during adjustment
+        set_adjustment_factor(FixedU128::zero());
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), bounds.min);
+
+        // Removing the override restores the runtime configured bounds
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_bounds(
+            RuntimeOrigin::root(),
+            None
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::NewBaseFeeBounds {
+                min: min_bfpg,
+                max: max_bfpg,
+            },
+        ));
+        assert_eq!(
+            DynamicEvmBaseFee::base_fee_bounds(),
+            BaseFeeBounds {
+                min: min_bfpg,
+                max: max_bfpg
+            }
+        );
+    });
+}
+
+#[test]
+fn set_base_fee_bounds_invalid_values_fail() {
+    ExtBuilder::build().execute_with(|| {
+        let min_bfpg = <TestRuntime as pallet::Config>::MinBaseFeePerGas::get();
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_bounds(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+
+        // Min greater than max
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_bounds(
+                RuntimeOrigin::root(),
+                Some(BaseFeeBounds {
+                    min: max_bfpg,
+                    max: min_bfpg,
+                })
+            ),
+            Error::<TestRuntime>::InvalidBaseFeeBounds
+        );
+
+        // Max which would cause precision loss
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_bounds(
+                RuntimeOrigin::root(),
+                Some(BaseFeeBounds {
+                    min: min_bfpg,
+                    max: U256::from(u128::MAX) + 1,
+                })
+            ),
+            Error::<TestRuntime>::InvalidBaseFeeBounds
+        );
+
+        // Min so small that the step limit cannot move bfpg away from it
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_bounds(
+                RuntimeOrigin::root(),
+                Some(BaseFeeBounds {
+                    min: U256::from(10_000),
+                    max: max_bfpg,
+                })
+            ),
+            Error::<TestRuntime>::UnreachableBaseFeeBounds
+        );
+    });
+}
+
+#[test]
+fn projected_base_fee_per_gas_follows_adjustment() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_factor(FixedU128::zero());
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+
+        // Projection matches the values produced by the adjustment logic
+        let projection = DynamicEvmBaseFee::projected_base_fee_per_gas(5);
+        assert_eq!(projection.len(), 5);
+        for (block, projected_bfpg) in projection.into_iter().enumerate() {
+            DynamicEvmBaseFee::on_finalize(block as u32 + 1);
+            assert_eq!(BaseFeePerGas::<TestRuntime>::get(), projected_bfpg);
+        }
+        assert!(BaseFeePerGas::<TestRuntime>::get() < init_bfpg);
+
+        // Number of projected blocks is capped
+        assert_eq!(
+            DynamicEvmBaseFee::projected_base_fee_per_gas(u32::MAX).len(),
+            MAX_PROJECTED_BLOCKS as usize
+        );
+        assert!(DynamicEvmBaseFee::projected_base_fee_per_gas(0).is_empty());
+    });
+}
+
+#[test]
+fn set_base_fee_burn_ratio_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            BaseFeeBurnRatio::<TestRuntime>::get(),
+            <TestRuntime as pallet::Config>::DefaultBaseFeeBurnRatio::get()
+        );
+
+        let ratio = Perbill::from_percent(25);
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_burn_ratio(
+            RuntimeOrigin::root(),
+            ratio
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::NewBaseFeeBurnRatio { ratio },
+        ));
+        assert_eq!(BaseFeeBurnRatio::<TestRuntime>::get(), ratio);
+
+        assert_noop!(
+            DynamicEvmBaseFee::set_base_fee_burn_ratio(RuntimeOrigin::signed(1), ratio),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn base_fee_is_split_between_burn_and_beneficiary() {
+    ExtBuilder::build().execute_with(|| {
+        let init_issuance = pallet_balances::TotalIssuance::<TestRuntime>::get();
+
+        // Default ratio burns 80% of the base fee
+        TestBaseFeeSplitter::on_unbalanced(Balances::issue(1_000));
+        assert_eq!(Balances::free_balance(BENEFICIARY), 200);
+
+        // Changed ratio applies to the subsequent base fees
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_burn_ratio(
+            RuntimeOrigin::root(),
+            Perbill::from_percent(50)
+        ));
+        TestBaseFeeSplitter::on_unbalanced(Balances::issue(1_000));
+        assert_eq!(Balances::free_balance(BENEFICIARY), 700);
+        assert_eq!(
+            pallet_balances::TotalIssuance::<TestRuntime>::get(),
+            init_issuance + 700
+        );
+
+        // Amounts are accumulated during the block, and reported at its end
+        assert_eq!(
+            BlockCollectedBaseFee::<TestRuntime>::get(),
+            CollectedBaseFee {
+                burned: 1_300,
+                distributed: 700,
+            }
+        );
+        DynamicEvmBaseFee::on_finalize(1);
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::BaseFeeCollected {
+                burned: 1_300,
+                distributed: 700,
+            },
+        ));
+        assert_eq!(
+            BlockCollectedBaseFee::<TestRuntime>::get(),
+            CollectedBaseFee::default()
+        );
+    });
+}
+
+#[test]
+fn base_fee_changes_are_reported_to_subscribers() {
+    ExtBuilder::build().execute_with(|| {
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+
+        // Unchanged value isn't reported
+        set_adjustment_factor(FixedU128::one());
+        BaseFeePerGas::<TestRuntime>::set(get_ideal_bfpg());
+        DynamicEvmBaseFee::on_finalize(1);
+        assert!(base_fee_changes().is_empty());
+
+        // Adjustment is reported
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        set_adjustment_factor(FixedU128::from(2));
+        DynamicEvmBaseFee::on_finalize(2);
+        let new_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        assert!(new_bfpg > old_bfpg);
+        assert_eq!(base_fee_changes(), vec![(old_bfpg, new_bfpg)]);
+
+        // Manually set value is reported
+        assert_ok!(DynamicEvmBaseFee::set_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            init_bfpg
+        ));
+        assert_eq!(
+            base_fee_changes(),
+            vec![(old_bfpg, new_bfpg), (new_bfpg, init_bfpg)]
+        );
+    });
+}
+
+#[test]
+fn force_set_base_fee_works() {
+    ExtBuilder::build().execute_with(|| {
+        let old_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let new_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+        assert!(
+            new_bfpg > old_bfpg + get_max_step_limit(),
+            "Sanity check, change must exceed the step limit."
+        );
+
+        assert_ok!(DynamicEvmBaseFee::force_set_base_fee(
+            RuntimeOrigin::signed(EMERGENCY_ACCOUNT),
+            new_bfpg
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DynamicEvmBaseFee(
+            Event::BaseFeeForceSet {
+                old: old_bfpg,
+                new: new_bfpg,
+            },
+        ));
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), new_bfpg);
+        assert_eq!(base_fee_changes(), vec![(old_bfpg, new_bfpg)]);
+    });
+}
+
+#[test]
+fn force_set_base_fee_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+
+        assert_noop!(
+            DynamicEvmBaseFee::force_set_base_fee(RuntimeOrigin::signed(1), max_bfpg),
+            BadOrigin
+        );
+        assert_noop!(
+            DynamicEvmBaseFee::force_set_base_fee(
+                RuntimeOrigin::signed(EMERGENCY_ACCOUNT),
+                max_bfpg + 1
+            ),
+            Error::<TestRuntime>::ValueOutOfBounds
+        );
+    });
+}
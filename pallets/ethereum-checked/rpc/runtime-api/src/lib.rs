@@ -0,0 +1,33 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_core::U256;
+
+sp_api::decl_runtime_apis! {
+
+    /// Ethereum Checked Api.
+    ///
+    /// Used by the remote callers to correlate & retry their checked Ethereum transactions.
+    pub trait EthereumCheckedApi<AccountId> where AccountId: Codec {
+        /// Current checked tx nonce of the account, expected by its next pinned transaction.
+        fn nonce(account: AccountId) -> U256;
+    }
+}
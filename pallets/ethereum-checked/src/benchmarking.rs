@@ -39,10 +39,12 @@ mod benchmarks {
         .unwrap();
         let checked_tx = CheckedEthereumTx {
             gas_limit: U256::from(1_000_000),
-            target,
+            action: CheckedEthereumTxAction::Call(target),
             value: U256::zero(),
             input,
             maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
         };
 
         #[block]
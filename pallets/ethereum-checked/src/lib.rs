@@ -21,8 +21,8 @@
 //! ## Overview
 //!
 //! A `pallet-ethereum like pallet that execute transactions from checked source,
-//! like XCM remote call. Only `Call` transactions are supported
-//! (no `Create`).
+//! like XCM remote call. Both `Call` and `Create` transactions are supported.
+//! Created contract address is reported via the standard `pallet_ethereum::Event::Executed`.
 //!
 //! The checked source guarantees that transactions are valid with prior checks, so these
 //! transactions are not required to include valid signatures. Instead, `pallet-ethereum-checked`
@@ -36,6 +36,11 @@
 //! - `transact`: transact an Ethereum transaction. Similar to `pallet_ethereum::Transact`,
 //! but is only for XCM remote call.
 //!
+//! ### Other
+//!
+//! - `chain_extension_transact`: transact an Ethereum transaction on behalf of a WASM
+//! contract, via its chain extension.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -45,10 +50,10 @@ use scale_info::TypeInfo;
 use ethereum_types::{H160, U256};
 use fp_ethereum::{TransactionData, ValidatedTransaction};
 use fp_evm::{
-    CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitReason,
-    ExitSucceed, TransactionValidationError,
+    CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, CreateInfo,
+    ExitReason, ExitSucceed, TransactionValidationError,
 };
-use pallet_evm::GasWeightMapping;
+use pallet_evm::{FeeCalculator, GasWeightMapping};
 
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
@@ -58,9 +63,12 @@ use frame_system::pallet_prelude::*;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_runtime::traits::TrailingZeroInput;
 use sp_runtime::traits::UniqueSaturatedInto;
-use sp_std::{marker::PhantomData, result::Result};
+use sp_std::{marker::PhantomData, prelude::*, result::Result};
 
-use astar_primitives::{ethereum_checked::CheckedEthereumTx, evm::UnifiedAddressMapper};
+use astar_primitives::{
+    ethereum_checked::{CheckedEthereumTx, CheckedEthereumTxAction},
+    evm::UnifiedAddressMapper,
+};
 
 pub use pallet::*;
 
@@ -77,6 +85,9 @@ mod tests;
 
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 
+/// Max size of the revert data kept in the failure event: 1024 bytes
+pub const MAX_REVERT_DATA_SIZE: u32 = 1024;
+
 /// Origin for dispatch-able calls.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum RawOrigin<AccountId> {
@@ -109,6 +120,8 @@ impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>, Acco
 pub enum CheckedEthereumTxKind {
     /// The tx is from XCM remote call.
     Xcm,
+    /// The tx is from WASM contract chain extension.
+    ChainExtension,
 }
 
 #[frame_support::pallet]
@@ -120,9 +133,16 @@ pub mod pallet {
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_evm::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
         /// Reserved Xcmp weight for block gas limit calculation.
         type ReservedXcmpWeight: Get<Weight>;
 
+        /// Weight limit of a single tx from the WASM contracts chain extension,
+        /// used for its block gas limit calculation.
+        type ChainExtensionTxWeightLimit: Get<Weight>;
+
         /// Invalid tx error.
         type InvalidEvmTransactionError: From<TransactionValidationError>;
 
@@ -142,11 +162,29 @@ pub mod pallet {
     #[pallet::origin]
     pub type Origin<T> = RawOrigin<<T as frame_system::Config>::AccountId>;
 
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// XCM remote call to EVM didn't succeed. Revert data is truncated to
+        /// `MAX_REVERT_DATA_SIZE` bytes.
+        XcmTransactFailed {
+            source: T::AccountId,
+            nonce: U256,
+            exit_reason: ExitReason,
+            revert_data: BoundedVec<u8, ConstU32<MAX_REVERT_DATA_SIZE>>,
+        },
+    }
+
     /// Global nonce for all transactions to avoid hash collision, which is
     /// caused by the same dummy signatures for all transactions.
     #[pallet::storage]
     pub type Nonce<T: Config> = StorageValue<_, U256, ValueQuery>;
 
+    /// Checked tx nonce of each source address, i.e. the number of its executed
+    /// checked transactions. Can be pinned by the transaction to avoid duplicated execution.
+    #[pallet::storage]
+    pub type SourceNonce<T: Config> = StorageMap<_, Blake2_128Concat, H160, U256, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Transact an Ethereum transaction. Similar to `pallet_ethereum::Transact`,
@@ -158,27 +196,48 @@ pub mod pallet {
         })]
         pub fn transact(origin: OriginFor<T>, tx: CheckedEthereumTx) -> DispatchResultWithPostInfo {
             let source = T::XcmTransactOrigin::ensure_origin(origin)?;
-            Self::do_transact(
-                T::AddressMapper::to_h160_or_default(&source).into_address(),
-                tx.into(),
-                CheckedEthereumTxKind::Xcm,
-                false,
-            )
-            .map(|(post_info, _)| post_info)
+            let source_address = T::AddressMapper::to_h160_or_default(&source).into_address();
+            let nonce = SourceNonce::<T>::get(source_address);
+
+            let (post_info, info) =
+                Self::do_transact(source_address, tx, CheckedEthereumTxKind::Xcm, false)?;
+
+            let (exit_reason, revert_data) = match info {
+                CallOrCreateInfo::Call(info) => (info.exit_reason, info.value),
+                CallOrCreateInfo::Create(info) => (info.exit_reason, Vec::new()),
+            };
+            if !matches!(exit_reason, ExitReason::Succeed(_)) {
+                Self::deposit_event(Event::<T>::XcmTransactFailed {
+                    source,
+                    nonce,
+                    exit_reason,
+                    revert_data: BoundedVec::truncate_from(revert_data),
+                });
+            }
+
+            Ok(post_info)
         }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    /// Validate and execute the checked tx. Only `Call` transaction action is allowed.
+    /// Validate and execute the checked tx.
     fn do_transact(
         source: H160,
         checked_tx: CheckedEthereumTx,
         tx_kind: CheckedEthereumTxKind,
         skip_apply: bool,
-    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
+    ) -> Result<(PostDispatchInfo, CallOrCreateInfo), DispatchErrorWithPostInfo> {
         let chain_id = T::ChainId::get();
         let nonce = Nonce::<T>::get();
+        let source_nonce = SourceNonce::<T>::get(source);
+        // Fee-less txs are paid by the checked source, only the dynamic fee txs are
+        // checked against the current base fee.
+        let base_fee = if checked_tx.maybe_fee.is_some() {
+            T::FeeCalculator::min_gas_price().0
+        } else {
+            U256::zero()
+        };
         let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), chain_id);
         let tx_data: TransactionData = (&tx).into();
 
@@ -200,7 +259,7 @@ impl<T: Config> Pallet<T> {
             CheckEvmTransactionConfig {
                 evm_config: T::config(),
                 block_gas_limit: U256::from(Self::block_gas_limit(&tx_kind)),
-                base_fee: U256::zero(),
+                base_fee,
                 chain_id,
                 is_transactional: true,
             },
@@ -208,60 +267,101 @@ impl<T: Config> Pallet<T> {
             weight_limit,
             proof_size_base_cost,
         )
-        // Gas limit validation. The fee payment has been validated as the tx is `checked`.
+        // Gas limit & base fee validation. The fee payment has been validated as the tx is `checked`.
         .validate_common()
-        .map_err(|_| DispatchErrorWithPostInfo {
-            post_info: PostDispatchInfo {
-                // actual_weight = overhead - nonce_write_1
-                actual_weight: Some(
-                    WeightInfoOf::<T>::transact_without_apply()
-                        .saturating_sub(T::DbWeight::get().writes(1)),
-                ),
-                pays_fee: Pays::Yes,
-            },
-            error: DispatchError::Other("Failed to validate Ethereum tx"),
-        })?;
+        .and_then(|v| v.with_base_fee())
+        .map_err(|_| Self::validation_error("Failed to validate Ethereum tx"))?;
+
+        // Pinned nonce validation.
+        if checked_tx
+            .maybe_nonce
+            .is_some_and(|expected| expected != source_nonce)
+        {
+            return Err(Self::validation_error("Invalid checked tx nonce"));
+        }
 
         Nonce::<T>::put(nonce.saturating_add(U256::one()));
+        SourceNonce::<T>::insert(source, source_nonce.saturating_add(U256::one()));
 
         if skip_apply {
+            let used_gas = fp_evm::UsedGas {
+                standard: checked_tx.gas_limit,
+                effective: checked_tx.gas_limit,
+            };
+            let exit_reason = ExitReason::Succeed(ExitSucceed::Returned);
+            let info = match checked_tx.action {
+                CheckedEthereumTxAction::Call(_) => CallOrCreateInfo::Call(CallInfo {
+                    exit_reason,
+                    value: Default::default(),
+                    used_gas,
+                    weight_info: None,
+                    logs: Default::default(),
+                }),
+                CheckedEthereumTxAction::Create => CallOrCreateInfo::Create(CreateInfo {
+                    exit_reason,
+                    value: Default::default(),
+                    used_gas,
+                    weight_info: None,
+                    logs: Default::default(),
+                }),
+            };
             return Ok((
                 PostDispatchInfo {
                     actual_weight: Some(WeightInfoOf::<T>::transact_without_apply()),
                     pays_fee: Pays::Yes,
                 },
-                CallInfo {
-                    exit_reason: ExitReason::Succeed(ExitSucceed::Returned),
-                    value: Default::default(),
-                    used_gas: fp_evm::UsedGas {
-                        standard: checked_tx.gas_limit,
-                        effective: checked_tx.gas_limit,
-                    },
-                    weight_info: None,
-                    logs: Default::default(),
-                },
+                info,
             ));
         }
 
         // Execute the tx.
-        let (post_info, apply_info) = T::ValidatedTransaction::apply(source, tx)?;
-        match apply_info {
-            CallOrCreateInfo::Call(info) => Ok((post_info, info)),
-            // It is not possible to have a `Create` transaction via `CheckedEthereumTx`.
-            CallOrCreateInfo::Create(_) => {
-                unreachable!("Cannot create a 'Create' transaction; qed")
-            }
+        T::ValidatedTransaction::apply(source, tx)
+    }
+
+    /// Error of the tx which failed validation, before any write.
+    fn validation_error(message: &'static str) -> DispatchErrorWithPostInfo {
+        DispatchErrorWithPostInfo {
+            post_info: PostDispatchInfo {
+                // actual_weight = overhead - nonce_writes_2
+                actual_weight: Some(
+                    WeightInfoOf::<T>::transact_without_apply()
+                        .saturating_sub(T::DbWeight::get().writes(2)),
+                ),
+                pays_fee: Pays::Yes,
+            },
+            error: DispatchError::Other(message),
         }
     }
 
+    /// Current checked tx nonce of the `account`, i.e. of its unified (or default) address.
+    pub fn account_nonce(account: &T::AccountId) -> U256 {
+        SourceNonce::<T>::get(T::AddressMapper::to_h160_or_default(account).into_address())
+    }
+
     /// Block gas limit calculation based on the tx kind.
     fn block_gas_limit(tx_kind: &CheckedEthereumTxKind) -> u64 {
         let weight_limit = match tx_kind {
             CheckedEthereumTxKind::Xcm => T::ReservedXcmpWeight::get(),
+            CheckedEthereumTxKind::ChainExtension => T::ChainExtensionTxWeightLimit::get(),
         };
         T::GasWeightMapping::weight_to_gas(weight_limit)
     }
 
+    /// Transact an Ethereum transaction from `source` on behalf of a WASM contract.
+    /// Returns the post dispatch info & the EVM call or create info, including the return
+    /// or revert data.
+    pub fn chain_extension_transact(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallOrCreateInfo), DispatchErrorWithPostInfo> {
+        Self::do_transact(
+            source,
+            checked_tx,
+            CheckedEthereumTxKind::ChainExtension,
+            false,
+        )
+    }
+
     /// Similar to `transact` dispatch-able call that transacts an Ethereum transaction,
     /// but not to apply it. This is to benchmark the weight overhead in addition to `gas_limit`.
     #[cfg(feature = "runtime-benchmarks")]
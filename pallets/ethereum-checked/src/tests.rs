@@ -21,7 +21,9 @@
 use super::*;
 use mock::*;
 
-use astar_primitives::ethereum_checked::EthereumTxInput;
+use astar_primitives::ethereum_checked::{
+    CheckedEthereumTxAction, CheckedEthereumTxFee, EthereumTxInput,
+};
 use ethereum::{ReceiptV3 as Receipt, TransactionV2 as Transaction};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
@@ -36,13 +38,15 @@ fn transact_works() {
     ExtBuilder::default().build().execute_with(|| {
         let store_tx = CheckedEthereumTx {
             gas_limit: U256::from(1_000_000),
-            target: contract_address(),
+            action: CheckedEthereumTxAction::Call(contract_address()),
             value: U256::zero(),
             // Calling `store(3)`
             input: bounded_input(
                 "6057361d0000000000000000000000000000000000000000000000000000000000000003",
             ),
             maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
         };
         assert_ok!(EthereumChecked::transact(
             RawOrigin::XcmEthereumTx(ALICE).into(),
@@ -80,13 +84,15 @@ fn origin_check_works() {
     ExtBuilder::default().build().execute_with(|| {
         let store_tx = CheckedEthereumTx {
             gas_limit: U256::from(1_000_000),
-            target: contract_address(),
+            action: CheckedEthereumTxAction::Call(contract_address()),
             value: U256::zero(),
             // Calling `store(3)`
             input: bounded_input(
                 "6057361d0000000000000000000000000000000000000000000000000000000000000003",
             ),
             maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
         };
         assert_noop!(
             EthereumChecked::transact(RuntimeOrigin::signed(ALICE), store_tx.clone()),
@@ -108,13 +114,15 @@ fn no_hash_collision() {
     ExtBuilder::default().build().execute_with(|| {
         let store_tx = CheckedEthereumTx {
             gas_limit: U256::from(1_000_000),
-            target: contract_address(),
+            action: CheckedEthereumTxAction::Call(contract_address()),
             value: U256::zero(),
             // Calling `store(3)`
             input: bounded_input(
                 "6057361d0000000000000000000000000000000000000000000000000000000000000003",
             ),
             maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
         };
         for _ in 0..5 {
             assert_ok!(EthereumChecked::transact(
@@ -131,3 +139,204 @@ fn no_hash_collision() {
         assert_eq!(tx_hashes.len(), 5);
     });
 }
+
+#[test]
+fn chain_extension_transact_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Call(contract_address()),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
+        };
+        let (_, call_info) =
+            EthereumChecked::chain_extension_transact(H160::repeat_byte(0x01), store_tx)
+                .expect("transact should succeed");
+        assert!(matches!(
+            call_info,
+            CallOrCreateInfo::Call(CallInfo {
+                exit_reason: ExitReason::Succeed(_),
+                ..
+            })
+        ));
+        assert_eq!(pallet_ethereum::Pending::<TestRuntime>::get().len(), 1);
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::one());
+    });
+}
+
+#[test]
+fn dynamic_fee_transact_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Call(contract_address()),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+            maybe_fee: Some(CheckedEthereumTxFee {
+                max_fee_per_gas: U256::from(2),
+                max_priority_fee_per_gas: U256::one(),
+            }),
+            maybe_nonce: None,
+        };
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            store_tx
+        ));
+
+        match pallet_ethereum::Pending::<TestRuntime>::get()[0] {
+            (Transaction::EIP1559(ref t), _, Receipt::EIP1559(ref r)) => {
+                assert_eq!(t.max_fee_per_gas, U256::from(2));
+                assert_eq!(t.max_priority_fee_per_gas, U256::one());
+                assert_eq!(r.status_code, 1);
+            }
+            _ => panic!("unexpected transaction type"),
+        }
+    });
+}
+
+#[test]
+fn dynamic_fee_below_base_fee_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Call(contract_address()),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+            // base fee is 1 in mock
+            maybe_fee: Some(CheckedEthereumTxFee {
+                max_fee_per_gas: U256::zero(),
+                max_priority_fee_per_gas: U256::zero(),
+            }),
+            maybe_nonce: None,
+        };
+        assert_eq!(
+            EthereumChecked::transact(RawOrigin::XcmEthereumTx(ALICE).into(), store_tx)
+                .map_err(|e| e.error),
+            Err(DispatchError::Other("Failed to validate Ethereum tx"))
+        );
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::zero());
+    });
+}
+
+#[test]
+fn create_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let create_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Create,
+            value: U256::zero(),
+            input: EthereumTxInput::try_from(
+                hex::decode(STORAGE_CONTRACT).expect("invalid code hex"),
+            )
+            .expect("input too large"),
+            maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
+        };
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            create_tx
+        ));
+
+        match pallet_ethereum::Pending::<TestRuntime>::get()[0] {
+            (Transaction::EIP1559(ref t), _, Receipt::EIP1559(ref r)) => {
+                assert_eq!(t.action, ethereum::TransactionAction::Create);
+                assert_eq!(r.status_code, 1);
+            }
+            _ => panic!("unexpected transaction type"),
+        }
+
+        // the storage contract created at genesis is the first one
+        let created = System::events()
+            .into_iter()
+            .rev()
+            .find_map(|record| match record.event {
+                RuntimeEvent::Evm(pallet_evm::Event::Created { address }) => Some(address),
+                _ => None,
+            })
+            .expect("contract should be created");
+        assert_ne!(created, contract_address());
+        assert!(!pallet_evm::AccountCodes::<TestRuntime>::get(created).is_empty());
+    });
+}
+
+#[test]
+fn pinned_nonce_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Call(contract_address()),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: Some(U256::zero()),
+        };
+        assert_eq!(EthereumChecked::account_nonce(&ALICE), U256::zero());
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            store_tx.clone()
+        ));
+        assert_eq!(EthereumChecked::account_nonce(&ALICE), U256::one());
+        assert_eq!(SourceNonce::<TestRuntime>::get(ALICE_H160), U256::one());
+
+        // retrying the same tx is rejected
+        assert_eq!(
+            EthereumChecked::transact(RawOrigin::XcmEthereumTx(ALICE).into(), store_tx.clone())
+                .map_err(|e| e.error),
+            Err(DispatchError::Other("Invalid checked tx nonce"))
+        );
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::one());
+
+        // source nonces are independent
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(BOB).into(),
+            store_tx
+        ));
+        assert_eq!(EthereumChecked::account_nonce(&BOB), U256::one());
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::from(2));
+    });
+}
+
+#[test]
+fn failed_transact_emits_event() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unknown_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            action: CheckedEthereumTxAction::Call(contract_address()),
+            value: U256::zero(),
+            // Calling unknown selector, no fallback
+            input: bounded_input("deadbeef"),
+            maybe_access_list: None,
+            maybe_fee: None,
+            maybe_nonce: None,
+        };
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            unknown_tx
+        ));
+        System::assert_last_event(RuntimeEvent::EthereumChecked(Event::XcmTransactFailed {
+            source: ALICE,
+            nonce: U256::zero(),
+            exit_reason: ExitReason::Revert(fp_evm::ExitRevert::Reverted),
+            revert_data: Default::default(),
+        }));
+    });
+}
@@ -58,14 +58,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: EthereumChecked Nonce (r:1 w:1)
 	/// Proof: EthereumChecked Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: EthereumChecked SourceNonce (r:1 w:1)
+	/// Proof: EthereumChecked SourceNonce (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn transact_without_apply() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
-		//  Estimated: `3010`
+		//  Estimated: `3533`
 		// Minimum execution time: 8_838_000 picoseconds.
-		Weight::from_parts(9_229_000, 3010)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(9_229_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
@@ -75,13 +77,15 @@ impl WeightInfo for () {
 	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: EthereumChecked Nonce (r:1 w:1)
 	/// Proof: EthereumChecked Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: EthereumChecked SourceNonce (r:1 w:1)
+	/// Proof: EthereumChecked SourceNonce (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn transact_without_apply() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
-		//  Estimated: `3010`
+		//  Estimated: `3533`
 		// Minimum execution time: 8_838_000 picoseconds.
-		Weight::from_parts(9_229_000, 3010)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(9_229_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
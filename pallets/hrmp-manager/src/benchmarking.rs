@@ -0,0 +1,103 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+
+/// Para Id of the other parachain, different from this parachain.
+fn other_para<T: Config>() -> ParaId {
+    ParaId::from(u32::from(T::SelfParaId::get()).wrapping_add(1))
+}
+
+fn channel_request<T: Config>() -> ChannelRequest<BlockNumberFor<T>> {
+    ChannelRequest {
+        max_capacity: 8,
+        max_message_size: 1024,
+        requested_at: frame_system::Pallet::<T>::block_number(),
+    }
+}
+
+#[benchmarks()]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn request_channel() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let recipient = other_para::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, recipient, 8, 1024);
+
+        assert!(OutboundRequests::<T>::contains_key(recipient));
+    }
+
+    #[benchmark]
+    fn accept_channel() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let sender = other_para::<T>();
+        InboundRequests::<T>::insert(sender, channel_request::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, sender);
+
+        assert!(!InboundRequests::<T>::contains_key(sender));
+    }
+
+    #[benchmark]
+    fn close_channel() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let recipient = other_para::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, T::SelfParaId::get(), recipient);
+    }
+
+    #[benchmark]
+    fn cancel_request() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let recipient = other_para::<T>();
+        OutboundRequests::<T>::insert(recipient, channel_request::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, recipient, 1);
+
+        assert!(!OutboundRequests::<T>::contains_key(recipient));
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
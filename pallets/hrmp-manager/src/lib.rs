@@ -0,0 +1,423 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # HRMP Manager Pallet
+//!
+//! ## Overview
+//!
+//! Governance tool for managing the HRMP channels of this parachain, without hand-crafting
+//! encoded relay chain calls and XCM programs in referenda.
+//!
+//! ## Solution
+//!
+//! `ManagerOrigin` can request, accept & close the HRMP channels, as well as cancel pending channel requests.
+//! Each call builds the corresponding relay chain `hrmp` pallet call and sends it to the relay chain,
+//! to be dispatched with the parachain origin:
+//! ```ignore
+//! WithdrawAsset(fee)
+//! BuyExecution { fees: fee, weight_limit: Unlimited }
+//! Transact { origin_kind: Native, require_weight_at_most, call }
+//! RefundSurplus
+//! DepositAsset { assets: All, beneficiary: Parachain(self) }
+//! ```
+//! Execution is paid from the sovereign account of this parachain on the relay chain, which also needs to hold
+//! the channel deposits required by the relay chain.
+//!
+//! ### Pending Requests
+//!
+//! Channel requests are tracked until they're resolved:
+//! * outbound requests sent by this parachain are tracked until the recipient accepts them,
+//!   or until they're cancelled,
+//! * inbound requests from other parachains are tracked until this parachain accepts them.
+//!
+//! Relay chain notifies the parachain about the new inbound requests, accepted outbound requests and
+//! closing channels via dedicated XCM instructions. In order to track them, the pallet must be configured as the
+//! corresponding `HrmpNewChannelOpenRequestHandler`, `HrmpChannelAcceptedHandler` & `HrmpChannelClosingHandler`
+//! of the XCM executor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_std::{vec, vec::Vec};
+
+use cumulus_primitives_core::{relay_chain::HrmpChannelId, ParaId};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{
+    HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "hrmp-manager";
+
+/// Pending HRMP channel request.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub struct ChannelRequest<BlockNumber> {
+    /// Maximum number of messages which can be queued in the channel.
+    pub max_capacity: u32,
+    /// Maximum size of a single message sent over the channel.
+    pub max_message_size: u32,
+    /// Block in which the request was sent or received.
+    pub requested_at: BlockNumber,
+}
+
+/// Calls of the relay chain `hrmp` pallet, encoded the same way as on the relay chain.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub enum HrmpCall {
+    #[codec(index = 0)]
+    InitOpenChannel {
+        recipient: ParaId,
+        proposed_max_capacity: u32,
+        proposed_max_message_size: u32,
+    },
+    #[codec(index = 1)]
+    AcceptOpenChannel { sender: ParaId },
+    #[codec(index = 2)]
+    CloseChannel { channel_id: HrmpChannelId },
+    #[codec(index = 6)]
+    CancelOpenRequest {
+        channel_id: HrmpChannelId,
+        open_requests: u32,
+    },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Used to send the XCM programs to the relay chain.
+        type XcmSender: SendXcm;
+
+        /// Para Id of this parachain.
+        type SelfParaId: Get<ParaId>;
+
+        /// Origin which can manage the HRMP channels.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Index of the `hrmp` pallet in the relay chain runtime.
+        #[pallet::constant]
+        type HrmpPalletIndex: Get<u8>;
+
+        /// Amount of the relay chain native token used to pay for the execution on the relay chain.
+        #[pallet::constant]
+        type RelayFee: Get<u128>;
+
+        /// Maximum weight the relay chain `hrmp` call can require.
+        #[pallet::constant]
+        type RelayTransactWeight: Get<Weight>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Request to open a channel toward the `recipient` has been sent to the relay chain.
+        ChannelRequested {
+            recipient: ParaId,
+            max_capacity: u32,
+            max_message_size: u32,
+            message_id: XcmHash,
+        },
+        /// Acceptance of the channel request from the `sender` has been sent to the relay chain.
+        ChannelAccepted { sender: ParaId, message_id: XcmHash },
+        /// Request to close the channel has been sent to the relay chain.
+        ChannelCloseRequested {
+            sender: ParaId,
+            recipient: ParaId,
+            message_id: XcmHash,
+        },
+        /// Cancellation of the channel request toward the `recipient` has been sent to the relay chain.
+        ChannelRequestCancelled {
+            recipient: ParaId,
+            message_id: XcmHash,
+        },
+        /// Relay chain notified about the new channel request from the `sender`.
+        InboundChannelRequested {
+            sender: ParaId,
+            max_capacity: u32,
+            max_message_size: u32,
+        },
+        /// Relay chain notified that the `recipient` has accepted the channel request.
+        OutboundChannelAccepted { recipient: ParaId },
+        /// Relay chain notified that the channel is being closed by the `initiator`.
+        ChannelClosing {
+            initiator: ParaId,
+            sender: ParaId,
+            recipient: ParaId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Channel cannot be opened toward this parachain itself.
+        SelfChannel,
+        /// Channel request toward the recipient is already pending.
+        RequestAlreadyPending,
+        /// There's no pending channel request toward the recipient.
+        RequestNotPending,
+        /// This parachain is neither the sender nor the recipient of the channel.
+        NotChannelParticipant,
+        /// Sending the XCM program to the relay chain has failed.
+        SendFailed,
+    }
+
+    /// Channel requests sent by this parachain, which haven't been accepted by the recipient yet.
+    #[pallet::storage]
+    pub type OutboundRequests<T: Config> =
+        StorageMap<_, Twox64Concat, ParaId, ChannelRequest<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Channel requests received from other parachains, which haven't been accepted yet.
+    #[pallet::storage]
+    pub type InboundRequests<T: Config> =
+        StorageMap<_, Twox64Concat, ParaId, ChannelRequest<BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Request opening of the channel toward the `recipient`.
+        ///
+        /// The channel is opened once the recipient accepts the request.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::request_channel())]
+        pub fn request_channel(
+            origin: OriginFor<T>,
+            recipient: ParaId,
+            max_capacity: u32,
+            max_message_size: u32,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(recipient != T::SelfParaId::get(), Error::<T>::SelfChannel);
+            ensure!(
+                !OutboundRequests::<T>::contains_key(recipient),
+                Error::<T>::RequestAlreadyPending
+            );
+
+            let message_id = Self::send_hrmp_call(HrmpCall::InitOpenChannel {
+                recipient,
+                proposed_max_capacity: max_capacity,
+                proposed_max_message_size: max_message_size,
+            })?;
+            OutboundRequests::<T>::insert(
+                recipient,
+                ChannelRequest {
+                    max_capacity,
+                    max_message_size,
+                    requested_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::ChannelRequested {
+                recipient,
+                max_capacity,
+                max_message_size,
+                message_id,
+            });
+            Ok(())
+        }
+
+        /// Accept the channel request from the `sender`.
+        ///
+        /// Requests which weren't tracked by the pallet can be accepted as well.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::accept_channel())]
+        pub fn accept_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(sender != T::SelfParaId::get(), Error::<T>::SelfChannel);
+
+            let message_id = Self::send_hrmp_call(HrmpCall::AcceptOpenChannel { sender })?;
+            InboundRequests::<T>::remove(sender);
+
+            Self::deposit_event(Event::<T>::ChannelAccepted { sender, message_id });
+            Ok(())
+        }
+
+        /// Close the channel from the `sender` to the `recipient`.
+        ///
+        /// This parachain must be either the sender or the recipient of the channel.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::close_channel())]
+        pub fn close_channel(
+            origin: OriginFor<T>,
+            sender: ParaId,
+            recipient: ParaId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let self_para_id = T::SelfParaId::get();
+            ensure!(
+                sender == self_para_id || recipient == self_para_id,
+                Error::<T>::NotChannelParticipant
+            );
+
+            let message_id = Self::send_hrmp_call(HrmpCall::CloseChannel {
+                channel_id: HrmpChannelId { sender, recipient },
+            })?;
+
+            Self::deposit_event(Event::<T>::ChannelCloseRequested {
+                sender,
+                recipient,
+                message_id,
+            });
+            Ok(())
+        }
+
+        /// Cancel the pending channel request toward the `recipient`.
+        ///
+        /// `open_requests` must be the number of all pending channel requests on the relay chain,
+        /// as required by the relay chain.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_request())]
+        pub fn cancel_request(
+            origin: OriginFor<T>,
+            recipient: ParaId,
+            open_requests: u32,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                OutboundRequests::<T>::contains_key(recipient),
+                Error::<T>::RequestNotPending
+            );
+
+            let message_id = Self::send_hrmp_call(HrmpCall::CancelOpenRequest {
+                channel_id: HrmpChannelId {
+                    sender: T::SelfParaId::get(),
+                    recipient,
+                },
+                open_requests,
+            })?;
+            OutboundRequests::<T>::remove(recipient);
+
+            Self::deposit_event(Event::<T>::ChannelRequestCancelled {
+                recipient,
+                message_id,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Encode the `hrmp` pallet call the same way the relay chain runtime does.
+        pub fn encode_hrmp_call(call: &HrmpCall) -> Vec<u8> {
+            (T::HrmpPalletIndex::get(), call).encode()
+        }
+
+        /// Build the XCM program which dispatches the `hrmp` pallet call on the relay chain,
+        /// with the parachain origin.
+        pub fn build_program(call: &HrmpCall) -> Xcm<()> {
+            let fee: Asset = (Location::here(), T::RelayFee::get()).into();
+
+            Xcm(vec![
+                WithdrawAsset(fee.clone().into()),
+                BuyExecution {
+                    fees: fee,
+                    weight_limit: Unlimited,
+                },
+                Transact {
+                    origin_kind: OriginKind::Native,
+                    require_weight_at_most: T::RelayTransactWeight::get(),
+                    call: Self::encode_hrmp_call(call).into(),
+                },
+                RefundSurplus,
+                DepositAsset {
+                    assets: Wild(AllCounted(1)),
+                    beneficiary: Location::new(0, [Parachain(T::SelfParaId::get().into())]),
+                },
+            ])
+        }
+
+        /// Build & send the XCM program dispatching the `hrmp` pallet call to the relay chain.
+        fn send_hrmp_call(call: HrmpCall) -> Result<XcmHash, Error<T>> {
+            let message = Self::build_program(&call);
+
+            let (message_id, _) =
+                send_xcm::<T::XcmSender>(Location::parent(), message).map_err(|error| {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Failed to send {:?} to the relay chain, error: {:?}",
+                        call,
+                        error
+                    );
+                    Error::<T>::SendFailed
+                })?;
+
+            Ok(message_id)
+        }
+    }
+
+    impl<T: Config> HandleHrmpNewChannelOpenRequest for Pallet<T> {
+        fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> XcmResult {
+            let sender = ParaId::from(sender);
+            InboundRequests::<T>::insert(
+                sender,
+                ChannelRequest {
+                    max_capacity,
+                    max_message_size,
+                    requested_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::InboundChannelRequested {
+                sender,
+                max_capacity,
+                max_message_size,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> HandleHrmpChannelAccepted for Pallet<T> {
+        fn handle(recipient: u32) -> XcmResult {
+            let recipient = ParaId::from(recipient);
+            OutboundRequests::<T>::remove(recipient);
+
+            Self::deposit_event(Event::<T>::OutboundChannelAccepted { recipient });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> HandleHrmpChannelClosing for Pallet<T> {
+        fn handle(initiator: u32, sender: u32, recipient: u32) -> XcmResult {
+            Self::deposit_event(Event::<T>::ChannelClosing {
+                initiator: initiator.into(),
+                sender: sender.into(),
+                recipient: recipient.into(),
+            });
+            Ok(())
+        }
+    }
+}
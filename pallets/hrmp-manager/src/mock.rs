@@ -0,0 +1,149 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_hrmp_manager};
+
+use cumulus_primitives_core::ParaId;
+use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use frame_system::EnsureRoot;
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use xcm::latest::prelude::*;
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+pub(crate) type AccountId = sp_runtime::AccountId32;
+type BlockNumber = u32;
+
+pub(crate) const PARA_ID: u32 = 2000;
+pub(crate) const SIBLING_PARA_ID: u32 = 2001;
+pub(crate) const HRMP_PALLET_INDEX: u8 = 60;
+pub(crate) const RELAY_FEE: u128 = 1_000_000_000;
+pub(crate) const RELAY_TRANSACT_WEIGHT: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        HrmpManager: pallet_hrmp_manager,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+parameter_types! {
+    pub const SelfParaId: ParaId = ParaId::new(PARA_ID);
+    pub const HrmpPalletIndex: u8 = HRMP_PALLET_INDEX;
+    pub const RelayFee: u128 = RELAY_FEE;
+    pub const RelayTransactWeight: Weight = RELAY_TRANSACT_WEIGHT;
+    pub static SentXcm: Vec<(Location, Xcm<()>)> = vec![];
+    pub static FailSend: bool = false;
+}
+
+/// Sender which only records the sent messages, or fails if `FailSend` is set.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        if FailSend::get() {
+            return Err(SendError::Transport("Test failure."));
+        }
+
+        let ticket = (
+            destination.take().ok_or(SendError::MissingArgument)?,
+            message.take().ok_or(SendError::MissingArgument)?,
+        );
+        Ok((ticket, Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let message_id = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+        SentXcm::mutate(|sent| sent.push(ticket));
+        Ok(message_id)
+    }
+}
+
+impl pallet_hrmp_manager::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmSender = TestSendXcm;
+    type SelfParaId = SelfParaId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type HrmpPalletIndex = HrmpPalletIndex;
+    type RelayFee = RelayFee;
+    type RelayTransactWeight = RelayTransactWeight;
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            SentXcm::set(vec![]);
+            FailSend::set(false);
+        });
+
+        ext
+    }
+}
@@ -0,0 +1,395 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, ChannelRequest, Error, Event, HrmpCall, InboundRequests, OutboundRequests};
+
+use cumulus_primitives_core::{relay_chain::HrmpChannelId, ParaId};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Get};
+use parity_scale_codec::Encode;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{
+    HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+};
+
+fn sibling() -> ParaId {
+    ParaId::from(SIBLING_PARA_ID)
+}
+
+/// Message sent to the relay chain, expected to dispatch the `encoded_call`.
+fn expected_message(encoded_call: Vec<u8>) -> (Location, Xcm<()>) {
+    let fee: Asset = (Location::here(), RELAY_FEE).into();
+    (
+        Location::parent(),
+        Xcm(vec![
+            WithdrawAsset(fee.clone().into()),
+            BuyExecution {
+                fees: fee,
+                weight_limit: Unlimited,
+            },
+            Transact {
+                origin_kind: OriginKind::Native,
+                require_weight_at_most: RELAY_TRANSACT_WEIGHT,
+                call: encoded_call.into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Location::new(0, [Parachain(PARA_ID)]),
+            },
+        ]),
+    )
+}
+
+fn last_message_id() -> XcmHash {
+    let (_, message) = SentXcm::get().last().cloned().expect("Message was sent.");
+    message.using_encoded(sp_io::hashing::blake2_256)
+}
+
+#[test]
+fn hrmp_calls_are_encoded_as_on_relay_chain() {
+    ExtBuilder::build().execute_with(|| {
+        let self_para = SelfParaId::get();
+        let channel_id = HrmpChannelId {
+            sender: self_para,
+            recipient: sibling(),
+        };
+
+        let init = HrmpCall::InitOpenChannel {
+            recipient: sibling(),
+            proposed_max_capacity: 8,
+            proposed_max_message_size: 1024,
+        };
+        assert_eq!(
+            HrmpManager::encode_hrmp_call(&init),
+            (HRMP_PALLET_INDEX, 0u8, SIBLING_PARA_ID, 8u32, 1024u32).encode()
+        );
+
+        let accept = HrmpCall::AcceptOpenChannel { sender: sibling() };
+        assert_eq!(
+            HrmpManager::encode_hrmp_call(&accept),
+            (HRMP_PALLET_INDEX, 1u8, SIBLING_PARA_ID).encode()
+        );
+
+        let close = HrmpCall::CloseChannel {
+            channel_id: channel_id.clone(),
+        };
+        assert_eq!(
+            HrmpManager::encode_hrmp_call(&close),
+            (HRMP_PALLET_INDEX, 2u8, PARA_ID, SIBLING_PARA_ID).encode()
+        );
+
+        let cancel = HrmpCall::CancelOpenRequest {
+            channel_id,
+            open_requests: 3,
+        };
+        assert_eq!(
+            HrmpManager::encode_hrmp_call(&cancel),
+            (HRMP_PALLET_INDEX, 6u8, PARA_ID, SIBLING_PARA_ID, 3u32).encode()
+        );
+    })
+}
+
+#[test]
+fn request_channel_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(HrmpManager::request_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            8,
+            1024
+        ));
+
+        let encoded_call = (HRMP_PALLET_INDEX, 0u8, SIBLING_PARA_ID, 8u32, 1024u32).encode();
+        assert_eq!(SentXcm::get(), vec![expected_message(encoded_call)]);
+        assert_eq!(
+            OutboundRequests::<Test>::get(sibling()),
+            Some(ChannelRequest {
+                max_capacity: 8,
+                max_message_size: 1024,
+                requested_at: 1,
+            })
+        );
+        System::assert_last_event(
+            Event::<Test>::ChannelRequested {
+                recipient: sibling(),
+                max_capacity: 8,
+                max_message_size: 1024,
+                message_id: last_message_id(),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn request_channel_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            HrmpManager::request_channel(
+                RuntimeOrigin::signed(AccountId::new([1; 32])),
+                sibling(),
+                8,
+                1024
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::request_channel(RuntimeOrigin::root(), SelfParaId::get(), 8, 1024),
+            Error::<Test>::SelfChannel
+        );
+
+        // Nothing is tracked if sending fails
+        FailSend::set(true);
+        assert_noop!(
+            HrmpManager::request_channel(RuntimeOrigin::root(), sibling(), 8, 1024),
+            Error::<Test>::SendFailed
+        );
+        FailSend::set(false);
+
+        // Only a single request toward the same recipient can be pending
+        assert_ok!(HrmpManager::request_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            8,
+            1024
+        ));
+        assert_noop!(
+            HrmpManager::request_channel(RuntimeOrigin::root(), sibling(), 16, 2048),
+            Error::<Test>::RequestAlreadyPending
+        );
+    })
+}
+
+#[test]
+fn accept_channel_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(<HrmpManager as HandleHrmpNewChannelOpenRequest>::handle(
+            SIBLING_PARA_ID,
+            1024,
+            8
+        ));
+        assert!(InboundRequests::<Test>::contains_key(sibling()));
+
+        assert_ok!(HrmpManager::accept_channel(
+            RuntimeOrigin::root(),
+            sibling()
+        ));
+
+        let encoded_call = (HRMP_PALLET_INDEX, 1u8, SIBLING_PARA_ID).encode();
+        assert_eq!(SentXcm::get(), vec![expected_message(encoded_call)]);
+        assert!(!InboundRequests::<Test>::contains_key(sibling()));
+        System::assert_last_event(
+            Event::<Test>::ChannelAccepted {
+                sender: sibling(),
+                message_id: last_message_id(),
+            }
+            .into(),
+        );
+
+        // Requests which weren't tracked can be accepted as well
+        assert_ok!(HrmpManager::accept_channel(
+            RuntimeOrigin::root(),
+            ParaId::from(2002)
+        ));
+        assert_eq!(SentXcm::get().len(), 2);
+    })
+}
+
+#[test]
+fn accept_channel_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            HrmpManager::accept_channel(RuntimeOrigin::signed(AccountId::new([1; 32])), sibling()),
+            BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::accept_channel(RuntimeOrigin::root(), SelfParaId::get()),
+            Error::<Test>::SelfChannel
+        );
+
+        // Request remains tracked if sending fails
+        assert_ok!(<HrmpManager as HandleHrmpNewChannelOpenRequest>::handle(
+            SIBLING_PARA_ID,
+            1024,
+            8
+        ));
+        FailSend::set(true);
+        assert_noop!(
+            HrmpManager::accept_channel(RuntimeOrigin::root(), sibling()),
+            Error::<Test>::SendFailed
+        );
+        assert!(InboundRequests::<Test>::contains_key(sibling()));
+    })
+}
+
+#[test]
+fn close_channel_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Outbound channel
+        assert_ok!(HrmpManager::close_channel(
+            RuntimeOrigin::root(),
+            SelfParaId::get(),
+            sibling()
+        ));
+        let encoded_call = (HRMP_PALLET_INDEX, 2u8, PARA_ID, SIBLING_PARA_ID).encode();
+        assert_eq!(SentXcm::get(), vec![expected_message(encoded_call)]);
+        System::assert_last_event(
+            Event::<Test>::ChannelCloseRequested {
+                sender: SelfParaId::get(),
+                recipient: sibling(),
+                message_id: last_message_id(),
+            }
+            .into(),
+        );
+
+        // Inbound channel
+        assert_ok!(HrmpManager::close_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            SelfParaId::get()
+        ));
+        let encoded_call = (HRMP_PALLET_INDEX, 2u8, SIBLING_PARA_ID, PARA_ID).encode();
+        assert_eq!(SentXcm::get()[1], expected_message(encoded_call));
+    })
+}
+
+#[test]
+fn close_channel_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            HrmpManager::close_channel(
+                RuntimeOrigin::signed(AccountId::new([1; 32])),
+                SelfParaId::get(),
+                sibling()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::close_channel(RuntimeOrigin::root(), sibling(), ParaId::from(2002)),
+            Error::<Test>::NotChannelParticipant
+        );
+    })
+}
+
+#[test]
+fn cancel_request_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(HrmpManager::request_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            8,
+            1024
+        ));
+
+        assert_ok!(HrmpManager::cancel_request(
+            RuntimeOrigin::root(),
+            sibling(),
+            3
+        ));
+
+        let encoded_call = (HRMP_PALLET_INDEX, 6u8, PARA_ID, SIBLING_PARA_ID, 3u32).encode();
+        assert_eq!(SentXcm::get()[1], expected_message(encoded_call));
+        assert!(!OutboundRequests::<Test>::contains_key(sibling()));
+        System::assert_last_event(
+            Event::<Test>::ChannelRequestCancelled {
+                recipient: sibling(),
+                message_id: last_message_id(),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn cancel_request_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            HrmpManager::cancel_request(
+                RuntimeOrigin::signed(AccountId::new([1; 32])),
+                sibling(),
+                1
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            HrmpManager::cancel_request(RuntimeOrigin::root(), sibling(), 1),
+            Error::<Test>::RequestNotPending
+        );
+    })
+}
+
+#[test]
+fn relay_notifications_are_tracked() {
+    ExtBuilder::build().execute_with(|| {
+        // New inbound request
+        assert_ok!(<HrmpManager as HandleHrmpNewChannelOpenRequest>::handle(
+            SIBLING_PARA_ID,
+            1024,
+            8
+        ));
+        assert_eq!(
+            InboundRequests::<Test>::get(sibling()),
+            Some(ChannelRequest {
+                max_capacity: 8,
+                max_message_size: 1024,
+                requested_at: 1,
+            })
+        );
+        System::assert_last_event(
+            Event::<Test>::InboundChannelRequested {
+                sender: sibling(),
+                max_capacity: 8,
+                max_message_size: 1024,
+            }
+            .into(),
+        );
+
+        // Outbound request accepted by the recipient
+        assert_ok!(HrmpManager::request_channel(
+            RuntimeOrigin::root(),
+            sibling(),
+            8,
+            1024
+        ));
+        assert_ok!(<HrmpManager as HandleHrmpChannelAccepted>::handle(
+            SIBLING_PARA_ID
+        ));
+        assert!(!OutboundRequests::<Test>::contains_key(sibling()));
+        System::assert_last_event(
+            Event::<Test>::OutboundChannelAccepted {
+                recipient: sibling(),
+            }
+            .into(),
+        );
+
+        // Channel closed by the sibling
+        assert_ok!(<HrmpManager as HandleHrmpChannelClosing>::handle(
+            SIBLING_PARA_ID,
+            SIBLING_PARA_ID,
+            PARA_ID
+        ));
+        System::assert_last_event(
+            Event::<Test>::ChannelClosing {
+                initiator: sibling(),
+                sender: sibling(),
+                recipient: SelfParaId::get(),
+            }
+            .into(),
+        );
+    })
+}
@@ -0,0 +1,167 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_hrmp_manager
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-hrmp-manager --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/hrmp-manager/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_hrmp_manager.
+pub trait WeightInfo {
+	fn request_channel() -> Weight;
+	fn accept_channel() -> Weight;
+	fn close_channel() -> Weight;
+	fn cancel_request() -> Weight;
+}
+
+/// Weights for pallet_hrmp_manager using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `HrmpManager::OutboundRequests` (r:1 w:1)
+	/// Proof: `HrmpManager::OutboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn request_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3489`
+		// Minimum execution time: 40_114_000 picoseconds.
+		Weight::from_parts(41_203_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpManager::InboundRequests` (r:0 w:1)
+	/// Proof: `HrmpManager::InboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn accept_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `1758`
+		// Minimum execution time: 35_872_000 picoseconds.
+		Weight::from_parts(36_950_000, 1758)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn close_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `1758`
+		// Minimum execution time: 33_406_000 picoseconds.
+		Weight::from_parts(34_281_000, 1758)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `HrmpManager::OutboundRequests` (r:1 w:1)
+	/// Proof: `HrmpManager::OutboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `324`
+		//  Estimated: `3489`
+		// Minimum execution time: 40_631_000 picoseconds.
+		Weight::from_parts(41_772_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `HrmpManager::OutboundRequests` (r:1 w:1)
+	/// Proof: `HrmpManager::OutboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn request_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3489`
+		// Minimum execution time: 40_114_000 picoseconds.
+		Weight::from_parts(41_203_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `HrmpManager::InboundRequests` (r:0 w:1)
+	/// Proof: `HrmpManager::InboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn accept_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `1758`
+		// Minimum execution time: 35_872_000 picoseconds.
+		Weight::from_parts(36_950_000, 1758)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn close_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `1758`
+		// Minimum execution time: 33_406_000 picoseconds.
+		Weight::from_parts(34_281_000, 1758)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `HrmpManager::OutboundRequests` (r:1 w:1)
+	/// Proof: `HrmpManager::OutboundRequests` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `324`
+		//  Estimated: `3489`
+		// Minimum execution time: 40_631_000 picoseconds.
+		Weight::from_parts(41_772_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -256,6 +256,12 @@ pub mod pallet {
             assert!(T::CycleConfiguration::eras_per_build_and_earn_subperiod() > 0);
             assert!(T::CycleConfiguration::blocks_per_era() > 0);
         }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(())
+        }
     }
 
     #[pallet::call]
@@ -410,6 +416,27 @@ pub mod pallet {
 
             new_issuance > relaxed_issuance_safety_cap
         }
+
+        /// Ensure the correctness of the state of this pallet.
+        ///
+        /// ### Invariants
+        ///
+        /// 1. [`InflationParams`] must be valid, all parts summing up to one whole (100%).
+        /// 2. Total issuance must not exceed the relaxed issuance safety cap of [`ActiveInflationConfig`].
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            // Invariant 1
+            if !InflationParams::<T>::get().is_valid() {
+                return Err("Inflation parameters are invalid.".into());
+            }
+
+            // Invariant 2
+            if Self::is_payout_cap_limit_exceeded(Zero::zero()) {
+                return Err("Total issuance exceeds the relaxed issuance safety cap.".into());
+            }
+
+            Ok(())
+        }
     }
 
     impl<T: Config> DappStakingObserver for Pallet<T> {
@@ -467,3 +467,25 @@ fn test_genesis_build() {
         assert!(ActiveInflationConfig::<Test>::get().recalculation_era > 0);
     })
 }
+
+#[test]
+fn try_state_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::do_try_state());
+
+        // Invalid inflation parameters must be detected
+        let init_params = InflationParams::<Test>::get();
+        let mut invalid_params = init_params;
+        invalid_params.treasury_part = Perquintill::zero();
+        assert!(!invalid_params.is_valid(), "Sanity check");
+        InflationParams::<Test>::put(invalid_params);
+        assert!(Inflation::do_try_state().is_err());
+        InflationParams::<Test>::put(init_params);
+
+        // Exceeded issuance safety cap must be detected
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.issuance_safety_cap = Balances::total_issuance() / 2;
+        });
+        assert!(Inflation::do_try_state().is_err());
+    })
+}
@@ -0,0 +1,180 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Lockdrop Call Whitelist Pallet
+//!
+//! ## Overview
+//!
+//! Governance managed whitelist of runtime calls which lockdrop accounts can dispatch via the dispatch-lockdrop precompile.
+//!
+//! ## Solution
+//!
+//! The pallet implements `Contains` for any SCALE encodable call, and is supposed to be combined with the static
+//! call filter of the dispatch-lockdrop precompile. This way new safe calls can be enabled for the lockdrop accounts
+//! without a runtime upgrade.
+//!
+//! Calls are whitelisted by their pallet & call indices, which are the first two bytes of the encoded call.
+//!
+//! Whitelisting calls which dispatch other calls (e.g. `Utility::batch`) must be avoided, since the nested calls
+//! aren't checked against this whitelist.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin which can manage the whitelist.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of whitelisted calls.
+        #[pallet::constant]
+        type MaxAllowedCalls: Get<u32>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Call has been added to the whitelist.
+        CallAllowed { pallet_index: u8, call_index: u8 },
+        /// Call has been removed from the whitelist.
+        CallDisallowed { pallet_index: u8, call_index: u8 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Call is already whitelisted.
+        AlreadyAllowed,
+        /// Call isn't whitelisted.
+        NotAllowed,
+        /// Maximum number of whitelisted calls has been reached.
+        TooManyAllowedCalls,
+    }
+
+    /// Whitelisted calls, identified by the pallet & call indices.
+    #[pallet::storage]
+    pub type AllowedCalls<T: Config> =
+        CountedStorageMap<_, Twox64Concat, (u8, u8), (), OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(T::MaxAllowedCalls::get() > 0);
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add the call with the specified pallet & call indices to the whitelist.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::allow_call())]
+        pub fn allow_call(
+            origin: OriginFor<T>,
+            pallet_index: u8,
+            call_index: u8,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !AllowedCalls::<T>::contains_key((pallet_index, call_index)),
+                Error::<T>::AlreadyAllowed
+            );
+            ensure!(
+                AllowedCalls::<T>::count() < T::MaxAllowedCalls::get(),
+                Error::<T>::TooManyAllowedCalls
+            );
+
+            AllowedCalls::<T>::insert((pallet_index, call_index), ());
+
+            Self::deposit_event(Event::<T>::CallAllowed {
+                pallet_index,
+                call_index,
+            });
+            Ok(())
+        }
+
+        /// Remove the call with the specified pallet & call indices from the whitelist.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::disallow_call())]
+        pub fn disallow_call(
+            origin: OriginFor<T>,
+            pallet_index: u8,
+            call_index: u8,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AllowedCalls::<T>::contains_key((pallet_index, call_index)),
+                Error::<T>::NotAllowed
+            );
+
+            AllowedCalls::<T>::remove((pallet_index, call_index));
+
+            Self::deposit_event(Event::<T>::CallDisallowed {
+                pallet_index,
+                call_index,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if the SCALE encoded `call` is whitelisted, `false` otherwise.
+        pub fn is_allowed_encoded_call(call: &[u8]) -> bool {
+            match call {
+                [pallet_index, call_index, ..] => {
+                    AllowedCalls::<T>::contains_key((*pallet_index, *call_index))
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl<T: Config, RuntimeCall: Encode> Contains<RuntimeCall> for Pallet<T> {
+        fn contains(call: &RuntimeCall) -> bool {
+            call.using_encoded(Self::is_allowed_encoded_call)
+        }
+    }
+}
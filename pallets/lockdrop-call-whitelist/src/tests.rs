@@ -0,0 +1,161 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, AllowedCalls, Error, Event};
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Contains};
+
+/// `System::remark`, with pallet index `0` & call index `0`.
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark {
+        remark: b"hello".to_vec(),
+    })
+}
+
+/// `System::remark_with_event`, with pallet index `0` & call index `7`.
+fn remark_with_event_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"hello".to_vec(),
+    })
+}
+
+fn allow(pallet_index: u8, call_index: u8) {
+    assert_ok!(LockdropCallWhitelist::allow_call(
+        RuntimeOrigin::signed(MANAGER_ACCOUNT),
+        pallet_index,
+        call_index
+    ));
+}
+
+#[test]
+fn no_calls_allowed_by_default() {
+    ExtBuilder::build().execute_with(|| {
+        assert!(!LockdropCallWhitelist::contains(&remark_call()));
+        assert!(!LockdropCallWhitelist::contains(&remark_with_event_call()));
+    });
+}
+
+#[test]
+fn whitelisted_calls_are_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        allow(0, 0);
+        System::assert_last_event(
+            Event::<Test>::CallAllowed {
+                pallet_index: 0,
+                call_index: 0,
+            }
+            .into(),
+        );
+
+        // Only the call with the matching indices is allowed, regardless of its arguments
+        assert!(LockdropCallWhitelist::contains(&remark_call()));
+        assert!(LockdropCallWhitelist::contains(&RuntimeCall::System(
+            frame_system::Call::remark { remark: vec![] }
+        )));
+        assert!(!LockdropCallWhitelist::contains(&remark_with_event_call()));
+    });
+}
+
+#[test]
+fn malformed_encoded_calls_are_not_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        allow(0, 0);
+
+        assert!(LockdropCallWhitelist::is_allowed_encoded_call(&[0, 0]));
+        assert!(!LockdropCallWhitelist::is_allowed_encoded_call(&[0]));
+        assert!(!LockdropCallWhitelist::is_allowed_encoded_call(&[]));
+    });
+}
+
+#[test]
+fn disallow_call_works() {
+    ExtBuilder::build().execute_with(|| {
+        allow(0, 7);
+        assert!(LockdropCallWhitelist::contains(&remark_with_event_call()));
+
+        assert_ok!(LockdropCallWhitelist::disallow_call(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            0,
+            7
+        ));
+        System::assert_last_event(
+            Event::<Test>::CallDisallowed {
+                pallet_index: 0,
+                call_index: 7,
+            }
+            .into(),
+        );
+
+        assert!(!LockdropCallWhitelist::contains(&remark_with_event_call()));
+        assert_eq!(AllowedCalls::<Test>::count(), 0);
+    });
+}
+
+#[test]
+fn allow_call_fails_if_already_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        allow(0, 0);
+
+        assert_noop!(
+            LockdropCallWhitelist::allow_call(RuntimeOrigin::signed(MANAGER_ACCOUNT), 0, 0),
+            Error::<Test>::AlreadyAllowed
+        );
+    });
+}
+
+#[test]
+fn allow_call_fails_when_limit_reached() {
+    ExtBuilder::build().execute_with(|| {
+        for call_index in 0..MAX_ALLOWED_CALLS as u8 {
+            allow(0, call_index);
+        }
+
+        assert_noop!(
+            LockdropCallWhitelist::allow_call(
+                RuntimeOrigin::signed(MANAGER_ACCOUNT),
+                0,
+                MAX_ALLOWED_CALLS as u8
+            ),
+            Error::<Test>::TooManyAllowedCalls
+        );
+    });
+}
+
+#[test]
+fn disallow_call_fails_if_not_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            LockdropCallWhitelist::disallow_call(RuntimeOrigin::signed(MANAGER_ACCOUNT), 0, 0),
+            Error::<Test>::NotAllowed
+        );
+    });
+}
+
+#[test]
+fn calls_fail_for_bad_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            LockdropCallWhitelist::allow_call(RuntimeOrigin::signed(2), 0, 0),
+            BadOrigin
+        );
+        assert_noop!(
+            LockdropCallWhitelist::disallow_call(RuntimeOrigin::signed(2), 0, 0),
+            BadOrigin
+        );
+    });
+}
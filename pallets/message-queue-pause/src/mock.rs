@@ -0,0 +1,125 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_message_queue_pause};
+
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types, traits::ConstU32, weights::Weight,
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+type AccountId = u64;
+type BlockNumber = u32;
+
+pub(crate) const PAUSE_ACCOUNT: AccountId = 1;
+pub(crate) const RESUME_ACCOUNT: AccountId = 2;
+
+pub(crate) const MAX_PAUSED_TARGETS: u32 = 3;
+pub(crate) const MAX_PAUSE_DURATION: BlockNumber = 100;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        MessageQueuePause: pallet_message_queue_pause,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+ord_parameter_types! {
+    pub const PauseManager: AccountId = PAUSE_ACCOUNT;
+    pub const ResumeManager: AccountId = RESUME_ACCOUNT;
+}
+
+impl pallet_message_queue_pause::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type PauseOrigin = EnsureSignedBy<PauseManager, AccountId>;
+    type ResumeOrigin = EnsureSignedBy<ResumeManager, AccountId>;
+    type MaxPausedTargets = ConstU32<MAX_PAUSED_TARGETS>;
+    type MaxPauseDuration = ConstU32<MAX_PAUSE_DURATION>;
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+        });
+
+        ext
+    }
+}
+
+/// Advance to the specified block number, running the pallet's `on_initialize` hook on the way.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    use frame_support::traits::Hooks;
+
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        MessageQueuePause::on_initialize(System::block_number());
+    }
+}
@@ -0,0 +1,293 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, PauseInfo, PauseTarget, PausedTargets};
+
+use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::QueuePausedQuery};
+
+fn is_paused(origin: AggregateMessageOrigin) -> bool {
+    <MessageQueuePause as QueuePausedQuery<AggregateMessageOrigin>>::is_paused(&origin)
+}
+
+#[test]
+fn pause_and_resume_parent_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert!(!is_paused(AggregateMessageOrigin::Parent));
+
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Parent,
+            10
+        ));
+        System::assert_last_event(
+            Event::<Test>::Paused {
+                target: PauseTarget::Parent,
+                until: 11,
+            }
+            .into(),
+        );
+        assert_eq!(
+            PausedTargets::<Test>::get(PauseTarget::Parent),
+            Some(PauseInfo {
+                since: 1,
+                until: 11
+            })
+        );
+
+        assert!(is_paused(AggregateMessageOrigin::Parent));
+        assert!(!is_paused(AggregateMessageOrigin::Here));
+        assert!(!is_paused(AggregateMessageOrigin::Sibling(2000.into())));
+
+        assert_ok!(MessageQueuePause::resume(
+            RuntimeOrigin::signed(RESUME_ACCOUNT),
+            PauseTarget::Parent
+        ));
+        System::assert_last_event(
+            Event::<Test>::Resumed {
+                target: PauseTarget::Parent,
+            }
+            .into(),
+        );
+        assert!(!is_paused(AggregateMessageOrigin::Parent));
+        assert_eq!(PausedTargets::<Test>::count(), 0);
+    });
+}
+
+#[test]
+fn pause_all_siblings_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::AllSiblings,
+            10
+        ));
+
+        assert!(is_paused(AggregateMessageOrigin::Sibling(1000.into())));
+        assert!(is_paused(AggregateMessageOrigin::Sibling(2000.into())));
+        assert!(!is_paused(AggregateMessageOrigin::Parent));
+    });
+}
+
+#[test]
+fn pause_specific_sibling_works() {
+    ExtBuilder::build().execute_with(|| {
+        let para_id = ParaId::from(2000);
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Sibling(para_id),
+            10
+        ));
+
+        assert!(is_paused(AggregateMessageOrigin::Sibling(para_id)));
+        assert!(!is_paused(AggregateMessageOrigin::Sibling(2001.into())));
+        assert!(!is_paused(AggregateMessageOrigin::Parent));
+    });
+}
+
+#[test]
+fn sibling_pause_is_independent_of_origin_kind_pause() {
+    ExtBuilder::build().execute_with(|| {
+        let para_id = ParaId::from(2000);
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::AllSiblings,
+            10
+        ));
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Sibling(para_id),
+            20
+        ));
+
+        // Lifting the origin kind pause must not lift the explicit para-id pause.
+        assert_ok!(MessageQueuePause::resume(
+            RuntimeOrigin::signed(RESUME_ACCOUNT),
+            PauseTarget::AllSiblings
+        ));
+        assert!(is_paused(AggregateMessageOrigin::Sibling(para_id)));
+        assert!(!is_paused(AggregateMessageOrigin::Sibling(2001.into())));
+    });
+}
+
+#[test]
+fn pause_again_extends_the_pause() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Parent,
+            10
+        ));
+
+        run_to_block(5);
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Parent,
+            10
+        ));
+        assert_eq!(
+            PausedTargets::<Test>::get(PauseTarget::Parent),
+            Some(PauseInfo {
+                since: 5,
+                until: 15
+            })
+        );
+        assert_eq!(PausedTargets::<Test>::count(), 1);
+    });
+}
+
+#[test]
+fn pause_expires_automatically() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Parent,
+            5
+        ));
+
+        run_to_block(5);
+        assert!(is_paused(AggregateMessageOrigin::Parent));
+
+        run_to_block(6);
+        assert!(!is_paused(AggregateMessageOrigin::Parent));
+        System::assert_last_event(
+            Event::<Test>::PauseExpired {
+                target: PauseTarget::Parent,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn pauses_expiring_in_same_block_are_lifted_in_deterministic_order() {
+    ExtBuilder::build().execute_with(|| {
+        for target in [
+            PauseTarget::Sibling(2000.into()),
+            PauseTarget::AllSiblings,
+            PauseTarget::Parent,
+        ] {
+            assert_ok!(MessageQueuePause::pause(
+                RuntimeOrigin::signed(PAUSE_ACCOUNT),
+                target,
+                3
+            ));
+        }
+        System::reset_events();
+
+        run_to_block(4);
+
+        let expired: Vec<_> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::MessageQueuePause(Event::PauseExpired { target }) => Some(target),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            expired,
+            vec![
+                PauseTarget::Parent,
+                PauseTarget::AllSiblings,
+                PauseTarget::Sibling(2000.into())
+            ]
+        );
+        assert_eq!(PausedTargets::<Test>::count(), 0);
+    });
+}
+
+#[test]
+fn pause_with_invalid_duration_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            MessageQueuePause::pause(RuntimeOrigin::signed(PAUSE_ACCOUNT), PauseTarget::Parent, 0),
+            Error::<Test>::InvalidPauseDuration
+        );
+        assert_noop!(
+            MessageQueuePause::pause(
+                RuntimeOrigin::signed(PAUSE_ACCOUNT),
+                PauseTarget::Parent,
+                MAX_PAUSE_DURATION + 1
+            ),
+            Error::<Test>::InvalidPauseDuration
+        );
+    });
+}
+
+#[test]
+fn pause_fails_when_too_many_targets_are_paused() {
+    ExtBuilder::build().execute_with(|| {
+        for para_id in 0..MAX_PAUSED_TARGETS {
+            assert_ok!(MessageQueuePause::pause(
+                RuntimeOrigin::signed(PAUSE_ACCOUNT),
+                PauseTarget::Sibling(para_id.into()),
+                10
+            ));
+        }
+
+        assert_noop!(
+            MessageQueuePause::pause(
+                RuntimeOrigin::signed(PAUSE_ACCOUNT),
+                PauseTarget::Parent,
+                10
+            ),
+            Error::<Test>::TooManyPausedTargets
+        );
+
+        // Extending an existing pause is still possible.
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Sibling(0.into()),
+            20
+        ));
+    });
+}
+
+#[test]
+fn resume_not_paused_target_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            MessageQueuePause::resume(RuntimeOrigin::signed(RESUME_ACCOUNT), PauseTarget::Parent),
+            Error::<Test>::NotPaused
+        );
+    });
+}
+
+#[test]
+fn calls_with_invalid_origin_fail() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            MessageQueuePause::pause(
+                RuntimeOrigin::signed(RESUME_ACCOUNT),
+                PauseTarget::Parent,
+                10
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(MessageQueuePause::pause(
+            RuntimeOrigin::signed(PAUSE_ACCOUNT),
+            PauseTarget::Parent,
+            10
+        ));
+        assert_noop!(
+            MessageQueuePause::resume(RuntimeOrigin::signed(PAUSE_ACCOUNT), PauseTarget::Parent),
+            BadOrigin
+        );
+    });
+}
@@ -0,0 +1,64 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{
+    oracle::{CurrencyId, Price},
+    BlockNumber,
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Aggregated prices of a single currency, quoted in USD.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AggregatedPrice {
+    /// Moving average price, as provided to the runtime consumers.
+    pub average: Price,
+    /// Latest processed block value, if any.
+    pub spot: Option<Price>,
+    /// Time-weighted average price over the recent blocks, if any.
+    pub twap: Option<Price>,
+    /// Number of blocks since the latest block value has been processed, if any.
+    pub staleness: Option<BlockNumber>,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Price Aggregator Api.
+    ///
+    /// Used by frontends and off-chain services to consume the same prices the runtime uses.
+    pub trait PriceApi {
+
+        /// Aggregated prices of the `currency`, or `None` if the currency isn't tracked.
+        fn price(currency: CurrencyId) -> Option<AggregatedPrice>;
+
+        /// Aggregated prices of all the tracked currencies.
+        fn prices() -> Vec<(CurrencyId, AggregatedPrice)>;
+
+        /// Block value of the `currency` in effect at the `block`, together with the block in which it was processed.
+        ///
+        /// `None` is returned if the value isn't available, e.g. the `block` precedes the stored history.
+        fn price_at(currency: CurrencyId, block: BlockNumber) -> Option<(BlockNumber, Price)>;
+
+        /// Stored block values history of the `currency`, ordered from the oldest to the newest.
+        fn price_history(currency: CurrencyId) -> Vec<(BlockNumber, Price)>;
+    }
+}
@@ -27,32 +27,56 @@ mod benchmarks {
 
     #[benchmark]
     fn process_block_aggregated_values() {
-        // Fill up the current block buffer with some values
+        let currency = <T as Config>::NativeCurrencyId::get();
+
+        // Fill up the current block buffer with some values, submitted by the distinct feeders.
+        // Minority of the values are outliers, to account for the worst case of rejecting them.
         let size_limit = <T as Config>::MaxValuesPerBlock::get();
-        let mut result = BoundedVec::<CurrencyAmount, <T as Config>::MaxValuesPerBlock>::default();
+        let mut result = BoundedVec::<
+            (T::AccountId, CurrencyAmount),
+            <T as Config>::MaxValuesPerBlock,
+        >::default();
         for x in 1..=size_limit {
-            let value = CurrencyAmount::from_rational(x as u128 + 3, 10);
+            let value = if x <= size_limit / 2 + 1 {
+                CurrencyAmount::from_rational(3, 10)
+            } else {
+                CurrencyAmount::from_rational(x as u128 + 1000, 10)
+            };
             result
-                .try_push(value)
+                .try_push((account("feeder", x, 0), value))
                 .expect("Must succeed since we are iterating to the limit");
         }
-        CurrentBlockValues::<T>::put(result);
+        CurrentBlockValues::<T>::insert(currency, result);
+
+        // Fill up the TWAP buffer, to account for the worst case encoding & decoding
+        BlockValuesCircularBuffer::<T>::mutate(currency, |b| {
+            for x in 1..=<T as Config>::TwapWindow::get() {
+                b.add(CurrencyAmount::from_rational(x as u128 + 3, 10));
+            }
+        });
+        BlockValuesHistory::<T>::mutate(currency, |h| {
+            for x in 1..=<T as Config>::PriceHistoryLength::get() {
+                h.add(x, CurrencyAmount::from_rational(x as u128 + 3, 10));
+            }
+        });
 
         #[block]
         {
-            Pallet::<T>::process_block_aggregated_values();
+            Pallet::<T>::process_block_aggregated_values(currency);
         }
 
         assert!(
-            CurrentBlockValues::<T>::get().is_empty(),
+            CurrentBlockValues::<T>::get(currency).is_empty(),
             "Should have been cleaned up."
         );
     }
 
     #[benchmark]
     fn process_intermediate_aggregated_values() {
+        let currency = <T as Config>::NativeCurrencyId::get();
+
         // 1. Fill up the current aggregator and make it trigger on the current block end
-        IntermediateValueAggregator::<T>::mutate(|a| {
+        IntermediateValueAggregator::<T>::mutate(currency, |a| {
             a.limit_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
             a.total = CurrencyAmount::from_rational(1234, 10);
@@ -61,27 +85,43 @@ mod benchmarks {
 
         // 2. Fill up the circular buffer with some values
         let buffer_length = <T as Config>::CircularBufferLength::get();
-        ValuesCircularBuffer::<T>::mutate(|b| {
+        ValuesCircularBuffer::<T>::mutate(currency, |b| {
             for x in 1..=buffer_length {
                 b.add(CurrencyAmount::from_rational(x as u128 + 3, 10));
             }
         });
         assert_eq!(
-            ValuesCircularBuffer::<T>::get().buffer.len(),
+            ValuesCircularBuffer::<T>::get(currency).buffer.len(),
             buffer_length as usize,
             "Sanity check."
         );
 
         // 3. Prepare local variables
-        let buffer_snapshot = ValuesCircularBuffer::<T>::get();
+        let buffer_snapshot = ValuesCircularBuffer::<T>::get(currency);
         let current_block = frame_system::Pallet::<T>::block_number();
 
         #[block]
         {
-            Pallet::<T>::process_intermediate_aggregated_values(current_block);
+            Pallet::<T>::process_intermediate_aggregated_values(currency, current_block);
         }
 
-        assert!(ValuesCircularBuffer::<T>::get() != buffer_snapshot);
+        assert!(ValuesCircularBuffer::<T>::get(currency) != buffer_snapshot);
+    }
+
+    #[benchmark]
+    fn reinstate_feeder() -> Result<(), BenchmarkError> {
+        let feeder: T::AccountId = whitelisted_caller();
+        OutlierStrikes::<T>::insert(&feeder, <T as Config>::MaxOutlierStrikes::get());
+
+        let origin =
+            T::ReinstateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, feeder.clone());
+
+        assert!(!Pallet::<T>::is_struck_out(&feeder));
+
+        Ok(())
     }
 
     impl_benchmark_test_suite!(
@@ -22,19 +22,28 @@
 //!
 //! Purpose of this pallet is to aggregate price data over some time, and then calculate the moving average.
 //!
+//! Prices of multiple currencies can be tracked at the same time. Each tracked currency (e.g. `ASTR`, `DOT`, `ETH`)
+//! is aggregated independently of the others, all prices being quoted in USD.
+//! The set of tracked currencies is bounded, and must always include the native currency.
+//!
 //! ## Solution
 //!
 //! The overall solution is broken down into several steps that occur over the course of various time periods.
 //!
 //! ### Block Aggregation
 //!
-//! During each block, the native currency price data is accumulated. This is done 'outside' the pallet, and it's only expected
+//! During each block, the price data of each tracked currency is accumulated. This is done 'outside' the pallet, and it's only expected
 //! that 'something' will push this data to the price aggregator pallet. The pallet itself doesn't care about the source of the data, nor who submitted it.
 //!
 //! At the end of each block, accumulated data is processed according to the specified algorithm (e.g. can be average, median, or something else).
 //! In case processing was successful, the result is stored in the intermediate value aggregator.
 //! In case processing fails, value is simply ignored.
 //!
+//! Submitted values which deviate more than `OutlierThreshold` from the median of all the values submitted in the same block
+//! are rejected as outliers, and never become part of the processed block value. Outliers are only rejected if they were
+//! submitted by a strict minority of the feeders. This prevents a single misconfigured data source from skewing the block value,
+//! while legitimate price movements reported by the majority of the feeders are always accepted.
+//!
 //! ### Intermediate Value Aggregation
 //!
 //! After a predetermined amount of time (blocks) has passed, the average value is calculated from the intermediate value aggregator.
@@ -43,30 +52,68 @@
 //!
 //! ### Moving Average Calculation
 //!
-//! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of the native currency, over some time period.
+//! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of a currency, over some time period.
 //! It's important to note that the moving average is not a 'real-time' value, but rather a 'lagging' indicator.
+//!
+//! ### Spot & TWAP
+//!
+//! Each processed block value is also pushed into a separate circular buffer, holding values of the last `TwapWindow` blocks.
+//! In case no value could be processed for the block, the last known value is carried forward, giving each block the same weight.
+//!
+//! The latest processed block value is provided as the 'spot' price, and the average of the buffer as the time-weighted average price (TWAP).
+//! TWAP is much harder to manipulate than the spot price, and should be preferred for anything related to fees or collateral.
+//!
+//! Each successfully processed block value is also stored in a bounded history, together with the block in which it was processed.
+//! The history holds the last `PriceHistoryLength` values, and can be queried by block number, e.g. to verify past settlement prices
+//! or to calculate custom averages.
+//!
+//! ### Staleness
+//!
+//! If no valid value has been processed for a currency for more than `StalenessThreshold` blocks, its price is marked as stale,
+//! and the `PriceStale` event is emitted. Once a valid value is processed again, the mark is cleared.
+//!
+//! While the native currency price is stale, the `PriceProvider` behaves according to `OnStalePrice`:
+//! it either provides the configured fallback price, or reports an error, so dependent logic can degrade predictably.
+//! Since `PriceProvider::average_price` can't report an error, it keeps providing the last known average price instead,
+//! and consumers which must not act upon the stale price should use `PriceProvider::try_average_price`.
+//!
+//! ### Outlier Strikes
+//!
+//! Each rejected outlier submission earns the submitting feeder a strike. Once a feeder accumulates `MaxOutlierStrikes` strikes,
+//! it's struck out: its submissions are ignored, and it's excluded from the feeder set wrapped by `ExcludeStruckOut`.
+//! Governance can reinstate the struck out feeder, clearing its strikes.
+//!
+//! Strikes are only ever given against the consensus of the feeders, and a majority of the `Feeders` is never struck out.
+//! In case striking out a feeder would leave no active majority, its strikes stay just below the limit instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{pallet_prelude::*, DefaultNoBound};
+use frame_support::{pallet_prelude::*, traits::SortedMembers, DefaultNoBound};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_arithmetic::{
     fixed_point::FixedU128,
     traits::{CheckedAdd, SaturatedConversion, Saturating, Zero},
+    Perbill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use orml_traits::OnNewData;
+use price_aggregator_runtime_api::AggregatedPrice;
 
 use astar_primitives::{
-    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    oracle::{
+        CurrencyAmount, CurrencyId, CurrencyPriceProvider, Price, PriceError, PriceProvider,
+        StalePriceBehavior,
+    },
     BlockNumber,
 };
 
 pub mod weights;
 pub use weights::WeightInfo;
 
+pub mod migration;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -124,6 +171,38 @@ impl ProcessBlockValues for MedianBlockValue {
     }
 }
 
+/// Handler of the feeders which have been struck out due to the repeated outlier submissions.
+pub trait OnStruckOut<AccountId> {
+    /// Called once the feeder `who` has been struck out, e.g. to slash its registration deposit.
+    fn on_struck_out(who: &AccountId);
+}
+
+impl<AccountId> OnStruckOut<AccountId> for () {
+    fn on_struck_out(_who: &AccountId) {}
+}
+
+/// Feeder set of the `Members`, excluding the feeders which have been struck out.
+pub struct ExcludeStruckOut<T, Members>(PhantomData<(T, Members)>);
+impl<T: Config, Members: SortedMembers<T::AccountId>> SortedMembers<T::AccountId>
+    for ExcludeStruckOut<T, Members>
+{
+    fn sorted_members() -> Vec<T::AccountId> {
+        Members::sorted_members()
+            .into_iter()
+            .filter(|who| !Pallet::<T>::is_struck_out(who))
+            .collect()
+    }
+
+    fn contains(who: &T::AccountId) -> bool {
+        Members::contains(who) && !Pallet::<T>::is_struck_out(who)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &T::AccountId) {
+        Members::add(who)
+    }
+}
+
 /// Used to aggregate the accumulated values over some time period.
 ///
 /// To avoid having a large memory footprint, values are summed up into a single accumulator.
@@ -246,6 +325,113 @@ impl<L: Get<u32>> CircularBuffer<L> {
         // At this point, length of the buffer is guaranteed to be greater than zero.
         sum.saturating_mul(FixedU128::from_rational(1, self.buffer.len() as u128))
     }
+
+    /// Returns the average of the `count` most recently added values, or `None` if there are no such values.
+    ///
+    /// In case fewer than `count` values are available, the average of all the values is returned.
+    pub fn latest_average(&self, count: u32) -> Option<CurrencyAmount> {
+        let count = (count as usize).min(self.buffer.len());
+        if count.is_zero() {
+            return None;
+        }
+
+        // Values preceding the 'head' are the most recent ones, followed by the values at the end of the buffer.
+        let head = (self.head as usize).min(self.buffer.len());
+        let (recent, earlier) = self.buffer.split_at(head);
+        let sum = recent
+            .iter()
+            .rev()
+            .chain(earlier.iter().rev())
+            .take(count)
+            .fold(CurrencyAmount::zero(), |acc, &value| {
+                acc.saturating_add(value)
+            });
+
+        Some(sum.saturating_mul(FixedU128::from_rational(1, count as u128)))
+    }
+
+    /// Returns the most recently added value, or `None` if the buffer is empty.
+    pub fn latest(&self) -> Option<CurrencyAmount> {
+        let index = if self.head.is_zero() {
+            // Either the buffer is empty, or it has been filled out and the last value is at the end.
+            self.buffer.len().checked_sub(1)?
+        } else {
+            self.head.saturating_sub(1) as usize
+        };
+
+        self.buffer.get(index).copied()
+    }
+}
+
+/// Used to store the recent processed block values, together with the blocks in which they were processed.
+///
+/// Works the same way as the `CircularBuffer`, i.e. once the capacity has been filled out,
+/// the oldest entry is always overwritten with the new entry.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+    DefaultNoBound,
+)]
+#[scale_info(skip_type_params(L))]
+pub struct HistoryBuffer<L: Get<u32>> {
+    /// Block numbers & currency values store.
+    pub(crate) buffer: BoundedVec<(BlockNumber, CurrencyAmount), L>,
+    /// Next index to write to.
+    #[codec(compact)]
+    pub(crate) head: u32,
+}
+
+impl<L: Get<u32>> HistoryBuffer<L> {
+    /// Adds a new entry to the history buffer, possibly overriding the oldest entry if capacity is filled.
+    pub fn add(&mut self, block: BlockNumber, value: CurrencyAmount) {
+        // This can never happen, parameters must ensure that.
+        // But we still check it and log an error if it does.
+        if self.head >= L::get() || self.head as usize > self.buffer.len() {
+            log::error!(
+                target: LOG_TARGET,
+                "Failed to push entry to the history buffer due to invalid next index. \
+                Next index: {:?}, Buffer length: {:?}, Buffer capacity: {:?}",
+                self.head,
+                self.buffer.len(),
+                L::get()
+            );
+            return;
+        }
+
+        if self.buffer.len() > self.head as usize {
+            // Vec has been filled out, so we need to override the 'head' entry
+            self.buffer[self.head as usize] = (block, value);
+        } else {
+            // Vec is not full yet, so we can just push the entry
+            let _ignorable = self.buffer.try_push((block, value));
+        }
+        self.head = self.head.saturating_add(1) % L::get();
+    }
+
+    /// Returns all the stored entries, ordered from the oldest to the newest.
+    pub fn entries(&self) -> Vec<(BlockNumber, CurrencyAmount)> {
+        let (newer, older) = self
+            .buffer
+            .split_at((self.head as usize).min(self.buffer.len()));
+
+        older.iter().chain(newer.iter()).copied().collect()
+    }
+
+    /// Returns the entry in effect at the `block`, i.e. the latest one processed at or before it.
+    ///
+    /// `None` is returned if the `block` precedes the oldest stored entry.
+    pub fn entry_at(&self, block: BlockNumber) -> Option<(BlockNumber, CurrencyAmount)> {
+        self.entries()
+            .into_iter()
+            .rev()
+            .find(|(processed_at, _)| *processed_at <= block)
+    }
 }
 
 const LOG_TARGET: &str = "price-aggregator";
@@ -255,7 +441,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -274,8 +460,17 @@ pub mod pallet {
         type ProcessBlockValues: ProcessBlockValues;
 
         /// Native currency ID that this pallet is supposed to track.
+        ///
+        /// Must be part of the `TrackedCurrencies`.
         type NativeCurrencyId: Get<CurrencyId>;
 
+        /// Maximum number of currencies which can be tracked by this pallet.
+        #[pallet::constant]
+        type MaxTrackedCurrencies: Get<u32>;
+
+        /// Currencies whose prices are aggregated by this pallet, including the native currency.
+        type TrackedCurrencies: Get<BoundedVec<CurrencyId, Self::MaxTrackedCurrencies>>;
+
         /// Maximum length of the circular buffer used to calculate the moving average.
         #[pallet::constant]
         type CircularBufferLength: Get<u32>;
@@ -285,79 +480,204 @@ pub mod pallet {
         #[pallet::constant]
         type AggregationDuration: Get<BlockNumberFor<Self>>;
 
+        /// Number of the most recent blocks over which the time-weighted average price is calculated.
+        #[pallet::constant]
+        type TwapWindow: Get<u32>;
+
+        /// Maximum number of the most recent processed block values kept in the queryable history.
+        #[pallet::constant]
+        type PriceHistoryLength: Get<u32>;
+
+        /// Maximum allowed deviation of a submitted value from the median of all the values submitted in the same block.
+        #[pallet::constant]
+        type OutlierThreshold: Get<Perbill>;
+
+        /// Number of blocks without a valid processed value, after which the currency price is considered stale.
+        #[pallet::constant]
+        type StalenessThreshold: Get<BlockNumberFor<Self>>;
+
+        /// Behavior of the native currency `PriceProvider` once the native currency price becomes stale.
+        type OnStalePrice: Get<StalePriceBehavior>;
+
+        /// Number of outlier strikes after which the feeder is struck out.
+        #[pallet::constant]
+        type MaxOutlierStrikes: Get<u32>;
+
+        /// Invoked once a feeder has been struck out.
+        type OnStruckOut: OnStruckOut<Self::AccountId>;
+
+        /// Complete feeder set, used to ensure a majority of it is never struck out.
+        type Feeders: SortedMembers<Self::AccountId>;
+
+        /// Origin which can reinstate the struck out feeders.
+        type ReinstateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
+        /// Initial moving average values of the native currency.
         pub circular_buffer: BoundedVec<CurrencyAmount, T::CircularBufferLength>,
     }
 
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            ValuesCircularBuffer::<T>::put(CircularBuffer::<T::CircularBufferLength> {
-                buffer: self.circular_buffer.clone(),
-                head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
-            });
+            ValuesCircularBuffer::<T>::insert(
+                T::NativeCurrencyId::get(),
+                CircularBuffer::<T::CircularBufferLength> {
+                    buffer: self.circular_buffer.clone(),
+                    head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
+                },
+            );
 
-            IntermediateValueAggregator::<T>::mutate(|aggregator| {
-                aggregator.limit_block = T::AggregationDuration::get().saturated_into();
-            });
+            for currency in T::TrackedCurrencies::get() {
+                IntermediateValueAggregator::<T>::mutate(currency, |aggregator| {
+                    aggregator.limit_block = T::AggregationDuration::get().saturated_into();
+                });
+            }
         }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// New average native currency value has been calculated and pushed into the moving average buffer.
-        AverageAggregatedValue { value: CurrencyAmount },
+        /// New average currency value has been calculated and pushed into the moving average buffer.
+        AverageAggregatedValue {
+            currency: CurrencyId,
+            value: CurrencyAmount,
+        },
+        /// Submitted value deviates too much from the median of the block values, and has been rejected.
+        OutlierRejected {
+            who: T::AccountId,
+            currency: CurrencyId,
+            value: CurrencyAmount,
+            median: CurrencyAmount,
+            strikes: u32,
+        },
+        /// No valid value has been processed for more than `StalenessThreshold` blocks, the price is now stale.
+        PriceStale {
+            currency: CurrencyId,
+            last_processed: BlockNumber,
+        },
+        /// Valid value has been processed again, the price is no longer stale.
+        PriceRecovered { currency: CurrencyId },
+        /// Feeder has accumulated too many outlier strikes, and has been struck out.
+        FeederStruckOut { who: T::AccountId },
+        /// Struck out feeder has been reinstated by governance.
+        FeederReinstated { who: T::AccountId },
     }
 
-    /// Storage for the accumulated native currency price in the current block.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Feeder isn't struck out.
+        NotStruckOut,
+    }
+
+    /// Storage for the accumulated currency prices in the current block, together with their feeders.
+    #[pallet::storage]
+    pub type CurrentBlockValues<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<(T::AccountId, CurrencyAmount), T::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Used to store the aggregated processed block values of each currency during some time period.
+    #[pallet::storage]
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, ValueAggregator, ValueQuery>;
+
+    /// Used to store aggregated intermediate values of each currency for some time period.
+    #[pallet::storage]
+    pub type ValuesCircularBuffer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        CircularBuffer<T::CircularBufferLength>,
+        ValueQuery,
+    >;
+
+    /// Used to store the processed block values of each currency over the last `TwapWindow` blocks.
+    #[pallet::storage]
+    pub type BlockValuesCircularBuffer<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, CircularBuffer<T::TwapWindow>, ValueQuery>;
+
+    /// Used to store the most recent processed block values of each currency, together with the blocks in which they were processed.
+    #[pallet::storage]
+    pub type BlockValuesHistory<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, HistoryBuffer<T::PriceHistoryLength>, ValueQuery>;
+
+    /// Block in which the latest block value of each currency has been processed.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type CurrentBlockValues<T: Config> =
-        StorageValue<_, BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>, ValueQuery>;
+    pub type LastProcessedBlock<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, BlockNumber, OptionQuery>;
 
-    /// Used to store the aggregated processed block values during some time period.
+    /// Number of outlier strikes accumulated by each feeder.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type IntermediateValueAggregator<T: Config> = StorageValue<_, ValueAggregator, ValueQuery>;
+    pub type OutlierStrikes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-    /// Used to store aggregated intermediate values for some time period.
+    /// Block since which the price of each stale currency has been considered stale.
     #[pallet::storage]
-    pub type ValuesCircularBuffer<T: Config> =
-        StorageValue<_, CircularBuffer<T::CircularBufferLength>, ValueQuery>;
+    pub type StalePrices<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, BlockNumber, OptionQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            // Need to account for the reads and writes of:
-            // - CurrentBlockValues
-            // - IntermediateValueAggregator
-            //
-            // Also need to account for the weight of processing block accumulated values.
-            let mut total_weight = T::DbWeight::get()
-                .reads_writes(2, 2)
-                .saturating_add(T::WeightInfo::process_block_aggregated_values());
-
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                total_weight
-                    .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+            let mut total_weight = Weight::zero();
+
+            for currency in T::TrackedCurrencies::get() {
+                // Need to account for the reads and writes of:
+                // - CurrentBlockValues
+                // - IntermediateValueAggregator
+                // - BlockValuesCircularBuffer
+                // - BlockValuesHistory
+                // - LastProcessedBlock
+                // - StalePrices
+                //
+                // Also need to account for the weight of processing block accumulated values.
+                total_weight.saturating_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(6, 6)
+                        .saturating_add(Weight::from_parts(
+                            0,
+                            CircularBuffer::<T::TwapWindow>::max_encoded_len().saturating_add(
+                                HistoryBuffer::<T::PriceHistoryLength>::max_encoded_len(),
+                            ) as u64,
+                        ))
+                        .saturating_add(T::WeightInfo::process_block_aggregated_values()),
+                );
+
+                if IntermediateValueAggregator::<T>::get(currency).limit_block
+                    <= now.saturated_into()
+                {
+                    total_weight
+                        .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+                }
             }
 
             total_weight
         }
 
         fn on_finalize(now: BlockNumberFor<T>) {
-            // 1. Process the accumulated native currency values in the current block.
-            Self::process_block_aggregated_values();
+            for currency in T::TrackedCurrencies::get() {
+                // 1. Process the accumulated currency values in the current block.
+                Self::process_block_aggregated_values(currency);
+
+                // 2. Check if we need to push the average aggregated value to the storage.
+                if IntermediateValueAggregator::<T>::get(currency).limit_block
+                    <= now.saturated_into()
+                {
+                    Self::process_intermediate_aggregated_values(currency, now);
+                }
 
-            // 2. Check if we need to push the average aggregated value to the storage.
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                Self::process_intermediate_aggregated_values(now);
+                // 3. Check whether the price has become stale, or has recovered.
+                Self::update_staleness(currency, now);
             }
         }
 
@@ -365,48 +685,327 @@ pub mod pallet {
             assert!(T::MaxValuesPerBlock::get() > 0);
             assert!(T::CircularBufferLength::get() > 0);
             assert!(!T::AggregationDuration::get().is_zero());
+            assert!(T::TwapWindow::get() > 0);
+            assert!(T::PriceHistoryLength::get() > 0);
+            assert!(!T::OutlierThreshold::get().is_zero());
+            assert!(!T::StalenessThreshold::get().is_zero());
+            assert!(T::MaxOutlierStrikes::get() > 0);
+            assert!(
+                T::TrackedCurrencies::get().contains(&T::NativeCurrencyId::get()),
+                "Native currency must be tracked."
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Reinstate the struck out feeder `who`, clearing its outlier strikes.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::reinstate_feeder())]
+        pub fn reinstate_feeder(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::ReinstateOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_struck_out(&who), Error::<T>::NotStruckOut);
+
+            OutlierStrikes::<T>::remove(&who);
+
+            Self::deposit_event(Event::FeederReinstated { who });
+            Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Used to process the native currency values accumulated in the current block.
+        /// `true` if the `currency` price is aggregated by this pallet, `false` otherwise.
+        pub fn is_tracked(currency: CurrencyId) -> bool {
+            T::TrackedCurrencies::get().contains(&currency)
+        }
+
+        /// Latest processed block value of the `currency`, or `None` if there is no such value.
+        pub fn spot_price(currency: CurrencyId) -> Option<CurrencyAmount> {
+            BlockValuesCircularBuffer::<T>::get(currency).latest()
+        }
+
+        /// Time-weighted average price of the `currency` over the last `TwapWindow` blocks,
+        /// or `None` if no block value has been processed yet.
+        pub fn twap_price(currency: CurrencyId) -> Option<CurrencyAmount> {
+            let buffer = BlockValuesCircularBuffer::<T>::get(currency);
+            if buffer.buffer.is_empty() {
+                None
+            } else {
+                Some(buffer.average())
+            }
+        }
+
+        /// Time-weighted average price of the `currency` over the last `window` blocks,
+        /// or `None` if no block value has been processed yet, or the `window` is zero.
         ///
-        /// Guarantees that the accumulated values are cleared after processing.
-        /// In case of an error during processing, intermediate aggregated value is not updated.
-        pub(crate) fn process_block_aggregated_values() {
-            // 1. Take the accumulated block values, clearing the existing storage.
-            let accumulated_values = CurrentBlockValues::<T>::take();
+        /// The `window` is capped by the `TwapWindow`.
+        pub fn twap_price_over(currency: CurrencyId, window: u32) -> Option<CurrencyAmount> {
+            BlockValuesCircularBuffer::<T>::get(currency).latest_average(window)
+        }
 
-            // 2. Attempt to process accumulated block values.
-            let processed_value = match T::ProcessBlockValues::process(
-                accumulated_values.as_slice(),
-            ) {
-                Ok(value) => value,
-                Err(message) => {
-                    log::trace!(
+        /// Number of blocks since the latest block value of the `currency` has been processed, if any.
+        pub fn staleness(currency: CurrencyId) -> Option<BlockNumber> {
+            let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+            LastProcessedBlock::<T>::get(currency).map(|block| now.saturating_sub(block))
+        }
+
+        /// Aggregated prices of the `currency`, or `None` if the currency isn't tracked.
+        pub fn aggregated_price(currency: CurrencyId) -> Option<AggregatedPrice> {
+            if !Self::is_tracked(currency) {
+                return None;
+            }
+
+            Some(AggregatedPrice {
+                average: ValuesCircularBuffer::<T>::get(currency).average(),
+                spot: Self::spot_price(currency),
+                twap: Self::twap_price(currency),
+                staleness: Self::staleness(currency),
+            })
+        }
+
+        /// Aggregated prices of all the tracked currencies.
+        pub fn aggregated_prices() -> Vec<(CurrencyId, AggregatedPrice)> {
+            T::TrackedCurrencies::get()
+                .into_iter()
+                .filter_map(|currency| {
+                    Self::aggregated_price(currency).map(|price| (currency, price))
+                })
+                .collect()
+        }
+
+        /// `true` if the feeder `who` has been struck out, `false` otherwise.
+        pub fn is_struck_out(who: &T::AccountId) -> bool {
+            OutlierStrikes::<T>::get(who) >= T::MaxOutlierStrikes::get()
+        }
+
+        /// `true` if one more feeder can be struck out, while keeping a majority of the `Feeders` active.
+        pub(crate) fn can_strike_out() -> bool {
+            let feeders = T::Feeders::sorted_members();
+            let struck_out = feeders
+                .iter()
+                .filter(|who| Self::is_struck_out(who))
+                .count();
+
+            struck_out.saturating_add(1).saturating_mul(2) < feeders.len()
+        }
+
+        /// Used to reject the outlier `value` submitted by the feeder `who`, recording a strike for it.
+        ///
+        /// Once the feeder reaches `MaxOutlierStrikes` strikes, it's struck out,
+        /// unless that would leave no active majority of the `Feeders`.
+        pub(crate) fn reject_outlier(
+            who: &T::AccountId,
+            currency: CurrencyId,
+            value: CurrencyAmount,
+            median: CurrencyAmount,
+        ) {
+            let strikes = OutlierStrikes::<T>::mutate(who, |strikes| {
+                if strikes.saturating_add(1) < T::MaxOutlierStrikes::get() || Self::can_strike_out()
+                {
+                    strikes.saturating_inc();
+                } else {
+                    log::warn!(
                         target: LOG_TARGET,
-                        "Failed to process the accumulated native currency values in the current block. \
-                        Reason: {:?}",
-                        message
+                        "Feeder {:?} isn't struck out, since no active majority of the feeders would remain.",
+                        who
                     );
+                }
+                *strikes
+            });
+
+            log::warn!(
+                target: LOG_TARGET,
+                "Rejected outlier {:?} value {:?} submitted by {:?}, block median is {:?}.",
+                currency,
+                value,
+                who,
+                median
+            );
+            Self::deposit_event(Event::OutlierRejected {
+                who: who.clone(),
+                currency,
+                value,
+                median,
+                strikes,
+            });
 
-                    // Nothing to do if we have no valid value to store.
-                    return;
+            if strikes == T::MaxOutlierStrikes::get() {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Feeder {:?} has been struck out after {:?} outlier submissions.",
+                    who,
+                    strikes
+                );
+                T::OnStruckOut::on_struck_out(who);
+                Self::deposit_event(Event::FeederStruckOut { who: who.clone() });
+            }
+        }
+
+        /// `true` if the `currency` price is stale, `false` otherwise.
+        ///
+        /// Price can only become stale after the first valid value has been processed.
+        pub fn is_stale(currency: CurrencyId) -> bool {
+            StalePrices::<T>::contains_key(currency)
+        }
+
+        /// Used to mark the `currency` price as stale if no valid value has been processed for
+        /// more than `StalenessThreshold` blocks, or to clear the stale mark once a valid value is processed.
+        pub(crate) fn update_staleness(currency: CurrencyId, now: BlockNumberFor<T>) {
+            let Some(last_processed) = LastProcessedBlock::<T>::get(currency) else {
+                return;
+            };
+            let now: BlockNumber = now.saturated_into();
+            let threshold: BlockNumber = T::StalenessThreshold::get().saturated_into();
+            let is_stale = now.saturating_sub(last_processed) > threshold;
+
+            match (is_stale, Self::is_stale(currency)) {
+                (true, false) => {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "No valid {:?} value processed since block {:?}, price is stale.",
+                        currency,
+                        last_processed
+                    );
+                    StalePrices::<T>::insert(currency, now);
+                    Self::deposit_event(Event::PriceStale {
+                        currency,
+                        last_processed,
+                    });
+                }
+                (false, true) => {
+                    StalePrices::<T>::remove(currency);
+                    Self::deposit_event(Event::PriceRecovered { currency });
                 }
+                _ => {}
+            }
+        }
+
+        /// Block value of the `currency` in effect at the `block`, together with the block in which it was processed.
+        ///
+        /// `None` is returned if no such value is available, e.g. the `block` precedes the stored history.
+        pub fn price_at(
+            currency: CurrencyId,
+            block: BlockNumber,
+        ) -> Option<(BlockNumber, CurrencyAmount)> {
+            BlockValuesHistory::<T>::get(currency).entry_at(block)
+        }
+
+        /// Stored block values history of the `currency`, ordered from the oldest to the newest.
+        pub fn price_history(currency: CurrencyId) -> Vec<(BlockNumber, CurrencyAmount)> {
+            BlockValuesHistory::<T>::get(currency).entries()
+        }
+
+        /// `true` if the `value` deviates from the `median` more than `OutlierThreshold`, `false` otherwise.
+        pub(crate) fn is_outlier(value: CurrencyAmount, median: CurrencyAmount) -> bool {
+            let deviation = value
+                .saturating_sub(median)
+                .max(median.saturating_sub(value));
+
+            deviation > median.saturating_mul(T::OutlierThreshold::get().into())
+        }
+
+        /// Used to filter out the outliers among the `currency` values submitted in the current block,
+        /// returning the remaining values.
+        ///
+        /// Outliers are detected against the median of all the submitted values, i.e. the consensus of the feeders.
+        /// In case the outliers were submitted by at least half of the feeders, there is no consensus to compare against,
+        /// and all the values are kept.
+        pub(crate) fn filter_outliers(
+            currency: CurrencyId,
+            submissions: Vec<(T::AccountId, CurrencyAmount)>,
+        ) -> Vec<CurrencyAmount> {
+            let values: Vec<CurrencyAmount> = submissions.iter().map(|(_, value)| *value).collect();
+            let Ok(median) = MedianBlockValue::process(values.as_slice()) else {
+                return values;
+            };
+
+            let count_feeders = |submissions: &[(T::AccountId, CurrencyAmount)]| {
+                let mut feeders: Vec<&T::AccountId> =
+                    submissions.iter().map(|(who, _)| who).collect();
+                feeders.sort();
+                feeders.dedup();
+                feeders.len()
             };
+            let feeders = count_feeders(submissions.as_slice());
 
-            // 3. Attempt to store the processed value.
+            let (outliers, accepted): (Vec<_>, Vec<_>) = submissions
+                .into_iter()
+                .partition(|(_, value)| Self::is_outlier(*value, median));
+            if outliers.is_empty() {
+                return values;
+            }
+
+            if count_feeders(outliers.as_slice()).saturating_mul(2) >= feeders {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "No consensus among the {:?} feeders of the {:?} values, keeping all the values.",
+                    feeders,
+                    currency
+                );
+                return values;
+            }
+
+            for (who, value) in outliers {
+                Self::reject_outlier(&who, currency, value, median);
+            }
+
+            accepted.into_iter().map(|(_, value)| value).collect()
+        }
+
+        /// Used to process the `currency` values accumulated in the current block.
+        ///
+        /// Guarantees that the accumulated values are cleared after processing.
+        /// In case of an error during processing, intermediate aggregated value is not updated,
+        /// and the last known block value is carried forward for the TWAP calculation.
+        pub(crate) fn process_block_aggregated_values(currency: CurrencyId) {
+            // 1. Take the accumulated block values, clearing the existing storage.
+            let submissions = CurrentBlockValues::<T>::take(currency);
+
+            // 2. Filter out the outliers, and attempt to process the remaining block values.
+            let accumulated_values = Self::filter_outliers(currency, submissions.into_inner());
+            let processed_value =
+                match T::ProcessBlockValues::process(accumulated_values.as_slice()) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        log::trace!(
+                            target: LOG_TARGET,
+                            "Failed to process the accumulated {:?} values in the current block. \
+                            Reason: {:?}",
+                            currency,
+                            message
+                        );
+
+                        // Carry the last known value forward, so each block has the same weight in the TWAP.
+                        BlockValuesCircularBuffer::<T>::mutate(currency, |buffer| {
+                            if let Some(latest) = buffer.latest() {
+                                buffer.add(latest);
+                            }
+                        });
+
+                        // Nothing else to do if we have no valid value to store.
+                        return;
+                    }
+                };
+
+            // 3. Push the processed value to the TWAP buffer & the history.
+            let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+            BlockValuesCircularBuffer::<T>::mutate(currency, |buffer| buffer.add(processed_value));
+            BlockValuesHistory::<T>::mutate(currency, |history| history.add(now, processed_value));
+            LastProcessedBlock::<T>::insert(currency, now);
+
+            // 4. Attempt to store the processed value.
             // This operation is practically infallible, but we check the results for the additional safety.
-            let intermediate_value = IntermediateValueAggregator::<T>::get();
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency);
             match intermediate_value.try_add(processed_value) {
                 Ok(new_aggregator) => {
-                    IntermediateValueAggregator::<T>::put(new_aggregator);
+                    IntermediateValueAggregator::<T>::insert(currency, new_aggregator);
                 }
                 Err(message) => {
                     log::error!(
                         target: LOG_TARGET,
-                        "Failed to add the processed native currency value to the intermediate storage. \
+                        "Failed to add the processed {:?} value to the intermediate storage. \
                         Reason: {:?}",
+                        currency,
                         message
                     );
                 }
@@ -414,30 +1013,38 @@ pub mod pallet {
         }
 
         /// Used to process the intermediate aggregated values, and push them to the moving average storage.
-        pub(crate) fn process_intermediate_aggregated_values(now: BlockNumberFor<T>) {
+        pub(crate) fn process_intermediate_aggregated_values(
+            currency: CurrencyId,
+            now: BlockNumberFor<T>,
+        ) {
             // 1. Get the average value from the intermediate aggregator.
-            let average_value = IntermediateValueAggregator::<T>::get().average();
+            let average_value = IntermediateValueAggregator::<T>::get(currency).average();
 
             // 2. Reset the aggregator back to zero, and set the new limit block.
-            IntermediateValueAggregator::<T>::put(ValueAggregator::new(
-                now.saturating_add(T::AggregationDuration::get())
-                    .saturated_into(),
-            ));
+            IntermediateValueAggregator::<T>::insert(
+                currency,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
 
             // 3. In case aggregated value equals 0, it means something has gone wrong since it's extremely unlikely
             // that price goes to absolute zero. The much more likely case is that there's a problem with the oracle data feed.
             if average_value.is_zero() {
                 log::error!(
                     target: LOG_TARGET,
-                    "The average aggregated price equals zero, which most likely means that oracle data feed is faulty. \
-                    Not pushing the 'zero' value to the moving average storage."
+                    "The average aggregated {:?} price equals zero, which most likely means that oracle data feed is faulty. \
+                    Not pushing the 'zero' value to the moving average storage.",
+                    currency
                 );
                 return;
             }
 
             // 4. Push the 'valid' average aggregated value to the circular buffer.
-            ValuesCircularBuffer::<T>::mutate(|buffer| buffer.add(average_value));
+            ValuesCircularBuffer::<T>::mutate(currency, |buffer| buffer.add(average_value));
             Self::deposit_event(Event::AverageAggregatedValue {
+                currency,
                 value: average_value,
             });
         }
@@ -446,18 +1053,31 @@ pub mod pallet {
     // Make this pallet an 'observer' ('listener') of the new oracle data feed.
     impl<T: Config> OnNewData<T::AccountId, CurrencyId, CurrencyAmount> for Pallet<T> {
         fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &CurrencyAmount) {
-            // Ignore any currency that is not native currency.
-            if T::NativeCurrencyId::get() != *key {
+            // Ignore any currency that isn't tracked.
+            if !Self::is_tracked(*key) {
                 return;
             }
 
-            CurrentBlockValues::<T>::mutate(|v| match v.try_push(*value) {
+            // Ignore values submitted by struck out feeders.
+            if Self::is_struck_out(who) {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Ignored {:?} value submitted by struck out feeder {:?}.",
+                    key,
+                    who
+                );
+                return;
+            }
+
+            // Outliers are filtered out once all the values of the block have been submitted.
+            CurrentBlockValues::<T>::mutate(key, |v| match v.try_push((who.clone(), *value)) {
                 Ok(()) => {}
                 Err(_) => {
                     log::error!(
                     target: LOG_TARGET,
-                        "Failed to push native currency value into the ongoing block due to exceeded capacity. \
+                        "Failed to push {:?} value into the ongoing block due to exceeded capacity. \
                         Value was submitted by: {:?}",
+                        key,
                         who
                     );
                 }
@@ -468,9 +1088,47 @@ pub mod pallet {
     // Make this pallet a `price provider` for the native currency.
     //
     // For this particular implementation, a simple moving average is used to calculate the average price.
+    // Once the price becomes stale, `OnStalePrice` determines what is provided instead.
     impl<T: Config> PriceProvider for Pallet<T> {
         fn average_price() -> FixedU128 {
-            ValuesCircularBuffer::<T>::get().average()
+            Self::try_average_price().unwrap_or_else(|_| {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Native price is stale, providing the last known average price since the error can't be reported."
+                );
+                ValuesCircularBuffer::<T>::get(T::NativeCurrencyId::get()).average()
+            })
+        }
+
+        fn try_average_price() -> Result<Price, PriceError> {
+            let native_currency_id = T::NativeCurrencyId::get();
+            if !Self::is_stale(native_currency_id) {
+                return Ok(ValuesCircularBuffer::<T>::get(native_currency_id).average());
+            }
+
+            match T::OnStalePrice::get() {
+                StalePriceBehavior::Fallback(price) => Ok(price),
+                StalePriceBehavior::Error => Err(PriceError::Stale),
+            }
+        }
+    }
+
+    // Make this pallet a `price provider` for all of the tracked currencies.
+    impl<T: Config> CurrencyPriceProvider for Pallet<T> {
+        fn average_price_of(currency: CurrencyId) -> Option<Price> {
+            if Self::is_tracked(currency) {
+                Some(ValuesCircularBuffer::<T>::get(currency).average())
+            } else {
+                None
+            }
+        }
+
+        fn spot_price_of(currency: CurrencyId) -> Option<Price> {
+            Self::spot_price(currency)
+        }
+
+        fn twap_price_of(currency: CurrencyId) -> Option<Price> {
+            Self::twap_price(currency)
         }
     }
 }
@@ -0,0 +1,132 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Exports for versioned migration `type`s for this pallet.
+pub mod versioned_migrations {
+    use super::*;
+
+    /// Migration V1 to V2 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 1.
+    pub type V1ToV2<T> = frame_support::migrations::VersionedMigration<
+        1,
+        2,
+        v2::VersionMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+// Per-currency aggregation
+mod v2 {
+    use super::*;
+
+    pub struct VersionMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let native_currency = T::NativeCurrencyId::get();
+
+            // 1. Move the native currency values into the per-currency storage maps.
+            // Block values are cleared at the end of each block, and their feeders weren't recorded, so they're dropped.
+            v1::CurrentBlockValues::<T>::kill();
+
+            let aggregator = v1::IntermediateValueAggregator::<T>::take();
+            IntermediateValueAggregator::<T>::insert(native_currency, aggregator);
+
+            let circular_buffer = v1::ValuesCircularBuffer::<T>::take();
+            ValuesCircularBuffer::<T>::insert(native_currency, circular_buffer);
+
+            // 2. Align the aggregation period of the newly tracked currencies with the native one.
+            let mut new_currencies = 0_u64;
+            for currency in T::TrackedCurrencies::get() {
+                if currency != native_currency {
+                    IntermediateValueAggregator::<T>::insert(
+                        currency,
+                        ValueAggregator::new(aggregator.limit_block),
+                    );
+                    new_currencies.saturating_inc();
+                }
+            }
+
+            log::info!(
+                target: LOG_TARGET,
+                "Moved native currency values to per-currency storage, initialized {} new currencies.",
+                new_currencies
+            );
+
+            T::DbWeight::get().reads_writes(3, 6_u64.saturating_add(new_currencies))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(v1::ValuesCircularBuffer::<T>::get().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 2,
+                "price-aggregator::migration::v2: wrong storage version"
+            );
+
+            let old_buffer = CircularBuffer::<T::CircularBufferLength>::decode(&mut data.as_ref())
+                .map_err(|_| "price-aggregator::migration::v2: failed to decode old buffer")?;
+            ensure!(
+                ValuesCircularBuffer::<T>::get(T::NativeCurrencyId::get()) == old_buffer,
+                "price-aggregator::migration::v2: native currency buffer mismatch"
+            );
+            ensure!(
+                !v1::ValuesCircularBuffer::<T>::exists(),
+                "price-aggregator::migration::v2: old buffer not removed"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+mod v1 {
+    use super::*;
+
+    /// v1 type for [`crate::CurrentBlockValues`]
+    #[storage_alias]
+    pub type CurrentBlockValues<T: Config> = StorageValue<
+        Pallet<T>,
+        BoundedVec<CurrencyAmount, <T as Config>::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// v1 type for [`crate::IntermediateValueAggregator`]
+    #[storage_alias]
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageValue<Pallet<T>, ValueAggregator, ValueQuery>;
+
+    /// v1 type for [`crate::ValuesCircularBuffer`]
+    #[storage_alias]
+    pub type ValuesCircularBuffer<T: Config> =
+        StorageValue<Pallet<T>, CircularBuffer<<T as Config>::CircularBufferLength>, ValueQuery>;
+}
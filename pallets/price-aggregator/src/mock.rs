@@ -17,23 +17,29 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, IntermediateValueAggregator,
+    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, CurrentBlockValues,
+    IntermediateValueAggregator, OnStruckOut,
 };
 
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU128, ConstU32, Hooks},
+    traits::{ConstU128, ConstU32, Hooks, SortedMembers},
     weights::Weight,
+    BoundedVec,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Perbill,
 };
 
-use astar_primitives::{oracle::CurrencyId, Balance, BlockNumber};
-type AccountId = u64;
+use astar_primitives::{
+    oracle::{CurrencyAmount, CurrencyId, StalePriceBehavior},
+    Balance, BlockNumber,
+};
+pub(crate) type AccountId = u64;
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -94,6 +100,27 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
     pub const AggregationDuration: BlockNumberFor<Test> = 16;
+    pub static OutlierThreshold: Perbill = Perbill::from_percent(100);
+    pub static StalenessThreshold: BlockNumberFor<Test> = 100;
+    pub static OnStalePrice: StalePriceBehavior = StalePriceBehavior::Error;
+    pub static StruckOutFeeders: Vec<AccountId> = vec![];
+    pub static FeederSet: Vec<AccountId> = vec![1, 2, 3];
+    pub TrackedCurrencies: BoundedVec<CurrencyId, ConstU32<2>> =
+        BoundedVec::truncate_from(vec![CurrencyId::ASTR, CurrencyId::DOT]);
+}
+
+pub struct RecordStruckOut;
+impl OnStruckOut<AccountId> for RecordStruckOut {
+    fn on_struck_out(who: &AccountId) {
+        StruckOutFeeders::mutate(|feeders| feeders.push(*who));
+    }
+}
+
+pub struct AllFeeders;
+impl SortedMembers<AccountId> for AllFeeders {
+    fn sorted_members() -> Vec<AccountId> {
+        FeederSet::get()
+    }
 }
 
 impl pallet_price_aggregator::Config for Test {
@@ -102,8 +129,19 @@ impl pallet_price_aggregator::Config for Test {
     type MaxValuesPerBlock = ConstU32<4>;
     type ProcessBlockValues = AverageBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<2>;
+    type TrackedCurrencies = TrackedCurrencies;
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    type TwapWindow = ConstU32<5>;
+    type PriceHistoryLength = ConstU32<4>;
+    type OutlierThreshold = OutlierThreshold;
+    type StalenessThreshold = StalenessThreshold;
+    type OnStalePrice = OnStalePrice;
+    type MaxOutlierStrikes = ConstU32<3>;
+    type OnStruckOut = RecordStruckOut;
+    type Feeders = AllFeeders;
+    type ReinstateOrigin = EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -124,11 +162,13 @@ impl ExtBuilder {
 
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
-            // 1. Set the initial limit block for the intermediate value aggregator
-            IntermediateValueAggregator::<Test>::mutate(|v| {
-                v.limit_block =
-                    <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
-            });
+            // 1. Set the initial limit block for the intermediate value aggregators
+            for currency in TrackedCurrencies::get() {
+                IntermediateValueAggregator::<Test>::mutate(currency, |v| {
+                    v.limit_block =
+                        <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
+                });
+            }
 
             // 2. Init block setting
             let init_block_number = 1;
@@ -139,3 +179,11 @@ impl ExtBuilder {
         ext
     }
 }
+
+/// Values of the `currency` accumulated in the current block, without their feeders.
+pub(crate) fn current_block_values(currency: CurrencyId) -> Vec<CurrencyAmount> {
+    CurrentBlockValues::<Test>::get(currency)
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect()
+}
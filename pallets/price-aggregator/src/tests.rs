@@ -18,21 +18,28 @@
 
 use crate::mock::*;
 use crate::{
-    pallet::Config, AverageBlockValue, CircularBuffer, CurrentBlockValues, Event,
-    IntermediateValueAggregator, MedianBlockValue, ProcessBlockValues, ValueAggregator,
-    ValuesCircularBuffer,
+    pallet::Config, AverageBlockValue, BlockValuesCircularBuffer, CircularBuffer,
+    CurrentBlockValues, Error, Event, ExcludeStruckOut, HistoryBuffer, IntermediateValueAggregator,
+    MedianBlockValue, OutlierStrikes, ProcessBlockValues, ValueAggregator, ValuesCircularBuffer,
 };
 
-use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use astar_primitives::oracle::{
+    CurrencyAmount, CurrencyId, CurrencyPriceProvider, PriceError, PriceProvider,
+    StalePriceBehavior,
+};
 
 use orml_traits::OnNewData;
+use price_aggregator_runtime_api::AggregatedPrice;
 
 use frame_support::{
-    assert_storage_noop,
-    traits::{Get, Hooks},
+    assert_noop, assert_ok, assert_storage_noop,
+    traits::{Get, Hooks, SortedMembers},
     BoundedVec,
 };
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{
+    traits::{BadOrigin, Zero},
+    Perbill, Saturating,
+};
 
 pub use num_traits::Bounded;
 
@@ -217,6 +224,7 @@ fn circular_buffer_basic_checks() {
     let mut circular_buffer = CircularBuffer::<BufferSize>::default();
     assert!(circular_buffer.buffer.is_empty());
     assert!(circular_buffer.head.is_zero());
+    assert!(circular_buffer.latest().is_none());
 
     // 2. Add a value, verify state is as expected
     let amount_1 = CurrencyAmount::from_rational(19, 10);
@@ -225,6 +233,7 @@ fn circular_buffer_basic_checks() {
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 1);
     assert_eq!(circular_buffer.average(), amount_1);
+    assert_eq!(circular_buffer.latest(), Some(amount_1));
 
     // 3. Add another value, verify state is as expected
     let amount_2 = CurrencyAmount::from_rational(7, 10);
@@ -245,6 +254,7 @@ fn circular_buffer_basic_checks() {
     }
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert!(circular_buffer.head.is_zero());
+    assert_eq!(circular_buffer.latest(), Some(amount_3));
 
     // 5. Add another value, verify 0-th element is replaced
     let amount_4 = CurrencyAmount::from_rational(9, 10);
@@ -252,6 +262,7 @@ fn circular_buffer_basic_checks() {
     expected_buffer[0] = amount_4;
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 1);
+    assert_eq!(circular_buffer.latest(), Some(amount_4));
 
     // 6. Repeat the cycle few more times, expect it works as expected
     for x in 0..BUFFER_SIZE * 5 {
@@ -265,6 +276,7 @@ fn circular_buffer_basic_checks() {
         circular_buffer.add(amount);
         assert_eq!(circular_buffer.buffer[init_head as usize], amount);
         assert_eq!(circular_buffer.head, (init_head + 1) % BUFFER_SIZE);
+        assert_eq!(circular_buffer.latest(), Some(amount));
     }
 }
 
@@ -303,44 +315,127 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     assert_eq!(inconsistent_buffer.head, BUFFER_SIZE);
 }
 
+#[test]
+fn circular_buffer_latest_average() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 3;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+
+    // 1. Empty buffer, or zero count, has no average
+    let mut buffer = CircularBuffer::<BufferSize>::default();
+    assert!(buffer.latest_average(2).is_none());
+
+    let amount_1 = CurrencyAmount::from_rational(1, 10);
+    let amount_2 = CurrencyAmount::from_rational(2, 10);
+    let amount_3 = CurrencyAmount::from_rational(3, 10);
+    buffer.add(amount_1);
+    assert!(buffer.latest_average(0).is_none());
+
+    // 2. Average is calculated over the most recent values, capped by the available values
+    buffer.add(amount_2);
+    assert_eq!(buffer.latest_average(1), Some(amount_2));
+    assert_eq!(
+        buffer.latest_average(5),
+        Some(amount_1.saturating_add(amount_2) * CurrencyAmount::from_rational(1, 2))
+    );
+
+    // 3. Once the buffer wraps around, the most recent values are still used
+    buffer.add(amount_3);
+    let amount_4 = CurrencyAmount::from_rational(4, 10);
+    buffer.add(amount_4);
+    assert_eq!(
+        buffer.latest_average(2),
+        Some(amount_3.saturating_add(amount_4) * CurrencyAmount::from_rational(1, 2))
+    );
+    assert_eq!(buffer.latest_average(3), Some(buffer.average()));
+}
+
+#[test]
+fn history_buffer_basic_checks() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 3;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+
+    // 1. Empty buffer has no entries
+    let mut history = HistoryBuffer::<BufferSize>::default();
+    assert!(history.entries().is_empty());
+    assert!(history.entry_at(10).is_none());
+
+    // 2. Fill up the buffer, entries are ordered from the oldest to the newest
+    let amount_1 = CurrencyAmount::from_rational(1, 10);
+    let amount_2 = CurrencyAmount::from_rational(2, 10);
+    let amount_3 = CurrencyAmount::from_rational(3, 10);
+    history.add(2, amount_1);
+    history.add(4, amount_2);
+    history.add(5, amount_3);
+    assert_eq!(
+        history.entries(),
+        vec![(2, amount_1), (4, amount_2), (5, amount_3)]
+    );
+
+    // 3. Entry in effect at the block is the latest one processed at or before it
+    assert!(history.entry_at(1).is_none());
+    assert_eq!(history.entry_at(2), Some((2, amount_1)));
+    assert_eq!(history.entry_at(3), Some((2, amount_1)));
+    assert_eq!(history.entry_at(4), Some((4, amount_2)));
+    assert_eq!(history.entry_at(100), Some((5, amount_3)));
+
+    // 4. Once full, the oldest entry is overwritten, and no longer available
+    let amount_4 = CurrencyAmount::from_rational(4, 10);
+    history.add(7, amount_4);
+    assert_eq!(
+        history.entries(),
+        vec![(4, amount_2), (5, amount_3), (7, amount_4)]
+    );
+    assert!(history.entry_at(3).is_none());
+    assert_eq!(history.entry_at(6), Some((5, amount_3)));
+}
+
 #[test]
 fn on_new_data_works_as_expected() {
     ExtBuilder::build().execute_with(|| {
         // 0. Initial sanity check
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(native_currency_id).is_empty(),
             "Init state must be empty."
         );
 
         // 1. Inform pallet of a new piece of data, verify state is as expected
         let dummy_account_1 = 123;
-        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
         let amount_1 = CurrencyAmount::from_rational(15, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_1);
-        assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
-            vec![amount_1],
-        );
+        assert_eq!(current_block_values(native_currency_id), vec![amount_1],);
 
-        // 2. Try to add non-native currency, verify no state change
-        let non_native_currency_id = CurrencyId::SDN;
+        // 2. Try to add untracked currency, verify no state change
+        let untracked_currency_id = CurrencyId::SDN;
         assert!(
-            non_native_currency_id != native_currency_id,
+            !PriceAggregator::is_tracked(untracked_currency_id),
             "Sanity check."
         );
 
-        let non_native_amount = CurrencyAmount::from_rational(7, 10);
+        let untracked_amount = CurrencyAmount::from_rational(7, 10);
         assert_storage_noop!(PriceAggregator::on_new_data(
             &dummy_account_1,
-            &non_native_currency_id,
-            &non_native_amount
+            &untracked_currency_id,
+            &untracked_amount
         ));
 
         // 3. Add additional amount, verify state is as expected
         let amount_2 = CurrencyAmount::from_rational(3, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_2);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            current_block_values(native_currency_id),
             vec![amount_1, amount_2],
         );
 
@@ -355,7 +450,7 @@ fn on_new_data_works_as_expected() {
         }
 
         assert_eq!(result.len(), limit as usize, "Sanity check.");
-        assert_eq!(CurrentBlockValues::<Test>::get().into_inner(), result);
+        assert_eq!(current_block_values(native_currency_id), result);
 
         // 5. Try to add one more value, overflowing the buffer, verify no state change
         assert_storage_noop!(PriceAggregator::on_new_data(
@@ -382,17 +477,19 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_finalize(block_number_1);
 
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(native_currency_id).is_empty(),
             "Buffer must be empty after the finalization."
         );
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(intermediate_value_aggregator.count, 1);
 
         let average_amount_1 = CurrencyAmount::from_rational(15, 10);
         assert_eq!(intermediate_value_aggregator.total, average_amount_1);
 
         // 3. Move to the next block, but for this one no new data is added
-        let intermediate_value_snapshot = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_snapshot =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
 
         let block_number_2 = block_number_1 + 1;
         System::set_block_number(block_number_2);
@@ -401,7 +498,7 @@ fn on_finalize_updates_aggregated_data() {
         // No new data is added, everything must still work without breaking
         PriceAggregator::on_finalize(block_number_2);
         assert_eq!(
-            IntermediateValueAggregator::<Test>::get(),
+            IntermediateValueAggregator::<Test>::get(native_currency_id),
             intermediate_value_snapshot,
             "No new data was added, so the state must remain the same."
         );
@@ -416,7 +513,8 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_3);
         PriceAggregator::on_finalize(block_number_3);
 
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(
             intermediate_value_aggregator.count, 2,
             "Count must be 2 since we added only 2 new values."
@@ -438,7 +536,7 @@ fn on_finalize_updates_circular_buffer() {
         // 1. Advance just until limit block is reached, checking appropriate storage items along the way
         let mut total = CurrencyAmount::zero();
         let current_block = System::block_number();
-        let limit_block = IntermediateValueAggregator::<Test>::get().limit_block;
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
 
         for block in current_block..limit_block {
             // Add new data
@@ -449,12 +547,14 @@ fn on_finalize_updates_circular_buffer() {
             // Finalize the block
             PriceAggregator::on_finalize(block);
             assert_eq!(
-                IntermediateValueAggregator::<Test>::get().total,
+                IntermediateValueAggregator::<Test>::get(native_currency_id).total,
                 total,
                 "Check total is updated as expected."
             );
             assert!(
-                ValuesCircularBuffer::<Test>::get().buffer.is_empty(),
+                ValuesCircularBuffer::<Test>::get(native_currency_id)
+                    .buffer
+                    .is_empty(),
                 "Circular buffer is expected to remain empty until limit block is reached."
             );
 
@@ -472,7 +572,8 @@ fn on_finalize_updates_circular_buffer() {
         PriceAggregator::on_finalize(current_block);
 
         // Check that value aggregator is reset & new block limit is correct
-        let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+        let reset_intermediate_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
         assert_eq!(reset_intermediate_aggregator.count, 0);
         assert_eq!(
@@ -481,7 +582,7 @@ fn on_finalize_updates_circular_buffer() {
         );
 
         // Check that circular buffer was updated as expected
-        let circular_buffer = ValuesCircularBuffer::<Test>::get();
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
         let expected_average = total * CurrencyAmount::from_rational(1, limit_block as u128 - 1);
         assert_eq!(
             circular_buffer.buffer.clone().into_inner(),
@@ -492,6 +593,7 @@ fn on_finalize_updates_circular_buffer() {
         // Verify deposited event
         System::assert_last_event(RuntimeEvent::PriceAggregator(
             Event::AverageAggregatedValue {
+                currency: native_currency_id,
                 value: expected_average,
             },
         ));
@@ -504,6 +606,7 @@ fn circular_buffer_really_is_circular() {
         // 0. Init data
         let aggregation_duration = <Test as Config>::AggregationDuration::get();
         let circular_buffer_length: u32 = <Test as Config>::CircularBufferLength::get();
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
 
         fn advance_to_block(block: u32) {
             let dummy_account = 456;
@@ -526,16 +629,18 @@ fn circular_buffer_really_is_circular() {
         // 1. Fill up the circular buffer
         for x in 0..circular_buffer_length {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(circular_buffer.buffer.len(), x as usize + 1);
             assert_eq!(circular_buffer.head, (x + 1) % circular_buffer_length);
 
             // Check that intermediate aggregator is reset & limit block is updated
-            let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let reset_intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
             assert_eq!(reset_intermediate_aggregator.count, 0);
             assert_eq!(
@@ -547,11 +652,12 @@ fn circular_buffer_really_is_circular() {
         // 2. Continue adding the data, verify circular buffer is updated as expected
         for x in 0..circular_buffer_length * 3 {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(
                 circular_buffer.buffer.len(),
                 circular_buffer_length as usize
@@ -560,3 +666,548 @@ fn circular_buffer_really_is_circular() {
         }
     })
 }
+
+#[test]
+fn tracked_currencies_are_aggregated_independently() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 789;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let other_currency_id = CurrencyId::DOT;
+        assert!(
+            native_currency_id != other_currency_id
+                && PriceAggregator::is_tracked(other_currency_id),
+            "Sanity check."
+        );
+
+        // 1. Submit different values for both currencies, verify they are stored separately
+        let native_amount = CurrencyAmount::from_rational(5, 100);
+        let other_amount = CurrencyAmount::from_rational(55, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &native_amount);
+        PriceAggregator::on_new_data(&dummy_account, &other_currency_id, &other_amount);
+        PriceAggregator::on_new_data(&dummy_account, &other_currency_id, &other_amount);
+
+        assert_eq!(
+            current_block_values(native_currency_id),
+            vec![native_amount]
+        );
+        assert_eq!(
+            current_block_values(other_currency_id),
+            vec![other_amount, other_amount]
+        );
+
+        // 2. Advance until the limit block, submitting only the other currency values
+        let limit_block = IntermediateValueAggregator::<Test>::get(other_currency_id).limit_block;
+        let mut other_total = CurrencyAmount::zero();
+        for block in System::block_number()..=limit_block {
+            if block > 1 {
+                PriceAggregator::on_new_data(&dummy_account, &other_currency_id, &other_amount);
+            }
+            other_total.saturating_accrue(other_amount);
+            PriceAggregator::on_finalize(block);
+
+            let new_block = block + 1;
+            System::set_block_number(new_block);
+            PriceAggregator::on_initialize(new_block);
+        }
+
+        // 3. Verify each currency has its own moving average
+        let other_average = other_total * CurrencyAmount::from_rational(1, limit_block as u128);
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![native_amount]
+        );
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(other_currency_id)
+                .buffer
+                .into_inner(),
+            vec![other_average]
+        );
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency: native_currency_id,
+                value: native_amount,
+            },
+        ));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency: other_currency_id,
+                value: other_average,
+            },
+        ));
+
+        // 4. Verify price provider interfaces
+        assert_eq!(PriceAggregator::average_price(), native_amount);
+        assert_eq!(
+            PriceAggregator::average_price_of(other_currency_id),
+            Some(other_average)
+        );
+        assert_eq!(PriceAggregator::average_price_of(CurrencyId::SDN), None);
+    })
+}
+
+#[test]
+fn spot_and_twap_prices_are_updated() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 321;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let twap_window = <Test as Config>::TwapWindow::get();
+
+        fn finalize_and_advance() {
+            let block = System::block_number();
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        }
+
+        // 0. Initial sanity check
+        assert!(PriceAggregator::spot_price(native_currency_id).is_none());
+        assert!(PriceAggregator::twap_price(native_currency_id).is_none());
+
+        // 1. Block without any submitted values doesn't produce any value
+        finalize_and_advance();
+        assert!(BlockValuesCircularBuffer::<Test>::get(native_currency_id)
+            .buffer
+            .is_empty());
+        assert!(PriceAggregator::spot_price(native_currency_id).is_none());
+
+        // 2. Submit values, verify both spot & TWAP are updated
+        let amount_1 = CurrencyAmount::from_rational(10, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        finalize_and_advance();
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(amount_1)
+        );
+        assert_eq!(
+            PriceAggregator::twap_price(native_currency_id),
+            Some(amount_1)
+        );
+
+        let amount_2 = CurrencyAmount::from_rational(20, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_2);
+        finalize_and_advance();
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(amount_2)
+        );
+        assert_eq!(
+            PriceAggregator::twap_price(native_currency_id),
+            Some(CurrencyAmount::from_rational(15, 10))
+        );
+
+        // 3. Block without submitted values carries the last value forward
+        finalize_and_advance();
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(amount_2)
+        );
+        assert_eq!(
+            BlockValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![amount_1, amount_2, amount_2]
+        );
+
+        // 4. Spike in a single block only partially moves the TWAP, old values leave the window
+        let spike = CurrencyAmount::from_rational(30, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &spike);
+        finalize_and_advance();
+        assert_eq!(PriceAggregator::spot_price(native_currency_id), Some(spike));
+
+        for _ in 0..twap_window - 1 {
+            PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_2);
+            finalize_and_advance();
+        }
+        assert_eq!(
+            PriceAggregator::twap_price(native_currency_id),
+            Some(
+                (spike + amount_2 * CurrencyAmount::from_rational(twap_window as u128 - 1, 1))
+                    * CurrencyAmount::from_rational(1, twap_window as u128)
+            )
+        );
+
+        // 5. Untracked currency has no spot nor TWAP price
+        assert!(PriceAggregator::spot_price_of(CurrencyId::SDN).is_none());
+        assert!(PriceAggregator::twap_price_of(CurrencyId::SDN).is_none());
+    })
+}
+
+#[test]
+fn outlier_values_are_rejected() {
+    ExtBuilder::build().execute_with(|| {
+        OutlierThreshold::set(Perbill::from_percent(10));
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let (account_1, account_2, account_3) = (1, 2, 3);
+
+        let finalize_block = || {
+            let block = System::block_number();
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        };
+
+        // 1. Values are only checked once the block is finalized
+        let amount_1 = CurrencyAmount::from_rational(20, 10);
+        let amount_2 = CurrencyAmount::from_rational(21, 10);
+        let outlier = CurrencyAmount::from_rational(30, 10);
+        PriceAggregator::on_new_data(&account_1, &native_currency_id, &amount_1);
+        PriceAggregator::on_new_data(&account_2, &native_currency_id, &amount_2);
+        PriceAggregator::on_new_data(&account_3, &native_currency_id, &outlier);
+        assert_eq!(
+            current_block_values(native_currency_id),
+            vec![amount_1, amount_2, outlier]
+        );
+
+        // 2. Value deviating too much from the median of the block values is rejected, and the submitter is identified
+        let median = amount_2;
+        finalize_block();
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::OutlierRejected {
+            who: account_3,
+            currency: native_currency_id,
+            value: outlier,
+            median,
+            strikes: 1,
+        }));
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(CurrencyAmount::from_rational(205, 100)),
+            "Outlier must not be part of the block value."
+        );
+
+        // 3. Legitimate price movement reported by all the feeders is accepted
+        let events_count = System::events().len();
+        let amount_1 = CurrencyAmount::from_rational(40, 10);
+        let amount_2 = CurrencyAmount::from_rational(41, 10);
+        let amount_3 = CurrencyAmount::from_rational(42, 10);
+        PriceAggregator::on_new_data(&account_1, &native_currency_id, &amount_1);
+        PriceAggregator::on_new_data(&account_2, &native_currency_id, &amount_2);
+        PriceAggregator::on_new_data(&account_3, &native_currency_id, &amount_3);
+        finalize_block();
+
+        assert_eq!(System::events().len(), events_count);
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(amount_2)
+        );
+
+        // 4. Without the consensus of the feeders, all the values are kept
+        let amount_1 = CurrencyAmount::from_rational(10, 10);
+        let amount_2 = CurrencyAmount::from_rational(30, 10);
+        PriceAggregator::on_new_data(&account_1, &native_currency_id, &amount_1);
+        PriceAggregator::on_new_data(&account_2, &native_currency_id, &amount_2);
+        finalize_block();
+
+        assert_eq!(System::events().len(), events_count);
+        assert_eq!(
+            PriceAggregator::spot_price(native_currency_id),
+            Some(CurrencyAmount::from_rational(20, 10))
+        );
+        assert_eq!(OutlierStrikes::<Test>::get(account_1), 0);
+        assert_eq!(OutlierStrikes::<Test>::get(account_2), 0);
+    })
+}
+
+#[test]
+fn aggregated_price_reports_staleness() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 654;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        // 1. Untracked currency has no aggregated price
+        assert!(PriceAggregator::aggregated_price(CurrencyId::SDN).is_none());
+
+        // 2. Tracked currency without any processed value
+        assert_eq!(
+            PriceAggregator::aggregated_price(native_currency_id),
+            Some(AggregatedPrice {
+                average: CurrencyAmount::zero(),
+                spot: None,
+                twap: None,
+                staleness: None,
+            })
+        );
+        assert_eq!(
+            PriceAggregator::aggregated_prices()
+                .into_iter()
+                .map(|(currency, _)| currency)
+                .collect::<Vec<_>>(),
+            vec![CurrencyId::ASTR, CurrencyId::DOT]
+        );
+
+        // 3. Process a block value, verify staleness grows with each block without new values
+        let amount = CurrencyAmount::from_rational(7, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+        PriceAggregator::on_finalize(System::block_number());
+
+        for staleness in 0..3 {
+            let price = PriceAggregator::aggregated_price(native_currency_id).unwrap();
+            assert_eq!(price.spot, Some(amount));
+            assert_eq!(price.twap, Some(amount));
+            assert_eq!(price.staleness, Some(staleness));
+
+            let new_block = System::block_number() + 1;
+            System::set_block_number(new_block);
+            PriceAggregator::on_initialize(new_block);
+            PriceAggregator::on_finalize(new_block);
+        }
+    })
+}
+
+#[test]
+fn stale_price_is_detected_and_handled() {
+    ExtBuilder::build().execute_with(|| {
+        StalenessThreshold::set(2);
+        let dummy_account = 654;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        let average = CurrencyAmount::from_rational(5, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |buffer| buffer.add(average));
+
+        let advance_block = || {
+            let new_block = System::block_number() + 1;
+            System::set_block_number(new_block);
+            PriceAggregator::on_initialize(new_block);
+            PriceAggregator::on_finalize(new_block);
+        };
+        let stale_events = || {
+            System::events()
+                .into_iter()
+                .filter(|record| {
+                    matches!(
+                        record.event,
+                        RuntimeEvent::PriceAggregator(Event::PriceStale { .. })
+                    )
+                })
+                .count()
+        };
+
+        // 1. Currency without any processed value can't become stale
+        PriceAggregator::on_finalize(System::block_number());
+        for _ in 0..3 {
+            advance_block();
+        }
+        assert!(!PriceAggregator::is_stale(native_currency_id));
+        assert_eq!(stale_events(), 0);
+
+        // 2. Process a native value, price remains valid up to the threshold
+        let amount = CurrencyAmount::from_rational(7, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+        PriceAggregator::on_finalize(System::block_number());
+        let last_processed = System::block_number();
+
+        for _ in 0..2 {
+            advance_block();
+            assert!(!PriceAggregator::is_stale(native_currency_id));
+            assert_eq!(PriceAggregator::try_average_price(), Ok(average));
+        }
+
+        // 3. Price becomes stale once the threshold is exceeded, which is reported only once
+        advance_block();
+        assert!(PriceAggregator::is_stale(native_currency_id));
+        assert!(!PriceAggregator::is_stale(CurrencyId::DOT));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::PriceStale {
+            currency: native_currency_id,
+            last_processed,
+        }));
+
+        advance_block();
+        assert_eq!(stale_events(), 1);
+
+        // 4. Error behavior, last known price is provided where the error can't be reported
+        OnStalePrice::set(StalePriceBehavior::Error);
+        assert_eq!(PriceAggregator::try_average_price(), Err(PriceError::Stale));
+        assert_eq!(PriceAggregator::average_price(), average);
+
+        // 5. Fallback behavior, fallback price is provided instead of the stale one
+        let fallback = CurrencyAmount::from_rational(3, 10);
+        OnStalePrice::set(StalePriceBehavior::Fallback(fallback));
+        assert_eq!(PriceAggregator::try_average_price(), Ok(fallback));
+        assert_eq!(PriceAggregator::average_price(), fallback);
+
+        // 6. Price recovers once a valid value is processed again
+        let new_block = System::block_number() + 1;
+        System::set_block_number(new_block);
+        PriceAggregator::on_initialize(new_block);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+        PriceAggregator::on_finalize(new_block);
+
+        assert!(!PriceAggregator::is_stale(native_currency_id));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::PriceRecovered {
+            currency: native_currency_id,
+        }));
+        assert_eq!(PriceAggregator::try_average_price(), Ok(average));
+        assert_eq!(PriceAggregator::average_price(), average);
+    })
+}
+
+#[test]
+fn repeated_outliers_strike_out_the_feeder() {
+    ExtBuilder::build().execute_with(|| {
+        OutlierThreshold::set(Perbill::from_percent(10));
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let (feeder, other_feeder_1, other_feeder_2) = (1, 2, 3);
+        let max_strikes: u32 = <Test as Config>::MaxOutlierStrikes::get();
+
+        type Feeders = ExcludeStruckOut<Test, AllFeeders>;
+
+        let median = CurrencyAmount::from_rational(20, 10);
+        let outlier = CurrencyAmount::from_rational(30, 10);
+        let submit_block = |feeder_value: CurrencyAmount| {
+            PriceAggregator::on_new_data(&feeder, &native_currency_id, &feeder_value);
+            PriceAggregator::on_new_data(&other_feeder_1, &native_currency_id, &median);
+            PriceAggregator::on_new_data(&other_feeder_2, &native_currency_id, &median);
+
+            let block = System::block_number();
+            PriceAggregator::on_finalize(block);
+            System::set_block_number(block + 1);
+            PriceAggregator::on_initialize(block + 1);
+        };
+
+        // 1. Each outlier submission earns the feeder a strike
+        for strikes in 1..max_strikes {
+            submit_block(outlier);
+            System::assert_has_event(RuntimeEvent::PriceAggregator(Event::OutlierRejected {
+                who: feeder,
+                currency: native_currency_id,
+                value: outlier,
+                median,
+                strikes,
+            }));
+            assert!(!PriceAggregator::is_struck_out(&feeder));
+            assert!(Feeders::contains(&feeder));
+        }
+
+        // 2. Reaching the limit strikes the feeder out, and excludes it from the feeder set
+        submit_block(outlier);
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::FeederStruckOut {
+            who: feeder,
+        }));
+        assert!(PriceAggregator::is_struck_out(&feeder));
+        assert_eq!(StruckOutFeeders::get(), vec![feeder]);
+        assert!(!Feeders::contains(&feeder));
+        assert_eq!(
+            Feeders::sorted_members(),
+            vec![other_feeder_1, other_feeder_2]
+        );
+
+        // 3. Any further submission of the struck out feeder is ignored
+        PriceAggregator::on_new_data(&feeder, &native_currency_id, &median);
+        PriceAggregator::on_new_data(&feeder, &native_currency_id, &outlier);
+        assert!(CurrentBlockValues::<Test>::get(native_currency_id).is_empty());
+        assert_eq!(OutlierStrikes::<Test>::get(feeder), max_strikes);
+
+        // 4. Only the reinstate origin can reinstate the feeder
+        assert_noop!(
+            PriceAggregator::reinstate_feeder(RuntimeOrigin::signed(other_feeder_1), feeder),
+            BadOrigin
+        );
+        assert_noop!(
+            PriceAggregator::reinstate_feeder(RuntimeOrigin::root(), other_feeder_1),
+            Error::<Test>::NotStruckOut
+        );
+
+        assert_ok!(PriceAggregator::reinstate_feeder(
+            RuntimeOrigin::root(),
+            feeder
+        ));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::FeederReinstated {
+            who: feeder,
+        }));
+        assert!(!OutlierStrikes::<Test>::contains_key(feeder));
+        assert!(Feeders::contains(&feeder));
+
+        // 5. Reinstated feeder can submit values again
+        PriceAggregator::on_new_data(&feeder, &native_currency_id, &median);
+        assert_eq!(current_block_values(native_currency_id), vec![median]);
+    })
+}
+
+#[test]
+fn majority_of_feeders_is_never_struck_out() {
+    ExtBuilder::build().execute_with(|| {
+        FeederSet::set(vec![1, 2, 3, 4]);
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let max_strikes: u32 = <Test as Config>::MaxOutlierStrikes::get();
+        let median = CurrencyAmount::from_rational(20, 10);
+        let outlier = CurrencyAmount::from_rational(30, 10);
+
+        // 1. Striking out a single feeder keeps the majority active
+        OutlierStrikes::<Test>::insert(1, max_strikes - 1);
+        PriceAggregator::reject_outlier(&1, native_currency_id, outlier, median);
+        assert!(PriceAggregator::is_struck_out(&1));
+
+        // 2. Striking out another feeder would leave no active majority, so its strikes stay below the limit
+        OutlierStrikes::<Test>::insert(2, max_strikes - 1);
+        PriceAggregator::reject_outlier(&2, native_currency_id, outlier, median);
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::OutlierRejected {
+            who: 2,
+            currency: native_currency_id,
+            value: outlier,
+            median,
+            strikes: max_strikes - 1,
+        }));
+        assert!(!PriceAggregator::is_struck_out(&2));
+        assert_eq!(StruckOutFeeders::get(), vec![1]);
+
+        // 3. Once the feeder set grows, the feeder can be struck out
+        FeederSet::set(vec![1, 2, 3, 4, 5]);
+        PriceAggregator::reject_outlier(&2, native_currency_id, outlier, median);
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::FeederStruckOut {
+            who: 2,
+        }));
+        assert!(PriceAggregator::is_struck_out(&2));
+        assert_eq!(StruckOutFeeders::get(), vec![1, 2]);
+    })
+}
+
+#[test]
+fn block_values_history_is_queryable_by_block() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 654;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let history_length: u32 = <Test as Config>::PriceHistoryLength::get();
+
+        // 1. Process values in every other block
+        let mut expected = Vec::new();
+        for x in 1..=history_length + 1 {
+            let block = System::block_number();
+            let amount = CurrencyAmount::from_rational(10 + x as u128, 10);
+            PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+            PriceAggregator::on_finalize(block);
+            expected.push((block, amount));
+
+            // Skip a block without any values
+            let empty_block = block + 1;
+            System::set_block_number(empty_block);
+            PriceAggregator::on_initialize(empty_block);
+            PriceAggregator::on_finalize(empty_block);
+
+            System::set_block_number(empty_block + 1);
+            PriceAggregator::on_initialize(empty_block + 1);
+        }
+
+        // 2. Only the most recent values are kept, and blocks without processed values aren't recorded
+        expected.remove(0);
+        assert_eq!(PriceAggregator::price_history(native_currency_id), expected);
+        assert!(PriceAggregator::price_history(CurrencyId::DOT).is_empty());
+
+        // 3. Query the value in effect at a particular block
+        let (oldest_block, oldest_amount) = expected[0];
+        assert!(PriceAggregator::price_at(native_currency_id, oldest_block - 1).is_none());
+        assert_eq!(
+            PriceAggregator::price_at(native_currency_id, oldest_block),
+            Some((oldest_block, oldest_amount))
+        );
+        assert_eq!(
+            PriceAggregator::price_at(native_currency_id, oldest_block + 1),
+            Some((oldest_block, oldest_amount))
+        );
+        assert_eq!(
+            PriceAggregator::price_at(native_currency_id, System::block_number()),
+            expected.last().copied()
+        );
+        assert!(PriceAggregator::price_at(CurrencyId::DOT, System::block_number()).is_none());
+    })
+}
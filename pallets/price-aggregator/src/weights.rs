@@ -51,6 +51,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn process_block_aggregated_values() -> Weight;
 	fn process_intermediate_aggregated_values() -> Weight;
+	fn reinstate_feeder() -> Weight;
 }
 
 /// Weights for pallet_price_aggregator using the Substrate node and recommended hardware.
@@ -74,6 +75,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::OutlierStrikes` (r:1 w:1)
+	/// Proof: `PriceAggregator::OutlierStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn reinstate_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_561_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -96,4 +108,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::OutlierStrikes` (r:1 w:1)
+	/// Proof: `PriceAggregator::OutlierStrikes` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn reinstate_feeder() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_561_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
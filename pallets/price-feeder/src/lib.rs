@@ -0,0 +1,412 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Price Feeder Pallet
+//!
+//! ## Overview
+//!
+//! Offchain worker which fetches currency prices from HTTP feeds, and submits them to the oracle.
+//!
+//! ## Solution
+//!
+//! Governance maintains a list of HTTPS endpoints for each currency on-chain, via `set_price_feeds`.
+//! Each endpoint is described by its URL, and by the path to the price value within the returned JSON document,
+//! expressed as dot-separated object keys (e.g. `astar.usd` for `{"astar":{"usd":0.05}}`).
+//!
+//! Every `SubmissionInterval` blocks, the offchain worker fetches the price of each currency from all of its endpoints,
+//! and calculates the median of the successfully fetched values. Failing endpoints are simply ignored.
+//!
+//! The resulting prices are submitted using a signed `feed_values` transaction of the oracle pallet,
+//! i.e. through the same path as any other oracle member submission.
+//!
+//! ### Running A Feeder
+//!
+//! Node must be started with offchain workers enabled, and the feeder key (key type `orcl`) must be inserted into its keystore.
+//! The account derived from that key must be an oracle member, otherwise the submission will be rejected.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::{
+    offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+    pallet_prelude::*,
+};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+    offchain::{http, Duration},
+    traits::{Saturating, Zero},
+    FixedPointNumber,
+};
+use sp_std::prelude::*;
+
+use astar_primitives::oracle::{CurrencyId, Price};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "price-feeder";
+
+/// Maximum duration of a single HTTP request, in milliseconds.
+const HTTP_TIMEOUT_MS: u64 = 2_000;
+
+/// Key type of the feeder key, used to sign the price submissions.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"orcl");
+
+/// Feeder key crypto, based on `sr25519`.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::Signature as Sr25519Signature;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::Verify,
+        MultiSignature, MultiSigner,
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Feeder authority ID, used to sign the price submissions.
+    pub struct FeederAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for FeederAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+
+    impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+        for FeederAuthId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
+/// HTTP price feed endpoint.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(L))]
+pub struct FeedEndpoint<L: Get<u32>> {
+    /// URL of the endpoint, e.g. `https://api.coingecko.com/api/v3/simple/price?ids=astar&vs_currencies=usd`.
+    pub url: BoundedVec<u8, L>,
+    /// Path to the price value within the returned JSON document, as dot-separated object keys, e.g. `astar.usd`.
+    pub path: BoundedVec<u8, L>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config:
+        frame_system::Config
+        + orml_oracle::Config<OracleKey = CurrencyId, OracleValue = Price>
+        + CreateSignedTransaction<orml_oracle::Call<Self>>
+    {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Crypto of the feeder key, used to sign the price submissions.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Origin which can manage the price feeds.
+        type FeedOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum number of endpoints per currency.
+        #[pallet::constant]
+        type MaxEndpoints: Get<u32>;
+
+        /// Maximum length of the endpoint URL & price path.
+        #[pallet::constant]
+        type MaxUrlLength: Get<u32>;
+
+        /// Interval, expressed in number of blocks, at which prices are submitted by the offchain worker.
+        #[pallet::constant]
+        type SubmissionInterval: Get<BlockNumberFor<Self>>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Price feed endpoints of the currency have been set.
+        PriceFeedsSet {
+            currency: CurrencyId,
+            endpoints: u32,
+        },
+        /// Price feed endpoints of the currency have been removed.
+        PriceFeedsRemoved { currency: CurrencyId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Currency has no price feed endpoints.
+        NoPriceFeeds,
+    }
+
+    /// Price feed endpoints of each currency.
+    #[pallet::storage]
+    pub type PriceFeeds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<FeedEndpoint<T::MaxUrlLength>, T::MaxEndpoints>,
+        OptionQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            if !(now % T::SubmissionInterval::get()).is_zero() {
+                return;
+            }
+
+            let prices = Self::fetch_prices();
+            if prices.is_empty() {
+                return;
+            }
+
+            Self::submit_prices(prices);
+        }
+
+        fn integrity_test() {
+            assert!(T::MaxEndpoints::get() > 0);
+            assert!(T::MaxUrlLength::get() > 0);
+            assert!(!T::SubmissionInterval::get().is_zero());
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the price feed endpoints of the `currency`, replacing the existing ones.
+        ///
+        /// Providing no endpoints removes the price feeds of the `currency`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_price_feeds())]
+        pub fn set_price_feeds(
+            origin: OriginFor<T>,
+            currency: CurrencyId,
+            endpoints: BoundedVec<FeedEndpoint<T::MaxUrlLength>, T::MaxEndpoints>,
+        ) -> DispatchResult {
+            T::FeedOrigin::ensure_origin(origin)?;
+
+            if endpoints.is_empty() {
+                ensure!(
+                    PriceFeeds::<T>::contains_key(currency),
+                    Error::<T>::NoPriceFeeds
+                );
+                PriceFeeds::<T>::remove(currency);
+                Self::deposit_event(Event::<T>::PriceFeedsRemoved { currency });
+            } else {
+                let count = endpoints.len() as u32;
+                PriceFeeds::<T>::insert(currency, endpoints);
+                Self::deposit_event(Event::<T>::PriceFeedsSet {
+                    currency,
+                    endpoints: count,
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Fetch the median price of each currency from its endpoints.
+        ///
+        /// Currencies for which no price could be fetched are skipped.
+        pub(crate) fn fetch_prices() -> Vec<(CurrencyId, Price)> {
+            PriceFeeds::<T>::iter()
+                .filter_map(|(currency, endpoints)| {
+                    let mut prices: Vec<Price> = endpoints
+                        .iter()
+                        .filter_map(|endpoint| match Self::fetch_price(endpoint) {
+                            Ok(price) => Some(price),
+                            Err(error) => {
+                                log::warn!(
+                                    target: LOG_TARGET,
+                                    "Failed to fetch {:?} price from {:?}: {:?}",
+                                    currency,
+                                    sp_std::str::from_utf8(&endpoint.url).unwrap_or_default(),
+                                    error
+                                );
+                                None
+                            }
+                        })
+                        .collect();
+
+                    median(&mut prices).map(|price| (currency, price))
+                })
+                .collect()
+        }
+
+        /// Fetch the price from a single endpoint.
+        fn fetch_price(endpoint: &FeedEndpoint<T::MaxUrlLength>) -> Result<Price, &'static str> {
+            let url = sp_std::str::from_utf8(&endpoint.url).map_err(|_| "Invalid URL.")?;
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+
+            let pending = http::Request::get(url)
+                .deadline(deadline)
+                .send()
+                .map_err(|_| "Failed to send the request.")?;
+            let response = pending
+                .try_wait(deadline)
+                .map_err(|_| "Request deadline reached.")?
+                .map_err(|_| "Request failed.")?;
+
+            if response.code != 200 {
+                return Err("Unexpected response status code.");
+            }
+
+            let body = response.body().collect::<Vec<u8>>();
+            parse_price(&body, &endpoint.path).ok_or("Failed to parse the price.")
+        }
+
+        /// Submit the prices to the oracle, using a signed transaction.
+        pub(crate) fn submit_prices(prices: Vec<(CurrencyId, Price)>) {
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "No feeder key in the keystore, skipping the price submission."
+                );
+                return;
+            }
+
+            if prices.len() > T::MaxFeedValues::get() as usize {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Too many prices to submit, only the first {} will be submitted.",
+                    T::MaxFeedValues::get()
+                );
+            }
+            let values = BoundedVec::<_, T::MaxFeedValues>::truncate_from(prices);
+            match signer.send_signed_transaction(|_| orml_oracle::Call::feed_values {
+                values: values.clone(),
+            }) {
+                Some((account, Ok(()))) => {
+                    log::debug!(
+                        target: LOG_TARGET,
+                        "Submitted prices {:?} from {:?}.",
+                        values,
+                        account.id
+                    );
+                }
+                Some((account, Err(()))) => {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Failed to submit prices from {:?}.",
+                        account.id
+                    );
+                }
+                None => {
+                    log::error!(target: LOG_TARGET, "No account available to submit prices.");
+                }
+            }
+        }
+    }
+}
+
+/// Median of the `values`, or `None` if there are no values.
+pub(crate) fn median(values: &mut [Price]) -> Option<Price> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let mid = values.len() / 2;
+
+    if values.len() % 2 == 0 {
+        Some(
+            values[mid.saturating_sub(1)]
+                .saturating_add(values[mid])
+                .saturating_mul(Price::from_rational(1, 2)),
+        )
+    } else {
+        Some(values[mid])
+    }
+}
+
+/// Parse the price located at the dot-separated `path` in the JSON `body`.
+///
+/// Price can either be a JSON number or a string, e.g. `0.0523` or `"0.0523"`.
+pub(crate) fn parse_price(body: &[u8], path: &[u8]) -> Option<Price> {
+    let document: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let path = sp_std::str::from_utf8(path).ok()?;
+
+    let value = path
+        .split('.')
+        .filter(|key| !key.is_empty())
+        .try_fold(&document, |value, key| value.get(key))?;
+
+    match value {
+        serde_json::Value::Number(number) => parse_decimal(&serde_json::to_string(number).ok()?),
+        serde_json::Value::String(string) => parse_decimal(string),
+        _ => None,
+    }
+}
+
+/// Parse a non-negative decimal number, optionally in the exponent notation, e.g. `0.0523` or `5.23e-2`.
+pub(crate) fn parse_decimal(input: &str) -> Option<Price> {
+    let (mantissa, exponent) = match input.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (input, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let digits = integer
+        .chars()
+        .chain(fraction.chars())
+        .try_fold(0_u128, |acc, c| {
+            acc.checked_mul(10)?.checked_add(c.to_digit(10)? as u128)
+        })?;
+
+    let scale = (fraction.len() as i32).checked_sub(exponent)?;
+    if scale >= 0 {
+        Some(Price::from_rational(
+            digits,
+            10_u128.checked_pow(scale as u32)?,
+        ))
+    } else {
+        Price::checked_from_integer(digits.checked_mul(10_u128.checked_pow(scale.unsigned_abs())?)?)
+    }
+}
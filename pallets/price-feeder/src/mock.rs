@@ -0,0 +1,177 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_price_feeder, crypto::FeederAuthId};
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, ConstU64, SortedMembers},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::{sr25519::Signature, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{
+    testing::TestXt,
+    traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify},
+    BuildStorage,
+};
+
+use astar_primitives::oracle::{CurrencyId, DummyCombineData, Price};
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+type BlockNumber = u32;
+pub(crate) type Extrinsic = TestXt<RuntimeCall, ()>;
+
+pub(crate) const SUBMISSION_INTERVAL: BlockNumber = 5;
+pub(crate) const MAX_ENDPOINTS: u32 = 3;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Oracle: orml_oracle,
+        PriceFeeder: pallet_price_feeder,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+pub struct NoMembers;
+impl SortedMembers<AccountId> for NoMembers {
+    fn sorted_members() -> Vec<AccountId> {
+        Vec::new()
+    }
+}
+
+parameter_types! {
+    pub RootOperatorAccountId: AccountId = AccountId::from_raw([0xff; 32]);
+}
+
+impl orml_oracle::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnNewData = ();
+    type CombineData = DummyCombineData<Test>;
+    type Time = Timestamp;
+    type OracleKey = CurrencyId;
+    type OracleValue = Price;
+    type RootOperatorAccountId = RootOperatorAccountId;
+    type Members = NoMembers;
+    type MaxHasDispatchedSize = ConstU32<8>;
+    type WeightInfo = ();
+    type MaxFeedValues = ConstU32<2>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: <Signature as Verify>::Signer,
+        _account: AccountId,
+        nonce: u64,
+    ) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+        Some((call, (nonce, ())))
+    }
+}
+
+impl pallet_price_feeder::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = FeederAuthId;
+    type FeedOrigin = EnsureRoot<AccountId>;
+    type MaxEndpoints = ConstU32<MAX_ENDPOINTS>;
+    type MaxUrlLength = ConstU32<128>;
+    type SubmissionInterval = ConstU32<SUBMISSION_INTERVAL>;
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+        });
+
+        ext
+    }
+}
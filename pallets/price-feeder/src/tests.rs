@@ -0,0 +1,259 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::offchain::{
+    testing::{OffchainState, PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_io::TestExternalities;
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{traits::BadOrigin, RuntimeAppPublic};
+
+fn endpoint(url: &str, path: &str) -> FeedEndpoint<<Test as Config>::MaxUrlLength> {
+    FeedEndpoint {
+        url: BoundedVec::truncate_from(url.as_bytes().to_vec()),
+        path: BoundedVec::truncate_from(path.as_bytes().to_vec()),
+    }
+}
+
+fn offchain_ext(
+    offchain: TestOffchainExt,
+    pool: TestTransactionPoolExt,
+    with_key: bool,
+) -> TestExternalities {
+    let keystore = MemoryKeystore::new();
+    if with_key {
+        keystore
+            .sr25519_generate_new(crypto::Public::ID, Some("//Feeder"))
+            .unwrap();
+    }
+
+    let mut ext = ExtBuilder::build();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt::new(keystore));
+
+    ext
+}
+
+fn expect_request(state: &mut OffchainState, url: &str, response: &[u8]) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: url.into(),
+        response: Some(response.to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn parse_decimal_works() {
+    assert_eq!(parse_decimal("1"), Some(Price::from_u32(1)));
+    assert_eq!(
+        parse_decimal("0.0523"),
+        Some(Price::from_rational(523, 10_000))
+    );
+    assert_eq!(parse_decimal(".5"), Some(Price::from_rational(1, 2)));
+    assert_eq!(
+        parse_decimal("5.23e-2"),
+        Some(Price::from_rational(523, 10_000))
+    );
+    assert_eq!(parse_decimal("1.5E3"), Some(Price::from_u32(1500)));
+    assert_eq!(parse_decimal("12e0"), Some(Price::from_u32(12)));
+
+    assert_eq!(parse_decimal(""), None);
+    assert_eq!(parse_decimal("."), None);
+    assert_eq!(parse_decimal("-1.5"), None);
+    assert_eq!(parse_decimal("1.5.3"), None);
+    assert_eq!(parse_decimal("abc"), None);
+    assert_eq!(parse_decimal("1e"), None);
+}
+
+#[test]
+fn parse_price_works() {
+    let body = br#"{"astar":{"usd":0.0523},"polkadot":{"usd":"4.75"},"flag":true}"#;
+
+    assert_eq!(
+        parse_price(body, b"astar.usd"),
+        Some(Price::from_rational(523, 10_000))
+    );
+    assert_eq!(
+        parse_price(body, b"polkadot.usd"),
+        Some(Price::from_rational(475, 100))
+    );
+
+    assert_eq!(parse_price(body, b"astar.eur"), None);
+    assert_eq!(parse_price(body, b"astar"), None);
+    assert_eq!(parse_price(body, b"flag"), None);
+    assert_eq!(parse_price(b"not json", b"astar.usd"), None);
+}
+
+#[test]
+fn median_works() {
+    assert_eq!(median(&mut []), None);
+    assert_eq!(
+        median(&mut [Price::from_u32(3), Price::from_u32(1), Price::from_u32(2)]),
+        Some(Price::from_u32(2))
+    );
+    assert_eq!(
+        median(&mut [Price::from_u32(4), Price::from_u32(1)]),
+        Some(Price::from_rational(5, 2))
+    );
+}
+
+#[test]
+fn set_price_feeds_works() {
+    ExtBuilder::build().execute_with(|| {
+        let endpoints = BoundedVec::truncate_from(vec![endpoint("https://feed-1.io/astar", "usd")]);
+
+        // Only feed origin can manage the feeds
+        assert_noop!(
+            PriceFeeder::set_price_feeds(
+                RuntimeOrigin::signed(RootOperatorAccountId::get()),
+                CurrencyId::ASTR,
+                endpoints.clone()
+            ),
+            BadOrigin
+        );
+
+        // Set the feeds
+        assert_ok!(PriceFeeder::set_price_feeds(
+            RuntimeOrigin::root(),
+            CurrencyId::ASTR,
+            endpoints.clone()
+        ));
+        assert_eq!(PriceFeeds::<Test>::get(CurrencyId::ASTR), Some(endpoints));
+        System::assert_last_event(RuntimeEvent::PriceFeeder(Event::PriceFeedsSet {
+            currency: CurrencyId::ASTR,
+            endpoints: 1,
+        }));
+
+        // Remove the feeds
+        assert_ok!(PriceFeeder::set_price_feeds(
+            RuntimeOrigin::root(),
+            CurrencyId::ASTR,
+            BoundedVec::new()
+        ));
+        assert!(!PriceFeeds::<Test>::contains_key(CurrencyId::ASTR));
+        System::assert_last_event(RuntimeEvent::PriceFeeder(Event::PriceFeedsRemoved {
+            currency: CurrencyId::ASTR,
+        }));
+
+        // Can't remove non-existing feeds
+        assert_noop!(
+            PriceFeeder::set_price_feeds(
+                RuntimeOrigin::root(),
+                CurrencyId::ASTR,
+                BoundedVec::new()
+            ),
+            Error::<Test>::NoPriceFeeds
+        );
+    })
+}
+
+#[test]
+fn offchain_worker_submits_median_prices() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = offchain_ext(offchain, pool, true);
+
+    ext.execute_with(|| {
+        assert_ok!(PriceFeeder::set_price_feeds(
+            RuntimeOrigin::root(),
+            CurrencyId::ASTR,
+            BoundedVec::truncate_from(vec![
+                endpoint("https://feed-1.io/astar", "astar.usd"),
+                endpoint("https://feed-2.io/astar", "price"),
+                endpoint("https://feed-3.io/astar", "astar.usd"),
+            ])
+        ));
+        assert_ok!(PriceFeeder::set_price_feeds(
+            RuntimeOrigin::root(),
+            CurrencyId::DOT,
+            BoundedVec::truncate_from(vec![endpoint("https://feed-1.io/dot", "dot.usd")])
+        ));
+
+        // 1. Nothing is fetched outside of the submission interval
+        PriceFeeder::offchain_worker(SUBMISSION_INTERVAL - 1);
+        assert!(pool_state.read().transactions.is_empty());
+
+        // 2. Prices are fetched from all endpoints, failing endpoints are ignored
+        for currency in PriceFeeds::<Test>::iter_keys() {
+            let responses: Vec<(&str, &str)> = match currency {
+                CurrencyId::ASTR => vec![
+                    ("https://feed-1.io/astar", r#"{"astar":{"usd":0.05}}"#),
+                    ("https://feed-2.io/astar", r#"{"price":"0.07"}"#),
+                    ("https://feed-3.io/astar", "invalid"),
+                ],
+                _ => vec![("https://feed-1.io/dot", r#"{"dot":{"usd":4.5}}"#)],
+            };
+            for (url, response) in responses {
+                expect_request(&mut offchain_state.write(), url, response.as_bytes());
+            }
+        }
+
+        PriceFeeder::offchain_worker(SUBMISSION_INTERVAL);
+
+        // 3. Medianized prices are submitted through the oracle feed path
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(tx.signature.unwrap().0, 0);
+
+        let RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) = tx.call else {
+            panic!("Unexpected call: {:?}", tx.call);
+        };
+        let mut values = values.into_inner();
+        values.sort_by_key(|(currency, _)| currency.encode());
+        assert_eq!(
+            values,
+            vec![
+                (CurrencyId::ASTR, Price::from_rational(6, 100)),
+                (CurrencyId::DOT, Price::from_rational(45, 10)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_requires_feeder_key() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = offchain_ext(offchain, pool, false);
+
+    ext.execute_with(|| {
+        assert_ok!(PriceFeeder::set_price_feeds(
+            RuntimeOrigin::root(),
+            CurrencyId::ASTR,
+            BoundedVec::truncate_from(vec![endpoint("https://feed-1.io/astar", "usd")])
+        ));
+        expect_request(
+            &mut offchain_state.write(),
+            "https://feed-1.io/astar",
+            br#"{"usd":1}"#,
+        );
+
+        PriceFeeder::offchain_worker(SUBMISSION_INTERVAL);
+        assert!(pool_state.read().transactions.is_empty());
+    });
+}
@@ -0,0 +1,70 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_price_feeder
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-price-feeder --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/price-feeder/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_price_feeder.
+pub trait WeightInfo {
+	fn set_price_feeds() -> Weight;
+}
+
+/// Weights for pallet_price_feeder using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `PriceFeeder::PriceFeeds` (r:1 w:1)
+	/// Proof: `PriceFeeder::PriceFeeds` (`max_values`: None, `max_size`: Some(2590), added: 5065, mode: `MaxEncodedLen`)
+	fn set_price_feeds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `6055`
+		// Minimum execution time: 14_118_000 picoseconds.
+		Weight::from_parts(14_562_000, 6055)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `PriceFeeder::PriceFeeds` (r:1 w:1)
+	/// Proof: `PriceFeeder::PriceFeeds` (`max_values`: None, `max_size`: Some(2590), added: 5065, mode: `MaxEncodedLen`)
+	fn set_price_feeds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `6055`
+		// Minimum execution time: 14_118_000 picoseconds.
+		Weight::from_parts(14_562_000, 6055)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
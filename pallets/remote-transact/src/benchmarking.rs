@@ -0,0 +1,135 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+/// Assert that the last event equals the provided one.
+pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Destination used in benchmarks, registered with the remote transact configuration.
+fn registered_destination<T: Config>() -> VersionedLocation {
+    let destination = VersionedLocation::from(Location::parent());
+    Destinations::<T>::insert(&destination, transact_info());
+    destination
+}
+
+fn transact_info() -> RemoteTransactInfo {
+    RemoteTransactInfo {
+        fee_location: VersionedLocation::from(Location::here()),
+        extra_weight: Weight::from_parts(1_000_000_000, 64 * 1024),
+        max_weight: Weight::from_parts(10_000_000_000, 1024 * 1024),
+    }
+}
+
+/// Remote call of the maximum allowed size.
+fn worst_case_call<T: Config>() -> BoundedVec<u8, T::MaxCallSize> {
+    let call: Vec<u8> = vec![1; T::MaxCallSize::get() as usize];
+    call.try_into().expect("Size is within the limit.")
+}
+
+#[benchmarks()]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_destination() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let destination = VersionedLocation::from(Location::parent());
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            Box::new(destination.clone()),
+            transact_info(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DestinationSet {
+                destination,
+                info: transact_info(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn remove_destination() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let destination = registered_destination::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Box::new(destination.clone()));
+
+        assert_last_event::<T>(Event::<T>::DestinationRemoved { destination }.into());
+    }
+
+    #[benchmark]
+    fn transact() {
+        let caller: T::AccountId = whitelisted_caller();
+        let destination = registered_destination::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            Box::new(destination),
+            worst_case_call::<T>(),
+            1_000_000_000,
+            transact_info().max_weight,
+        );
+    }
+
+    #[benchmark]
+    fn transact_as_sovereign() {
+        let origin = T::SovereignOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let destination = registered_destination::<T>();
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            Box::new(destination),
+            worst_case_call::<T>(),
+            1_000_000_000,
+            transact_info().max_weight,
+        );
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
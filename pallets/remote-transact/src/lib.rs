@@ -0,0 +1,398 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Remote Transact Pallet
+//!
+//! ## Overview
+//!
+//! Helper pallet which builds & sends `Transact` XCM programs toward the configured destinations
+//! (relay chain, sibling parachains), so neither governance nor users need to hand-craft raw XCM
+//! in order to dispatch calls on, or manage assets of, a remote chain.
+//!
+//! ## Solution
+//!
+//! Each supported destination is registered by the `ManagerOrigin`, together with:
+//! * the asset used to pay for the execution on the destination (as seen from the destination),
+//! * the weight of the XCM instructions surrounding the `Transact` instruction,
+//! * the maximum weight a single remote call can require.
+//!
+//! Two flavors of remote transact are supported:
+//! * `transact` - the call is dispatched by the account **derived** from the local signed origin,
+//!   e.g. `HashedDescription` of the `(Parachain(id), AccountId32)` location on the destination.
+//!   Fees are paid from the derived account, which needs to be funded in advance.
+//! * `transact_as_sovereign` - the call is dispatched by the sovereign account of this chain on the destination.
+//!   Only `SovereignOrigin` is allowed to use it.
+//!
+//! The sent program has the following structure:
+//! ```ignore
+//! DescendOrigin(account)  // only in case of derived account
+//! WithdrawAsset(fee)
+//! BuyExecution { fees: fee, weight_limit: Limited(require_weight_at_most + extra_weight) }
+//! Transact { origin_kind: SovereignAccount, require_weight_at_most, call }
+//! RefundSurplus
+//! DepositAsset { assets: All, beneficiary: origin }
+//! ```
+//!
+//! Derived account of any local account on any registered destination can be queried via `derived_account`,
+//! e.g. in order to fund it before the first remote transact.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::Convert;
+use sp_std::{boxed::Box, vec};
+
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_executor::traits::ConvertLocation;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "remote-transact";
+
+/// Remote transact configuration of a destination.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct RemoteTransactInfo {
+    /// Location of the asset used to pay for the execution, as seen from the destination.
+    pub fee_location: VersionedLocation,
+    /// Weight of the XCM instructions surrounding the `Transact` instruction, on the destination.
+    pub extra_weight: Weight,
+    /// Maximum weight a single remote call can require.
+    pub max_weight: Weight,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Used to send the XCM programs to the destinations.
+        type XcmSender: SendXcm;
+
+        /// Location of this chain, used to express the origin as seen from the destination.
+        type UniversalLocation: Get<InteriorLocation>;
+
+        /// Converts local account into its (interior) location.
+        type AccountIdToLocation: Convert<Self::AccountId, Location>;
+
+        /// Converts location into account the same way the destinations do.
+        /// Used to compute the account derived from the local account on the destination.
+        type RemoteLocationToAccountId: ConvertLocation<Self::AccountId>;
+
+        /// Origin which can manage the supported destinations.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin which can dispatch remote calls as this chain's sovereign account.
+        type SovereignOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum length of the encoded remote call.
+        #[pallet::constant]
+        type MaxCallSize: Get<u32>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Destination has been registered, or its configuration has been updated.
+        DestinationSet {
+            destination: VersionedLocation,
+            info: RemoteTransactInfo,
+        },
+        /// Destination has been removed.
+        DestinationRemoved { destination: VersionedLocation },
+        /// Remote call has been sent to the destination, to be dispatched by the derived account of `account`.
+        TransactSent {
+            account: T::AccountId,
+            destination: VersionedLocation,
+            message_id: XcmHash,
+        },
+        /// Remote call has been sent to the destination, to be dispatched by the sovereign account.
+        SovereignTransactSent {
+            destination: VersionedLocation,
+            message_id: XcmHash,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Location cannot be converted into the latest XCM version.
+        BadVersion,
+        /// Destination isn't registered.
+        DestinationNotSupported,
+        /// Requested weight exceeds the maximum weight allowed for the destination.
+        WeightLimitExceeded,
+        /// Origin cannot be expressed as seen from the destination.
+        CannotReanchor,
+        /// Account cannot be derived for the origin on the destination.
+        CannotDeriveAccount,
+        /// Sending the XCM program to the destination has failed.
+        SendFailed,
+    }
+
+    /// Supported destinations, with their remote transact configuration.
+    ///
+    /// Destination is always stored in the latest XCM version.
+    #[pallet::storage]
+    pub type Destinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, VersionedLocation, RemoteTransactInfo, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register the `destination`, or update its remote transact configuration.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_destination())]
+        pub fn set_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+            info: RemoteTransactInfo,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            let info = RemoteTransactInfo {
+                fee_location: Self::latest_versioned_location(info.fee_location)?,
+                ..info
+            };
+            Destinations::<T>::insert(&destination, &info);
+
+            Self::deposit_event(Event::<T>::DestinationSet { destination, info });
+            Ok(())
+        }
+
+        /// Remove the `destination` from the supported destinations.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_destination())]
+        pub fn remove_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            ensure!(
+                Destinations::<T>::contains_key(&destination),
+                Error::<T>::DestinationNotSupported
+            );
+            Destinations::<T>::remove(&destination);
+
+            Self::deposit_event(Event::<T>::DestinationRemoved { destination });
+            Ok(())
+        }
+
+        /// Dispatch the encoded `call` on the `destination`, using the account derived from the caller.
+        ///
+        /// Execution is paid with `fee_amount` of the destination's fee asset, withdrawn from the derived account.
+        /// Any unused fee is deposited back into the derived account.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::transact())]
+        pub fn transact(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+            call: BoundedVec<u8, T::MaxCallSize>,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            let account_location = T::AccountIdToLocation::convert(account.clone());
+            let message_id = Self::do_transact(
+                &destination,
+                account_location,
+                call,
+                fee_amount,
+                require_weight_at_most,
+            )?;
+
+            Self::deposit_event(Event::<T>::TransactSent {
+                account,
+                destination,
+                message_id,
+            });
+            Ok(())
+        }
+
+        /// Dispatch the encoded `call` on the `destination`, using the sovereign account of this chain.
+        ///
+        /// Execution is paid with `fee_amount` of the destination's fee asset, withdrawn from the sovereign account.
+        /// Any unused fee is deposited back into the sovereign account.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::transact_as_sovereign())]
+        pub fn transact_as_sovereign(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+            call: BoundedVec<u8, T::MaxCallSize>,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+        ) -> DispatchResult {
+            T::SovereignOrigin::ensure_origin(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            let message_id = Self::do_transact(
+                &destination,
+                Location::here(),
+                call,
+                fee_amount,
+                require_weight_at_most,
+            )?;
+
+            Self::deposit_event(Event::<T>::SovereignTransactSent {
+                destination,
+                message_id,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Account derived from the local `account` on the `destination`.
+        ///
+        /// Remote calls sent via `transact` are dispatched by, and paid from, this account.
+        pub fn derived_account(
+            destination: &Location,
+            account: T::AccountId,
+        ) -> Result<T::AccountId, Error<T>> {
+            let origin =
+                Self::origin_on_destination(destination, T::AccountIdToLocation::convert(account))?;
+            T::RemoteLocationToAccountId::convert_location(&origin)
+                .ok_or(Error::<T>::CannotDeriveAccount)
+        }
+
+        /// Build the remote transact XCM program, to be executed on the `destination` on behalf of the `origin`.
+        ///
+        /// `origin` is the local interior location, `Here` in case of the sovereign account.
+        pub fn build_program(
+            destination: &Location,
+            origin: Location,
+            info: &RemoteTransactInfo,
+            call: BoundedVec<u8, T::MaxCallSize>,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+        ) -> Result<Xcm<()>, Error<T>> {
+            ensure!(
+                require_weight_at_most.all_lte(info.max_weight),
+                Error::<T>::WeightLimitExceeded
+            );
+
+            let fee_location = Location::try_from(info.fee_location.clone())
+                .map_err(|_| Error::<T>::BadVersion)?;
+            let fee: Asset = (fee_location, fee_amount).into();
+            let beneficiary = Self::origin_on_destination(destination, origin.clone())?;
+
+            let mut instructions = vec![];
+            if !origin.is_here() {
+                instructions.push(DescendOrigin(origin.interior));
+            }
+            instructions.extend([
+                WithdrawAsset(fee.clone().into()),
+                BuyExecution {
+                    fees: fee,
+                    weight_limit: Limited(require_weight_at_most.saturating_add(info.extra_weight)),
+                },
+                Transact {
+                    origin_kind: OriginKind::SovereignAccount,
+                    require_weight_at_most,
+                    call: call.into_inner().into(),
+                },
+                RefundSurplus,
+                DepositAsset {
+                    assets: Wild(AllCounted(1)),
+                    beneficiary,
+                },
+            ]);
+
+            Ok(Xcm(instructions))
+        }
+
+        /// Build & send the remote transact XCM program to the `destination`.
+        fn do_transact(
+            destination: &VersionedLocation,
+            origin: Location,
+            call: BoundedVec<u8, T::MaxCallSize>,
+            fee_amount: u128,
+            require_weight_at_most: Weight,
+        ) -> Result<XcmHash, Error<T>> {
+            let info =
+                Destinations::<T>::get(destination).ok_or(Error::<T>::DestinationNotSupported)?;
+            let destination =
+                Location::try_from(destination.clone()).map_err(|_| Error::<T>::BadVersion)?;
+
+            let message = Self::build_program(
+                &destination,
+                origin,
+                &info,
+                call,
+                fee_amount,
+                require_weight_at_most,
+            )?;
+
+            let (message_id, _) =
+                send_xcm::<T::XcmSender>(destination, message).map_err(|error| {
+                    log::error!(
+                        target: LOG_TARGET,
+                        "Failed to send remote transact program, error: {:?}",
+                        error
+                    );
+                    Error::<T>::SendFailed
+                })?;
+
+            Ok(message_id)
+        }
+
+        /// Express the local interior `origin` location as seen from the `destination`.
+        fn origin_on_destination(
+            destination: &Location,
+            origin: Location,
+        ) -> Result<Location, Error<T>> {
+            origin
+                .reanchored(destination, &T::UniversalLocation::get())
+                .map_err(|_| Error::<T>::CannotReanchor)
+        }
+
+        /// Convert the location into the latest XCM version.
+        fn latest_versioned_location(
+            location: VersionedLocation,
+        ) -> Result<VersionedLocation, Error<T>> {
+            Location::try_from(location)
+                .map(VersionedLocation::from)
+                .map_err(|_| Error::<T>::BadVersion)
+        }
+    }
+}
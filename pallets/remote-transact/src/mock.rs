@@ -0,0 +1,162 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_remote_transact, RemoteTransactInfo};
+
+use frame_support::{construct_runtime, parameter_types, traits::ConstU32, weights::Weight};
+use frame_system::EnsureRoot;
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, Convert, IdentityLookup},
+    BuildStorage,
+};
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_builder::{DescribeAllTerminal, DescribeFamily, HashedDescription};
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+pub(crate) type AccountId = sp_runtime::AccountId32;
+type BlockNumber = u32;
+
+pub(crate) const ALICE: AccountId = sp_runtime::AccountId32::new([1u8; 32]);
+pub(crate) const PARA_ID: u32 = 2000;
+pub(crate) const SIBLING_PARA_ID: u32 = 2001;
+pub(crate) const MAX_CALL_SIZE: u32 = 16;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        RemoteTransact: pallet_remote_transact,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+parameter_types! {
+    pub UniversalLocation: InteriorLocation =
+        [GlobalConsensus(NetworkId::Polkadot), Parachain(PARA_ID)].into();
+    pub static SentXcm: Vec<(Location, Xcm<()>)> = vec![];
+}
+
+/// Sender which only records the sent messages.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let ticket = (
+            destination.take().ok_or(SendError::MissingArgument)?,
+            message.take().ok_or(SendError::MissingArgument)?,
+        );
+        Ok((ticket, Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let message_id = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+        SentXcm::mutate(|sent| sent.push(ticket));
+        Ok(message_id)
+    }
+}
+
+pub struct AccountIdToLocation;
+impl Convert<AccountId, Location> for AccountIdToLocation {
+    fn convert(account: AccountId) -> Location {
+        AccountId32 {
+            network: None,
+            id: account.into(),
+        }
+        .into()
+    }
+}
+
+impl pallet_remote_transact::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmSender = TestSendXcm;
+    type UniversalLocation = UniversalLocation;
+    type AccountIdToLocation = AccountIdToLocation;
+    type RemoteLocationToAccountId =
+        HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type SovereignOrigin = EnsureRoot<AccountId>;
+    type MaxCallSize = ConstU32<MAX_CALL_SIZE>;
+    type WeightInfo = ();
+}
+
+/// Remote transact configuration used in tests.
+pub(crate) fn transact_info() -> RemoteTransactInfo {
+    RemoteTransactInfo {
+        fee_location: VersionedLocation::from(Location::here()),
+        extra_weight: Weight::from_parts(1_000, 100),
+        max_weight: Weight::from_parts(10_000, 1_000),
+    }
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            SentXcm::set(vec![]);
+        });
+
+        ext
+    }
+}
@@ -0,0 +1,315 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Destinations, Error, Event};
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, weights::Weight, BoundedVec};
+use parity_scale_codec::Encode;
+use xcm::{latest::prelude::*, v3, VersionedLocation};
+use xcm_executor::traits::ConvertLocation;
+
+fn parent() -> Box<VersionedLocation> {
+    Box::new(VersionedLocation::from(Location::parent()))
+}
+
+fn sibling() -> Box<VersionedLocation> {
+    Box::new(VersionedLocation::from(Location::new(
+        1,
+        [Parachain(SIBLING_PARA_ID)],
+    )))
+}
+
+fn remote_call() -> BoundedVec<u8, <Test as crate::Config>::MaxCallSize> {
+    vec![1, 2, 3].try_into().unwrap()
+}
+
+#[test]
+fn set_and_remove_destination_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Destination is stored in the latest XCM version
+        let destination = Box::new(VersionedLocation::V3(v3::Location::parent()));
+        assert_ok!(RemoteTransact::set_destination(
+            RuntimeOrigin::root(),
+            destination.clone(),
+            transact_info()
+        ));
+        System::assert_last_event(
+            Event::<Test>::DestinationSet {
+                destination: *parent(),
+                info: transact_info(),
+            }
+            .into(),
+        );
+        assert_eq!(Destinations::<Test>::get(*parent()), Some(transact_info()));
+
+        // Configuration can be updated
+        let mut info = transact_info();
+        info.max_weight = Weight::from_parts(20_000, 2_000);
+        assert_ok!(RemoteTransact::set_destination(
+            RuntimeOrigin::root(),
+            parent(),
+            info.clone()
+        ));
+        assert_eq!(Destinations::<Test>::get(*parent()), Some(info));
+
+        assert_ok!(RemoteTransact::remove_destination(
+            RuntimeOrigin::root(),
+            destination
+        ));
+        System::assert_last_event(
+            Event::<Test>::DestinationRemoved {
+                destination: *parent(),
+            }
+            .into(),
+        );
+        assert!(!Destinations::<Test>::contains_key(*parent()));
+    })
+}
+
+#[test]
+fn set_and_remove_destination_fails() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            RemoteTransact::set_destination(
+                RuntimeOrigin::signed(ALICE),
+                parent(),
+                transact_info()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            RemoteTransact::remove_destination(RuntimeOrigin::signed(ALICE), parent()),
+            BadOrigin
+        );
+        assert_noop!(
+            RemoteTransact::remove_destination(RuntimeOrigin::root(), parent()),
+            Error::<Test>::DestinationNotSupported
+        );
+    })
+}
+
+#[test]
+fn derived_account_matches_remote_conversion() {
+    ExtBuilder::build().execute_with(|| {
+        let account_junction = AccountId32 {
+            network: None,
+            id: ALICE.into(),
+        };
+        type RemoteConverter = <Test as crate::Config>::RemoteLocationToAccountId;
+
+        // Relay chain sees this chain as its child
+        assert_eq!(
+            RemoteTransact::derived_account(&Location::parent(), ALICE),
+            Ok(RemoteConverter::convert_location(&Location::new(
+                0,
+                [Parachain(PARA_ID), account_junction]
+            ))
+            .unwrap())
+        );
+
+        // Sibling parachains see this chain as their sibling
+        let sibling_account =
+            RemoteTransact::derived_account(&Location::new(1, [Parachain(SIBLING_PARA_ID)]), ALICE)
+                .unwrap();
+        assert_eq!(
+            sibling_account,
+            RemoteConverter::convert_location(&Location::new(
+                1,
+                [Parachain(PARA_ID), account_junction]
+            ))
+            .unwrap()
+        );
+        assert_ne!(sibling_account, ALICE);
+    })
+}
+
+#[test]
+fn transact_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(RemoteTransact::set_destination(
+            RuntimeOrigin::root(),
+            sibling(),
+            transact_info()
+        ));
+
+        let require_weight_at_most = Weight::from_parts(5_000, 500);
+        assert_ok!(RemoteTransact::transact(
+            RuntimeOrigin::signed(ALICE),
+            sibling(),
+            remote_call(),
+            100,
+            require_weight_at_most,
+        ));
+
+        let fee: Asset = (Location::here(), 100).into();
+        let expected_message = Xcm(vec![
+            DescendOrigin(
+                [AccountId32 {
+                    network: None,
+                    id: ALICE.into(),
+                }]
+                .into(),
+            ),
+            WithdrawAsset(fee.clone().into()),
+            BuyExecution {
+                fees: fee,
+                weight_limit: Limited(require_weight_at_most + transact_info().extra_weight),
+            },
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                require_weight_at_most,
+                call: vec![1, 2, 3].into(),
+            },
+            RefundSurplus,
+            DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Location::new(
+                    1,
+                    [
+                        Parachain(PARA_ID),
+                        AccountId32 {
+                            network: None,
+                            id: ALICE.into(),
+                        },
+                    ],
+                ),
+            },
+        ]);
+        let sent = SentXcm::get();
+        assert_eq!(
+            sent,
+            vec![(
+                Location::new(1, [Parachain(SIBLING_PARA_ID)]),
+                expected_message
+            )]
+        );
+
+        let message_id = sent[0].1.using_encoded(sp_io::hashing::blake2_256);
+        System::assert_last_event(
+            Event::<Test>::TransactSent {
+                account: ALICE,
+                destination: *sibling(),
+                message_id,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn transact_as_sovereign_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(RemoteTransact::set_destination(
+            RuntimeOrigin::root(),
+            parent(),
+            transact_info()
+        ));
+
+        let require_weight_at_most = Weight::from_parts(5_000, 500);
+        assert_ok!(RemoteTransact::transact_as_sovereign(
+            RuntimeOrigin::root(),
+            parent(),
+            remote_call(),
+            100,
+            require_weight_at_most,
+        ));
+
+        // No origin descend, and the refund goes back to the sovereign account
+        let sent = SentXcm::get();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, Location::parent());
+        assert!(matches!(sent[0].1 .0[0], WithdrawAsset(_)));
+        assert_eq!(
+            sent[0].1 .0.last(),
+            Some(&DepositAsset {
+                assets: Wild(AllCounted(1)),
+                beneficiary: Location::new(0, [Parachain(PARA_ID)]),
+            })
+        );
+
+        let message_id = sent[0].1.using_encoded(sp_io::hashing::blake2_256);
+        System::assert_last_event(
+            Event::<Test>::SovereignTransactSent {
+                destination: *parent(),
+                message_id,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn transact_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let require_weight_at_most = Weight::from_parts(5_000, 500);
+
+        // Destination isn't registered
+        assert_noop!(
+            RemoteTransact::transact(
+                RuntimeOrigin::signed(ALICE),
+                parent(),
+                remote_call(),
+                100,
+                require_weight_at_most,
+            ),
+            Error::<Test>::DestinationNotSupported
+        );
+
+        assert_ok!(RemoteTransact::set_destination(
+            RuntimeOrigin::root(),
+            parent(),
+            transact_info()
+        ));
+
+        // Requested weight exceeds the destination limit
+        assert_noop!(
+            RemoteTransact::transact(
+                RuntimeOrigin::signed(ALICE),
+                parent(),
+                remote_call(),
+                100,
+                transact_info().max_weight + Weight::from_parts(1, 0),
+            ),
+            Error::<Test>::WeightLimitExceeded
+        );
+
+        // Only sovereign origin can transact as sovereign account
+        assert_noop!(
+            RemoteTransact::transact_as_sovereign(
+                RuntimeOrigin::signed(ALICE),
+                parent(),
+                remote_call(),
+                100,
+                require_weight_at_most,
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            RemoteTransact::transact(
+                RuntimeOrigin::root(),
+                parent(),
+                remote_call(),
+                100,
+                require_weight_at_most,
+            ),
+            BadOrigin
+        );
+
+        assert!(SentXcm::get().is_empty());
+    })
+}
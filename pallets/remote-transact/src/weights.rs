@@ -0,0 +1,153 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_remote_transact
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-remote-transact --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/remote-transact/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_remote_transact.
+pub trait WeightInfo {
+	fn set_destination() -> Weight;
+	fn remove_destination() -> Weight;
+	fn transact() -> Weight;
+	fn transact_as_sovereign() -> Weight;
+}
+
+/// Weights for pallet_remote_transact using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `RemoteTransact::Destinations` (r:0 w:1)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_214_000 picoseconds.
+		Weight::from_parts(10_563_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:1)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3608`
+		// Minimum execution time: 12_751_000 picoseconds.
+		Weight::from_parts(13_102_000, 3608)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:0)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn transact() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3738`
+		// Minimum execution time: 41_885_000 picoseconds.
+		Weight::from_parts(43_017_000, 3738)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:0)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn transact_as_sovereign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3738`
+		// Minimum execution time: 38_406_000 picoseconds.
+		Weight::from_parts(39_472_000, 3738)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `RemoteTransact::Destinations` (r:0 w:1)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_214_000 picoseconds.
+		Weight::from_parts(10_563_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:1)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3608`
+		// Minimum execution time: 12_751_000 picoseconds.
+		Weight::from_parts(13_102_000, 3608)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:0)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn transact() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3738`
+		// Minimum execution time: 41_885_000 picoseconds.
+		Weight::from_parts(43_017_000, 3738)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RemoteTransact::Destinations` (r:1 w:0)
+	/// Proof: `RemoteTransact::Destinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn transact_as_sovereign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		//  Estimated: `3738`
+		// Minimum execution time: 38_406_000 picoseconds.
+		Weight::from_parts(39_472_000, 3738)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
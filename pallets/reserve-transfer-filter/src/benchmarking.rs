@@ -0,0 +1,90 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use xcm::latest::prelude::*;
+
+/// Assert that the last event equals the provided one.
+pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks()]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_filter_mode() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, FilterMode::Whitelist);
+
+        assert_last_event::<T>(
+            Event::<T>::FilterModeSet {
+                mode: FilterMode::Whitelist,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn allow_destination() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let destination = VersionedLocation::from(Location::new(1, [Parachain(2000)]));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Box::new(destination.clone()));
+
+        assert_last_event::<T>(Event::<T>::DestinationAllowed { destination }.into());
+    }
+
+    #[benchmark]
+    fn disallow_destination() {
+        let destination = VersionedLocation::from(Location::new(1, [Parachain(2000)]));
+        AllowedDestinations::<T>::insert(&destination, ());
+
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Box::new(destination.clone()));
+
+        assert_last_event::<T>(Event::<T>::DestinationDisallowed { destination }.into());
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
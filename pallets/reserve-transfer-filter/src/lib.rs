@@ -0,0 +1,243 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Reserve Transfer Filter Pallet
+//!
+//! ## Overview
+//!
+//! Governance managed whitelist of destinations to which reserve transfers may be initiated from this chain.
+//!
+//! ## Solution
+//!
+//! The pallet implements `Contains<Location>`, and is supposed to be plugged into the transfer configuration,
+//! e.g. `orml-xtokens` locations filter, or the call filter for `pallet-xcm` transfer calls.
+//!
+//! The filter can be in one of the two modes:
+//! * `AllowAll` - transfers to any destination are allowed. This is the default mode, for backward compatibility.
+//! * `Whitelist` - transfers are only allowed to the whitelisted destinations.
+//!
+//! A destination is considered whitelisted if it, or any of its prefixes, is in the whitelist.
+//! E.g. if `{parents: 1, interior: X1(Parachain(2000))}` is whitelisted, transfers to any account on that parachain are allowed.
+//!
+//! Whitelisted destinations are stored in the latest XCM version. Developers must ensure to properly migrate legacy versions
+//! to the latest one when XCM version is bumped.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use sp_std::boxed::Box;
+use xcm::{latest::Location, VersionedLocation};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Mode of the reserve transfer destination filter.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub enum FilterMode {
+    /// Transfers to any destination are allowed.
+    #[default]
+    AllowAll,
+    /// Transfers are only allowed to the whitelisted destinations.
+    Whitelist,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin which can manage the filter mode and the whitelist.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of whitelisted destinations.
+        #[pallet::constant]
+        type MaxAllowedDestinations: Get<u32>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Filter mode has been changed.
+        FilterModeSet { mode: FilterMode },
+        /// Destination has been added to the whitelist.
+        DestinationAllowed { destination: VersionedLocation },
+        /// Destination has been removed from the whitelist.
+        DestinationDisallowed { destination: VersionedLocation },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Destination location couldn't be converted to the latest XCM version.
+        BadVersion,
+        /// Destination is already whitelisted.
+        AlreadyAllowed,
+        /// Destination isn't whitelisted.
+        NotAllowed,
+        /// Maximum number of whitelisted destinations has been reached.
+        TooManyAllowedDestinations,
+    }
+
+    /// Current mode of the filter.
+    #[pallet::storage]
+    pub type Mode<T: Config> = StorageValue<_, FilterMode, ValueQuery>;
+
+    /// Whitelisted reserve transfer destinations.
+    #[pallet::storage]
+    pub type AllowedDestinations<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, VersionedLocation, (), OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(T::MaxAllowedDestinations::get() > 0);
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the filter mode.
+        ///
+        /// Switching to `Whitelist` mode with an empty whitelist disables reserve transfers to all destinations.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_filter_mode())]
+        pub fn set_filter_mode(origin: OriginFor<T>, mode: FilterMode) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Mode::<T>::put(mode);
+
+            Self::deposit_event(Event::<T>::FilterModeSet { mode });
+            Ok(())
+        }
+
+        /// Add the destination to the whitelist.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::allow_destination())]
+        pub fn allow_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            ensure!(
+                !AllowedDestinations::<T>::contains_key(&destination),
+                Error::<T>::AlreadyAllowed
+            );
+            ensure!(
+                AllowedDestinations::<T>::count() < T::MaxAllowedDestinations::get(),
+                Error::<T>::TooManyAllowedDestinations
+            );
+
+            AllowedDestinations::<T>::insert(&destination, ());
+
+            Self::deposit_event(Event::<T>::DestinationAllowed { destination });
+            Ok(())
+        }
+
+        /// Remove the destination from the whitelist.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::disallow_destination())]
+        pub fn disallow_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let destination = Self::latest_versioned_location(*destination)?;
+            ensure!(
+                AllowedDestinations::<T>::contains_key(&destination),
+                Error::<T>::NotAllowed
+            );
+
+            AllowedDestinations::<T>::remove(&destination);
+
+            Self::deposit_event(Event::<T>::DestinationDisallowed { destination });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if reserve transfers to the `destination` are allowed, `false` otherwise.
+        pub fn is_allowed_destination(destination: &Location) -> bool {
+            match Mode::<T>::get() {
+                FilterMode::AllowAll => true,
+                FilterMode::Whitelist => {
+                    // Check the destination itself, and all of its prefixes, starting from the longest one.
+                    let mut prefix = destination.clone();
+                    loop {
+                        if AllowedDestinations::<T>::contains_key(VersionedLocation::from(
+                            prefix.clone(),
+                        )) {
+                            return true;
+                        }
+                        if prefix.take_last().is_none() {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        /// `true` if reserve transfers to the versioned `destination` are allowed, `false` otherwise.
+        ///
+        /// Destinations which can't be converted into the latest XCM version are only allowed in `AllowAll` mode.
+        pub fn is_allowed_versioned_destination(destination: &VersionedLocation) -> bool {
+            match Location::try_from(destination.clone()) {
+                Ok(destination) => Self::is_allowed_destination(&destination),
+                Err(_) => Mode::<T>::get() == FilterMode::AllowAll,
+            }
+        }
+
+        /// Convert the location into the latest XCM version.
+        fn latest_versioned_location(
+            location: VersionedLocation,
+        ) -> Result<VersionedLocation, Error<T>> {
+            Location::try_from(location)
+                .map(VersionedLocation::from)
+                .map_err(|_| Error::<T>::BadVersion)
+        }
+    }
+
+    impl<T: Config> Contains<Location> for Pallet<T> {
+        fn contains(destination: &Location) -> bool {
+            Self::is_allowed_destination(destination)
+        }
+    }
+}
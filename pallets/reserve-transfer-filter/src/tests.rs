@@ -0,0 +1,224 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, AllowedDestinations, Error, Event, FilterMode, Mode};
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Contains};
+use xcm::{latest::prelude::*, VersionedLocation};
+
+fn sibling(para_id: u32) -> Location {
+    Location::new(1, [Parachain(para_id)])
+}
+
+fn sibling_account(para_id: u32) -> Location {
+    Location::new(
+        1,
+        [
+            Parachain(para_id),
+            AccountId32 {
+                network: None,
+                id: [1; 32],
+            },
+        ],
+    )
+}
+
+fn allow(location: Location) {
+    assert_ok!(ReserveTransferFilter::allow_destination(
+        RuntimeOrigin::signed(MANAGER_ACCOUNT),
+        Box::new(location.into_versioned())
+    ));
+}
+
+#[test]
+fn all_destinations_allowed_by_default() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Mode::<Test>::get(), FilterMode::AllowAll);
+
+        assert!(ReserveTransferFilter::contains(&Location::parent()));
+        assert!(ReserveTransferFilter::contains(&sibling_account(2000)));
+
+        // Whitelist is ignored while all destinations are allowed
+        allow(sibling(2000));
+        assert!(ReserveTransferFilter::contains(&sibling_account(3000)));
+    });
+}
+
+#[test]
+fn set_filter_mode_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(ReserveTransferFilter::set_filter_mode(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            FilterMode::Whitelist
+        ));
+        System::assert_last_event(
+            Event::<Test>::FilterModeSet {
+                mode: FilterMode::Whitelist,
+            }
+            .into(),
+        );
+        assert_eq!(Mode::<Test>::get(), FilterMode::Whitelist);
+
+        // Empty whitelist disallows all destinations
+        assert!(!ReserveTransferFilter::contains(&Location::parent()));
+        assert!(!ReserveTransferFilter::contains(&sibling_account(2000)));
+
+        assert_ok!(ReserveTransferFilter::set_filter_mode(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            FilterMode::AllowAll
+        ));
+        assert!(ReserveTransferFilter::contains(&sibling_account(2000)));
+    });
+}
+
+#[test]
+fn whitelisted_destinations_are_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(ReserveTransferFilter::set_filter_mode(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            FilterMode::Whitelist
+        ));
+
+        allow(sibling(2000));
+        System::assert_last_event(
+            Event::<Test>::DestinationAllowed {
+                destination: sibling(2000).into_versioned(),
+            }
+            .into(),
+        );
+
+        // Whitelisted chain, and any location within it, is allowed
+        assert!(ReserveTransferFilter::contains(&sibling(2000)));
+        assert!(ReserveTransferFilter::contains(&sibling_account(2000)));
+
+        // Other destinations aren't allowed
+        assert!(!ReserveTransferFilter::contains(&sibling_account(3000)));
+        assert!(!ReserveTransferFilter::contains(&Location::parent()));
+        assert!(!ReserveTransferFilter::contains(&Location::new(
+            1,
+            [AccountId32 {
+                network: None,
+                id: [1; 32],
+            }]
+        )));
+    });
+}
+
+#[test]
+fn disallow_destination_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(ReserveTransferFilter::set_filter_mode(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            FilterMode::Whitelist
+        ));
+        allow(sibling(2000));
+        assert!(ReserveTransferFilter::contains(&sibling_account(2000)));
+
+        assert_ok!(ReserveTransferFilter::disallow_destination(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            Box::new(sibling(2000).into_versioned())
+        ));
+        System::assert_last_event(
+            Event::<Test>::DestinationDisallowed {
+                destination: sibling(2000).into_versioned(),
+            }
+            .into(),
+        );
+
+        assert!(!ReserveTransferFilter::contains(&sibling_account(2000)));
+        assert_eq!(AllowedDestinations::<Test>::count(), 0);
+    });
+}
+
+#[test]
+fn allow_destination_stores_latest_version() {
+    ExtBuilder::build().execute_with(|| {
+        let v3_location = xcm::v3::MultiLocation::new(1, xcm::v3::Junction::Parachain(2000));
+        assert_ok!(ReserveTransferFilter::allow_destination(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            Box::new(VersionedLocation::V3(v3_location))
+        ));
+
+        assert!(AllowedDestinations::<Test>::contains_key(
+            sibling(2000).into_versioned()
+        ));
+
+        // Same destination in the latest version is already allowed
+        assert_noop!(
+            ReserveTransferFilter::allow_destination(
+                RuntimeOrigin::signed(MANAGER_ACCOUNT),
+                Box::new(sibling(2000).into_versioned())
+            ),
+            Error::<Test>::AlreadyAllowed
+        );
+    });
+}
+
+#[test]
+fn allow_destination_fails_when_limit_reached() {
+    ExtBuilder::build().execute_with(|| {
+        for para_id in 0..MAX_ALLOWED_DESTINATIONS {
+            allow(sibling(para_id));
+        }
+
+        assert_noop!(
+            ReserveTransferFilter::allow_destination(
+                RuntimeOrigin::signed(MANAGER_ACCOUNT),
+                Box::new(sibling(MAX_ALLOWED_DESTINATIONS).into_versioned())
+            ),
+            Error::<Test>::TooManyAllowedDestinations
+        );
+    });
+}
+
+#[test]
+fn disallow_destination_fails_if_not_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            ReserveTransferFilter::disallow_destination(
+                RuntimeOrigin::signed(MANAGER_ACCOUNT),
+                Box::new(sibling(2000).into_versioned())
+            ),
+            Error::<Test>::NotAllowed
+        );
+    });
+}
+
+#[test]
+fn calls_fail_for_bad_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            ReserveTransferFilter::set_filter_mode(RuntimeOrigin::signed(2), FilterMode::Whitelist),
+            BadOrigin
+        );
+        assert_noop!(
+            ReserveTransferFilter::allow_destination(
+                RuntimeOrigin::signed(2),
+                Box::new(sibling(2000).into_versioned())
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            ReserveTransferFilter::disallow_destination(
+                RuntimeOrigin::signed(2),
+                Box::new(sibling(2000).into_versioned())
+            ),
+            BadOrigin
+        );
+    });
+}
@@ -0,0 +1,122 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_reserve_transfer_filter
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-reserve-transfer-filter --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/reserve-transfer-filter/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_reserve_transfer_filter.
+pub trait WeightInfo {
+	fn set_filter_mode() -> Weight;
+	fn allow_destination() -> Weight;
+	fn disallow_destination() -> Weight;
+}
+
+/// Weights for pallet_reserve_transfer_filter using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ReserveTransferFilter::Mode` (r:0 w:1)
+	/// Proof: `ReserveTransferFilter::Mode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_filter_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_120_000 picoseconds.
+		Weight::from_parts(5_347_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ReserveTransferFilter::AllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::AllowedDestinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ReserveTransferFilter::CounterForAllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::CounterForAllowedDestinations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn allow_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 13_211_000 picoseconds.
+		Weight::from_parts(13_618_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ReserveTransferFilter::AllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::AllowedDestinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ReserveTransferFilter::CounterForAllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::CounterForAllowedDestinations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn disallow_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `3591`
+		// Minimum execution time: 14_027_000 picoseconds.
+		Weight::from_parts(14_402_000, 3591)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `ReserveTransferFilter::Mode` (r:0 w:1)
+	/// Proof: `ReserveTransferFilter::Mode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_filter_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_120_000 picoseconds.
+		Weight::from_parts(5_347_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ReserveTransferFilter::AllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::AllowedDestinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ReserveTransferFilter::CounterForAllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::CounterForAllowedDestinations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn allow_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 13_211_000 picoseconds.
+		Weight::from_parts(13_618_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ReserveTransferFilter::AllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::AllowedDestinations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ReserveTransferFilter::CounterForAllowedDestinations` (r:1 w:1)
+	/// Proof: `ReserveTransferFilter::CounterForAllowedDestinations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn disallow_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `3591`
+		// Minimum execution time: 14_027_000 picoseconds.
+		Weight::from_parts(14_402_000, 3591)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
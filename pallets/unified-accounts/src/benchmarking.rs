@@ -57,6 +57,8 @@ mod benchmarks {
             Event::<T>::AccountClaimed {
                 account_id: caller_clone,
                 evm_address,
+                claimed_at: frame_system::Pallet::<T>::block_number(),
+                method: ClaimMethod::Signature,
             }
             .into(),
         );
@@ -79,6 +81,49 @@ mod benchmarks {
             Event::<T>::AccountClaimed {
                 account_id: caller,
                 evm_address,
+                claimed_at: frame_system::Pallet::<T>::block_number(),
+                method: ClaimMethod::Default,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn claim_secondary_evm_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+        let evm_address = Pallet::<T>::eth_address(&eth_secret_key);
+        let signature = Pallet::<T>::eth_sign_prehash(
+            &Pallet::<T>::build_signing_payload(&caller),
+            &eth_secret_key,
+        )
+        .into();
+
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get() * 2
+        ));
+        // claim primary mapping
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        // fill up secondary addresses to have the worst case
+        let secondary_addresses: Vec<EvmAddress> = (1..T::MaxSecondaryAddresses::get())
+            .map(|idx| EvmAddress::from_low_u64_be(idx.into()))
+            .collect();
+        SecondaryEvmAddresses::<T>::insert(
+            &caller,
+            BoundedVec::try_from(secondary_addresses).expect("Within the limit; qed."),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), evm_address, signature);
+
+        assert_last_event::<T>(
+            Event::<T>::SecondaryAccountClaimed {
+                account_id: caller,
+                evm_address,
             }
             .into(),
         );
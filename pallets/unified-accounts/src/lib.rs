@@ -31,6 +31,7 @@
 //! connect their evm address to have a unified experience across the different VMs.
 //! - Connect evm address you control
 //! - Connect default evm address
+//! - Connect additional (secondary) evm addresses you control
 //!
 //! ## Interface
 //!
@@ -38,6 +39,21 @@
 //!    account id given that no prior mapping exists for both and signature provided is valid.
 //! * `claim_default_evm_address`: Creates the double mapping with default evm address given that
 //!    no prior mapping exists.
+//! * `claim_secondary_evm_address`: Maps an additional evm address to the caller account given that
+//!    caller already has a primary mapping, signature provided is valid and the limit of secondary
+//!    addresses isn't reached.
+//!
+//! ## Secondary Addresses
+//! Besides the primary evm address, every native account can claim up to
+//! [`MaxSecondaryAddresses`](`crate::Config::MaxSecondaryAddresses`) secondary evm addresses, e.g. to keep
+//! separate hot & cold keys while retaining a single on-chain identity. Secondary addresses are resolved to the
+//! same native account (`EvmAddress => AccountId`), while the native account always resolves to its primary
+//! evm address (`AccountId => EvmAddress`).
+//!
+//! ## Claim Metadata
+//! For every created mapping, the block number and the claim method (explicit signature or default address)
+//! are recorded in [`ClaimMetadata`]. This allows dApps to enforce policies like _mapping must be older than N blocks_,
+//! protecting them against fresh-mapping attacks. Mappings created before metadata was introduced have no metadata.
 //!
 //! ## Storage Fee
 //! User is also charged a storage fee [`AccountMappingStorageFee`](`crate::Config::AccountMappingStorageFee`)
@@ -84,7 +100,7 @@ use sp_runtime::{
     traits::{LookupError, StaticLookup, Zero},
     MultiAddress,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, prelude::*};
 
 pub use pallet::*;
 
@@ -99,6 +115,24 @@ mod tests;
 /// ECDSA Signature type, with last bit for recovering address
 type EvmSignature = [u8; 65];
 
+/// Method used to create the account mapping.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ClaimMethod {
+    /// Evm address ownership was proven with an explicit signature.
+    Signature,
+    /// Default evm address derived from the native account was claimed.
+    Default,
+}
+
+/// Metadata recorded when the account mapping is created.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClaimInfo<BlockNumber> {
+    /// Block number in which the mapping was created.
+    pub claimed_at: BlockNumber,
+    /// Method used to create the mapping.
+    pub method: ClaimMethod,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -118,10 +152,14 @@ pub mod pallet {
         #[pallet::constant]
         type ChainId: Get<u64>;
         /// The amount of currency needed for mappings to be added.
-        /// Two storage items with values sizes, sizeof(AccountId) and sizeof(H160)
-        /// respectively
+        /// Three storage items with values sizes, sizeof(AccountId), sizeof(H160)
+        /// and sizeof(ClaimInfo) respectively
         #[pallet::constant]
         type AccountMappingStorageFee: Get<Balance>;
+        /// Maximum number of secondary evm addresses which can be mapped to a single native account.
+        /// Storage fee for each of them is the same as for the primary mapping.
+        #[pallet::constant]
+        type MaxSecondaryAddresses: Get<u32>;
         /// Weight information for the extrinsics in this module
         type WeightInfo: WeightInfo;
     }
@@ -136,6 +174,10 @@ pub mod pallet {
         InvalidSignature,
         /// Funds unavailable to claim account
         FundsUnavailable,
+        /// Account has no primary evm address mapped
+        NoPrimaryMapping,
+        /// Maximum number of secondary evm addresses reached
+        TooManySecondaryAddresses,
     }
 
     #[pallet::event]
@@ -146,6 +188,14 @@ pub mod pallet {
         AccountClaimed {
             account_id: T::AccountId,
             evm_address: EvmAddress,
+            claimed_at: BlockNumberFor<T>,
+            method: ClaimMethod,
+        },
+        /// Secondary evm address claimed.
+        /// Evm address is resolved to the native account
+        SecondaryAccountClaimed {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
         },
     }
 
@@ -161,6 +211,32 @@ pub mod pallet {
     pub type NativeToEvm<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+    /// Claim metadata for native accounts
+    /// ClaimMetadata: AccountId => Option<ClaimInfo>
+    #[pallet::storage]
+    pub type ClaimMetadata<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ClaimInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Secondary evm addresses for native accounts
+    /// SecondaryEvmAddresses: AccountId => BoundedVec<EvmAddress>
+    #[pallet::storage]
+    pub type SecondaryEvmAddresses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<EvmAddress, T::MaxSecondaryAddresses>,
+        ValueQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()?;
+            Ok(())
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Claim account mapping between Substrate account and Evm address.
@@ -201,29 +277,10 @@ pub mod pallet {
             // charge the storage fee
             Self::charge_storage_fee(&who)?;
 
-            // Check if the default account id already exists for this evm address
-            let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
-            if frame_system::Pallet::<T>::account_exists(&default_account_id) {
-                // Transfer all the free native balance from old account id to the newly
-                // since this `default_account_id` will no longer be connected to evm address
-                // and users cannot access it.
-                // For the reset of the assets types (like XC20, etc) that should be handled by UI.
-                T::Currency::transfer(
-                    &default_account_id,
-                    &who,
-                    T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
-                    Expendable,
-                )?;
-            }
+            Self::transfer_default_account_balance(&who, &evm_address)?;
 
             // create double mappings for the pair
-            EvmToNative::<T>::insert(&evm_address, &who);
-            NativeToEvm::<T>::insert(&who, &evm_address);
-
-            Self::deposit_event(Event::AccountClaimed {
-                account_id: who,
-                evm_address,
-            });
+            Self::add_mappings(who, evm_address, ClaimMethod::Signature);
             Ok(())
         }
 
@@ -239,6 +296,61 @@ pub mod pallet {
             let _ = Self::do_claim_default_evm_address(who)?;
             Ok(())
         }
+
+        /// Claim additional (secondary) evm address for the caller's account.
+        /// Ensure caller already has a primary mapping and no prior mapping exists for evm address.
+        ///
+        /// Secondary evm address is resolved to the caller's account, while the caller's account
+        /// keeps resolving to its primary evm address.
+        ///
+        /// - `evm_address`: The evm address to bind to the caller's account
+        /// - `signature`: A signature generated by the address to prove ownership
+        ///
+        /// WARNING:
+        /// - Same as for `claim_evm_address`, only native balance is transferred from the
+        /// evm address's default account.
+        /// - Once connected user cannot change their mapping EVER.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim_secondary_evm_address())]
+        pub fn claim_secondary_evm_address(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: EvmSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                NativeToEvm::<T>::contains_key(&who),
+                Error::<T>::NoPrimaryMapping
+            );
+            ensure!(
+                !EvmToNative::<T>::contains_key(evm_address),
+                Error::<T>::AlreadyMapped
+            );
+
+            // recover evm address from signature
+            let address = Self::verify_signature(&who, &signature)
+                .ok_or(Error::<T>::UnexpectedSignatureFormat)?;
+            ensure!(evm_address == address, Error::<T>::InvalidSignature);
+
+            let mut secondary_addresses = SecondaryEvmAddresses::<T>::get(&who);
+            secondary_addresses
+                .try_push(evm_address)
+                .map_err(|_| Error::<T>::TooManySecondaryAddresses)?;
+
+            // charge the storage fee
+            Self::charge_storage_fee(&who)?;
+
+            Self::transfer_default_account_balance(&who, &evm_address)?;
+
+            EvmToNative::<T>::insert(&evm_address, &who);
+            SecondaryEvmAddresses::<T>::insert(&who, secondary_addresses);
+
+            Self::deposit_event(Event::SecondaryAccountClaimed {
+                account_id: who,
+                evm_address,
+            });
+            Ok(())
+        }
     }
 }
 
@@ -261,14 +373,53 @@ impl<T: Config> Pallet<T> {
         Self::charge_storage_fee(&account_id)?;
 
         // create double mappings for the pair with default evm address
+        Self::add_mappings(account_id, evm_address, ClaimMethod::Default);
+        Ok(evm_address)
+    }
+
+    /// Create the double mappings for the pair, record the claim metadata and deposit the event.
+    fn add_mappings(account_id: T::AccountId, evm_address: EvmAddress, method: ClaimMethod) {
+        let claimed_at = frame_system::Pallet::<T>::block_number();
+
         EvmToNative::<T>::insert(&evm_address, &account_id);
         NativeToEvm::<T>::insert(&account_id, &evm_address);
+        ClaimMetadata::<T>::insert(&account_id, ClaimInfo { claimed_at, method });
 
         Self::deposit_event(Event::AccountClaimed {
             account_id,
             evm_address,
+            claimed_at,
+            method,
         });
-        Ok(evm_address)
+    }
+
+    /// Transfer all the free native balance from the default account id of the evm address
+    /// since this `default_account_id` will no longer be connected to evm address and users cannot access it.
+    /// For the rest of the assets types (like XC20, etc) that should be handled by UI.
+    fn transfer_default_account_balance(
+        who: &T::AccountId,
+        evm_address: &EvmAddress,
+    ) -> DispatchResult {
+        let default_account_id = T::DefaultMappings::to_default_account_id(evm_address);
+        if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+            T::Currency::transfer(
+                &default_account_id,
+                who,
+                T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
+                Expendable,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Secondary evm addresses mapped to the account.
+    pub fn secondary_evm_addresses(account_id: &T::AccountId) -> Vec<EvmAddress> {
+        SecondaryEvmAddresses::<T>::get(account_id).into_inner()
+    }
+
+    /// Claim metadata of the account mapping, if any.
+    pub fn claim_info(account_id: &T::AccountId) -> Option<ClaimInfo<BlockNumberFor<T>>> {
+        ClaimMetadata::<T>::get(account_id)
     }
 
     /// Charge the (exact) storage fee (politely) from the user and burn it
@@ -285,6 +436,53 @@ impl<T: Config> Pallet<T> {
             Polite,
         )
     }
+
+    /// Ensure the correctness of the state of this pallet.
+    ///
+    /// ### Invariants of the account mappings
+    ///
+    /// 1. Primary evm address in [`NativeToEvm`] must resolve back to the account in [`EvmToNative`].
+    /// 2. Account with secondary evm addresses must have a primary mapping, and each secondary
+    ///    evm address must resolve back to the account in [`EvmToNative`].
+    /// 3. Each evm address in [`EvmToNative`] must be either the primary or one of the secondary
+    ///    evm addresses of the account it resolves to.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        // Invariant 1
+        for (account_id, evm_address) in NativeToEvm::<T>::iter() {
+            if EvmToNative::<T>::get(evm_address).as_ref() != Some(&account_id) {
+                return Err("Primary evm address doesn't resolve back to the account.".into());
+            }
+        }
+
+        // Invariant 2
+        for (account_id, secondary_addresses) in SecondaryEvmAddresses::<T>::iter() {
+            if secondary_addresses.is_empty() {
+                continue;
+            }
+
+            if !NativeToEvm::<T>::contains_key(&account_id) {
+                return Err("Account with secondary evm addresses has no primary mapping.".into());
+            }
+
+            if secondary_addresses
+                .iter()
+                .any(|evm_address| EvmToNative::<T>::get(evm_address).as_ref() != Some(&account_id))
+            {
+                return Err("Secondary evm address doesn't resolve back to the account.".into());
+            }
+        }
+
+        // Invariant 3
+        for (evm_address, account_id) in EvmToNative::<T>::iter() {
+            let is_primary = NativeToEvm::<T>::get(&account_id) == Some(evm_address);
+            if !is_primary && !SecondaryEvmAddresses::<T>::get(&account_id).contains(&evm_address) {
+                return Err("Evm address resolves to an account which doesn't own it.".into());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// EIP-712 compatible signature scheme for verifying ownership of EVM Address
@@ -389,6 +587,10 @@ impl<T: Config> OnKilledAccount<T::AccountId> for KillAccountMapping<T> {
             EvmToNative::<T>::remove(evm_addr);
             NativeToEvm::<T>::remove(who);
         }
+        ClaimMetadata::<T>::remove(who);
+        for evm_addr in SecondaryEvmAddresses::<T>::take(who) {
+            EvmToNative::<T>::remove(evm_addr);
+        }
     }
 }
 
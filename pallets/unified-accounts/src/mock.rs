@@ -156,6 +156,7 @@ impl pallet_ethereum::Config for TestRuntime {
 
 parameter_types! {
     pub const AccountMappingStorageFee: u128 = 100_000_000;
+    pub const MaxSecondaryAddresses: u32 = 2;
 }
 
 impl pallet_unified_accounts::Config for TestRuntime {
@@ -164,6 +165,7 @@ impl pallet_unified_accounts::Config for TestRuntime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type MaxSecondaryAddresses = MaxSecondaryAddresses;
     type WeightInfo = ();
 }
 
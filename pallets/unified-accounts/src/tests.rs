@@ -136,6 +136,7 @@ fn on_killed_account_hook() {
         // make sure mapping is removed
         assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), None);
         assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), None);
+        assert_eq!(ClaimMetadata::<TestRuntime>::get(ALICE), None);
     });
 }
 
@@ -182,12 +183,23 @@ fn account_claim_should_work() {
             crate::Event::AccountClaimed {
                 account_id: ALICE.clone(),
                 evm_address: alice_eth.clone(),
+                claimed_at: 1,
+                method: ClaimMethod::Signature,
             },
         ));
 
         // make sure mappings are in place
         assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth).unwrap(), ALICE);
-        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE).unwrap(), alice_eth)
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE).unwrap(), alice_eth);
+
+        // make sure claim metadata is recorded
+        assert_eq!(
+            ClaimMetadata::<TestRuntime>::get(ALICE),
+            Some(ClaimInfo {
+                claimed_at: 1,
+                method: ClaimMethod::Signature,
+            })
+        );
     });
 }
 
@@ -204,6 +216,8 @@ fn account_default_claim_works() {
             crate::Event::AccountClaimed {
                 account_id: ALICE.clone(),
                 evm_address: alice_default_evm.clone(),
+                claimed_at: 1,
+                method: ClaimMethod::Default,
             },
         ));
 
@@ -374,6 +388,8 @@ fn charging_storage_fee_should_not_reap_account() {
             crate::Event::AccountClaimed {
                 account_id: ALICE.clone(),
                 evm_address: alice_eth,
+                claimed_at: 1,
+                method: ClaimMethod::Signature,
             },
         ));
 
@@ -397,3 +413,213 @@ fn charging_storage_fee_should_not_reap_account() {
         );
     });
 }
+
+#[test]
+fn claim_metadata_is_recorded() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(10);
+
+        // claim default account for alice
+        assert_ok!(UnifiedAccounts::claim_default_evm_address(
+            RuntimeOrigin::signed(ALICE)
+        ));
+        assert_eq!(
+            UnifiedAccounts::claim_info(&ALICE),
+            Some(ClaimInfo {
+                claimed_at: 10,
+                method: ClaimMethod::Default,
+            })
+        );
+
+        System::set_block_number(25);
+
+        // claim evm address with signature for bob
+        connect_accounts(&BOB, &bob_secret());
+        assert_eq!(
+            UnifiedAccounts::claim_info(&BOB),
+            Some(ClaimInfo {
+                claimed_at: 25,
+                method: ClaimMethod::Signature,
+            })
+        );
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountClaimed {
+                account_id: BOB.clone(),
+                evm_address: UnifiedAccounts::eth_address(&bob_secret()),
+                claimed_at: 25,
+                method: ClaimMethod::Signature,
+            },
+        ));
+    });
+}
+
+#[test]
+fn secondary_account_claim_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+
+        // primary mapping must exist first
+        assert_noop!(
+            UnifiedAccounts::claim_secondary_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                bob_eth,
+                get_evm_signature(&ALICE, &bob_secret())
+            ),
+            Error::<TestRuntime>::NoPrimaryMapping
+        );
+
+        connect_accounts(&ALICE, &alice_secret());
+
+        // signature for other account should not work
+        assert_noop!(
+            UnifiedAccounts::claim_secondary_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                bob_eth,
+                get_evm_signature(&BOB, &bob_secret())
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        // primary evm address cannot be claimed as secondary
+        assert_noop!(
+            UnifiedAccounts::claim_secondary_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+
+        let alice_balance = Balances::free_balance(&ALICE);
+        assert_ok!(UnifiedAccounts::claim_secondary_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            bob_eth,
+            get_evm_signature(&ALICE, &bob_secret())
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::SecondaryAccountClaimed {
+                account_id: ALICE.clone(),
+                evm_address: bob_eth,
+            },
+        ));
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            alice_balance - AccountMappingStorageFee::get()
+        );
+
+        // both evm addresses resolve to alice, alice resolves to the primary one
+        assert_eq!(
+            <UnifiedAccounts as UnifiedAddressMapper<_>>::to_account_id(&alice_eth),
+            Some(ALICE)
+        );
+        assert_eq!(
+            <UnifiedAccounts as UnifiedAddressMapper<_>>::to_account_id(&bob_eth),
+            Some(ALICE)
+        );
+        assert_eq!(
+            <UnifiedAccounts as UnifiedAddressMapper<_>>::to_h160(&ALICE),
+            Some(alice_eth)
+        );
+        assert_eq!(
+            UnifiedAccounts::secondary_evm_addresses(&ALICE),
+            vec![bob_eth]
+        );
+
+        // secondary address is taken, bob cannot claim it anymore
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(BOB),
+                bob_eth,
+                get_evm_signature(&BOB, &bob_secret())
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn secondary_account_claim_limit_is_enforced() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(UnifiedAccounts::claim_default_evm_address(
+            RuntimeOrigin::signed(ALICE)
+        ));
+
+        let secrets: Vec<_> = [b"Secondary1", b"Secondary2", b"Secondary3"]
+            .iter()
+            .map(|seed| libsecp256k1::SecretKey::parse(&keccak_256(&seed[..])).unwrap())
+            .collect();
+
+        for secret in secrets.iter().take(MaxSecondaryAddresses::get() as usize) {
+            assert_ok!(UnifiedAccounts::claim_secondary_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                UnifiedAccounts::eth_address(secret),
+                get_evm_signature(&ALICE, secret)
+            ));
+        }
+
+        let secret = &secrets[MaxSecondaryAddresses::get() as usize];
+        assert_noop!(
+            UnifiedAccounts::claim_secondary_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                UnifiedAccounts::eth_address(secret),
+                get_evm_signature(&ALICE, secret)
+            ),
+            Error::<TestRuntime>::TooManySecondaryAddresses
+        );
+    });
+}
+
+#[test]
+fn on_killed_account_hook_removes_secondary_addresses() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+
+        connect_accounts(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_secondary_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            bob_eth,
+            get_evm_signature(&ALICE, &bob_secret())
+        ));
+
+        // kill alice
+        Balances::set_balance(&ALICE, 0);
+
+        assert_eq!(EvmToNative::<TestRuntime>::get(bob_eth), None);
+        assert!(UnifiedAccounts::secondary_evm_addresses(&ALICE).is_empty());
+    });
+}
+
+#[test]
+fn try_state_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+
+        connect_accounts(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_secondary_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            bob_eth,
+            get_evm_signature(&ALICE, &bob_secret())
+        ));
+        assert_ok!(UnifiedAccounts::claim_default_evm_address(
+            RuntimeOrigin::signed(BOB)
+        ));
+        assert_ok!(UnifiedAccounts::do_try_state());
+
+        // primary evm address resolving to another account
+        EvmToNative::<TestRuntime>::insert(&alice_eth, &CHARLIE);
+        assert!(UnifiedAccounts::do_try_state().is_err());
+        EvmToNative::<TestRuntime>::insert(&alice_eth, &ALICE);
+
+        // secondary evm address not resolving to the account
+        EvmToNative::<TestRuntime>::remove(&bob_eth);
+        assert!(UnifiedAccounts::do_try_state().is_err());
+        EvmToNative::<TestRuntime>::insert(&bob_eth, &ALICE);
+        assert_ok!(UnifiedAccounts::do_try_state());
+
+        // evm address resolving to an account which doesn't own it
+        EvmToNative::<TestRuntime>::insert(H160::repeat_byte(0xAA), &CHARLIE);
+        assert!(UnifiedAccounts::do_try_state().is_err());
+    });
+}
@@ -23,6 +23,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `devserver-01`, CPU: `Intel(R) Xeon(R) E-2236 CPU @ 3.40GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("shibuya-dev"), DB CACHE: 1024
+//!
+//! The weights of the functions marked as estimated below weren't produced by the benchmark CLI,
+//! they must be regenerated on reference hardware before relying on them in production.
 
 // Executed Command:
 // ./target/release/astar-collator
@@ -50,6 +53,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn claim_evm_address() -> Weight;
 	fn claim_default_evm_address() -> Weight;
+	fn claim_secondary_evm_address() -> Weight;
 	fn to_account_id() -> Weight;
 	fn to_account_id_or_default() -> Weight;
 	fn to_h160() -> Weight;
@@ -63,6 +67,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimMetadata (r:0 w:1)
+	/// Proof: UnifiedAccounts ClaimMetadata (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
 	/// Storage: EVMChainId ChainId (r:1 w:0)
 	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: System BlockHash (r:1 w:0)
@@ -70,25 +76,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `256`
-		//  Estimated: `3593`
-		// Minimum execution time: 91_231_000 picoseconds.
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
 		Weight::from_parts(91_688_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimMetadata (r:0 w:1)
+	/// Proof: UnifiedAccounts ClaimMetadata (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `3533`
-		// Minimum execution time: 40_749_000 picoseconds.
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
 		Weight::from_parts(41_411_000, 3533)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts SecondaryEvmAddresses (r:1 w:1)
+	/// Proof: UnifiedAccounts SecondaryEvmAddresses (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_secondary_evm_address() -> Weight {
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
+		Weight::from_parts(95_302_000, 3880)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
@@ -139,6 +159,8 @@ impl WeightInfo for () {
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimMetadata (r:0 w:1)
+	/// Proof: UnifiedAccounts ClaimMetadata (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
 	/// Storage: EVMChainId ChainId (r:1 w:0)
 	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: System BlockHash (r:1 w:0)
@@ -146,25 +168,39 @@ impl WeightInfo for () {
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `256`
-		//  Estimated: `3593`
-		// Minimum execution time: 91_231_000 picoseconds.
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
 		Weight::from_parts(91_688_000, 3593)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimMetadata (r:0 w:1)
+	/// Proof: UnifiedAccounts ClaimMetadata (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `3533`
-		// Minimum execution time: 40_749_000 picoseconds.
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
 		Weight::from_parts(41_411_000, 3533)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts SecondaryEvmAddresses (r:1 w:1)
+	/// Proof: UnifiedAccounts SecondaryEvmAddresses (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_secondary_evm_address() -> Weight {
+		// Estimated from the storage accesses, not measured by the benchmark CLI.
+		Weight::from_parts(95_302_000, 3880)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
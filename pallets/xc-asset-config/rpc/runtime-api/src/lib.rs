@@ -0,0 +1,42 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use xcm::VersionedLocation;
+
+sp_api::decl_runtime_apis! {
+
+    /// Cross-chain Asset Config Api.
+    ///
+    /// Used by bridges, wallets & frontends to discover the registered cross-chain assets,
+    /// without depending on the storage layout of the pallet.
+    pub trait XcAssetConfigApi<AssetId> where AssetId: Codec {
+
+        /// All registered cross-chain assets, as `(asset_id, location, units_per_second, is_payment_asset)` tuples.
+        ///
+        /// Location is provided in the latest XCM version. Assets which can't be used to pay for the
+        /// execution have `units_per_second` set to zero.
+        fn registered_assets() -> Vec<(AssetId, VersionedLocation, u128, bool)>;
+
+        /// Registered cross-chain asset with the `asset_id`, or `None` if it isn't registered.
+        fn registered_asset(asset_id: AssetId) -> Option<(AssetId, VersionedLocation, u128, bool)>;
+    }
+}
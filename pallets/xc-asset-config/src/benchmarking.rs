@@ -22,9 +22,32 @@ use super::*;
 use crate::Pallet as XcAssetConfig;
 
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::{traits::Get, BoundedVec};
 use frame_system::RawOrigin;
-use sp_std::boxed::Box;
-use xcm::v4::Location;
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::{boxed::Box, vec::Vec};
+use xcm::latest::{
+    Junction::{GeneralIndex, Parachain},
+    Location,
+};
+
+/// Unique asset Id & location for the given index.
+fn asset<T: Config>(index: u32) -> (Location, T::AssetId) {
+    let asset_id = T::AssetId::decode(&mut TrailingZeroInput::new(&index.encode()))
+        .expect("Infinite input, decoding cannot fail.");
+    (Location::new(1, [Parachain(index)]), asset_id)
+}
+
+/// Unique NFT collection Id & location for the given index.
+fn nft_collection<T: Config>(index: u32) -> (Location, T::CollectionId) {
+    let collection_id = T::CollectionId::decode(&mut TrailingZeroInput::new(&index.encode()))
+        .expect("Infinite input, decoding cannot fail.");
+    (
+        Location::new(1, [Parachain(index), GeneralIndex(index.into())]),
+        collection_id,
+    )
+}
 
 benchmarks! {
 
@@ -32,7 +55,7 @@ benchmarks! {
         let asset_location = Location::parent();
         let asset_id = T::AssetId::default();
 
-    }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), asset_id)
+    }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), asset_id, None)
     verify {
         assert_eq!(AssetIdToLocation::<T>::get(&asset_id), Some(asset_location.into_versioned()));
     }
@@ -42,7 +65,7 @@ benchmarks! {
         let asset_id = T::AssetId::default();
         let units = 123;
 
-        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
 
     }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), units)
     verify {
@@ -54,7 +77,7 @@ benchmarks! {
         let asset_id = T::AssetId::default();
         let units = 123;
 
-        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
         XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), units)?;
 
         let new_asset_location = Location::here();
@@ -71,7 +94,7 @@ benchmarks! {
         let asset_id = T::AssetId::default();
         let units = 123;
 
-        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
         XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), units)?;
 
     }: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()))
@@ -84,7 +107,7 @@ benchmarks! {
         let asset_id = T::AssetId::default();
         let units = 123;
 
-        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
         XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), units)?;
 
     }: _(RawOrigin::Root, asset_id)
@@ -94,6 +117,128 @@ benchmarks! {
         assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location.into_versioned()));
     }
 
+    register_assets {
+        let n in 1 .. T::MaxBatchSize::get();
+        let units = 123;
+
+        let assets: Vec<_> = (0..n).map(|index| {
+            let (asset_location, asset_id) = asset::<T>(index);
+            (asset_location.into_versioned(), asset_id, units)
+        }).collect();
+        let assets: BoundedVec<_, T::MaxBatchSize> = assets.try_into().expect("Length is within the limit.");
+
+    }: _(RawOrigin::Root, assets)
+    verify {
+        for index in 0..n {
+            let (asset_location, asset_id) = asset::<T>(index);
+            assert_eq!(AssetLocationToId::<T>::get(&asset_location.clone().into_versioned()), Some(asset_id));
+            assert_eq!(AssetLocationUnitsPerSecond::<T>::get(&asset_location.into_versioned()), Some(units));
+        }
+    }
+
+    set_assets_units_per_second {
+        let n in 1 .. T::MaxBatchSize::get();
+        let units = 123;
+
+        for index in 0..n {
+            let (asset_location, asset_id) = asset::<T>(index);
+            XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id, None)?;
+        }
+
+        let units_per_second: Vec<_> = (0..n).map(|index| {
+            let (asset_location, _) = asset::<T>(index);
+            (asset_location.into_versioned(), units)
+        }).collect();
+        let units_per_second: BoundedVec<_, T::MaxBatchSize> = units_per_second.try_into().expect("Length is within the limit.");
+
+    }: _(RawOrigin::Root, units_per_second)
+    verify {
+        for index in 0..n {
+            let (asset_location, _) = asset::<T>(index);
+            assert_eq!(AssetLocationUnitsPerSecond::<T>::get(&asset_location.into_versioned()), Some(units));
+        }
+    }
+
+    set_min_xcm_amount {
+        let asset_location = Location::parent();
+        let asset_id = T::AssetId::default();
+        let min_xcm_amount = 123;
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id, None)?;
+
+    }: _(RawOrigin::Root, asset_id, Some(min_xcm_amount))
+    verify {
+        assert_eq!(AssetMinXcmAmount::<T>::get(&asset_id), Some(min_xcm_amount));
+    }
+
+    freeze_xc_asset {
+        let asset_location = Location::parent();
+        let asset_id = T::AssetId::default();
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
+
+    }: _(RawOrigin::Root, Box::new(asset_location.into_versioned()))
+    verify {
+        assert!(FrozenAssets::<T>::contains_key(&asset_id));
+    }
+
+    thaw_xc_asset {
+        let asset_location = Location::parent();
+        let asset_id = T::AssetId::default();
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id, None)?;
+        XcAssetConfig::<T>::freeze_xc_asset(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()))?;
+
+    }: _(RawOrigin::Root, Box::new(asset_location.into_versioned()))
+    verify {
+        assert!(!FrozenAssets::<T>::contains_key(&asset_id));
+    }
+
+    reserve_asset_id_range {
+        let max_ranges = T::MaxReservedRanges::get();
+        for index in 1..max_ranges {
+            let (_, asset_id) = asset::<T>(index);
+            XcAssetConfig::<T>::reserve_asset_id_range(RawOrigin::Root.into(), asset_id, asset_id, AssetIdSpace::Local)?;
+        }
+        let (_, asset_id) = asset::<T>(max_ranges);
+
+    }: _(RawOrigin::Root, asset_id, asset_id, AssetIdSpace::CrossChain)
+    verify {
+        assert_eq!(XcAssetConfig::<T>::asset_id_space(asset_id), Some(AssetIdSpace::CrossChain));
+    }
+
+    remove_asset_id_range {
+        let max_ranges = T::MaxReservedRanges::get();
+        for index in 1..=max_ranges {
+            let (_, asset_id) = asset::<T>(index);
+            XcAssetConfig::<T>::reserve_asset_id_range(RawOrigin::Root.into(), asset_id, asset_id, AssetIdSpace::Local)?;
+        }
+        let (_, asset_id) = asset::<T>(max_ranges);
+
+    }: _(RawOrigin::Root, asset_id, asset_id)
+    verify {
+        assert!(XcAssetConfig::<T>::asset_id_space(asset_id).is_none());
+    }
+
+    register_nft_collection {
+        let (collection_location, collection_id) = nft_collection::<T>(1);
+
+    }: _(RawOrigin::Root, Box::new(collection_location.clone().into_versioned()), collection_id)
+    verify {
+        assert_eq!(NftCollectionIdToLocation::<T>::get(&collection_id), Some(collection_location.into_versioned()));
+    }
+
+    remove_nft_collection {
+        let (collection_location, collection_id) = nft_collection::<T>(1);
+
+        XcAssetConfig::<T>::register_nft_collection(RawOrigin::Root.into(), Box::new(collection_location.clone().into_versioned()), collection_id)?;
+
+    }: _(RawOrigin::Root, collection_id)
+    verify {
+        assert!(!NftCollectionIdToLocation::<T>::contains_key(&collection_id));
+        assert!(!NftCollectionLocationToId::<T>::contains_key(&collection_location.into_versioned()));
+    }
+
 }
 
 #[cfg(test)]
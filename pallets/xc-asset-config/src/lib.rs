@@ -23,8 +23,9 @@
 //! This pallet provides mappings between local asset Id and remove asset location.
 //! E.g. a multilocation like `{parents: 0, interior: X1::(Junction::Parachain(1000))}` could ba mapped to local asset Id `789`.
 //!
-//! The pallet ensures that the latest Location version is always used. Developers must ensure to properly migrate legacy versions
-//! to newest when they become available.
+//! The pallet ensures that the latest Location version is always used. Extrinsics accept locations of any version
+//! which can be converted into the latest one. Developers must ensure to properly migrate legacy versions
+//! to newest when they become available, which can be done using the `MigrationXcmLatest` migration.
 //!
 //! Additionally, it stores information whether a foreign asset is supported as a payment currency for execution on local network.
 //!
@@ -32,11 +33,21 @@
 //!
 //! ### Dispatchable Function
 //!
-//! - `register_asset_location` - used to register mapping between local asset Id and remote asset location
+//! - `register_asset_location` - used to register mapping between local asset Id and remote asset location,
+//!   optionally creating the local asset with the provided metadata
 //! - `set_asset_units_per_second` - registers asset as payment currency and sets the desired payment per second of execution time
 //! - `change_existing_asset_location` - changes the remote location of an existing local asset Id
 //! - `remove_payment_asset` - removes asset from the set of supported payment assets
 //! - `remove_asset` - removes all information related to this asset
+//! - `register_assets` - batch version of `register_asset_location`, which also sets the `units per second` of each asset
+//! - `set_assets_units_per_second` - batch version of `set_asset_units_per_second`
+//! - `set_min_xcm_amount` - sets or clears the minimum amount of the asset which can be transferred cross-chain
+//! - `freeze_xc_asset` - freezes the asset, halting its cross-chain transfers
+//! - `thaw_xc_asset` - thaws the previously frozen asset
+//! - `reserve_asset_id_range` - reserves the range of asset Ids for the local or for the cross-chain assets
+//! - `remove_asset_id_range` - removes the reservation of the asset Ids range
+//! - `register_nft_collection` - registers mapping between local NFT collection Id and remote collection location
+//! - `remove_nft_collection` - removes the mapping of the local NFT collection Id
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
@@ -46,9 +57,28 @@
 //! - `get_xc_asset_location`
 //! - `get_asset_id`
 //!
+//! `XcNftCollectionLocation` interface for mapping NFT collection Id to collection location and vice versa
+//! - `get_collection_location`
+//! - `get_collection_id`
+//!
 //! `ExecutionPaymentRate` interface for fetching `units per second` if asset is supported payment asset
 //! - `get_units_per_second`
 //!
+//! `meets_min_xcm_amount` and its variants check whether the transferred amount meets the minimum cross-chain
+//! transfer amount of the asset, and are supposed to be used for filtering outgoing transfers.
+//!
+//! `FrozenXcAssets` interface for checking whether the asset location belongs to a frozen asset. It's supposed to be used
+//! by the XCM asset transactor, so frozen assets can neither be received (minted) nor sent (burned) cross-chain.
+//!
+//! `registered_assets` and `registered_asset` provide the registered assets together with their execution payment
+//! configuration, and are supposed to be exposed via the runtime API.
+//!
+//! `asset_id_space` and `is_local_asset_id_allowed` check the asset Id against the reserved ranges, which separate
+//! the Ids of the locally created assets from the Ids of the cross-chain assets.
+//!
+//! `AssetRegistrar` interface for creating the local asset representation, used when registering asset with metadata
+//! - `create_asset`
+//!
 //! - `weight_to_fee` method is used to convert weight to fee based on units per second and weight.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -74,14 +104,19 @@ pub mod pallet {
 
     use crate::weights::WeightInfo;
     use frame_support::{
-        pallet_prelude::*, traits::EnsureOrigin, weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+        pallet_prelude::*,
+        traits::{Contains, EnsureOrigin},
+        weights::constants::WEIGHT_REF_TIME_PER_SECOND,
     };
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::HasCompact;
-    use sp_std::boxed::Box;
-    use xcm::{v4::Location, VersionedLocation};
+    use sp_std::{boxed::Box, vec::Vec};
+    use xcm::{
+        latest::{Asset, Assets, Fungibility, Location},
+        VersionedAsset, VersionedAssets, VersionedLocation,
+    };
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -97,12 +132,93 @@ pub mod pallet {
         fn get_asset_id(xc_asset_location: Location) -> Option<AssetId>;
     }
 
+    /// Defines conversion between NFT collection Id and cross-chain collection location
+    pub trait XcNftCollectionLocation<CollectionId> {
+        /// Get collection location from collection Id
+        fn get_collection_location(collection_id: CollectionId) -> Option<Location>;
+
+        /// Get local collection Id from collection location
+        fn get_collection_id(collection_location: Location) -> Option<CollectionId>;
+    }
+
     /// Used to fetch `units per second` if cross-chain asset is applicable for local execution payment.
     pub trait ExecutionPaymentRate {
         /// returns units per second from asset type or `None` if asset type isn't a supported payment asset.
         fn get_units_per_second(asset_location: Location) -> Option<u128>;
     }
 
+    /// Metadata of the local asset representation, created alongside the asset location registration.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct AssetMetadata {
+        /// Asset name.
+        pub name: Vec<u8>,
+        /// Asset symbol.
+        pub symbol: Vec<u8>,
+        /// Number of decimals used to represent the asset.
+        pub decimals: u8,
+        /// Minimum balance an account must hold.
+        pub min_balance: u128,
+    }
+
+    /// Purpose for which a range of asset Ids is reserved.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum AssetIdSpace {
+        /// Ids of the locally created assets, which can't be registered as cross-chain assets.
+        Local,
+        /// Ids of the cross-chain assets, which can't be used for the locally created assets.
+        CrossChain,
+    }
+
+    /// Inclusive range of asset Ids, reserved for a single purpose.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AssetIdRange<AssetId> {
+        /// First asset Id of the range.
+        pub start: AssetId,
+        /// Last asset Id of the range.
+        pub end: AssetId,
+        /// Purpose of the range.
+        pub space: AssetIdSpace,
+    }
+
+    impl<AssetId: PartialOrd> AssetIdRange<AssetId> {
+        /// `true` if the `asset_id` belongs to the range, `false` otherwise.
+        pub fn contains(&self, asset_id: &AssetId) -> bool {
+            self.start <= *asset_id && *asset_id <= self.end
+        }
+
+        /// `true` if the ranges have at least one asset Id in common, `false` otherwise.
+        pub fn overlaps(&self, other: &Self) -> bool {
+            self.start <= other.end && other.start <= self.end
+        }
+    }
+
+    /// Used to create the local asset representation of a cross-chain asset.
+    pub trait AssetRegistrar<AssetId> {
+        /// Create the asset with the specified Id, and set its metadata.
+        fn create_asset(asset_id: AssetId, metadata: AssetMetadata) -> DispatchResult;
+
+        /// Weight of the `create_asset` call.
+        fn create_asset_weight() -> Weight;
+
+        /// `true` if the local asset with the specified Id already exists, `false` otherwise.
+        fn asset_exists(asset_id: AssetId) -> bool;
+    }
+
+    /// Asset creation isn't supported.
+    impl<AssetId> AssetRegistrar<AssetId> for () {
+        fn create_asset(_asset_id: AssetId, _metadata: AssetMetadata) -> DispatchResult {
+            Err(DispatchError::Unavailable)
+        }
+
+        fn create_asset_weight() -> Weight {
+            Weight::zero()
+        }
+
+        fn asset_exists(_asset_id: AssetId) -> bool {
+            false
+        }
+    }
+
     impl<T: Config> XcAssetLocation<T::AssetId> for Pallet<T> {
         fn get_xc_asset_location(asset_id: T::AssetId) -> Option<Location> {
             AssetIdToLocation::<T>::get(asset_id).and_then(|x| x.try_into().ok())
@@ -113,18 +229,157 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> XcNftCollectionLocation<T::CollectionId> for Pallet<T> {
+        fn get_collection_location(collection_id: T::CollectionId) -> Option<Location> {
+            NftCollectionIdToLocation::<T>::get(collection_id).and_then(|x| x.try_into().ok())
+        }
+
+        fn get_collection_id(collection_location: Location) -> Option<T::CollectionId> {
+            NftCollectionLocationToId::<T>::get(collection_location.into_versioned())
+        }
+    }
+
     impl<T: Config> ExecutionPaymentRate for Pallet<T> {
         fn get_units_per_second(asset_location: Location) -> Option<u128> {
             AssetLocationUnitsPerSecond::<T>::get(asset_location.into_versioned())
         }
     }
 
+    /// Locations of the frozen cross-chain assets.
+    pub struct FrozenXcAssets<T>(PhantomData<T>);
+    impl<T: Config> Contains<Location> for FrozenXcAssets<T> {
+        fn contains(asset_location: &Location) -> bool {
+            Pallet::<T>::is_frozen(asset_location)
+        }
+    }
+
     impl<T: Config> Pallet<T> {
         /// Convert weight to fee based on units per second and weight.
         pub fn weight_to_fee(weight: Weight, units_per_second: u128) -> u128 {
             units_per_second.saturating_mul(weight.ref_time() as u128)
                 / (WEIGHT_REF_TIME_PER_SECOND as u128)
         }
+
+        /// `true` if the asset with the specified location is frozen, `false` otherwise.
+        pub fn is_frozen(asset_location: &Location) -> bool {
+            AssetLocationToId::<T>::get(asset_location.clone().into_versioned())
+                .map_or(false, |asset_id| FrozenAssets::<T>::contains_key(asset_id))
+        }
+
+        /// `true` if the `amount` meets the minimum cross-chain transfer amount of the asset, `false` otherwise.
+        ///
+        /// Any amount of an asset without the minimum amount can be transferred.
+        pub fn meets_min_xcm_amount(asset_id: T::AssetId, amount: u128) -> bool {
+            AssetMinXcmAmount::<T>::get(asset_id).map_or(true, |min| amount >= min)
+        }
+
+        /// `true` if the `asset` meets the minimum cross-chain transfer amount of the registered asset it represents.
+        ///
+        /// Non-fungible and unregistered assets aren't restricted.
+        pub fn asset_meets_min_xcm_amount(asset: &Asset) -> bool {
+            let Fungibility::Fungible(amount) = asset.fun else {
+                return true;
+            };
+
+            AssetLocationToId::<T>::get(asset.id.0.clone().into_versioned())
+                .map_or(true, |asset_id| {
+                    Self::meets_min_xcm_amount(asset_id, amount)
+                })
+        }
+
+        /// Versioned variant of the `asset_meets_min_xcm_amount`.
+        ///
+        /// Assets which can't be converted into the latest version aren't restricted, since they can't be transferred anyway.
+        pub fn versioned_asset_meets_min_xcm_amount(asset: &VersionedAsset) -> bool {
+            Asset::try_from(asset.clone())
+                .map_or(true, |asset| Self::asset_meets_min_xcm_amount(&asset))
+        }
+
+        /// `true` if all the `assets` meet the minimum cross-chain transfer amount, `false` otherwise.
+        ///
+        /// Assets which can't be converted into the latest version aren't restricted, since they can't be transferred anyway.
+        pub fn versioned_assets_meet_min_xcm_amount(assets: &VersionedAssets) -> bool {
+            Assets::try_from(assets.clone()).map_or(true, |assets| {
+                assets.inner().iter().all(Self::asset_meets_min_xcm_amount)
+            })
+        }
+
+        /// All registered assets, as `(asset_id, location, units_per_second, is_payment_asset)` tuples.
+        ///
+        /// Assets which can't be used to pay for the execution have `units_per_second` set to zero.
+        pub fn registered_assets() -> Vec<(T::AssetId, VersionedLocation, u128, bool)> {
+            AssetIdToLocation::<T>::iter()
+                .map(|(asset_id, asset_location)| Self::asset_info(asset_id, asset_location))
+                .collect()
+        }
+
+        /// Registered asset with the `asset_id`, in the same format as `registered_assets`.
+        pub fn registered_asset(
+            asset_id: T::AssetId,
+        ) -> Option<(T::AssetId, VersionedLocation, u128, bool)> {
+            AssetIdToLocation::<T>::get(asset_id)
+                .map(|asset_location| Self::asset_info(asset_id, asset_location))
+        }
+
+        fn asset_info(
+            asset_id: T::AssetId,
+            asset_location: VersionedLocation,
+        ) -> (T::AssetId, VersionedLocation, u128, bool) {
+            let units_per_second = AssetLocationUnitsPerSecond::<T>::get(&asset_location);
+            (
+                asset_id,
+                asset_location,
+                units_per_second.unwrap_or_default(),
+                units_per_second.is_some(),
+            )
+        }
+
+        /// Purpose of the reserved range the `asset_id` belongs to, or `None` if it isn't reserved.
+        pub fn asset_id_space(asset_id: T::AssetId) -> Option<AssetIdSpace> {
+            ReservedAssetIdRanges::<T>::get()
+                .iter()
+                .find(|range| range.contains(&asset_id))
+                .map(|range| range.space)
+        }
+
+        /// `true` if the `asset_id` can be used for a locally created asset, `false` otherwise.
+        ///
+        /// Ids reserved for the cross-chain assets can't be used.
+        pub fn is_local_asset_id_allowed(asset_id: T::AssetId) -> bool {
+            Self::asset_id_space(asset_id) != Some(AssetIdSpace::CrossChain)
+        }
+
+        /// Ensure the `asset_id` can be used for a cross-chain asset.
+        ///
+        /// Ids reserved for the local assets can't be used. If any range is reserved for the cross-chain assets,
+        /// the `asset_id` must belong to one of them.
+        pub(crate) fn ensure_cross_chain_asset_id(asset_id: T::AssetId) -> DispatchResult {
+            let ranges = ReservedAssetIdRanges::<T>::get();
+            match ranges.iter().find(|range| range.contains(&asset_id)) {
+                Some(range) => ensure!(
+                    range.space == AssetIdSpace::CrossChain,
+                    Error::<T>::AssetIdReservedForLocal
+                ),
+                None => ensure!(
+                    !ranges
+                        .iter()
+                        .any(|range| range.space == AssetIdSpace::CrossChain),
+                    Error::<T>::AssetIdOutsideCrossChainRange
+                ),
+            }
+
+            Ok(())
+        }
+
+        /// Convert the location of any supported XCM version into the latest versioned location,
+        /// which is used as the storage key.
+        pub(crate) fn latest_versioned_location(
+            location: VersionedLocation,
+        ) -> Result<VersionedLocation, Error<T>> {
+            Location::try_from(location)
+                .map(VersionedLocation::from)
+                .map_err(|_| Error::<T>::MultiLocationNotSupported)
+        }
     }
 
     #[pallet::config]
@@ -133,13 +388,27 @@ pub mod pallet {
 
         /// The Asset Id. This will be used to create the asset and to associate it with
         /// a AssetLocation
-        type AssetId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen;
+        type AssetId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen + Ord;
 
         /// The required origin for managing cross-chain asset configuration
         ///
         /// Should most likely be root.
         type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+        /// Maximum number of assets which can be configured in a single batch call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Used to create the local asset representation, when asset metadata is provided on registration.
+        type AssetRegistrar: AssetRegistrar<Self::AssetId>;
+
+        /// Maximum number of asset Id ranges which can be reserved.
+        #[pallet::constant]
+        type MaxReservedRanges: Get<u32>;
+
+        /// The NFT collection Id, used to associate the local collection with the cross-chain collection location.
+        type CollectionId: Member + Parameter + Copy + MaxEncodedLen;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -151,6 +420,28 @@ pub mod pallet {
         AssetDoesNotExist,
         /// Failed to convert to latest versioned Location
         MultiLocationNotSupported,
+        /// Asset is already frozen.
+        AssetAlreadyFrozen,
+        /// Asset isn't frozen.
+        AssetNotFrozen,
+        /// Local asset with the same Id already exists.
+        AssetIdInUse,
+        /// Asset Id is reserved for the local assets.
+        AssetIdReservedForLocal,
+        /// Asset Id doesn't belong to any range reserved for the cross-chain assets.
+        AssetIdOutsideCrossChainRange,
+        /// Range start is greater than its end.
+        InvalidAssetIdRange,
+        /// Range overlaps with an already reserved range.
+        AssetIdRangeOverlap,
+        /// Maximum number of reserved ranges has been reached.
+        TooManyReservedRanges,
+        /// Range isn't reserved.
+        AssetIdRangeNotFound,
+        /// NFT collection is already registered.
+        NftCollectionAlreadyRegistered,
+        /// NFT collection does not exist (hasn't been registered).
+        NftCollectionDoesNotExist,
     }
 
     #[pallet::event]
@@ -179,6 +470,39 @@ pub mod pallet {
             asset_location: VersionedLocation,
             asset_id: T::AssetId,
         },
+        /// Changed the minimum cross-chain transfer amount of an asset
+        MinXcmAmountChanged {
+            asset_id: T::AssetId,
+            min_xcm_amount: Option<u128>,
+        },
+        /// Asset has been frozen, its cross-chain transfers are halted
+        AssetFrozen {
+            asset_location: VersionedLocation,
+            asset_id: T::AssetId,
+        },
+        /// Asset has been thawed, its cross-chain transfers are resumed
+        AssetThawed {
+            asset_location: VersionedLocation,
+            asset_id: T::AssetId,
+        },
+        /// Range of asset Ids has been reserved
+        AssetIdRangeReserved {
+            start: T::AssetId,
+            end: T::AssetId,
+            space: AssetIdSpace,
+        },
+        /// Reservation of the asset Ids range has been removed
+        AssetIdRangeRemoved { start: T::AssetId, end: T::AssetId },
+        /// Registered mapping between NFT collection location and collection Id
+        NftCollectionRegistered {
+            collection_location: VersionedLocation,
+            collection_id: T::CollectionId,
+        },
+        /// Removed mapping between NFT collection location and collection Id
+        NftCollectionRemoved {
+            collection_location: VersionedLocation,
+            collection_id: T::CollectionId,
+        },
     }
 
     /// Mapping from an asset id to asset type.
@@ -203,37 +527,70 @@ pub mod pallet {
     pub type AssetLocationUnitsPerSecond<T: Config> =
         StorageMap<_, Twox64Concat, VersionedLocation, u128>;
 
+    /// Minimum amount of an asset which can be transferred to another chain.
+    /// Used to prevent sending dust which would be lost on the destination, e.g. due to its existential deposit.
+    ///
+    /// Not all assets have the minimum amount. If value doesn't exist here, any amount can be transferred.
+    #[pallet::storage]
+    pub type AssetMinXcmAmount<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, u128>;
+
+    /// Frozen assets, which can neither be received nor sent cross-chain.
+    /// Used to quickly halt a depegged or exploited remote asset.
+    #[pallet::storage]
+    pub type FrozenAssets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, ()>;
+
+    /// Asset Id ranges reserved either for the local or for the cross-chain assets.
+    /// Used to prevent the cross-chain assets from colliding with the locally created assets.
+    ///
+    /// If no range is reserved for the cross-chain assets, they can use any Id which isn't reserved for the local assets.
+    #[pallet::storage]
+    pub type ReservedAssetIdRanges<T: Config> =
+        StorageValue<_, BoundedVec<AssetIdRange<T::AssetId>, T::MaxReservedRanges>, ValueQuery>;
+
+    /// Mapping from a local NFT collection Id to the cross-chain collection location.
+    /// Used when sending collection items to another chain.
+    #[pallet::storage]
+    pub type NftCollectionIdToLocation<T: Config> =
+        StorageMap<_, Twox64Concat, T::CollectionId, VersionedLocation>;
+
+    /// Mapping from a cross-chain collection location to the local NFT collection Id.
+    /// Used when receiving collection items from another chain, to find the local collection in which they should be minted.
+    #[pallet::storage]
+    pub type NftCollectionLocationToId<T: Config> =
+        StorageMap<_, Twox64Concat, VersionedLocation, T::CollectionId>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register new asset location to asset Id mapping.
         ///
         /// This makes the asset eligible for XCM interaction.
+        ///
+        /// If `metadata` is provided, the local asset representation is created as well, using the provided metadata.
+        /// Otherwise, the local asset is expected to be created separately.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::register_asset_location())]
+        #[pallet::weight(T::WeightInfo::register_asset_location().saturating_add(
+            metadata.as_ref().map_or(Weight::zero(), |_| T::AssetRegistrar::create_asset_weight())
+        ))]
         pub fn register_asset_location(
             origin: OriginFor<T>,
             asset_location: Box<VersionedLocation>,
             #[pallet::compact] asset_id: T::AssetId,
+            metadata: Option<AssetMetadata>,
         ) -> DispatchResult {
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            // Ensure such an assetId does not exist
-            ensure!(
-                !AssetIdToLocation::<T>::contains_key(&asset_id),
-                Error::<T>::AssetAlreadyRegistered
-            );
-
-            let v4_asset_loc = Location::try_from(*asset_location)
-                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
-            let asset_location = VersionedLocation::V4(v4_asset_loc);
+            if metadata.is_some() {
+                ensure!(
+                    !T::AssetRegistrar::asset_exists(asset_id),
+                    Error::<T>::AssetIdInUse
+                );
+            }
+            Self::do_register_asset_location(*asset_location, asset_id)?;
 
-            AssetIdToLocation::<T>::insert(&asset_id, asset_location.clone());
-            AssetLocationToId::<T>::insert(&asset_location, asset_id);
+            if let Some(metadata) = metadata {
+                T::AssetRegistrar::create_asset(asset_id, metadata)?;
+            }
 
-            Self::deposit_event(Event::AssetRegistered {
-                asset_location,
-                asset_id,
-            });
             Ok(())
         }
 
@@ -248,22 +605,7 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            let v4_asset_loc = Location::try_from(*asset_location)
-                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
-            let asset_location = VersionedLocation::V4(v4_asset_loc);
-
-            ensure!(
-                AssetLocationToId::<T>::contains_key(&asset_location),
-                Error::<T>::AssetDoesNotExist
-            );
-
-            AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
-
-            Self::deposit_event(Event::UnitsPerSecondChanged {
-                asset_location,
-                units_per_second,
-            });
-            Ok(())
+            Self::do_set_asset_units_per_second(*asset_location, units_per_second)
         }
 
         /// Change the xcm type mapping for a given asset Id.
@@ -277,9 +619,7 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            let v4_asset_loc = Location::try_from(*new_asset_location)
-                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
-            let new_asset_location = VersionedLocation::V4(v4_asset_loc);
+            let new_asset_location = Self::latest_versioned_location(*new_asset_location)?;
 
             let previous_asset_location =
                 AssetIdToLocation::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
@@ -315,9 +655,7 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ManagerOrigin::ensure_origin(origin)?;
 
-            let v4_asset_loc = Location::try_from(*asset_location)
-                .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
-            let asset_location = VersionedLocation::V4(v4_asset_loc);
+            let asset_location = Self::latest_versioned_location(*asset_location)?;
 
             AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
 
@@ -340,6 +678,8 @@ pub mod pallet {
             AssetIdToLocation::<T>::remove(&asset_id);
             AssetLocationToId::<T>::remove(&asset_location);
             AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            AssetMinXcmAmount::<T>::remove(&asset_id);
+            FrozenAssets::<T>::remove(&asset_id);
 
             Self::deposit_event(Event::AssetRemoved {
                 asset_id,
@@ -347,5 +687,277 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Register multiple assets at once, setting the amount of units we are charging per execution second for each of them.
+        ///
+        /// Either all the assets are registered, or none of them is.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::register_assets(assets.len() as u32))]
+        pub fn register_assets(
+            origin: OriginFor<T>,
+            assets: BoundedVec<(VersionedLocation, T::AssetId, u128), T::MaxBatchSize>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            for (asset_location, asset_id, units_per_second) in assets {
+                Self::do_register_asset_location(asset_location.clone(), asset_id)?;
+                Self::do_set_asset_units_per_second(asset_location, units_per_second)?;
+            }
+
+            Ok(())
+        }
+
+        /// Change the amount of units we are charging per execution second for multiple AssetLocations at once.
+        ///
+        /// Either all the values are changed, or none of them is.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_assets_units_per_second(units.len() as u32))]
+        pub fn set_assets_units_per_second(
+            origin: OriginFor<T>,
+            units: BoundedVec<(VersionedLocation, u128), T::MaxBatchSize>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            for (asset_location, units_per_second) in units {
+                Self::do_set_asset_units_per_second(asset_location, units_per_second)?;
+            }
+
+            Ok(())
+        }
+
+        /// Set the minimum amount of the asset which can be transferred to another chain.
+        ///
+        /// Outgoing transfers of smaller amounts are supposed to be rejected, since the transferred
+        /// dust would be lost on the destination. `None` removes the minimum amount.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_min_xcm_amount())]
+        pub fn set_min_xcm_amount(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            min_xcm_amount: Option<u128>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetDoesNotExist
+            );
+
+            AssetMinXcmAmount::<T>::set(&asset_id, min_xcm_amount);
+
+            Self::deposit_event(Event::MinXcmAmountChanged {
+                asset_id,
+                min_xcm_amount,
+            });
+            Ok(())
+        }
+
+        /// Freeze the asset, halting its cross-chain transfers.
+        ///
+        /// Incoming reserve transfers of the asset won't be minted, and outgoing transfers will be rejected.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::freeze_xc_asset())]
+        pub fn freeze_xc_asset(
+            origin: OriginFor<T>,
+            asset_location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let asset_location = Self::latest_versioned_location(*asset_location)?;
+            let asset_id = AssetLocationToId::<T>::get(&asset_location)
+                .ok_or(Error::<T>::AssetDoesNotExist)?;
+            ensure!(
+                !FrozenAssets::<T>::contains_key(&asset_id),
+                Error::<T>::AssetAlreadyFrozen
+            );
+
+            FrozenAssets::<T>::insert(&asset_id, ());
+
+            Self::deposit_event(Event::AssetFrozen {
+                asset_location,
+                asset_id,
+            });
+            Ok(())
+        }
+
+        /// Thaw the previously frozen asset, resuming its cross-chain transfers.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::thaw_xc_asset())]
+        pub fn thaw_xc_asset(
+            origin: OriginFor<T>,
+            asset_location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let asset_location = Self::latest_versioned_location(*asset_location)?;
+            let asset_id = AssetLocationToId::<T>::get(&asset_location)
+                .ok_or(Error::<T>::AssetDoesNotExist)?;
+            ensure!(
+                FrozenAssets::<T>::contains_key(&asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+
+            FrozenAssets::<T>::remove(&asset_id);
+
+            Self::deposit_event(Event::AssetThawed {
+                asset_location,
+                asset_id,
+            });
+            Ok(())
+        }
+
+        /// Reserve the inclusive range of asset Ids for the local or for the cross-chain assets.
+        ///
+        /// Cross-chain assets can't be registered with the Ids reserved for the local assets.
+        /// Once any range is reserved for the cross-chain assets, they can only be registered with the Ids from such ranges.
+        /// Already registered assets aren't affected.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::reserve_asset_id_range())]
+        pub fn reserve_asset_id_range(
+            origin: OriginFor<T>,
+            start: T::AssetId,
+            end: T::AssetId,
+            space: AssetIdSpace,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(start <= end, Error::<T>::InvalidAssetIdRange);
+
+            let range = AssetIdRange { start, end, space };
+            ReservedAssetIdRanges::<T>::try_mutate(|ranges| -> DispatchResult {
+                ensure!(
+                    !ranges.iter().any(|reserved| reserved.overlaps(&range)),
+                    Error::<T>::AssetIdRangeOverlap
+                );
+                ranges
+                    .try_push(range)
+                    .map_err(|_| Error::<T>::TooManyReservedRanges)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AssetIdRangeReserved { start, end, space });
+            Ok(())
+        }
+
+        /// Remove the reservation of the asset Ids range.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_asset_id_range())]
+        pub fn remove_asset_id_range(
+            origin: OriginFor<T>,
+            start: T::AssetId,
+            end: T::AssetId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ReservedAssetIdRanges::<T>::try_mutate(|ranges| -> DispatchResult {
+                let index = ranges
+                    .iter()
+                    .position(|range| range.start == start && range.end == end)
+                    .ok_or(Error::<T>::AssetIdRangeNotFound)?;
+                ranges.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AssetIdRangeRemoved { start, end });
+            Ok(())
+        }
+
+        /// Register new NFT collection location to local collection Id mapping.
+        ///
+        /// This makes the collection items eligible for XCM interaction.
+        /// The local collection is expected to be created separately.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::register_nft_collection())]
+        pub fn register_nft_collection(
+            origin: OriginFor<T>,
+            collection_location: Box<VersionedLocation>,
+            collection_id: T::CollectionId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let collection_location = Self::latest_versioned_location(*collection_location)?;
+            ensure!(
+                !NftCollectionIdToLocation::<T>::contains_key(&collection_id)
+                    && !NftCollectionLocationToId::<T>::contains_key(&collection_location),
+                Error::<T>::NftCollectionAlreadyRegistered
+            );
+
+            NftCollectionIdToLocation::<T>::insert(&collection_id, collection_location.clone());
+            NftCollectionLocationToId::<T>::insert(&collection_location, collection_id);
+
+            Self::deposit_event(Event::NftCollectionRegistered {
+                collection_location,
+                collection_id,
+            });
+            Ok(())
+        }
+
+        /// Remove the mapping of the local NFT collection Id, halting the cross-chain transfers of its items.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::remove_nft_collection())]
+        pub fn remove_nft_collection(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let collection_location = NftCollectionIdToLocation::<T>::get(&collection_id)
+                .ok_or(Error::<T>::NftCollectionDoesNotExist)?;
+
+            NftCollectionIdToLocation::<T>::remove(&collection_id);
+            NftCollectionLocationToId::<T>::remove(&collection_location);
+
+            Self::deposit_event(Event::NftCollectionRemoved {
+                collection_location,
+                collection_id,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Register new asset location to asset Id mapping.
+        fn do_register_asset_location(
+            asset_location: VersionedLocation,
+            asset_id: T::AssetId,
+        ) -> DispatchResult {
+            // Ensure such an assetId does not exist
+            ensure!(
+                !AssetIdToLocation::<T>::contains_key(&asset_id),
+                Error::<T>::AssetAlreadyRegistered
+            );
+            Self::ensure_cross_chain_asset_id(asset_id)?;
+
+            let asset_location = Self::latest_versioned_location(asset_location)?;
+
+            AssetIdToLocation::<T>::insert(&asset_id, asset_location.clone());
+            AssetLocationToId::<T>::insert(&asset_location, asset_id);
+
+            Self::deposit_event(Event::AssetRegistered {
+                asset_location,
+                asset_id,
+            });
+            Ok(())
+        }
+
+        /// Change the amount of units we are charging per execution second for a given registered AssetLocation.
+        fn do_set_asset_units_per_second(
+            asset_location: VersionedLocation,
+            units_per_second: u128,
+        ) -> DispatchResult {
+            let asset_location = Self::latest_versioned_location(asset_location)?;
+
+            ensure!(
+                AssetLocationToId::<T>::contains_key(&asset_location),
+                Error::<T>::AssetDoesNotExist
+            );
+
+            AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
+
+            Self::deposit_event(Event::UnitsPerSecondChanged {
+                asset_location,
+                units_per_second,
+            });
+            Ok(())
+        }
     }
 }
@@ -22,7 +22,7 @@ use frame_support::{
     traits::{Get, UncheckedOnRuntimeUpgrade},
 };
 use sp_std::{marker::PhantomData, vec::Vec};
-use xcm::{IntoVersion, VersionedLocation};
+use xcm::{IdentifyVersion, IntoVersion, VersionedLocation};
 
 /// Exports for versioned migration `type`s for this pallet.
 pub mod versioned {
@@ -37,6 +37,16 @@ pub mod versioned {
         Pallet<T>,
         <T as frame_system::Config>::DbWeight,
     >;
+
+    /// Migration storage V3 to V4 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 3.
+    pub type V3ToV4<T> = frame_support::migrations::VersionedMigration<
+        3,
+        4,
+        MigrationXcmLatest<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
 }
 
 pub struct MigrationXcmV3<T: Config>(PhantomData<T>);
@@ -218,3 +228,101 @@ impl<T: Config> UncheckedOnRuntimeUpgrade for MigrationXcmV4<T> {
         Ok(())
     }
 }
+
+/// Re-encodes all the stored locations into the latest XCM version.
+///
+/// Since locations are used as storage keys, lookups only work if the keys are encoded in the same version
+/// as the lookup location, which is always the latest one.
+pub struct MigrationXcmLatest<T: Config>(PhantomData<T>);
+impl<T: Config> MigrationXcmLatest<T> {
+    /// Number of entries in each of the storage maps.
+    #[cfg(feature = "try-runtime")]
+    fn entries_count() -> (u32, u32, u32) {
+        (
+            AssetIdToLocation::<T>::iter_keys().count() as u32,
+            AssetLocationToId::<T>::iter_keys().count() as u32,
+            AssetLocationUnitsPerSecond::<T>::iter_keys().count() as u32,
+        )
+    }
+}
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for MigrationXcmLatest<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let latest_version = xcm::latest::VERSION;
+        let mut consumed_weight = Weight::zero();
+
+        // 1st map
+        AssetIdToLocation::<T>::translate::<VersionedLocation, _>(|asset_id, location| {
+            consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            location
+                .into_version(latest_version)
+                .map_err(|_| {
+                    log::error!(
+                        "Failed to convert AssetIdToLocation value for asset Id: {asset_id:?}",
+                    );
+                })
+                .ok()
+        });
+
+        // 2nd map
+        let location_to_id_entries: Vec<_> = AssetLocationToId::<T>::drain().collect();
+        for (location, asset_id) in location_to_id_entries {
+            consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+            if let Ok(new_location) = location.into_version(latest_version) {
+                AssetLocationToId::<T>::insert(new_location, asset_id);
+            } else {
+                log::error!("Failed to convert AssetLocationToId key for asset Id: {asset_id:?}",);
+            }
+        }
+
+        // 3rd map
+        let location_to_price_entries: Vec<_> = AssetLocationUnitsPerSecond::<T>::drain().collect();
+        for (location, price) in location_to_price_entries {
+            consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+            if let Ok(new_location) = location.into_version(latest_version) {
+                AssetLocationUnitsPerSecond::<T>::insert(new_location, price);
+            } else {
+                log::error!("Failed to convert AssetLocationUnitsPerSecond key!");
+            }
+        }
+
+        consumed_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok(Self::entries_count().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let old_count: (u32, u32, u32) = Decode::decode(&mut state.as_ref())
+            .map_err(|_| "Cannot decode data from pre_upgrade")?;
+        ensure!(
+            old_count == Self::entries_count(),
+            "Number of entries must remain the same."
+        );
+
+        let latest_version = xcm::latest::VERSION;
+        for (asset_id, location) in AssetIdToLocation::<T>::iter() {
+            ensure!(
+                location.identify_version() == latest_version,
+                "AssetIdToLocation value must be in the latest version."
+            );
+            ensure!(
+                AssetLocationToId::<T>::get(&location) == Some(asset_id),
+                "AssetLocationToId must map the location back to the asset Id."
+            );
+        }
+        for location in AssetLocationUnitsPerSecond::<T>::iter_keys() {
+            ensure!(
+                location.identify_version() == latest_version,
+                "AssetLocationUnitsPerSecond key must be in the latest version."
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -16,15 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_xc_asset_config};
+use crate::{self as pallet_xc_asset_config, AssetMetadata, AssetRegistrar};
 
-use frame_support::{construct_runtime, parameter_types, traits::ConstU32, weights::Weight};
+use frame_support::{
+    construct_runtime, ensure, pallet_prelude::DispatchResult, parameter_types, traits::ConstU32,
+    weights::Weight,
+};
 use sp_core::H256;
 
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
 
 type Balance = u128;
@@ -103,10 +106,44 @@ impl pallet_balances::Config for Test {
 
 type AssetId = u128;
 
+parameter_types! {
+    pub static CreatedAssets: Vec<(AssetId, AssetMetadata)> = vec![];
+    pub static FailAssetCreation: bool = false;
+}
+
+/// Registrar which only records the created assets, or fails if `FailAssetCreation` is set.
+pub struct MockAssetRegistrar;
+impl AssetRegistrar<AssetId> for MockAssetRegistrar {
+    fn create_asset(asset_id: AssetId, metadata: AssetMetadata) -> DispatchResult {
+        ensure!(
+            !FailAssetCreation::get(),
+            DispatchError::Other("Asset creation failed.")
+        );
+        ensure!(
+            !CreatedAssets::get().iter().any(|(id, _)| *id == asset_id),
+            DispatchError::Other("Asset already exists.")
+        );
+        CreatedAssets::mutate(|assets| assets.push((asset_id, metadata)));
+        Ok(())
+    }
+
+    fn create_asset_weight() -> Weight {
+        Weight::from_parts(1_000, 0)
+    }
+
+    fn asset_exists(asset_id: AssetId) -> bool {
+        CreatedAssets::get().iter().any(|(id, _)| *id == asset_id)
+    }
+}
+
 impl pallet_xc_asset_config::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxBatchSize = ConstU32<4>;
+    type AssetRegistrar = MockAssetRegistrar;
+    type MaxReservedRanges = ConstU32<3>;
+    type CollectionId = u32;
     type WeightInfo = ();
 }
 
@@ -119,7 +156,11 @@ impl ExternalityBuilder {
             .unwrap();
 
         let mut ext = TestExternalities::from(storage);
-        ext.execute_with(|| System::set_block_number(1));
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            CreatedAssets::set(vec![]);
+            FailAssetCreation::set(false);
+        });
         ext
     }
 }
@@ -17,12 +17,12 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, pallet::Event, *};
-use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
+use frame_support::{assert_noop, assert_ok, traits::Contains, WeakBoundedVec};
 use mock::*;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{traits::BadOrigin, DispatchError};
 use xcm::latest::prelude::*;
 
-use xcm::{v4::Location, VersionedLocation};
+use xcm::{latest::Location, VersionedAssets, VersionedLocation};
 
 #[test]
 fn only_root_as_origin() {
@@ -34,7 +34,8 @@ fn only_root_as_origin() {
             XcAssetConfig::register_asset_location(
                 RuntimeOrigin::signed(1),
                 Box::new(asset_location.clone()),
-                asset_id
+                asset_id,
+                None
             ),
             BadOrigin
         );
@@ -69,6 +70,56 @@ fn only_root_as_origin() {
             XcAssetConfig::remove_asset(RuntimeOrigin::signed(1), asset_id,),
             BadOrigin
         );
+
+        assert_noop!(
+            XcAssetConfig::set_min_xcm_amount(RuntimeOrigin::signed(1), asset_id, Some(1)),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::freeze_xc_asset(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::thaw_xc_asset(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::reserve_asset_id_range(
+                RuntimeOrigin::signed(1),
+                1,
+                10,
+                AssetIdSpace::Local
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::remove_asset_id_range(RuntimeOrigin::signed(1), 1, 10),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::register_nft_collection(
+                RuntimeOrigin::signed(1),
+                Box::new(asset_location.clone()),
+                3
+            ),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XcAssetConfig::remove_nft_collection(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
     })
 }
 
@@ -83,7 +134,8 @@ fn register_asset_location_and_units_per_sec_is_ok() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
         System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
             asset_location: asset_location.clone().into_versioned(),
@@ -135,7 +187,8 @@ fn asset_is_already_registered() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
 
         // Now repeat the process and expect an error
@@ -143,13 +196,280 @@ fn asset_is_already_registered() {
             XcAssetConfig::register_asset_location(
                 RuntimeOrigin::root(),
                 Box::new(asset_location.clone().into_versioned()),
-                asset_id
+                asset_id,
+                None
             ),
             Error::<Test>::AssetAlreadyRegistered
         );
     })
 }
 
+#[test]
+fn register_asset_location_with_metadata_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = Location::new(1, [PalletInstance(17), GeneralIndex(7)]);
+        let asset_id = 13;
+        let metadata = AssetMetadata {
+            name: b"Test Asset".to_vec(),
+            symbol: b"TST".to_vec(),
+            decimals: 12,
+            min_balance: 1_000,
+        };
+
+        // Register asset together with its metadata
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id,
+            Some(metadata.clone())
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
+            asset_location: asset_location.clone().into_versioned(),
+            asset_id,
+        }));
+
+        // Both the mapping and the local asset must be created
+        assert_eq!(
+            AssetIdToLocation::<Test>::get(&asset_id).unwrap(),
+            asset_location.clone().into_versioned()
+        );
+        assert_eq!(CreatedAssets::get(), vec![(asset_id, metadata)]);
+    })
+}
+
+#[test]
+fn register_asset_location_with_metadata_is_atomic() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = Location::new(1, [PalletInstance(17), GeneralIndex(7)]);
+        let asset_id = 13;
+        let metadata = AssetMetadata {
+            name: b"Test Asset".to_vec(),
+            symbol: b"TST".to_vec(),
+            decimals: 12,
+            min_balance: 1_000,
+        };
+
+        // Local asset creation fails
+        FailAssetCreation::set(true);
+
+        // Expect the location registration to be reverted as well
+        assert_noop!(
+            XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                asset_id,
+                Some(metadata)
+            ),
+            DispatchError::Other("Asset creation failed.")
+        );
+        assert!(!AssetIdToLocation::<Test>::contains_key(&asset_id));
+        assert!(!AssetLocationToId::<Test>::contains_key(
+            asset_location.into_versioned()
+        ));
+    })
+}
+
+#[test]
+fn register_asset_location_with_metadata_detects_collision() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = Location::new(1, [PalletInstance(17), GeneralIndex(7)]);
+        let asset_id = 13;
+        let metadata = AssetMetadata {
+            name: b"Test Asset".to_vec(),
+            symbol: b"TST".to_vec(),
+            decimals: 12,
+            min_balance: 1_000,
+        };
+
+        // Local asset with the same Id already exists
+        CreatedAssets::set(vec![(asset_id, metadata.clone())]);
+
+        assert_noop!(
+            XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                asset_id,
+                Some(metadata)
+            ),
+            Error::<Test>::AssetIdInUse
+        );
+
+        // Existing local asset can still be registered, if no metadata is provided
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id,
+            None
+        ));
+    })
+}
+
+#[test]
+fn reserve_and_remove_asset_id_range_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(XcAssetConfig::reserve_asset_id_range(
+            RuntimeOrigin::root(),
+            1,
+            100,
+            AssetIdSpace::Local
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::AssetIdRangeReserved {
+                start: 1,
+                end: 100,
+                space: AssetIdSpace::Local,
+            },
+        ));
+        assert_ok!(XcAssetConfig::reserve_asset_id_range(
+            RuntimeOrigin::root(),
+            1_000,
+            1_000,
+            AssetIdSpace::CrossChain
+        ));
+
+        assert_eq!(XcAssetConfig::asset_id_space(0), None);
+        assert_eq!(XcAssetConfig::asset_id_space(1), Some(AssetIdSpace::Local));
+        assert_eq!(
+            XcAssetConfig::asset_id_space(100),
+            Some(AssetIdSpace::Local)
+        );
+        assert_eq!(XcAssetConfig::asset_id_space(101), None);
+        assert_eq!(
+            XcAssetConfig::asset_id_space(1_000),
+            Some(AssetIdSpace::CrossChain)
+        );
+        assert!(XcAssetConfig::is_local_asset_id_allowed(100));
+        assert!(XcAssetConfig::is_local_asset_id_allowed(101));
+        assert!(!XcAssetConfig::is_local_asset_id_allowed(1_000));
+
+        // Invalid & overlapping ranges can't be reserved
+        assert_noop!(
+            XcAssetConfig::reserve_asset_id_range(
+                RuntimeOrigin::root(),
+                20,
+                10,
+                AssetIdSpace::Local
+            ),
+            Error::<Test>::InvalidAssetIdRange
+        );
+        assert_noop!(
+            XcAssetConfig::reserve_asset_id_range(
+                RuntimeOrigin::root(),
+                100,
+                200,
+                AssetIdSpace::CrossChain
+            ),
+            Error::<Test>::AssetIdRangeOverlap
+        );
+        assert_noop!(
+            XcAssetConfig::reserve_asset_id_range(
+                RuntimeOrigin::root(),
+                0,
+                1_000,
+                AssetIdSpace::Local
+            ),
+            Error::<Test>::AssetIdRangeOverlap
+        );
+
+        // Number of reserved ranges is limited
+        assert_ok!(XcAssetConfig::reserve_asset_id_range(
+            RuntimeOrigin::root(),
+            2_000,
+            3_000,
+            AssetIdSpace::CrossChain
+        ));
+        assert_noop!(
+            XcAssetConfig::reserve_asset_id_range(
+                RuntimeOrigin::root(),
+                5_000,
+                6_000,
+                AssetIdSpace::CrossChain
+            ),
+            Error::<Test>::TooManyReservedRanges
+        );
+
+        // Range must match exactly in order to be removed
+        assert_noop!(
+            XcAssetConfig::remove_asset_id_range(RuntimeOrigin::root(), 1, 50),
+            Error::<Test>::AssetIdRangeNotFound
+        );
+        assert_ok!(XcAssetConfig::remove_asset_id_range(
+            RuntimeOrigin::root(),
+            1,
+            100
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::AssetIdRangeRemoved { start: 1, end: 100 },
+        ));
+        assert_eq!(XcAssetConfig::asset_id_space(1), None);
+        assert_eq!(ReservedAssetIdRanges::<Test>::get().len(), 2);
+    })
+}
+
+#[test]
+fn registration_respects_reserved_asset_id_ranges() {
+    ExternalityBuilder::build().execute_with(|| {
+        let location = |index: u32| Box::new(Location::new(1, [Parachain(index)]).into_versioned());
+
+        // Without any reservation, any Id can be used
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            location(1),
+            5,
+            None
+        ));
+
+        // Ids reserved for the local assets can't be used
+        assert_ok!(XcAssetConfig::reserve_asset_id_range(
+            RuntimeOrigin::root(),
+            1,
+            100,
+            AssetIdSpace::Local
+        ));
+        assert_noop!(
+            XcAssetConfig::register_asset_location(RuntimeOrigin::root(), location(2), 50, None),
+            Error::<Test>::AssetIdReservedForLocal
+        );
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            location(2),
+            500,
+            None
+        ));
+
+        // Once a cross-chain range is reserved, only its Ids can be used
+        assert_ok!(XcAssetConfig::reserve_asset_id_range(
+            RuntimeOrigin::root(),
+            1_000,
+            1_999,
+            AssetIdSpace::CrossChain
+        ));
+        assert_noop!(
+            XcAssetConfig::register_asset_location(RuntimeOrigin::root(), location(3), 501, None),
+            Error::<Test>::AssetIdOutsideCrossChainRange
+        );
+        assert_noop!(
+            XcAssetConfig::register_assets(
+                RuntimeOrigin::root(),
+                vec![(*location(3), 1_000, 1), (*location(4), 2_000, 1)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::AssetIdOutsideCrossChainRange
+        );
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            location(3),
+            1_000,
+            None
+        ));
+
+        // Already registered assets aren't affected
+        assert_eq!(AssetIdToLocation::<Test>::get(5), Some(*location(1)));
+        assert_eq!(AssetIdToLocation::<Test>::get(500), Some(*location(2)));
+    })
+}
+
 #[test]
 fn change_asset_location_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -162,7 +482,8 @@ fn change_asset_location_is_ok() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
         assert_ok!(XcAssetConfig::set_asset_units_per_second(
             RuntimeOrigin::root(),
@@ -221,7 +542,8 @@ fn remove_payment_asset_is_ok() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
         assert_ok!(XcAssetConfig::set_asset_units_per_second(
             RuntimeOrigin::root(),
@@ -263,13 +585,23 @@ fn remove_asset_is_ok() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
         assert_ok!(XcAssetConfig::set_asset_units_per_second(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
             units
         ));
+        assert_ok!(XcAssetConfig::set_min_xcm_amount(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(1)
+        ));
+        assert_ok!(XcAssetConfig::freeze_xc_asset(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+        ));
 
         // Remove asset entirely and assert op is ok
         assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id,));
@@ -286,6 +618,237 @@ fn remove_asset_is_ok() {
         assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
             asset_location.clone().into_versioned()
         ));
+        assert!(!AssetMinXcmAmount::<Test>::contains_key(asset_id));
+        assert!(!FrozenAssets::<Test>::contains_key(asset_id));
+    })
+}
+
+#[test]
+fn freeze_and_thaw_xc_asset_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare location and Id
+        let asset_location = Location::new(1, [Parachain(2007)]);
+        let asset_id = 17;
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id,
+            None
+        ));
+        assert!(!XcAssetConfig::is_frozen(&asset_location));
+        assert!(!FrozenXcAssets::<Test>::contains(&asset_location));
+
+        // Freeze the asset
+        assert_ok!(XcAssetConfig::freeze_xc_asset(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetFrozen {
+            asset_location: asset_location.clone().into_versioned(),
+            asset_id,
+        }));
+        assert!(FrozenAssets::<Test>::contains_key(asset_id));
+        assert!(XcAssetConfig::is_frozen(&asset_location));
+        assert!(FrozenXcAssets::<Test>::contains(&asset_location));
+
+        // Other assets aren't affected
+        assert!(!FrozenXcAssets::<Test>::contains(&Location::parent()));
+
+        // Asset cannot be frozen twice
+        assert_noop!(
+            XcAssetConfig::freeze_xc_asset(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetAlreadyFrozen
+        );
+
+        // Frozen asset keeps being frozen if its location changes
+        let new_asset_location = Location::new(1, [Parachain(2008)]);
+        assert_ok!(XcAssetConfig::change_existing_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(new_asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        assert!(!XcAssetConfig::is_frozen(&asset_location));
+        assert!(XcAssetConfig::is_frozen(&new_asset_location));
+
+        // Thaw the asset
+        assert_ok!(XcAssetConfig::thaw_xc_asset(
+            RuntimeOrigin::root(),
+            Box::new(new_asset_location.clone().into_versioned()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetThawed {
+            asset_location: new_asset_location.clone().into_versioned(),
+            asset_id,
+        }));
+        assert!(!FrozenAssets::<Test>::contains_key(asset_id));
+        assert!(!FrozenXcAssets::<Test>::contains(&new_asset_location));
+
+        // Asset which isn't frozen cannot be thawed
+        assert_noop!(
+            XcAssetConfig::thaw_xc_asset(
+                RuntimeOrigin::root(),
+                Box::new(new_asset_location.into_versioned()),
+            ),
+            Error::<Test>::AssetNotFrozen
+        );
+    })
+}
+
+#[test]
+fn register_and_remove_nft_collection_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare location and Id
+        let collection_location =
+            Location::new(1, [Parachain(2007), PalletInstance(12), GeneralIndex(3)]);
+        let collection_id = 5;
+
+        // Register collection and ensure it's ok
+        assert_ok!(XcAssetConfig::register_nft_collection(
+            RuntimeOrigin::root(),
+            Box::new(collection_location.clone().into_versioned()),
+            collection_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::NftCollectionRegistered {
+                collection_location: collection_location.clone().into_versioned(),
+                collection_id,
+            },
+        ));
+        assert_eq!(
+            XcAssetConfig::get_collection_location(collection_id),
+            Some(collection_location.clone())
+        );
+        assert_eq!(
+            XcAssetConfig::get_collection_id(collection_location.clone()),
+            Some(collection_id)
+        );
+
+        // NFT collections don't interfere with the fungible assets
+        assert!(XcAssetConfig::get_asset_id(collection_location.clone()).is_none());
+
+        // Neither the Id nor the location can be registered twice
+        assert_noop!(
+            XcAssetConfig::register_nft_collection(
+                RuntimeOrigin::root(),
+                Box::new(collection_location.clone().into_versioned()),
+                collection_id + 1
+            ),
+            Error::<Test>::NftCollectionAlreadyRegistered
+        );
+        assert_noop!(
+            XcAssetConfig::register_nft_collection(
+                RuntimeOrigin::root(),
+                Box::new(Location::new(1, [Parachain(2008)]).into_versioned()),
+                collection_id
+            ),
+            Error::<Test>::NftCollectionAlreadyRegistered
+        );
+
+        // Remove collection and ensure it's ok
+        assert_ok!(XcAssetConfig::remove_nft_collection(
+            RuntimeOrigin::root(),
+            collection_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::NftCollectionRemoved {
+                collection_location: collection_location.clone().into_versioned(),
+                collection_id,
+            },
+        ));
+        assert!(!NftCollectionIdToLocation::<Test>::contains_key(
+            collection_id
+        ));
+        assert!(!NftCollectionLocationToId::<Test>::contains_key(
+            collection_location.into_versioned()
+        ));
+
+        // Collection which isn't registered cannot be removed
+        assert_noop!(
+            XcAssetConfig::remove_nft_collection(RuntimeOrigin::root(), collection_id),
+            Error::<Test>::NftCollectionDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn set_min_xcm_amount_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare location, Id and min amount
+        let asset_location = Location::new(1, [Parachain(2007)]);
+        let asset_id = 17;
+        let min_xcm_amount = 1_000;
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id,
+            None
+        ));
+
+        // Any amount can be transferred without the min amount
+        assert!(XcAssetConfig::meets_min_xcm_amount(asset_id, 1));
+
+        // Set the min amount and expect amounts below it to be rejected
+        assert_ok!(XcAssetConfig::set_min_xcm_amount(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(min_xcm_amount)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::MinXcmAmountChanged {
+                asset_id,
+                min_xcm_amount: Some(min_xcm_amount),
+            },
+        ));
+        assert_eq!(
+            AssetMinXcmAmount::<Test>::get(asset_id),
+            Some(min_xcm_amount)
+        );
+
+        assert!(!XcAssetConfig::meets_min_xcm_amount(
+            asset_id,
+            min_xcm_amount - 1
+        ));
+        assert!(XcAssetConfig::meets_min_xcm_amount(
+            asset_id,
+            min_xcm_amount
+        ));
+
+        // Same applies to the assets represented by the registered location
+        let dust: Asset = (asset_location.clone(), min_xcm_amount - 1).into();
+        let enough: Asset = (asset_location.clone(), min_xcm_amount).into();
+        let unregistered: Asset = (Location::parent(), 1).into();
+
+        assert!(!XcAssetConfig::asset_meets_min_xcm_amount(&dust));
+        assert!(XcAssetConfig::asset_meets_min_xcm_amount(&enough));
+        assert!(XcAssetConfig::asset_meets_min_xcm_amount(&unregistered));
+        assert!(!XcAssetConfig::versioned_asset_meets_min_xcm_amount(
+            &dust.clone().into()
+        ));
+        assert!(XcAssetConfig::versioned_assets_meet_min_xcm_amount(
+            &VersionedAssets::from(Assets::from(vec![enough, unregistered.clone()]))
+        ));
+        assert!(!XcAssetConfig::versioned_assets_meet_min_xcm_amount(
+            &VersionedAssets::from(Assets::from(vec![dust, unregistered]))
+        ));
+
+        // Clear the min amount and expect any amount to be accepted again
+        assert_ok!(XcAssetConfig::set_min_xcm_amount(
+            RuntimeOrigin::root(),
+            asset_id,
+            None
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::MinXcmAmountChanged {
+                asset_id,
+                min_xcm_amount: None,
+            },
+        ));
+        assert!(!AssetMinXcmAmount::<Test>::contains_key(asset_id));
+        assert!(XcAssetConfig::meets_min_xcm_amount(asset_id, 1));
     })
 }
 
@@ -319,6 +882,27 @@ fn not_registered_asset_is_not_ok() {
             XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id,),
             Error::<Test>::AssetDoesNotExist
         );
+
+        assert_noop!(
+            XcAssetConfig::set_min_xcm_amount(RuntimeOrigin::root(), asset_id, Some(1)),
+            Error::<Test>::AssetDoesNotExist
+        );
+
+        assert_noop!(
+            XcAssetConfig::freeze_xc_asset(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+
+        assert_noop!(
+            XcAssetConfig::thaw_xc_asset(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
     })
 }
 
@@ -339,7 +923,8 @@ fn public_interfaces_are_ok() {
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(asset_location.clone().into_versioned()),
-            asset_id
+            asset_id,
+            None
         ));
         assert_eq!(
             XcAssetConfig::get_xc_asset_location(asset_id),
@@ -364,19 +949,79 @@ fn public_interfaces_are_ok() {
     })
 }
 
+#[test]
+fn registered_assets_are_listed() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert!(XcAssetConfig::registered_assets().is_empty());
+        assert!(XcAssetConfig::registered_asset(1).is_none());
+
+        // Register two assets, only one of them used for execution payment
+        let payment_location = Location::parent();
+        let other_location = Location::new(1, [Parachain(2000)]);
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(payment_location.clone().into_versioned()),
+            1,
+            None
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(payment_location.clone().into_versioned()),
+            123
+        ));
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(other_location.clone().into_versioned()),
+            2,
+            None
+        ));
+
+        let payment_asset = (1, payment_location.into_versioned(), 123, true);
+        let other_asset = (2, other_location.into_versioned(), 0, false);
+        assert_eq!(
+            XcAssetConfig::registered_asset(1),
+            Some(payment_asset.clone())
+        );
+        assert_eq!(
+            XcAssetConfig::registered_asset(2),
+            Some(other_asset.clone())
+        );
+
+        let mut assets = XcAssetConfig::registered_assets();
+        assets.sort_by_key(|(asset_id, ..)| *asset_id);
+        assert_eq!(assets, vec![payment_asset, other_asset.clone()]);
+
+        // Removed payment asset is still listed, but can't be used for execution payment anymore
+        assert_ok!(XcAssetConfig::remove_payment_asset(
+            RuntimeOrigin::root(),
+            Box::new(Location::parent().into_versioned())
+        ));
+        assert_eq!(
+            XcAssetConfig::registered_asset(1),
+            Some((1, Location::parent().into_versioned(), 0, false))
+        );
+
+        // Removed asset isn't listed anymore
+        assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), 1));
+        assert!(XcAssetConfig::registered_asset(1).is_none());
+        assert_eq!(XcAssetConfig::registered_assets(), vec![other_asset]);
+    })
+}
+
 #[test]
 fn different_xcm_versions_are_ok() {
     ExternalityBuilder::build().execute_with(|| {
         // Prepare location and Id
         let legacy_asset_location = xcm::v3::MultiLocation::parent();
-        let new_asset_location = xcm::v4::Location::parent();
+        let new_asset_location = Location::parent();
         let asset_id = 17;
 
         // Register asset using legacy multilocation
         assert_ok!(XcAssetConfig::register_asset_location(
             RuntimeOrigin::root(),
             Box::new(VersionedLocation::V3(legacy_asset_location.clone())),
-            asset_id
+            asset_id,
+            None
         ));
 
         // Ensure that the new format is properly returned
@@ -405,7 +1050,8 @@ fn incompatible_versioned_multilocations_are_not_ok() {
             XcAssetConfig::register_asset_location(
                 RuntimeOrigin::root(),
                 Box::new(VersionedLocation::V2(incompatible_asset_location.clone())),
-                asset_id
+                asset_id,
+                None
             ),
             Error::<Test>::MultiLocationNotSupported
         );
@@ -437,3 +1083,188 @@ fn incompatible_versioned_multilocations_are_not_ok() {
         );
     })
 }
+
+#[test]
+fn register_assets_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Prepare the asset family
+        let assets: Vec<_> = (1..=3)
+            .map(|index| {
+                (
+                    Location::new(1, [Parachain(1000), GeneralIndex(index)]),
+                    index,
+                    index * 100,
+                )
+            })
+            .collect();
+        let batch: Vec<_> = assets
+            .iter()
+            .map(|(asset_location, asset_id, units)| {
+                (asset_location.clone().into_versioned(), *asset_id, *units)
+            })
+            .collect();
+
+        // Only root can register the assets
+        assert_noop!(
+            XcAssetConfig::register_assets(
+                RuntimeOrigin::signed(1),
+                batch.clone().try_into().unwrap()
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(XcAssetConfig::register_assets(
+            RuntimeOrigin::root(),
+            batch.try_into().unwrap()
+        ));
+
+        // Ensure all the assets are registered & usable for payment
+        for (asset_location, asset_id, units) in assets {
+            System::assert_has_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetRegistered {
+                asset_location: asset_location.clone().into_versioned(),
+                asset_id,
+            }));
+            assert_eq!(
+                XcAssetConfig::get_asset_id(asset_location.clone()),
+                Some(asset_id)
+            );
+            assert_eq!(
+                XcAssetConfig::get_units_per_second(asset_location),
+                Some(units)
+            );
+        }
+    })
+}
+
+#[test]
+fn register_assets_is_atomic() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = Location::parent();
+        let asset_id = 17;
+
+        // Duplicated asset Id in the batch fails the whole batch
+        let batch = vec![
+            (Location::here().into_versioned(), asset_id, 100),
+            (asset_location.clone().into_versioned(), asset_id, 200),
+        ];
+        assert_noop!(
+            XcAssetConfig::register_assets(RuntimeOrigin::root(), batch.try_into().unwrap()),
+            Error::<Test>::AssetAlreadyRegistered
+        );
+
+        // Incompatible location in the batch fails the whole batch
+        #[allow(deprecated)]
+        let batch = vec![
+            (asset_location.clone().into_versioned(), asset_id, 100),
+            (
+                VersionedLocation::V2(xcm::v2::MultiLocation {
+                    parents: 1,
+                    interior: xcm::v2::Junctions::X1(xcm::v2::Junction::GeneralKey(
+                        WeakBoundedVec::<_, _>::force_from([123_u8; 33].to_vec(), None),
+                    )),
+                }),
+                asset_id + 1,
+                200,
+            ),
+        ];
+        assert_noop!(
+            XcAssetConfig::register_assets(RuntimeOrigin::root(), batch.try_into().unwrap()),
+            Error::<Test>::MultiLocationNotSupported
+        );
+    })
+}
+
+#[test]
+fn set_assets_units_per_second_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location_1 = Location::parent();
+        let asset_location_2 = Location::new(1, [Parachain(1000)]);
+        for (asset_location, asset_id) in [(&asset_location_1, 1), (&asset_location_2, 2)] {
+            assert_ok!(XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone().into_versioned()),
+                asset_id,
+                None
+            ));
+        }
+
+        // Only root can set the units per second
+        let batch = vec![
+            (asset_location_1.clone().into_versioned(), 11),
+            (asset_location_2.clone().into_versioned(), 22),
+        ];
+        assert_noop!(
+            XcAssetConfig::set_assets_units_per_second(
+                RuntimeOrigin::signed(1),
+                batch.clone().try_into().unwrap()
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(XcAssetConfig::set_assets_units_per_second(
+            RuntimeOrigin::root(),
+            batch.try_into().unwrap()
+        ));
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location_1.clone()),
+            Some(11)
+        );
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location_2.clone()),
+            Some(22)
+        );
+
+        // Not registered asset in the batch fails the whole batch
+        let batch = vec![
+            (asset_location_1.clone().into_versioned(), 33),
+            (Location::here().into_versioned(), 44),
+        ];
+        assert_noop!(
+            XcAssetConfig::set_assets_units_per_second(
+                RuntimeOrigin::root(),
+                batch.try_into().unwrap()
+            ),
+            Error::<Test>::AssetDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn migration_to_latest_xcm_location_works() {
+    use crate::migrations::versioned::V3ToV4;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    ExternalityBuilder::build().execute_with(|| {
+        StorageVersion::new(3).put::<XcAssetConfig>();
+
+        // Prepare legacy entries
+        let legacy_asset_location = VersionedLocation::V3(xcm::v3::MultiLocation::parent());
+        let asset_id = 7;
+        let units = 123;
+        AssetIdToLocation::<Test>::insert(asset_id, legacy_asset_location.clone());
+        AssetLocationToId::<Test>::insert(legacy_asset_location.clone(), asset_id);
+        AssetLocationUnitsPerSecond::<Test>::insert(legacy_asset_location.clone(), units);
+
+        // Legacy key cannot be found using the latest location
+        assert!(XcAssetConfig::get_asset_id(Location::parent()).is_none());
+
+        V3ToV4::<Test>::on_runtime_upgrade();
+
+        // Ensure all entries are re-encoded into the latest version
+        assert_eq!(XcAssetConfig::on_chain_storage_version(), 4);
+        assert!(AssetLocationToId::<Test>::get(&legacy_asset_location).is_none());
+        assert!(AssetLocationUnitsPerSecond::<Test>::get(&legacy_asset_location).is_none());
+        assert_eq!(
+            AssetIdToLocation::<Test>::get(asset_id),
+            Some(Location::parent().into_versioned())
+        );
+        assert_eq!(
+            XcAssetConfig::get_asset_id(Location::parent()),
+            Some(asset_id)
+        );
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(Location::parent()),
+            Some(units)
+        );
+    })
+}
@@ -52,6 +52,15 @@ pub trait WeightInfo {
 	fn change_existing_asset_location() -> Weight;
 	fn remove_payment_asset() -> Weight;
 	fn remove_asset() -> Weight;
+	fn register_assets(n: u32, ) -> Weight;
+	fn set_assets_units_per_second(n: u32, ) -> Weight;
+	fn set_min_xcm_amount() -> Weight;
+	fn freeze_xc_asset() -> Weight;
+	fn thaw_xc_asset() -> Weight;
+	fn reserve_asset_id_range() -> Weight;
+	fn remove_asset_id_range() -> Weight;
+	fn register_nft_collection() -> Weight;
+	fn remove_nft_collection() -> Weight;
 }
 
 /// Weights for pallet_xc_asset_config using the Substrate node and recommended hardware.
@@ -59,15 +68,17 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:0)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
 	fn register_asset_location() -> Weight {
-		// Minimum execution time: 15_540 nanoseconds.
-		Weight::from_parts(16_114_000, 0)
+		// Minimum execution time: 17_062 nanoseconds.
+		Weight::from_parts(17_703_000, 0)
 			.saturating_add(Weight::from_parts(0, 2493))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
@@ -110,12 +121,124 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetMinXcmAmount (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetMinXcmAmount (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:0 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
 		// Minimum execution time: 18_645 nanoseconds.
 		Weight::from_parts(18_878_000, 0)
 			.saturating_add(Weight::from_parts(0, 2987))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:32 w:32)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:0)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:32 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn register_assets(n: u32, ) -> Weight {
+		// Minimum execution time: 30_112 nanoseconds.
+		Weight::from_parts(6_419_338, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 4_712
+			.saturating_add(Weight::from_parts(25_284_113, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2661).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:32 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn set_assets_units_per_second(n: u32, ) -> Weight {
+		// Minimum execution time: 15_873 nanoseconds.
+		Weight::from_parts(5_902_114, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 3_208
+			.saturating_add(Weight::from_parts(10_417_926, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2661).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetMinXcmAmount (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetMinXcmAmount (max_values: None, max_size: None, mode: Measured)
+	fn set_min_xcm_amount() -> Weight {
+		// Minimum execution time: 12_411 nanoseconds.
+		Weight::from_parts(12_784_000, 0)
+			.saturating_add(Weight::from_parts(0, 2493))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	fn freeze_xc_asset() -> Weight {
+		// Minimum execution time: 14_302 nanoseconds.
+		Weight::from_parts(14_686_000, 0)
+			.saturating_add(Weight::from_parts(0, 4970))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	fn thaw_xc_asset() -> Weight {
+		// Minimum execution time: 14_118 nanoseconds.
+		Weight::from_parts(14_455_000, 0)
+			.saturating_add(Weight::from_parts(0, 4970))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:1)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	fn reserve_asset_id_range() -> Weight {
+		// Minimum execution time: 11_942 nanoseconds.
+		Weight::from_parts(12_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 2224))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:1)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	fn remove_asset_id_range() -> Weight {
+		// Minimum execution time: 11_507 nanoseconds.
+		Weight::from_parts(11_896_000, 0)
+			.saturating_add(Weight::from_parts(0, 2224))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig NftCollectionIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig NftCollectionLocationToId (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionLocationToId (max_values: None, max_size: None, mode: Measured)
+	fn register_nft_collection() -> Weight {
+		// Minimum execution time: 16_218 nanoseconds.
+		Weight::from_parts(16_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 5014))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig NftCollectionIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig NftCollectionLocationToId (r:0 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionLocationToId (max_values: None, max_size: None, mode: Measured)
+	fn remove_nft_collection() -> Weight {
+		// Minimum execution time: 15_604 nanoseconds.
+		Weight::from_parts(16_125_000, 0)
+			.saturating_add(Weight::from_parts(0, 2503))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
@@ -123,15 +246,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:0)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
 	fn register_asset_location() -> Weight {
-		// Minimum execution time: 15_540 nanoseconds.
-		Weight::from_parts(16_114_000, 0)
+		// Minimum execution time: 17_062 nanoseconds.
+		Weight::from_parts(17_703_000, 0)
 			.saturating_add(Weight::from_parts(0, 2493))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
@@ -174,11 +299,123 @@ impl WeightInfo for () {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetMinXcmAmount (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetMinXcmAmount (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:0 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
 		// Minimum execution time: 18_645 nanoseconds.
 		Weight::from_parts(18_878_000, 0)
 			.saturating_add(Weight::from_parts(0, 2987))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:32 w:32)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:0)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:32 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn register_assets(n: u32, ) -> Weight {
+		// Minimum execution time: 30_112 nanoseconds.
+		Weight::from_parts(6_419_338, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 4_712
+			.saturating_add(Weight::from_parts(25_284_113, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2661).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:32 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:32)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn set_assets_units_per_second(n: u32, ) -> Weight {
+		// Minimum execution time: 15_873 nanoseconds.
+		Weight::from_parts(5_902_114, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 3_208
+			.saturating_add(Weight::from_parts(10_417_926, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2661).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetMinXcmAmount (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetMinXcmAmount (max_values: None, max_size: None, mode: Measured)
+	fn set_min_xcm_amount() -> Weight {
+		// Minimum execution time: 12_411 nanoseconds.
+		Weight::from_parts(12_784_000, 0)
+			.saturating_add(Weight::from_parts(0, 2493))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	fn freeze_xc_asset() -> Weight {
+		// Minimum execution time: 14_302 nanoseconds.
+		Weight::from_parts(14_686_000, 0)
+			.saturating_add(Weight::from_parts(0, 4970))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig FrozenAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig FrozenAssets (max_values: None, max_size: None, mode: Measured)
+	fn thaw_xc_asset() -> Weight {
+		// Minimum execution time: 14_118 nanoseconds.
+		Weight::from_parts(14_455_000, 0)
+			.saturating_add(Weight::from_parts(0, 4970))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:1)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	fn reserve_asset_id_range() -> Weight {
+		// Minimum execution time: 11_942 nanoseconds.
+		Weight::from_parts(12_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 2224))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig ReservedAssetIdRanges (r:1 w:1)
+	// Proof Skipped: XcAssetConfig ReservedAssetIdRanges (max_values: Some(1), max_size: None, mode: Measured)
+	fn remove_asset_id_range() -> Weight {
+		// Minimum execution time: 11_507 nanoseconds.
+		Weight::from_parts(11_896_000, 0)
+			.saturating_add(Weight::from_parts(0, 2224))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig NftCollectionIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig NftCollectionLocationToId (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionLocationToId (max_values: None, max_size: None, mode: Measured)
+	fn register_nft_collection() -> Weight {
+		// Minimum execution time: 16_218 nanoseconds.
+		Weight::from_parts(16_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 5014))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig NftCollectionIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig NftCollectionLocationToId (r:0 w:1)
+	// Proof Skipped: XcAssetConfig NftCollectionLocationToId (max_values: None, max_size: None, mode: Measured)
+	fn remove_nft_collection() -> Weight {
+		// Minimum execution time: 15_604 nanoseconds.
+		Weight::from_parts(16_125_000, 0)
+			.saturating_add(Weight::from_parts(0, 2503))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
\ No newline at end of file
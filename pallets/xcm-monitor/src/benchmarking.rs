@@ -0,0 +1,104 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::Pallet as System;
+
+/// Assert that the last event equals the provided one.
+pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+#[benchmarks()]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_thresholds() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let thresholds = AlertThresholds {
+            max_failures: Some(1),
+            max_weight: Some(Weight::from_parts(1, 1)),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, thresholds);
+
+        assert_last_event::<T>(Event::<T>::ThresholdsSet { thresholds }.into());
+    }
+
+    #[benchmark]
+    fn note_message() {
+        let now = System::<T>::block_number();
+
+        #[block]
+        {
+            Pallet::<T>::note_inbound(Channel::Parent, true, Weight::from_parts(1, 1));
+        }
+
+        assert!(BlockStats::<T>::contains_key(now, Channel::Parent));
+    }
+
+    #[benchmark]
+    fn roll_up(x: Linear<0, { T::MaxChannelsPerBlock::get() }>) {
+        // Make sure both alerts are emitted & histories are full, to hit the worst case.
+        Thresholds::<T>::put(AlertThresholds {
+            max_failures: Some(0),
+            max_weight: Some(Weight::zero()),
+        });
+
+        let earlier = System::<T>::block_number();
+        let block = earlier.saturating_add(One::one());
+        System::<T>::set_block_number(block);
+
+        for id in 0..x {
+            let channel = Channel::Sibling(id.into());
+            Pallet::<T>::note_inbound(channel, false, Weight::from_parts(1, 1));
+
+            let history = vec![(earlier, ChannelStats::default()); T::HistoryDepth::get() as usize];
+            History::<T>::insert(channel, BoundedVec::truncate_from(history));
+        }
+
+        #[block]
+        {
+            Pallet::<T>::roll_up(block);
+        }
+
+        assert_eq!(BlockStats::<T>::iter_prefix(block).count(), 0);
+        assert_eq!(BlockChannelCount::<T>::get(block), 0);
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExtBuilder::build()
+    }
+}
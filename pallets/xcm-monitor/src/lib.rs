@@ -0,0 +1,420 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM Monitor Pallet
+//!
+//! ## Overview
+//!
+//! Lightweight on-chain monitoring of XCM message processing, which allows operators to detect
+//! stuck or misbehaving channels (e.g. HRMP channels) directly from on-chain data.
+//!
+//! ## Solution
+//!
+//! The pallet provides two wrappers which are supposed to be plugged into the runtime configuration:
+//! * `MonitoredMessageProcessor` - wraps the `pallet-message-queue` message processor, and counts processed & failed
+//!   inbound messages, as well as the weight consumed by their processing.
+//! * `MonitoredRouter` - wraps the XCM router, and counts sent & failed outbound messages.
+//!
+//! Statistics are collected per `Channel` (relay chain or a sibling parachain), per block.
+//! At the beginning of the next block, statistics of the previous block are moved into the per-channel history,
+//! which retains only the last `HistoryDepth` entries. Blocks without any activity on a channel aren't recorded.
+//!
+//! ### Alerts
+//!
+//! Governance can configure alert thresholds for the number of failed messages and the consumed weight.
+//! If the block statistics of a channel breach any of the thresholds, an event is emitted.
+//!
+//! ### Bounded Overhead
+//!
+//! Number of channels tracked per block is limited by `MaxChannelsPerBlock`. Activity on any additional channels
+//! in the same block is ignored.
+//! Inbound monitoring is best effort - it's skipped if there's no weight left to account for it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{ProcessMessage, ProcessMessageError},
+    weights::WeightMeter,
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
+use xcm_builder::InspectMessageQueues;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "xcm-monitor";
+
+/// Channel over which XCM messages are exchanged.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub enum Channel {
+    /// Relay chain (UMP/DMP).
+    Parent,
+    /// Sibling parachain (XCMP).
+    Sibling(ParaId),
+}
+
+impl Channel {
+    /// Channel from which the inbound message with the specified `origin` is coming, if any.
+    pub fn from_origin(origin: &AggregateMessageOrigin) -> Option<Self> {
+        match origin {
+            AggregateMessageOrigin::Here => None,
+            AggregateMessageOrigin::Parent => Some(Self::Parent),
+            AggregateMessageOrigin::Sibling(para_id) => Some(Self::Sibling(*para_id)),
+        }
+    }
+
+    /// Channel over which the outbound message to the specified `destination` is sent, if any.
+    pub fn from_destination(destination: &Location) -> Option<Self> {
+        match destination.unpack() {
+            (1, []) => Some(Self::Parent),
+            (1, [Parachain(id)]) => Some(Self::Sibling((*id).into())),
+            _ => None,
+        }
+    }
+}
+
+/// Statistics of the XCM messages exchanged over a channel.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub struct ChannelStats {
+    /// Number of inbound messages which were successfully processed.
+    pub inbound: u32,
+    /// Number of inbound messages whose processing failed.
+    pub inbound_failed: u32,
+    /// Number of outbound messages which were successfully sent.
+    pub outbound: u32,
+    /// Number of outbound messages which couldn't be sent.
+    pub outbound_failed: u32,
+    /// Weight consumed by processing of the inbound messages.
+    pub weight_used: Weight,
+}
+
+impl ChannelStats {
+    /// Total number of failed inbound & outbound messages.
+    pub fn failures(&self) -> u32 {
+        self.inbound_failed.saturating_add(self.outbound_failed)
+    }
+}
+
+/// Per-block, per-channel thresholds which trigger alert events when breached.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Default)]
+pub struct AlertThresholds {
+    /// Maximum number of failed messages, `None` if not monitored.
+    pub max_failures: Option<u32>,
+    /// Maximum weight consumed by inbound message processing, `None` if not monitored.
+    pub max_weight: Option<Weight>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin which can configure the alert thresholds.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of channels tracked per block.
+        #[pallet::constant]
+        type MaxChannelsPerBlock: Get<u32>;
+
+        /// Number of block statistics entries retained in the history of each channel.
+        #[pallet::constant]
+        type HistoryDepth: Get<u32>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Alert thresholds have been changed.
+        ThresholdsSet { thresholds: AlertThresholds },
+        /// Number of failed messages over the channel breached the threshold.
+        FailureThresholdBreached {
+            channel: Channel,
+            block: BlockNumberFor<T>,
+            failures: u32,
+        },
+        /// Weight consumed by processing of the inbound messages over the channel breached the threshold.
+        WeightThresholdBreached {
+            channel: Channel,
+            block: BlockNumberFor<T>,
+            weight_used: Weight,
+        },
+    }
+
+    /// Statistics of the channels, collected in the specified block.
+    #[pallet::storage]
+    pub type BlockStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        Channel,
+        ChannelStats,
+        OptionQuery,
+    >;
+
+    /// Number of channels tracked in the specified block.
+    #[pallet::storage]
+    pub type BlockChannelCount<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, u32, ValueQuery>;
+
+    /// Recent block statistics of the channel, ordered from the oldest to the newest.
+    #[pallet::storage]
+    pub type History<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        Channel,
+        BoundedVec<(BlockNumberFor<T>, ChannelStats), T::HistoryDepth>,
+        ValueQuery,
+    >;
+
+    /// Alert thresholds.
+    #[pallet::storage]
+    pub type Thresholds<T: Config> = StorageValue<_, AlertThresholds, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            if now.is_zero() {
+                return Weight::zero();
+            }
+
+            let previous = now.saturating_sub(One::one());
+            let channel_count = BlockChannelCount::<T>::get(previous);
+            if channel_count.is_zero() {
+                return T::WeightInfo::roll_up(0);
+            }
+
+            Self::roll_up(previous);
+            T::WeightInfo::roll_up(channel_count)
+        }
+
+        fn integrity_test() {
+            assert!(T::MaxChannelsPerBlock::get() > 0);
+            assert!(T::HistoryDepth::get() > 0);
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the alert thresholds.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_thresholds())]
+        pub fn set_thresholds(origin: OriginFor<T>, thresholds: AlertThresholds) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Thresholds::<T>::put(thresholds);
+
+            Self::deposit_event(Event::<T>::ThresholdsSet { thresholds });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Note the processed inbound message.
+        pub fn note_inbound(channel: Channel, success: bool, weight_used: Weight) {
+            Self::note(channel, |stats| {
+                if success {
+                    stats.inbound.saturating_inc();
+                } else {
+                    stats.inbound_failed.saturating_inc();
+                }
+                stats.weight_used.saturating_accrue(weight_used);
+            })
+        }
+
+        /// Note the sent outbound message.
+        pub fn note_outbound(channel: Channel, success: bool) {
+            Self::note(channel, |stats| {
+                if success {
+                    stats.outbound.saturating_inc();
+                } else {
+                    stats.outbound_failed.saturating_inc();
+                }
+            })
+        }
+
+        /// Update the statistics of the channel in the current block.
+        fn note(channel: Channel, update: impl FnOnce(&mut ChannelStats)) {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            BlockStats::<T>::mutate(now, channel, |maybe_stats| {
+                if maybe_stats.is_none() {
+                    let channel_count = BlockChannelCount::<T>::get(now);
+                    if channel_count >= T::MaxChannelsPerBlock::get() {
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "Too many channels tracked in block {:?}, ignoring activity on {:?}.",
+                            now,
+                            channel
+                        );
+                        return;
+                    }
+                    BlockChannelCount::<T>::insert(now, channel_count.saturating_add(1));
+                }
+
+                update(maybe_stats.get_or_insert_with(Default::default));
+            });
+        }
+
+        /// Move the statistics collected in the `block` into the history, and check them against the alert thresholds.
+        pub(crate) fn roll_up(block: BlockNumberFor<T>) {
+            BlockChannelCount::<T>::remove(block);
+            let thresholds = Thresholds::<T>::get();
+
+            for (channel, stats) in BlockStats::<T>::drain_prefix(block) {
+                let failures = stats.failures();
+                if thresholds.max_failures.map_or(false, |max| failures > max) {
+                    Self::deposit_event(Event::<T>::FailureThresholdBreached {
+                        channel,
+                        block,
+                        failures,
+                    });
+                }
+                if thresholds
+                    .max_weight
+                    .map_or(false, |max| stats.weight_used.any_gt(max))
+                {
+                    Self::deposit_event(Event::<T>::WeightThresholdBreached {
+                        channel,
+                        block,
+                        weight_used: stats.weight_used,
+                    });
+                }
+
+                History::<T>::mutate(channel, |history| {
+                    if history.is_full() {
+                        history.remove(0);
+                    }
+                    // Cannot fail since there's room for at least one entry.
+                    let _ = history.try_push((block, stats));
+                });
+            }
+        }
+    }
+}
+
+/// Message processor which notes the outcome of the inbound message processing, performed by the `Inner` processor.
+///
+/// Messages whose processing was yielded aren't noted, since they will be processed again later.
+pub struct MonitoredMessageProcessor<T, Inner>(PhantomData<(T, Inner)>);
+impl<T, Inner> ProcessMessage for MonitoredMessageProcessor<T, Inner>
+where
+    T: Config,
+    Inner: ProcessMessage<Origin = AggregateMessageOrigin>,
+{
+    type Origin = AggregateMessageOrigin;
+
+    fn process_message(
+        message: &[u8],
+        origin: Self::Origin,
+        meter: &mut WeightMeter,
+        id: &mut [u8; 32],
+    ) -> Result<bool, ProcessMessageError> {
+        let consumed_before = meter.consumed();
+        let result = Inner::process_message(message, origin.clone(), meter, id);
+        let weight_used = meter.consumed().saturating_sub(consumed_before);
+
+        let success = match result {
+            Ok(success) => success,
+            Err(ProcessMessageError::Yield) => return result,
+            Err(_) => false,
+        };
+
+        if let Some(channel) = Channel::from_origin(&origin) {
+            // Monitoring is best effort, skip it if there's no weight left to account for it.
+            if meter.try_consume(T::WeightInfo::note_message()).is_ok() {
+                Pallet::<T>::note_inbound(channel, success, weight_used);
+            }
+        }
+
+        result
+    }
+}
+
+/// XCM router which notes the outcome of sending the outbound message, performed by the `Inner` router.
+///
+/// Destinations which aren't supported by the `Inner` router aren't noted.
+pub struct MonitoredRouter<T, Inner>(PhantomData<(T, Inner)>);
+impl<T: Config, Inner: SendXcm> SendXcm for MonitoredRouter<T, Inner> {
+    type Ticket = (Option<Channel>, Inner::Ticket);
+
+    fn validate(
+        destination: &mut Option<Location>,
+        message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let channel = destination.as_ref().and_then(Channel::from_destination);
+
+        match Inner::validate(destination, message) {
+            Ok((ticket, price)) => Ok(((channel, ticket), price)),
+            Err(SendError::NotApplicable) => Err(SendError::NotApplicable),
+            Err(error) => {
+                if let Some(channel) = channel {
+                    Pallet::<T>::note_outbound(channel, false);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    fn deliver((channel, ticket): Self::Ticket) -> Result<XcmHash, SendError> {
+        let result = Inner::deliver(ticket);
+        if let Some(channel) = channel {
+            Pallet::<T>::note_outbound(channel, result.is_ok());
+        }
+
+        result
+    }
+}
+
+impl<T, Inner: InspectMessageQueues> InspectMessageQueues for MonitoredRouter<T, Inner> {
+    fn clear_messages() {
+        Inner::clear_messages()
+    }
+
+    fn get_messages() -> Vec<(VersionedLocation, Vec<VersionedXcm<()>>)> {
+        Inner::get_messages()
+    }
+}
@@ -0,0 +1,222 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_xcm_monitor, Channel};
+
+use cumulus_primitives_core::AggregateMessageOrigin;
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{ConstU32, ProcessMessage, ProcessMessageError},
+    weights::{Weight, WeightMeter},
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use xcm::latest::prelude::*;
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+type AccountId = u64;
+type BlockNumber = u32;
+
+pub(crate) const MANAGER_ACCOUNT: AccountId = 1;
+
+pub(crate) const MAX_CHANNELS_PER_BLOCK: u32 = 3;
+pub(crate) const HISTORY_DEPTH: u32 = 2;
+
+/// Weight consumed by processing of a single message.
+pub(crate) const MESSAGE_WEIGHT: Weight = Weight::from_parts(100, 10);
+
+/// Sibling parachain which doesn't accept any messages.
+pub(crate) const UNREACHABLE_PARA_ID: u32 = 3000;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        XcmMonitor: pallet_xcm_monitor,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+ord_parameter_types! {
+    pub const Manager: AccountId = MANAGER_ACCOUNT;
+}
+
+impl pallet_xcm_monitor::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureSignedBy<Manager, AccountId>;
+    type MaxChannelsPerBlock = ConstU32<MAX_CHANNELS_PER_BLOCK>;
+    type HistoryDepth = ConstU32<HISTORY_DEPTH>;
+    type WeightInfo = ();
+}
+
+/// Message processing outcome, encoded as the first byte of the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum Outcome {
+    Success = 0,
+    Failure = 1,
+    Yield = 2,
+    Corrupt = 3,
+}
+
+/// Message processor which processes messages according to the encoded `Outcome`.
+pub struct MockMessageProcessor;
+impl ProcessMessage for MockMessageProcessor {
+    type Origin = AggregateMessageOrigin;
+
+    fn process_message(
+        message: &[u8],
+        _origin: Self::Origin,
+        meter: &mut WeightMeter,
+        _id: &mut [u8; 32],
+    ) -> Result<bool, ProcessMessageError> {
+        match message.first() {
+            Some(x) if *x == Outcome::Yield as u8 => Err(ProcessMessageError::Yield),
+            Some(x) if *x == Outcome::Corrupt as u8 => Err(ProcessMessageError::Corrupt),
+            Some(x) => {
+                meter.consume(MESSAGE_WEIGHT);
+                Ok(*x == Outcome::Success as u8)
+            }
+            None => Err(ProcessMessageError::BadFormat),
+        }
+    }
+}
+
+pub(crate) type MonitoredProcessor =
+    pallet_xcm_monitor::MonitoredMessageProcessor<Test, MockMessageProcessor>;
+
+/// Process the message with the specified outcome, coming from the `origin`.
+pub(crate) fn process_message(
+    origin: AggregateMessageOrigin,
+    outcome: Outcome,
+) -> Result<bool, ProcessMessageError> {
+    MonitoredProcessor::process_message(
+        &[outcome as u8],
+        origin,
+        &mut WeightMeter::new(),
+        &mut [0; 32],
+    )
+}
+
+parameter_types! {
+    pub static FailDelivery: bool = false;
+}
+
+/// Router which routes messages to the relay chain and sibling parachains.
+///
+/// Messages to the `UNREACHABLE_PARA_ID` can't be sent, and delivery fails if `FailDelivery` is set.
+pub struct MockRouter;
+impl SendXcm for MockRouter {
+    type Ticket = ();
+
+    fn validate(
+        destination: &mut Option<Location>,
+        _message: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        match destination.as_ref().map(|d| d.unpack()) {
+            Some((1, [Parachain(UNREACHABLE_PARA_ID)])) => Err(SendError::Transport("unreachable")),
+            Some((1, [])) | Some((1, [Parachain(_)])) => Ok(((), Assets::new())),
+            _ => Err(SendError::NotApplicable),
+        }
+    }
+
+    fn deliver(_ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        if FailDelivery::get() {
+            Err(SendError::Transport("delivery failed"))
+        } else {
+            Ok([0; 32])
+        }
+    }
+}
+
+pub(crate) type MonitoredRouter = pallet_xcm_monitor::MonitoredRouter<Test, MockRouter>;
+
+/// Send an empty message to the `destination`.
+pub(crate) fn send_message(destination: Location) -> Result<XcmHash, SendError> {
+    send_xcm::<MonitoredRouter>(destination, Xcm(vec![])).map(|(hash, _)| hash)
+}
+
+pub(crate) fn sibling(para_id: u32) -> Channel {
+    Channel::Sibling(para_id.into())
+}
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            FailDelivery::set(false);
+        });
+
+        ext
+    }
+}
+
+/// Advance to the specified block number, running the pallet's `on_initialize` hook on the way.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    use frame_support::traits::Hooks;
+
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        XcmMonitor::on_initialize(System::block_number());
+    }
+}
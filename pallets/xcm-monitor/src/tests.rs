@@ -0,0 +1,373 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+    mock::*, AlertThresholds, BlockChannelCount, BlockStats, Channel, ChannelStats, Event, History,
+    Thresholds,
+};
+
+use cumulus_primitives_core::AggregateMessageOrigin;
+use frame_support::{
+    assert_noop, assert_ok, error::BadOrigin, traits::ProcessMessageError, weights::Weight,
+};
+use xcm::latest::prelude::*;
+
+fn sibling_location(para_id: u32) -> Location {
+    Location::new(1, [Parachain(para_id)])
+}
+
+fn sibling_origin(para_id: u32) -> AggregateMessageOrigin {
+    AggregateMessageOrigin::Sibling(para_id.into())
+}
+
+#[test]
+fn channel_conversion_works() {
+    assert_eq!(
+        Channel::from_origin(&AggregateMessageOrigin::Parent),
+        Some(Channel::Parent)
+    );
+    assert_eq!(
+        Channel::from_origin(&sibling_origin(2000)),
+        Some(sibling(2000))
+    );
+    assert_eq!(Channel::from_origin(&AggregateMessageOrigin::Here), None);
+
+    assert_eq!(
+        Channel::from_destination(&Location::parent()),
+        Some(Channel::Parent)
+    );
+    assert_eq!(
+        Channel::from_destination(&sibling_location(2000)),
+        Some(sibling(2000))
+    );
+    assert_eq!(Channel::from_destination(&Location::here()), None);
+    assert_eq!(
+        Channel::from_destination(&Location::new(
+            1,
+            [
+                Parachain(2000),
+                AccountId32 {
+                    network: None,
+                    id: [1; 32]
+                }
+            ]
+        )),
+        None
+    );
+    assert_eq!(
+        Channel::from_destination(&Location::new(2, [GlobalConsensus(Kusama)])),
+        None
+    );
+}
+
+#[test]
+fn inbound_messages_are_noted() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Parent, Outcome::Success),
+            Ok(true)
+        );
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Parent, Outcome::Success),
+            Ok(true)
+        );
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Parent, Outcome::Failure),
+            Ok(false)
+        );
+        assert_eq!(
+            process_message(sibling_origin(2000), Outcome::Corrupt),
+            Err(ProcessMessageError::Corrupt)
+        );
+
+        assert_eq!(
+            BlockStats::<Test>::get(1, Channel::Parent),
+            Some(ChannelStats {
+                inbound: 2,
+                inbound_failed: 1,
+                weight_used: MESSAGE_WEIGHT.saturating_mul(3),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            BlockStats::<Test>::get(1, sibling(2000)),
+            Some(ChannelStats {
+                inbound_failed: 1,
+                ..Default::default()
+            })
+        );
+        assert_eq!(BlockChannelCount::<Test>::get(1), 2);
+    });
+}
+
+#[test]
+fn yielded_and_local_inbound_messages_are_not_noted() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Parent, Outcome::Yield),
+            Err(ProcessMessageError::Yield)
+        );
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Here, Outcome::Success),
+            Ok(true)
+        );
+
+        assert_eq!(BlockStats::<Test>::iter().count(), 0);
+        assert_eq!(BlockChannelCount::<Test>::get(1), 0);
+    });
+}
+
+#[test]
+fn outbound_messages_are_noted() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(send_message(Location::parent()));
+        assert_ok!(send_message(sibling_location(2000)));
+        assert_ok!(send_message(sibling_location(2000)));
+
+        FailDelivery::set(true);
+        assert!(send_message(sibling_location(2000)).is_err());
+
+        // Validation failure is noted as well.
+        assert!(send_message(sibling_location(UNREACHABLE_PARA_ID)).is_err());
+
+        assert_eq!(
+            BlockStats::<Test>::get(1, Channel::Parent),
+            Some(ChannelStats {
+                outbound: 1,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            BlockStats::<Test>::get(1, sibling(2000)),
+            Some(ChannelStats {
+                outbound: 2,
+                outbound_failed: 1,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            BlockStats::<Test>::get(1, sibling(UNREACHABLE_PARA_ID)),
+            Some(ChannelStats {
+                outbound_failed: 1,
+                ..Default::default()
+            })
+        );
+    });
+}
+
+#[test]
+fn unsupported_destinations_are_not_noted() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(
+            send_message(Location::new(2, [GlobalConsensus(Kusama)])),
+            Err(SendError::NotApplicable)
+        );
+
+        assert_eq!(BlockStats::<Test>::iter().count(), 0);
+        assert_eq!(BlockChannelCount::<Test>::get(1), 0);
+    });
+}
+
+#[test]
+fn channels_per_block_are_bounded() {
+    ExtBuilder::build().execute_with(|| {
+        for para_id in 0..MAX_CHANNELS_PER_BLOCK {
+            assert_ok!(send_message(sibling_location(2000 + para_id)));
+        }
+        assert_eq!(BlockChannelCount::<Test>::get(1), MAX_CHANNELS_PER_BLOCK);
+
+        // Activity on a new channel is ignored, but the message is still sent.
+        assert_ok!(send_message(Location::parent()));
+        assert_eq!(BlockStats::<Test>::get(1, Channel::Parent), None);
+        assert_eq!(BlockChannelCount::<Test>::get(1), MAX_CHANNELS_PER_BLOCK);
+
+        // Activity on an already tracked channel is still noted.
+        assert_ok!(send_message(sibling_location(2000)));
+        assert_eq!(
+            BlockStats::<Test>::get(1, sibling(2000)).map(|stats| stats.outbound),
+            Some(2)
+        );
+
+        // Bound applies per block.
+        run_to_block(2);
+        assert_ok!(send_message(Location::parent()));
+        assert_eq!(BlockChannelCount::<Test>::get(2), 1);
+    });
+}
+
+#[test]
+fn stats_are_rolled_up_into_history() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(send_message(Location::parent()));
+        assert_eq!(
+            process_message(sibling_origin(2000), Outcome::Success),
+            Ok(true)
+        );
+
+        run_to_block(2);
+        assert_eq!(BlockStats::<Test>::iter_prefix(1).count(), 0);
+        assert_eq!(BlockChannelCount::<Test>::get(1), 0);
+        assert_eq!(
+            History::<Test>::get(Channel::Parent).into_inner(),
+            vec![(
+                1,
+                ChannelStats {
+                    outbound: 1,
+                    ..Default::default()
+                }
+            )]
+        );
+        assert_eq!(
+            History::<Test>::get(sibling(2000)).into_inner(),
+            vec![(
+                1,
+                ChannelStats {
+                    inbound: 1,
+                    weight_used: MESSAGE_WEIGHT,
+                    ..Default::default()
+                }
+            )]
+        );
+
+        // Blocks without activity aren't recorded.
+        run_to_block(3);
+        assert_eq!(History::<Test>::get(Channel::Parent).len(), 1);
+    });
+}
+
+#[test]
+fn history_depth_is_respected() {
+    ExtBuilder::build().execute_with(|| {
+        for _ in 0..HISTORY_DEPTH + 1 {
+            assert_ok!(send_message(Location::parent()));
+            run_to_block(System::block_number() + 1);
+        }
+
+        let history = History::<Test>::get(Channel::Parent);
+        assert_eq!(history.len() as u32, HISTORY_DEPTH);
+
+        // Oldest entries are dropped first.
+        let blocks: Vec<_> = history.iter().map(|(block, _)| *block).collect();
+        assert_eq!(blocks, (2..=HISTORY_DEPTH + 1).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn set_thresholds_works() {
+    ExtBuilder::build().execute_with(|| {
+        let thresholds = AlertThresholds {
+            max_failures: Some(1),
+            max_weight: Some(Weight::from_parts(1_000, 1_000)),
+        };
+
+        assert_ok!(XcmMonitor::set_thresholds(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            thresholds
+        ));
+        System::assert_last_event(Event::<Test>::ThresholdsSet { thresholds }.into());
+        assert_eq!(Thresholds::<Test>::get(), thresholds);
+    });
+}
+
+#[test]
+fn set_thresholds_requires_manager_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            XcmMonitor::set_thresholds(
+                RuntimeOrigin::signed(MANAGER_ACCOUNT + 1),
+                AlertThresholds::default()
+            ),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn threshold_breaches_are_reported() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(XcmMonitor::set_thresholds(
+            RuntimeOrigin::signed(MANAGER_ACCOUNT),
+            AlertThresholds {
+                max_failures: Some(1),
+                max_weight: Some(MESSAGE_WEIGHT),
+            }
+        ));
+
+        // Parent channel breaches the failure threshold, but not the weight threshold.
+        FailDelivery::set(true);
+        assert!(send_message(Location::parent()).is_err());
+        assert_eq!(
+            process_message(AggregateMessageOrigin::Parent, Outcome::Failure),
+            Ok(false)
+        );
+
+        // Sibling channel breaches the weight threshold, but not the failure threshold.
+        for _ in 0..2 {
+            assert_eq!(
+                process_message(sibling_origin(2000), Outcome::Success),
+                Ok(true)
+            );
+        }
+
+        // Sibling channel which is exactly at the thresholds doesn't breach them.
+        assert_eq!(
+            process_message(sibling_origin(2001), Outcome::Failure),
+            Ok(false)
+        );
+
+        System::reset_events();
+        run_to_block(2);
+
+        let events: Vec<_> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(
+            &Event::<Test>::FailureThresholdBreached {
+                channel: Channel::Parent,
+                block: 1,
+                failures: 2,
+            }
+            .into()
+        ));
+        assert!(events.contains(
+            &Event::<Test>::WeightThresholdBreached {
+                channel: sibling(2000),
+                block: 1,
+                weight_used: MESSAGE_WEIGHT.saturating_mul(2),
+            }
+            .into()
+        ));
+    });
+}
+
+#[test]
+fn no_alerts_without_thresholds() {
+    ExtBuilder::build().execute_with(|| {
+        FailDelivery::set(true);
+        for _ in 0..10 {
+            assert!(send_message(Location::parent()).is_err());
+        }
+
+        System::reset_events();
+        run_to_block(2);
+        assert!(System::events().is_empty());
+        assert_eq!(History::<Test>::get(Channel::Parent).len(), 1);
+    });
+}
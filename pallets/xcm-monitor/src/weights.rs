@@ -0,0 +1,142 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_xcm_monitor
+//!
+//! These values were estimated by hand from the storage accesses of each extrinsic, they
+//! were NOT produced by the benchmark CLI. The benchmarks exist in `benchmarking.rs`;
+//! regenerate this file on reference hardware before relying on it in production:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=shibuya-dev --steps=50 --repeat=20
+//!   --pallet=pallet-xcm-monitor --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./pallets/xcm-monitor/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_monitor.
+pub trait WeightInfo {
+	fn set_thresholds() -> Weight;
+	fn note_message() -> Weight;
+	fn roll_up(x: u32, ) -> Weight;
+}
+
+/// Weights for pallet_xcm_monitor using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `XcmMonitor::Thresholds` (r:0 w:1)
+	/// Proof: `XcmMonitor::Thresholds` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	fn set_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_403_000 picoseconds.
+		Weight::from_parts(5_617_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmMonitor::BlockStats` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockStats` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::BlockChannelCount` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockChannelCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn note_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3530`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(10_124_000, 3530)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmMonitor::BlockChannelCount` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockChannelCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::Thresholds` (r:1 w:0)
+	/// Proof: `XcmMonitor::Thresholds` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::BlockStats` (r:17 w:16)
+	/// Proof: `XcmMonitor::BlockStats` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::History` (r:16 w:16)
+	/// Proof: `XcmMonitor::History` (`max_values`: None, `max_size`: Some(1418), added: 3893, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn roll_up(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + x * (1460 ±0)`
+		//  Estimated: `3481 + x * (3893 ±0)`
+		// Minimum execution time: 4_215_000 picoseconds.
+		Weight::from_parts(4_532_000, 3481)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(14_207_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3893).saturating_mul(x.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `XcmMonitor::Thresholds` (r:0 w:1)
+	/// Proof: `XcmMonitor::Thresholds` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	fn set_thresholds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_403_000 picoseconds.
+		Weight::from_parts(5_617_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmMonitor::BlockStats` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockStats` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::BlockChannelCount` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockChannelCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn note_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3530`
+		// Minimum execution time: 9_812_000 picoseconds.
+		Weight::from_parts(10_124_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmMonitor::BlockChannelCount` (r:1 w:1)
+	/// Proof: `XcmMonitor::BlockChannelCount` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::Thresholds` (r:1 w:0)
+	/// Proof: `XcmMonitor::Thresholds` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::BlockStats` (r:17 w:16)
+	/// Proof: `XcmMonitor::BlockStats` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `XcmMonitor::History` (r:16 w:16)
+	/// Proof: `XcmMonitor::History` (`max_values`: None, `max_size`: Some(1418), added: 3893, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn roll_up(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + x * (1460 ±0)`
+		//  Estimated: `3481 + x * (3893 ±0)`
+		// Minimum execution time: 4_215_000 picoseconds.
+		Weight::from_parts(4_532_000, 3481)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(14_207_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3893).saturating_mul(x.into()))
+	}
+}
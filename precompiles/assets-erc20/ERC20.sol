@@ -112,7 +112,7 @@
 
     /**
      * @title Extension for ERC20 interface
-     * @dev Extended functions with minimum balance check as well as mint & burn.
+     * @dev Extended functions with minimum balance check as well as asset administration.
      */
     interface IERC20Plus is IERC20 {
 
@@ -137,5 +137,81 @@
      * Selector: 9dc29fac
      */
     function burn(address who, uint256 amount) external returns (bool);
+
+    /**
+     * @dev Disallows further transfers from the specified account.
+     * Only usable by asset freezer.
+     * Selector: 8d1fdf2f
+     */
+    function freeze(address who) external returns (bool);
+
+    /**
+     * @dev Allows transfers from the specified account again.
+     * Only usable by asset admin.
+     * Selector: 5ea20216
+     */
+    function thaw(address who) external returns (bool);
+
+    /**
+     * @dev Disallows further transfers of the asset for all accounts.
+     * Only usable by asset freezer.
+     * Selector: d4937f51
+     */
+    function freezeAsset() external returns (bool);
+
+    /**
+     * @dev Allows transfers of the asset again.
+     * Only usable by asset admin.
+     * Selector: 51ec2ad7
+     */
+    function thawAsset() external returns (bool);
+
+    /**
+     * @dev Sets the name, symbol and decimals of the asset.
+     * Only usable by asset owner.
+     * Selector: 37d2c2f4
+     */
+    function setMetadata(
+        string memory name,
+        string memory symbol,
+        uint8 decimals
+    ) external returns (bool);
 }
 
+
+    /**
+     * @title Extension for ERC20 interface
+     * @dev EIP-2612 permit extension, allowing approvals to be made via signatures.
+     */
+    interface IERC20Permit is IERC20 {
+
+    /**
+     * @dev Sets `value` as the allowance of `spender` over `owner`'s tokens,
+     * given `owner`'s signed approval.
+     * Emits an {Approval} event.
+     * Selector: d505accf
+     */
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    /**
+     * @dev Returns the current nonce for `owner`. This value must be
+     * included whenever a signature is generated for {permit}.
+     * Selector: 7ecebe00
+     */
+    function nonces(address owner) external view returns (uint256);
+
+    /**
+     * @dev Returns the domain separator used in the encoding of the signature for {permit}.
+     * Selector: 3644e515
+     */
+    // solhint-disable-next-line func-name-mixedcase
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+}
@@ -0,0 +1,214 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! EIP-2612 `permit` support, allowing approvals to be given with an off-chain signature.
+
+use super::*;
+use frame_support::{
+    storage::types::{StorageDoubleMap, ValueQuery},
+    traits::{StorageInstance, Time},
+    Blake2_128Concat,
+};
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::vec::Vec;
+
+/// EIP-2612 permit typehash.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+    "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-2612 permit domain used to compute an individualized domain separator.
+const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Version of the permit domain.
+const PERMIT_VERSION: [u8; 32] = keccak256!("1");
+
+/// Storage prefix for nonces.
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+    const STORAGE_PREFIX: &'static str = "Nonces";
+
+    fn pallet_prefix() -> &'static str {
+        "Erc20AssetsPrecompile"
+    }
+}
+
+/// Storage type used to store EIP-2612 nonces, per asset precompile address and owner.
+pub type NoncesStorage = StorageDoubleMap<
+    Nonces,
+    // Asset precompile address
+    Blake2_128Concat,
+    H160,
+    // Owner
+    Blake2_128Concat,
+    H160,
+    // Nonce
+    U256,
+    ValueQuery,
+>;
+
+pub struct Eip2612<Runtime, Instance: 'static = ()>(PhantomData<(Runtime, Instance)>);
+
+impl<Runtime, Instance> Eip2612<Runtime, Instance>
+where
+    Instance: 'static,
+    Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_assets::Call<Runtime, Instance>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256> + solidity::Codec,
+    Runtime: AddressToAssetId<AssetIdOf<Runtime, Instance>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
+    AssetIdOf<Runtime, Instance>: Copy,
+{
+    /// Domain separator of the asset precompile at `address`.
+    pub fn compute_domain_separator(address: H160, asset_id: AssetIdOf<Runtime, Instance>) -> H256 {
+        let name: H256 =
+            keccak_256(&pallet_assets::Pallet::<Runtime, Instance>::name(asset_id)).into();
+        let chain_id: U256 = Runtime::ChainId::get().into();
+
+        let domain_separator_inner = solidity::encode_arguments((
+            H256::from(PERMIT_DOMAIN),
+            name,
+            H256::from(PERMIT_VERSION),
+            chain_id,
+            Address(address),
+        ));
+
+        keccak_256(&domain_separator_inner).into()
+    }
+
+    /// Digest of the permit, which the owner must sign.
+    pub fn generate_permit(
+        address: H160,
+        asset_id: AssetIdOf<Runtime, Instance>,
+        owner: H160,
+        spender: H160,
+        value: U256,
+        nonce: U256,
+        deadline: U256,
+    ) -> [u8; 32] {
+        let domain_separator = Self::compute_domain_separator(address, asset_id);
+
+        let permit_content = solidity::encode_arguments((
+            H256::from(PERMIT_TYPEHASH),
+            Address(owner),
+            Address(spender),
+            value,
+            nonce,
+            deadline,
+        ));
+        let permit_content = keccak_256(&permit_content);
+
+        let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+        pre_digest.extend_from_slice(b"\x19\x01");
+        pre_digest.extend_from_slice(domain_separator.as_bytes());
+        pre_digest.extend_from_slice(&permit_content);
+        keccak_256(&pre_digest)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn permit(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: H256,
+        s: H256,
+    ) -> EvmResult {
+        // NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+        handle.record_db_read::<Runtime>(104)?;
+        handle.record_log_costs_manual(3, 32)?;
+
+        let owner: H160 = owner.into();
+        let spender: H160 = spender.into();
+        let address = handle.code_address();
+
+        // Blockchain time is in ms while Ethereum uses second timestamps.
+        let timestamp: u128 = Runtime::Timestamp::now().unique_saturated_into();
+        let timestamp: U256 = U256::from(timestamp / 1000);
+        if deadline < timestamp {
+            return Err(revert("Permit expired"));
+        }
+
+        let nonce = NoncesStorage::get(address, owner);
+        let permit =
+            Self::generate_permit(address, asset_id, owner, spender, value, nonce, deadline);
+
+        let mut sig = [0u8; 65];
+        sig[0..32].copy_from_slice(r.as_bytes());
+        sig[32..64].copy_from_slice(s.as_bytes());
+        sig[64] = v;
+
+        let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
+            .map_err(|_| revert("Invalid permit"))?;
+        let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+        if signer == H160::zero() || signer != owner {
+            return Err(revert("Invalid permit"));
+        }
+
+        NoncesStorage::insert(address, owner, nonce.saturating_add(U256::one()));
+
+        Erc20AssetsPrecompileSet::<Runtime, Instance>::approve_inner(
+            asset_id, handle, owner, spender, value,
+        )?;
+
+        log3(
+            address,
+            SELECTOR_LOG_APPROVAL,
+            owner,
+            spender,
+            solidity::encode_event_data(value),
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        // NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+        handle.record_db_read::<Runtime>(104)?;
+
+        Ok(NoncesStorage::get(handle.code_address(), H160::from(owner)))
+    }
+
+    pub(crate) fn domain_separator(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<H256> {
+        // Storage item: Metadata:
+        // Blake2_128(16) + AssetId(16) + AssetMetadata[deposit(16) + name(StringLimit)
+        // + symbol(StringLimit) + decimals(1) + is_frozen(1)]
+        handle.record_db_read::<Runtime>(
+            50 + (2 * <Runtime as pallet_assets::Config<Instance>>::StringLimit::get()) as usize,
+        )?;
+
+        Ok(Self::compute_domain_separator(
+            handle.code_address(),
+            asset_id,
+        ))
+    }
+}
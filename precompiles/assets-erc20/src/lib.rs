@@ -51,12 +51,15 @@ use pallet_evm::AddressMapping;
 use precompile_utils::prelude::*;
 use sp_runtime::traits::{Bounded, Dispatchable, StaticLookup};
 
-use sp_core::{Get, MaxEncodedLen, H160, U256};
+use sp_core::{Get, MaxEncodedLen, H160, H256, U256};
 use sp_std::{
     convert::{TryFrom, TryInto},
     marker::PhantomData,
 };
 
+mod eip2612;
+pub use eip2612::*;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -74,6 +77,19 @@ pub type BalanceOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<I
 /// Alias for the Asset Id type for the provided Runtime and Instance.
 pub type AssetIdOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<Instance>>::AssetId;
 
+/// Maximum length of the asset name and symbol, as configured in `pallet-assets`.
+pub struct GetAssetsStringLimit<Runtime, Instance = ()>(PhantomData<(Runtime, Instance)>);
+
+impl<Runtime, Instance> Get<u32> for GetAssetsStringLimit<Runtime, Instance>
+where
+    Runtime: pallet_assets::Config<Instance>,
+    Instance: 'static,
+{
+    fn get() -> u32 {
+        <Runtime as pallet_assets::Config<Instance>>::StringLimit::get()
+    }
+}
+
 /// This trait ensure we can convert EVM address to AssetIds
 /// We will require Runtime to have this trait implemented
 pub trait AddressToAssetId<AssetId> {
@@ -539,6 +555,150 @@ where
         Ok(true)
     }
 
+    #[precompile::public("freeze(address)")]
+    fn freeze(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let who = Runtime::AddressMapping::into_account_id(who.into());
+
+        // Dispatch call (if enough gas).
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_assets::Call::<Runtime, Instance>::freeze {
+                id: asset_id.into(),
+                who: Runtime::Lookup::unlookup(who),
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("thaw(address)")]
+    fn thaw(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let who = Runtime::AddressMapping::into_account_id(who.into());
+
+        // Dispatch call (if enough gas).
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_assets::Call::<Runtime, Instance>::thaw {
+                id: asset_id.into(),
+                who: Runtime::Lookup::unlookup(who),
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("freezeAsset()")]
+    fn freeze_asset(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        // Dispatch call (if enough gas).
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_assets::Call::<Runtime, Instance>::freeze_asset {
+                id: asset_id.into(),
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("thawAsset()")]
+    fn thaw_asset(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        // Dispatch call (if enough gas).
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_assets::Call::<Runtime, Instance>::thaw_asset {
+                id: asset_id.into(),
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("setMetadata(string,string,uint8)")]
+    fn set_metadata(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        name: BoundedString<GetAssetsStringLimit<Runtime, Instance>>,
+        symbol: BoundedString<GetAssetsStringLimit<Runtime, Instance>>,
+        decimals: u8,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        // Dispatch call (if enough gas).
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_assets::Call::<Runtime, Instance>::set_metadata {
+                id: asset_id.into(),
+                name: name.into(),
+                symbol: symbol.into(),
+                decimals,
+            },
+        )?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("permit(address,address,uint256,uint256,uint8,bytes32,bytes32)")]
+    #[allow(clippy::too_many_arguments)]
+    fn eip2612_permit(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        v: u8,
+        r: H256,
+        s: H256,
+    ) -> EvmResult {
+        <Eip2612<Runtime, Instance>>::permit(
+            asset_id, handle, owner, spender, value, deadline, v, r, s,
+        )
+    }
+
+    #[precompile::public("nonces(address)")]
+    #[precompile::view]
+    fn eip2612_nonces(
+        _asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+    ) -> EvmResult<U256> {
+        <Eip2612<Runtime, Instance>>::nonces(handle, owner)
+    }
+
+    #[precompile::public("DOMAIN_SEPARATOR()")]
+    #[precompile::view]
+    fn eip2612_domain_separator(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<H256> {
+        <Eip2612<Runtime, Instance>>::domain_separator(asset_id, handle)
+    }
+
     fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime, Instance>> {
         value
             .try_into()
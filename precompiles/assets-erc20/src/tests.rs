@@ -95,6 +95,15 @@ fn selectors() {
 
     assert!(PrecompileCall::mint_selectors().contains(&0x40c10f19));
     assert!(PrecompileCall::burn_selectors().contains(&0x9dc29fac));
+    assert!(PrecompileCall::freeze_selectors().contains(&0x8d1fdf2f));
+    assert!(PrecompileCall::thaw_selectors().contains(&0x5ea20216));
+    assert!(PrecompileCall::freeze_asset_selectors().contains(&0xd4937f51));
+    assert!(PrecompileCall::thaw_asset_selectors().contains(&0x51ec2ad7));
+    assert!(PrecompileCall::set_metadata_selectors().contains(&0x37d2c2f4));
+
+    assert!(PrecompileCall::eip2612_permit_selectors().contains(&0xd505accf));
+    assert!(PrecompileCall::eip2612_nonces_selectors().contains(&0x7ecebe00));
+    assert!(PrecompileCall::eip2612_domain_separator_selectors().contains(&0x3644e515));
 
     assert_eq!(
         crate::SELECTOR_LOG_TRANSFER,
@@ -412,6 +421,217 @@ fn burn_non_admin_is_not_ok() {
     });
 }
 
+#[test]
+fn freeze_and_thaw_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = 0;
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            CryptoAlith.into(),
+            true,
+            1,
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            asset_id,
+            Bob.into(),
+            1000,
+        ));
+
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::freeze {
+                    who: Address(Bob.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Bob's account is frozen, transfers out of it must fail
+        assert!(Assets::transfer(
+            RuntimeOrigin::signed(Bob.into()),
+            asset_id,
+            CryptoAlith.into(),
+            10,
+        )
+        .is_err());
+
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::thaw {
+                    who: Address(Bob.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(Bob.into()),
+            asset_id,
+            CryptoAlith.into(),
+            10,
+        ));
+    });
+}
+
+#[test]
+fn freeze_and_thaw_asset_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = 0;
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            CryptoAlith.into(),
+            true,
+            1,
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            asset_id,
+            Bob.into(),
+            1000,
+        ));
+
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::freeze_asset {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Whole asset is frozen, no transfers are possible
+        assert!(Assets::transfer(
+            RuntimeOrigin::signed(Bob.into()),
+            asset_id,
+            CryptoAlith.into(),
+            10,
+        )
+        .is_err());
+
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::thaw_asset {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        assert_ok!(Assets::transfer(
+            RuntimeOrigin::signed(Bob.into()),
+            asset_id,
+            CryptoAlith.into(),
+            10,
+        ));
+    });
+}
+
+#[test]
+fn freeze_non_admin_is_not_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = 0;
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            CryptoAlith.into(),
+            true,
+            1,
+        ));
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                LocalAssetId(asset_id),
+                PrecompileCall::freeze {
+                    who: Address(CryptoAlith.into()),
+                },
+            )
+            .execute_reverts(|output| from_utf8(&output).unwrap().contains("NoPermission"));
+
+        precompiles()
+            .prepare_test(Bob, LocalAssetId(asset_id), PrecompileCall::freeze_asset {})
+            .execute_reverts(|output| from_utf8(&output).unwrap().contains("NoPermission"));
+    });
+}
+
+#[test]
+fn set_metadata_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = 0;
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            CryptoAlith.into(),
+            true,
+            1,
+        ));
+
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::set_metadata {
+                    name: "TestToken".into(),
+                    symbol: "Test".into(),
+                    decimals: 12,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        precompiles()
+            .prepare_test(CryptoAlith, LocalAssetId(asset_id), PrecompileCall::name {})
+            .execute_returns(UnboundedBytes::from("TestToken"));
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::symbol {},
+            )
+            .execute_returns(UnboundedBytes::from("Test"));
+        precompiles()
+            .prepare_test(
+                CryptoAlith,
+                LocalAssetId(asset_id),
+                PrecompileCall::decimals {},
+            )
+            .execute_returns(12u8);
+    });
+}
+
+#[test]
+fn set_metadata_non_owner_is_not_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_id = 0;
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            asset_id,
+            CryptoAlith.into(),
+            true,
+            1,
+        ));
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                LocalAssetId(asset_id),
+                PrecompileCall::set_metadata {
+                    name: "TestToken".into(),
+                    symbol: "Test".into(),
+                    decimals: 12,
+                },
+            )
+            .execute_reverts(|output| from_utf8(&output).unwrap().contains("NoPermission"));
+    });
+}
+
 #[test]
 fn approve() {
     ExtBuilder::default()
@@ -1075,3 +1295,251 @@ fn minimum_balance_is_right() {
             .execute_returns(U256::from(expected_min_balance));
     });
 }
+
+/// Secret key of the permit signer, along with its address.
+fn permit_signer() -> (libsecp256k1::SecretKey, H160) {
+    let secret = libsecp256k1::SecretKey::parse(&sp_io::hashing::keccak_256(b"Alice")).unwrap();
+    let public = libsecp256k1::PublicKey::from_secret_key(&secret);
+    let address = H160::from_slice(&sp_io::hashing::keccak_256(&public.serialize()[1..65])[12..]);
+    (secret, address)
+}
+
+/// Sign the permit digest, returning the `(v, r, s)` signature parts.
+fn sign_permit(secret: &libsecp256k1::SecretKey, permit: [u8; 32]) -> (u8, H256, H256) {
+    let (signature, recovery_id) =
+        libsecp256k1::sign(&libsecp256k1::Message::parse(&permit), secret);
+    let signature = signature.serialize();
+    (
+        recovery_id.serialize() + 27,
+        H256::from_slice(&signature[0..32]),
+        H256::from_slice(&signature[32..64]),
+    )
+}
+
+/// Create asset `0`, minting `1000` units to the `owner`.
+fn create_asset_for(owner: H160) {
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        0u128,
+        CryptoAlith.into(),
+        true,
+        1
+    ));
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(CryptoAlith.into()),
+        0u128,
+        owner.into(),
+        1000
+    ));
+}
+
+#[test]
+fn permit_valid() {
+    let (secret, owner) = permit_signer();
+    ExtBuilder::default()
+        .with_balances(vec![(CryptoAlith.into(), 1000), (owner.into(), 1000)])
+        .build()
+        .execute_with(|| {
+            create_asset_for(owner);
+
+            let deadline: U256 = 1_000.into();
+            let permit = Eip2612::<Runtime>::generate_permit(
+                LocalAssetId(0u128).into(),
+                0u128,
+                owner,
+                Bob.into(),
+                500.into(),
+                0.into(),
+                deadline,
+            );
+            let (v, r, s) = sign_permit(&secret, permit);
+
+            precompiles()
+                .prepare_test(
+                    CryptoAlith,
+                    LocalAssetId(0u128),
+                    PrecompileCall::nonces {
+                        owner: Address(owner),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(U256::zero());
+
+            // Permit can be submitted by anyone
+            precompiles()
+                .prepare_test(
+                    Charlie,
+                    LocalAssetId(0u128),
+                    PrecompileCall::eip2612_permit {
+                        owner: Address(owner),
+                        spender: Address(Bob.into()),
+                        value: 500.into(),
+                        deadline,
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .expect_log(log3(
+                    LocalAssetId(0u128),
+                    SELECTOR_LOG_APPROVAL,
+                    owner,
+                    Bob,
+                    solidity::encode_event_data(U256::from(500)),
+                ))
+                .execute_returns(());
+
+            precompiles()
+                .prepare_test(
+                    CryptoAlith,
+                    LocalAssetId(0u128),
+                    PrecompileCall::allowance {
+                        owner: Address(owner),
+                        spender: Address(Bob.into()),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(U256::from(500));
+            precompiles()
+                .prepare_test(
+                    CryptoAlith,
+                    LocalAssetId(0u128),
+                    PrecompileCall::nonces {
+                        owner: Address(owner),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(U256::one());
+
+            // Same permit can't be replayed
+            precompiles()
+                .prepare_test(
+                    Charlie,
+                    LocalAssetId(0u128),
+                    PrecompileCall::eip2612_permit {
+                        owner: Address(owner),
+                        spender: Address(Bob.into()),
+                        value: 500.into(),
+                        deadline,
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .execute_reverts(|output| output == b"Invalid permit");
+        });
+}
+
+#[test]
+fn permit_invalid_signer_fails() {
+    let (secret, owner) = permit_signer();
+    ExtBuilder::default()
+        .with_balances(vec![(CryptoAlith.into(), 1000), (owner.into(), 1000)])
+        .build()
+        .execute_with(|| {
+            create_asset_for(owner);
+
+            // Permit signed by the owner, but submitted on behalf of another account
+            let permit = Eip2612::<Runtime>::generate_permit(
+                LocalAssetId(0u128).into(),
+                0u128,
+                CryptoAlith.into(),
+                Bob.into(),
+                500.into(),
+                0.into(),
+                1_000.into(),
+            );
+            let (v, r, s) = sign_permit(&secret, permit);
+
+            precompiles()
+                .prepare_test(
+                    Charlie,
+                    LocalAssetId(0u128),
+                    PrecompileCall::eip2612_permit {
+                        owner: Address(CryptoAlith.into()),
+                        spender: Address(Bob.into()),
+                        value: 500.into(),
+                        deadline: 1_000.into(),
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .execute_reverts(|output| output == b"Invalid permit");
+        });
+}
+
+#[test]
+fn permit_expired_fails() {
+    let (secret, owner) = permit_signer();
+    ExtBuilder::default()
+        .with_balances(vec![(CryptoAlith.into(), 1000), (owner.into(), 1000)])
+        .build()
+        .execute_with(|| {
+            create_asset_for(owner);
+            // Timestamp is in milliseconds, while the deadline is in seconds
+            Timestamp::set_timestamp(1_001_000);
+
+            let deadline: U256 = 1_000.into();
+            let permit = Eip2612::<Runtime>::generate_permit(
+                LocalAssetId(0u128).into(),
+                0u128,
+                owner,
+                Bob.into(),
+                500.into(),
+                0.into(),
+                deadline,
+            );
+            let (v, r, s) = sign_permit(&secret, permit);
+
+            precompiles()
+                .prepare_test(
+                    Charlie,
+                    LocalAssetId(0u128),
+                    PrecompileCall::eip2612_permit {
+                        owner: Address(owner),
+                        spender: Address(Bob.into()),
+                        value: 500.into(),
+                        deadline,
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .execute_reverts(|output| output == b"Permit expired");
+        });
+}
+
+#[test]
+fn domain_separator_is_specific_to_asset() {
+    ExtBuilder::default()
+        .with_balances(vec![(CryptoAlith.into(), 1000)])
+        .build()
+        .execute_with(|| {
+            for asset_id in [0u128, 1u128] {
+                assert_ok!(Assets::force_create(
+                    RuntimeOrigin::root(),
+                    asset_id,
+                    CryptoAlith.into(),
+                    true,
+                    1
+                ));
+            }
+
+            let expected =
+                Eip2612::<Runtime>::compute_domain_separator(LocalAssetId(0u128).into(), 0u128);
+            assert_ne!(
+                expected,
+                Eip2612::<Runtime>::compute_domain_separator(LocalAssetId(1u128).into(), 1u128)
+            );
+
+            precompiles()
+                .prepare_test(
+                    CryptoAlith,
+                    LocalAssetId(0u128),
+                    PrecompileCall::eip2612_domain_separator {},
+                )
+                .expect_no_logs()
+                .execute_returns(expected);
+        });
+}
@@ -0,0 +1,36 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Collator selection interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005008
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: pallets/collator-selection/src/lib.rs
+///
+/// All calls are executed on behalf of the native account mapped to the caller's address.
+/// Session keys must be registered for that account before registering as a candidate.
+interface CollatorSelection {
+    /// Bond required to register as a collator candidate.
+    /// @return amount of native currency which is reserved on registration
+    function candidacyBond() external view returns (uint256);
+
+    /// Checks whether the native account mapped to the given evm address is a collator candidate.
+    /// @param evmAddress: The evm address to check.
+    /// @return true if the account is a candidate, false otherwise
+    function isCandidate(address evmAddress) external view returns (bool);
+
+    /// Registers the caller as a collator candidate, reserving the candidacy bond.
+    /// @return true on success
+    function registerAsCandidate() external returns (bool);
+
+    /// Deregisters the caller as a collator candidate. Candidacy bond starts un-bonding.
+    /// @return true on success
+    function leaveIntent() external returns (bool);
+
+    /// Withdraws the candidacy bond, once the un-bonding period has finished.
+    /// @return true on success
+    function withdrawBond() external returns (bool);
+}
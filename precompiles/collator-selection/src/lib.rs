@@ -0,0 +1,125 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar collator selection interface.
+//!
+//! Allows collator operators managing their funds from EVM wallets to participate in collation.
+//! All calls are dispatched on behalf of the caller's native account, as resolved by the runtime's
+//! EVM address mapping (i.e. unified account, if one is claimed).
+//!
+//! Note that session keys still need to be registered for the native account before registering as a candidate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Currency,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::{Get, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::marker::PhantomData;
+
+use astar_primitives::AccountId;
+use pallet_collator_selection::{CandidacyBond, Candidates};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<R> = <<R as pallet_collator_selection::Config>::Currency as Currency<
+    <R as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Storage item: CandidacyBond:
+/// Balance(16)
+const CANDIDACY_BOND_READ_SIZE: usize = 16;
+/// Storage item: Candidates (per candidate):
+/// AccountId(32) + Balance(16)
+const CANDIDATE_INFO_SIZE: usize = 32 + 16;
+
+/// A precompile that exposes collator selection candidate operations.
+pub struct CollatorSelectionPrecompile<R>(PhantomData<R>);
+
+#[precompile_utils::precompile]
+impl<R> CollatorSelectionPrecompile<R>
+where
+    R: pallet_evm::Config
+        + pallet_collator_selection::Config
+        + frame_system::Config<AccountId = AccountId>,
+    BalanceOf<R>: Into<U256>,
+    <R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+    R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    R::RuntimeCall: From<pallet_collator_selection::Call<R>>,
+{
+    /// Bond required to register as a collator candidate.
+    #[precompile::public("candidacyBond()")]
+    #[precompile::view]
+    fn candidacy_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_read::<R>(CANDIDACY_BOND_READ_SIZE)?;
+
+        Ok(CandidacyBond::<R>::get().into())
+    }
+
+    /// `true` if the native account of the given EVM address is a collator candidate, `false` otherwise.
+    #[precompile::public("isCandidate(address)")]
+    #[precompile::view]
+    fn is_candidate(handle: &mut impl PrecompileHandle, evm_address: Address) -> EvmResult<bool> {
+        handle.record_db_read::<R>(
+            4 + (R::MaxCandidates::get() as usize).saturating_mul(CANDIDATE_INFO_SIZE),
+        )?;
+
+        let account = R::AddressMapping::into_account_id(evm_address.into());
+        Ok(Candidates::<R>::get()
+            .iter()
+            .any(|candidate| candidate.who == account))
+    }
+
+    /// Register the caller as a collator candidate, reserving the candidacy bond.
+    #[precompile::public("registerAsCandidate()")]
+    fn register_as_candidate(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_collator_selection::Call::<R>::register_as_candidate {};
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(true)
+    }
+
+    /// Deregister the caller as a collator candidate, starting the candidacy bond un-bonding.
+    #[precompile::public("leaveIntent()")]
+    fn leave_intent(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_collator_selection::Call::<R>::leave_intent {};
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(true)
+    }
+
+    /// Withdraw the candidacy bond of the caller, after the un-bonding period has finished.
+    #[precompile::public("withdrawBond()")]
+    fn withdraw_bond(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_collator_selection::Call::<R>::withdraw_bond {};
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,166 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use frame_support::traits::ReservableCurrency;
+use precompile_utils::testing::*;
+use sp_core::H160;
+use sp_std::str::from_utf8;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn native_account(account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(account.into())
+}
+
+#[test]
+fn candidacy_bond_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::candidacy_bond {},
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(CANDIDACY_BOND));
+    });
+}
+
+#[test]
+fn register_as_candidate_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let viktor = native_account(TestAccount::Viktor);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::is_candidate {
+                    evm_address: H160::from(TestAccount::Viktor).into(),
+                },
+            )
+            .execute_returns(false);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::register_as_candidate {},
+            )
+            .execute_returns(true);
+
+        assert!(CollatorSelection::is_account_candidate(&viktor));
+        assert_eq!(Balances::reserved_balance(&viktor), CANDIDACY_BOND);
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            pallet_collator_selection::Event::CandidateAdded(viktor, CANDIDACY_BOND),
+        ));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::is_candidate {
+                    evm_address: H160::from(TestAccount::Viktor).into(),
+                },
+            )
+            .execute_returns(true);
+    });
+}
+
+#[test]
+fn register_as_candidate_without_session_keys_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Unregistered,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::register_as_candidate {},
+            )
+            .execute_reverts(|output| {
+                from_utf8(&output)
+                    .unwrap()
+                    .contains("ValidatorNotRegistered")
+            });
+
+        assert!(!CollatorSelection::is_account_candidate(&native_account(
+            TestAccount::Unregistered
+        )));
+    });
+}
+
+#[test]
+fn leave_intent_and_withdraw_bond_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let viktor = native_account(TestAccount::Viktor);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::register_as_candidate {},
+            )
+            .execute_returns(true);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::leave_intent {},
+            )
+            .execute_returns(true);
+        assert!(!CollatorSelection::is_account_candidate(&viktor));
+
+        // Bond is still locked until the next session
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::withdraw_bond {},
+            )
+            .execute_reverts(|output| from_utf8(&output).unwrap().contains("BondStillLocked"));
+        assert_eq!(Balances::reserved_balance(&viktor), CANDIDACY_BOND);
+
+        SessionIndex::set(1);
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::withdraw_bond {},
+            )
+            .execute_returns(true);
+        assert_eq!(Balances::reserved_balance(&viktor), 0);
+    });
+}
+
+#[test]
+fn leave_intent_for_non_candidate_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::leave_intent {},
+            )
+            .execute_reverts(|output| from_utf8(&output).unwrap().contains("NotCandidate"));
+    });
+}
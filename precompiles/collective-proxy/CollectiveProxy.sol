@@ -0,0 +1,26 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Collective proxy interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005015
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/collective-proxy/src/lib.rs
+///
+/// Only the approved controller contract can execute calls, on behalf of the collective proxy account.
+interface CollectiveProxy {
+    /// Native account on whose behalf the calls are executed.
+    ///
+    /// @return The proxy account.
+    function proxyAccount() external view returns (bytes32);
+
+    /// Execute the SCALE encoded runtime call on behalf of the collective proxy account.
+    /// Outcome of the call is reported by the `CollectiveProxyExecuted` runtime event.
+    ///
+    /// @param call The SCALE encoded runtime call.
+    /// @return Whether the call was dispatched.
+    function executeCall(bytes calldata call) external returns (bool);
+}
@@ -0,0 +1,142 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar collective proxy interface.
+//!
+//! Allows an approved EVM controller, e.g. a governance contract of a community, to execute calls
+//! on behalf of the collective proxy account, using `pallet-collective-proxy` `execute_call`.
+//! This completes the flow for communities whose governance lives in EVM contracts.
+//!
+//! The call is dispatched with the caller's mapped native account as the origin, so the runtime's
+//! `CollectiveProxy` origin must accept it, e.g. by including [`EnsureEvmController`].
+//! Calls are still subject to the `CallFilter` configured for the collective proxy.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{ConstU32, EnsureOrigin, Get},
+};
+use frame_system::RawOrigin;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeLimit as _;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// `DecodeLimit` specifies the max depth a call can use when decoding, as unbounded depth
+// can be used to overflow the stack.
+// Default value is 8, which is the same as in XCM call decoding.
+pub struct CollectiveProxyPrecompile<Runtime, DecodeLimit = ConstU32<8>>(
+    PhantomData<(Runtime, DecodeLimit)>,
+);
+
+type CallLengthLimit = ConstU32<2048>;
+
+#[precompile_utils::precompile]
+impl<Runtime, DecodeLimit> CollectiveProxyPrecompile<Runtime, DecodeLimit>
+where
+    Runtime: pallet_evm::Config + pallet_collective_proxy::Config,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_collective_proxy::Call<Runtime>>,
+    Runtime::AccountId: Into<[u8; 32]>,
+    DecodeLimit: Get<u32>,
+{
+    /// Native account on whose behalf the collective proxy calls are executed.
+    #[precompile::public("proxyAccount()")]
+    #[precompile::view]
+    fn proxy_account(_handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+        let account: [u8; 32] =
+            <Runtime as pallet_collective_proxy::Config>::ProxyAccountId::get().into();
+
+        Ok(account.into())
+    }
+
+    /// Execute the SCALE encoded runtime call on behalf of the collective proxy account.
+    ///
+    /// The outcome of the proxied call is reported by the `CollectiveProxyExecuted` event,
+    /// the precompile only reverts if the call couldn't be decoded or the caller isn't allowed
+    /// to act on behalf of the collective.
+    #[precompile::public("executeCall(bytes)")]
+    fn execute_call(
+        handle: &mut impl PrecompileHandle,
+        call: BoundedBytes<CallLengthLimit>,
+    ) -> EvmResult<bool> {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let input: Vec<u8> = call.into();
+
+        let call =
+            <Runtime as pallet_collective_proxy::Config>::RuntimeCall::decode_with_depth_limit(
+                DecodeLimit::get(),
+                &mut &*input,
+            )
+            .map_err(|_| revert("could not decode call"))?;
+
+        let call = pallet_collective_proxy::Call::<Runtime>::execute_call {
+            call: Box::new(call),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(true)
+    }
+}
+
+/// Ensures that the origin is the native account mapped from the `Controller` EVM address,
+/// i.e. that the call was made by the controller contract through the precompile.
+///
+/// If no controller is configured, every origin is rejected.
+pub struct EnsureEvmController<Runtime, Controller>(PhantomData<(Runtime, Controller)>);
+
+impl<Runtime, Controller, O> EnsureOrigin<O> for EnsureEvmController<Runtime, Controller>
+where
+    Runtime: pallet_evm::Config,
+    Controller: Get<Option<H160>>,
+    O: Into<Result<RawOrigin<Runtime::AccountId>, O>> + From<RawOrigin<Runtime::AccountId>>,
+{
+    type Success = Runtime::AccountId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        let controller = Controller::get().map(Runtime::AddressMapping::into_account_id);
+
+        o.into().and_then(|o| match o {
+            RawOrigin::Signed(who) if Some(&who) == controller.as_ref() => Ok(who),
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Controller::get()
+            .map(|controller| {
+                O::from(RawOrigin::Signed(Runtime::AddressMapping::into_account_id(
+                    controller,
+                )))
+            })
+            .ok_or(())
+    }
+}
@@ -0,0 +1,272 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use fp_evm::{IsPrecompileResult, Precompile};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, InstanceFilter},
+    weights::Weight,
+};
+pub use pallet_evm::{
+    AddressMapping, EnsureAddressNever, EnsureAddressRoot, PrecompileResult, PrecompileSet,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32, BuildStorage,
+};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type Block = frame_system::mocking::MockBlockU32<TestRuntime>;
+
+pub const PRECOMPILE_ADDRESS: H160 = H160::repeat_byte(0x7B);
+pub const PROXY_ACCOUNT: AccountId = AccountId32::new([0x11; 32]);
+
+#[derive(
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+    derive_more::Display,
+    TypeInfo,
+)]
+pub enum TestAccount {
+    Viktor,
+    Alice,
+    Controller,
+    Precompile,
+}
+impl Default for TestAccount {
+    fn default() -> Self {
+        Self::Viktor
+    }
+}
+impl From<TestAccount> for H160 {
+    fn from(value: TestAccount) -> H160 {
+        match value {
+            TestAccount::Viktor => H160::repeat_byte(0xAA),
+            TestAccount::Alice => H160::repeat_byte(0xAB),
+            TestAccount::Controller => H160::repeat_byte(0xCC),
+            TestAccount::Precompile => PRECOMPILE_ADDRESS,
+        }
+    }
+}
+
+/// Maps the H160 address into the native account by zero-padding it.
+pub struct AddressMapper;
+impl AddressMapping<AccountId> for AddressMapper {
+    fn into_account_id(account: H160) -> AccountId {
+        let mut account_id = [0u8; 32];
+        account_id[0..20].clone_from_slice(&account.as_bytes());
+        account_id.into()
+    }
+}
+
+impl frame_system::Config for TestRuntime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU32<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+pub type TestPrecompile = CollectiveProxyPrecompile<TestRuntime>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TestPrecompileSet<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for TestPrecompileSet<R>
+where
+    R: pallet_evm::Config,
+    TestPrecompile: Precompile,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            a if a == PRECOMPILE_ADDRESS => Some(TestPrecompile::execute(handle)),
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+        IsPrecompileResult::Answer {
+            is_precompile: address == PRECOMPILE_ADDRESS,
+            extra_cost: 0,
+        }
+    }
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxLocks = ConstU32<10>;
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+}
+
+impl pallet_timestamp::Config for TestRuntime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const PrecompilesValue: TestPrecompileSet<TestRuntime> =
+        TestPrecompileSet(PhantomData);
+    pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub ChainId: u64 = 1024;
+}
+
+pub type PrecompileCall = CollectiveProxyPrecompileCall<TestRuntime, ConstU32<8>>;
+
+impl pallet_evm::Config for TestRuntime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AddressMapper;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = TestPrecompileSet<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type Timestamp = Timestamp;
+    type ChainId = ChainId;
+    type OnChargeTransaction = ();
+    type BlockGasLimit = ();
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type WeightInfo = ();
+    type GasLimitPovSizeRatio = ConstU64<4>;
+    type SuicideQuickClearLimit = ConstU32<0>;
+}
+
+parameter_types! {
+    pub const ProxyAccountId: AccountId = PROXY_ACCOUNT;
+    pub Controller: Option<H160> = Some(TestAccount::Controller.into());
+}
+
+#[derive(Default)]
+pub struct MockCallFilter;
+impl InstanceFilter<RuntimeCall> for MockCallFilter {
+    fn filter(&self, c: &RuntimeCall) -> bool {
+        matches!(
+            c,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                | RuntimeCall::System(frame_system::Call::remark { .. })
+        )
+    }
+}
+
+impl pallet_collective_proxy::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type CollectiveProxy = EnsureEvmController<TestRuntime, Controller>;
+    type ProxyAccountId = ProxyAccountId;
+    type CallFilter = MockCallFilter;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum TestRuntime
+    {
+        System: frame_system,
+        Evm: pallet_evm,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        CollectiveProxy: pallet_collective_proxy,
+    }
+);
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<TestRuntime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        pallet_balances::GenesisConfig::<TestRuntime> {
+            balances: vec![
+                (
+                    AddressMapper::into_account_id(TestAccount::Viktor.into()),
+                    1_000,
+                ),
+                (
+                    AddressMapper::into_account_id(TestAccount::Controller.into()),
+                    1_000,
+                ),
+                (PROXY_ACCOUNT, 1_000),
+            ],
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet balances storage can be assimilated");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
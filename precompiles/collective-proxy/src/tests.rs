@@ -0,0 +1,168 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+use core::str::from_utf8;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+/// Call allowed by the filter, transferring `100` from the proxy account to `Alice`.
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: account(TestAccount::Alice),
+        value: 100,
+    })
+}
+
+#[test]
+fn proxy_account_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::proxy_account {},
+            )
+            .expect_no_logs()
+            .execute_returns(H256::from(<[u8; 32]>::from(PROXY_ACCOUNT)));
+    });
+}
+
+#[test]
+fn execute_call_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Controller,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::execute_call {
+                    call: transfer_call().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        System::assert_last_event(RuntimeEvent::CollectiveProxy(
+            pallet_collective_proxy::Event::CollectiveProxyExecuted { result: Ok(()) },
+        ));
+        assert_eq!(Balances::free_balance(&PROXY_ACCOUNT), 900);
+        assert_eq!(Balances::free_balance(&account(TestAccount::Alice)), 100);
+        assert_eq!(
+            Balances::free_balance(&account(TestAccount::Controller)),
+            1_000
+        );
+    });
+}
+
+#[test]
+fn execute_call_with_filtered_call_is_reported() {
+    ExtBuilder.build().execute_with(|| {
+        let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+            dest: account(TestAccount::Alice),
+            value: 100,
+        });
+
+        // Proxy call itself succeeds, the filtered call outcome is part of the event
+        precompiles()
+            .prepare_test(
+                TestAccount::Controller,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::execute_call {
+                    call: call.encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        System::assert_last_event(RuntimeEvent::CollectiveProxy(
+            pallet_collective_proxy::Event::CollectiveProxyExecuted {
+                result: Err(frame_system::Error::<TestRuntime>::CallFiltered.into()),
+            },
+        ));
+        assert_eq!(Balances::free_balance(&PROXY_ACCOUNT), 1_000);
+    });
+}
+
+#[test]
+fn execute_call_by_non_controller_fails() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::execute_call {
+                    call: transfer_call().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| from_utf8(output).unwrap().contains("BadOrigin"));
+
+        assert_eq!(Balances::free_balance(&PROXY_ACCOUNT), 1_000);
+    });
+}
+
+#[test]
+fn execute_call_with_invalid_call_fails() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Controller,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::execute_call {
+                    call: vec![0xff, 0xff].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"could not decode call");
+    });
+}
+
+#[test]
+fn ensure_evm_controller_works() {
+    ExtBuilder.build().execute_with(|| {
+        type EnsureController = EnsureEvmController<TestRuntime, Controller>;
+
+        assert_eq!(
+            EnsureController::try_origin(RuntimeOrigin::signed(account(TestAccount::Controller)))
+                .ok(),
+            Some(account(TestAccount::Controller))
+        );
+        assert!(
+            EnsureController::try_origin(RuntimeOrigin::signed(account(TestAccount::Viktor)))
+                .is_err()
+        );
+        assert!(EnsureController::try_origin(RuntimeOrigin::root()).is_err());
+
+        // Without a controller, no origin passes
+        type EnsureNone = EnsureEvmController<TestRuntime, ()>;
+        assert!(
+            EnsureNone::try_origin(RuntimeOrigin::signed(account(TestAccount::Controller)))
+                .is_err()
+        );
+    });
+}
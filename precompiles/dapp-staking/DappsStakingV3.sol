@@ -35,6 +35,38 @@ interface DAppStaking {
         bytes contract_address;
     }
 
+    /// @notice Describes an unlocking chunk.
+    /// @param amount: Amount undergoing the unlocking period.
+    /// @param unlock_block: Block from which the chunk can be claimed.
+    struct UnlockingChunk {
+        uint128 amount;
+        uint256 unlock_block;
+    }
+
+    /// @notice Describes the locked, unlocking & staked amounts of the ongoing era.
+    /// @param total_locked: Total locked amount, excluding the amount undergoing the unlocking period.
+    /// @param unlocking: Total amount undergoing the unlocking period.
+    /// @param current_stake_amount: Total amount staked in the ongoing era.
+    /// @param next_stake_amount: Total amount staked from the next era.
+    struct EraInfo {
+        uint128 total_locked;
+        uint128 unlocking;
+        uint128 current_stake_amount;
+        uint128 next_stake_amount;
+    }
+
+    /// @notice Describes the stake of a staker on a particular smart contract.
+    /// @param period: Period in which the stake was made, only valid if it matches the ongoing period.
+    /// @param voting: Amount staked during the voting subperiod.
+    /// @param build_and_earn: Amount staked during the build&earn subperiod.
+    /// @param loyal_staker: Whether the staker is eligible for the bonus reward.
+    struct StakerInfo {
+        uint256 period;
+        uint128 voting;
+        uint128 build_and_earn;
+        bool loyal_staker;
+    }
+
     // Storage getters
 
     /// @notice Get the current protocol state.
@@ -45,6 +77,19 @@ interface DAppStaking {
     /// @return period: The unlocking period expressed in the number of blocks.
     function unlocking_period() external view returns (uint256);
 
+    /// @notice Get the unlocking chunks of the given staker.
+    /// @param staker: The staker address.
+    /// @return chunks: The unlocking chunks, with the amounts and the blocks from which they can be claimed.
+    function unlocking_chunks(address staker) external view returns (UnlockingChunk[] memory);
+
+    /// @notice Get the locked, unlocking & staked amounts of the ongoing era.
+    function current_era_info() external view returns (EraInfo memory);
+
+    /// @notice Get the stake of the given staker on the given smart contract.
+    /// @param staker: The staker address.
+    /// @param smart_contract: The smart contract to be checked.
+    function staker_info(address staker, SmartContract calldata smart_contract) external view returns (StakerInfo memory);
+
 
     // Extrinsic calls
 
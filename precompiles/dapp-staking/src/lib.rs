@@ -67,6 +67,31 @@ pub(crate) struct PrecompileProtocolState {
     subperiod: u8,
 }
 
+/// Helper struct used to encode an unlocking chunk.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileUnlockingChunk {
+    amount: u128,
+    unlock_block: U256,
+}
+
+/// Helper struct used to encode the ongoing era info.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileEraInfo {
+    total_locked: u128,
+    unlocking: u128,
+    current_stake_amount: u128,
+    next_stake_amount: u128,
+}
+
+/// Helper struct used to encode staker info for a particular smart contract.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileStakerInfo {
+    period: U256,
+    voting: u128,
+    build_and_earn: u128,
+    loyal_staker: bool,
+}
+
 /// Helper struct used to encode different smart contract types for the v2 interface.
 #[derive(Debug, Clone, solidity::Codec)]
 pub struct SmartContractV2 {
@@ -604,6 +629,82 @@ where
         Ok(DAppStaking::<R>::unlocking_period().into())
     }
 
+    /// Read the unlocking chunks of the given staker.
+    #[precompile::public("unlocking_chunks(address)")]
+    #[precompile::view]
+    fn unlocking_chunks(
+        handle: &mut impl PrecompileHandle,
+        staker: Address,
+    ) -> EvmResult<Vec<PrecompileUnlockingChunk>> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: Ledger:
+        // Blake2_128Concat(16 + AccountId(32)) + Ledger::max_encoded_len
+        handle.record_db_read::<R>(48 + AccountLedgerFor::<R>::max_encoded_len())?;
+
+        let staker = R::AddressMapping::into_account_id(staker.into());
+        let ledger = Ledger::<R>::get(&staker);
+
+        Ok(ledger
+            .unlocking_chunks()
+            .iter()
+            .map(|chunk| PrecompileUnlockingChunk {
+                amount: chunk.amount,
+                unlock_block: chunk.unlock_block.into(),
+            })
+            .collect())
+    }
+
+    /// Read the locked, unlocking & staked amounts for the ongoing era.
+    #[precompile::public("current_era_info()")]
+    #[precompile::view]
+    fn current_era_info(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileEraInfo> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: CurrentEraInfo:
+        // Twox64Concat(8) + EraInfo::max_encoded_len
+        handle.record_db_read::<R>(8 + EraInfo::max_encoded_len())?;
+
+        let era_info = CurrentEraInfo::<R>::get();
+
+        Ok(PrecompileEraInfo {
+            total_locked: era_info.total_locked(),
+            unlocking: era_info.unlocking(),
+            current_stake_amount: era_info.current_stake_amount().total(),
+            next_stake_amount: era_info.next_stake_amount().total(),
+        })
+    }
+
+    /// Read the staking info of the given staker on the given smart contract.
+    ///
+    /// Stake is only valid if its `period` matches the ongoing period, otherwise it has already expired.
+    #[precompile::public("staker_info(address,(uint8,bytes))")]
+    #[precompile::view]
+    fn staker_info(
+        handle: &mut impl PrecompileHandle,
+        staker: Address,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileStakerInfo> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: StakerInfo:
+        // Blake2_128Concat(16 + AccountId(32)) + Blake2_128Concat(16 + SmartContract::max_encoded_len)
+        // + SingularStakingInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            64 + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + SingularStakingInfo::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let staker = R::AddressMapping::into_account_id(staker.into());
+
+        let staking_info = StakerInfo::<R>::get(&staker, &smart_contract).unwrap_or_default();
+
+        Ok(PrecompileStakerInfo {
+            period: staking_info.period_number().into(),
+            voting: staking_info.staked_amount(Subperiod::Voting),
+            build_and_earn: staking_info.staked_amount(Subperiod::BuildAndEarn),
+            loyal_staker: staking_info.is_loyal(),
+        })
+    }
+
     /// Attempt to lock the given amount into the dApp staking protocol.
     #[precompile::public("lock(uint128)")]
     fn lock(handle: &mut impl PrecompileHandle, amount: u128) -> EvmResult<bool> {
@@ -252,6 +252,7 @@ impl pallet_dapp_staking::BenchmarkHelper<MockSmartContract, AccountId>
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(0);
 }
 
 impl pallet_dapp_staking::Config for Test {
@@ -268,6 +269,7 @@ impl pallet_dapp_staking::Config for Test {
     type Observers = ();
     type AccountCheck = ();
     type TierSlots = StandardTierSlots;
+    type SponsorshipPool = ();
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -279,6 +281,9 @@ impl pallet_dapp_staking::Config for Test {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = ConstU32<0>;
+    type OwnerLoyaltyMinimumStake = ConstU128<0>;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = pallet_dapp_staking::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -82,6 +82,154 @@ fn unlocking_period_is_ok() {
     });
 }
 
+#[test]
+fn unlocking_chunks_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // No unlocking chunks initially
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::unlocking_chunks {
+                    staker: Address(ALICE),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Vec::<PrecompileUnlockingChunk>::new());
+
+        // Lock & unlock some amount in different blocks, to create multiple unlocking chunks
+        let staker = AddressMapper::into_account_id(ALICE);
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            1000,
+        ));
+        assert_ok!(DappStaking::unlock(
+            RawOrigin::Signed(staker.clone()).into(),
+            100,
+        ));
+        run_for_blocks(1);
+        assert_ok!(DappStaking::unlock(
+            RawOrigin::Signed(staker.clone()).into(),
+            200,
+        ));
+
+        let ledger = Ledger::<Test>::get(&staker);
+        assert_eq!(ledger.unlocking_chunks().len(), 2);
+        let expected_outcome: Vec<_> = ledger
+            .unlocking_chunks()
+            .iter()
+            .map(|chunk| PrecompileUnlockingChunk {
+                amount: chunk.amount,
+                unlock_block: chunk.unlock_block.into(),
+            })
+            .collect();
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::unlocking_chunks {
+                    staker: Address(ALICE),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(expected_outcome);
+    });
+}
+
+#[test]
+fn current_era_info_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Prepare some locked, unlocking & staked amounts
+        let smart_contract =
+            <Test as pallet_dapp_staking::Config>::SmartContract::evm(H160::repeat_byte(0xFA));
+        register_and_stake(ALICE, smart_contract, 1000);
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            500,
+        ));
+        assert_ok!(DappStaking::unlock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            300,
+        ));
+
+        let era_info = CurrentEraInfo::<Test>::get();
+        let expected_outcome = PrecompileEraInfo {
+            total_locked: 1200,
+            unlocking: 300,
+            current_stake_amount: era_info.current_stake_amount().total(),
+            next_stake_amount: 1000,
+        };
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::current_era_info {},
+            )
+            .expect_no_logs()
+            .execute_returns(expected_outcome);
+    });
+}
+
+#[test]
+fn staker_info_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let smart_contract_h160 = H160::repeat_byte(0xFA);
+        let smart_contract =
+            <Test as pallet_dapp_staking::Config>::SmartContract::evm(smart_contract_h160);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Evm,
+            address: smart_contract_h160.as_bytes().try_into().unwrap(),
+        };
+
+        // No stake initially
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: Address(ALICE),
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                period: U256::zero(),
+                voting: 0,
+                build_and_earn: 0,
+                loyal_staker: false,
+            });
+
+        // Stake during the voting subperiod makes the staker loyal
+        let amount = 1000;
+        register_and_stake(ALICE, smart_contract, amount);
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: Address(ALICE),
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                period: ActiveProtocolState::<Test>::get().period_number().into(),
+                voting: amount,
+                build_and_earn: 0,
+                loyal_staker: true,
+            });
+    });
+}
+
 #[test]
 fn lock_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
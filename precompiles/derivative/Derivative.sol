@@ -0,0 +1,28 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Derivative accounts interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500E
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/derivative/src/lib.rs
+///
+/// Derivative accounts are derived from the native account mapped to the caller.
+/// Only calls allowed by the runtime filter can be dispatched, including the nested ones.
+interface Derivative {
+    /// Native account Id derived from the owner account.
+    ///
+    /// @param owner The account which owns the derivative account.
+    /// @param index The index of the derivative account.
+    /// @return The derivative native account Id.
+    function derivativeAccountId(address owner, uint16 index) external view returns (bytes32);
+
+    /// Dispatch the SCALE encoded runtime call on behalf of the caller's derivative account.
+    ///
+    /// @param index The index of the derivative account.
+    /// @param call The SCALE encoded runtime call.
+    function asDerivative(uint16 index, bytes calldata call) external returns (bool);
+}
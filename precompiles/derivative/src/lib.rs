@@ -0,0 +1,126 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Astar derivative accounts interface.
+//!
+//! Allows EVM accounts, including smart contracts, to dispatch runtime calls on behalf of their
+//! derivative accounts, using `pallet-utility`'s `as_derivative`.
+//! Each index derives a distinct native account from the caller, so a single contract can keep
+//! its funds and positions (e.g. dApp staking) separated across many sub-accounts.
+//!
+//! Only calls allowed by the `CallFilter` can be dispatched. Same as with proxies, the filter is applied
+//! to the dispatch origin, so it also covers calls nested into batches. This means that the `CallFilter`
+//! must allow the `pallet-utility` calls, since `as_derivative` itself is dispatched with the filtered origin.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{Contains, IsType, OriginTrait},
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeLimit;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const LOG_TARGET: &str = "precompile::derivative";
+
+/// Max depth a call can use when decoding, same as in XCM call decoding.
+const CALL_DECODE_LIMIT: u32 = 8;
+
+type OriginOf<Runtime> =
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin;
+
+/// A precompile that allows EVM accounts to dispatch calls on behalf of their derivative accounts.
+pub struct DerivativePrecompile<Runtime, CallFilter>(PhantomData<(Runtime, CallFilter)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, CallFilter> DerivativePrecompile<Runtime, CallFilter>
+where
+    Runtime: pallet_evm::Config + pallet_utility::Config,
+    OriginOf<Runtime>: From<Option<Runtime::AccountId>>
+        + OriginTrait<Call = <Runtime as frame_system::Config>::RuntimeCall>,
+    <Runtime as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_utility::Call<Runtime>>,
+    Runtime::AccountId: Into<[u8; 32]>,
+    CallFilter: Contains<<Runtime as frame_system::Config>::RuntimeCall> + 'static,
+{
+    /// Native account Id derived from the `owner` account, with the specified `index`.
+    #[precompile::public("derivativeAccountId(address,uint16)")]
+    #[precompile::view]
+    fn derivative_account_id(
+        _handle: &mut impl PrecompileHandle,
+        owner: Address,
+        index: u16,
+    ) -> EvmResult<H256> {
+        let owner = Runtime::AddressMapping::into_account_id(owner.into());
+        let derivative = pallet_utility::Pallet::<Runtime>::derivative_account_id(owner, index);
+
+        Ok(H256::from(derivative.into()))
+    }
+
+    /// Dispatch the SCALE encoded runtime `call` on behalf of the caller's derivative account
+    /// with the specified `index`.
+    #[precompile::public("asDerivative(uint16,bytes)")]
+    fn as_derivative(
+        handle: &mut impl PrecompileHandle,
+        index: u16,
+        call: UnboundedBytes,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: index: {:?}, call: {:?}",
+            index,
+            call
+        );
+
+        let input: Vec<u8> = call.into();
+        let runtime_call =
+            <Runtime as pallet_utility::Config>::RuntimeCall::decode_with_depth_limit(
+                CALL_DECODE_LIMIT,
+                &mut &*input,
+            )
+            .map_err(|_| revert("could not decode call"))?;
+        // Nested calls are checked by the origin filter, once dispatched
+        if !CallFilter::contains(runtime_call.into_ref()) {
+            return Err(revert("call not allowed"));
+        }
+
+        // Prepare call & dispatch it
+        let mut origin: OriginOf<Runtime> = Some(Runtime::AddressMapping::into_account_id(
+            handle.context().caller,
+        ))
+        .into();
+        origin.add_filter(CallFilter::contains);
+        let as_derivative_call = pallet_utility::Call::<Runtime>::as_derivative {
+            index,
+            call: runtime_call.into(),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, as_derivative_call)?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,152 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+
+use frame_support::assert_ok;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+fn derivative_account(test_account: TestAccount, index: u16) -> AccountId {
+    pallet_utility::Pallet::<TestRuntime>::derivative_account_id(account(test_account), index)
+}
+
+/// Call allowed to be dispatched by the derivative accounts, transferring `100` to `Bob`.
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: account(TestAccount::Bob),
+        value: 100,
+    })
+}
+
+#[test]
+fn derivative_account_id_works() {
+    ExtBuilder.build().execute_with(|| {
+        for index in [0, 1, u16::MAX] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::derivative_account_id {
+                        owner: Address(TestAccount::Viktor.into()),
+                        index,
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(H256::from(<[u8; 32]>::from(derivative_account(
+                    TestAccount::Viktor,
+                    index,
+                ))));
+        }
+    });
+}
+
+#[test]
+fn as_derivative_works() {
+    ExtBuilder.build().execute_with(|| {
+        for index in [0, 1] {
+            assert_ok!(Balances::transfer_allow_death(
+                RuntimeOrigin::signed(account(TestAccount::Viktor)),
+                derivative_account(TestAccount::Viktor, index),
+                200
+            ));
+        }
+
+        // Call is dispatched on behalf of the derivative account with the specified index only
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_derivative {
+                    index: 1,
+                    call: transfer_call().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            Balances::free_balance(&derivative_account(TestAccount::Viktor, 0)),
+            200
+        );
+        assert_eq!(
+            Balances::free_balance(&derivative_account(TestAccount::Viktor, 1)),
+            100
+        );
+        assert_eq!(Balances::free_balance(&account(TestAccount::Viktor)), 600);
+        assert_eq!(Balances::free_balance(&account(TestAccount::Bob)), 1_100);
+    });
+}
+
+#[test]
+fn as_derivative_with_invalid_call_fails() {
+    ExtBuilder.build().execute_with(|| {
+        // Not decodable
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_derivative {
+                    index: 0,
+                    call: vec![0xff, 0xff].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"could not decode call");
+
+        // Not allowed by the filter
+        let disallowed_call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: account(TestAccount::Bob),
+            value: 100,
+        });
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_derivative {
+                    index: 0,
+                    call: disallowed_call.clone().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"call not allowed");
+
+        // Filter applies to the nested calls too
+        let batch_call = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![disallowed_call, transfer_call()],
+        });
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_derivative {
+                    index: 0,
+                    call: batch_call.encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"CallFiltered\") })"));
+    });
+}
@@ -0,0 +1,64 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Governance interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500B
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/governance/src/lib.rs
+///
+/// All actions are performed on behalf of the native account mapped to the caller.
+/// Only proposals allowed by the runtime filter can be submitted.
+interface Governance {
+    /// Number of public proposals submitted so far.
+    function publicPropCount() external view returns (uint32);
+
+    /// Deposit backing the public proposal, placed by each of its supporters.
+    ///
+    /// @param propIndex The index of the public proposal.
+    /// @return The deposit amount, or zero if there is no such proposal.
+    function depositOf(uint32 propIndex) external view returns (uint256);
+
+    /// Submit the SCALE encoded runtime call as a public proposal.
+    ///
+    /// @param call The SCALE encoded runtime call, must not exceed 128 bytes.
+    /// @param value The deposit backing the proposal, must not be below the minimum deposit.
+    function propose(bytes calldata call, uint256 value) external returns (bool);
+
+    /// Second the public proposal, placing the same deposit as the proposer.
+    ///
+    /// @param propIndex The index of the public proposal.
+    function second(uint32 propIndex) external returns (bool);
+
+    /// Vote on the ongoing referendum.
+    ///
+    /// @param refIndex The index of the referendum.
+    /// @param aye Whether the vote is in favor of the referendum.
+    /// @param balance The amount of tokens locked for the vote.
+    /// @param conviction The conviction, from 0 (no lock, 0.1x votes) to 6 (32x lock periods, 6x votes).
+    function vote(uint32 refIndex, bool aye, uint256 balance, uint8 conviction) external returns (bool);
+
+    /// Remove the vote cast on the referendum.
+    ///
+    /// @param refIndex The index of the referendum.
+    function removeVote(uint32 refIndex) external returns (bool);
+
+    /// Delegate the voting power to another account.
+    ///
+    /// @dev Delegation applies to all the referenda, since there are no separate governance tracks.
+    /// @param to The address of the delegate.
+    /// @param conviction The conviction, from 0 (no lock, 0.1x votes) to 6 (32x lock periods, 6x votes).
+    /// @param balance The amount of tokens locked for the delegation.
+    function delegate(address to, uint8 conviction, uint256 balance) external returns (bool);
+
+    /// Undelegate the voting power.
+    ///
+    /// @dev Delegated tokens stay locked for the period determined by the delegation conviction.
+    function undelegate() external returns (bool);
+
+    /// Unlock the balance which is no longer locked by the votes.
+    function unlock() external returns (bool);
+}
@@ -0,0 +1,271 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Astar governance interface.
+//!
+//! Allows EVM accounts, including smart contracts, to take part in on-chain governance on behalf of their
+//! mapped native account (e.g. the unified account, if one is claimed).
+//! This way DAOs built with Solidity tooling can submit proposals, back them with deposits and vote with conviction,
+//! the same way as any native account can.
+//!
+//! Astar governance is based on `pallet-democracy`, so:
+//! * submitting a proposal creates a public proposal which competes for the next referendum launch,
+//! * seconding a proposal places a deposit equal to the one of the proposer, backing it,
+//! * votes are cast on the ongoing referenda, with conviction,
+//! * voting power can be delegated to another account, with conviction. Since there are no tracks,
+//!   delegation applies to all the referenda.
+//!
+//! Only proposals allowed by the `ProposalFilter` can be submitted, and they must be small enough to be stored inline.
+//! This way the filter can inspect the proposed call, and no separate preimage is required.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{Bounded, ConstU32, Contains},
+    BoundedVec,
+};
+use pallet_democracy::{AccountVote, BalanceOf, Conviction, Vote};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{DecodeLimit, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const LOG_TARGET: &str = "precompile::governance";
+
+/// Max depth a call can use when decoding, same as in XCM call decoding.
+const CALL_DECODE_LIMIT: u32 = 8;
+
+/// Max length of the encoded proposal, which allows it to be stored inline, without the preimage.
+type ProposalLengthLimit = ConstU32<128>;
+
+/// A precompile that allows EVM accounts to participate in on-chain governance.
+pub struct GovernancePrecompile<Runtime, ProposalFilter>(PhantomData<(Runtime, ProposalFilter)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, ProposalFilter> GovernancePrecompile<Runtime, ProposalFilter>
+where
+    Runtime: pallet_evm::Config + pallet_democracy::Config,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_democracy::Call<Runtime>>,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+    ProposalFilter: Contains<Runtime::RuntimeCall>,
+{
+    /// Number of public proposals submitted so far.
+    #[precompile::public("publicPropCount()")]
+    #[precompile::view]
+    fn public_prop_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_db_read::<Runtime>(u32::max_encoded_len())?;
+
+        Ok(pallet_democracy::PublicPropCount::<Runtime>::get())
+    }
+
+    /// Deposit backing the public proposal with the specified index, placed by each of its supporters.
+    ///
+    /// Returns zero if there is no such proposal.
+    #[precompile::public("depositOf(uint32)")]
+    #[precompile::view]
+    fn deposit_of(handle: &mut impl PrecompileHandle, prop_index: u32) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(
+            BoundedVec::<Runtime::AccountId, <Runtime as pallet_democracy::Config>::MaxDeposits>::max_encoded_len()
+                .saturating_add(BalanceOf::<Runtime>::max_encoded_len()),
+        )?;
+
+        Ok(pallet_democracy::DepositOf::<Runtime>::get(prop_index)
+            .map(|(_, deposit)| deposit.into())
+            .unwrap_or_default())
+    }
+
+    /// Submit the SCALE encoded runtime `call` as a public proposal, backing it with the `value` deposit.
+    #[precompile::public("propose(bytes,uint256)")]
+    fn propose(
+        handle: &mut impl PrecompileHandle,
+        call: BoundedBytes<ProposalLengthLimit>,
+        value: U256,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: call: {:?}, value: {:?}",
+            call,
+            value
+        );
+
+        let input: Vec<u8> = call.into();
+        let value = Self::u256_to_balance(value).in_field("value")?;
+
+        let runtime_call =
+            Runtime::RuntimeCall::decode_with_depth_limit(CALL_DECODE_LIMIT, &mut &*input)
+                .map_err(|_| revert("could not decode call"))?;
+        if !ProposalFilter::contains(&runtime_call) {
+            return Err(revert("call not allowed"));
+        }
+
+        let proposal = Bounded::Inline(
+            input
+                .try_into()
+                .map_err(|_| revert("call too large to be stored inline"))?,
+        );
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let propose_call = pallet_democracy::Call::<Runtime>::propose { proposal, value };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), propose_call)?;
+
+        Ok(true)
+    }
+
+    /// Second the public proposal with the specified index, placing the same deposit as the proposer.
+    #[precompile::public("second(uint32)")]
+    fn second(handle: &mut impl PrecompileHandle, prop_index: u32) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let second_call = pallet_democracy::Call::<Runtime>::second {
+            proposal: prop_index,
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), second_call)?;
+
+        Ok(true)
+    }
+
+    /// Vote on the ongoing referendum with the specified index.
+    ///
+    /// `conviction` ranges from `0` (no lock, 0.1x votes) to `6` (32x lock periods, 6x votes).
+    #[precompile::public("vote(uint32,bool,uint256,uint8)")]
+    fn vote(
+        handle: &mut impl PrecompileHandle,
+        ref_index: u32,
+        aye: bool,
+        balance: U256,
+        conviction: u8,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: ref_index: {:?}, aye: {:?}, balance: {:?}, conviction: {:?}",
+            ref_index,
+            aye,
+            balance,
+            conviction
+        );
+
+        let balance = Self::u256_to_balance(balance).in_field("balance")?;
+        let conviction =
+            Conviction::try_from(conviction).map_err(|_| revert("invalid conviction"))?;
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let vote_call = pallet_democracy::Call::<Runtime>::vote {
+            ref_index,
+            vote: AccountVote::Standard {
+                vote: Vote { aye, conviction },
+                balance,
+            },
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), vote_call)?;
+
+        Ok(true)
+    }
+
+    /// Remove the vote cast on the referendum with the specified index.
+    ///
+    /// If the referendum is still ongoing, the vote is no longer counted.
+    #[precompile::public("removeVote(uint32)")]
+    fn remove_vote(handle: &mut impl PrecompileHandle, ref_index: u32) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let remove_vote_call = pallet_democracy::Call::<Runtime>::remove_vote { index: ref_index };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), remove_vote_call)?;
+
+        Ok(true)
+    }
+
+    /// Unlock the caller's balance which is no longer locked by the votes.
+    #[precompile::public("unlock()")]
+    fn unlock(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let unlock_call = pallet_democracy::Call::<Runtime>::unlock {
+            target: Runtime::Lookup::unlookup(origin.clone()),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), unlock_call)?;
+
+        Ok(true)
+    }
+
+    /// Delegate the caller's voting power to the `to` account, with the specified `conviction`.
+    ///
+    /// Delegated `balance` is locked, and is counted in all the referenda the delegate votes on.
+    #[precompile::public("delegate(address,uint8,uint256)")]
+    fn delegate(
+        handle: &mut impl PrecompileHandle,
+        to: Address,
+        conviction: u8,
+        balance: U256,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: to: {:?}, conviction: {:?}, balance: {:?}",
+            to,
+            conviction,
+            balance
+        );
+
+        let balance = Self::u256_to_balance(balance).in_field("balance")?;
+        let conviction =
+            Conviction::try_from(conviction).map_err(|_| revert("invalid conviction"))?;
+        let to = Runtime::AddressMapping::into_account_id(to.into());
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let delegate_call = pallet_democracy::Call::<Runtime>::delegate {
+            to: Runtime::Lookup::unlookup(to),
+            conviction,
+            balance,
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), delegate_call)?;
+
+        Ok(true)
+    }
+
+    /// Undelegate the caller's voting power.
+    ///
+    /// Delegated balance stays locked for the period determined by the delegation conviction.
+    #[precompile::public("undelegate()")]
+    fn undelegate(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let undelegate_call = pallet_democracy::Call::<Runtime>::undelegate {};
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), undelegate_call)?;
+
+        Ok(true)
+    }
+
+    fn u256_to_balance(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").into())
+    }
+}
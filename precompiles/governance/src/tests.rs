@@ -0,0 +1,375 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+
+use pallet_democracy::Tally;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+/// Proposal allowed to be submitted via the precompile.
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark {
+        remark: b"hello".to_vec(),
+    })
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+/// Submit the remark proposal on behalf of the `Viktor` account.
+fn propose_remark(value: Balance) {
+    precompiles()
+        .prepare_test(
+            TestAccount::Viktor,
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::propose {
+                call: remark_call().encode().into(),
+                value: value.into(),
+            },
+        )
+        .expect_no_logs()
+        .execute_returns(true);
+}
+
+#[test]
+fn propose_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::public_prop_count {},
+            )
+            .expect_no_logs()
+            .execute_returns(0_u32);
+
+        propose_remark(MINIMUM_DEPOSIT);
+
+        // Proposal is submitted on behalf of the mapped account
+        assert_eq!(
+            Balances::reserved_balance(&account(TestAccount::Viktor)),
+            MINIMUM_DEPOSIT
+        );
+        let (prop_index, proposal, proposer) = pallet_democracy::PublicProps::<TestRuntime>::get()
+            .into_iter()
+            .next()
+            .expect("Proposal was submitted");
+        assert_eq!(prop_index, 0);
+        assert_eq!(proposer, account(TestAccount::Viktor));
+        assert_eq!(
+            proposal,
+            Bounded::Inline(remark_call().encode().try_into().unwrap())
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::public_prop_count {},
+            )
+            .expect_no_logs()
+            .execute_returns(1_u32);
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::deposit_of { prop_index: 0 },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(MINIMUM_DEPOSIT));
+    });
+}
+
+#[test]
+fn propose_fails_for_invalid_proposals() {
+    ExtBuilder.build().execute_with(|| {
+        // Call which isn't allowed by the filter
+        let call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: account(TestAccount::Alice),
+            value: 10,
+        });
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::propose {
+                    call: call.encode().into(),
+                    value: MINIMUM_DEPOSIT.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"call not allowed");
+
+        // Call which can't be decoded
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::propose {
+                    call: vec![0xFF, 0xFF].into(),
+                    value: MINIMUM_DEPOSIT.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"could not decode call");
+
+        // Deposit below the minimum
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::propose {
+                    call: remark_call().encode().into(),
+                    value: (MINIMUM_DEPOSIT - 1).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"ValueLow\") })"));
+
+        assert!(pallet_democracy::PublicProps::<TestRuntime>::get().is_empty());
+    });
+}
+
+#[test]
+fn second_works() {
+    ExtBuilder.build().execute_with(|| {
+        propose_remark(MINIMUM_DEPOSIT);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::second { prop_index: 0 },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Seconder places the same deposit as the proposer
+        assert_eq!(
+            Balances::reserved_balance(&account(TestAccount::Alice)),
+            MINIMUM_DEPOSIT
+        );
+        let (backers, deposit) =
+            pallet_democracy::DepositOf::<TestRuntime>::get(0).expect("Proposal exists");
+        assert_eq!(
+            backers.into_inner(),
+            vec![account(TestAccount::Viktor), account(TestAccount::Alice)]
+        );
+        assert_eq!(deposit, MINIMUM_DEPOSIT);
+
+        // Non-existing proposal can't be seconded
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::second { prop_index: 1 },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"ProposalMissing\") })"));
+
+        // Deposit of a non-existing proposal is zero
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::deposit_of { prop_index: 1 },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::zero());
+    });
+}
+
+#[test]
+fn vote_with_conviction_works() {
+    ExtBuilder.build().execute_with(|| {
+        // Proposal is tabled as a referendum at the start of the next launch period
+        propose_remark(MINIMUM_DEPOSIT);
+        run_to_block(10);
+        assert!(Democracy::referendum_status(0).is_ok());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vote {
+                    ref_index: 0,
+                    aye: true,
+                    balance: 100.into(),
+                    conviction: 2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vote {
+                    ref_index: 0,
+                    aye: false,
+                    balance: 50.into(),
+                    conviction: 1,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Votes are weighted by conviction
+        assert_eq!(
+            Democracy::referendum_status(0).unwrap().tally,
+            Tally {
+                ayes: 200,
+                nays: 50,
+                turnout: 150,
+            }
+        );
+
+        // Vote can be removed while the referendum is ongoing
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::remove_vote { ref_index: 0 },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            Democracy::referendum_status(0).unwrap().tally,
+            Tally {
+                ayes: 200,
+                nays: 0,
+                turnout: 100,
+            }
+        );
+
+        // Balance isn't locked anymore once the vote is removed, so it can be unlocked
+        assert!(
+            !pallet_balances::Locks::<TestRuntime>::get(account(TestAccount::Alice)).is_empty()
+        );
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::unlock {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert!(pallet_balances::Locks::<TestRuntime>::get(account(TestAccount::Alice)).is_empty());
+    });
+}
+
+#[test]
+fn vote_fails_for_invalid_conviction() {
+    ExtBuilder.build().execute_with(|| {
+        propose_remark(MINIMUM_DEPOSIT);
+        run_to_block(10);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vote {
+                    ref_index: 0,
+                    aye: true,
+                    balance: 100.into(),
+                    conviction: 7,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"invalid conviction");
+    });
+}
+
+#[test]
+fn delegate_and_undelegate_works() {
+    ExtBuilder.build().execute_with(|| {
+        propose_remark(MINIMUM_DEPOSIT);
+        run_to_block(10);
+
+        // Alice delegates her voting power to Viktor
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::delegate {
+                    to: Address(TestAccount::Viktor.into()),
+                    conviction: 1,
+                    balance: 50.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Delegated votes are counted along with the delegate's own vote
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vote {
+                    ref_index: 0,
+                    aye: true,
+                    balance: 100.into(),
+                    conviction: 1,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            Democracy::referendum_status(0).unwrap().tally,
+            Tally {
+                ayes: 150,
+                nays: 0,
+                turnout: 150,
+            }
+        );
+
+        // Delegated votes are no longer counted once undelegated
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::undelegate {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            Democracy::referendum_status(0).unwrap().tally,
+            Tally {
+                ayes: 100,
+                nays: 0,
+                turnout: 100,
+            }
+        );
+
+        // Can't undelegate twice
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::undelegate {},
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"NotDelegating\") })"));
+    });
+}
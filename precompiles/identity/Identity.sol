@@ -0,0 +1,85 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Identity interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005014
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/identity/src/lib.rs
+///
+/// The identity belongs to the mapped native account of the address.
+interface Identity {
+    /// Identity field, only raw data of at most 32 bytes is accepted.
+    /// Hashed fields, which can only be set natively, are returned as their hash.
+    struct Data {
+        bool hasData;
+        bytes value;
+    }
+
+    /// Additional identity field, as a key-value pair.
+    struct Additional {
+        Data key;
+        Data value;
+    }
+
+    /// Identity information.
+    struct IdentityInfo {
+        Additional[] additional;
+        Data display;
+        Data legal;
+        Data web;
+        Data riot;
+        Data email;
+        bool hasPgpFingerprint;
+        bytes pgpFingerprint;
+        Data image;
+        Data twitter;
+    }
+
+    /// Registrar judgement.
+    ///
+    /// judgement: 0 - unknown, 1 - fee paid (pending request), 2 - reasonable, 3 - known good,
+    ///            4 - out of date, 5 - low quality, 6 - erroneous.
+    /// feePaid: fee reserved for the pending request, only set for the fee paid judgement.
+    struct JudgementInfo {
+        uint32 registrarIndex;
+        uint8 judgement;
+        uint256 feePaid;
+    }
+
+    /// Identity registration, isValid is false if there's no identity.
+    struct Registration {
+        bool isValid;
+        JudgementInfo[] judgements;
+        uint256 deposit;
+        IdentityInfo info;
+    }
+
+    /// Identity of the address, including the registrars judgements.
+    ///
+    /// @param who The address to query.
+    /// @return The identity registration.
+    function identityOf(address who) external view returns (Registration memory);
+
+    /// Set the caller's identity, reserving the deposit for it.
+    ///
+    /// @param info The identity information.
+    function setIdentity(IdentityInfo memory info) external returns (bool);
+
+    /// Clear the caller's identity, unreserving the deposit.
+    function clearIdentity() external returns (bool);
+
+    /// Request a judgement of the caller's identity from the registrar, reserving its fee.
+    ///
+    /// @param registrarIndex The registrar index.
+    /// @param maxFee The maximum fee the caller is willing to pay.
+    function requestJudgement(uint32 registrarIndex, uint256 maxFee) external returns (bool);
+
+    /// Cancel the pending judgement request, unreserving the fee.
+    ///
+    /// @param registrarIndex The registrar index.
+    function cancelRequest(uint32 registrarIndex) external returns (bool);
+}
@@ -0,0 +1,398 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar identity interface.
+//!
+//! Allows EVM accounts, e.g. the unified accounts, to manage their on-chain identity based on `pallet-identity`,
+//! and request judgements from the registrars, without switching to a native wallet.
+//! Identity of any address, including the registrars judgements, can be queried by the smart contracts,
+//! this way deployer accounts can be verified on-chain.
+//!
+//! The identity belongs to the mapped native account of the address.
+//!
+//! Identity fields are encoded as a `(bool hasData, bytes value)` pair, and only raw data is accepted,
+//! at most 32 bytes long. Hashed fields, which can only be set natively, are returned as their hash.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{ConstU32, Currency, Get},
+};
+use pallet_evm::AddressMapping;
+use pallet_identity::{legacy::IdentityInfo as LegacyIdentityInfo, Judgement};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Max length of the raw identity data.
+type MaxDataLength = ConstU32<32>;
+
+/// Length of the PGP fingerprint.
+const PGP_FINGERPRINT_LENGTH: u32 = 20;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_identity::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Helper struct used to encode an identity field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, solidity::Codec)]
+pub struct Data {
+    has_data: bool,
+    value: BoundedBytes<MaxDataLength>,
+}
+
+/// Helper struct used to encode an additional identity field, as a key-value pair.
+#[derive(Debug, Clone, Default, PartialEq, Eq, solidity::Codec)]
+pub struct Additional {
+    key: Data,
+    value: Data,
+}
+
+/// Helper struct used to encode the identity information, with the same fields as
+/// `pallet_identity::legacy::IdentityInfo`.
+#[derive(Debug, Clone, PartialEq, Eq, solidity::Codec)]
+pub struct IdentityInfo<FieldLimit> {
+    additional: BoundedVec<Additional, FieldLimit>,
+    display: Data,
+    legal: Data,
+    web: Data,
+    riot: Data,
+    email: Data,
+    has_pgp_fingerprint: bool,
+    pgp_fingerprint: BoundedBytes<ConstU32<PGP_FINGERPRINT_LENGTH>>,
+    image: Data,
+    twitter: Data,
+}
+
+// Implemented manually, since the runtime field limit doesn't implement `Default`.
+impl<FieldLimit> Default for IdentityInfo<FieldLimit> {
+    fn default() -> Self {
+        Self {
+            additional: Vec::new().into(),
+            display: Data::default(),
+            legal: Data::default(),
+            web: Data::default(),
+            riot: Data::default(),
+            email: Data::default(),
+            has_pgp_fingerprint: false,
+            pgp_fingerprint: Vec::new().into(),
+            image: Data::default(),
+            twitter: Data::default(),
+        }
+    }
+}
+
+/// Helper struct used to encode a registrar judgement.
+///
+/// `judgement` codes: 0 - unknown, 1 - fee paid, 2 - reasonable, 3 - known good,
+/// 4 - out of date, 5 - low quality, 6 - erroneous.
+/// `fee_paid` is only set for the fee paid judgement, which is a pending request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, solidity::Codec)]
+pub struct JudgementInfo {
+    registrar_index: u32,
+    judgement: u8,
+    fee_paid: U256,
+}
+
+/// Helper struct used to encode the identity registration.
+///
+/// `is_valid` is false if the account has no identity, other fields are empty then.
+#[derive(Debug, Clone, PartialEq, Eq, solidity::Codec)]
+pub struct Registration<FieldLimit> {
+    is_valid: bool,
+    judgements: Vec<JudgementInfo>,
+    deposit: U256,
+    info: IdentityInfo<FieldLimit>,
+}
+
+impl<FieldLimit> Default for Registration<FieldLimit> {
+    fn default() -> Self {
+        Self {
+            is_valid: false,
+            judgements: Vec::new(),
+            deposit: U256::zero(),
+            info: IdentityInfo::default(),
+        }
+    }
+}
+
+/// A precompile that allows EVM accounts to manage their identity.
+pub struct IdentityPrecompile<Runtime, MaxAdditionalFields>(
+    PhantomData<(Runtime, MaxAdditionalFields)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, MaxAdditionalFields> IdentityPrecompile<Runtime, MaxAdditionalFields>
+where
+    MaxAdditionalFields: Get<u32> + 'static,
+    Runtime: pallet_evm::Config
+        + pallet_identity::Config<IdentityInformation = LegacyIdentityInfo<MaxAdditionalFields>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_identity::Call<Runtime>>,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+    /// Identity registration of the `who` mapped native account, including the registrars judgements.
+    #[precompile::public("identityOf(address)")]
+    #[precompile::view]
+    fn identity_of(
+        handle: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<Registration<MaxAdditionalFields>> {
+        // Storage item: IdentityOf:
+        // Blake2_128(16) + AccountId(32) + Registration + Option<Username>
+        handle.record_db_read::<Runtime>(
+            48 + pallet_identity::Registration::<
+                BalanceOf<Runtime>,
+                <Runtime as pallet_identity::Config>::MaxRegistrars,
+                LegacyIdentityInfo<MaxAdditionalFields>,
+            >::max_encoded_len()
+                + 1
+                + <Runtime as pallet_identity::Config>::MaxUsernameLength::get() as usize,
+        )?;
+
+        let who = Runtime::AddressMapping::into_account_id(who.into());
+        let Some((registration, _)) = pallet_identity::Pallet::<Runtime>::identity(who) else {
+            return Ok(Registration::default());
+        };
+
+        let judgements = registration
+            .judgements
+            .into_iter()
+            .map(|(registrar_index, judgement)| {
+                Self::judgement_to_output(registrar_index, judgement)
+            })
+            .collect();
+
+        Ok(Registration {
+            is_valid: true,
+            judgements,
+            deposit: registration.deposit.into(),
+            info: Self::identity_info_to_output(registration.info),
+        })
+    }
+
+    /// Set the caller's identity, reserving the deposit for it.
+    ///
+    /// Any pending judgement requests are cancelled, and the given judgements are reset.
+    #[precompile::public(
+        "setIdentity((((bool,bytes),(bool,bytes))[],(bool,bytes),(bool,bytes),(bool,bytes),(bool,bytes),(bool,bytes),bool,bytes,(bool,bytes),(bool,bytes)))"
+    )]
+    fn set_identity(
+        handle: &mut impl PrecompileHandle,
+        info: IdentityInfo<MaxAdditionalFields>,
+    ) -> EvmResult<bool> {
+        let info = Self::identity_info_from_input(info)?;
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let set_identity_call = pallet_identity::Call::<Runtime>::set_identity {
+            info: Box::new(info),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), set_identity_call)?;
+
+        Ok(true)
+    }
+
+    /// Clear the caller's identity, unreserving the deposit.
+    #[precompile::public("clearIdentity()")]
+    fn clear_identity(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let clear_identity_call = pallet_identity::Call::<Runtime>::clear_identity {};
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), clear_identity_call)?;
+
+        Ok(true)
+    }
+
+    /// Request a judgement of the caller's identity from the registrar, reserving the registrar's fee,
+    /// which must not exceed `max_fee`.
+    #[precompile::public("requestJudgement(uint32,uint256)")]
+    fn request_judgement(
+        handle: &mut impl PrecompileHandle,
+        registrar_index: u32,
+        max_fee: U256,
+    ) -> EvmResult<bool> {
+        let max_fee = Self::u256_to_balance(max_fee).in_field("max_fee")?;
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let request_judgement_call = pallet_identity::Call::<Runtime>::request_judgement {
+            reg_index: registrar_index,
+            max_fee,
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            request_judgement_call,
+        )?;
+
+        Ok(true)
+    }
+
+    /// Cancel the pending judgement request to the registrar, unreserving the fee.
+    #[precompile::public("cancelRequest(uint32)")]
+    fn cancel_request(handle: &mut impl PrecompileHandle, registrar_index: u32) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let cancel_request_call = pallet_identity::Call::<Runtime>::cancel_request {
+            reg_index: registrar_index,
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), cancel_request_call)?;
+
+        Ok(true)
+    }
+
+    fn data_from_input(data: Data) -> MayRevert<pallet_identity::Data> {
+        if !data.has_data {
+            return Ok(pallet_identity::Data::None);
+        }
+
+        let value: Vec<u8> = data.value.into();
+        Ok(pallet_identity::Data::Raw(
+            value
+                .try_into()
+                .map_err(|_| RevertReason::value_is_too_large("data"))?,
+        ))
+    }
+
+    fn data_to_output(data: pallet_identity::Data) -> Data {
+        let value: Vec<u8> = match data {
+            pallet_identity::Data::None => return Data::default(),
+            pallet_identity::Data::Raw(value) => value.into(),
+            pallet_identity::Data::BlakeTwo256(hash)
+            | pallet_identity::Data::Sha256(hash)
+            | pallet_identity::Data::Keccak256(hash)
+            | pallet_identity::Data::ShaThree256(hash) => hash.to_vec(),
+        };
+
+        Data {
+            has_data: true,
+            value: value.into(),
+        }
+    }
+
+    fn identity_info_from_input(
+        info: IdentityInfo<MaxAdditionalFields>,
+    ) -> MayRevert<LegacyIdentityInfo<MaxAdditionalFields>> {
+        let additional: Vec<Additional> = info.additional.into();
+        let additional = additional
+            .into_iter()
+            .map(|field| {
+                Ok((
+                    Self::data_from_input(field.key)?,
+                    Self::data_from_input(field.value)?,
+                ))
+            })
+            .collect::<MayRevert<Vec<_>>>()
+            .in_field("additional")?
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("additional fields"))?;
+
+        let pgp_fingerprint = if info.has_pgp_fingerprint {
+            let fingerprint: Vec<u8> = info.pgp_fingerprint.into();
+            Some(
+                fingerprint
+                    .try_into()
+                    .map_err(|_| Revert::new(RevertReason::custom("invalid fingerprint length")))
+                    .in_field("pgp_fingerprint")?,
+            )
+        } else {
+            None
+        };
+
+        Ok(LegacyIdentityInfo {
+            additional,
+            display: Self::data_from_input(info.display).in_field("display")?,
+            legal: Self::data_from_input(info.legal).in_field("legal")?,
+            web: Self::data_from_input(info.web).in_field("web")?,
+            riot: Self::data_from_input(info.riot).in_field("riot")?,
+            email: Self::data_from_input(info.email).in_field("email")?,
+            pgp_fingerprint,
+            image: Self::data_from_input(info.image).in_field("image")?,
+            twitter: Self::data_from_input(info.twitter).in_field("twitter")?,
+        })
+    }
+
+    fn identity_info_to_output(
+        info: LegacyIdentityInfo<MaxAdditionalFields>,
+    ) -> IdentityInfo<MaxAdditionalFields> {
+        let additional: Vec<Additional> = info
+            .additional
+            .into_iter()
+            .map(|(key, value)| Additional {
+                key: Self::data_to_output(key),
+                value: Self::data_to_output(value),
+            })
+            .collect();
+
+        IdentityInfo {
+            additional: additional.into(),
+            display: Self::data_to_output(info.display),
+            legal: Self::data_to_output(info.legal),
+            web: Self::data_to_output(info.web),
+            riot: Self::data_to_output(info.riot),
+            email: Self::data_to_output(info.email),
+            has_pgp_fingerprint: info.pgp_fingerprint.is_some(),
+            pgp_fingerprint: info
+                .pgp_fingerprint
+                .map(|fingerprint| fingerprint.to_vec())
+                .unwrap_or_default()
+                .into(),
+            image: Self::data_to_output(info.image),
+            twitter: Self::data_to_output(info.twitter),
+        }
+    }
+
+    fn judgement_to_output(
+        registrar_index: u32,
+        judgement: Judgement<BalanceOf<Runtime>>,
+    ) -> JudgementInfo {
+        let (judgement, fee_paid) = match judgement {
+            Judgement::Unknown => (0, U256::zero()),
+            Judgement::FeePaid(fee) => (1, fee.into()),
+            Judgement::Reasonable => (2, U256::zero()),
+            Judgement::KnownGood => (3, U256::zero()),
+            Judgement::OutOfDate => (4, U256::zero()),
+            Judgement::LowQuality => (5, U256::zero()),
+            Judgement::Erroneous => (6, U256::zero()),
+        };
+
+        JudgementInfo {
+            registrar_index,
+            judgement,
+            fee_paid,
+        }
+    }
+
+    fn u256_to_balance(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").into())
+    }
+}
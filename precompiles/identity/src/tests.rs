@@ -0,0 +1,279 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::assert_ok;
+use precompile_utils::testing::*;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+fn data(value: &[u8]) -> Data {
+    Data {
+        has_data: true,
+        value: value.to_vec().into(),
+    }
+}
+
+fn sample_info() -> IdentityInfo<MaxAdditionalFields> {
+    IdentityInfo {
+        additional: vec![Additional {
+            key: data(b"discord"),
+            value: data(b"viktor#0001"),
+        }]
+        .into(),
+        display: data(b"Viktor"),
+        email: data(b"viktor@astar.network"),
+        has_pgp_fingerprint: true,
+        pgp_fingerprint: [1u8; 20].to_vec().into(),
+        ..Default::default()
+    }
+}
+
+/// Set the sample identity on behalf of the `Viktor` account.
+fn set_sample_identity() {
+    precompiles()
+        .prepare_test(
+            TestAccount::Viktor,
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::set_identity {
+                info: sample_info(),
+            },
+        )
+        .expect_no_logs()
+        .execute_returns(true);
+}
+
+fn identity_of(test_account: TestAccount) -> Registration<MaxAdditionalFields> {
+    let registration = Identity::identity(account(test_account))
+        .map(|(registration, _)| registration)
+        .expect("Identity is set");
+
+    Registration {
+        is_valid: true,
+        judgements: Vec::new(),
+        deposit: registration.deposit.into(),
+        info: sample_info(),
+    }
+}
+
+/// Register `Alice` as a registrar, with the specified fee.
+fn add_registrar(fee: Balance) {
+    assert_ok!(Identity::add_registrar(
+        RuntimeOrigin::root(),
+        account(TestAccount::Alice)
+    ));
+    assert_ok!(Identity::set_fee(
+        RuntimeOrigin::signed(account(TestAccount::Alice)),
+        0,
+        fee
+    ));
+}
+
+#[test]
+fn set_identity_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::identity_of {
+                    who: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Registration::<MaxAdditionalFields>::default());
+
+        set_sample_identity();
+
+        // Identity is set for the mapped account, deposit is reserved from it
+        let expected = identity_of(TestAccount::Viktor);
+        assert!(expected.deposit > BASIC_DEPOSIT.into());
+        assert_eq!(
+            U256::from(Balances::reserved_balance(&account(TestAccount::Viktor))),
+            expected.deposit
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::identity_of {
+                    who: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(expected);
+    });
+}
+
+#[test]
+fn set_identity_fails_for_invalid_fingerprint() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::set_identity {
+                    info: IdentityInfo {
+                        has_pgp_fingerprint: true,
+                        pgp_fingerprint: [1u8; 19].to_vec().into(),
+                        ..sample_info()
+                    },
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"pgp_fingerprint: invalid fingerprint length");
+    });
+}
+
+#[test]
+fn clear_identity_works() {
+    ExtBuilder.build().execute_with(|| {
+        set_sample_identity();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::clear_identity {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        assert!(Identity::identity(account(TestAccount::Viktor)).is_none());
+        assert_eq!(Balances::reserved_balance(&account(TestAccount::Viktor)), 0);
+    });
+}
+
+#[test]
+fn judgement_works() {
+    ExtBuilder.build().execute_with(|| {
+        add_registrar(5);
+        set_sample_identity();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::request_judgement {
+                    registrar_index: 0,
+                    max_fee: 10.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // Pending request is reported as a fee paid judgement
+        let mut expected = identity_of(TestAccount::Viktor);
+        expected.judgements = vec![JudgementInfo {
+            registrar_index: 0,
+            judgement: 1,
+            fee_paid: 5.into(),
+        }];
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::identity_of {
+                    who: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(expected.clone());
+
+        let (registration, _) =
+            Identity::identity(account(TestAccount::Viktor)).expect("Identity is set");
+        assert_ok!(Identity::provide_judgement(
+            RuntimeOrigin::signed(account(TestAccount::Alice)),
+            0,
+            account(TestAccount::Viktor),
+            pallet_identity::Judgement::KnownGood,
+            BlakeTwo256::hash_of(&registration.info),
+        ));
+
+        expected.judgements = vec![JudgementInfo {
+            registrar_index: 0,
+            judgement: 3,
+            fee_paid: U256::zero(),
+        }];
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::identity_of {
+                    who: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(expected);
+    });
+}
+
+#[test]
+fn cancel_request_works() {
+    ExtBuilder.build().execute_with(|| {
+        add_registrar(5);
+        set_sample_identity();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::request_judgement {
+                    registrar_index: 0,
+                    max_fee: 4.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"FeeChanged\") })"));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::request_judgement {
+                    registrar_index: 0,
+                    max_fee: 5.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_request { registrar_index: 0 },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let (registration, _) =
+            Identity::identity(account(TestAccount::Viktor)).expect("Identity is set");
+        assert!(registration.judgements.is_empty());
+    });
+}
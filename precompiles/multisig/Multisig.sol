@@ -0,0 +1,71 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Multisig interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500D
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/multisig/src/lib.rs
+///
+/// All actions are performed on behalf of the native account mapped to the caller.
+/// Signatories are mapped into the native accounts too, their order doesn't matter.
+/// Only calls allowed by the runtime filter can be dispatched by the multisig.
+interface Multisig {
+    /// Multisig operation timepoint, the block number and the extrinsic index of its first approval.
+    /// Zero height stands for no timepoint, as used when submitting the first approval.
+    struct Timepoint {
+        uint32 height;
+        uint32 index;
+    }
+
+    /// Native account Id of the multisig.
+    ///
+    /// @param signatories All the signatories of the multisig.
+    /// @param threshold The number of approvals required to dispatch a call.
+    /// @return The multisig native account Id.
+    function multiAccountId(address[] calldata signatories, uint16 threshold) external view returns (bytes32);
+
+    /// Approve the SCALE encoded runtime call to be dispatched by the multisig account.
+    /// If the approval is the final one, the call is dispatched right away.
+    ///
+    /// @param threshold The number of approvals required to dispatch the call.
+    /// @param otherSignatories The signatories of the multisig, except for the caller.
+    /// @param maybeTimepoint The timepoint of the operation, zero if this is the first approval.
+    /// @param call The SCALE encoded runtime call.
+    function asMulti(
+        uint16 threshold,
+        address[] calldata otherSignatories,
+        Timepoint calldata maybeTimepoint,
+        bytes calldata call
+    ) external returns (bool);
+
+    /// Approve the runtime call with the specified hash to be dispatched by the multisig account.
+    /// The call is never dispatched this way, the final approval must be submitted via `asMulti`.
+    ///
+    /// @param threshold The number of approvals required to dispatch the call.
+    /// @param otherSignatories The signatories of the multisig, except for the caller.
+    /// @param maybeTimepoint The timepoint of the operation, zero if this is the first approval.
+    /// @param callHash The blake2_256 hash of the SCALE encoded runtime call.
+    function approveAsMulti(
+        uint16 threshold,
+        address[] calldata otherSignatories,
+        Timepoint calldata maybeTimepoint,
+        bytes32 callHash
+    ) external returns (bool);
+
+    /// Cancel the multisig operation. Only the account which submitted the first approval can cancel it.
+    ///
+    /// @param threshold The number of approvals required to dispatch the call.
+    /// @param otherSignatories The signatories of the multisig, except for the caller.
+    /// @param timepoint The timepoint of the operation.
+    /// @param callHash The blake2_256 hash of the SCALE encoded runtime call.
+    function cancelAsMulti(
+        uint16 threshold,
+        address[] calldata otherSignatories,
+        Timepoint calldata timepoint,
+        bytes32 callHash
+    ) external returns (bool);
+}
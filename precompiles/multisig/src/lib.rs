@@ -0,0 +1,244 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Astar multisig interface.
+//!
+//! Allows EVM accounts, e.g. the unified accounts, to take part in native multisigs based on `pallet-multisig`.
+//! This way teams can operate the multisig accounts using Solidity tooling, without relying on the native wallets.
+//!
+//! Signatories are specified as H160 addresses, which are mapped into the native accounts.
+//! The order of the signatories doesn't matter, they are sorted by the precompile.
+//!
+//! Multisig operation is identified by the `timepoint` of its first approval, which is a block number
+//! and an extrinsic index pair. When submitting the first approval, zero `timepoint` must be used.
+//!
+//! Only calls allowed by the `CallFilter` can be dispatched by the multisig account via this precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{Contains, Get},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeLimit;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const LOG_TARGET: &str = "precompile::multisig";
+
+/// Max depth a call can use when decoding, same as in XCM call decoding.
+const CALL_DECODE_LIMIT: u32 = 8;
+
+/// Max number of signatories allowed by `pallet-multisig`.
+pub struct GetMaxSignatories<Runtime>(PhantomData<Runtime>);
+impl<Runtime: pallet_multisig::Config> Get<u32> for GetMaxSignatories<Runtime> {
+    fn get() -> u32 {
+        <Runtime as pallet_multisig::Config>::MaxSignatories::get()
+    }
+}
+
+/// Helper struct used to encode the multisig operation timepoint.
+///
+/// Zero `height` stands for no timepoint, as used when submitting the first approval.
+#[derive(Debug, Clone, Default, solidity::Codec)]
+pub struct Timepoint {
+    height: u32,
+    index: u32,
+}
+
+/// A precompile that allows EVM accounts to operate native multisigs.
+pub struct MultisigPrecompile<Runtime, CallFilter>(PhantomData<(Runtime, CallFilter)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, CallFilter> MultisigPrecompile<Runtime, CallFilter>
+where
+    Runtime: pallet_evm::Config + pallet_multisig::Config,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    <Runtime as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_multisig::Call<Runtime>>,
+    Runtime::AccountId: Into<[u8; 32]>,
+    BlockNumberFor<Runtime>: From<u32>,
+    CallFilter: Contains<<Runtime as pallet_multisig::Config>::RuntimeCall>,
+{
+    /// Native account Id of the multisig composed of the `signatories`, with the specified `threshold`.
+    #[precompile::public("multiAccountId(address[],uint16)")]
+    #[precompile::view]
+    fn multi_account_id(
+        _handle: &mut impl PrecompileHandle,
+        signatories: BoundedVec<Address, GetMaxSignatories<Runtime>>,
+        threshold: u16,
+    ) -> EvmResult<H256> {
+        let signatories = Self::sorted_accounts(signatories);
+        let multi_account_id =
+            pallet_multisig::Pallet::<Runtime>::multi_account_id(&signatories, threshold);
+
+        Ok(H256::from(multi_account_id.into()))
+    }
+
+    /// Approve the SCALE encoded runtime `call` to be dispatched by the multisig account.
+    ///
+    /// If the approval is the final one, the call is dispatched right away.
+    #[precompile::public("asMulti(uint16,address[],(uint32,uint32),bytes)")]
+    fn as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, GetMaxSignatories<Runtime>>,
+        maybe_timepoint: Timepoint,
+        call: UnboundedBytes,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: threshold: {:?}, other_signatories: {:?}, maybe_timepoint: {:?}, call: {:?}",
+            threshold,
+            other_signatories,
+            maybe_timepoint,
+            call
+        );
+
+        let input: Vec<u8> = call.into();
+        let runtime_call =
+            <Runtime as pallet_multisig::Config>::RuntimeCall::decode_with_depth_limit(
+                CALL_DECODE_LIMIT,
+                &mut &*input,
+            )
+            .map_err(|_| revert("could not decode call"))?;
+        if !CallFilter::contains(&runtime_call) {
+            return Err(revert("call not allowed"));
+        }
+
+        // Weight of the call is always known upfront, so there's no need for the caller to specify it
+        let max_weight = runtime_call.get_dispatch_info().weight;
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let as_multi_call = pallet_multisig::Call::<Runtime>::as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            maybe_timepoint: Self::into_timepoint(maybe_timepoint),
+            call: runtime_call.into(),
+            max_weight,
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), as_multi_call)?;
+
+        Ok(true)
+    }
+
+    /// Approve the runtime call with the specified `callHash` to be dispatched by the multisig account.
+    ///
+    /// The call is never dispatched this way, the final approval must be submitted via `asMulti`.
+    #[precompile::public("approveAsMulti(uint16,address[],(uint32,uint32),bytes32)")]
+    fn approve_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, GetMaxSignatories<Runtime>>,
+        maybe_timepoint: Timepoint,
+        call_hash: H256,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: threshold: {:?}, other_signatories: {:?}, maybe_timepoint: {:?}, call_hash: {:?}",
+            threshold,
+            other_signatories,
+            maybe_timepoint,
+            call_hash
+        );
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let approve_as_multi_call = pallet_multisig::Call::<Runtime>::approve_as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            maybe_timepoint: Self::into_timepoint(maybe_timepoint),
+            call_hash: call_hash.into(),
+            // Call is never dispatched by this extrinsic, so its weight doesn't matter
+            max_weight: Weight::zero(),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), approve_as_multi_call)?;
+
+        Ok(true)
+    }
+
+    /// Cancel the multisig operation with the specified `callHash`, started at the `timepoint`.
+    ///
+    /// Only the account which submitted the first approval can cancel the operation.
+    #[precompile::public("cancelAsMulti(uint16,address[],(uint32,uint32),bytes32)")]
+    fn cancel_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: BoundedVec<Address, GetMaxSignatories<Runtime>>,
+        timepoint: Timepoint,
+        call_hash: H256,
+    ) -> EvmResult<bool> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: threshold: {:?}, other_signatories: {:?}, timepoint: {:?}, call_hash: {:?}",
+            threshold,
+            other_signatories,
+            timepoint,
+            call_hash
+        );
+
+        let timepoint = Self::into_timepoint(timepoint).ok_or(revert("invalid timepoint"))?;
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let cancel_as_multi_call = pallet_multisig::Call::<Runtime>::cancel_as_multi {
+            threshold,
+            other_signatories: Self::sorted_accounts(other_signatories),
+            timepoint,
+            call_hash: call_hash.into(),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), cancel_as_multi_call)?;
+
+        Ok(true)
+    }
+
+    /// Map the addresses into the native accounts, sorted as required by `pallet-multisig`.
+    fn sorted_accounts(
+        addresses: BoundedVec<Address, GetMaxSignatories<Runtime>>,
+    ) -> Vec<Runtime::AccountId> {
+        let addresses: Vec<Address> = addresses.into();
+        let mut accounts: Vec<Runtime::AccountId> = addresses
+            .into_iter()
+            .map(|address| Runtime::AddressMapping::into_account_id(address.into()))
+            .collect();
+        accounts.sort();
+        accounts
+    }
+
+    fn into_timepoint(
+        timepoint: Timepoint,
+    ) -> Option<pallet_multisig::Timepoint<BlockNumberFor<Runtime>>> {
+        (timepoint.height != 0).then(|| pallet_multisig::Timepoint {
+            height: timepoint.height.into(),
+            index: timepoint.index,
+        })
+    }
+}
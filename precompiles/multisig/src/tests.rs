@@ -0,0 +1,284 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+
+use frame_support::assert_ok;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+use sp_io::hashing::blake2_256;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+fn addresses(test_accounts: &[TestAccount]) -> BoundedVec<Address, GetMaxSignatories<TestRuntime>> {
+    test_accounts
+        .iter()
+        .map(|test_account| Address(test_account.clone().into()))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Multisig account composed of `Viktor`, `Alice` & `Bob`, with the specified `threshold`.
+fn multisig_account_with(threshold: u16) -> AccountId {
+    let mut signatories = vec![
+        account(TestAccount::Viktor),
+        account(TestAccount::Alice),
+        account(TestAccount::Bob),
+    ];
+    signatories.sort();
+    pallet_multisig::Pallet::<TestRuntime>::multi_account_id(&signatories, threshold)
+}
+
+/// 2-of-3 multisig account composed of `Viktor`, `Alice` & `Bob`.
+fn multisig_account() -> AccountId {
+    multisig_account_with(2)
+}
+
+/// Call allowed to be dispatched by the multisig, transferring `100` to `Bob`.
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: account(TestAccount::Bob),
+        value: 100,
+    })
+}
+
+/// Timepoint of the multisig operation started in the current block.
+fn current_timepoint() -> Timepoint {
+    let timepoint = pallet_multisig::Pallet::<TestRuntime>::timepoint();
+    Timepoint {
+        height: timepoint.height,
+        index: timepoint.index,
+    }
+}
+
+#[test]
+fn multi_account_id_works() {
+    ExtBuilder.build().execute_with(|| {
+        // Order of the signatories doesn't matter
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::multi_account_id {
+                    signatories: addresses(&[
+                        TestAccount::Bob,
+                        TestAccount::Viktor,
+                        TestAccount::Alice,
+                    ]),
+                    threshold: 2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(H256::from(<[u8; 32]>::from(multisig_account())));
+
+        // Threshold is a part of the multisig identity
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::multi_account_id {
+                    signatories: addresses(&[
+                        TestAccount::Viktor,
+                        TestAccount::Alice,
+                        TestAccount::Bob,
+                    ]),
+                    threshold: 3,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(H256::from(<[u8; 32]>::from(multisig_account_with(3))));
+    });
+}
+
+#[test]
+fn as_multi_works() {
+    ExtBuilder.build().execute_with(|| {
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(account(TestAccount::Viktor)),
+            multisig_account(),
+            500
+        ));
+        let timepoint = current_timepoint();
+
+        // First approval only opens the multisig operation, placing the deposit
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Bob, TestAccount::Alice]),
+                    maybe_timepoint: Timepoint::default(),
+                    call: transfer_call().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            Balances::reserved_balance(&account(TestAccount::Viktor)),
+            DEPOSIT_BASE + 2 * DEPOSIT_FACTOR
+        );
+        assert_eq!(Balances::free_balance(&multisig_account()), 500);
+
+        // Final approval dispatches the call on behalf of the multisig account
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Viktor, TestAccount::Bob]),
+                    maybe_timepoint: timepoint,
+                    call: transfer_call().encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(Balances::free_balance(&multisig_account()), 400);
+        assert_eq!(Balances::free_balance(&account(TestAccount::Bob)), 1_100);
+        assert_eq!(Balances::reserved_balance(&account(TestAccount::Viktor)), 0);
+    });
+}
+
+#[test]
+fn approve_as_multi_and_cancel_as_multi_works() {
+    ExtBuilder.build().execute_with(|| {
+        let call_hash = H256::from(blake2_256(&transfer_call().encode()));
+        let timepoint = current_timepoint();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::approve_as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Alice, TestAccount::Bob]),
+                    maybe_timepoint: Timepoint::default(),
+                    call_hash,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert!(pallet_multisig::Multisigs::<TestRuntime>::contains_key(
+            multisig_account(),
+            <[u8; 32]>::from(call_hash)
+        ));
+        assert_eq!(
+            Balances::reserved_balance(&account(TestAccount::Viktor)),
+            DEPOSIT_BASE + 2 * DEPOSIT_FACTOR
+        );
+
+        // Only the depositor can cancel the operation
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Viktor, TestAccount::Bob]),
+                    timepoint: timepoint.clone(),
+                    call_hash,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"NotOwner\") })"));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Alice, TestAccount::Bob]),
+                    timepoint,
+                    call_hash,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert!(!pallet_multisig::Multisigs::<TestRuntime>::contains_key(
+            multisig_account(),
+            <[u8; 32]>::from(call_hash)
+        ));
+        assert_eq!(Balances::reserved_balance(&account(TestAccount::Viktor)), 0);
+    });
+}
+
+#[test]
+fn cancel_as_multi_without_timepoint_fails() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Alice, TestAccount::Bob]),
+                    timepoint: Timepoint::default(),
+                    call_hash: H256::repeat_byte(0x01),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"invalid timepoint");
+    });
+}
+
+#[test]
+fn as_multi_with_invalid_call_fails() {
+    ExtBuilder.build().execute_with(|| {
+        // Not decodable
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Alice, TestAccount::Bob]),
+                    maybe_timepoint: Timepoint::default(),
+                    call: vec![0xff, 0xff].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"could not decode call");
+
+        // Not allowed by the filter
+        let disallowed_call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: account(TestAccount::Bob),
+            value: 100,
+        });
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::as_multi {
+                    threshold: 2,
+                    other_signatories: addresses(&[TestAccount::Alice, TestAccount::Bob]),
+                    maybe_timepoint: Timepoint::default(),
+                    call: disallowed_call.encode().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"call not allowed");
+    });
+}
@@ -0,0 +1,60 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Price aggregator interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005009
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: pallets/price-aggregator/src/lib.rs
+///
+/// All prices are quoted in USD. Pairs are identified by the symbol of the currency,
+/// optionally followed by the `/USD` suffix, e.g. `bytes32("DOT")` or `bytes32("DOT/USD")`.
+///
+/// Price data is returned in the layout of Chainlink's `AggregatorV3Interface.latestRoundData`.
+/// Round is the block in which the price has been processed, and `updatedAt` is the (estimated) timestamp of that block.
+/// Consumers are expected to check `updatedAt` against their own staleness tolerance.
+interface PriceAggregator {
+    /// Number of decimals of the returned prices.
+    /// @return number of decimals
+    function decimals() external view returns (uint8);
+
+    /// Latest (spot) price of the pair.
+    /// Reverts if the pair isn't supported, or no price is available.
+    /// @param pair: The pair, e.g. `bytes32("DOT/USD")`.
+    /// @return (roundId, answer, startedAt, updatedAt, answeredInRound)
+    function getPrice(
+        bytes32 pair
+    )
+        external
+        view
+        returns (
+            uint80 roundId,
+            int256 answer,
+            uint256 startedAt,
+            uint256 updatedAt,
+            uint80 answeredInRound
+        );
+
+    /// Time-weighted average price of the pair, over the last `window` blocks.
+    /// Window is capped by the TWAP window configured in the runtime.
+    /// Reverts if the pair isn't supported, or no price is available.
+    /// @param pair: The pair, e.g. `bytes32("DOT/USD")`.
+    /// @param window: Number of most recent blocks to average the price over.
+    /// @return (roundId, answer, startedAt, updatedAt, answeredInRound)
+    function getTwap(
+        bytes32 pair,
+        uint32 window
+    )
+        external
+        view
+        returns (
+            uint80 roundId,
+            int256 answer,
+            uint256 startedAt,
+            uint256 updatedAt,
+            uint80 answeredInRound
+        );
+}
@@ -0,0 +1,159 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar price aggregator interface.
+//!
+//! Exposes the prices aggregated by the native oracle to EVM smart contracts.
+//! Price data is returned in the layout of Chainlink's `AggregatorV3Interface::latestRoundData`,
+//! so existing Solidity integrations can consume it with minimal changes.
+//!
+//! All prices are quoted in USD. Pairs are identified by the `bytes32` encoded symbol of the currency,
+//! optionally followed by the `/USD` suffix, e.g. both `bytes32("DOT")` and `bytes32("DOT/USD")` identify the `DOT/USD` pair.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::{Get, Time};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber};
+use sp_std::marker::PhantomData;
+
+use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use pallet_price_aggregator::{CircularBuffer, LastProcessedBlock, Pallet as PriceAggregator};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Number of decimals of the returned prices.
+pub const PRICE_DECIMALS: u8 = 18;
+
+/// Storage item: LastProcessedBlock:
+/// Twox64Concat(8 + CurrencyId(1)) + BlockNumber(4)
+const LAST_PROCESSED_BLOCK_READ_SIZE: usize = 8 + 1 + 4;
+/// Storage item: Timestamp::Now:
+/// Moment(8)
+const TIMESTAMP_READ_SIZE: usize = 8;
+
+/// Price data in the `AggregatorV3Interface::latestRoundData` layout:
+/// `(roundId, answer, startedAt, updatedAt, answeredInRound)`.
+///
+/// Round is the block in which the price has been processed, and timestamps are expressed in seconds.
+type RoundData = (U256, U256, U256, U256, U256);
+
+/// A precompile that exposes the prices aggregated by the native oracle.
+///
+/// `BlockTime` is the expected block time in milliseconds, used to derive the timestamp of the price update.
+pub struct PriceAggregatorPrecompile<R, BlockTime>(PhantomData<(R, BlockTime)>);
+
+#[precompile_utils::precompile]
+impl<R, BlockTime> PriceAggregatorPrecompile<R, BlockTime>
+where
+    R: pallet_evm::Config + pallet_price_aggregator::Config,
+    BlockTime: Get<u64>,
+{
+    /// Number of decimals of the returned prices.
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(_: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        Ok(PRICE_DECIMALS)
+    }
+
+    /// Latest (spot) price of the pair.
+    #[precompile::public("getPrice(bytes32)")]
+    #[precompile::view]
+    fn get_price(handle: &mut impl PrecompileHandle, pair: H256) -> EvmResult<RoundData> {
+        let currency = Self::currency_id(pair)?;
+
+        handle.record_db_read::<R>(CircularBuffer::<R::TwapWindow>::max_encoded_len())?;
+        let price = PriceAggregator::<R>::spot_price(currency);
+
+        Self::round_data(handle, currency, price)
+    }
+
+    /// Time-weighted average price of the pair, over the last `window` blocks.
+    #[precompile::public("getTwap(bytes32,uint32)")]
+    #[precompile::view]
+    fn get_twap(
+        handle: &mut impl PrecompileHandle,
+        pair: H256,
+        window: u32,
+    ) -> EvmResult<RoundData> {
+        let currency = Self::currency_id(pair)?;
+
+        handle.record_db_read::<R>(CircularBuffer::<R::TwapWindow>::max_encoded_len())?;
+        let price = PriceAggregator::<R>::twap_price_over(currency, window);
+
+        Self::round_data(handle, currency, price)
+    }
+}
+
+impl<R, BlockTime> PriceAggregatorPrecompile<R, BlockTime>
+where
+    R: pallet_evm::Config + pallet_price_aggregator::Config,
+    BlockTime: Get<u64>,
+{
+    /// Resolve the currency identified by the `bytes32` encoded pair.
+    fn currency_id(pair: H256) -> EvmResult<CurrencyId> {
+        let pair = pair.as_bytes();
+        let len = pair
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |i| i + 1);
+        let symbol = pair[..len].strip_suffix(b"/USD").unwrap_or(&pair[..len]);
+
+        match symbol {
+            b"ASTR" => Ok(CurrencyId::ASTR),
+            b"SDN" => Ok(CurrencyId::SDN),
+            b"DOT" => Ok(CurrencyId::DOT),
+            b"ETH" => Ok(CurrencyId::ETH),
+            _ => Err(revert("Unsupported pair")),
+        }
+    }
+
+    /// Combine the `price` of the `currency` with the round info.
+    fn round_data(
+        handle: &mut impl PrecompileHandle,
+        currency: CurrencyId,
+        price: Option<CurrencyAmount>,
+    ) -> EvmResult<RoundData> {
+        handle.record_db_read::<R>(LAST_PROCESSED_BLOCK_READ_SIZE)?;
+        handle.record_db_read::<R>(TIMESTAMP_READ_SIZE)?;
+
+        let (Some(price), Some(round)) = (price, LastProcessedBlock::<R>::get(currency)) else {
+            return Err(revert("No price available"));
+        };
+
+        // Price update timestamp is derived from the number of blocks elapsed since the update.
+        let staleness = PriceAggregator::<R>::staleness(currency).unwrap_or_default();
+        let now: u64 = <R as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+        let updated_at =
+            now.saturating_sub(BlockTime::get().saturating_mul(staleness.into())) / 1000;
+
+        Ok((
+            round.into(),
+            price.into_inner().into(),
+            updated_at.into(),
+            updated_at.into(),
+            round.into(),
+        ))
+    }
+}
@@ -0,0 +1,291 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use fp_evm::{IsPrecompileResult, Precompile};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, SortedMembers},
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+pub use pallet_evm::{
+    AddressMapping, EnsureAddressNever, EnsureAddressRoot, PrecompileResult, PrecompileSet,
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32, BuildStorage, Perbill,
+};
+
+use astar_primitives::{oracle::StalePriceBehavior, BlockNumber};
+use pallet_price_aggregator::{AverageBlockValue, IntermediateValueAggregator};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type Block = frame_system::mocking::MockBlockU32<TestRuntime>;
+
+pub const PRECOMPILE_ADDRESS: H160 = H160::repeat_byte(0x7B);
+
+/// Expected block time, in milliseconds.
+pub const BLOCK_TIME: u64 = 6_000;
+
+#[derive(
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Clone,
+    Encode,
+    Decode,
+    Debug,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+    derive_more::Display,
+    TypeInfo,
+)]
+pub enum TestAccount {
+    Viktor,
+    Precompile,
+}
+impl Default for TestAccount {
+    fn default() -> Self {
+        Self::Viktor
+    }
+}
+impl From<TestAccount> for H160 {
+    fn from(value: TestAccount) -> H160 {
+        match value {
+            TestAccount::Viktor => H160::repeat_byte(0xAA),
+            TestAccount::Precompile => PRECOMPILE_ADDRESS,
+        }
+    }
+}
+
+/// Maps the H160 address into the native account by zero-padding it.
+pub struct AddressMapper;
+impl AddressMapping<AccountId> for AddressMapper {
+    fn into_account_id(account: H160) -> AccountId {
+        let mut account_id = [0u8; 32];
+        account_id[0..20].clone_from_slice(&account.as_bytes());
+        account_id.into()
+    }
+}
+
+impl frame_system::Config for TestRuntime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU32<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TestPrecompileSet<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for TestPrecompileSet<R>
+where
+    R: pallet_evm::Config,
+    PriceAggregatorPrecompile<R, ConstU64<BLOCK_TIME>>: Precompile,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            a if a == PRECOMPILE_ADDRESS => {
+                Some(PriceAggregatorPrecompile::<R, ConstU64<BLOCK_TIME>>::execute(handle))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+        IsPrecompileResult::Answer {
+            is_precompile: address == PRECOMPILE_ADDRESS,
+            extra_cost: 0,
+        }
+    }
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+}
+
+impl pallet_timestamp::Config for TestRuntime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const PrecompilesValue: TestPrecompileSet<TestRuntime> =
+        TestPrecompileSet(PhantomData);
+    pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub ChainId: u64 = 1024;
+}
+
+pub type PrecompileCall = PriceAggregatorPrecompileCall<TestRuntime, ConstU64<BLOCK_TIME>>;
+
+impl pallet_evm::Config for TestRuntime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AddressMapper;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = TestPrecompileSet<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type Timestamp = Timestamp;
+    type ChainId = ChainId;
+    type OnChargeTransaction = ();
+    type BlockGasLimit = ();
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type WeightInfo = ();
+    type GasLimitPovSizeRatio = ConstU64<4>;
+    type SuicideQuickClearLimit = ConstU32<0>;
+}
+
+parameter_types! {
+    pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    pub OutlierThreshold: Perbill = Perbill::from_percent(100);
+    pub OnStalePrice: StalePriceBehavior = StalePriceBehavior::Error;
+    pub TrackedCurrencies: BoundedVec<CurrencyId, ConstU32<2>> =
+        BoundedVec::truncate_from(vec![CurrencyId::ASTR, CurrencyId::DOT]);
+}
+
+pub struct NoFeeders;
+impl SortedMembers<AccountId> for NoFeeders {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![]
+    }
+}
+
+impl pallet_price_aggregator::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxValuesPerBlock = ConstU32<4>;
+    type ProcessBlockValues = AverageBlockValue;
+    type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<2>;
+    type TrackedCurrencies = TrackedCurrencies;
+    type CircularBufferLength = ConstU32<7>;
+    type AggregationDuration = ConstU32<16>;
+    type TwapWindow = ConstU32<5>;
+    type PriceHistoryLength = ConstU32<4>;
+    type OutlierThreshold = OutlierThreshold;
+    type StalenessThreshold = ConstU32<100>;
+    type OnStalePrice = OnStalePrice;
+    type MaxOutlierStrikes = ConstU32<3>;
+    type OnStruckOut = ();
+    type Feeders = NoFeeders;
+    type ReinstateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+construct_runtime!(
+    pub enum TestRuntime
+    {
+        System: frame_system,
+        Evm: pallet_evm,
+        Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        PriceAggregator: pallet_price_aggregator,
+    }
+);
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<TestRuntime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| {
+            for currency in TrackedCurrencies::get() {
+                IntermediateValueAggregator::<TestRuntime>::mutate(currency, |v| {
+                    v.limit_block = 17;
+                });
+            }
+            run_to_block(1);
+        });
+        ext
+    }
+}
+
+/// Move to the specified block, finalizing the current one, and setting the timestamp according to the `BLOCK_TIME`.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    use frame_support::traits::Hooks;
+
+    while System::block_number() < n {
+        let current = System::block_number();
+        if current > 0 {
+            PriceAggregator::on_finalize(current);
+        }
+        System::set_block_number(current + 1);
+        Timestamp::set_timestamp((current as u64 + 1) * BLOCK_TIME);
+        PriceAggregator::on_initialize(current + 1);
+    }
+}
@@ -0,0 +1,161 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use orml_traits::OnNewData;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+/// `bytes32` encoded pair, as it would be passed from Solidity.
+fn pair(symbol: &str) -> H256 {
+    let mut bytes = [0u8; 32];
+    bytes[..symbol.len()].copy_from_slice(symbol.as_bytes());
+    H256(bytes)
+}
+
+fn price(value: u128) -> CurrencyAmount {
+    CurrencyAmount::saturating_from_integer(value)
+}
+
+fn feed(currency: CurrencyId, value: u128) {
+    let feeder = AddressMapper::into_account_id(TestAccount::Viktor.into());
+    PriceAggregator::on_new_data(&feeder, &currency, &price(value));
+}
+
+#[test]
+fn decimals_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::decimals {},
+            )
+            .expect_no_logs()
+            .execute_returns(PRICE_DECIMALS);
+    });
+}
+
+#[test]
+fn get_price_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        feed(CurrencyId::ASTR, 2);
+        run_to_block(2);
+        feed(CurrencyId::ASTR, 4);
+        run_to_block(3);
+
+        // Price was processed in block 2, one block ago
+        let updated_at = U256::from(2 * BLOCK_TIME / 1000);
+        let expected = (
+            U256::from(2),
+            U256::from(price(4).into_inner()),
+            updated_at,
+            updated_at,
+            U256::from(2),
+        );
+
+        for symbol in ["ASTR", "ASTR/USD"] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::get_price { pair: pair(symbol) },
+                )
+                .expect_no_logs()
+                .execute_returns(expected);
+        }
+    });
+}
+
+#[test]
+fn get_twap_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        feed(CurrencyId::ASTR, 2);
+        run_to_block(2);
+        feed(CurrencyId::ASTR, 4);
+        run_to_block(3);
+
+        let updated_at = U256::from(2 * BLOCK_TIME / 1000);
+        for (window, expected_price) in [(1, price(4)), (2, price(3)), (5, price(3))] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::get_twap {
+                        pair: pair("ASTR"),
+                        window,
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns((
+                    U256::from(2),
+                    U256::from(expected_price.into_inner()),
+                    updated_at,
+                    updated_at,
+                    U256::from(2),
+                ));
+        }
+
+        // Zero window has no price
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::get_twap {
+                    pair: pair("ASTR"),
+                    window: 0,
+                },
+            )
+            .execute_reverts(|output| output == b"No price available");
+    });
+}
+
+#[test]
+fn unsupported_pair_or_missing_price_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        feed(CurrencyId::ASTR, 2);
+        run_to_block(2);
+
+        // Unknown currency, or pair not quoted in USD
+        for symbol in ["BTC", "ASTR/DOT", ""] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::get_price { pair: pair(symbol) },
+                )
+                .execute_reverts(|output| output == b"Unsupported pair");
+        }
+
+        // Tracked currency without any processed value, and currency which isn't tracked at all
+        for symbol in ["DOT", "SDN"] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::get_price { pair: pair(symbol) },
+                )
+                .execute_reverts(|output| output == b"No price available");
+        }
+    });
+}
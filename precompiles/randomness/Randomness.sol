@@ -0,0 +1,31 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title On-chain randomness interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005010
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/randomness/src/lib.rs
+///
+/// SECURITY: the randomness is derived from on-chain data and isn't a VRF output.
+/// Collators can influence it, by choosing whether to author a block, and know it in advance.
+/// An output is only unbiased for commitments made before the returned block number,
+/// so commit first, then consume an output whose block number is greater than the commitment block.
+/// Don't use it to secure significant value.
+interface Randomness {
+    /// Random output for the subject.
+    ///
+    /// @param subject Context of the request, use a value unique to your purpose.
+    /// @return randomness The random output.
+    /// @return blockNumber The block since which the output was determinable.
+    function random(bytes32 subject) external view returns (bytes32 randomness, uint256 blockNumber);
+
+    /// Random seed of the source, same as `random` with an empty subject.
+    ///
+    /// @return randomness The random seed.
+    /// @return blockNumber The block since which the seed was determinable.
+    function randomSeed() external view returns (bytes32 randomness, uint256 blockNumber);
+}
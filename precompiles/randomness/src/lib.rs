@@ -0,0 +1,92 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar on-chain randomness interface.
+//!
+//! Exposes the randomness source configured in the runtime to EVM smart contracts,
+//! as an alternative to external VRF providers.
+//!
+//! # Security
+//!
+//! The randomness is derived from on-chain data, e.g. the parent block hashes, and **isn't**
+//! a VRF output. The collators producing the blocks can influence it, by choosing whether to
+//! author a block, and can know it before the other participants. It must not be used to secure
+//! significant value.
+//!
+//! Alongside every output, the block number since which the output was determinable is returned.
+//! The output is only unbiased for commitments made *before* that block, so contracts should
+//! record the commitments first, and only consume an output whose block number is greater
+//! than the block of the last commitment.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Randomness;
+use frame_system::pallet_prelude::BlockNumberFor;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Storage item: RandomMaterial:
+/// Twox128(16) + Twox128(16) + Compact(2) + 81 * Hash(32)
+///
+/// The collective flip material is the largest randomness source used by the runtimes,
+/// reading it is charged for any source.
+pub const RANDOMNESS_READ_SIZE: usize = 16 + 16 + 2 + 81 * 32;
+
+/// A precompile exposing the runtime randomness source `R`.
+pub struct RandomnessPrecompile<Runtime, R>(PhantomData<(Runtime, R)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, R> RandomnessPrecompile<Runtime, R>
+where
+    Runtime: pallet_evm::Config + frame_system::Config,
+    R: Randomness<<Runtime as frame_system::Config>::Hash, BlockNumberFor<Runtime>>,
+    <Runtime as frame_system::Config>::Hash: Into<H256>,
+    BlockNumberFor<Runtime>: Into<U256>,
+{
+    /// Random output for the `subject`, and the block number since which it was determinable.
+    ///
+    /// Different subjects yield independent outputs from the same source, contracts should use
+    /// a subject unique to their purpose, e.g. a hash of their address and the round number.
+    #[precompile::public("random(bytes32)")]
+    #[precompile::view]
+    fn random(handle: &mut impl PrecompileHandle, subject: H256) -> EvmResult<(H256, U256)> {
+        handle.record_db_read::<Runtime>(RANDOMNESS_READ_SIZE)?;
+
+        let (output, block_number) = R::random(subject.as_bytes());
+        Ok((output.into(), block_number.into()))
+    }
+
+    /// Random seed of the source, and the block number since which it was determinable.
+    ///
+    /// Same as `random` with an empty subject.
+    #[precompile::public("randomSeed()")]
+    #[precompile::view]
+    fn random_seed(handle: &mut impl PrecompileHandle) -> EvmResult<(H256, U256)> {
+        handle.record_db_read::<Runtime>(RANDOMNESS_READ_SIZE)?;
+
+        let (output, block_number) = R::random_seed();
+        Ok((output.into(), block_number.into()))
+    }
+}
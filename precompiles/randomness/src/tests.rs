@@ -0,0 +1,62 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use precompile_utils::testing::*;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+#[test]
+fn random_works() {
+    ExtBuilder.build().execute_with(|| {
+        System::set_block_number(5);
+        let subject = H256::repeat_byte(0x01);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::random { subject },
+            )
+            .expect_no_logs()
+            .execute_returns((
+                BlakeTwo256::hash_of(&(subject.as_bytes(), 5u32)),
+                U256::from(4),
+            ));
+    });
+}
+
+#[test]
+fn random_seed_works() {
+    ExtBuilder.build().execute_with(|| {
+        System::set_block_number(3);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::random_seed {},
+            )
+            .expect_no_logs()
+            .execute_returns((BlakeTwo256::hash_of(&(&[] as &[u8], 3u32)), U256::from(2)));
+    });
+}
@@ -0,0 +1,35 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Precompile registry interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500F
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/registry/src/lib.rs
+///
+/// Allows feature-detection of the precompiles, since Astar, Shiden & Shibuya enable different precompile sets.
+interface PrecompileRegistry {
+    /// Whether the address is an active precompile.
+    ///
+    /// @param a The address to query.
+    /// @return True if the address is an active precompile.
+    function isActivePrecompile(address a) external view returns (bool);
+
+    /// Version of the precompile interface.
+    ///
+    /// @param a The precompile address.
+    /// @return The interface version, or zero if the address isn't an active precompile
+    ///         or doesn't expose an interface.
+    function interfaceVersion(address a) external view returns (uint32);
+
+    /// Whether the precompile supports the interface.
+    ///
+    /// @param a The precompile address.
+    /// @param interfaceId Either a single function selector, or the ERC-165 interface identifier
+    ///                    (XOR of all the function selectors).
+    /// @return True if the address is an active precompile which supports the interface.
+    function supportsInterface(address a, bytes4 interfaceId) external view returns (bool);
+}
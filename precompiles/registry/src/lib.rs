@@ -0,0 +1,167 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Astar precompile registry.
+//!
+//! Allows contracts and tooling to feature-detect the precompiles available on the network,
+//! since Astar, Shiden & Shibuya don't enable the same precompile sets.
+//!
+//! For every address, the registry reports whether it's an active precompile, the version of
+//! its Solidity interface, and whether it supports a given interface, in the spirit of ERC-165.
+//! Interfaces of the installed precompiles are provided by the runtime via [`PrecompileInterfaces`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{ExitError, IsPrecompileResult, PrecompileHandle};
+use frame_support::traits::Get;
+use pallet_evm::PrecompileSet;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity interface exposed by a precompile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecompileInterface {
+    /// Version of the interface, bumped whenever the interface changes.
+    pub version: u32,
+    /// Selectors of all the functions exposed by the precompile.
+    pub selectors: &'static [u32],
+}
+
+impl PrecompileInterface {
+    pub const fn new(version: u32, selectors: &'static [u32]) -> Self {
+        Self { version, selectors }
+    }
+
+    /// Whether the interface supports `interface_id`.
+    ///
+    /// The `interface_id` is either a single function selector, or the ERC-165 interface identifier,
+    /// i.e. XOR of all the function selectors.
+    pub fn supports(&self, interface_id: u32) -> bool {
+        // Invalid interface identifier, as defined by ERC-165
+        if interface_id == u32::MAX {
+            return false;
+        }
+
+        self.selectors.contains(&interface_id)
+            || self
+                .selectors
+                .iter()
+                .fold(0, |acc, selector| acc ^ selector)
+                == interface_id
+    }
+}
+
+/// Provides interfaces of the precompiles installed in the runtime.
+pub trait PrecompileInterfaces {
+    /// Interface of the precompile at the `address`, if it exposes one.
+    fn interface(address: H160) -> Option<PrecompileInterface>;
+}
+
+impl PrecompileInterfaces for () {
+    fn interface(_address: H160) -> Option<PrecompileInterface> {
+        None
+    }
+}
+
+/// A precompile that allows feature-detection of the precompiles installed in the runtime.
+pub struct PrecompileRegistry<Runtime, Interfaces>(PhantomData<(Runtime, Interfaces)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Interfaces> PrecompileRegistry<Runtime, Interfaces>
+where
+    Runtime: pallet_evm::Config,
+    Interfaces: PrecompileInterfaces,
+{
+    /// Whether the `address` is an active precompile.
+    #[precompile::public("isActivePrecompile(address)")]
+    #[precompile::view]
+    fn is_active_precompile(
+        handle: &mut impl PrecompileHandle,
+        address: Address,
+    ) -> EvmResult<bool> {
+        Self::is_precompile(handle, address.into())
+    }
+
+    /// Version of the `address` precompile interface.
+    ///
+    /// Returns zero if the `address` isn't an active precompile, or doesn't expose an interface.
+    #[precompile::public("interfaceVersion(address)")]
+    #[precompile::view]
+    fn interface_version(handle: &mut impl PrecompileHandle, address: Address) -> EvmResult<u32> {
+        Ok(Self::active_interface(handle, address.into())?
+            .map(|interface| interface.version)
+            .unwrap_or_default())
+    }
+
+    /// Whether the `address` precompile supports the `interface_id`.
+    ///
+    /// The `interface_id` is either a single function selector, or the ERC-165 interface identifier.
+    /// Being a `bytes4`, it's encoded left-aligned in the ABI word, hence decoded from its leading bytes.
+    #[precompile::public("supportsInterface(address,bytes4)")]
+    #[precompile::view]
+    fn supports_interface(
+        handle: &mut impl PrecompileHandle,
+        address: Address,
+        interface_id: H256,
+    ) -> EvmResult<bool> {
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&interface_id.as_bytes()[..4]);
+        let interface_id = u32::from_be_bytes(selector);
+
+        Ok(Self::active_interface(handle, address.into())?
+            .map(|interface| interface.supports(interface_id))
+            .unwrap_or_default())
+    }
+}
+
+impl<Runtime, Interfaces> PrecompileRegistry<Runtime, Interfaces>
+where
+    Runtime: pallet_evm::Config,
+    Interfaces: PrecompileInterfaces,
+{
+    /// Whether the `address` is an active precompile, charging the cost of the check.
+    fn is_precompile(handle: &mut impl PrecompileHandle, address: H160) -> EvmResult<bool> {
+        match Runtime::PrecompilesValue::get().is_precompile(address, handle.remaining_gas()) {
+            IsPrecompileResult::Answer {
+                is_precompile,
+                extra_cost,
+            } => {
+                handle.record_cost(extra_cost)?;
+                Ok(is_precompile)
+            }
+            IsPrecompileResult::OutOfGas => Err(ExitError::OutOfGas.into()),
+        }
+    }
+
+    /// Interface of the `address` precompile, if it's active and exposes one.
+    fn active_interface(
+        handle: &mut impl PrecompileHandle,
+        address: H160,
+    ) -> EvmResult<Option<PrecompileInterface>> {
+        if Self::is_precompile(handle, address)? {
+            Ok(Interfaces::interface(address))
+        } else {
+            Ok(None)
+        }
+    }
+}
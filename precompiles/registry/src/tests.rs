@@ -0,0 +1,136 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+/// ABI representation of the `bytes4` interface identifier.
+fn interface_id(selector: u32) -> H256 {
+    let mut id = H256::zero();
+    id.as_bytes_mut()[..4].copy_from_slice(&selector.to_be_bytes());
+    id
+}
+
+fn assert_supports_interface(address: H160, selector: u32, supported: bool) {
+    precompiles()
+        .prepare_test(
+            TestAccount::Viktor,
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::supports_interface {
+                address: Address(address),
+                interface_id: interface_id(selector),
+            },
+        )
+        .expect_no_logs()
+        .execute_returns(supported);
+}
+
+#[test]
+fn is_active_precompile_works() {
+    ExtBuilder.build().execute_with(|| {
+        for (address, is_active) in [
+            (PRECOMPILE_ADDRESS, true),
+            (SAMPLE_ADDRESS, true),
+            (PLAIN_ADDRESS, true),
+            (INACTIVE_ADDRESS, false),
+            (TestAccount::Viktor.into(), false),
+        ] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::is_active_precompile {
+                        address: Address(address),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(is_active);
+        }
+    });
+}
+
+#[test]
+fn interface_version_works() {
+    ExtBuilder.build().execute_with(|| {
+        for (address, version) in [
+            (PRECOMPILE_ADDRESS, 1u32),
+            (SAMPLE_ADDRESS, 2),
+            // No interface exposed
+            (PLAIN_ADDRESS, 0),
+            // Known interface, but precompile isn't active
+            (INACTIVE_ADDRESS, 0),
+            (TestAccount::Viktor.into(), 0),
+        ] {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Viktor,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::interface_version {
+                        address: Address(address),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(version);
+        }
+    });
+}
+
+#[test]
+fn supports_interface_works() {
+    ExtBuilder.build().execute_with(|| {
+        // Single function selectors
+        assert_supports_interface(SAMPLE_ADDRESS, 0x1111_1111, true);
+        assert_supports_interface(SAMPLE_ADDRESS, 0x2222_2222, true);
+        assert_supports_interface(SAMPLE_ADDRESS, 0x4444_4444, false);
+
+        // ERC-165 interface identifier, XOR of all the selectors
+        assert_supports_interface(SAMPLE_ADDRESS, 0x3333_3333, true);
+
+        // Registry reports its own interface
+        assert_supports_interface(
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::supports_interface_selectors()[0],
+            true,
+        );
+        let registry_interface_id = PrecompileCall::selectors()
+            .iter()
+            .fold(0, |acc, selector| acc ^ selector);
+        assert_supports_interface(PRECOMPILE_ADDRESS, registry_interface_id, true);
+    });
+}
+
+#[test]
+fn supports_interface_rejects_unknown_and_invalid() {
+    ExtBuilder.build().execute_with(|| {
+        // Invalid interface identifier, as defined by ERC-165
+        assert_supports_interface(SAMPLE_ADDRESS, u32::MAX, false);
+
+        // No interface exposed
+        assert_supports_interface(PLAIN_ADDRESS, 0x1111_1111, false);
+
+        // Known interface, but precompile isn't active
+        assert_supports_interface(INACTIVE_ADDRESS, 0x1111_1111, false);
+
+        // Not a precompile
+        assert_supports_interface(TestAccount::Viktor.into(), 0x1111_1111, false);
+    });
+}
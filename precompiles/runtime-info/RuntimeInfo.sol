@@ -0,0 +1,35 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Runtime info interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005011
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/runtime-info/src/lib.rs
+///
+/// Allows contracts to adapt their behavior across runtime upgrades, and across Astar, Shiden & Shibuya.
+interface RuntimeInfo {
+    /// Name of the runtime, e.g. "astar", "shiden" or "shibuya".
+    ///
+    /// @return The runtime spec name.
+    function specName() external view returns (string memory);
+
+    /// Version of the runtime, bumped on every runtime upgrade.
+    ///
+    /// @return The runtime spec version.
+    function specVersion() external view returns (uint32);
+
+    /// Version of the extrinsics interface.
+    ///
+    /// @return The runtime transaction version.
+    function transactionVersion() external view returns (uint32);
+
+    /// Addresses of the precompiles enabled in the runtime.
+    /// Prefixed precompile sets, e.g. the XC20 assets, aren't included.
+    ///
+    /// @return The list of precompile addresses.
+    function enabledPrecompiles() external view returns (address[] memory);
+}
@@ -0,0 +1,81 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar runtime info interface.
+//!
+//! Exposes the version of the runtime and the precompiles it enables, so contracts and SDKs
+//! can adapt their behavior across runtime upgrades, and across Astar, Shiden & Shibuya,
+//! without hardcoding it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use precompile_utils::prelude::*;
+use sp_core::H160;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile exposing the runtime version, and the `EnabledPrecompiles` addresses.
+pub struct RuntimeInfoPrecompile<Runtime, EnabledPrecompiles>(
+    PhantomData<(Runtime, EnabledPrecompiles)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, EnabledPrecompiles> RuntimeInfoPrecompile<Runtime, EnabledPrecompiles>
+where
+    Runtime: pallet_evm::Config + frame_system::Config,
+    EnabledPrecompiles: Get<Vec<H160>>,
+{
+    /// Name of the runtime, e.g. `astar`, `shiden` or `shibuya`.
+    #[precompile::public("specName()")]
+    #[precompile::view]
+    fn spec_name(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+        let version = <Runtime as frame_system::Config>::Version::get();
+        let spec_name: &[u8] = version.spec_name.as_ref();
+
+        Ok(spec_name.into())
+    }
+
+    /// Version of the runtime, bumped on every runtime upgrade.
+    #[precompile::public("specVersion()")]
+    #[precompile::view]
+    fn spec_version(_handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        Ok(<Runtime as frame_system::Config>::Version::get().spec_version)
+    }
+
+    /// Version of the extrinsics interface, bumped whenever the extrinsics encoding changes.
+    #[precompile::public("transactionVersion()")]
+    #[precompile::view]
+    fn transaction_version(_handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        Ok(<Runtime as frame_system::Config>::Version::get().transaction_version)
+    }
+
+    /// Addresses of the precompiles enabled in the runtime.
+    ///
+    /// Prefixed precompile sets, e.g. the XC20 assets, aren't included.
+    #[precompile::public("enabledPrecompiles()")]
+    #[precompile::view]
+    fn enabled_precompiles(_handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        Ok(EnabledPrecompiles::get().into_iter().map(Address).collect())
+    }
+}
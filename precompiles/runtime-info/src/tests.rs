@@ -0,0 +1,74 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H160;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+#[test]
+fn runtime_version_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::spec_name {},
+            )
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from("test-runtime"));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::spec_version {},
+            )
+            .expect_no_logs()
+            .execute_returns(42u32);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::transaction_version {},
+            )
+            .expect_no_logs()
+            .execute_returns(3u32);
+    });
+}
+
+#[test]
+fn enabled_precompiles_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::enabled_precompiles {},
+            )
+            .expect_no_logs()
+            .execute_returns(vec![
+                Address(H160::from_low_u64_be(1)),
+                Address(PRECOMPILE_ADDRESS),
+            ]);
+    });
+}
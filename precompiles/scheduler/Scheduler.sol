@@ -0,0 +1,31 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Scheduler interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500A
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/scheduler/src/lib.rs
+///
+/// Scheduled calls are dispatched on behalf of the scheduling contract, and only calls allowed
+/// by the runtime call filter can be scheduled.
+/// Since scheduled calls aren't charged any fee once dispatched, the weight of all executions is charged upfront.
+interface Scheduler {
+    /// Schedule the SCALE encoded runtime call to be dispatched at the specified block.
+    ///
+    /// @param call The SCALE encoded runtime call, must not exceed 128 bytes.
+    /// @param when The block in which the call should be dispatched, must be in the future.
+    /// @param maybePeriodic If not zero, the call is repeated every `maybePeriodic` blocks,
+    /// up to the maximum number of executions defined by the runtime.
+    /// @return id The Id of the scheduled call, which can be used to cancel it.
+    function scheduleCall(bytes calldata call, uint32 when, uint32 maybePeriodic) external returns (bytes32 id);
+
+    /// Cancel the call previously scheduled by the caller.
+    ///
+    /// @dev Weight charged upfront for the remaining executions isn't refunded.
+    /// @param id The Id of the scheduled call.
+    function cancelNamed(bytes32 id) external returns (bool);
+}
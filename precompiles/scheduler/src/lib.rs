@@ -0,0 +1,196 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar scheduler interface.
+//!
+//! Allows EVM smart contracts to schedule runtime calls, which are dispatched by `pallet-scheduler`
+//! on behalf of the scheduling contract, either once or periodically.
+//! This way protocols can set up on-chain keepers (e.g. epoch rollovers) without relying on off-chain bots.
+//!
+//! Only calls allowed by the `CallFilter` can be scheduled, and they must be small enough to be stored inline.
+//! Since scheduled calls aren't charged any fee once dispatched, their weight is charged upfront, for every execution.
+//! Periodic calls are executed at most `MaxPeriodicExecutions` times.
+//!
+//! Each scheduled call is identified by the `bytes32` Id returned when scheduling it.
+//! The Id is bound to the contract which scheduled the call, so no other contract can cancel it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    traits::{
+        schedule::{v3::Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY},
+        Bounded, ConstU32, Contains, Get,
+    },
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use pallet_scheduler::WeightInfo;
+use parity_scale_codec::{DecodeLimit, Encode};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_io::hashing::blake2_256;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const LOG_TARGET: &str = "precompile::scheduler";
+
+/// Max depth a call can use when decoding, same as in XCM call decoding.
+const CALL_DECODE_LIMIT: u32 = 8;
+
+/// Max length of the encoded call, which allows it to be stored inline, without the preimage.
+type CallLengthLimit = ConstU32<128>;
+
+/// A precompile that allows EVM smart contracts to schedule runtime calls.
+pub struct SchedulerPrecompile<Runtime, CallFilter, MaxPeriodicExecutions = ConstU32<100>>(
+    PhantomData<(Runtime, CallFilter, MaxPeriodicExecutions)>,
+);
+
+#[precompile_utils::precompile]
+impl<Runtime, CallFilter, MaxPeriodicExecutions>
+    SchedulerPrecompile<Runtime, CallFilter, MaxPeriodicExecutions>
+where
+    Runtime: pallet_evm::Config + pallet_scheduler::Config,
+    BlockNumberFor<Runtime>: From<u32>,
+    CallFilter: Contains<<Runtime as pallet_scheduler::Config>::RuntimeCall>,
+    MaxPeriodicExecutions: Get<u32>,
+{
+    /// Schedule the SCALE encoded runtime `call` to be dispatched on behalf of the caller at block `when`.
+    ///
+    /// If `maybe_periodic` isn't zero, the call is repeated every `maybe_periodic` blocks,
+    /// at most `MaxPeriodicExecutions` times in total.
+    ///
+    /// Returns the Id of the scheduled call, which can be used to cancel it.
+    #[precompile::public("scheduleCall(bytes,uint32,uint32)")]
+    fn schedule_call(
+        handle: &mut impl PrecompileHandle,
+        call: BoundedBytes<CallLengthLimit>,
+        when: u32,
+        maybe_periodic: u32,
+    ) -> EvmResult<H256> {
+        log::trace!(
+            target: LOG_TARGET,
+            "raw arguments: call: {:?}, when: {:?}, maybe_periodic: {:?}",
+            call,
+            when,
+            maybe_periodic
+        );
+
+        let caller: H160 = handle.context().caller;
+        let input: Vec<u8> = call.into();
+
+        let runtime_call =
+            <Runtime as pallet_scheduler::Config>::RuntimeCall::decode_with_depth_limit(
+                CALL_DECODE_LIMIT,
+                &mut &*input,
+            )
+            .map_err(|_| revert("could not decode call"))?;
+        if !CallFilter::contains(&runtime_call) {
+            return Err(revert("call not allowed"));
+        }
+
+        // Scheduled call is dispatched without any fee, so its weight is charged upfront, for every execution.
+        let (maybe_periodic, executions) = if maybe_periodic == 0 {
+            (None, 1)
+        } else {
+            let executions = MaxPeriodicExecutions::get();
+            (Some((maybe_periodic.into(), executions)), executions)
+        };
+        let weight = runtime_call
+            .get_dispatch_info()
+            .weight
+            .saturating_mul(executions.into())
+            .saturating_add(
+                <Runtime as pallet_scheduler::Config>::WeightInfo::schedule_named(
+                    <Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+                ),
+            );
+        handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(weight))?;
+
+        let id: H256 = blake2_256(
+            &(
+                caller,
+                frame_system::Pallet::<Runtime>::block_number(),
+                when,
+                maybe_periodic,
+                &input,
+            )
+                .encode(),
+        )
+        .into();
+
+        let origin =
+            frame_system::RawOrigin::Signed(Runtime::AddressMapping::into_account_id(caller));
+        let bounded_call = Bounded::Inline(
+            input
+                .try_into()
+                .map_err(|_| revert("call too large to be stored inline"))?,
+        );
+
+        // Trait function is used explicitly, since the dispatchable with the same name requires the privileged origin.
+        <pallet_scheduler::Pallet<Runtime> as ScheduleNamed<_, _, _>>::schedule_named(
+            Self::task_name(caller, id),
+            DispatchTime::At(when.into()),
+            maybe_periodic,
+            LOWEST_PRIORITY,
+            origin.into(),
+            bounded_call,
+        )
+        .map_err(|e| {
+            log::trace!(target: LOG_TARGET, "failed to schedule call: {:?}", e);
+            revert("failed to schedule call")
+        })?;
+
+        Ok(id)
+    }
+
+    /// Cancel the call with the specified `id`, previously scheduled by the caller.
+    ///
+    /// The weight charged upfront for the remaining executions isn't refunded.
+    #[precompile::public("cancelNamed(bytes32)")]
+    fn cancel_named(handle: &mut impl PrecompileHandle, id: H256) -> EvmResult<bool> {
+        log::trace!(target: LOG_TARGET, "raw arguments: id: {:?}", id);
+
+        handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(
+            <Runtime as pallet_scheduler::Config>::WeightInfo::cancel_named(
+                <Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+            ),
+        ))?;
+
+        let caller: H160 = handle.context().caller;
+        <pallet_scheduler::Pallet<Runtime> as ScheduleNamed<_, _, _>>::cancel_named(
+            Self::task_name(caller, id),
+        )
+        .map_err(|e| {
+            log::trace!(target: LOG_TARGET, "failed to cancel call: {:?}", e);
+            revert("scheduled call not found")
+        })?;
+
+        Ok(true)
+    }
+
+    /// Scheduler task name of the call with the specified `id`, scheduled by the `caller`.
+    fn task_name(caller: H160, id: H256) -> [u8; 32] {
+        blake2_256(&(b"evm_scheduler", caller, id).encode())
+    }
+}
@@ -0,0 +1,273 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+/// Transfer of `value` to `BOB`, allowed to be scheduled.
+fn transfer_call(value: Balance) -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { dest: BOB, value })
+}
+
+/// Id of the call scheduled by the `caller` in the current block.
+fn scheduled_call_id(
+    caller: TestAccount,
+    call: &RuntimeCall,
+    when: BlockNumber,
+    maybe_periodic: Option<(BlockNumber, u32)>,
+) -> H256 {
+    blake2_256(
+        &(
+            H160::from(caller),
+            System::block_number(),
+            when,
+            maybe_periodic,
+            &call.encode(),
+        )
+            .encode(),
+    )
+    .into()
+}
+
+#[test]
+fn schedule_call_works() {
+    ExtBuilder.build().execute_with(|| {
+        let call = transfer_call(10);
+        let id = scheduled_call_id(TestAccount::Viktor, &call, 3, None);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 0,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(id);
+
+        // Call isn't dispatched before the scheduled block
+        run_to_block(2);
+        assert_eq!(Balances::free_balance(BOB), 0);
+
+        // Call is dispatched on behalf of the caller, exactly once
+        run_to_block(3);
+        assert_eq!(Balances::free_balance(BOB), 10);
+        run_to_block(10);
+        assert_eq!(Balances::free_balance(BOB), 10);
+    });
+}
+
+#[test]
+fn periodic_call_is_executed_max_number_of_times() {
+    ExtBuilder.build().execute_with(|| {
+        let call = transfer_call(10);
+        let id = scheduled_call_id(
+            TestAccount::Viktor,
+            &call,
+            3,
+            Some((2, MaxPeriodicExecutions::get())),
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(id);
+
+        run_to_block(3);
+        assert_eq!(Balances::free_balance(BOB), 10);
+        run_to_block(5);
+        assert_eq!(Balances::free_balance(BOB), 20);
+        run_to_block(7);
+        assert_eq!(Balances::free_balance(BOB), 30);
+
+        // No more executions after the max number is reached
+        run_to_block(20);
+        assert_eq!(Balances::free_balance(BOB), 30);
+    });
+}
+
+#[test]
+fn weight_of_all_executions_is_charged_upfront() {
+    ExtBuilder.build().execute_with(|| {
+        let call = transfer_call(10);
+        let schedule_weight = <() as pallet_scheduler::WeightInfo>::schedule_named(
+            <TestRuntime as pallet_scheduler::Config>::MaxScheduledPerBlock::get(),
+        );
+        let call_weight = call.get_dispatch_info().weight;
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 0,
+                },
+            )
+            .expect_cost(call_weight.saturating_add(schedule_weight).ref_time())
+            .expect_no_logs()
+            .execute_some();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 2,
+                },
+            )
+            .expect_cost(
+                call_weight
+                    .saturating_mul(MaxPeriodicExecutions::get().into())
+                    .saturating_add(schedule_weight)
+                    .ref_time(),
+            )
+            .expect_no_logs()
+            .execute_some();
+    });
+}
+
+#[test]
+fn cancel_named_works() {
+    ExtBuilder.build().execute_with(|| {
+        let call = transfer_call(10);
+        let id = scheduled_call_id(TestAccount::Viktor, &call, 3, Some((2, 3)));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(id);
+
+        run_to_block(3);
+        assert_eq!(Balances::free_balance(BOB), 10);
+
+        // Call can't be cancelled by another caller
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_named { id },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"scheduled call not found");
+
+        // Cancel the remaining executions
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_named { id },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        run_to_block(10);
+        assert_eq!(Balances::free_balance(BOB), 10);
+
+        // Call can't be cancelled twice
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::cancel_named { id },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"scheduled call not found");
+    });
+}
+
+#[test]
+fn schedule_call_fails_for_invalid_calls() {
+    ExtBuilder.build().execute_with(|| {
+        // Call which isn't allowed by the filter
+        let call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: BOB,
+            value: 10,
+        });
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: call.encode().into(),
+                    when: 3,
+                    maybe_periodic: 0,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"call not allowed");
+
+        // Call which can't be decoded
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: vec![0xFF, 0xFF].into(),
+                    when: 3,
+                    maybe_periodic: 0,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"could not decode call");
+
+        // Call scheduled in the past
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::schedule_call {
+                    call: transfer_call(10).encode().into(),
+                    when: 1,
+                    maybe_periodic: 0,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"failed to schedule call");
+
+        assert_eq!(Balances::free_balance(BOB), 0);
+    });
+}
@@ -13,4 +13,16 @@ interface SR25519 {
         bytes calldata signature,
         bytes calldata message
     ) external view returns (bool);
+
+    /**
+     * @dev Verify multiple messages signed using SR25519 crypto at once.
+     * Each message is expected to be signed by the public key at the same index.
+     * Gas is charged per item, based on the message length.
+     * @return A boolean confirming whether all of the signatures are valid.
+     */
+    function verifyBatch(
+        bytes32[] calldata public_keys,
+        bytes[] calldata signatures,
+        bytes[] calldata messages
+    ) external view returns (bool);
 }
\ No newline at end of file
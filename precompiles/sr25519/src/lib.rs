@@ -21,7 +21,7 @@
 use fp_evm::PrecompileHandle;
 use sp_core::{crypto::UncheckedFrom, sr25519, H256};
 use sp_core::{ByteArray, ConstU32};
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use precompile_utils::prelude::*;
 
@@ -33,6 +33,15 @@ mod tests;
 // SR25519 signature bytes
 type SR25519SignatureBytes = ConstU32<64>;
 
+/// Max number of signatures which can be verified in a single batch.
+pub const MAX_BATCH_SIZE: u32 = 64;
+type GetMaxBatchSize = ConstU32<MAX_BATCH_SIZE>;
+
+/// Gas cost of a single signature verification within a batch.
+pub const BATCH_VERIFY_ITEM_COST: u64 = 2_000;
+/// Gas cost per 32-byte word of the message verified within a batch.
+pub const BATCH_VERIFY_WORD_COST: u64 = 3;
+
 /// A precompile to wrap substrate sr25519 functions.
 pub struct Sr25519Precompile<Runtime>(PhantomData<Runtime>);
 
@@ -46,14 +55,57 @@ impl<Runtime: pallet_evm::Config> Sr25519Precompile<Runtime> {
         signature: BoundedBytes<SR25519SignatureBytes>,
         message: UnboundedBytes,
     ) -> EvmResult<bool> {
+        Ok(Self::verify_signature(
+            public,
+            signature.as_bytes(),
+            message.as_bytes(),
+        ))
+    }
+
+    /// Verify multiple signatures at once, where `signatures[i]` is a signature of `messages[i]` by `pubkeys[i]`.
+    ///
+    /// Returns `true` only if all of the signatures are valid.
+    /// Gas is charged upfront for every item, based on the message length.
+    #[precompile::public("verifyBatch(bytes32[],bytes[],bytes[])")]
+    #[precompile::view]
+    fn verify_batch(
+        handle: &mut impl PrecompileHandle,
+        pubkeys: BoundedVec<H256, GetMaxBatchSize>,
+        signatures: BoundedVec<BoundedBytes<SR25519SignatureBytes>, GetMaxBatchSize>,
+        messages: BoundedVec<UnboundedBytes, GetMaxBatchSize>,
+    ) -> EvmResult<bool> {
+        let pubkeys: Vec<_> = pubkeys.into();
+        let signatures: Vec<_> = signatures.into();
+        let messages: Vec<_> = messages.into();
+
+        if pubkeys.len() != signatures.len() || pubkeys.len() != messages.len() {
+            return Err(revert("input lengths mismatch"));
+        }
+
+        let cost = messages.iter().fold(0_u64, |cost, message| {
+            let words = (message.as_bytes().len() as u64).div_ceil(32);
+            cost.saturating_add(BATCH_VERIFY_ITEM_COST)
+                .saturating_add(words.saturating_mul(BATCH_VERIFY_WORD_COST))
+        });
+        handle.record_cost(cost)?;
+
+        Ok(pubkeys.into_iter().zip(signatures).zip(messages).all(
+            |((public, signature), message)| {
+                Self::verify_signature(public, signature.as_bytes(), message.as_bytes())
+            },
+        ))
+    }
+
+    /// Verify the sr25519 `signature` of the `message`, made by the `public` key.
+    fn verify_signature(public: H256, signature: &[u8], message: &[u8]) -> bool {
         // Parse pub key
         let public = sr25519::Public::unchecked_from(public);
         // Parse signature
-        let signature = if let Ok(sig) = sr25519::Signature::from_slice(&signature.as_bytes()) {
+        let signature = if let Ok(sig) = sr25519::Signature::from_slice(signature) {
             sig
         } else {
             // Return `false` if signature length is wrong
-            return Ok(false);
+            return false;
         };
 
         log::trace!(
@@ -62,8 +114,7 @@ impl<Runtime: pallet_evm::Config> Sr25519Precompile<Runtime> {
             signature, public, message,
         );
 
-        let is_confirmed =
-            sp_io::crypto::sr25519_verify(&signature, &message.as_bytes(), &public.into());
+        let is_confirmed = sp_io::crypto::sr25519_verify(&signature, message, &public.into());
 
         log::trace!(
             target: "sr25519-precompile",
@@ -71,6 +122,6 @@ impl<Runtime: pallet_evm::Config> Sr25519Precompile<Runtime> {
             signature, is_confirmed,
         );
 
-        Ok(is_confirmed)
+        is_confirmed
     }
 }
@@ -19,6 +19,7 @@
 use hex_literal::hex;
 
 use crate::mock::*;
+use crate::*;
 
 use precompile_utils::testing::*;
 use sp_core::{sr25519, Pair};
@@ -105,3 +106,97 @@ fn substrate_test_vector_works() {
             .execute_returns(true);
     });
 }
+
+/// Prepare `count` signed messages, each one signed by a different key.
+fn signed_messages(
+    count: u8,
+) -> (
+    Vec<H256>,
+    Vec<BoundedBytes<ConstU32<64>>>,
+    Vec<UnboundedBytes>,
+) {
+    (0..count)
+        .map(|i| {
+            let pair = sr25519::Pair::from_seed(&[i; 32]);
+            let message = vec![i; 40];
+            let signature = pair.sign(&message[..]);
+            (
+                pair.public().into(),
+                <sr25519::Signature as AsRef<[u8]>>::as_ref(&signature).into(),
+                message.into(),
+            )
+        })
+        .fold(
+            (vec![], vec![], vec![]),
+            |(mut pubkeys, mut signatures, mut messages), (public, signature, message)| {
+                pubkeys.push(public);
+                signatures.push(signature);
+                messages.push(message);
+                (pubkeys, signatures, messages)
+            },
+        )
+}
+
+#[test]
+fn verify_batch_works() {
+    ExtBuilder.build().execute_with(|| {
+        let (pubkeys, signatures, messages) = signed_messages(3);
+
+        // Each 40 bytes long message consists of 2 words
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::verify_batch {
+                    pubkeys: pubkeys.into(),
+                    signatures: signatures.into(),
+                    messages: messages.into(),
+                },
+            )
+            .expect_cost(3 * (BATCH_VERIFY_ITEM_COST + 2 * BATCH_VERIFY_WORD_COST))
+            .expect_no_logs()
+            .execute_returns(true);
+    });
+}
+
+#[test]
+fn verify_batch_with_bad_signature_returns_false() {
+    ExtBuilder.build().execute_with(|| {
+        let (pubkeys, signatures, mut messages) = signed_messages(3);
+        messages[1] = hex!["00"].into();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::verify_batch {
+                    pubkeys: pubkeys.into(),
+                    signatures: signatures.into(),
+                    messages: messages.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(false);
+    });
+}
+
+#[test]
+fn verify_batch_with_mismatched_lengths_fails() {
+    ExtBuilder.build().execute_with(|| {
+        let (pubkeys, mut signatures, messages) = signed_messages(3);
+        signatures.pop();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::verify_batch {
+                    pubkeys: pubkeys.into(),
+                    signatures: signatures.into(),
+                    messages: messages.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"input lengths mismatch");
+    });
+}
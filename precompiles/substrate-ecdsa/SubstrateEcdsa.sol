@@ -13,4 +13,22 @@ interface ISubstrateEcdsa {
         bytes calldata signature,
         bytes calldata message
     ) external view returns (bool);
+
+    /**
+     * @dev Recover the public key which signed the message, using Substrate version of ECDSA crypto.
+     * @return The compressed (33 bytes) public key of the signer.
+     */
+    function recover(
+        bytes calldata message,
+        bytes calldata signature
+    ) external view returns (bytes memory);
+
+    /**
+     * @dev Recover the Substrate account Id of the key which signed the message.
+     * @return The account Id, derived as blake2 hash of the compressed public key.
+     */
+    function recoverAccountId(
+        bytes calldata message,
+        bytes calldata signature
+    ) external view returns (bytes32);
 }
@@ -19,7 +19,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use fp_evm::PrecompileHandle;
-use sp_core::{ecdsa, ByteArray, ConstU32};
+use sp_core::{ecdsa, ByteArray, ConstU32, H256};
+use sp_io::hashing::blake2_256;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -87,4 +88,54 @@ impl<Runtime: pallet_evm::Config> SubstrateEcdsaPrecompile<Runtime> {
 
         Ok(is_confirmed)
     }
+
+    /// Recover the compressed (33 bytes) public key which signed the `message`.
+    #[precompile::public("recover(bytes,bytes)")]
+    #[precompile::view]
+    fn recover(
+        _handle: &mut impl PrecompileHandle,
+        message: UnboundedBytes,
+        signature_bytes: BoundedBytes<ECDSASignatureBytes>,
+    ) -> EvmResult<UnboundedBytes> {
+        let public = Self::recover_public(message.into(), signature_bytes.into())?;
+
+        Ok(public.as_slice().into())
+    }
+
+    /// Recover the Substrate account Id (32 bytes) of the key which signed the `message`.
+    ///
+    /// Account Id is derived the same way as for the native ECDSA accounts, i.e. as the blake2 hash of the compressed public key.
+    #[precompile::public("recoverAccountId(bytes,bytes)")]
+    #[precompile::view]
+    fn recover_account_id(
+        _handle: &mut impl PrecompileHandle,
+        message: UnboundedBytes,
+        signature_bytes: BoundedBytes<ECDSASignatureBytes>,
+    ) -> EvmResult<H256> {
+        let public = Self::recover_public(message.into(), signature_bytes.into())?;
+
+        Ok(blake2_256(public.as_slice()).into())
+    }
+
+    /// Recover the public key which signed the `message`, using the Substrate version of ECDSA crypto,
+    /// i.e. the message is hashed with blake2 before signing.
+    fn recover_public(message: Vec<u8>, signature_bytes: Vec<u8>) -> EvmResult<ecdsa::Public> {
+        let signature: [u8; 65] = signature_bytes
+            .try_into()
+            .map_err(|_| revert("invalid signature length"))?;
+
+        let public = sp_io::crypto::secp256k1_ecdsa_recover_compressed(
+            &signature,
+            &blake2_256(&message[..]),
+        )
+        .map_err(|_| revert("invalid signature"))?;
+
+        log::trace!(
+            target: "substrate-ecdsa-precompile",
+            "Recovered public {:?} for signature {:?}",
+            public, signature,
+        );
+
+        Ok(ecdsa::Public::from_raw(public))
+    }
 }
@@ -20,8 +20,9 @@ use hex_literal::hex;
 
 use crate::mock::*;
 
-use precompile_utils::testing::*;
-use sp_core::{ecdsa, Pair};
+use precompile_utils::{prelude::UnboundedBytes, testing::*};
+use sp_core::{ecdsa, Pair, H256};
+use sp_runtime::{traits::IdentifyAccount, AccountId32, MultiSigner};
 
 fn precompiles() -> TestPrecompileSet<Runtime> {
     PrecompilesValue::get()
@@ -107,3 +108,72 @@ fn substrate_test_vector_works() {
             .execute_returns(true);
     });
 }
+
+#[test]
+fn recover_works() {
+    ExtBuilder.build().execute_with(|| {
+        let pair = ecdsa::Pair::from_seed(b"12345678901234567890123456789012");
+        let public = pair.public();
+        let message = hex!("2f8c6129d816cf51c374bc7f08c3e63ed156cf78aefb4a6550d97b87997977ee00000000000000000200d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a4500000000000000");
+        let signature = pair.sign(&message[..]);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::recover {
+                    message: message.into(),
+                    signature_bytes: <ecdsa::Signature as AsRef<[u8]>>::as_ref(&signature).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from(
+                <ecdsa::Public as AsRef<[u8]>>::as_ref(&public),
+            ));
+
+        // Account Id matches the one of the native ECDSA account
+        let account_id: AccountId32 = MultiSigner::from(public).into_account();
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::recover_account_id {
+                    message: message.into(),
+                    signature_bytes: <ecdsa::Signature as AsRef<[u8]>>::as_ref(&signature).into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(H256::from(<[u8; 32]>::from(account_id)));
+    });
+}
+
+#[test]
+fn recover_with_invalid_signature_fails() {
+    ExtBuilder.build().execute_with(|| {
+        let message = hex!["00"];
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::recover {
+                    message: message.into(),
+                    signature_bytes: hex!["0042"].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"invalid signature length");
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::recover_account_id {
+                    message: message.into(),
+                    signature_bytes: [0xFF_u8; 65].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"invalid signature");
+    });
+}
@@ -0,0 +1,46 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Treasury interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005012 for the main treasury,
+/// and at the address 0x0000000000000000000000000000000000005013 for the community treasury
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/treasury/src/lib.rs
+///
+/// Proposals are submitted on behalf of the caller's mapped native account, which reserves the proposal bond.
+interface Treasury {
+    /// Number of spend proposals submitted so far.
+    ///
+    /// @return The proposal count.
+    function proposalCount() external view returns (uint32);
+
+    /// Status of the spend proposal.
+    ///
+    /// @param proposalIndex The proposal index.
+    /// @return 0 if the proposal doesn't exist (or was already rejected or awarded),
+    ///         1 if it's pending the council decision, 2 if it's approved.
+    function proposalStatus(uint32 proposalIndex) external view returns (uint8);
+
+    /// Details of the spend proposal, reverts if it doesn't exist.
+    ///
+    /// @param proposalIndex The proposal index.
+    /// @return proposer The proposer native account.
+    /// @return value The requested value.
+    /// @return beneficiary The beneficiary native account.
+    /// @return bond The reserved proposal bond.
+    function proposal(uint32 proposalIndex)
+        external
+        view
+        returns (bytes32 proposer, uint256 value, bytes32 beneficiary, uint256 bond);
+
+    /// Propose to spend from the treasury.
+    ///
+    /// @param value The requested value.
+    /// @param beneficiary The address whose mapped native account receives the value, if approved.
+    /// @return The index of the new proposal.
+    function proposeSpend(uint256 value, address beneficiary) external returns (uint32);
+}
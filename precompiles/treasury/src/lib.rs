@@ -0,0 +1,166 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar treasury interface.
+//!
+//! Allows EVM accounts, including smart contracts, to submit treasury spend proposals on behalf of their
+//! mapped native account (e.g. the unified account, if one is claimed), and to follow up on their status.
+//! This way teams whose tooling is entirely EVM based can apply for grants on-chain.
+//!
+//! Proposals are submitted with `pallet-treasury` `propose_spend`, so the proposer bond is reserved
+//! from the caller's native account, and the proposal is approved or rejected by the council.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    BoundedVec,
+};
+use pallet_evm::AddressMapping;
+use pallet_treasury::{BalanceOf, Proposal, ProposalIndex};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Status of a spend proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ProposalStatus {
+    /// The proposal doesn't exist, or was already rejected or awarded.
+    None = 0,
+    /// The proposal awaits the council decision.
+    Pending = 1,
+    /// The proposal was approved, and will be awarded at the end of the spend period.
+    Approved = 2,
+}
+
+/// A precompile that allows EVM accounts to submit treasury spend proposals.
+pub struct TreasuryPrecompile<Runtime, Instance: 'static = ()>(PhantomData<(Runtime, Instance)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> TreasuryPrecompile<Runtime, Instance>
+where
+    Instance: 'static,
+    Runtime: pallet_evm::Config + pallet_treasury::Config<Instance>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_treasury::Call<Runtime, Instance>>,
+    Runtime::AccountId: Into<[u8; 32]>,
+    BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256>,
+{
+    /// Number of spend proposals submitted so far.
+    #[precompile::public("proposalCount()")]
+    #[precompile::view]
+    fn proposal_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_db_read::<Runtime>(ProposalIndex::max_encoded_len())?;
+
+        Ok(pallet_treasury::Pallet::<Runtime, Instance>::proposal_count())
+    }
+
+    /// Status of the spend proposal with the specified index, see [`ProposalStatus`].
+    #[precompile::public("proposalStatus(uint32)")]
+    #[precompile::view]
+    fn proposal_status(handle: &mut impl PrecompileHandle, proposal_index: u32) -> EvmResult<u8> {
+        handle.record_db_read::<Runtime>(Self::proposal_max_encoded_len())?;
+        if pallet_treasury::Pallet::<Runtime, Instance>::proposals(proposal_index).is_none() {
+            return Ok(ProposalStatus::None as u8);
+        }
+
+        handle.record_db_read::<Runtime>(BoundedVec::<
+            ProposalIndex,
+            <Runtime as pallet_treasury::Config<Instance>>::MaxApprovals,
+        >::max_encoded_len())?;
+        let status = if pallet_treasury::Pallet::<Runtime, Instance>::approvals()
+            .contains(&proposal_index)
+        {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Pending
+        };
+
+        Ok(status as u8)
+    }
+
+    /// Details of the spend proposal with the specified index:
+    /// the proposer & beneficiary native accounts, the requested value and the reserved bond.
+    #[precompile::public("proposal(uint32)")]
+    #[precompile::view]
+    fn proposal(
+        handle: &mut impl PrecompileHandle,
+        proposal_index: u32,
+    ) -> EvmResult<(H256, U256, H256, U256)> {
+        handle.record_db_read::<Runtime>(Self::proposal_max_encoded_len())?;
+
+        let proposal = pallet_treasury::Pallet::<Runtime, Instance>::proposals(proposal_index)
+            .ok_or(revert("proposal not found"))?;
+
+        Ok((
+            H256(proposal.proposer.into()),
+            proposal.value.into(),
+            H256(proposal.beneficiary.into()),
+            proposal.bond.into(),
+        ))
+    }
+
+    /// Propose to spend `value` from the treasury, to the `beneficiary` mapped native account.
+    ///
+    /// Returns the index of the new proposal.
+    #[precompile::public("proposeSpend(uint256,address)")]
+    fn propose_spend(
+        handle: &mut impl PrecompileHandle,
+        value: U256,
+        beneficiary: Address,
+    ) -> EvmResult<u32> {
+        let value = Self::u256_to_balance(value).in_field("value")?;
+        let beneficiary = Runtime::AddressMapping::into_account_id(beneficiary.into());
+
+        // The new proposal will take the next free index
+        handle.record_db_read::<Runtime>(ProposalIndex::max_encoded_len())?;
+        let proposal_index = pallet_treasury::Pallet::<Runtime, Instance>::proposal_count();
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        #[allow(deprecated)]
+        let propose_call = pallet_treasury::Call::<Runtime, Instance>::propose_spend {
+            value,
+            beneficiary: Runtime::Lookup::unlookup(beneficiary),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), propose_call)?;
+
+        Ok(proposal_index)
+    }
+
+    fn proposal_max_encoded_len() -> usize {
+        Proposal::<Runtime::AccountId, BalanceOf<Runtime, Instance>>::max_encoded_len()
+    }
+
+    fn u256_to_balance(value: U256) -> MayRevert<BalanceOf<Runtime, Instance>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").into())
+    }
+}
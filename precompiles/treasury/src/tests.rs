@@ -0,0 +1,154 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+
+use frame_support::assert_ok;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+/// Propose to spend `value` to `Alice`, on behalf of the `Viktor` account.
+fn propose_spend(value: Balance, proposal_index: u32) {
+    precompiles()
+        .prepare_test(
+            TestAccount::Viktor,
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::propose_spend {
+                value: value.into(),
+                beneficiary: Address(TestAccount::Alice.into()),
+            },
+        )
+        .expect_no_logs()
+        .execute_returns(proposal_index);
+}
+
+fn assert_proposal_status(proposal_index: u32, status: ProposalStatus) {
+    precompiles()
+        .prepare_test(
+            TestAccount::Viktor,
+            PRECOMPILE_ADDRESS,
+            PrecompileCall::proposal_status { proposal_index },
+        )
+        .expect_no_logs()
+        .execute_returns(status as u8);
+}
+
+#[test]
+fn propose_spend_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::proposal_count {},
+            )
+            .expect_no_logs()
+            .execute_returns(0_u32);
+
+        propose_spend(100, 0);
+        propose_spend(400, 1);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::proposal_count {},
+            )
+            .expect_no_logs()
+            .execute_returns(2_u32);
+
+        // Bond is reserved from the mapped account, at least the minimum, otherwise 5% of the value
+        assert_eq!(
+            Balances::reserved_balance(&account(TestAccount::Viktor)),
+            PROPOSAL_BOND_MINIMUM + 20
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::proposal { proposal_index: 1 },
+            )
+            .expect_no_logs()
+            .execute_returns((
+                H256(account(TestAccount::Viktor).into()),
+                U256::from(400),
+                H256(account(TestAccount::Alice).into()),
+                U256::from(20),
+            ));
+    });
+}
+
+#[test]
+fn propose_spend_fails_for_insufficient_bond() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::propose_spend {
+                    value: 100_000.into(),
+                    beneficiary: Address(TestAccount::Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output.ends_with(b"message: Some(\"InsufficientProposersBalance\") })")
+            });
+    });
+}
+
+#[test]
+#[allow(deprecated)]
+fn proposal_status_works() {
+    ExtBuilder.build().execute_with(|| {
+        assert_proposal_status(0, ProposalStatus::None);
+
+        propose_spend(100, 0);
+        propose_spend(100, 1);
+        assert_proposal_status(0, ProposalStatus::Pending);
+        assert_proposal_status(1, ProposalStatus::Pending);
+
+        assert_ok!(Treasury::approve_proposal(RuntimeOrigin::root(), 0));
+        assert_ok!(Treasury::reject_proposal(RuntimeOrigin::root(), 1));
+        assert_proposal_status(0, ProposalStatus::Approved);
+        assert_proposal_status(1, ProposalStatus::None);
+    });
+}
+
+#[test]
+fn proposal_fails_if_not_found() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::proposal { proposal_index: 0 },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"proposal not found");
+    });
+}
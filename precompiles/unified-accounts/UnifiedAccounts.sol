@@ -25,4 +25,27 @@ interface UnifiedAccounts {
     function get_native_address_or_default(
         address evmAddress
     ) external view returns (bytes32, bool);
+
+    /// Computes the default evm address of the given account id, regardless of any existing mapping.
+    /// @param accountId: The account id for which you want the default evm address for.
+    /// @return default evm address of the account id
+    function computeDefaultEvmAddress(
+        bytes32 accountId
+    ) external view returns (address);
+
+    /// Batch version of `get_evm_address_or_default`, at most 128 account ids can be resolved in a single call.
+    /// Gas is charged per account id.
+    /// @param accountIds: The account ids for which you want the evm addresses for.
+    /// @return (addresses, mapped) where `mapped[i]` is true if `addresses[i]` is a mapped address, false if it's the default one
+    function toH160Batch(
+        bytes32[] calldata accountIds
+    ) external view returns (address[] memory, bool[] memory);
+
+    /// Batch version of `get_native_address_or_default`, at most 128 evm addresses can be resolved in a single call.
+    /// Gas is charged per evm address.
+    /// @param evmAddresses: The evm addresses for which you want the account ids for.
+    /// @return (accounts, mapped) where `mapped[i]` is true if `accounts[i]` is a mapped account, false if it's the default one
+    function toAccountIdBatch(
+        address[] calldata evmAddresses
+    ) external view returns (bytes32[] memory, bool[] memory);
 }
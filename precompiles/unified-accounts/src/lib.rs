@@ -21,15 +21,27 @@
 use astar_primitives::evm::{UnifiedAddress, UnifiedAddressMapper};
 use core::marker::PhantomData;
 use fp_evm::PrecompileHandle;
-use frame_support::traits::IsType;
+use frame_support::traits::{ConstU32, IsType};
 use precompile_utils::prelude::*;
 use sp_core::{crypto::AccountId32, H256};
+use sp_std::prelude::*;
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Maximum number of items which can be resolved in a single batch lookup.
+pub const BATCH_LOOKUP_LIMIT: u32 = 128;
+type GetBatchLookupLimit = ConstU32<BATCH_LOOKUP_LIMIT>;
+
+/// Storage item: NativeToEvm:
+/// Blake2_128Concat(16 + AccountId(32)) + EvmAddress(20)
+const NATIVE_TO_EVM_READ_SIZE: usize = 16 + 32 + 20;
+/// Storage item: EvmToNative:
+/// Blake2_128Concat(16 + EvmAddress(20)) + AccountId(32)
+const EVM_TO_NATIVE_READ_SIZE: usize = 16 + 20 + 32;
+
 /// A precompile that expose AU related functions.
 pub struct UnifiedAccountsPrecompile<T, UA>(PhantomData<(T, UA)>);
 
@@ -46,13 +58,7 @@ where
         _: &mut impl PrecompileHandle,
         account_id: H256,
     ) -> EvmResult<(Address, bool)> {
-        let account_id = AccountId32::new(account_id.into()).into();
-
-        let output: (Address, bool) = match UA::to_h160_or_default(&account_id) {
-            UnifiedAddress::Mapped(address) => (address.into(), true),
-            UnifiedAddress::Default(address) => (address.into(), false),
-        };
-        Ok(output)
+        Ok(Self::evm_address_or_default(account_id))
     }
 
     #[precompile::public("get_native_address_or_default(address)")]
@@ -61,10 +67,86 @@ where
         _: &mut impl PrecompileHandle,
         evm_address: Address,
     ) -> EvmResult<(H256, bool)> {
-        let output: (H256, bool) = match UA::to_account_id_or_default(&evm_address.into()) {
+        Ok(Self::native_address_or_default(evm_address))
+    }
+
+    /// Compute the default evm address of the given account id, ignoring any existing mapping.
+    #[precompile::public("computeDefaultEvmAddress(bytes32)")]
+    #[precompile::view]
+    fn compute_default_evm_address(
+        _: &mut impl PrecompileHandle,
+        account_id: H256,
+    ) -> EvmResult<Address> {
+        let account_id = AccountId32::new(account_id.into()).into();
+        Ok(UA::to_default_h160(&account_id).into())
+    }
+
+    /// Batch version of `get_evm_address_or_default`.
+    /// Returns evm addresses and mapping flags, in the same order as the input account ids.
+    #[precompile::public("toH160Batch(bytes32[])")]
+    #[precompile::view]
+    fn to_h160_batch(
+        handle: &mut impl PrecompileHandle,
+        account_ids: BoundedVec<H256, GetBatchLookupLimit>,
+    ) -> EvmResult<(Vec<Address>, Vec<bool>)> {
+        let account_ids: Vec<H256> = account_ids.into();
+
+        let mut addresses = Vec::with_capacity(account_ids.len());
+        let mut mapped = Vec::with_capacity(account_ids.len());
+        for account_id in account_ids {
+            handle.record_db_read::<R>(NATIVE_TO_EVM_READ_SIZE)?;
+
+            let (address, is_mapped) = Self::evm_address_or_default(account_id);
+            addresses.push(address);
+            mapped.push(is_mapped);
+        }
+
+        Ok((addresses, mapped))
+    }
+
+    /// Batch version of `get_native_address_or_default`.
+    /// Returns account ids and mapping flags, in the same order as the input evm addresses.
+    #[precompile::public("toAccountIdBatch(address[])")]
+    #[precompile::view]
+    fn to_account_id_batch(
+        handle: &mut impl PrecompileHandle,
+        evm_addresses: BoundedVec<Address, GetBatchLookupLimit>,
+    ) -> EvmResult<(Vec<H256>, Vec<bool>)> {
+        let evm_addresses: Vec<Address> = evm_addresses.into();
+
+        let mut account_ids = Vec::with_capacity(evm_addresses.len());
+        let mut mapped = Vec::with_capacity(evm_addresses.len());
+        for evm_address in evm_addresses {
+            handle.record_db_read::<R>(EVM_TO_NATIVE_READ_SIZE)?;
+
+            let (account_id, is_mapped) = Self::native_address_or_default(evm_address);
+            account_ids.push(account_id);
+            mapped.push(is_mapped);
+        }
+
+        Ok((account_ids, mapped))
+    }
+}
+
+impl<R, UA> UnifiedAccountsPrecompile<R, UA>
+where
+    R: pallet_evm::Config + pallet_unified_accounts::Config,
+    <R as frame_system::Config>::AccountId: IsType<AccountId32>,
+    UA: UnifiedAddressMapper<R::AccountId>,
+{
+    fn evm_address_or_default(account_id: H256) -> (Address, bool) {
+        let account_id = AccountId32::new(account_id.into()).into();
+
+        match UA::to_h160_or_default(&account_id) {
+            UnifiedAddress::Mapped(address) => (address.into(), true),
+            UnifiedAddress::Default(address) => (address.into(), false),
+        }
+    }
+
+    fn native_address_or_default(evm_address: Address) -> (H256, bool) {
+        match UA::to_account_id_or_default(&evm_address.into()) {
             UnifiedAddress::Mapped(account_id) => (H256::from(account_id.into().as_ref()), true),
             UnifiedAddress::Default(account_id) => (H256::from(account_id.into().as_ref()), false),
-        };
-        Ok(output)
+        }
     }
 }
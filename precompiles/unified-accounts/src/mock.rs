@@ -244,6 +244,7 @@ impl pallet_unified_accounts::Config for TestRuntime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type MaxSecondaryAddresses = ConstU32<4>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -21,6 +21,7 @@ use crate::*;
 
 use frame_support::assert_ok;
 use precompile_utils::testing::*;
+use sp_core::H160;
 
 fn precompiles() -> TestPrecompileSet<TestRuntime> {
     PrecompilesValue::get()
@@ -128,3 +129,120 @@ fn test_get_native_address() {
             .execute_returns(res);
     });
 }
+
+#[test]
+fn test_compute_default_evm_address() {
+    ExtBuilder.build().execute_with(|| {
+        let alice_default_evm =
+            <TestRuntime as pallet_unified_accounts::Config>::DefaultMappings::to_default_h160(
+                &ALICE,
+            );
+
+        // claim the account, default address must still be returned
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            signature
+        ));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::compute_default_evm_address {
+                    account_id: H256::zero(), // Alice's Address
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Address::from(alice_default_evm));
+    });
+}
+
+#[test]
+fn test_to_h160_batch() {
+    ExtBuilder.build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            signature
+        ));
+
+        let bob = AccountId32::new([1u8; 32]);
+        let bob_default_evm =
+            <TestRuntime as pallet_unified_accounts::Config>::DefaultMappings::to_default_h160(
+                &bob,
+            );
+
+        let res: (Vec<Address>, Vec<bool>) = (
+            vec![alice_eth.into(), bob_default_evm.into()],
+            vec![true, false],
+        );
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::to_h160_batch {
+                    account_ids: vec![H256::zero(), H256::repeat_byte(1)].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(res);
+    });
+}
+
+#[test]
+fn test_to_account_id_batch() {
+    ExtBuilder.build().execute_with(|| {
+        let alice_eth: Address = UnifiedAccounts::eth_address(&alice_secret()).into();
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth.into(),
+            signature
+        ));
+
+        let unmapped: Address = H160::repeat_byte(0x42).into();
+        let unmapped_default =
+            <TestRuntime as pallet_unified_accounts::Config>::DefaultMappings::to_default_account_id(
+                &unmapped.into(),
+            );
+
+        let alice_converted: &[u8; 32] = ALICE.as_ref();
+        let unmapped_converted: &[u8; 32] = unmapped_default.as_ref();
+        let res: (Vec<H256>, Vec<bool>) = (
+            vec![alice_converted.into(), unmapped_converted.into()],
+            vec![true, false],
+        );
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::to_account_id_batch {
+                    evm_addresses: vec![alice_eth, unmapped].into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(res);
+    });
+}
+
+#[test]
+fn test_batch_lookup_limit() {
+    ExtBuilder.build().execute_with(|| {
+        let too_many = vec![H256::zero(); BATCH_LOOKUP_LIMIT as usize + 1];
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::to_h160_batch {
+                    account_ids: too_many.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|_| true);
+    });
+}
@@ -0,0 +1,99 @@
+pragma solidity ^0.8.0;
+
+    /**
+     * @title ERC721 interface
+     * @dev see https://eips.ethereum.org/EIPS/eip-721
+     * @dev copied from https://github.com/OpenZeppelin/openzeppelin-contracts
+     */
+    interface IERC721 {
+
+    /**
+     * @dev Emitted when `tokenId` token is transferred from `from` to `to`.
+     */
+    event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+
+    /**
+     * @dev Emitted when `owner` enables `approved` to manage the `tokenId` token.
+     */
+    event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+
+    /**
+     * @dev Returns the name of the collection.
+     * Selector: 06fdde03
+     */
+    function name() external view returns (string memory);
+
+    /**
+     * @dev Returns the symbol of the collection.
+     * Selector: 95d89b41
+     */
+    function symbol() external view returns (string memory);
+
+    /**
+     * @dev Returns the metadata URI of the `tokenId` token.
+     * Selector: c87b56dd
+     */
+    function tokenURI(uint256 tokenId) external view returns (string memory);
+
+    /**
+     * @dev Returns the number of tokens in `owner`'s account.
+     * Selector: 70a08231
+     */
+    function balanceOf(address owner) external view returns (uint256);
+
+    /**
+     * @dev Returns the owner of the `tokenId` token.
+     * Selector: 6352211e
+     */
+    function ownerOf(uint256 tokenId) external view returns (address);
+
+    /**
+     * @dev Returns the account approved for `tokenId` token.
+     * Selector: 081812fc
+     */
+    function getApproved(uint256 tokenId) external view returns (address);
+
+    /**
+     * @dev Operator approvals are not supported, always returns false.
+     * Selector: e985e9c5
+     */
+    function isApprovedForAll(address owner, address operator) external view returns (bool);
+
+    /**
+     * @dev Gives permission to `to` to transfer `tokenId` token to another account.
+     * Approving the zero address clears the existing approval.
+     * Selector: 095ea7b3
+     */
+    function approve(address to, uint256 tokenId) external;
+
+    /**
+     * @dev Operator approvals are not supported, always reverts.
+     * Selector: a22cb465
+     */
+    function setApprovalForAll(address operator, bool approved) external;
+
+    /**
+     * @dev Transfers `tokenId` token from `from` to `to`.
+     * Selector: 23b872dd
+     */
+    function transferFrom(address from, address to, uint256 tokenId) external;
+
+    /**
+     * @dev Transfers `tokenId` token from `from` to `to`.
+     * Transfers to contracts are rejected since `onERC721Received` cannot be invoked.
+     * Selector: 42842e0e
+     */
+    function safeTransferFrom(address from, address to, uint256 tokenId) external;
+
+    /**
+     * @dev Same as `safeTransferFrom(address,address,uint256)`, `data` is ignored.
+     * Selector: b88d4fde
+     */
+    function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external;
+
+    /**
+     * @dev Returns true if this contract implements the interface defined by `interfaceId`.
+     * Selector: 01ffc9a7
+     */
+    function supportsInterface(bytes4 interfaceId) external view returns (bool);
+}
@@ -0,0 +1,530 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar ERC721 interface for the Uniques pallet collections.
+//!
+//! Every existing collection is exposed as an ERC721 contract, at the address derived from its id,
+//! analogous to the XC20 assets. This allows EVM marketplaces to trade the native NFTs.
+//!
+//! A few parts of the standard can't be mapped onto the Uniques pallet:
+//! - operator approvals don't exist, so `setApprovalForAll` reverts
+//!   and `isApprovedForAll` is always false,
+//! - receiver contracts aren't notified, so `safeTransferFrom` reverts
+//!   if the receiver is a contract.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::evm::UnifiedAddressMapper;
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{
+        tokens::nonfungibles::{Inspect, InspectEnumerable},
+        Currency, Get,
+    },
+    Blake2_128Concat, DefaultNoBound,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Interface identifiers reported by `supportsInterface`: ERC165, ERC721 & ERC721Metadata.
+pub const SUPPORTED_INTERFACES: [u32; 3] = [0x01ffc9a7, 0x80ac58cd, 0x5b5e139f];
+
+/// Collection attribute used as the ERC721 symbol.
+pub const SYMBOL_ATTRIBUTE_KEY: &[u8] = b"symbol";
+
+/// Storage item: Asset:
+/// Blake2_128(16) + CollectionId(16) + Blake2_128(16) + ItemId(16)
+/// + ItemDetails[owner(32) + approved(33) + is_frozen(1) + deposit(16)]
+const ITEM_READ_SIZE: usize = 146;
+/// Storage item: Account:
+/// Blake2_128(16) + AccountId(32) + Blake2_128(16) + CollectionId(16) + Blake2_128(16) + ItemId(16)
+const ACCOUNT_ITEM_READ_SIZE: usize = 112;
+/// Storage item: NativeToEvm:
+/// Blake2_128Concat(16 + AccountId(32)) + EvmAddress(20)
+const NATIVE_TO_EVM_READ_SIZE: usize = 16 + 32 + 20;
+/// Storage item: AccountCodesMetadata:
+/// Blake2_128(16) + H160(20) + CodeMetadata(40)
+const CODE_METADATA_READ_SIZE: usize = 76;
+
+/// Alias for the Collection Id type for the provided Runtime and Instance.
+pub type CollectionIdOf<Runtime, Instance = ()> =
+    <Runtime as pallet_uniques::Config<Instance>>::CollectionId;
+
+/// Alias for the Item Id type for the provided Runtime and Instance.
+pub type ItemIdOf<Runtime, Instance = ()> = <Runtime as pallet_uniques::Config<Instance>>::ItemId;
+
+type UniquesPallet<Runtime, Instance> = pallet_uniques::Pallet<Runtime, Instance>;
+
+type DepositBalanceOf<Runtime, Instance = ()> =
+    <<Runtime as pallet_uniques::Config<Instance>>::Currency as Currency<
+        <Runtime as frame_system::Config>::AccountId,
+    >>::Balance;
+
+/// This trait ensure we can convert EVM address to CollectionIds
+/// We will require Runtime to have this trait implemented
+pub trait AddressToCollectionId<CollectionId> {
+    // Get collectionId from address
+    fn address_to_collection_id(address: H160) -> Option<CollectionId>;
+
+    // Get address from CollectionId
+    fn collection_id_to_address(collection_id: CollectionId) -> H160;
+}
+
+/// Ownership details of an item, with the same layout as the `pallet_uniques::ItemDetails`,
+/// whose fields aren't accessible outside of the pallet.
+#[derive(Encode, Decode)]
+struct ItemDetails<AccountId, DepositBalance> {
+    owner: AccountId,
+    approved: Option<AccountId>,
+    is_frozen: bool,
+    deposit: DepositBalance,
+}
+
+/// The items in existence and their ownership details, as stored by the Uniques pallet.
+#[frame_support::storage_alias]
+type Asset<T: pallet_uniques::Config<I>, I: 'static> = StorageDoubleMap<
+    pallet_uniques::Pallet<T, I>,
+    Blake2_128Concat,
+    CollectionIdOf<T, I>,
+    Blake2_128Concat,
+    ItemIdOf<T, I>,
+    ItemDetails<<T as frame_system::Config>::AccountId, DepositBalanceOf<T, I>>,
+>;
+
+/// Collection precompiles can only fall in the address range defined by the runtime,
+/// e.g. addresses starting with a dedicated 4 byte prefix, followed by the collection id.
+/// In order to route the address to Erc721UniquesPrecompileSet<R>, we check whether the collection
+/// exists in pallet-uniques.
+///
+/// This means that every address in the range will go through an additional db read.
+#[derive(Clone, DefaultNoBound)]
+pub struct Erc721UniquesPrecompileSet<Runtime, UA, Instance: 'static = ()>(
+    PhantomData<(Runtime, UA, Instance)>,
+);
+
+impl<Runtime, UA, Instance> Erc721UniquesPrecompileSet<Runtime, UA, Instance> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+impl<Runtime, UA, Instance> Erc721UniquesPrecompileSet<Runtime, UA, Instance>
+where
+    Instance: 'static,
+    Runtime: pallet_uniques::Config<Instance> + pallet_evm::Config + frame_system::Config,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_uniques::Call<Runtime, Instance>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime: AddressToCollectionId<CollectionIdOf<Runtime, Instance>>,
+    UA: UnifiedAddressMapper<Runtime::AccountId>,
+    ItemIdOf<Runtime, Instance>: TryFrom<U256> + Into<U256>,
+{
+    /// PrecompileSet discriminant. Allows to knows if the address maps to a collection id,
+    /// and if this is the case which one.
+    #[precompile::discriminant]
+    fn discriminant(
+        address: H160,
+        gas: u64,
+    ) -> DiscriminantResult<CollectionIdOf<Runtime, Instance>> {
+        let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+        if gas < extra_cost {
+            return DiscriminantResult::OutOfGas;
+        }
+
+        let collection_id = match Runtime::address_to_collection_id(address) {
+            Some(collection_id) => collection_id,
+            None => return DiscriminantResult::None(extra_cost),
+        };
+
+        let collection_owner =
+            <UniquesPallet<Runtime, Instance> as Inspect<Runtime::AccountId>>::collection_owner(
+                &collection_id,
+            );
+        if collection_owner.is_some() {
+            DiscriminantResult::Some(collection_id, extra_cost)
+        } else {
+            DiscriminantResult::None(extra_cost)
+        }
+    }
+
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+    ) -> EvmResult<U256> {
+        let owner = Runtime::AddressMapping::into_account_id(owner.into());
+
+        // Items are counted one by one, each of them being a separate storage item
+        handle.record_db_read::<Runtime>(ACCOUNT_ITEM_READ_SIZE)?;
+        let mut balance = U256::zero();
+        for _ in <UniquesPallet<Runtime, Instance> as InspectEnumerable<
+            Runtime::AccountId,
+        >>::owned_in_collection(&collection_id, &owner)
+        {
+            handle.record_db_read::<Runtime>(ACCOUNT_ITEM_READ_SIZE)?;
+            balance = balance.saturating_add(U256::one());
+        }
+
+        Ok(balance)
+    }
+
+    #[precompile::public("ownerOf(uint256)")]
+    #[precompile::view]
+    fn owner_of(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        token_id: U256,
+    ) -> EvmResult<Address> {
+        let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+        let details = Self::item_details(handle, collection_id, item_id)?;
+
+        Self::account_to_address(handle, &details.owner)
+    }
+
+    #[precompile::public("getApproved(uint256)")]
+    #[precompile::view]
+    fn get_approved(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        token_id: U256,
+    ) -> EvmResult<Address> {
+        let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+        let details = Self::item_details(handle, collection_id, item_id)?;
+
+        match details.approved {
+            Some(approved) => Self::account_to_address(handle, &approved),
+            None => Ok(Address(H160::zero())),
+        }
+    }
+
+    /// Operator approvals aren't supported by the Uniques pallet.
+    #[precompile::public("isApprovedForAll(address,address)")]
+    #[precompile::view]
+    fn is_approved_for_all(
+        _collection_id: CollectionIdOf<Runtime, Instance>,
+        _handle: &mut impl PrecompileHandle,
+        _owner: Address,
+        _operator: Address,
+    ) -> EvmResult<bool> {
+        Ok(false)
+    }
+
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        approved: Address,
+        token_id: U256,
+    ) -> EvmResult {
+        handle.record_log_costs_manual(4, 0)?;
+
+        let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+        let details = Self::item_details(handle, collection_id, item_id)?;
+        let owner = Self::account_to_address(handle, &details.owner)?;
+
+        let approved: H160 = approved.into();
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        if approved == H160::zero() {
+            // Approval can only be cleared if there is one
+            if details.approved.is_some() {
+                RuntimeHelper::<Runtime>::try_dispatch(
+                    handle,
+                    Some(origin).into(),
+                    pallet_uniques::Call::<Runtime, Instance>::cancel_approval {
+                        collection: collection_id,
+                        item: item_id,
+                        maybe_check_delegate: None,
+                    },
+                )?;
+            }
+        } else {
+            let delegate = Runtime::AddressMapping::into_account_id(approved);
+            RuntimeHelper::<Runtime>::try_dispatch(
+                handle,
+                Some(origin).into(),
+                pallet_uniques::Call::<Runtime, Instance>::approve_transfer {
+                    collection: collection_id,
+                    item: item_id,
+                    delegate: Runtime::Lookup::unlookup(delegate),
+                },
+            )?;
+        }
+
+        log4(
+            handle.context().address,
+            SELECTOR_LOG_APPROVAL,
+            owner.0,
+            approved,
+            Self::token_id_topic(token_id),
+            Vec::new(),
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+
+    /// Operator approvals aren't supported by the Uniques pallet.
+    #[precompile::public("setApprovalForAll(address,bool)")]
+    fn set_approval_for_all(
+        _collection_id: CollectionIdOf<Runtime, Instance>,
+        _handle: &mut impl PrecompileHandle,
+        _operator: Address,
+        _approved: bool,
+    ) -> EvmResult {
+        Err(revert("operator approvals are not supported"))
+    }
+
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> EvmResult {
+        handle.record_log_costs_manual(4, 0)?;
+
+        let from: H160 = from.into();
+        let to: H160 = to.into();
+        if to == H160::zero() {
+            return Err(revert("transfer to the zero address"));
+        }
+
+        let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+        let details = Self::item_details(handle, collection_id, item_id)?;
+        if details.owner != Runtime::AddressMapping::into_account_id(from) {
+            return Err(revert("from is not the owner"));
+        }
+
+        // Caller must be either the owner, the approved account, or the collection admin
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let dest = Runtime::AddressMapping::into_account_id(to);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_uniques::Call::<Runtime, Instance>::transfer {
+                collection: collection_id,
+                item: item_id,
+                dest: Runtime::Lookup::unlookup(dest),
+            },
+        )?;
+
+        log4(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER,
+            from,
+            to,
+            Self::token_id_topic(token_id),
+            Vec::new(),
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("safeTransferFrom(address,address,uint256)")]
+    fn safe_transfer_from(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> EvmResult {
+        Self::ensure_not_contract(handle, to.into())?;
+        Self::transfer_from(collection_id, handle, from, to, token_id)
+    }
+
+    #[precompile::public("safeTransferFrom(address,address,uint256,bytes)")]
+    fn safe_transfer_from_with_data(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        _data: UnboundedBytes,
+    ) -> EvmResult {
+        Self::ensure_not_contract(handle, to.into())?;
+        Self::transfer_from(collection_id, handle, from, to, token_id)
+    }
+
+    /// Collection name, taken from the collection metadata.
+    #[precompile::public("name()")]
+    #[precompile::view]
+    fn name(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<UnboundedBytes> {
+        // Storage item: ClassMetadataOf:
+        // Blake2_128(16) + CollectionId(16)
+        // + CollectionMetadata[deposit(16) + data(StringLimit) + is_frozen(1)]
+        handle.record_db_read::<Runtime>(
+            49 + <Runtime as pallet_uniques::Config<Instance>>::StringLimit::get() as usize,
+        )?;
+
+        // Empty key maps to the collection metadata
+        Ok(
+            <UniquesPallet<Runtime, Instance> as Inspect<Runtime::AccountId>>::collection_attribute(
+                &collection_id,
+                &[],
+            )
+            .unwrap_or_default()
+            .as_slice()
+            .into(),
+        )
+    }
+
+    /// Collection symbol, taken from the `symbol` collection attribute.
+    #[precompile::public("symbol()")]
+    #[precompile::view]
+    fn symbol(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<UnboundedBytes> {
+        // Storage item: Attribute:
+        // Blake2_128(16) + CollectionId(16) + Blake2_128(16) + Option<ItemId>(17)
+        // + Blake2_128(16) + key(KeyLimit) + value(ValueLimit) + deposit(16)
+        handle.record_db_read::<Runtime>(
+            97 + <Runtime as pallet_uniques::Config<Instance>>::KeyLimit::get() as usize
+                + <Runtime as pallet_uniques::Config<Instance>>::ValueLimit::get() as usize,
+        )?;
+
+        Ok(
+            <UniquesPallet<Runtime, Instance> as Inspect<Runtime::AccountId>>::collection_attribute(
+                &collection_id,
+                SYMBOL_ATTRIBUTE_KEY,
+            )
+            .unwrap_or_default()
+            .as_slice()
+            .into(),
+        )
+    }
+
+    /// Token URI, taken from the item metadata.
+    #[precompile::public("tokenURI(uint256)")]
+    #[precompile::view]
+    fn token_uri(
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        token_id: U256,
+    ) -> EvmResult<UnboundedBytes> {
+        let item_id = Self::u256_to_item_id(token_id).in_field("tokenId")?;
+        Self::item_details(handle, collection_id, item_id)?;
+
+        // Storage item: InstanceMetadataOf:
+        // Blake2_128(16) + CollectionId(16) + Blake2_128(16) + ItemId(16)
+        // + ItemMetadata[deposit(16) + data(StringLimit) + is_frozen(1)]
+        handle.record_db_read::<Runtime>(
+            81 + <Runtime as pallet_uniques::Config<Instance>>::StringLimit::get() as usize,
+        )?;
+
+        // Empty key maps to the item metadata
+        Ok(
+            <UniquesPallet<Runtime, Instance> as Inspect<Runtime::AccountId>>::attribute(
+                &collection_id,
+                &item_id,
+                &[],
+            )
+            .unwrap_or_default()
+            .as_slice()
+            .into(),
+        )
+    }
+
+    /// Whether the collection contract supports the `interface_id`, as defined by ERC165.
+    ///
+    /// Being a `bytes4`, the `interface_id` is encoded left-aligned in the ABI word,
+    /// hence decoded from its leading bytes.
+    #[precompile::public("supportsInterface(bytes4)")]
+    #[precompile::view]
+    fn supports_interface(
+        _collection_id: CollectionIdOf<Runtime, Instance>,
+        _handle: &mut impl PrecompileHandle,
+        interface_id: H256,
+    ) -> EvmResult<bool> {
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&interface_id.as_bytes()[..4]);
+
+        Ok(SUPPORTED_INTERFACES.contains(&u32::from_be_bytes(selector)))
+    }
+
+    /// Ownership details of the item, reverts if the item doesn't exist.
+    fn item_details(
+        handle: &mut impl PrecompileHandle,
+        collection_id: CollectionIdOf<Runtime, Instance>,
+        item_id: ItemIdOf<Runtime, Instance>,
+    ) -> EvmResult<ItemDetails<Runtime::AccountId, DepositBalanceOf<Runtime, Instance>>> {
+        handle.record_db_read::<Runtime>(ITEM_READ_SIZE)?;
+
+        Asset::<Runtime, Instance>::get(collection_id, item_id)
+            .ok_or(revert("token does not exist"))
+    }
+
+    /// EVM address of the native account.
+    fn account_to_address(
+        handle: &mut impl PrecompileHandle,
+        account_id: &Runtime::AccountId,
+    ) -> EvmResult<Address> {
+        handle.record_db_read::<Runtime>(NATIVE_TO_EVM_READ_SIZE)?;
+
+        Ok(Address(UA::to_h160_or_default(account_id).into_address()))
+    }
+
+    /// Ensures the `address` isn't a contract, since contracts can't be notified of the transfer.
+    fn ensure_not_contract(handle: &mut impl PrecompileHandle, address: H160) -> EvmResult {
+        handle.record_db_read::<Runtime>(CODE_METADATA_READ_SIZE)?;
+
+        if pallet_evm::Pallet::<Runtime>::account_code_metadata(address).size > 0 {
+            Err(revert("transfer to contracts is not supported"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn token_id_topic(token_id: U256) -> H256 {
+        let mut topic = [0u8; 32];
+        token_id.to_big_endian(&mut topic);
+        topic.into()
+    }
+
+    fn u256_to_item_id(value: U256) -> MayRevert<ItemIdOf<Runtime, Instance>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("item id type").into())
+    }
+}
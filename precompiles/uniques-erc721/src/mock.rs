@@ -0,0 +1,236 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Testing utilities.
+
+use super::*;
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU64, Everything},
+    weights::Weight,
+};
+
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
+use precompile_utils::{
+    mock_account,
+    testing::{AddressInPrefixedSet, MockAccount},
+};
+
+use sp_core::{ConstU32, H160, H256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub type AccountId = MockAccount;
+pub type CollectionId = u128;
+pub type ItemId = u128;
+pub type Balance = u128;
+pub type Block = frame_system::mocking::MockBlock<Runtime>;
+
+/// The collection precompile address prefix. Addresses that match against this prefix will
+/// be routed to Erc721UniquesPrecompileSet
+pub const COLLECTION_PRECOMPILE_ADDRESS_PREFIX: u32 = 0xfffffffd;
+
+mock_account!(
+    CollectionAddress(CollectionId),
+    |value: CollectionAddress| {
+        AddressInPrefixedSet(COLLECTION_PRECOMPILE_ADDRESS_PREFIX, value.0).into()
+    }
+);
+
+// Implement the trait, where we convert AccountId to CollectionId
+impl AddressToCollectionId<CollectionId> for Runtime {
+    /// The way to convert an account to collectionId is by ensuring that the prefix is 0XFFFFFFFD
+    /// and by taking the lowest 128 bits as the collectionId
+    fn address_to_collection_id(address: H160) -> Option<CollectionId> {
+        let address: MockAccount = address.into();
+        if address.has_prefix_u32(COLLECTION_PRECOMPILE_ADDRESS_PREFIX) {
+            Some(address.without_prefix())
+        } else {
+            None
+        }
+    }
+
+    fn collection_id_to_address(collection_id: CollectionId) -> H160 {
+        CollectionAddress(collection_id).into()
+    }
+}
+
+/// Mock accounts are EVM addresses, so the mapping is an identity.
+pub struct UnifiedAccounts;
+impl UnifiedAddressMapper<AccountId> for UnifiedAccounts {
+    fn to_account_id(evm_address: &H160) -> Option<AccountId> {
+        Some((*evm_address).into())
+    }
+
+    fn to_default_account_id(evm_address: &H160) -> AccountId {
+        (*evm_address).into()
+    }
+
+    fn to_h160(account_id: &AccountId) -> Option<H160> {
+        Some(account_id.clone().into())
+    }
+
+    fn to_default_h160(account_id: &AccountId) -> H160 {
+        account_id.clone().into()
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type RuntimeFreezeReason = ();
+    type MaxFreezes = ConstU32<0>;
+}
+
+parameter_types! {
+    pub const PrecompilesValue: Erc721UniquesPrecompileSet<Runtime, UnifiedAccounts> =
+        Erc721UniquesPrecompileSet(PhantomData);
+    pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+pub type PrecompileCall = Erc721UniquesPrecompileSetCall<Runtime, UnifiedAccounts, ()>;
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Erc721UniquesPrecompileSet<Self, UnifiedAccounts>;
+    type PrecompilesValue = PrecompilesValue;
+    type Timestamp = Timestamp;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = ();
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type WeightInfo = ();
+    type GasLimitPovSizeRatio = ConstU64<4>;
+    type SuicideQuickClearLimit = ConstU32<0>;
+}
+
+// Deposits don't matter for the precompile, so they are disabled
+impl pallet_uniques::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = CollectionId;
+    type ItemId = ItemId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+    pub enum Runtime
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Uniques: pallet_uniques,
+        Evm: pallet_evm,
+        Timestamp: pallet_timestamp,
+    }
+);
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,481 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use fp_evm::IsPrecompileResult;
+use frame_support::{assert_ok, BoundedVec};
+use pallet_evm::PrecompileSet;
+
+use crate::mock::*;
+use crate::*;
+
+use precompile_utils::testing::*;
+
+fn precompiles() -> Erc721UniquesPrecompileSet<Runtime, UnifiedAccounts> {
+    PrecompilesValue::get()
+}
+
+/// Creates the collection `0` owned by `CryptoAlith`, with item `1` minted to `Bob`,
+/// and items `2` & `3` minted to `Charlie`.
+fn create_collection() {
+    assert_ok!(Uniques::force_create(
+        RuntimeOrigin::root(),
+        0,
+        CryptoAlith.into(),
+        true
+    ));
+    assert_ok!(Uniques::mint(
+        RuntimeOrigin::signed(CryptoAlith.into()),
+        0,
+        1,
+        Bob.into()
+    ));
+    for item in [2, 3] {
+        assert_ok!(Uniques::mint(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            0,
+            item,
+            Charlie.into()
+        ));
+    }
+}
+
+fn token_id_topic(token_id: u128) -> H256 {
+    H256::from_low_u64_be(token_id as u64)
+}
+
+#[test]
+fn only_existing_collections_are_precompiles() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        let is_precompile = |address: H160| {
+            matches!(
+                precompiles().is_precompile(address, u64::MAX),
+                IsPrecompileResult::Answer {
+                    is_precompile: true,
+                    ..
+                }
+            )
+        };
+
+        assert!(is_precompile(CollectionAddress(0).into()));
+        assert!(!is_precompile(CollectionAddress(1).into()));
+        assert!(!is_precompile(Bob.into()));
+    });
+}
+
+#[test]
+fn balance_of_and_owner_of_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        for (owner, balance) in [(Bob, 1), (Charlie, 2), (CryptoAlith, 0)] {
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    CollectionAddress(0),
+                    PrecompileCall::balance_of {
+                        owner: Address(owner.into()),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(U256::from(balance));
+        }
+
+        for (token_id, owner) in [(1, Bob), (2, Charlie), (3, Charlie)] {
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    CollectionAddress(0),
+                    PrecompileCall::owner_of {
+                        token_id: token_id.into(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(Address(owner.into()));
+        }
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::owner_of { token_id: 4.into() },
+            )
+            .execute_reverts(|output| output == b"token does not exist");
+    });
+}
+
+#[test]
+fn metadata_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+        assert_ok!(Uniques::set_collection_metadata(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            0,
+            BoundedVec::truncate_from(b"Astar Degens".to_vec()),
+            false
+        ));
+        assert_ok!(Uniques::set_attribute(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            0,
+            None,
+            BoundedVec::truncate_from(SYMBOL_ATTRIBUTE_KEY.to_vec()),
+            BoundedVec::truncate_from(b"DEGEN".to_vec())
+        ));
+        assert_ok!(Uniques::set_metadata(
+            RuntimeOrigin::signed(CryptoAlith.into()),
+            0,
+            1,
+            BoundedVec::truncate_from(b"ipfs://degen/1".to_vec()),
+            false
+        ));
+
+        precompiles()
+            .prepare_test(Bob, CollectionAddress(0), PrecompileCall::name {})
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from("Astar Degens"));
+        precompiles()
+            .prepare_test(Bob, CollectionAddress(0), PrecompileCall::symbol {})
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from("DEGEN"));
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::token_uri { token_id: 1.into() },
+            )
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from("ipfs://degen/1"));
+
+        // Item without metadata
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::token_uri { token_id: 2.into() },
+            )
+            .expect_no_logs()
+            .execute_returns(UnboundedBytes::from(""));
+
+        // Non-existent item
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::token_uri { token_id: 4.into() },
+            )
+            .execute_reverts(|output| output == b"token does not exist");
+    });
+}
+
+#[test]
+fn transfer_from_by_owner_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Bob.into()),
+                    to: Address(David.into()),
+                    token_id: 1.into(),
+                },
+            )
+            .expect_log(log4(
+                CollectionAddress(0),
+                SELECTOR_LOG_TRANSFER,
+                Bob,
+                David,
+                token_id_topic(1),
+                Vec::new(),
+            ))
+            .execute_returns(());
+
+        assert_eq!(Uniques::owner(0, 1), Some(David.into()));
+    });
+}
+
+#[test]
+fn transfer_from_by_approved_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        precompiles()
+            .prepare_test(
+                Charlie,
+                CollectionAddress(0),
+                PrecompileCall::approve {
+                    approved: Address(Bob.into()),
+                    token_id: 2.into(),
+                },
+            )
+            .expect_log(log4(
+                CollectionAddress(0),
+                SELECTOR_LOG_APPROVAL,
+                Charlie,
+                Bob,
+                token_id_topic(2),
+                Vec::new(),
+            ))
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::get_approved { token_id: 2.into() },
+            )
+            .expect_no_logs()
+            .execute_returns(Address(Bob.into()));
+
+        // Bob isn't approved for the other items of Charlie
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Charlie.into()),
+                    to: Address(Bob.into()),
+                    token_id: 3.into(),
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"NoPermission\") })"));
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Charlie.into()),
+                    to: Address(Bob.into()),
+                    token_id: 2.into(),
+                },
+            )
+            .expect_log(log4(
+                CollectionAddress(0),
+                SELECTOR_LOG_TRANSFER,
+                Charlie,
+                Bob,
+                token_id_topic(2),
+                Vec::new(),
+            ))
+            .execute_returns(());
+
+        assert_eq!(Uniques::owner(0, 2), Some(Bob.into()));
+
+        // Approval is consumed by the transfer
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::get_approved { token_id: 2.into() },
+            )
+            .expect_no_logs()
+            .execute_returns(Address(H160::zero()));
+    });
+}
+
+#[test]
+fn approve_zero_address_clears_approval() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+        assert_ok!(Uniques::approve_transfer(
+            RuntimeOrigin::signed(Charlie.into()),
+            0,
+            2,
+            Bob.into()
+        ));
+
+        precompiles()
+            .prepare_test(
+                Charlie,
+                CollectionAddress(0),
+                PrecompileCall::approve {
+                    approved: Address(H160::zero()),
+                    token_id: 2.into(),
+                },
+            )
+            .expect_log(log4(
+                CollectionAddress(0),
+                SELECTOR_LOG_APPROVAL,
+                Charlie,
+                H160::zero(),
+                token_id_topic(2),
+                Vec::new(),
+            ))
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::get_approved { token_id: 2.into() },
+            )
+            .expect_no_logs()
+            .execute_returns(Address(H160::zero()));
+    });
+}
+
+#[test]
+fn transfer_from_fails_for_invalid_arguments() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        // Sender is not the owner
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Bob.into()),
+                    to: Address(David.into()),
+                    token_id: 2.into(),
+                },
+            )
+            .execute_reverts(|output| output == b"from is not the owner");
+
+        // Zero address receiver
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Bob.into()),
+                    to: Address(H160::zero()),
+                    token_id: 1.into(),
+                },
+            )
+            .execute_reverts(|output| output == b"transfer to the zero address");
+
+        // Non-existent item
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::transfer_from {
+                    from: Address(Bob.into()),
+                    to: Address(David.into()),
+                    token_id: 4.into(),
+                },
+            )
+            .execute_reverts(|output| output == b"token does not exist");
+    });
+}
+
+#[test]
+fn safe_transfer_from_rejects_contracts() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+        pallet_evm::AccountCodes::<Runtime>::insert(H160::from(David), vec![0x00]);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::safe_transfer_from {
+                    from: Address(Bob.into()),
+                    to: Address(David.into()),
+                    token_id: 1.into(),
+                },
+            )
+            .execute_reverts(|output| output == b"transfer to contracts is not supported");
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::safe_transfer_from_with_data {
+                    from: Address(Bob.into()),
+                    to: Address(Charlie.into()),
+                    token_id: 1.into(),
+                    data: UnboundedBytes::from("data"),
+                },
+            )
+            .expect_log(log4(
+                CollectionAddress(0),
+                SELECTOR_LOG_TRANSFER,
+                Bob,
+                Charlie,
+                token_id_topic(1),
+                Vec::new(),
+            ))
+            .execute_returns(());
+
+        assert_eq!(Uniques::owner(0, 1), Some(Charlie.into()));
+    });
+}
+
+#[test]
+fn operator_approvals_are_not_supported() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::set_approval_for_all {
+                    operator: Address(Charlie.into()),
+                    approved: true,
+                },
+            )
+            .execute_reverts(|output| output == b"operator approvals are not supported");
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                CollectionAddress(0),
+                PrecompileCall::is_approved_for_all {
+                    owner: Address(Bob.into()),
+                    operator: Address(Charlie.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(false);
+    });
+}
+
+#[test]
+fn supports_interface_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_collection();
+
+        for (interface_id, supported) in [
+            (0x01ffc9a7u32, true),
+            (0x80ac58cd, true),
+            (0x5b5e139f, true),
+            // ERC721Enumerable
+            (0x780e9d63, false),
+            (0xffffffff, false),
+        ] {
+            let mut id = H256::zero();
+            id.as_bytes_mut()[..4].copy_from_slice(&interface_id.to_be_bytes());
+
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    CollectionAddress(0),
+                    PrecompileCall::supports_interface { interface_id: id },
+                )
+                .expect_no_logs()
+                .execute_returns(supported);
+        }
+    });
+}
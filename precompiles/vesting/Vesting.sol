@@ -0,0 +1,28 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Vesting interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x000000000000000000000000000000000000500C
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/vesting/src/lib.rs
+///
+/// All actions are performed on behalf of the native account mapped to the caller.
+interface Vesting {
+    /// Amount of the account balance which is still locked by the vesting schedules.
+    ///
+    /// @param account The account to query.
+    /// @return The locked amount, or zero if the account has no vesting schedules.
+    function vestingBalanceOf(address account) external view returns (uint256);
+
+    /// Unlock the caller's funds which have vested so far.
+    function vest() external returns (bool);
+
+    /// Unlock the funds of the target account which have vested so far.
+    ///
+    /// @param target The account whose vested funds are unlocked. Released funds stay with the target.
+    function vestOther(address target) external returns (bool);
+}
@@ -0,0 +1,118 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Astar vesting interface.
+//!
+//! Allows EVM accounts to release their vested funds, without having to use any of the native tooling.
+//! This is primarily useful for the accounts which received vested funds (e.g. lockdrop participants)
+//! but only control the EVM keys.
+//!
+//! All the calls are dispatched on behalf of the native account mapped to the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{Currency, VestingSchedule},
+    BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use pallet_vesting::{MaxVestingSchedulesGet, VestingInfo};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const LOG_TARGET: &str = "precompile::vesting";
+
+type BalanceOf<Runtime> = <<Runtime as pallet_vesting::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// A precompile that allows EVM accounts to release their vested funds.
+pub struct VestingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> VestingPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_vesting::Config,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+        + GetDispatchInfo
+        + From<pallet_vesting::Call<Runtime>>,
+    BalanceOf<Runtime>: Into<U256>,
+{
+    /// Amount of the `account` balance which is still locked by the vesting schedules.
+    ///
+    /// Returns zero if the account has no vesting schedules.
+    #[precompile::public("vestingBalanceOf(address)")]
+    #[precompile::view]
+    fn vesting_balance_of(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<U256> {
+        // Vesting: Blake2_128(16) + AccountId(32) + BoundedVec<VestingInfo, MaxVestingSchedules>
+        handle.record_db_read::<Runtime>(
+            BoundedVec::<
+                VestingInfo<BalanceOf<Runtime>, BlockNumberFor<Runtime>>,
+                MaxVestingSchedulesGet<Runtime>,
+            >::max_encoded_len()
+            .saturating_add(48),
+        )?;
+
+        let account = Runtime::AddressMapping::into_account_id(account.into());
+
+        Ok(pallet_vesting::Pallet::<Runtime>::vesting_balance(&account)
+            .map(Into::into)
+            .unwrap_or_default())
+    }
+
+    /// Unlock the caller's funds which have vested so far.
+    #[precompile::public("vest()")]
+    fn vest(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let vest_call = pallet_vesting::Call::<Runtime>::vest {};
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), vest_call)?;
+
+        Ok(true)
+    }
+
+    /// Unlock the funds of the `target` account which have vested so far.
+    ///
+    /// Anyone can call this on behalf of the `target`, the released funds stay with the `target`.
+    #[precompile::public("vestOther(address)")]
+    fn vest_other(handle: &mut impl PrecompileHandle, target: Address) -> EvmResult<bool> {
+        log::trace!(target: LOG_TARGET, "raw arguments: target: {:?}", target);
+
+        let target = Runtime::AddressMapping::into_account_id(target.into());
+
+        // Prepare call & dispatch it
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let vest_other_call = pallet_vesting::Call::<Runtime>::vest_other {
+            target: Runtime::Lookup::unlookup(target),
+        };
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), vest_other_call)?;
+
+        Ok(true)
+    }
+}
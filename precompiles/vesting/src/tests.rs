@@ -0,0 +1,156 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::mock::*;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<TestRuntime> {
+    PrecompilesValue::get()
+}
+
+fn account(test_account: TestAccount) -> AccountId {
+    AddressMapper::into_account_id(test_account.into())
+}
+
+/// Amount of the account balance locked by the vesting, as it's currently applied to the account.
+fn vesting_lock(test_account: TestAccount) -> Balance {
+    pallet_balances::Locks::<TestRuntime>::get(account(test_account))
+        .iter()
+        .find(|lock| lock.id == *b"vesting ")
+        .map(|lock| lock.amount)
+        .unwrap_or_default()
+}
+
+#[test]
+fn vesting_balance_of_works() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vesting_balance_of {
+                    account: Address(TestAccount::Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(1_000));
+
+        // Balance vests linearly, block by block
+        run_to_block(5);
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vesting_balance_of {
+                    account: Address(TestAccount::Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(600));
+
+        // Accounts without vesting schedules have nothing locked
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vesting_balance_of {
+                    account: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(U256::zero());
+    });
+}
+
+#[test]
+fn vest_works() {
+    ExtBuilder.build().execute_with(|| {
+        assert_eq!(vesting_lock(TestAccount::Alice), 1_000);
+
+        run_to_block(5);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vest {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(vesting_lock(TestAccount::Alice), 600);
+        assert_eq!(Balances::free_balance(&account(TestAccount::Alice)), 1_000);
+
+        // Once the schedule ends, the lock is removed altogether
+        run_to_block(1 + VESTING_LENGTH);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vest {},
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(vesting_lock(TestAccount::Alice), 0);
+        assert!(pallet_vesting::Vesting::<TestRuntime>::get(account(TestAccount::Alice)).is_none());
+    });
+}
+
+#[test]
+fn vest_other_works() {
+    ExtBuilder.build().execute_with(|| {
+        run_to_block(3);
+
+        // Anyone can release the funds vested by the other account
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vest_other {
+                    target: Address(TestAccount::Alice.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(vesting_lock(TestAccount::Alice), 800);
+        assert_eq!(Balances::free_balance(&account(TestAccount::Viktor)), 1_000);
+    });
+}
+
+#[test]
+fn vest_without_schedule_fails() {
+    ExtBuilder.build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vest {},
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"NotVesting\") })"));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::vest_other {
+                    target: Address(TestAccount::Viktor.into()),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"message: Some(\"NotVesting\") })"));
+    });
+}
@@ -114,7 +114,8 @@ interface XCM {
     ///
     /// @dev The token transfer burns/transfers the corresponding amount before sending
     /// @param assets The assets we want to transfer, defined by their location and amount.
-    /// @param feeItem Which of the currencies to be used as fee
+    /// Assets can be provided in any order, but must not contain duplicates.
+    /// @param feeItem Index of the asset (in the provided list) to be used as fee
     /// @param destination The Multilocation to which we want to send the tokens
     /// @param weight The weight we want to buy in the destination chain, to set the
     /// weightlimit to Unlimited, you should use the value 0 for ref_time
@@ -125,6 +126,42 @@ interface XCM {
         WeightV2 memory weight
     ) external returns (bool);
 
+    /// Transfer an NFT through XCM based on its collection location, paying the fee with a fungible asset
+    ///
+    /// @dev The NFT collection must be registered as cross-chain collection on both chains
+    /// @param collectionLocation The Multilocation of the NFT collection
+    /// @param itemId The Id of the item within the collection
+    /// @param feeAssetLocation The Multilocation of the asset to be used as fee
+    /// @param feeAmount The amount of the fee asset to be used as fee
+    /// @param destination The Multilocation to which we want to send the NFT
+    /// @param weight The weight we want to buy in the destination chain, to set the
+    /// weightlimit to Unlimited, you should use the value 0 for ref_time
+    function transfer_nft(
+        Multilocation memory collectionLocation,
+        uint256 itemId,
+        Multilocation memory feeAssetLocation,
+        uint256 feeAmount,
+        Multilocation memory destination,
+        WeightV2 memory weight
+    ) external returns (bool);
+
+    /// Execute a call on the destination chain through XCM
+    ///
+    /// @dev The call is dispatched on the destination chain from the origin derived from the caller's account.
+    /// Execution is paid for with the fee asset, withdrawn from that derived account.
+    /// @param destination The Multilocation of the destination chain
+    /// @param call The SCALE encoded call to execute on the destination chain
+    /// @param feeAsset The ERC20 address of the currency used to pay for execution, zero address for the native currency
+    /// @param feeAmount The amount of the fee asset to be spent to pay for execution in destination chain
+    /// @param weight The max weight that the encoded call is allowed to consume in the destination chain
+    function remote_transact(
+        Multilocation memory destination,
+        bytes memory call,
+        address feeAsset,
+        uint256 feeAmount,
+        WeightV2 memory weight
+    ) external returns (bool);
+
     /**
      * @param destination - Multilocation of destination chain where to send this call
      * @param xcm_call - encoded xcm call you want to send to destination
@@ -247,6 +247,58 @@ where
             Junctions::from(Junction::Parachain(para_id)).into_exterior(1)
         };
 
+        Self::remote_transact_internal(
+            handle,
+            dest,
+            fee_asset_addr,
+            fee_amount,
+            remote_call,
+            Weight::from_parts(transact_weight, DEFAULT_PROOF_SIZE),
+        )
+    }
+
+    #[precompile::public("remote_transact((uint8,bytes[]),bytes,address,uint256,(uint64,uint64))")]
+    fn remote_transact(
+        handle: &mut impl PrecompileHandle,
+        destination: Location,
+        remote_call: BoundedBytes<GetXcmSizeLimit>,
+        fee_asset_addr: Address,
+        fee_amount: U256,
+        transact_weight: WeightV2,
+    ) -> EvmResult<bool> {
+        // Raw call arguments
+        let fee_amount: u128 = fee_amount
+            .try_into()
+            .map_err(|_| revert("error converting fee_amount, maybe value too large"))?;
+
+        let remote_call: Vec<u8> = remote_call.into();
+
+        log::trace!(target: "xcm-precompile:remote_transact", "Raw arguments: destination: {:?}, fee_asset_addr: {:?}, \
+         fee_amount: {:?}, remote_call: {:?}, transact_weight: {:?}",
+         destination, fee_asset_addr, fee_amount, remote_call, transact_weight);
+
+        Self::remote_transact_internal(
+            handle,
+            destination,
+            fee_asset_addr,
+            fee_amount,
+            remote_call,
+            transact_weight.get_weight(),
+        )
+    }
+
+    /// Send `WithdrawAsset/BuyExecution/Transact` program to the `dest`.
+    ///
+    /// Message is sent via `pallet-xcm`, on behalf of the caller, so the remote call is dispatched
+    /// from the origin derived from the caller's account on the destination chain.
+    fn remote_transact_internal(
+        handle: &mut impl PrecompileHandle,
+        dest: Location,
+        fee_asset_addr: Address,
+        fee_amount: u128,
+        remote_call: Vec<u8>,
+        transact_weight: Weight,
+    ) -> EvmResult<bool> {
         let fee_asset = {
             let address: H160 = fee_asset_addr.into();
 
@@ -277,7 +329,7 @@ where
             },
             Transact {
                 origin_kind: OriginKind::SovereignAccount,
-                require_weight_at_most: Weight::from_parts(transact_weight, DEFAULT_PROOF_SIZE),
+                require_weight_at_most: transact_weight,
                 call: remote_call.into(),
             },
         ]);
@@ -746,6 +798,10 @@ where
         Ok(true)
     }
 
+    #[precompile::public(
+        "transfer_multi_assets(((uint8,bytes[]),uint256)[],uint32,(uint8,bytes[]),(uint64,uint64))"
+    )]
+    // Misspelled selector, kept for backward compatibility.
     #[precompile::public(
         "transfet_multi_assets(((uint8,bytes[]),uint256)[],uint32,(uint8,bytes[]),(uint64,uint64))"
     )]
@@ -768,7 +824,7 @@ where
         weight: {:?}",
         assets, fee_item, destination, weight);
 
-        let multiasset_vec: EvmResult<Vec<Asset>> = assets
+        let mut multiasset_vec: Vec<Asset> = assets
             .into_iter()
             .map(|evm_multiasset| {
                 let to_balance: u128 = evm_multiasset
@@ -777,13 +833,23 @@ where
                     .map_err(|_| revert("value too large in assets"))?;
                 Ok((evm_multiasset.get_location(), to_balance).into())
             })
-            .collect();
+            .collect::<EvmResult<_>>()?;
+
+        let fee_asset_id = multiasset_vec
+            .get(fee_item as usize)
+            .map(|asset| asset.id.clone())
+            .ok_or(revert("fee_item out of bounds"))?;
+
+        // Since multiassets must be sorted, the fee item index needs to be adjusted
+        // to keep pointing to the asset designated by the caller.
+        multiasset_vec.sort();
+        let fee_item = multiasset_vec
+            .iter()
+            .position(|asset| asset.id == fee_asset_id)
+            .unwrap_or_default() as u32;
 
-        // Since multiassets sorts them, we need to check whether the index is still correct,
-        // and error otherwise as there is not much we can do other than that
-        let multiassets = Assets::from_sorted_and_deduplicated(multiasset_vec?).map_err(|_| {
-            revert("In field Assets, Provided assets either not sorted nor deduplicated")
-        })?;
+        let multiassets = Assets::from_sorted_and_deduplicated(multiasset_vec)
+            .map_err(|_| revert("In field Assets, Provided assets are not deduplicated"))?;
 
         let call = orml_xtokens::Call::<Runtime>::transfer_multiassets {
             assets: Box::new(VersionedAssets::V4(multiassets)),
@@ -802,6 +868,77 @@ where
 
         Ok(true)
     }
+
+    #[precompile::public(
+        "transfer_nft((uint8,bytes[]),uint256,(uint8,bytes[]),uint256,(uint8,bytes[]),(uint64,uint64))"
+    )]
+    fn transfer_nft(
+        handle: &mut impl PrecompileHandle,
+        collection_location: Location,
+        item_id: U256,
+        fee_asset_location: Location,
+        fee_amount: U256,
+        destination: Location,
+        weight: WeightV2,
+    ) -> EvmResult<bool> {
+        // Read call arguments
+        let item_id: u128 = item_id
+            .try_into()
+            .map_err(|_| revert("error converting item_id, maybe value too large"))?;
+        let fee_amount: u128 = fee_amount
+            .try_into()
+            .map_err(|_| revert("error converting fee_amount, maybe value too large"))?;
+
+        let weight_limit = if weight.is_zero() {
+            WeightLimit::Unlimited
+        } else {
+            WeightLimit::Limited(weight.get_weight())
+        };
+
+        log::trace!(target: "xcm-precompile::transfer_nft", "Raw arguments: collection_location: {:?}, item_id: {:?}, fee_asset_location: {:?}, \
+        fee_amount: {:?}, destination: {:?}, weight: {:?}",
+        collection_location, item_id, fee_asset_location, fee_amount, destination, weight);
+
+        // Destination is expected to be the beneficiary location, its last junction being the beneficiary account.
+        let (dest, beneficiary) = match destination.split_last_interior() {
+            (dest, Some(beneficiary)) => (dest, Location::new(0, [beneficiary])),
+            (_, None) => return Err(revert("destination doesn't specify the beneficiary")),
+        };
+
+        let fee_asset_id = AssetId(fee_asset_location);
+        let mut multiasset_vec: Vec<Asset> = vec![
+            (collection_location, AssetInstance::Index(item_id)).into(),
+            (fee_asset_id.clone(), fee_amount).into(),
+        ];
+
+        // Since multiassets must be sorted, the fee item index needs to be determined after sorting.
+        multiasset_vec.sort();
+        let fee_asset_item = multiasset_vec
+            .iter()
+            .position(|asset| asset.id == fee_asset_id && matches!(asset.fun, Fungible(_)))
+            .unwrap_or_default() as u32;
+
+        let multiassets = Assets::from_sorted_and_deduplicated(multiasset_vec)
+            .map_err(|_| revert("In field Assets, Provided assets are not deduplicated"))?;
+
+        let call = pallet_xcm::Call::<Runtime>::transfer_assets {
+            dest: Box::new(VersionedLocation::V4(dest)),
+            beneficiary: Box::new(VersionedLocation::V4(beneficiary)),
+            assets: Box::new(VersionedAssets::V4(multiassets)),
+            fee_asset_item,
+            weight_limit,
+        };
+
+        let origin = Some(Runtime::AddressMapping::into_account_id(
+            handle.context().caller,
+        ))
+        .into();
+
+        // Dispatch a call.
+        RuntimeHelper::<Runtime>::try_dispatch(handle, origin, call)?;
+
+        Ok(true)
+    }
 }
 
 #[derive(Debug, Clone, solidity::Codec)]
@@ -436,7 +436,7 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = StoringRouter;
     type AssetTransactor = LocalAssetTransactor;
     type OriginConverter = ();
-    type IsReserve = ();
+    type IsReserve = astar_primitives::xcm::ReserveAssetFilter;
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = Barrier;
@@ -914,6 +914,87 @@ mod xcm_new_interface_test {
         });
     }
 
+    #[test]
+    fn transfer_multiassets_with_unsorted_assets_works() {
+        let destination = Location::new(
+            1,
+            Junctions::from([
+                Parachain(2),
+                AccountId32 {
+                    network: None,
+                    id: [1u8; 32],
+                },
+            ]),
+        );
+        let weight = WeightV2::from(3_000_000_000u64, 1024);
+
+        let asset_1_location =
+            Location::new(1, Junctions::from([Parachain(2), GeneralIndex(0u128)]));
+        let asset_2_location =
+            Location::new(1, Junctions::from([Parachain(2), GeneralIndex(1u128)]));
+
+        // Assets are provided in reverse order, fee is paid with the 2nd one (asset 1)
+        let assets = vec![
+            (asset_2_location.clone(), U256::from(700)).into(),
+            (asset_1_location.clone(), U256::from(500)).into(),
+        ]
+        .into();
+
+        let multiassets = Assets::from_sorted_and_deduplicated(vec![
+            (asset_1_location.clone(), 500).into(),
+            (asset_2_location, 700).into(),
+        ])
+        .unwrap();
+
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::transfer_multi_assets {
+                        assets,
+                        fee_item: 1u32,
+                        destination: destination.clone(),
+                        weight,
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            let expected: crate::mock::RuntimeEvent =
+                mock::RuntimeEvent::Xtokens(XtokensEvent::TransferredAssets {
+                    sender: TestAccount::Alice.into(),
+                    assets: multiassets,
+                    fee: (asset_1_location, 500).into(),
+                    dest: destination,
+                })
+                .into();
+            assert!(events().contains(&expected));
+        });
+    }
+
+    #[test]
+    fn transfer_multiassets_with_invalid_fee_item_fails() {
+        let destination = Location::new(1, Junctions::from([Parachain(2)]));
+        let asset_location = Location::new(1, Junctions::from([Parachain(2), GeneralIndex(0u128)]));
+
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::transfer_multi_assets {
+                        assets: vec![(asset_location, U256::from(500)).into()].into(),
+                        fee_item: 1u32,
+                        destination,
+                        weight: WeightV2::from(3_000_000_000u64, 1024),
+                    },
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"fee_item out of bounds");
+        });
+    }
+
     #[test]
     fn transfer_multiassets_cannot_insert_more_than_max() {
         // We have definaed MaxAssetsForTransfer = 2,
@@ -960,4 +1041,129 @@ mod xcm_new_interface_test {
                 .execute_reverts(|output| output == b"assets: Value is too large for length");
         });
     }
+
+    #[test]
+    fn remote_transact_works() {
+        let destination = Location::new(1, Junctions::from([Parachain(10)]));
+        let weight = WeightV2::from(3_000_000_000u64, 1024);
+        let remote_call = vec![0xff_u8, 0xaa, 0x77, 0x00];
+
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::remote_transact {
+                        destination: destination.clone(),
+                        remote_call: remote_call.clone().into(),
+                        fee_asset_addr: Address::from(NATIVE_ADDRESS),
+                        fee_amount: 367.into(),
+                        transact_weight: weight.clone(),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            let sent_xcm = take_sent_xcm();
+            assert_eq!(sent_xcm.len(), 1);
+            let (location, Xcm(instructions)) = &sent_xcm[0];
+            assert_eq!(location, &destination);
+
+            // Program is executed from the caller's derived origin, and pays for the execution
+            // with the fee asset withdrawn from it.
+            assert!(matches!(
+                instructions.as_slice(),
+                [
+                    DescendOrigin(..),
+                    WithdrawAsset(..),
+                    BuyExecution { .. },
+                    Transact {
+                        origin_kind: OriginKind::SovereignAccount,
+                        require_weight_at_most,
+                        call,
+                    }
+                ] if *require_weight_at_most == weight.get_weight() && call.clone().into_encoded() == remote_call
+            ));
+        });
+    }
+
+    #[test]
+    fn remote_transact_with_unknown_fee_asset_fails() {
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::remote_transact {
+                        destination: Location::parent(),
+                        remote_call: vec![0xff_u8, 0xaa, 0x77, 0x00].into(),
+                        fee_asset_addr: Address::from(H160::repeat_byte(0xDE)),
+                        fee_amount: 367.into(),
+                        transact_weight: WeightV2::from(3_000_000_000u64, 1024),
+                    },
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"Failed to resolve fee asset id from address");
+        });
+    }
+
+    #[test]
+    fn transfer_nft_works() {
+        let beneficiary = Junction::AccountId32 {
+            network: None,
+            id: [1u8; 32],
+        };
+        let destination = Location::new(1, [Parachain(2), beneficiary]);
+        let collection_location = Location::new(1, [Parachain(2), GeneralIndex(7)]);
+        let fee_asset_location = Location::new(1, [Parachain(2)]);
+
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::transfer_nft {
+                        collection_location,
+                        item_id: 42.into(),
+                        fee_asset_location,
+                        fee_amount: 500.into(),
+                        destination,
+                        weight: WeightV2::from(3_000_000_000u64, 1024),
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            // NFT is sent to the reserve chain, and deposited to the beneficiary
+            let sent_messages = take_sent_xcm();
+            let (dest, xcm) = sent_messages.first().expect("message was sent");
+            assert_eq!(*dest, Location::new(1, [Parachain(2)]));
+            assert!(xcm.0.iter().any(|instruction| matches!(
+                instruction,
+                DepositAsset { beneficiary: deposit_beneficiary, .. }
+                    if *deposit_beneficiary == Location::new(0, [beneficiary])
+            )));
+        });
+    }
+
+    #[test]
+    fn transfer_nft_without_beneficiary_reverts() {
+        ExtBuilder.build().execute_with(|| {
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alice,
+                    PRECOMPILE_ADDRESS,
+                    PrecompileCall::transfer_nft {
+                        collection_location: Location::new(1, [Parachain(2), GeneralIndex(7)]),
+                        item_id: 42.into(),
+                        fee_asset_location: Location::new(1, [Parachain(2)]),
+                        fee_amount: 500.into(),
+                        destination: Location::here(),
+                        weight: WeightV2::from(3_000_000_000u64, 1024),
+                    },
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"destination doesn't specify the beneficiary");
+        });
+    }
 }
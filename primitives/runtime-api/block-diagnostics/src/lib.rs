@@ -0,0 +1,120 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API which provides block production diagnostics.
+//!
+//! Summarizes the weight & proof size consumed by the block, per dispatch class, together with
+//! the heaviest extrinsics included in it. Since the data is derived from the block post-state,
+//! block fullness can be investigated without re-executing the block with a custom build.
+
+use frame_support::{
+    dispatch::{DispatchClass, DispatchInfo, PerDispatchClass},
+    weights::Weight,
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Weight consumed by a single extrinsic.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExtrinsicWeight {
+    /// Index of the extrinsic in the block.
+    pub extrinsic_index: u32,
+    /// Dispatch class of the extrinsic.
+    pub class: DispatchClass,
+    /// Actual (post-dispatch) weight consumed by the extrinsic.
+    pub weight: Weight,
+    /// `true` if the extrinsic was dispatched successfully, `false` otherwise.
+    pub success: bool,
+}
+
+impl ExtrinsicWeight {
+    /// Create a new record from the dispatch info reported in the extrinsic's `ExtrinsicSuccess` or `ExtrinsicFailed` event.
+    pub fn new(extrinsic_index: u32, info: &DispatchInfo, success: bool) -> Self {
+        Self {
+            extrinsic_index,
+            class: info.class,
+            weight: info.weight,
+            success,
+        }
+    }
+}
+
+/// Block production diagnostics.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BlockDiagnostics {
+    /// Total weight & proof size consumed by the block, per dispatch class.
+    /// Includes the base extrinsic weight, as well as the weight of the block hooks.
+    pub consumed: PerDispatchClass<Weight>,
+    /// Maximum weight & proof size of the block.
+    pub max_block: Weight,
+    /// Number of extrinsics included in the block.
+    pub extrinsics_count: u32,
+    /// Heaviest extrinsics included in the block, in descending order.
+    pub heaviest_extrinsics: Vec<ExtrinsicWeight>,
+}
+
+impl BlockDiagnostics {
+    /// Create block diagnostics, keeping at most `limit` heaviest extrinsics.
+    ///
+    /// Extrinsics are ordered by the block limit fraction they've consumed, taking into account
+    /// both the `ref_time` and the `proof_size` dimensions, whichever is larger.
+    pub fn new(
+        consumed: PerDispatchClass<Weight>,
+        max_block: Weight,
+        mut extrinsics: Vec<ExtrinsicWeight>,
+        limit: u32,
+    ) -> Self {
+        let extrinsics_count = extrinsics.len() as u32;
+
+        let block_fraction = |weight: &Weight| {
+            Perbill::from_rational(weight.ref_time(), max_block.ref_time().max(1)).max(
+                Perbill::from_rational(weight.proof_size(), max_block.proof_size().max(1)),
+            )
+        };
+        extrinsics.sort_by(|a, b| {
+            block_fraction(&b.weight)
+                .cmp(&block_fraction(&a.weight))
+                .then(a.extrinsic_index.cmp(&b.extrinsic_index))
+        });
+        extrinsics.truncate(limit as usize);
+
+        Self {
+            consumed,
+            max_block,
+            extrinsics_count,
+            heaviest_extrinsics: extrinsics,
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Block Diagnostics Api.
+    ///
+    /// Used to provide information about the block fullness, without the need to re-execute the block.
+    /// Must be called on top of the block which is being investigated.
+    pub trait BlockDiagnosticsApi {
+
+        /// Get the block production diagnostics, including at most `max_extrinsics` heaviest extrinsics.
+        fn block_diagnostics(max_extrinsics: u32) -> BlockDiagnostics;
+    }
+}
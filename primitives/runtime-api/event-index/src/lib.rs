@@ -0,0 +1,73 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API which provides the notable events of the block in a runtime agnostic form.
+//!
+//! Used by the node's event indexer, so historical events can be filtered by category
+//! & involved account without running an external indexer.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Category of the indexed event.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum EventCategory {
+    /// Native or asset transfer.
+    Transfer,
+    /// dApp staking event.
+    DappStaking,
+    /// Cross-chain messaging event.
+    Xcm,
+}
+
+/// Event indexed for the later queries.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct IndexedEvent<AccountId> {
+    /// Index of the event in the block events.
+    pub event_index: u32,
+    /// Index of the extrinsic which emitted the event, if any.
+    pub extrinsic_index: Option<u32>,
+    /// Category of the event.
+    pub category: EventCategory,
+    /// Accounts involved in the event.
+    pub accounts: Vec<AccountId>,
+    /// SCALE encoded runtime event.
+    pub event: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Event Index Api.
+    ///
+    /// Used to provide the notable events of the block, i.e. transfers, dApp staking & XCM events.
+    /// Must be called on top of the block which emitted the events.
+    pub trait EventIndexApi<AccountId: Codec> {
+
+        /// Get the notable events of the block.
+        fn indexed_events() -> Vec<IndexedEvent<AccountId>>;
+    }
+}
@@ -0,0 +1,59 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API which combines the on-chain identity of an account with its unified account mappings.
+//!
+//! Mappings between native & EVM addresses are created by proving the ownership of both,
+//! so explorers can use this API to display verified `H160 <> identity` associations.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Identity of the native account, together with the EVM addresses mapped to it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UnifiedIdentity<AccountId, Identity> {
+    /// Native account.
+    pub account_id: AccountId,
+    /// Primary EVM address mapped to the native account, if any.
+    pub evm_address: Option<H160>,
+    /// Secondary EVM addresses mapped to the native account.
+    pub secondary_evm_addresses: Vec<H160>,
+    /// Identity registered for the native account, if any.
+    pub identity: Option<Identity>,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Unified Identity Api.
+    ///
+    /// Used to provide the identity information together with the unified account mappings.
+    pub trait UnifiedIdentityApi<AccountId: Codec, Identity: Codec> {
+
+        /// Get the identity & mapped EVM addresses of the native account.
+        fn identity_of(account_id: AccountId) -> UnifiedIdentity<AccountId, Identity>;
+
+        /// Get the identity & mapped EVM addresses of the native account to which the EVM address is mapped.
+        /// `None` if the EVM address isn't mapped.
+        fn identity_of_evm_address(evm_address: H160) -> Option<UnifiedIdentity<AccountId, Identity>>;
+    }
+}
@@ -0,0 +1,89 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API which provides a unified view of the extrinsic execution.
+//!
+//! Substrate events & EVM logs/receipts produced by a single extrinsic are correlated,
+//! so explorers can present cross-VM execution (e.g. EVM calls made via dispatch or XVM) as a single trace.
+
+use ethereum::{Log, ReceiptV3 as EthereumReceipt};
+use fp_rpc::TransactionStatus;
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// EVM log emitted during the extrinsic execution.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EvmLogRecord {
+    /// Index of the corresponding event in the block events.
+    pub event_index: u32,
+    /// The EVM log.
+    pub log: Log,
+}
+
+/// Ethereum transaction executed as part of the extrinsic.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EthereumTransactionRecord {
+    /// Index of the `Executed` event in the block events.
+    pub event_index: u32,
+    /// Transaction status, including the produced logs.
+    pub status: TransactionStatus,
+    /// Transaction receipt.
+    pub receipt: Option<EthereumReceipt>,
+}
+
+/// Unified execution trace of a single extrinsic.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExtrinsicTrace<Event> {
+    /// Index of the extrinsic in the block.
+    pub extrinsic_index: u32,
+    /// All events emitted by the extrinsic, together with their index in the block events.
+    pub events: Vec<(u32, Event)>,
+    /// All EVM logs emitted by the extrinsic, regardless of the execution path.
+    pub evm_logs: Vec<EvmLogRecord>,
+    /// Ethereum transactions executed by the extrinsic.
+    pub ethereum_transactions: Vec<EthereumTransactionRecord>,
+}
+
+impl<Event> ExtrinsicTrace<Event> {
+    /// Create a new, empty, trace for the extrinsic.
+    pub fn new(extrinsic_index: u32) -> Self {
+        Self {
+            extrinsic_index,
+            events: Vec::new(),
+            evm_logs: Vec::new(),
+            ethereum_transactions: Vec::new(),
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// Unified Trace Api.
+    ///
+    /// Used to provide correlated Substrate & EVM execution information of the block extrinsics.
+    /// Must be called on top of the block in which the extrinsic was included.
+    pub trait UnifiedTraceApi<RuntimeEvent: Codec> {
+
+        /// Get the unified execution trace of the extrinsic with the given index.
+        fn extrinsic_trace(extrinsic_index: u32) -> ExtrinsicTrace<RuntimeEvent>;
+    }
+}
@@ -85,6 +85,7 @@ where
                         RawOrigin::Root.into(),
                         Box::new(location.clone().into_versioned()),
                         (i as u128).into(),
+                        None,
                     )
                 );
                 assert_ok!(
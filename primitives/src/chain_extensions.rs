@@ -0,0 +1,120 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Registry of the chain extensions available to the WASM contracts.
+//!
+//! Every chain extension is assigned a unique [`ChainExtensionId`], which is the upper half of the
+//! `func_id` used by the contracts, so each extension has its own namespace of function ids.
+//! Since the Ids are enum discriminants, assigning the same Id twice fails to compile.
+//!
+//! Runtimes declare their set of chain extensions with the [`chain_extensions!`](crate::chain_extensions!) macro,
+//! which registers each extension under its Id and ensures no extension is included twice.
+
+pub use pallet_contracts::chain_extension::RegisteredChainExtension;
+
+/// Ids of the chain extensions, shared by all runtimes.
+///
+/// Ids of the removed chain extensions must never be reused, since deployed contracts may still call them.
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChainExtensionId {
+    Assets = 2,
+    UnifiedAccounts = 3,
+    PriceAggregator = 4,
+    DappStaking = 5,
+    Xcm = 6,
+    Scheduler = 7,
+    SignatureVerification = 8,
+    Nfts = 9,
+    Governance = 10,
+    Evm = 11,
+}
+
+/// `true` if none of the `ids` is repeated, `false` otherwise.
+pub const fn are_unique(ids: &[ChainExtensionId]) -> bool {
+    let mut i = 0;
+    while i < ids.len() {
+        let mut j = i + 1;
+        while j < ids.len() {
+            if ids[i] as u16 == ids[j] as u16 {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Declare the set of chain extensions of the runtime.
+///
+/// Registers every extension under the given [`ChainExtensionId`] variant, and defines the type,
+/// to be used as the `pallet_contracts::Config::ChainExtension`.
+/// Fails to compile if the same Id is used more than once.
+///
+/// ```ignore
+/// astar_primitives::chain_extensions! {
+///     Runtime;
+///     pub type RuntimeChainExtensions = {
+///         Assets => AssetsExtension<Runtime>,
+///         DappStaking => DappStakingExtension<Runtime>,
+///     };
+/// }
+/// ```
+#[macro_export]
+macro_rules! chain_extensions {
+    (
+        $runtime:ty;
+        $(#[$attr:meta])*
+        $vis:vis type $name:ident = {
+            $( $id:ident => $extension:ty ),* $(,)?
+        };
+    ) => {
+        $(
+            impl $crate::chain_extensions::RegisteredChainExtension<$runtime> for $extension {
+                const ID: u16 = $crate::chain_extensions::ChainExtensionId::$id as u16;
+            }
+        )*
+
+        $(#[$attr])*
+        $vis type $name = ( $( $extension, )* );
+
+        const _: () = assert!(
+            $crate::chain_extensions::are_unique(&[
+                $( $crate::chain_extensions::ChainExtensionId::$id ),*
+            ]),
+            "Chain extension Ids must be unique.",
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn are_unique_works() {
+        use ChainExtensionId::*;
+
+        assert!(are_unique(&[]));
+        assert!(are_unique(&[Assets]));
+        assert!(are_unique(&[Assets, DappStaking, Xcm]));
+        assert!(!are_unique(&[Assets, DappStaking, Assets]));
+        assert!(!are_unique(&[Xcm, Xcm]));
+    }
+}
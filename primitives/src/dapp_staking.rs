@@ -25,7 +25,7 @@ use sp_arithmetic::ArithmeticError;
 use sp_core::H160;
 use sp_runtime::{
     traits::{UniqueSaturatedInto, Zero},
-    FixedPointNumber,
+    FixedPointNumber, Perbill,
 };
 use sp_std::hash::Hash;
 
@@ -114,6 +114,26 @@ pub trait Observer {
 
 impl Observer for () {}
 
+/// Interface for the fee sponsorship pool, which can be funded with a share of the dApp rewards.
+///
+/// dApps opt into sponsorship, and choose the share of their rewards, via the sponsorship pool itself.
+pub trait SponsorshipPool<AccountId, SmartContract> {
+    /// Account into which sponsorship funds for the given smart contract should be deposited,
+    /// and the share of the dApp rewards which should be deposited into it.
+    ///
+    /// `None` if the smart contract didn't opt into sponsorship, or sponsorship isn't available.
+    fn sponsorship(smart_contract: &SmartContract) -> Option<(AccountId, Perbill)>;
+
+    /// Called after the sponsorship pool of the smart contract has been funded with `amount`.
+    fn on_funded(_smart_contract: &SmartContract, _amount: Balance) {}
+}
+
+impl<AccountId, SmartContract> SponsorshipPool<AccountId, SmartContract> for () {
+    fn sponsorship(_smart_contract: &SmartContract) -> Option<(AccountId, Perbill)> {
+        None
+    }
+}
+
 /// Interface for staking reward handler.
 ///
 /// Provides reward pool values for stakers - normal & bonus rewards, as well as dApp reward pool.
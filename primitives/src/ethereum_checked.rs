@@ -31,19 +31,43 @@ pub const MAX_ETHEREUM_TX_INPUT_SIZE: u32 = 2u32.pow(16);
 
 pub type EthereumTxInput = BoundedVec<u8, ConstU32<MAX_ETHEREUM_TX_INPUT_SIZE>>;
 
-/// The checked Ethereum transaction. Only contracts `call` is support(no `create`).
+/// Dynamic fee (EIP-1559) parameters of a checked Ethereum transaction.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CheckedEthereumTxFee {
+    /// Max fee per gas, including the base fee.
+    pub max_fee_per_gas: U256,
+    /// Max priority fee per gas, paid on top of the base fee.
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// The action of a checked Ethereum transaction.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CheckedEthereumTxAction {
+    /// Call the contract at the address.
+    Call(H160),
+    /// Create a contract, with the tx input as its init code.
+    Create,
+}
+
+/// The checked Ethereum transaction, either a contract call or a contract creation.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct CheckedEthereumTx {
     /// Gas limit.
     pub gas_limit: U256,
-    /// Contract address to call.
-    pub target: H160,
+    /// Contract address to call, or contract creation.
+    pub action: CheckedEthereumTxAction,
     /// Amount to transfer.
     pub value: U256,
-    /// Input of a contract call.
+    /// Input of a contract call, or init code of a contract creation.
     pub input: EthereumTxInput,
     /// Optional access list, specified in EIP-2930.
     pub maybe_access_list: Option<Vec<(H160, Vec<H256>)>>,
+    /// Optional dynamic fee, specified in EIP-1559. If not set, the tx is free of EVM fees
+    /// & its execution is paid by the origin of the checked source instead.
+    pub maybe_fee: Option<CheckedEthereumTxFee>,
+    /// Optional expected checked tx nonce of the source. If set, the tx is rejected unless
+    /// it matches, so a failed remote call can be retried deterministically.
+    pub maybe_nonce: Option<U256>,
 }
 
 impl CheckedEthereumTx {
@@ -59,14 +83,22 @@ impl CheckedEthereumTx {
             Vec::new()
         };
 
+        let (max_fee_per_gas, max_priority_fee_per_gas) = match self.maybe_fee {
+            Some(ref fee) => (fee.max_fee_per_gas, fee.max_priority_fee_per_gas),
+            None => (U256::zero(), U256::zero()),
+        };
+
         Transaction::EIP1559(EIP1559Transaction {
             chain_id,
             nonce,
-            max_fee_per_gas: U256::zero(),
-            max_priority_fee_per_gas: U256::zero(),
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
             gas_limit: self.gas_limit,
             value: self.value,
-            action: TransactionAction::Call(self.target),
+            action: match self.action {
+                CheckedEthereumTxAction::Call(target) => TransactionAction::Call(target),
+                CheckedEthereumTxAction::Create => TransactionAction::Create,
+            },
             input: self.input.to_vec(),
             access_list,
             odd_y_parity: true,
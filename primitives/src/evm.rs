@@ -19,13 +19,15 @@
 use crate::{AccountId, AssetId};
 
 use frame_support::{
+    dispatch::DispatchClass,
     ensure,
     traits::{
         fungible::{Balanced, Credit},
         tokens::{fungible::Inspect, imbalance::OnUnbalanced},
+        Get,
     },
 };
-use pallet_evm::{AddressMapping, HashedAddressMapping, OnChargeEVMTransaction};
+use pallet_evm::{AddressMapping, GasWeightMapping, HashedAddressMapping, OnChargeEVMTransaction};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::{Hasher, H160, H256, U256};
@@ -154,8 +156,8 @@ impl<Address> UnifiedAddress<Address> {
 /// This adapter allows us to do that.
 ///
 /// Two separate `OnUnbalanced` handers are used:
-/// - `UOF` for the fee
-/// - `OUT` for the tip
+/// - `FeeHandler` for the base fee
+/// - `TipHandler` for the priority fee (tip), e.g. [`ToBlockAuthor`] to reward the collator which included the transaction
 pub struct EVMFungibleAdapterWrapper<F, FeeHandler, TipHandler>(
     core::marker::PhantomData<(F, FeeHandler, TipHandler)>,
 );
@@ -195,3 +197,37 @@ where
         }
     }
 }
+
+/// Deposits the received amount into the account of the current block author.
+///
+/// In case the author is unknown, or the deposit fails, the amount is handed over to the `Fallback` handler.
+pub struct ToBlockAuthor<T, F, Fallback>(PhantomData<(T, F, Fallback)>);
+impl<T, F, Fallback> OnUnbalanced<Credit<T::AccountId, F>> for ToBlockAuthor<T, F, Fallback>
+where
+    T: pallet_authorship::Config,
+    F: Balanced<T::AccountId>,
+    Fallback: OnUnbalanced<Credit<T::AccountId, F>>,
+{
+    fn on_nonzero_unbalanced(amount: Credit<T::AccountId, F>) {
+        let amount = match pallet_authorship::Pallet::<T>::author() {
+            Some(author) => match F::resolve(&author, amount) {
+                Ok(()) => return,
+                Err(amount) => amount,
+            },
+            None => amount,
+        };
+
+        Fallback::on_unbalanced(amount);
+    }
+}
+
+/// Gas used in the current block, derived from the weight consumed by the normal dispatch class.
+///
+/// Since native transactions consume the same block space as EVM transactions, they're also taken into account.
+pub struct BlockGasUsed<T>(PhantomData<T>);
+impl<T: pallet_evm::Config> Get<U256> for BlockGasUsed<T> {
+    fn get() -> U256 {
+        let normal_weight = *frame_system::Pallet::<T>::block_weight().get(DispatchClass::Normal);
+        U256::from(T::GasWeightMapping::weight_to_gas(normal_weight))
+    }
+}
@@ -50,6 +50,9 @@ pub mod genesis;
 /// Parachain related constants.
 pub mod parachain;
 
+/// Chain extensions registry.
+pub mod chain_extensions;
+
 /// Benchmark primitives
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarks;
@@ -84,6 +87,10 @@ pub type Hash = sp_core::H256;
 /// [2^64; 2^128-1] Ecosystem assets
 /// 2^128-1         Relay chain token (KSM)
 pub type AssetId = u128;
+/// Id used for identifying non-fungible collections.
+pub type CollectionId = u128;
+/// Id used for identifying non-fungible items within a collection.
+pub type ItemId = u128;
 /// Block type.
 pub type Block = sp_runtime::generic::Block<Header, sp_runtime::OpaqueExtrinsic>;
 /// Index of a transaction in the chain.
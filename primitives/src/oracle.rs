@@ -16,7 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{pallet_prelude::*, traits::Time};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{SortedMembers, Time},
+};
 use sp_arithmetic::fixed_point::FixedU128;
 use sp_std::vec::Vec;
 
@@ -24,15 +27,117 @@ use sp_std::vec::Vec;
 pub trait PriceProvider {
     /// Get the price of the native token.
     fn average_price() -> Price;
+
+    /// Get the price of the native token, or an error if the price isn't reliable, e.g. it's stale.
+    ///
+    /// By default, the `average_price` is considered to always be reliable.
+    fn try_average_price() -> Result<Price, PriceError> {
+        Ok(Self::average_price())
+    }
+}
+
+/// Interface for fetching price of any currency tracked by the price provider.
+pub trait CurrencyPriceProvider {
+    /// Get the price of the specified currency, or `None` if the currency isn't tracked.
+    fn average_price_of(currency: CurrencyId) -> Option<Price>;
+
+    /// Get the latest (spot) price of the specified currency, or `None` if it's not available.
+    fn spot_price_of(currency: CurrencyId) -> Option<Price>;
+
+    /// Get the time-weighted average price of the specified currency, or `None` if it's not available.
+    fn twap_price_of(currency: CurrencyId) -> Option<Price>;
 }
 
 pub type Price = FixedU128;
 pub type CurrencyAmount = FixedU128;
 
+/// Reason why the price provider couldn't provide a reliable price.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PriceError {
+    /// No valid price has been received for too long.
+    Stale,
+}
+
+/// Behavior of the price provider once the price becomes stale.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum StalePriceBehavior {
+    /// Provide the specified fallback price instead of the stale one.
+    Fallback(Price),
+    /// Report the `PriceError::Stale` error, and keep providing the last known price where an error can't be reported.
+    Error,
+}
+
+/// Currency whose price is tracked by the oracle.
+///
+/// All prices are quoted in USD, so each currency ID uniquely identifies a currency pair,
+/// e.g. `DOT` stands for the `DOT/USD` pair.
+///
+/// New variants must only ever be appended, to keep the encoding of existing ones intact.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum CurrencyId {
     ASTR,
     SDN,
+    DOT,
+    ETH,
+}
+
+/// Source of the oracle feeder set.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum OracleMembershipMode {
+    /// Feeders are managed manually, via the membership pallet.
+    Manual,
+    /// Feeders are the active collators of the current session.
+    ActiveCollators,
+    /// Feeders are the manually managed members which are also active collators of the current session.
+    ActiveCollatorMembers,
+}
+
+/// Oracle feeder set, derived according to the `Mode`.
+///
+/// `Manual` provides the manually managed members, while `Collators` provides the active collators,
+/// which are updated each session.
+pub struct OracleMembers<Manual, Collators, Mode>(PhantomData<(Manual, Collators, Mode)>);
+impl<AccountId, Manual, Collators, Mode> SortedMembers<AccountId>
+    for OracleMembers<Manual, Collators, Mode>
+where
+    AccountId: Ord + Clone,
+    Manual: SortedMembers<AccountId>,
+    Collators: Get<Vec<AccountId>>,
+    Mode: Get<OracleMembershipMode>,
+{
+    fn sorted_members() -> Vec<AccountId> {
+        match Mode::get() {
+            OracleMembershipMode::Manual => Manual::sorted_members(),
+            OracleMembershipMode::ActiveCollators => {
+                let mut collators = Collators::get();
+                collators.sort();
+                collators.dedup();
+                collators
+            }
+            OracleMembershipMode::ActiveCollatorMembers => {
+                let collators = Collators::get();
+                Manual::sorted_members()
+                    .into_iter()
+                    .filter(|member| collators.contains(member))
+                    .collect()
+            }
+        }
+    }
+
+    fn contains(who: &AccountId) -> bool {
+        match Mode::get() {
+            OracleMembershipMode::Manual => Manual::contains(who),
+            OracleMembershipMode::ActiveCollators => Collators::get().contains(who),
+            OracleMembershipMode::ActiveCollatorMembers => {
+                Manual::contains(who) && Collators::get().contains(who)
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        Manual::add(who)
+    }
 }
 
 type TimestampedValue<T, I = ()> =
@@ -51,3 +156,48 @@ impl<T: orml_oracle::Config<I>, I> orml_traits::CombineData<CurrencyId, Timestam
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::parameter_types;
+
+    parameter_types! {
+        pub static Mode: OracleMembershipMode = OracleMembershipMode::Manual;
+        pub static Collators: Vec<u32> = vec![5, 3, 1];
+    }
+
+    pub struct ManualMembers;
+    impl SortedMembers<u32> for ManualMembers {
+        fn sorted_members() -> Vec<u32> {
+            vec![1, 2, 3]
+        }
+    }
+
+    type Members = OracleMembers<ManualMembers, Collators, Mode>;
+
+    #[test]
+    fn oracle_members_follow_mode() {
+        Mode::set(OracleMembershipMode::Manual);
+        assert_eq!(Members::sorted_members(), vec![1, 2, 3]);
+        assert!(Members::contains(&2));
+        assert!(!Members::contains(&5));
+
+        Mode::set(OracleMembershipMode::ActiveCollators);
+        assert_eq!(Members::sorted_members(), vec![1, 3, 5]);
+        assert!(!Members::contains(&2));
+        assert!(Members::contains(&5));
+
+        Mode::set(OracleMembershipMode::ActiveCollatorMembers);
+        assert_eq!(Members::sorted_members(), vec![1, 3]);
+        assert!(Members::contains(&3));
+        assert!(!Members::contains(&2));
+        assert!(!Members::contains(&5));
+
+        // Derived set follows the collator set changes
+        Collators::set(vec![2]);
+        assert_eq!(Members::sorted_members(), vec![2]);
+        Mode::set(OracleMembershipMode::ActiveCollators);
+        assert_eq!(Members::sorted_members(), vec![2]);
+    }
+}
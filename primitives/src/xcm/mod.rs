@@ -23,9 +23,14 @@
 //! Collection of common XCM primitives used by runtimes.
 //!
 //! - `AssetLocationIdConverter` - conversion between local asset Id and cross-chain asset multilocation
+//! - `NftCollectionLocationIdConverter` - conversion between local NFT collection Id and cross-chain collection multilocation
+//! - `ExcludeFrozenAssets` - used to exclude frozen cross-chain assets from being matched by the asset transactor
 //! - `FixedRateOfForeignAsset` - weight trader for execution payment in foreign asset
 //! - `ReserveAssetFilter` - used to check whether asset/origin are a valid reserve location
+//! - `EthereumAssetReserveFilter` - used to check whether bridged Ethereum asset is coming from a valid reserve location
+//! - `EthereumAwareReserveProvider` - used to provide the reserve location of an asset, including bridged Ethereum assets
 //! - `XcmFungibleFeeHandler` - used to handle XCM fee execution fees
+//! - `AssetsRegistrar` - used to create the local asset representation of a cross-chain asset
 //!
 //! Please refer to implementation below for more info.
 //!
@@ -33,22 +38,27 @@
 use crate::AccountId;
 
 use frame_support::{
+    dispatch::{DispatchResult, RawOrigin},
     ensure,
     traits::{tokens::fungibles, Contains, ContainsPair, Get, ProcessMessageError},
     weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 };
-use sp_runtime::traits::{Bounded, Convert, MaybeEquivalence, Zero};
-use sp_std::marker::PhantomData;
+use sp_runtime::traits::{Bounded, Convert, MaybeEquivalence, StaticLookup, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 // Polkadot imports
 use xcm::latest::{prelude::*, Weight};
 use xcm_builder::{CreateMatcher, MatchXcm, TakeRevenue};
-use xcm_executor::traits::{MatchesFungibles, Properties, ShouldExecute, WeightTrader};
+use xcm_executor::traits::{
+    Error as MatchError, MatchesFungibles, Properties, ShouldExecute, WeightTrader,
+};
 
 // ORML imports
 use orml_traits::location::{RelativeReserveProvider, Reserve};
 
-use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetLocation};
+use pallet_xc_asset_config::{
+    AssetMetadata, AssetRegistrar, ExecutionPaymentRate, XcAssetLocation, XcNftCollectionLocation,
+};
 
 #[cfg(test)]
 mod tests;
@@ -76,10 +86,61 @@ where
     }
 }
 
+/// Used to convert between cross-chain NFT collection multilocation and local collection Id.
+///
+/// This implementation relies on `XcAssetConfig` pallet to handle mapping.
+/// In case collection location hasn't been mapped, it means its items can't be transferred cross-chain.
+pub struct NftCollectionLocationIdConverter<CollectionId, CollectionMapper>(
+    PhantomData<(CollectionId, CollectionMapper)>,
+);
+impl<CollectionId, CollectionMapper> MaybeEquivalence<Location, CollectionId>
+    for NftCollectionLocationIdConverter<CollectionId, CollectionMapper>
+where
+    CollectionId: Clone,
+    CollectionMapper: XcNftCollectionLocation<CollectionId>,
+{
+    fn convert(location: &Location) -> Option<CollectionId> {
+        CollectionMapper::get_collection_id(location.clone())
+    }
+
+    fn convert_back(id: &CollectionId) -> Option<Location> {
+        CollectionMapper::get_collection_location(id.clone())
+    }
+}
+
+/// Used to match fungible assets, excluding the frozen ones.
+///
+/// Frozen assets aren't handled by the asset transactor, which means they can neither be deposited
+/// (i.e. minted when received via reserve transfer) nor withdrawn (i.e. burned when sent to another chain).
+/// Assets received while frozen remain trapped, and can be claimed once the asset is thawed.
+pub struct ExcludeFrozenAssets<Matcher, Frozen>(PhantomData<(Matcher, Frozen)>);
+impl<AssetId, Balance, Matcher, Frozen> MatchesFungibles<AssetId, Balance>
+    for ExcludeFrozenAssets<Matcher, Frozen>
+where
+    Matcher: MatchesFungibles<AssetId, Balance>,
+    Frozen: Contains<Location>,
+{
+    fn matches_fungibles(asset: &Asset) -> Result<(AssetId, Balance), MatchError> {
+        if Frozen::contains(&asset.id.0) {
+            log::trace!(
+                target: "xcm::matches_fungibles",
+                "ExcludeFrozenAssets asset: {:?} is frozen",
+                asset,
+            );
+            return Err(MatchError::AssetNotHandled);
+        }
+
+        Matcher::matches_fungibles(asset)
+    }
+}
+
 /// Used as weight trader for foreign assets.
 ///
 /// In case foreigin asset is supported as payment asset, XCM execution time
 /// on-chain can be paid by the foreign asset, using the configured rate.
+///
+/// Payment assets aren't fixed - any asset from the holding which is registered as a payment asset
+/// (i.e. has `units per second` configured) can be used, so newly registered assets become usable immediately.
 pub struct FixedRateOfForeignAsset<T: ExecutionPaymentRate, R: TakeRevenue> {
     /// Total used weight
     weight: Weight,
@@ -112,52 +173,59 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeig
             weight, payment,
         );
 
-        // Atm in pallet, we only support one asset so this should work
-        let payment_asset = payment
+        // Any asset from the holding which is registered as a payment asset can be used to pay for the execution.
+        // In case multiple such assets can cover the fee, the one which was already used for payment is preferred,
+        // otherwise the first one is used.
+        let affordable: Vec<(Location, u128, u128)> = payment
             .fungible_assets_iter()
-            .next()
-            .ok_or(XcmError::TooExpensive)?;
-
-        match payment_asset {
-            Asset {
-                id: AssetId(asset_location),
-                fun: Fungibility::Fungible(_),
-            } => {
-                if let Some(units_per_second) = T::get_units_per_second(asset_location.clone()) {
+            .filter_map(|asset| match asset {
+                Asset {
+                    id: AssetId(asset_location),
+                    fun: Fungibility::Fungible(balance),
+                } => {
+                    let units_per_second = T::get_units_per_second(asset_location.clone())?;
                     let amount = units_per_second.saturating_mul(weight.ref_time() as u128) // TODO: change this to u64?
                         / (WEIGHT_REF_TIME_PER_SECOND as u128);
-                    if amount == 0 {
-                        return Ok(payment);
-                    }
+                    (balance >= amount).then_some((asset_location, units_per_second, amount))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let (asset_location, units_per_second, amount) = affordable
+            .iter()
+            .find(|(asset_location, _, _)| {
+                self.asset_location_and_units_per_second
+                    .as_ref()
+                    .map_or(false, |(used_location, _)| used_location == asset_location)
+            })
+            .or_else(|| affordable.first())
+            .cloned()
+            .ok_or(XcmError::TooExpensive)?;
 
-                    let unused = payment
-                        .checked_sub((asset_location.clone(), amount).into())
-                        .map_err(|_| XcmError::TooExpensive)?;
+        if amount == 0 {
+            return Ok(payment);
+        }
 
-                    self.weight = self.weight.saturating_add(weight);
+        let unused = payment
+            .checked_sub((asset_location.clone(), amount).into())
+            .map_err(|_| XcmError::TooExpensive)?;
 
-                    // If there are multiple calls to `BuyExecution` but with different assets, we need to be able to handle that.
-                    // Current primitive implementation will just keep total track of consumed asset for the FIRST consumed asset.
-                    // Others will just be ignored when refund is concerned.
-                    if let Some((old_asset_location, _)) =
-                        self.asset_location_and_units_per_second.clone()
-                    {
-                        if old_asset_location == asset_location {
-                            self.consumed = self.consumed.saturating_add(amount);
-                        }
-                    } else {
-                        self.consumed = self.consumed.saturating_add(amount);
-                        self.asset_location_and_units_per_second =
-                            Some((asset_location, units_per_second));
-                    }
+        self.weight = self.weight.saturating_add(weight);
 
-                    Ok(unused)
-                } else {
-                    Err(XcmError::TooExpensive)
-                }
+        // If there are multiple calls to `BuyExecution` but with different assets, we need to be able to handle that.
+        // Current primitive implementation will just keep total track of consumed asset for the FIRST consumed asset.
+        // Others will just be ignored when refund is concerned.
+        if let Some((old_asset_location, _)) = self.asset_location_and_units_per_second.clone() {
+            if old_asset_location == asset_location {
+                self.consumed = self.consumed.saturating_add(amount);
             }
-            _ => Err(XcmError::TooExpensive),
+        } else {
+            self.consumed = self.consumed.saturating_add(amount);
+            self.asset_location_and_units_per_second = Some((asset_location, units_per_second));
         }
+
+        Ok(unused)
     }
 
     fn refund_weight(&mut self, weight: Weight, _: &XcmContext) -> Option<Asset> {
@@ -220,6 +288,32 @@ impl ContainsPair<Asset, Location> for ReserveAssetFilter {
     }
 }
 
+/// Used to determine whether the bridged Ethereum asset is coming from a trusted reserve or not.
+///
+/// Assets originating from the `EthereumNetwork` (e.g. bridged via Snowbridge) are represented by
+/// a location like `{parents: 2, interior: X2(GlobalConsensus(Ethereum { chain_id }), AccountKey20 { .. })}`.
+/// Such assets are not received from Ethereum directly, but are instead forwarded by the `BridgeReserve`
+/// (e.g. Asset Hub), which is the only origin trusted to act as a reserve for them.
+pub struct EthereumAssetReserveFilter<EthereumNetwork, BridgeReserve>(
+    PhantomData<(EthereumNetwork, BridgeReserve)>,
+);
+impl<EthereumNetwork: Get<NetworkId>, BridgeReserve: Get<Location>> ContainsPair<Asset, Location>
+    for EthereumAssetReserveFilter<EthereumNetwork, BridgeReserve>
+{
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        is_ethereum_asset_location::<EthereumNetwork>(&asset.id.0)
+            && origin == &BridgeReserve::get()
+    }
+}
+
+/// `true` if the location represents the `EthereumNetwork` itself or any asset originating from it, `false` otherwise.
+pub fn is_ethereum_asset_location<EthereumNetwork: Get<NetworkId>>(location: &Location) -> bool {
+    match (location.parents, location.first_interior()) {
+        (2, Some(GlobalConsensus(network))) => *network == EthereumNetwork::get(),
+        _ => false,
+    }
+}
+
 /// Used to deposit XCM fees into a destination account.
 ///
 /// Only handles fungible assets for now.
@@ -294,6 +388,75 @@ impl<AbsoluteLocation: Get<Location>> Reserve
     }
 }
 
+/// `Asset` reserve location provider, aware of the bridged Ethereum assets.
+///
+/// Reserve of the assets originating from the `EthereumNetwork` is the `BridgeReserve` (e.g. Asset Hub),
+/// since Ethereum isn't directly reachable. Reserve of any other asset is provided by the `Fallback` provider.
+pub struct EthereumAwareReserveProvider<EthereumNetwork, BridgeReserve, Fallback>(
+    PhantomData<(EthereumNetwork, BridgeReserve, Fallback)>,
+);
+impl<EthereumNetwork: Get<NetworkId>, BridgeReserve: Get<Location>, Fallback: Reserve> Reserve
+    for EthereumAwareReserveProvider<EthereumNetwork, BridgeReserve, Fallback>
+{
+    fn reserve(asset: &Asset) -> Option<Location> {
+        if is_ethereum_asset_location::<EthereumNetwork>(&asset.id.0) {
+            Some(BridgeReserve::get())
+        } else {
+            Fallback::reserve(asset)
+        }
+    }
+}
+
+/// Creates the local `pallet-assets` representation of a cross-chain asset, owned by `Owner`.
+///
+/// Asset is created as sufficient, since it can be received via XCM by accounts without any native balance.
+pub struct AssetsRegistrar<T, Owner>(PhantomData<(T, Owner)>);
+impl<T, Owner> AssetRegistrar<<T as pallet_assets::Config>::AssetId> for AssetsRegistrar<T, Owner>
+where
+    T: pallet_assets::Config,
+    <T as pallet_assets::Config>::AssetIdParameter: From<<T as pallet_assets::Config>::AssetId>,
+    <T as pallet_assets::Config>::Balance: From<u128>,
+    Owner: Get<T::AccountId>,
+{
+    fn create_asset(
+        asset_id: <T as pallet_assets::Config>::AssetId,
+        metadata: AssetMetadata,
+    ) -> DispatchResult {
+        pallet_assets::Pallet::<T>::force_create(
+            RawOrigin::Root.into(),
+            asset_id.clone().into(),
+            T::Lookup::unlookup(Owner::get()),
+            true,
+            metadata.min_balance.into(),
+        )?;
+
+        pallet_assets::Pallet::<T>::force_set_metadata(
+            RawOrigin::Root.into(),
+            asset_id.into(),
+            metadata.name,
+            metadata.symbol,
+            metadata.decimals,
+            false,
+        )
+    }
+
+    fn create_asset_weight() -> Weight {
+        use pallet_assets::WeightInfo;
+
+        let string_limit = <T as pallet_assets::Config>::StringLimit::get();
+        <T as pallet_assets::Config>::WeightInfo::force_create().saturating_add(
+            <T as pallet_assets::Config>::WeightInfo::force_set_metadata(
+                string_limit,
+                string_limit,
+            ),
+        )
+    }
+
+    fn asset_exists(asset_id: <T as pallet_assets::Config>::AssetId) -> bool {
+        <pallet_assets::Pallet<T> as fungibles::Inspect<T::AccountId>>::asset_exists(asset_id)
+    }
+}
+
 // Copying the barrier here due to this issue - https://github.com/paritytech/polkadot-sdk/issues/1638
 // The fix was introduced in v1.3.0 via this PR - https://github.com/paritytech/polkadot-sdk/pull/1733
 // Below is the exact same copy from the fix PR.
@@ -57,6 +57,24 @@ impl XcAssetLocation<AssetId> for AssetLocationMapper {
     }
 }
 
+/// Helper struct used for testing `NftCollectionLocationIdConverter`
+struct NftCollectionMapper;
+impl XcNftCollectionLocation<u32> for NftCollectionMapper {
+    fn get_collection_location(collection_id: u32) -> Option<Location> {
+        match collection_id {
+            1 => Some((*GENERAL_INDEX).clone()),
+            _ => None,
+        }
+    }
+
+    fn get_collection_id(collection_location: Location) -> Option<u32> {
+        match collection_location {
+            a if a == (*GENERAL_INDEX).clone() => Some(1),
+            _ => None,
+        }
+    }
+}
+
 /// Helper struct used for testing `FixedRateOfForeignAsset`
 struct ExecutionPayment;
 impl ExecutionPaymentRate for ExecutionPayment {
@@ -98,6 +116,18 @@ fn asset_location_to_id() {
     );
 }
 
+#[test]
+fn nft_collection_location_to_id_and_back() {
+    type Converter = NftCollectionLocationIdConverter<u32, NftCollectionMapper>;
+
+    assert_eq!(Converter::convert(&*GENERAL_INDEX), Some(1));
+    assert_eq!(Converter::convert_back(&1), Some((*GENERAL_INDEX).clone()));
+
+    // Collection which isn't mapped isn't supported
+    assert_eq!(Converter::convert(&*PARACHAIN), None);
+    assert_eq!(Converter::convert_back(&2), None);
+}
+
 #[test]
 fn asset_id_to_location() {
     // Test cases where the AssetId is valid
@@ -121,6 +151,40 @@ fn asset_id_to_location() {
     );
 }
 
+#[test]
+fn exclude_frozen_assets_is_ok() {
+    /// Only the sibling parachain asset is frozen
+    struct FrozenAssets;
+    impl Contains<Location> for FrozenAssets {
+        fn contains(location: &Location) -> bool {
+            *location == *PARACHAIN
+        }
+    }
+
+    type Matcher = xcm_builder::ConvertedConcreteId<
+        AssetId,
+        u128,
+        AssetLocationIdConverter<AssetId, AssetLocationMapper>,
+        xcm_executor::traits::JustTry,
+    >;
+    type FrozenAwareMatcher = ExcludeFrozenAssets<Matcher, FrozenAssets>;
+
+    // Not frozen asset is matched
+    let asset: Asset = (PARENT, 100).into();
+    assert_eq!(
+        FrozenAwareMatcher::matches_fungibles(&asset),
+        Ok((RELAY_ASSET, 100))
+    );
+
+    // Frozen asset isn't matched
+    let asset: Asset = ((*PARACHAIN).clone(), 100).into();
+    assert_eq!(Matcher::matches_fungibles(&asset), Ok((20, 100)));
+    assert_eq!(
+        FrozenAwareMatcher::matches_fungibles(&asset),
+        Err(MatchError::AssetNotHandled)
+    );
+}
+
 #[test]
 fn fixed_rate_of_foreign_asset_buy_is_ok() {
     let mut fixed_rate_trader = FixedRateOfForeignAsset::<ExecutionPayment, ()>::new();
@@ -286,6 +350,103 @@ fn fixed_rate_of_foreign_asset_buy_execution_fails() {
     );
 }
 
+#[test]
+fn fixed_rate_of_foreign_asset_buy_with_any_payment_asset_is_ok() {
+    let mut fixed_rate_trader = FixedRateOfForeignAsset::<ExecutionPayment, ()>::new();
+
+    let total_payment = 10_000;
+    let unsupported_asset = Asset {
+        id: xcm::latest::AssetId(Location::here()),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let parachain_asset = Asset {
+        id: xcm::latest::AssetId((*PARACHAIN).clone()),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let parent_asset = Asset {
+        id: xcm::latest::AssetId(PARENT),
+        fun: Fungibility::Fungible(total_payment),
+    };
+    let weight: Weight = Weight::from_parts(1_000_000_000, 0);
+    let ctx = XcmContext {
+        // arbitary ML
+        origin: Some(Location::here()),
+        message_id: XcmHash::default(),
+        topic: None,
+    };
+
+    // 1. Unsupported asset is ignored, and the registered payment asset is used instead
+    let expected_execution_fee = execution_fee(
+        weight,
+        ExecutionPayment::get_units_per_second((*PARACHAIN).clone()).unwrap(),
+    );
+    assert!(expected_execution_fee > 0); // sanity check
+
+    let payment = vec![unsupported_asset.clone(), parachain_asset.clone()];
+    let result = fixed_rate_trader.buy_weight(weight, payment.into(), &ctx);
+    if let Ok(assets) = result {
+        // Unsupported asset is returned untouched
+        assert_eq!(assets.len(), 2);
+        assert_ok!(assets.ensure_contains(&unsupported_asset.clone().into()));
+        assert_ok!(assets.ensure_contains(
+            &Asset::from(((*PARACHAIN).clone(), total_payment - expected_execution_fee)).into()
+        ));
+
+        assert_eq!(fixed_rate_trader.consumed, expected_execution_fee);
+        assert_eq!(fixed_rate_trader.weight, weight);
+        assert_eq!(
+            fixed_rate_trader.asset_location_and_units_per_second,
+            Some((
+                (*PARACHAIN).clone(),
+                ExecutionPayment::get_units_per_second((*PARACHAIN).clone()).unwrap()
+            ))
+        );
+    } else {
+        panic!("Should have been `Ok` wrapped Assets!");
+    }
+
+    // 2. Multiple payment assets are available, expect the previously used one to be preferred
+    let old_consumed = fixed_rate_trader.consumed;
+    let payment = vec![parent_asset.clone(), parachain_asset.clone()];
+    let result = fixed_rate_trader.buy_weight(weight, payment.into(), &ctx);
+    if let Ok(assets) = result {
+        assert_eq!(assets.len(), 2);
+        assert_ok!(assets.ensure_contains(&parent_asset.clone().into()));
+        assert_ok!(assets.ensure_contains(
+            &Asset::from(((*PARACHAIN).clone(), total_payment - expected_execution_fee)).into()
+        ));
+
+        assert_eq!(
+            fixed_rate_trader.consumed,
+            old_consumed + expected_execution_fee
+        );
+        assert_eq!(fixed_rate_trader.weight, weight + weight);
+    } else {
+        panic!("Should have been `Ok` wrapped Assets!");
+    }
+
+    // 3. Previously used asset can't cover the fee, expect another payment asset to be used
+    let old_consumed = fixed_rate_trader.consumed;
+    let expected_execution_fee = execution_fee(
+        weight,
+        ExecutionPayment::get_units_per_second(PARENT).unwrap(),
+    );
+    let payment = vec![parent_asset.clone(), Asset::from(((*PARACHAIN).clone(), 1))];
+    let result = fixed_rate_trader.buy_weight(weight, payment.into(), &ctx);
+    if let Ok(assets) = result {
+        assert_eq!(assets.len(), 2);
+        assert_ok!(assets.ensure_contains(
+            &Asset::from((PARENT, total_payment - expected_execution_fee)).into()
+        ));
+
+        // Consumed amount is only tracked for the first used asset
+        assert_eq!(fixed_rate_trader.consumed, old_consumed);
+        assert_eq!(fixed_rate_trader.weight, weight * 3);
+    } else {
+        panic!("Should have been `Ok` wrapped Assets!");
+    }
+}
+
 #[test]
 fn fixed_rate_of_foreign_asset_refund_is_ok() {
     let mut fixed_rate_trader = FixedRateOfForeignAsset::<ExecutionPayment, ()>::new();
@@ -425,3 +586,112 @@ fn reserve_asset_filter_for_unsupported_asset_multi_location() {
 
     assert!(!ReserveAssetFilter::contains(&multi_asset, &origin));
 }
+
+frame_support::parameter_types! {
+    pub EthereumNetwork: NetworkId = NetworkId::Ethereum { chain_id: 1 };
+    pub AssetHubLocation: Location = Location::new(1, [Parachain(1000)]);
+}
+
+type EthereumReserveFilter = EthereumAssetReserveFilter<EthereumNetwork, AssetHubLocation>;
+
+/// Location of an asset originating from the `EthereumNetwork`
+fn ethereum_asset_location(chain_id: u64) -> Location {
+    Location::new(
+        2,
+        [
+            GlobalConsensus(NetworkId::Ethereum { chain_id }),
+            AccountKey20 {
+                network: None,
+                key: [7; 20],
+            },
+        ],
+    )
+}
+
+#[test]
+fn ethereum_asset_reserve_filter_is_ok() {
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(ethereum_asset_location(1)),
+        fun: Fungibility::Fungible(123456),
+    };
+
+    assert!(EthereumReserveFilter::contains(
+        &multi_asset,
+        &AssetHubLocation::get()
+    ));
+
+    // Regular reserve filter doesn't trust anyone for the bridged assets
+    assert!(!ReserveAssetFilter::contains(
+        &multi_asset,
+        &AssetHubLocation::get()
+    ));
+}
+
+#[test]
+fn ethereum_asset_reserve_filter_with_origin_mismatch() {
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(ethereum_asset_location(1)),
+        fun: Fungibility::Fungible(123456),
+    };
+
+    // Only the bridge reserve is trusted, not the relay chain or other siblings
+    for origin in [
+        Location::parent(),
+        Location::new(1, [Parachain(1001)]),
+        Location::new(2, [GlobalConsensus(NetworkId::Ethereum { chain_id: 1 })]),
+    ] {
+        assert!(!EthereumReserveFilter::contains(&multi_asset, &origin));
+    }
+}
+
+#[test]
+fn ethereum_asset_reserve_filter_for_unsupported_asset_location() {
+    // Different Ethereum network
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(ethereum_asset_location(11155111)),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert!(!EthereumReserveFilter::contains(
+        &multi_asset,
+        &AssetHubLocation::get()
+    ));
+
+    // Regular sibling parachain asset
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(Location::new(1, [Parachain(1000), GeneralIndex(30)])),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert!(!EthereumReserveFilter::contains(
+        &multi_asset,
+        &AssetHubLocation::get()
+    ));
+}
+
+#[test]
+fn ethereum_aware_reserve_provider_is_ok() {
+    type ReserveProvider = EthereumAwareReserveProvider<
+        EthereumNetwork,
+        AssetHubLocation,
+        AbsoluteAndRelativeReserveProvider<AssetHubLocation>,
+    >;
+
+    // Bridged asset reserve is the bridge reserve
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(ethereum_asset_location(1)),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert_eq!(
+        ReserveProvider::reserve(&multi_asset),
+        Some(AssetHubLocation::get())
+    );
+
+    // Other assets are handled by the fallback provider
+    let multi_asset = Asset {
+        id: xcm::latest::AssetId(Location::new(1, [Parachain(20), GeneralIndex(30)])),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert_eq!(
+        ReserveProvider::reserve(&multi_asset),
+        Some(Location::new(1, [Parachain(20)]))
+    );
+}
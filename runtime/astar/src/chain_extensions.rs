@@ -20,11 +20,14 @@ use super::Runtime;
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
-use pallet_contracts::chain_extension::RegisteredChainExtension;
-
-// Following impls defines chain extension IDs.
-impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
-    const ID: u16 = 02;
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
+pub use pallet_chain_extension_price_aggregator::PriceAggregatorExtension;
+
+astar_primitives::chain_extensions! {
+    Runtime;
+    pub type AstarChainExtensions = {
+        Assets => AssetsExtension<Runtime>,
+        PriceAggregator => PriceAggregatorExtension<Runtime>,
+        DappStaking => DappStakingExtension<Runtime>,
+    };
 }
-
-pub type AstarChainExtensions<Runtime> = AssetsExtension<Runtime>;
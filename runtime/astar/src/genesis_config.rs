@@ -23,6 +23,8 @@ use astar_primitives::{evm::EVM_REVERT_CODE, genesis::GenesisAccount, parachain:
 pub fn get_preset(id: &sp_genesis_builder::PresetId) -> Option<Vec<u8>> {
     let genesis = match id.try_into() {
         Ok("development") => default_config(ASTAR_ID),
+        Ok("local") => local_config(ASTAR_ID),
+        Ok("staging") => staging_config(ASTAR_ID),
         _ => return None,
     };
     Some(
@@ -32,15 +34,46 @@ pub fn get_preset(id: &sp_genesis_builder::PresetId) -> Option<Vec<u8>> {
     )
 }
 
+/// Names of the predefined genesis configs.
+pub fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
+    vec![
+        sp_genesis_builder::PresetId::from("development"),
+        sp_genesis_builder::PresetId::from("local"),
+        sp_genesis_builder::PresetId::from("staging"),
+    ]
+}
+
 /// Get the default genesis config for the Astar runtime.
-pub fn default_config(para_id: u32) -> serde_json::Value {
+fn default_config(para_id: u32) -> serde_json::Value {
+    build_genesis_config(para_id, &["Alice", "Bob"])
+}
+
+/// Get the local testnet genesis config for the Astar runtime.
+///
+/// Same as the default one, but with four collators for multi-collator local networks.
+fn local_config(para_id: u32) -> serde_json::Value {
+    build_genesis_config(para_id, &["Alice", "Bob", "Charlie", "Dave"])
+}
+
+/// Get the staging genesis config for the Astar runtime.
+///
+/// Same as the default one, but with a single collator, e.g. for rehearsing runtime upgrades.
+fn staging_config(para_id: u32) -> serde_json::Value {
+    build_genesis_config(para_id, &["Alice"])
+}
+
+/// Build the genesis config with the given well-known accounts as invulnerable collators.
+fn build_genesis_config(para_id: u32, collators: &[&str]) -> serde_json::Value {
     let alice = GenesisAccount::<sr25519::Public>::from_seed("Alice");
     let bob = GenesisAccount::<sr25519::Public>::from_seed("Bob");
     let charlie = GenesisAccount::<sr25519::Public>::from_seed("Charlie");
     let dave = GenesisAccount::<sr25519::Public>::from_seed("Dave");
     let eve = GenesisAccount::<sr25519::Public>::from_seed("Eve");
 
-    let authorities = vec![&alice, &bob];
+    let authorities = collators
+        .iter()
+        .map(|seed| GenesisAccount::<sr25519::Public>::from_seed(seed))
+        .collect::<Vec<_>>();
     let accounts = vec![&alice, &bob, &charlie, &dave, &eve]
         .iter()
         .map(|x| x.account_id())
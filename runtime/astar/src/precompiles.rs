@@ -18,25 +18,53 @@
 
 //! The Astar Network EVM precompiles. This can be compiled with ``#[no_std]`, ready for Wasm.
 
-use crate::RuntimeCall;
-use astar_primitives::precompiles::DispatchFilterValidate;
-use frame_support::{parameter_types, traits::Contains};
+use crate::{
+    AstarAssetLocationIdConverter, MaxAdditionalFields, ProxyType, Runtime, RuntimeCall,
+    MILLISECS_PER_BLOCK,
+};
+use astar_primitives::{
+    governance::{CommunityTreasuryInst, MainTreasuryInst},
+    precompiles::DispatchFilterValidate,
+};
+use frame_support::{
+    parameter_types,
+    traits::{Contains, Get, InstanceFilter},
+};
 use pallet_evm_precompile_assets_erc20::Erc20AssetsPrecompileSet;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
-use pallet_evm_precompile_dapp_staking::DappStakingV3Precompile;
+use pallet_evm_precompile_collective_proxy::{
+    CollectiveProxyPrecompile, CollectiveProxyPrecompileCall,
+};
+use pallet_evm_precompile_dapp_staking::{DappStakingV3Precompile, DappStakingV3PrecompileCall};
+use pallet_evm_precompile_derivative::{DerivativePrecompile, DerivativePrecompileCall};
 use pallet_evm_precompile_dispatch::Dispatch;
-use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
+use pallet_evm_precompile_dispatch_lockdrop::{DispatchLockdrop, DispatchLockdropCall};
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_governance::{GovernancePrecompile, GovernancePrecompileCall};
+use pallet_evm_precompile_identity::{IdentityPrecompile, IdentityPrecompileCall};
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_multisig::{MultisigPrecompile, MultisigPrecompileCall};
+use pallet_evm_precompile_price_aggregator::{
+    PriceAggregatorPrecompile, PriceAggregatorPrecompileCall,
+};
+use pallet_evm_precompile_registry::{
+    PrecompileInterface, PrecompileInterfaces, PrecompileRegistry, PrecompileRegistryCall,
+};
+use pallet_evm_precompile_runtime_info::{RuntimeInfoPrecompile, RuntimeInfoPrecompileCall};
+use pallet_evm_precompile_scheduler::{SchedulerPrecompile, SchedulerPrecompileCall};
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
-use pallet_evm_precompile_sr25519::Sr25519Precompile;
-use pallet_evm_precompile_substrate_ecdsa::SubstrateEcdsaPrecompile;
-use pallet_evm_precompile_xcm::XcmPrecompile;
+use pallet_evm_precompile_sr25519::{Sr25519Precompile, Sr25519PrecompileCall};
+use pallet_evm_precompile_substrate_ecdsa::{
+    SubstrateEcdsaPrecompile, SubstrateEcdsaPrecompileCall,
+};
+use pallet_evm_precompile_treasury::{TreasuryPrecompile, TreasuryPrecompileCall};
+use pallet_evm_precompile_vesting::{VestingPrecompile, VestingPrecompileCall};
+use pallet_evm_precompile_xcm::{XcmPrecompile, XcmPrecompileCall};
 use precompile_utils::precompile_set::*;
-use sp_core::ConstU32;
-use sp_std::fmt::Debug;
+use sp_core::{ConstU32, ConstU64, H160};
+use sp_std::{fmt::Debug, vec::Vec};
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
@@ -80,9 +108,9 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     fn contains(t: &RuntimeCall) -> bool {
         match t {
             RuntimeCall::Utility(pallet_utility::Call::batch { calls })
-            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
-                .iter()
-                .all(|call| WhitelistedLockdropCalls::contains(call)),
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().all(|call| LockdropCalls::contains(call))
+            }
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::unbond_and_unstake { .. }) => true,
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::withdraw_unbonded { .. }) => true,
             RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
@@ -94,6 +122,170 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     }
 }
 
+/// Calls allowed to pass through dispatch-lockdrop precompile
+pub type LockdropCalls = WhitelistedLockdropCalls;
+
+/// Filter that only allows whitelisted runtime call to be scheduled via scheduler precompile
+pub struct WhitelistedSchedulerCalls;
+
+impl Contains<RuntimeCall> for WhitelistedSchedulerCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedSchedulerCalls::contains(call)),
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_staker_rewards {
+                ..
+            }) => true,
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_bonus_reward { .. }) => true,
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_dapp_reward { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that only allows whitelisted proposals to be submitted via governance precompile
+pub struct WhitelistedGovernanceProposals;
+
+impl Contains<RuntimeCall> for WhitelistedGovernanceProposals {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedGovernanceProposals::contains(call)),
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. }) => true,
+            RuntimeCall::Treasury(pallet_treasury::Call::spend_local { .. }) => true,
+            RuntimeCall::CommunityTreasury(pallet_treasury::Call::spend_local { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that only allows whitelisted calls to be dispatched by the multisig via multisig precompile
+pub struct WhitelistedMultisigCalls;
+
+impl Contains<RuntimeCall> for WhitelistedMultisigCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedMultisigCalls::contains(call)),
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::DappStaking(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that allows the derivative accounts to dispatch the same calls as the `NonTransfer`,
+/// `Balances` and `Assets` proxies can, via derivative precompile
+pub struct DerivativeCalls;
+
+impl Contains<RuntimeCall> for DerivativeCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        [
+            ProxyType::NonTransfer,
+            ProxyType::Balances,
+            ProxyType::Assets,
+        ]
+        .iter()
+        .any(|proxy_type| proxy_type.filter(t))
+    }
+}
+
+/// Interfaces of the Astar runtime precompiles, reported by the registry precompile.
+///
+/// UPDATE this once new precompile is added, or its interface is changed.
+pub struct AstarPrecompileInterfaces;
+
+impl PrecompileInterfaces for AstarPrecompileInterfaces {
+    fn interface(address: H160) -> Option<PrecompileInterface> {
+        // All the precompiles with an interface are at the low addresses
+        if address != H160::from_low_u64_be(address.to_low_u64_be()) {
+            return None;
+        }
+
+        let (version, selectors) = match address.to_low_u64_be() {
+            20481 => (3, DappStakingV3PrecompileCall::<Runtime>::selectors()),
+            20482 => (1, Sr25519PrecompileCall::<Runtime>::selectors()),
+            20483 => (1, SubstrateEcdsaPrecompileCall::<Runtime>::selectors()),
+            20484 => (
+                2,
+                XcmPrecompileCall::<Runtime, AstarAssetLocationIdConverter>::selectors(),
+            ),
+            20487 => (
+                1,
+                DispatchLockdropCall::<
+                    Runtime,
+                    DispatchFilterValidate<RuntimeCall, LockdropCalls>,
+                    ConstU32<8>,
+                >::selectors(),
+            ),
+            20489 => (
+                1,
+                PriceAggregatorPrecompileCall::<
+                    Runtime,
+                    ConstU64<MILLISECS_PER_BLOCK>,
+                >::selectors(),
+            ),
+            20490 => (
+                1,
+                SchedulerPrecompileCall::<
+                    Runtime,
+                    WhitelistedSchedulerCalls,
+                    ConstU32<100>,
+                >::selectors(),
+            ),
+            20491 => (
+                1,
+                GovernancePrecompileCall::<Runtime, WhitelistedGovernanceProposals>::selectors(),
+            ),
+            20492 => (1, VestingPrecompileCall::<Runtime>::selectors()),
+            20493 => (
+                1,
+                MultisigPrecompileCall::<Runtime, WhitelistedMultisigCalls>::selectors(),
+            ),
+            20494 => (
+                1,
+                DerivativePrecompileCall::<Runtime, DerivativeCalls>::selectors(),
+            ),
+            20495 => (
+                1,
+                PrecompileRegistryCall::<Runtime, AstarPrecompileInterfaces>::selectors(),
+            ),
+            20497 => (
+                1,
+                RuntimeInfoPrecompileCall::<Runtime, AstarEnabledPrecompiles>::selectors(),
+            ),
+            20498 => (1, TreasuryPrecompileCall::<Runtime, MainTreasuryInst>::selectors()),
+            20499 => (1, TreasuryPrecompileCall::<Runtime, CommunityTreasuryInst>::selectors()),
+            20500 => (1, IdentityPrecompileCall::<Runtime, MaxAdditionalFields>::selectors()),
+            20501 => (1, CollectiveProxyPrecompileCall::<Runtime, ConstU32<8>>::selectors()),
+            _ => return None,
+        };
+
+        Some(PrecompileInterface::new(version, selectors))
+    }
+}
+
+/// Addresses of the Astar runtime precompiles, reported by the runtime info precompile.
+pub struct AstarEnabledPrecompiles;
+impl Get<Vec<H160>> for AstarEnabledPrecompiles {
+    fn get() -> Vec<H160> {
+        AstarPrecompiles::<Runtime, AstarAssetLocationIdConverter>::used_addresses_h160().collect()
+    }
+}
+
 /// The PrecompileSet installed in the Astar runtime.
 #[precompile_utils::precompile_name_from_address]
 pub type AstarPrecompilesSetAt<R, C> = (
@@ -148,14 +340,72 @@ pub type AstarPrecompilesSetAt<R, C> = (
     // precompiles address
     PrecompileAt<
         AddressU64<20487>,
-        DispatchLockdrop<
-            R,
-            DispatchFilterValidate<RuntimeCall, WhitelistedLockdropCalls>,
-            ConstU32<8>,
-        >,
+        DispatchLockdrop<R, DispatchFilterValidate<RuntimeCall, LockdropCalls>, ConstU32<8>>,
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    // Skipping 20488 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20489>,
+        PriceAggregatorPrecompile<R, ConstU64<MILLISECS_PER_BLOCK>>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20490>,
+        SchedulerPrecompile<R, WhitelistedSchedulerCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20491>,
+        GovernancePrecompile<R, WhitelistedGovernanceProposals>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20492>,
+        VestingPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20493>,
+        MultisigPrecompile<R, WhitelistedMultisigCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20494>,
+        DerivativePrecompile<R, DerivativeCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20495>,
+        PrecompileRegistry<R, AstarPrecompileInterfaces>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    // Skipping 20496 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20497>,
+        RuntimeInfoPrecompile<R, AstarEnabledPrecompiles>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20498>,
+        TreasuryPrecompile<R, MainTreasuryInst>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20499>,
+        TreasuryPrecompile<R, CommunityTreasuryInst>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20500>,
+        IdentityPrecompile<R, MaxAdditionalFields>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20501>,
+        CollectiveProxyPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type AstarPrecompiles<R, C> = PrecompileSetBuilder<
@@ -164,7 +414,7 @@ pub type AstarPrecompiles<R, C> = PrecompileSetBuilder<
         // Skip precompiles if out of range.
         PrecompilesInRangeInclusive<
             // We take range as last precompile index, UPDATE this once new prcompile is added
-            (AddressU64<1>, AddressU64<20487>),
+            (AddressU64<1>, AddressU64<20501>),
             AstarPrecompilesSetAt<R, C>,
         >,
         // Prefixed precompile sets (XC20)
@@ -20,21 +20,18 @@ use super::{Runtime, UnifiedAccounts};
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
-use pallet_contracts::chain_extension::RegisteredChainExtension;
-
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
+pub use pallet_chain_extension_evm::EvmExtension;
+pub use pallet_chain_extension_signature_verification::SignatureVerificationExtension;
 pub use pallet_chain_extension_unified_accounts::UnifiedAccountsExtension;
 
-// Following impls defines chain extension IDs.
-
-impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
-    const ID: u16 = 02;
+astar_primitives::chain_extensions! {
+    Runtime;
+    pub type LocalChainExtensions = {
+        Assets => AssetsExtension<Runtime>,
+        UnifiedAccounts => UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
+        DappStaking => DappStakingExtension<Runtime>,
+        SignatureVerification => SignatureVerificationExtension<Runtime>,
+        Evm => EvmExtension<Runtime>,
+    };
 }
-
-impl RegisteredChainExtension<Runtime> for UnifiedAccountsExtension<Runtime, UnifiedAccounts> {
-    const ID: u16 = 03;
-}
-
-pub type LocalChainExtensions<Runtime, UnifiedAccounts> = (
-    AssetsExtension<Runtime>,
-    UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
-);
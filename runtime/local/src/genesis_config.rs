@@ -37,7 +37,7 @@ pub fn get_preset(id: &sp_genesis_builder::PresetId) -> Option<Vec<u8>> {
 }
 
 /// Get the default genesis config for the local runtime.
-pub fn default_config() -> serde_json::Value {
+fn default_config() -> serde_json::Value {
     let alice = GenesisAccount::<sr25519::Public>::from_seed("Alice");
     let bob = GenesisAccount::<sr25519::Public>::from_seed("Bob");
     let charlie = GenesisAccount::<sr25519::Public>::from_seed("Charlie");
@@ -69,7 +69,7 @@ use astar_primitives::{
         CycleConfiguration, DAppId, EraNumber, PeriodNumber, RankedTier, SmartContract,
         StandardTierSlots,
     },
-    evm::{EvmRevertCodeHandler, HashedDefaultMappings},
+    evm::{BlockGasUsed, EvmRevertCodeHandler, HashedDefaultMappings},
     governance::{
         CommunityCouncilCollectiveInst, CommunityCouncilMembershipInst, CommunityTreasuryInst,
         EnsureRootOrAllMainCouncil, EnsureRootOrAllTechnicalCommittee,
@@ -80,11 +80,14 @@ use astar_primitives::{
     },
     Address, AssetId, Balance, BlockNumber, Hash, Header, Nonce,
 };
+use block_diagnostics_runtime_api::{BlockDiagnostics, ExtrinsicWeight};
+use event_index_runtime_api::{EventCategory, IndexedEvent};
+use unified_trace_runtime_api::{EthereumTransactionRecord, EvmLogRecord, ExtrinsicTrace};
 
 pub use astar_primitives::{AccountId, Signature};
 pub use pallet_dapp_staking::TierThreshold;
 
-pub use crate::precompiles::WhitelistedCalls;
+pub use crate::precompiles::{LockdropCalls, WhitelistedCalls};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
@@ -411,6 +414,12 @@ parameter_types! {
     pub MinBaseFeePerGas: U256 = U256::from(800_000_000_000_u128);
     pub MaxBaseFeePerGas: U256 = U256::from(80_000_000_000_000_u128);
     pub StepLimitRatio: Perquintill = Perquintill::from_rational(5_u128, 100_000);
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::BlockFullness;
+    /// Target gas usage of a block, aligned with the target block fullness of native transactions.
+    pub TargetBlockGas: U256 = U256::from(TargetBlockFullness::get() * BlockGasLimit::get().low_u64());
+    /// Share of the EVM base fee which is burned.
+    pub DefaultEvmBaseFeeBurnRatio: Perbill = Perbill::one();
 }
 
 /// Simple wrapper for fetching current native transaction fee weight fee multiplier.
@@ -429,6 +438,12 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type BlockGasUsed = BlockGasUsed<Runtime>;
+    type TargetBlockGas = TargetBlockGas;
+    type DefaultBaseFeeBurnRatio = DefaultEvmBaseFeeBurnRatio;
+    type OnBaseFeeChanged = ();
+    type ForceOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -460,6 +475,8 @@ impl pallet_dapp_staking::BenchmarkHelper<SmartContract<AccountId>, AccountId>
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyMinimumStake: Balance = 1_000 * AST;
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(10);
 }
 
 impl pallet_dapp_staking::Config for Runtime {
@@ -476,6 +493,7 @@ impl pallet_dapp_staking::Config for Runtime {
     type Observers = Inflation;
     type AccountCheck = ();
     type TierSlots = StandardTierSlots;
+    type SponsorshipPool = ();
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -487,6 +505,9 @@ impl pallet_dapp_staking::Config for Runtime {
     type MinimumStakeAmount = ConstU128<AST>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = ConstU32<2>;
+    type OwnerLoyaltyMinimumStake = OwnerLoyaltyMinimumStake;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = pallet_dapp_staking::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -538,8 +559,8 @@ impl pallet_utility::Config for Runtime {
 }
 
 parameter_types! {
-    // 2 storage items with value size 20 and 32
-    pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // 3 storage items with values 20, 32 and 5 (claim metadata)
+    pub const AccountMappingStorageFee: u128 = deposit(3, 32 + 20 + 5);
 }
 
 impl pallet_unified_accounts::Config for Runtime {
@@ -548,15 +569,27 @@ impl pallet_unified_accounts::Config for Runtime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type MaxSecondaryAddresses = ConstU32<4>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
+impl pallet_lockdrop_call_whitelist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type MaxAllowedCalls = ConstU32<64>;
+    type WeightInfo = pallet_lockdrop_call_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub ReservedXcmpWeight: Weight = Weight::zero();
+    /// Contracts calling into EVM are limited to the same gas as normal EVM transactions.
+    pub ChainExtensionTxWeightLimit: Weight = NORMAL_DISPATCH_RATIO * RuntimeBlockWeights::get().max_block;
 }
 
 impl pallet_ethereum_checked::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservedXcmpWeight = ReservedXcmpWeight;
+    type ChainExtensionTxWeightLimit = ChainExtensionTxWeightLimit;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AddressMapper = UnifiedAccounts;
@@ -626,7 +659,10 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesType = Precompiles;
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
-    type OnChargeTransaction = pallet_evm::EVMFungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = pallet_evm::EVMFungibleAdapter<
+        Balances,
+        pallet_dynamic_evm_base_fee::BaseFeeSplitter<Runtime, Balances, ()>,
+    >;
     type BlockGasLimit = BlockGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
@@ -636,6 +672,14 @@ impl pallet_evm::Config for Runtime {
     type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
 }
 
+impl astar_precompile_benchmarks::Config for Runtime {
+    type WeightInfo = astar_precompile_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
+impl astar_chain_extension_benchmarks::Config for Runtime {
+    type WeightInfo = astar_chain_extension_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
 }
@@ -732,7 +776,7 @@ impl pallet_contracts::Config for Runtime {
     type CallStack = [pallet_contracts::Frame<Self>; 5];
     type WeightPrice = pallet_transaction_payment::Pallet<Self>;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-    type ChainExtension = LocalChainExtensions<Self, UnifiedAccounts>;
+    type ChainExtension = LocalChainExtensions;
     type Schedule = Schedule;
     type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
     type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
@@ -1203,6 +1247,7 @@ construct_runtime!(
         DynamicEvmBaseFee: pallet_dynamic_evm_base_fee = 62,
         EthereumChecked: pallet_ethereum_checked = 64,
         UnifiedAccounts: pallet_unified_accounts = 65,
+        LockdropCallWhitelist: pallet_lockdrop_call_whitelist = 66,
 
         Contracts: pallet_contracts = 70,
 
@@ -1343,6 +1388,7 @@ mod benches {
         [pallet_dapp_staking, DappStaking]
         [pallet_inflation, Inflation]
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
+        [pallet_lockdrop_call_whitelist, LockdropCallWhitelist]
         [pallet_tx_pause, TxPause]
         [pallet_safe_mode, SafeMode]
     );
@@ -1858,6 +1904,117 @@ impl_runtime_apis! {
         }
     }
 
+    impl collective_proxy_runtime_api::CollectiveProxyApi<Block, AccountId> for Runtime {
+        fn proxy_permissions() -> Vec<collective_proxy_runtime_api::ProxyPermissions<AccountId>> {
+            vec![CollectiveProxy::permissions()]
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block> for Runtime {
+        fn base_fee_per_gas() -> U256 {
+            pallet_dynamic_evm_base_fee::BaseFeePerGas::<Runtime>::get()
+        }
+
+        fn projected_base_fee_per_gas(blocks: u32) -> Vec<U256> {
+            DynamicEvmBaseFee::projected_base_fee_per_gas(blocks)
+        }
+    }
+
+    impl ethereum_checked_runtime_api::EthereumCheckedApi<Block, AccountId> for Runtime {
+        fn nonce(account: AccountId) -> U256 {
+            EthereumChecked::account_nonce(&account)
+        }
+    }
+
+    impl unified_trace_runtime_api::UnifiedTraceApi<Block, RuntimeEvent> for Runtime {
+        fn extrinsic_trace(extrinsic_index: u32) -> ExtrinsicTrace<RuntimeEvent> {
+            let statuses = pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get().unwrap_or_default();
+            let receipts = pallet_ethereum::CurrentReceipts::<Runtime>::get().unwrap_or_default();
+
+            let mut trace = ExtrinsicTrace::new(extrinsic_index);
+            for (event_index, record) in System::read_events_no_consensus().enumerate() {
+                if record.phase != frame_system::Phase::ApplyExtrinsic(extrinsic_index) {
+                    continue;
+                }
+                let event_index = event_index as u32;
+
+                match &record.event {
+                    RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => {
+                        trace.evm_logs.push(EvmLogRecord { event_index, log: log.clone() });
+                    }
+                    RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed { transaction_hash, .. }) => {
+                        if let Some(idx) = statuses.iter().position(|status| status.transaction_hash == *transaction_hash) {
+                            trace.ethereum_transactions.push(EthereumTransactionRecord {
+                                event_index,
+                                status: statuses[idx].clone(),
+                                receipt: receipts.get(idx).cloned(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+
+                trace.events.push((event_index, record.event));
+            }
+
+            trace
+        }
+    }
+
+    impl block_diagnostics_runtime_api::BlockDiagnosticsApi<Block> for Runtime {
+        fn block_diagnostics(max_extrinsics: u32) -> BlockDiagnostics {
+            let extrinsics = System::read_events_no_consensus()
+                .filter_map(|record| match (record.phase, &record.event) {
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicSuccess { dispatch_info }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, true)),
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicFailed { dispatch_info, .. }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, false)),
+                    _ => None,
+                })
+                .collect();
+
+            BlockDiagnostics::new(
+                System::block_weight(),
+                RuntimeBlockWeights::get().max_block,
+                extrinsics,
+                max_extrinsics,
+            )
+        }
+    }
+
+    impl event_index_runtime_api::EventIndexApi<Block, AccountId> for Runtime {
+        fn indexed_events() -> Vec<IndexedEvent<AccountId>> {
+            System::read_events_no_consensus()
+                .enumerate()
+                .filter_map(|(event_index, record)| {
+                    let (category, accounts) = match &record.event {
+                        RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, to, .. })
+                        | RuntimeEvent::Assets(pallet_assets::Event::Transferred { from, to, .. }) => {
+                            (EventCategory::Transfer, vec![from.clone(), to.clone()])
+                        }
+                        RuntimeEvent::DappStaking(event) => (EventCategory::DappStaking, event.accounts()),
+                        _ => return None,
+                    };
+
+                    Some(IndexedEvent {
+                        event_index: event_index as u32,
+                        extrinsic_index: match record.phase {
+                            frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+                            _ => None,
+                        },
+                        category,
+                        accounts,
+                        event: record.event.encode(),
+                    })
+                })
+                .collect()
+        }
+    }
+
 
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 
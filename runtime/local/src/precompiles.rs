@@ -18,7 +18,7 @@
 
 //! The Local Network EVM precompiles. This can be compiled with ``#[no_std]`, ready for Wasm.
 
-use crate::{RuntimeCall, UnifiedAccounts};
+use crate::{LockdropCallWhitelist, RuntimeCall, UnifiedAccounts};
 use astar_primitives::precompiles::DispatchFilterValidate;
 use frame_support::traits::ConstU32;
 use frame_support::{parameter_types, traits::Contains};
@@ -77,9 +77,9 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     fn contains(t: &RuntimeCall) -> bool {
         match t {
             RuntimeCall::Utility(pallet_utility::Call::batch { calls })
-            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
-                .iter()
-                .all(|call| WhitelistedLockdropCalls::contains(call)),
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().all(|call| LockdropCalls::contains(call))
+            }
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::unbond_and_unstake { .. }) => true,
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::withdraw_unbonded { .. }) => true,
             RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
@@ -91,6 +91,10 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     }
 }
 
+/// Calls allowed to pass through dispatch-lockdrop precompile, either statically whitelisted,
+/// or whitelisted by governance
+pub type LockdropCalls = (WhitelistedLockdropCalls, LockdropCallWhitelist);
+
 /// The PrecompileSet installed in the Local runtime.
 #[precompile_utils::precompile_name_from_address]
 pub type LocalPrecompilesSetAt<R> = (
@@ -140,11 +144,7 @@ pub type LocalPrecompilesSetAt<R> = (
     >,
     PrecompileAt<
         AddressU64<20487>,
-        DispatchLockdrop<
-            R,
-            DispatchFilterValidate<RuntimeCall, WhitelistedLockdropCalls>,
-            ConstU32<8>,
-        >,
+        DispatchLockdrop<R, DispatchFilterValidate<RuntimeCall, LockdropCalls>, ConstU32<8>>,
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
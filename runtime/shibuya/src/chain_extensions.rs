@@ -17,24 +17,34 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{Runtime, UnifiedAccounts};
+use frame_support::traits::Everything;
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
-use pallet_contracts::chain_extension::RegisteredChainExtension;
-
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
+pub use pallet_chain_extension_evm::EvmExtension;
+pub use pallet_chain_extension_governance::GovernanceExtension;
+pub use pallet_chain_extension_nfts::NftsExtension;
+pub use pallet_chain_extension_price_aggregator::PriceAggregatorExtension;
+pub use pallet_chain_extension_scheduler::SchedulerExtension;
+pub use pallet_chain_extension_signature_verification::SignatureVerificationExtension;
 pub use pallet_chain_extension_unified_accounts::UnifiedAccountsExtension;
-
-// Following impls defines chain extension IDs.
-
-impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
-    const ID: u16 = 02;
+pub use pallet_chain_extension_xcm::XcmExtension;
+
+astar_primitives::chain_extensions! {
+    Runtime;
+    pub type ShibuyaChainExtensions = {
+        Assets => AssetsExtension<Runtime>,
+        UnifiedAccounts => UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
+        PriceAggregator => PriceAggregatorExtension<Runtime>,
+        DappStaking => DappStakingExtension<Runtime>,
+        // Contracts can only execute the messages `pallet-xcm` allows to be executed.
+        Xcm => XcmExtension<Runtime, <Runtime as pallet_xcm::Config>::XcmExecuteFilter>,
+        Scheduler => SchedulerExtension<Runtime>,
+        SignatureVerification => SignatureVerificationExtension<Runtime>,
+        Nfts => NftsExtension<Runtime>,
+        // Any contract may take part in the governance on Shibuya.
+        Governance => GovernanceExtension<Runtime, Everything>,
+        Evm => EvmExtension<Runtime>,
+    };
 }
-
-impl RegisteredChainExtension<Runtime> for UnifiedAccountsExtension<Runtime, UnifiedAccounts> {
-    const ID: u16 = 03;
-}
-
-pub type ShibuyaChainExtensions<Runtime, UnifiedAccounts> = (
-    AssetsExtension<Runtime>,
-    UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
-);
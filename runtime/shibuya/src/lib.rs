@@ -30,7 +30,7 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit, HoldConsideration},
         tokens::{PayFromAccount, UnityAssetBalanceConversion},
-        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, Contains,
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, Contains, EitherOfDiverse,
         EqualPrivilegeOnly, FindAuthor, Get, Imbalance, InsideBoth, InstanceFilter,
         LinearStoragePrice, Nothing, OnFinalize, OnUnbalanced, WithdrawReasons,
     },
@@ -50,6 +50,7 @@ use frame_system::{
 use pallet_ethereum::PostLogContent;
 use pallet_evm::{FeeCalculator, GasWeightMapping, Runner};
 use pallet_identity::legacy::IdentityInfo;
+use pallet_nfts::PalletFeatures;
 use pallet_transaction_payment::{
     FeeDetails, Multiplier, RuntimeDispatchInfo, TargetedFeeAdjustment,
 };
@@ -62,7 +63,7 @@ use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{
         AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto,
-        DispatchInfoOf, Dispatchable, IdentityLookup, OpaqueKeys, PostDispatchInfoOf,
+        DispatchInfoOf, Dispatchable, IdentityLookup, OpaqueKeys, PostDispatchInfoOf, StaticLookup,
         UniqueSaturatedInto,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
@@ -83,7 +84,10 @@ use astar_primitives::{
         AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, EraNumber,
         PeriodNumber, RankedTier, SmartContract, StandardTierSlots,
     },
-    evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler, HashedDefaultMappings},
+    evm::{
+        BlockGasUsed, EVMFungibleAdapterWrapper, EvmRevertCodeHandler, HashedDefaultMappings,
+        ToBlockAuthor,
+    },
     governance::{
         CommunityCouncilCollectiveInst, CommunityCouncilMembershipInst, CommunityTreasuryInst,
         EnsureRootOrAllMainCouncil, EnsureRootOrAllTechnicalCommittee,
@@ -93,18 +97,28 @@ use astar_primitives::{
         MainCouncilMembershipInst, MainTreasuryInst, OracleMembershipInst,
         TechnicalCommitteeCollectiveInst, TechnicalCommitteeMembershipInst,
     },
-    oracle::{CurrencyAmount, CurrencyId, DummyCombineData, Price},
-    xcm::AssetLocationIdConverter,
-    Address, AssetId, BlockNumber, Hash, Header, Nonce, UnfreezeChainOnFailedMigration,
+    oracle::{
+        CurrencyAmount, CurrencyId, DummyCombineData, OracleMembershipMode, Price,
+        StalePriceBehavior,
+    },
+    xcm::{AssetLocationIdConverter, NftCollectionLocationIdConverter},
+    Address, AssetId, BlockNumber, CollectionId, Hash, Header, ItemId, Nonce,
+    UnfreezeChainOnFailedMigration,
 };
 pub use astar_primitives::{AccountId, Balance, Signature};
+use block_diagnostics_runtime_api::{BlockDiagnostics, ExtrinsicWeight};
+use event_index_runtime_api::{EventCategory, IndexedEvent};
+use unified_identity_runtime_api::UnifiedIdentity;
+use unified_trace_runtime_api::{EthereumTransactionRecord, EvmLogRecord, ExtrinsicTrace};
 
 pub use pallet_dapp_staking::TierThreshold;
 pub use pallet_inflation::InflationParameters;
 
-pub use crate::precompiles::WhitelistedCalls;
+pub use crate::precompiles::{LockdropCalls, ShibuyaPrecompileInterfaces, WhitelistedCalls};
 
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use pallet_evm_precompile_collective_proxy::EnsureEvmController;
+use pallet_evm_precompile_uniques_erc721::AddressToCollectionId;
 
 #[cfg(any(feature = "std", test))]
 use sp_version::NativeVersion;
@@ -124,8 +138,12 @@ mod weights;
 pub mod xcm_config;
 
 pub type ShibuyaAssetLocationIdConverter = AssetLocationIdConverter<AssetId, XcAssetConfig>;
+pub type ShibuyaNftCollectionLocationIdConverter =
+    NftCollectionLocationIdConverter<CollectionId, XcAssetConfig>;
 
-pub use precompiles::{ShibuyaPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX};
+pub use precompiles::{
+    ShibuyaPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX, NFT_PRECOMPILE_ADDRESS_PREFIX,
+};
 pub type Precompiles = ShibuyaPrecompiles<Runtime, ShibuyaAssetLocationIdConverter>;
 
 use chain_extensions::ShibuyaChainExtensions;
@@ -188,6 +206,26 @@ impl AddressToAssetId<AssetId> for Runtime {
     }
 }
 
+impl AddressToCollectionId<CollectionId> for Runtime {
+    fn address_to_collection_id(address: H160) -> Option<CollectionId> {
+        let mut data = [0u8; 16];
+        let address_bytes: [u8; 20] = address.into();
+        if NFT_PRECOMPILE_ADDRESS_PREFIX.eq(&address_bytes[0..4]) {
+            data.copy_from_slice(&address_bytes[4..20]);
+            Some(u128::from_be_bytes(data))
+        } else {
+            None
+        }
+    }
+
+    fn collection_id_to_address(collection_id: CollectionId) -> H160 {
+        let mut data = [0u8; 20];
+        data[0..4].copy_from_slice(NFT_PRECOMPILE_ADDRESS_PREFIX);
+        data[4..20].copy_from_slice(&collection_id.to_be_bytes());
+        H160::from(data)
+    }
+}
+
 // Make the WASM binary available.
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
@@ -277,12 +315,55 @@ impl Contains<RuntimeCall> for BaseFilter {
     fn contains(call: &RuntimeCall) -> bool {
         match call {
             // Filter permission-less assets creation/destroying.
-            // Custom asset's `id` should fit in `u32` as not to mix with service assets.
+            // Custom asset's `id` should fit in `u32` as not to mix with service assets,
+            // and mustn't belong to the range reserved for the cross-chain assets.
             RuntimeCall::Assets(method) => match method {
-                pallet_assets::Call::create { id, .. } => *id < (u32::MAX as AssetId).into(),
+                pallet_assets::Call::create { id, .. } => {
+                    *id < (u32::MAX as AssetId).into()
+                        && XcAssetConfig::is_local_asset_id_allowed(id.0)
+                }
 
                 _ => true,
             },
+            // Filter reserve transfers to destinations which aren't allowed by governance,
+            // as well as transfers of amounts below the asset's minimum cross-chain transfer amount.
+            RuntimeCall::PolkadotXcm(method) => match method {
+                pallet_xcm::Call::reserve_transfer_assets { dest, assets, .. }
+                | pallet_xcm::Call::limited_reserve_transfer_assets { dest, assets, .. }
+                | pallet_xcm::Call::transfer_assets { dest, assets, .. }
+                | pallet_xcm::Call::transfer_assets_using_type_and_then { dest, assets, .. } => {
+                    ReserveTransferFilter::is_allowed_versioned_destination(dest)
+                        && XcAssetConfig::versioned_assets_meet_min_xcm_amount(assets)
+                }
+                _ => true,
+            },
+            // Filter transfers of amounts below the asset's minimum cross-chain transfer amount,
+            // since such dust would be lost on the destination.
+            RuntimeCall::XTokens(method) => match method {
+                orml_xtokens::Call::transfer {
+                    currency_id,
+                    amount,
+                    ..
+                }
+                | orml_xtokens::Call::transfer_with_fee {
+                    currency_id,
+                    amount,
+                    ..
+                } => XcAssetConfig::meets_min_xcm_amount(*currency_id, *amount),
+                orml_xtokens::Call::transfer_multicurrencies { currencies, .. } => {
+                    currencies.iter().all(|(currency_id, amount)| {
+                        XcAssetConfig::meets_min_xcm_amount(*currency_id, *amount)
+                    })
+                }
+                orml_xtokens::Call::transfer_multiasset { asset, .. }
+                | orml_xtokens::Call::transfer_multiasset_with_fee { asset, .. } => {
+                    XcAssetConfig::versioned_asset_meets_min_xcm_amount(asset)
+                }
+                orml_xtokens::Call::transfer_multiassets { assets, .. } => {
+                    XcAssetConfig::versioned_assets_meet_min_xcm_amount(assets)
+                }
+                _ => true,
+            },
             // Filter cross-chain asset config, only allow registration for non-root users
             RuntimeCall::XcAssetConfig(method) => match method {
                 pallet_xc_asset_config::Call::register_asset_location { .. } => true,
@@ -383,6 +464,20 @@ impl pallet_identity::Config for Runtime {
     type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
+/// Identity registration of an account, as stored by `pallet-identity`.
+pub type IdentityRegistration =
+    pallet_identity::Registration<Balance, MaxRegistrars, IdentityInfo<MaxAdditionalFields>>;
+
+/// Identity of the account, together with the EVM addresses mapped to it.
+fn unified_identity_of(account_id: AccountId) -> UnifiedIdentity<AccountId, IdentityRegistration> {
+    UnifiedIdentity {
+        evm_address: pallet_unified_accounts::NativeToEvm::<Runtime>::get(&account_id),
+        secondary_evm_addresses: UnifiedAccounts::secondary_evm_addresses(&account_id),
+        identity: Identity::identity(&account_id).map(|(registration, _)| registration),
+        account_id,
+    }
+}
+
 parameter_types! {
     // One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
     pub const DepositBase: Balance = deposit(1, 88);
@@ -467,6 +562,8 @@ impl DappStakingAccountCheck<AccountId> for AccountCheck {
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 5 * SBY;
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyMinimumStake: Balance = 50_000 * SBY;
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(10);
 }
 
 impl pallet_dapp_staking::Config for Runtime {
@@ -483,6 +580,7 @@ impl pallet_dapp_staking::Config for Runtime {
     type Observers = Inflation;
     type AccountCheck = AccountCheck;
     type TierSlots = StandardTierSlots;
+    type SponsorshipPool = ();
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -494,6 +592,9 @@ impl pallet_dapp_staking::Config for Runtime {
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = ConstU32<3>;
+    type OwnerLoyaltyMinimumStake = OwnerLoyaltyMinimumStake;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = weights::pallet_dapp_staking::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = DAppStakingBenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -710,6 +811,77 @@ impl pallet_assets::Config for Runtime {
     type BenchmarkHelper = astar_primitives::benchmarks::AssetsBenchmarkHelper;
 }
 
+parameter_types! {
+    pub const UniquesCollectionDeposit: Balance = 1 * SBY;
+    pub const UniquesItemDeposit: Balance = deposit(1, 48);
+    pub const UniquesMetadataDepositBase: Balance = deposit(1, 129);
+    pub const UniquesAttributeDepositBase: Balance = deposit(1, 0);
+    pub const UniquesDepositPerByte: Balance = deposit(0, 1);
+    pub const UniquesStringLimit: u32 = 128;
+    pub const UniquesKeyLimit: u32 = 32;
+    pub const UniquesValueLimit: u32 = 64;
+}
+
+impl pallet_uniques::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = CollectionId;
+    type ItemId = ItemId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = UniquesCollectionDeposit;
+    type ItemDeposit = UniquesItemDeposit;
+    type MetadataDepositBase = UniquesMetadataDepositBase;
+    type AttributeDepositBase = UniquesAttributeDepositBase;
+    type DepositPerByte = UniquesDepositPerByte;
+    type StringLimit = UniquesStringLimit;
+    type KeyLimit = UniquesKeyLimit;
+    type ValueLimit = UniquesValueLimit;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+parameter_types! {
+    pub const NftsCollectionDeposit: Balance = 1 * SBY;
+    pub const NftsItemDeposit: Balance = deposit(1, 48);
+    pub const NftsMetadataDepositBase: Balance = deposit(1, 129);
+    pub const NftsAttributeDepositBase: Balance = deposit(1, 0);
+    pub const NftsDepositPerByte: Balance = deposit(0, 1);
+    pub const NftsMaxDeadlineDuration: BlockNumber = 30 * DAYS;
+    pub NftsPalletFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = CollectionId;
+    type ItemId = ItemId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = NftsCollectionDeposit;
+    type ItemDeposit = NftsItemDeposit;
+    type MetadataDepositBase = NftsMetadataDepositBase;
+    type AttributeDepositBase = NftsAttributeDepositBase;
+    type DepositPerByte = NftsDepositPerByte;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<20>;
+    type ItemAttributesApprovalsLimit = ConstU32<30>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = NftsMaxDeadlineDuration;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftsPalletFeatures;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as sp_runtime::traits::Verify>::Signer;
+    type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
 parameter_types! {
     pub const MinVestedTransfer: Balance = 1 * SBY;
     pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
@@ -762,7 +934,7 @@ impl pallet_contracts::Config for Runtime {
     type CallStack = [pallet_contracts::Frame<Self>; 5];
     type WeightPrice = pallet_transaction_payment::Pallet<Self>;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-    type ChainExtension = ShibuyaChainExtensions<Self, UnifiedAccounts>;
+    type ChainExtension = ShibuyaChainExtensions;
     type Schedule = Schedule;
     type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
     type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
@@ -877,6 +1049,12 @@ parameter_types! {
     pub MinBaseFeePerGas: U256 = U256::from(800_000_000_000_u128);
     pub MaxBaseFeePerGas: U256 = U256::from(80_000_000_000_000_u128);
     pub StepLimitRatio: Perquintill = Perquintill::from_rational(5_u128, 100_000);
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::PriceBased;
+    /// Target gas usage of a block, aligned with the target block fullness of native transactions.
+    pub TargetBlockGas: U256 = U256::from(TargetBlockFullness::get() * BlockGasLimit::get().low_u64());
+    /// Share of the EVM base fee which is burned, the rest goes to collators.
+    pub DefaultEvmBaseFeeBurnRatio: Perbill = Perbill::from_percent(80);
 }
 
 /// Simple wrapper for fetching current native transaction fee weight fee multiplier.
@@ -895,11 +1073,24 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type BlockGasUsed = BlockGasUsed<Runtime>;
+    type TargetBlockGas = TargetBlockGas;
+    type DefaultBaseFeeBurnRatio = DefaultEvmBaseFeeBurnRatio;
+    type OnBaseFeeChanged = ();
+    type ForceOrigin = EnsureRootOrHalfTechnicalCommittee;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    /// Contracts calling into EVM are limited to the same gas as normal EVM transactions.
+    pub ChainExtensionTxWeightLimit: Weight = NORMAL_DISPATCH_RATIO * RuntimeBlockWeights::get().max_block;
+}
+
 impl pallet_ethereum_checked::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservedXcmpWeight = ReservedXcmpWeight;
+    type ChainExtensionTxWeightLimit = ChainExtensionTxWeightLimit;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AddressMapper = UnifiedAccounts;
@@ -965,7 +1156,11 @@ impl pallet_evm::Config for Runtime {
     // Ethereum-compatible chain_id:
     // * Shibuya: 81
     type ChainId = EVMChainId;
-    type OnChargeTransaction = EVMFungibleAdapterWrapper<Balances, DealWithFees, CollatorRewardPot>;
+    type OnChargeTransaction = EVMFungibleAdapterWrapper<
+        Balances,
+        pallet_dynamic_evm_base_fee::BaseFeeSplitter<Runtime, Balances, CollatorRewardPot>,
+        ToBlockAuthor<Runtime, Balances, CollatorRewardPot>,
+    >;
     type BlockGasLimit = BlockGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
@@ -975,6 +1170,14 @@ impl pallet_evm::Config for Runtime {
     type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
 }
 
+impl astar_precompile_benchmarks::Config for Runtime {
+    type WeightInfo = astar_precompile_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
+impl astar_chain_extension_benchmarks::Config for Runtime {
+    type WeightInfo = astar_chain_extension_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_evm_chain_id::Config for Runtime {}
 
 parameter_types! {
@@ -1152,6 +1355,10 @@ impl pallet_xc_asset_config::Config for Runtime {
     type AssetId = AssetId;
     // Good enough for testnet since we lack pallet-assets hooks for now
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxBatchSize = ConstU32<32>;
+    type AssetRegistrar = astar_primitives::xcm::AssetsRegistrar<Runtime, TreasuryAccountId>;
+    type MaxReservedRanges = ConstU32<16>;
+    type CollectionId = CollectionId;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
@@ -1168,23 +1375,88 @@ impl pallet_message_queue::Config for Runtime {
         cumulus_primitives_core::AggregateMessageOrigin,
     >;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type MessageProcessor = xcm_builder::ProcessXcmMessage<
-        AggregateMessageOrigin,
-        xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
-        RuntimeCall,
+    type MessageProcessor = pallet_xcm_monitor::MonitoredMessageProcessor<
+        Runtime,
+        xcm_builder::ProcessXcmMessage<
+            AggregateMessageOrigin,
+            xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
+            RuntimeCall,
+        >,
     >;
     type Size = u32;
     type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
-    type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+    type QueuePausedQuery = (NarrowOriginToSibling<XcmpQueue>, MessageQueuePause);
     type HeapSize = ConstU32<{ 128 * 1048 }>;
     type MaxStale = ConstU32<8>;
     type ServiceWeight = MessageQueueServiceWeight;
     type IdleMaxServiceWeight = MessageQueueServiceWeight;
 }
 
+impl pallet_message_queue_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PauseOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type ResumeOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type MaxPausedTargets = ConstU32<16>;
+    type MaxPauseDuration = ConstU32<{ 7 * DAYS }>;
+    type WeightInfo = pallet_message_queue_pause::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_remote_transact::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmSender = xcm_config::XcmRouter;
+    type UniversalLocation = xcm_config::UniversalLocation;
+    type AccountIdToLocation = astar_primitives::xcm::AccountIdToMultiLocation;
+    type RemoteLocationToAccountId = xcm_config::RemoteLocationToAccountId;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type SovereignOrigin = EnsureRoot<AccountId>;
+    type MaxCallSize = ConstU32<1024>;
+    type WeightInfo = pallet_remote_transact::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_reserve_transfer_filter::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type MaxAllowedDestinations = ConstU32<64>;
+    type WeightInfo = pallet_reserve_transfer_filter::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_lockdrop_call_whitelist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type MaxAllowedCalls = ConstU32<64>;
+    type WeightInfo = pallet_lockdrop_call_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// Index of the `hrmp` pallet in the relay chain runtime.
+    pub const RelayHrmpPalletIndex: u8 = 60;
+    // 1 ROC
+    pub const HrmpRelayFee: u128 = 1_000_000_000_000;
+    pub const HrmpRelayTransactWeight: Weight = Weight::from_parts(4_000_000_000, 64 * 1024);
+}
+
+impl pallet_hrmp_manager::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type XcmSender = xcm_config::XcmRouter;
+    type SelfParaId = ParachainInfo;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type HrmpPalletIndex = RelayHrmpPalletIndex;
+    type RelayFee = HrmpRelayFee;
+    type RelayTransactWeight = HrmpRelayTransactWeight;
+    type WeightInfo = pallet_hrmp_manager::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_xcm_monitor::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type MaxChannelsPerBlock = ConstU32<32>;
+    type HistoryDepth = ConstU32<16>;
+    type WeightInfo = pallet_xcm_monitor::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
-    // 2 storage items with values 20 and 32
-    pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // 3 storage items with values 20, 32 and 5 (claim metadata)
+    pub const AccountMappingStorageFee: u128 = deposit(3, 32 + 20 + 5);
 }
 
 impl pallet_unified_accounts::Config for Runtime {
@@ -1193,24 +1465,53 @@ impl pallet_unified_accounts::Config for Runtime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = EVMChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type MaxSecondaryAddresses = ConstU32<4>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
 parameter_types! {
     // Of course it's not true for Shibuya, but SBY is worthless, a test token.
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    // Native currency must always be tracked.
+    pub TrackedCurrencies: sp_runtime::BoundedVec<CurrencyId, ConstU32<4>> =
+        sp_runtime::BoundedVec::truncate_from(vec![CurrencyId::ASTR, CurrencyId::DOT, CurrencyId::ETH]);
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = DAYS;
-}
+    pub const PriceOutlierThreshold: Perbill = Perbill::from_percent(50);
+    // Native price is considered stale if no valid value is received for 6 hours.
+    pub const PriceStalenessThreshold: BlockNumber = 6 * HOURS;
+    // Report the stale native price as an error, so dApp staking preserves its tier configuration.
+    pub const OnStalePrice: StalePriceBehavior = StalePriceBehavior::Error;
+}
+
+/// Complete set of the oracle feeders, including the struck out ones.
+pub type OracleFeeders = astar_primitives::oracle::OracleMembers<
+    OracleMembership,
+    ActiveCollators,
+    OracleMembershipSource,
+>;
 
 impl pallet_price_aggregator::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<4>;
+    type TrackedCurrencies = TrackedCurrencies;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    // ~20 minutes
+    type TwapWindow = ConstU32<100>;
+    // ~30 minutes
+    type PriceHistoryLength = ConstU32<300>;
+    type OutlierThreshold = PriceOutlierThreshold;
+    type StalenessThreshold = PriceStalenessThreshold;
+    type OnStalePrice = OnStalePrice;
+    type MaxOutlierStrikes = ConstU32<10>;
+    type OnStruckOut = ();
+    type Feeders = OracleFeeders;
+    type ReinstateOrigin = EnsureRootOrHalfMainCouncil;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1232,6 +1533,19 @@ parameter_types! {
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 }
 
+parameter_types! {
+    // Oracle feeders are managed manually, via the oracle membership pallet.
+    pub const OracleMembershipSource: OracleMembershipMode = OracleMembershipMode::Manual;
+}
+
+/// Active collators of the current session, used to derive the oracle feeder set.
+pub struct ActiveCollators;
+impl Get<Vec<AccountId>> for ActiveCollators {
+    fn get() -> Vec<AccountId> {
+        pallet_session::Validators::<Runtime>::get()
+    }
+}
+
 impl orml_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnNewData = PriceAggregator;
@@ -1243,13 +1557,13 @@ impl orml_oracle::Config for Runtime {
     #[cfg(feature = "runtime-benchmarks")]
     type Members = OracleMembershipWrapper;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type Members = OracleMembership;
+    type Members = pallet_price_aggregator::ExcludeStruckOut<Runtime, OracleFeeders>;
     type MaxHasDispatchedSize = ConstU32<8>;
     type WeightInfo = weights::orml_oracle::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type MaxFeedValues = ConstU32<2>;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type MaxFeedValues = ConstU32<1>;
+    type MaxFeedValues = ConstU32<4>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = OracleBenchmarkHelper;
 }
@@ -1285,6 +1599,69 @@ impl frame_support::traits::SortedMembers<AccountId> for OracleMembershipWrapper
     }
 }
 
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as sp_runtime::traits::Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<(
+        RuntimeCall,
+        <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload,
+    )> {
+        let period = BlockHashCount::get()
+            .checked_next_power_of_two()
+            .map(|c| c / 2)
+            .unwrap_or(2) as u64;
+        let current_block = u64::from(System::block_number()).saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+        );
+
+        let raw_payload = SignedPayload::new(call, extra).ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, extra, _) = raw_payload.deconstruct();
+        let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+
+        Some((call, (address, signature, extra)))
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl pallet_price_feeder::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    // Feeder keys must belong to the oracle members, otherwise submissions are rejected by the oracle.
+    type AuthorityId = pallet_price_feeder::crypto::FeederAuthId;
+    type FeedOrigin = EnsureRootOrHalfMainCouncil;
+    type MaxEndpoints = ConstU32<5>;
+    type MaxUrlLength = ConstU32<256>;
+    // ~2 minutes
+    type SubmissionInterval = ConstU32<10>;
+    type WeightInfo = pallet_price_feeder::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const CouncilMaxMembers: u32 = 16;
     pub const TechnicalCommitteeMaxMembers: u32 = 8;
@@ -1519,10 +1896,19 @@ impl InstanceFilter<RuntimeCall> for CommunityCouncilCallFilter {
     }
 }
 
+parameter_types! {
+    /// EVM controller contract approved to act on behalf of the community council through the
+    /// collective proxy precompile. `None` until such contract is approved by governance.
+    pub const CommunityCouncilEvmController: Option<H160> = None;
+}
+
 impl pallet_collective_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureRootOrHalfCommunityCouncil;
+    type CollectiveProxy = EitherOfDiverse<
+        EnsureRootOrHalfCommunityCouncil,
+        EnsureEvmController<Runtime, CommunityCouncilEvmController>,
+    >;
     type ProxyAccountId = CommunityTreasuryAccountId;
     type CallFilter = CommunityCouncilCallFilter;
     type WeightInfo = pallet_collective_proxy::weights::SubstrateWeight<Runtime>;
@@ -1615,6 +2001,7 @@ construct_runtime!(
         TransactionPayment: pallet_transaction_payment = 30,
         Balances: pallet_balances = 31,
         Vesting: pallet_vesting = 32,
+        PriceFeeder: pallet_price_feeder = 33,
         DappStaking: pallet_dapp_staking = 34,
         Inflation: pallet_inflation = 35,
         Assets: pallet_assets = 36,
@@ -1628,6 +2015,10 @@ construct_runtime!(
         Aura: pallet_aura = 43,
         AuraExt: cumulus_pallet_aura_ext = 44,
 
+        Nfts: pallet_nfts = 46,
+        Uniques: pallet_uniques = 47,
+        HrmpManager: pallet_hrmp_manager = 48,
+        XcmMonitor: pallet_xcm_monitor = 49,
         XcmpQueue: cumulus_pallet_xcmp_queue = 50,
         PolkadotXcm: pallet_xcm = 51,
         CumulusXcm: cumulus_pallet_xcm = 52,
@@ -1635,6 +2026,9 @@ construct_runtime!(
         XcAssetConfig: pallet_xc_asset_config = 54,
         XTokens: orml_xtokens = 55,
         MessageQueue: pallet_message_queue = 56,
+        MessageQueuePause: pallet_message_queue_pause = 57,
+        RemoteTransact: pallet_remote_transact = 58,
+        ReserveTransferFilter: pallet_reserve_transfer_filter = 59,
 
         EVM: pallet_evm = 60,
         Ethereum: pallet_ethereum = 61,
@@ -1642,6 +2036,7 @@ construct_runtime!(
         EVMChainId: pallet_evm_chain_id = 63,
         EthereumChecked: pallet_ethereum_checked = 64,
         UnifiedAccounts: pallet_unified_accounts = 65,
+        LockdropCallWhitelist: pallet_lockdrop_call_whitelist = 66,
 
         Contracts: pallet_contracts = 70,
 
@@ -1712,7 +2107,10 @@ pub type Executive = frame_executive::Executive<
 pub type Migrations = (Unreleased, Permanent);
 
 /// Unreleased migrations. Add new ones here:
-pub type Unreleased = ();
+pub type Unreleased = (
+    pallet_price_aggregator::migration::versioned_migrations::V1ToV2<Runtime>,
+    pallet_xc_asset_config::migrations::versioned::V3ToV4<Runtime>,
+);
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
 pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
@@ -1803,12 +2201,21 @@ mod benches {
         [pallet_unified_accounts, UnifiedAccounts]
         [xcm_benchmarks_generic, XcmGeneric]
         [xcm_benchmarks_fungible, XcmFungible]
+        [pallet_message_queue_pause, MessageQueuePause]
+        [pallet_remote_transact, RemoteTransact]
+        [pallet_reserve_transfer_filter, ReserveTransferFilter]
+        [pallet_lockdrop_call_whitelist, LockdropCallWhitelist]
+        [pallet_xcm_monitor, XcmMonitor]
+        [pallet_hrmp_manager, HrmpManager]
         [pallet_price_aggregator, PriceAggregator]
         [pallet_collective_proxy, CollectiveProxy]
         [orml_oracle, Oracle]
+        [pallet_price_feeder, PriceFeeder]
         [vesting_mbm, VestingMBM]
         [pallet_tx_pause, TxPause]
         [pallet_safe_mode, SafeMode]
+        [astar_precompile_benchmarks, PrecompileBenchmarks::<Runtime>]
+        [astar_chain_extension_benchmarks, ChainExtensionBenchmarks::<Runtime>]
     );
 }
 
@@ -2301,6 +2708,162 @@ impl_runtime_apis! {
         }
     }
 
+    impl collective_proxy_runtime_api::CollectiveProxyApi<Block, AccountId> for Runtime {
+        fn proxy_permissions() -> Vec<collective_proxy_runtime_api::ProxyPermissions<AccountId>> {
+            vec![CollectiveProxy::permissions()]
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block> for Runtime {
+        fn base_fee_per_gas() -> U256 {
+            pallet_dynamic_evm_base_fee::BaseFeePerGas::<Runtime>::get()
+        }
+
+        fn projected_base_fee_per_gas(blocks: u32) -> Vec<U256> {
+            DynamicEvmBaseFee::projected_base_fee_per_gas(blocks)
+        }
+    }
+
+    impl ethereum_checked_runtime_api::EthereumCheckedApi<Block, AccountId> for Runtime {
+        fn nonce(account: AccountId) -> U256 {
+            EthereumChecked::account_nonce(&account)
+        }
+    }
+
+    impl price_aggregator_runtime_api::PriceApi<Block> for Runtime {
+        fn price(currency: CurrencyId) -> Option<price_aggregator_runtime_api::AggregatedPrice> {
+            PriceAggregator::aggregated_price(currency)
+        }
+
+        fn prices() -> Vec<(CurrencyId, price_aggregator_runtime_api::AggregatedPrice)> {
+            PriceAggregator::aggregated_prices()
+        }
+
+        fn price_at(currency: CurrencyId, block: BlockNumber) -> Option<(BlockNumber, Price)> {
+            PriceAggregator::price_at(currency, block)
+        }
+
+        fn price_history(currency: CurrencyId) -> Vec<(BlockNumber, Price)> {
+            PriceAggregator::price_history(currency)
+        }
+    }
+
+    impl xc_asset_config_runtime_api::XcAssetConfigApi<Block, AssetId> for Runtime {
+        fn registered_assets() -> Vec<(AssetId, VersionedLocation, u128, bool)> {
+            XcAssetConfig::registered_assets()
+        }
+
+        fn registered_asset(asset_id: AssetId) -> Option<(AssetId, VersionedLocation, u128, bool)> {
+            XcAssetConfig::registered_asset(asset_id)
+        }
+    }
+
+    impl unified_trace_runtime_api::UnifiedTraceApi<Block, RuntimeEvent> for Runtime {
+        fn extrinsic_trace(extrinsic_index: u32) -> ExtrinsicTrace<RuntimeEvent> {
+            let statuses = pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get().unwrap_or_default();
+            let receipts = pallet_ethereum::CurrentReceipts::<Runtime>::get().unwrap_or_default();
+
+            let mut trace = ExtrinsicTrace::new(extrinsic_index);
+            for (event_index, record) in System::read_events_no_consensus().enumerate() {
+                if record.phase != frame_system::Phase::ApplyExtrinsic(extrinsic_index) {
+                    continue;
+                }
+                let event_index = event_index as u32;
+
+                match &record.event {
+                    RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => {
+                        trace.evm_logs.push(EvmLogRecord { event_index, log: log.clone() });
+                    }
+                    RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed { transaction_hash, .. }) => {
+                        if let Some(idx) = statuses.iter().position(|status| status.transaction_hash == *transaction_hash) {
+                            trace.ethereum_transactions.push(EthereumTransactionRecord {
+                                event_index,
+                                status: statuses[idx].clone(),
+                                receipt: receipts.get(idx).cloned(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+
+                trace.events.push((event_index, record.event));
+            }
+
+            trace
+        }
+    }
+
+    impl block_diagnostics_runtime_api::BlockDiagnosticsApi<Block> for Runtime {
+        fn block_diagnostics(max_extrinsics: u32) -> BlockDiagnostics {
+            let extrinsics = System::read_events_no_consensus()
+                .filter_map(|record| match (record.phase, &record.event) {
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicSuccess { dispatch_info }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, true)),
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicFailed { dispatch_info, .. }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, false)),
+                    _ => None,
+                })
+                .collect();
+
+            BlockDiagnostics::new(
+                System::block_weight(),
+                RuntimeBlockWeights::get().max_block,
+                extrinsics,
+                max_extrinsics,
+            )
+        }
+    }
+
+    impl event_index_runtime_api::EventIndexApi<Block, AccountId> for Runtime {
+        fn indexed_events() -> Vec<IndexedEvent<AccountId>> {
+            System::read_events_no_consensus()
+                .enumerate()
+                .filter_map(|(event_index, record)| {
+                    let (category, accounts) = match &record.event {
+                        RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, to, .. })
+                        | RuntimeEvent::Assets(pallet_assets::Event::Transferred { from, to, .. }) => {
+                            (EventCategory::Transfer, vec![from.clone(), to.clone()])
+                        }
+                        RuntimeEvent::DappStaking(event) => (EventCategory::DappStaking, event.accounts()),
+                        RuntimeEvent::XTokens(orml_xtokens::Event::TransferredAssets { sender, .. }) => {
+                            (EventCategory::Xcm, vec![sender.clone()])
+                        }
+                        RuntimeEvent::PolkadotXcm(_)
+                        | RuntimeEvent::XcmpQueue(_)
+                        | RuntimeEvent::XcmMonitor(_)
+                        | RuntimeEvent::XTokens(_) => (EventCategory::Xcm, Vec::new()),
+                        _ => return None,
+                    };
+
+                    Some(IndexedEvent {
+                        event_index: event_index as u32,
+                        extrinsic_index: match record.phase {
+                            frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+                            _ => None,
+                        },
+                        category,
+                        accounts,
+                        event: record.event.encode(),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    impl unified_identity_runtime_api::UnifiedIdentityApi<Block, AccountId, IdentityRegistration> for Runtime {
+        fn identity_of(account_id: AccountId) -> UnifiedIdentity<AccountId, IdentityRegistration> {
+            unified_identity_of(account_id)
+        }
+
+        fn identity_of_evm_address(evm_address: H160) -> Option<UnifiedIdentity<AccountId, IdentityRegistration>> {
+            pallet_unified_accounts::EvmToNative::<Runtime>::get(evm_address).map(unified_identity_of)
+        }
+    }
+
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
         fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
             if !matches!(xcm_version, xcm::v3::VERSION | xcm::v4::VERSION) {
@@ -2333,7 +2896,8 @@ impl_runtime_apis! {
             }
             // for foreign assets with “units per second” configurations
             else {
-                let versioned_location = VersionedLocation::V4(asset_id.0);
+                // xc-asset-config stores locations in the latest XCM version
+                let versioned_location = VersionedLocation::from(asset_id.0);
 
                 match pallet_xc_asset_config::AssetLocationUnitsPerSecond::<Runtime>::get(versioned_location) {
                     Some(units_per_sec) => {
@@ -2374,9 +2938,7 @@ impl_runtime_apis! {
         }
 
         fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-            vec![
-                sp_genesis_builder::PresetId::from("development"),
-            ]
+            genesis_config::preset_names()
         }
     }
 
@@ -2389,6 +2951,8 @@ impl_runtime_apis! {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
             use frame_support::traits::StorageInfoTrait;
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use astar_precompile_benchmarks::Pallet as PrecompileBenchmarks;
+            use astar_chain_extension_benchmarks::Pallet as ChainExtensionBenchmarks;
             use frame_system_benchmarking::Pallet as SystemBench;
             use baseline::Pallet as BaselineBench;
 
@@ -2419,6 +2983,8 @@ impl_runtime_apis! {
             impl frame_system_benchmarking::Config for Runtime {}
             impl baseline::Config for Runtime {}
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use astar_precompile_benchmarks::Pallet as PrecompileBenchmarks;
+            use astar_chain_extension_benchmarks::Pallet as ChainExtensionBenchmarks;
 
             pub struct TestDeliveryHelper;
             impl xcm_builder::EnsureDelivery for TestDeliveryHelper {
@@ -2575,6 +3141,7 @@ impl_runtime_apis! {
                             RuntimeOrigin::root(),
                             Box::new(TransactAssetLocation::get().into_versioned()),
                             TransactAssetId::get(),
+                            None,
                         )
                     );
 
@@ -18,26 +18,61 @@
 
 //! The Astar Network EVM precompiles. This can be compiled with ``#[no_std]`, ready for Wasm.
 
-use crate::{RuntimeCall, UnifiedAccounts};
-use astar_primitives::precompiles::DispatchFilterValidate;
-use frame_support::traits::ConstU32;
-use frame_support::{parameter_types, traits::Contains};
+use crate::{
+    LockdropCallWhitelist, MaxAdditionalFields, ProxyType, RandomnessCollectiveFlip, Runtime,
+    RuntimeCall, ShibuyaAssetLocationIdConverter, UnifiedAccounts, MILLISECS_PER_BLOCK,
+};
+use astar_primitives::{
+    governance::{CommunityTreasuryInst, MainTreasuryInst},
+    precompiles::DispatchFilterValidate,
+};
+use frame_support::traits::{ConstU32, ConstU64};
+use frame_support::{
+    parameter_types,
+    traits::{Contains, Get, InstanceFilter},
+};
 use pallet_evm_precompile_assets_erc20::Erc20AssetsPrecompileSet;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
-use pallet_evm_precompile_dapp_staking::DappStakingV3Precompile;
+use pallet_evm_precompile_collator_selection::{
+    CollatorSelectionPrecompile, CollatorSelectionPrecompileCall,
+};
+use pallet_evm_precompile_collective_proxy::{
+    CollectiveProxyPrecompile, CollectiveProxyPrecompileCall,
+};
+use pallet_evm_precompile_dapp_staking::{DappStakingV3Precompile, DappStakingV3PrecompileCall};
+use pallet_evm_precompile_derivative::{DerivativePrecompile, DerivativePrecompileCall};
 use pallet_evm_precompile_dispatch::Dispatch;
-use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
+use pallet_evm_precompile_dispatch_lockdrop::{DispatchLockdrop, DispatchLockdropCall};
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_governance::{GovernancePrecompile, GovernancePrecompileCall};
+use pallet_evm_precompile_identity::{IdentityPrecompile, IdentityPrecompileCall};
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_multisig::{MultisigPrecompile, MultisigPrecompileCall};
+use pallet_evm_precompile_price_aggregator::{
+    PriceAggregatorPrecompile, PriceAggregatorPrecompileCall,
+};
+use pallet_evm_precompile_randomness::{RandomnessPrecompile, RandomnessPrecompileCall};
+use pallet_evm_precompile_registry::{
+    PrecompileInterface, PrecompileInterfaces, PrecompileRegistry, PrecompileRegistryCall,
+};
+use pallet_evm_precompile_runtime_info::{RuntimeInfoPrecompile, RuntimeInfoPrecompileCall};
+use pallet_evm_precompile_scheduler::{SchedulerPrecompile, SchedulerPrecompileCall};
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
-use pallet_evm_precompile_sr25519::Sr25519Precompile;
-use pallet_evm_precompile_substrate_ecdsa::SubstrateEcdsaPrecompile;
-use pallet_evm_precompile_unified_accounts::UnifiedAccountsPrecompile;
-use pallet_evm_precompile_xcm::XcmPrecompile;
+use pallet_evm_precompile_sr25519::{Sr25519Precompile, Sr25519PrecompileCall};
+use pallet_evm_precompile_substrate_ecdsa::{
+    SubstrateEcdsaPrecompile, SubstrateEcdsaPrecompileCall,
+};
+use pallet_evm_precompile_treasury::{TreasuryPrecompile, TreasuryPrecompileCall};
+use pallet_evm_precompile_unified_accounts::{
+    UnifiedAccountsPrecompile, UnifiedAccountsPrecompileCall,
+};
+use pallet_evm_precompile_uniques_erc721::Erc721UniquesPrecompileSet;
+use pallet_evm_precompile_xcm::{XcmPrecompile, XcmPrecompileCall};
 use precompile_utils::precompile_set::*;
-use sp_std::fmt::Debug;
+use sp_core::H160;
+use sp_std::{fmt::Debug, vec::Vec};
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
@@ -46,6 +81,13 @@ parameter_types! {
     pub AssetPrefix: &'static [u8] = ASSET_PRECOMPILE_ADDRESS_PREFIX;
 }
 
+/// The NFT collection precompile address prefix. Addresses that match against this prefix will be
+/// routed to Erc721UniquesPrecompileSet
+pub const NFT_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8, 255u8, 255u8, 254u8];
+parameter_types! {
+    pub NftPrefix: &'static [u8] = NFT_PRECOMPILE_ADDRESS_PREFIX;
+}
+
 /// Precompile checks for ethereum spec precompiles
 /// We allow DELEGATECALL to stay compliant with Ethereum behavior.
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
@@ -81,9 +123,9 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     fn contains(t: &RuntimeCall) -> bool {
         match t {
             RuntimeCall::Utility(pallet_utility::Call::batch { calls })
-            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
-                .iter()
-                .all(|call| WhitelistedLockdropCalls::contains(call)),
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().all(|call| LockdropCalls::contains(call))
+            }
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::unbond_and_unstake { .. }) => true,
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::withdraw_unbonded { .. }) => true,
             RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
@@ -95,6 +137,180 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     }
 }
 
+/// Calls allowed to pass through dispatch-lockdrop precompile, either statically whitelisted,
+/// or whitelisted by governance
+pub type LockdropCalls = (WhitelistedLockdropCalls, LockdropCallWhitelist);
+
+/// Filter that only allows whitelisted runtime call to be scheduled via scheduler precompile
+pub struct WhitelistedSchedulerCalls;
+
+impl Contains<RuntimeCall> for WhitelistedSchedulerCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedSchedulerCalls::contains(call)),
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_staker_rewards {
+                ..
+            }) => true,
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_bonus_reward { .. }) => true,
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::claim_dapp_reward { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that only allows whitelisted proposals to be submitted via governance precompile
+pub struct WhitelistedGovernanceProposals;
+
+impl Contains<RuntimeCall> for WhitelistedGovernanceProposals {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedGovernanceProposals::contains(call)),
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. }) => true,
+            RuntimeCall::Treasury(pallet_treasury::Call::spend_local { .. }) => true,
+            RuntimeCall::CommunityTreasury(pallet_treasury::Call::spend_local { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that only allows whitelisted calls to be dispatched by the multisig via multisig precompile
+pub struct WhitelistedMultisigCalls;
+
+impl Contains<RuntimeCall> for WhitelistedMultisigCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        match t {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
+                .iter()
+                .all(|call| WhitelistedMultisigCalls::contains(call)),
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) => true,
+            RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) => true,
+            RuntimeCall::DappStaking(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Filter that allows the derivative accounts to dispatch the same calls as the `NonTransfer`,
+/// `Balances` and `Assets` proxies can, via derivative precompile
+pub struct DerivativeCalls;
+
+impl Contains<RuntimeCall> for DerivativeCalls {
+    fn contains(t: &RuntimeCall) -> bool {
+        [
+            ProxyType::NonTransfer,
+            ProxyType::Balances,
+            ProxyType::Assets,
+        ]
+        .iter()
+        .any(|proxy_type| proxy_type.filter(t))
+    }
+}
+
+/// Interfaces of the Shibuya runtime precompiles, reported by the registry precompile.
+///
+/// UPDATE this once new precompile is added, or its interface is changed.
+pub struct ShibuyaPrecompileInterfaces;
+
+impl PrecompileInterfaces for ShibuyaPrecompileInterfaces {
+    fn interface(address: H160) -> Option<PrecompileInterface> {
+        // All the precompiles with an interface are at the low addresses
+        if address != H160::from_low_u64_be(address.to_low_u64_be()) {
+            return None;
+        }
+
+        let (version, selectors) = match address.to_low_u64_be() {
+            20481 => (3, DappStakingV3PrecompileCall::<Runtime>::selectors()),
+            20482 => (1, Sr25519PrecompileCall::<Runtime>::selectors()),
+            20483 => (1, SubstrateEcdsaPrecompileCall::<Runtime>::selectors()),
+            20484 => (
+                2,
+                XcmPrecompileCall::<Runtime, ShibuyaAssetLocationIdConverter>::selectors(),
+            ),
+            20486 => (
+                1,
+                UnifiedAccountsPrecompileCall::<Runtime, UnifiedAccounts>::selectors(),
+            ),
+            20487 => (
+                1,
+                DispatchLockdropCall::<
+                    Runtime,
+                    DispatchFilterValidate<RuntimeCall, LockdropCalls>,
+                    ConstU32<8>,
+                >::selectors(),
+            ),
+            20488 => (1, CollatorSelectionPrecompileCall::<Runtime>::selectors()),
+            20489 => (
+                1,
+                PriceAggregatorPrecompileCall::<
+                    Runtime,
+                    ConstU64<MILLISECS_PER_BLOCK>,
+                >::selectors(),
+            ),
+            20490 => (
+                1,
+                SchedulerPrecompileCall::<
+                    Runtime,
+                    WhitelistedSchedulerCalls,
+                    ConstU32<100>,
+                >::selectors(),
+            ),
+            20491 => (
+                1,
+                GovernancePrecompileCall::<Runtime, WhitelistedGovernanceProposals>::selectors(),
+            ),
+            20493 => (
+                1,
+                MultisigPrecompileCall::<Runtime, WhitelistedMultisigCalls>::selectors(),
+            ),
+            20494 => (
+                1,
+                DerivativePrecompileCall::<Runtime, DerivativeCalls>::selectors(),
+            ),
+            20495 => (
+                1,
+                PrecompileRegistryCall::<Runtime, ShibuyaPrecompileInterfaces>::selectors(),
+            ),
+            20496 => (
+                1,
+                RandomnessPrecompileCall::<Runtime, RandomnessCollectiveFlip>::selectors(),
+            ),
+            20497 => (
+                1,
+                RuntimeInfoPrecompileCall::<Runtime, ShibuyaEnabledPrecompiles>::selectors(),
+            ),
+            20498 => (1, TreasuryPrecompileCall::<Runtime, MainTreasuryInst>::selectors()),
+            20499 => (1, TreasuryPrecompileCall::<Runtime, CommunityTreasuryInst>::selectors()),
+            20500 => (1, IdentityPrecompileCall::<Runtime, MaxAdditionalFields>::selectors()),
+            20501 => (1, CollectiveProxyPrecompileCall::<Runtime, ConstU32<8>>::selectors()),
+            _ => return None,
+        };
+
+        Some(PrecompileInterface::new(version, selectors))
+    }
+}
+
+/// Addresses of the Shibuya runtime precompiles, reported by the runtime info precompile.
+pub struct ShibuyaEnabledPrecompiles;
+impl Get<Vec<H160>> for ShibuyaEnabledPrecompiles {
+    fn get() -> Vec<H160> {
+        ShibuyaPrecompiles::<Runtime, ShibuyaAssetLocationIdConverter>::used_addresses_h160()
+            .collect()
+    }
+}
+
 /// The PrecompileSet installed in the Shibuya runtime.
 #[precompile_utils::precompile_name_from_address]
 pub type ShibuyaPrecompilesSetAt<R, C> = (
@@ -152,14 +368,76 @@ pub type ShibuyaPrecompilesSetAt<R, C> = (
     >,
     PrecompileAt<
         AddressU64<20487>,
-        DispatchLockdrop<
-            R,
-            DispatchFilterValidate<RuntimeCall, WhitelistedLockdropCalls>,
-            ConstU32<8>,
-        >,
+        DispatchLockdrop<R, DispatchFilterValidate<RuntimeCall, LockdropCalls>, ConstU32<8>>,
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    PrecompileAt<
+        AddressU64<20488>,
+        CollatorSelectionPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20489>,
+        PriceAggregatorPrecompile<R, ConstU64<MILLISECS_PER_BLOCK>>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20490>,
+        SchedulerPrecompile<R, WhitelistedSchedulerCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20491>,
+        GovernancePrecompile<R, WhitelistedGovernanceProposals>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    // Skipping 20492 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20493>,
+        MultisigPrecompile<R, WhitelistedMultisigCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20494>,
+        DerivativePrecompile<R, DerivativeCalls>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20495>,
+        PrecompileRegistry<R, ShibuyaPrecompileInterfaces>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20496>,
+        RandomnessPrecompile<R, RandomnessCollectiveFlip>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20497>,
+        RuntimeInfoPrecompile<R, ShibuyaEnabledPrecompiles>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20498>,
+        TreasuryPrecompile<R, MainTreasuryInst>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20499>,
+        TreasuryPrecompile<R, CommunityTreasuryInst>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20500>,
+        IdentityPrecompile<R, MaxAdditionalFields>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20501>,
+        CollectiveProxyPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type ShibuyaPrecompiles<R, C> = PrecompileSetBuilder<
@@ -173,5 +451,11 @@ pub type ShibuyaPrecompiles<R, C> = PrecompileSetBuilder<
         >,
         // Prefixed precompile sets (XC20)
         PrecompileSetStartingWith<AssetPrefix, Erc20AssetsPrecompileSet<R>, CallableByContract>,
+        // Prefixed precompile sets (ERC721 Uniques collections)
+        PrecompileSetStartingWith<
+            NftPrefix,
+            Erc721UniquesPrecompileSet<R, UnifiedAccounts>,
+            CallableByContract,
+        >,
     ),
 >;
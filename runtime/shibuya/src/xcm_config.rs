@@ -17,10 +17,11 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, DealWithFees,
-    MessageQueue, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
-    RuntimeOrigin, ShibuyaAssetLocationIdConverter, TreasuryAccountId, XcAssetConfig,
-    XcmWeightToFee, XcmpQueue,
+    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, CollectionId,
+    DealWithFees, HrmpManager, ItemId, MessageQueue, ParachainInfo, ParachainSystem, PolkadotXcm,
+    ReserveTransferFilter, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+    ShibuyaAssetLocationIdConverter, ShibuyaNftCollectionLocationIdConverter, TreasuryAccountId,
+    Uniques, XcAssetConfig, XcmWeightToFee, XcmpQueue,
 };
 use crate::weights;
 use frame_support::{
@@ -39,12 +40,12 @@ use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowUnpaidExecutionFrom,
-    ConvertedConcreteId, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin,
-    FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete,
-    NoChecking, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
-    SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-    SovereignSignedViaLocation, TakeWeightCredit, UsingComponents, WeightInfoBounds,
-    WithComputedOrigin,
+    ChildParachainConvertsVia, ConvertedConcreteId, DescribeAllTerminal, DescribeFamily,
+    EnsureXcmOrigin, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter,
+    HashedDescription, IsConcrete, NoChecking, NonFungiblesAdapter, ParentAsSuperuser,
+    ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+    UsingComponents, WeightInfoBounds, WithComputedOrigin,
 };
 use xcm_executor::{traits::JustTry, XcmExecutor};
 
@@ -54,8 +55,10 @@ use orml_xcm_support::DisabledParachainFee;
 // Astar imports
 use astar_primitives::xcm::{
     AbsoluteAndRelativeReserveProvider, AccountIdToMultiLocation, AllowTopLevelPaidExecutionFrom,
+    EthereumAssetReserveFilter, EthereumAwareReserveProvider, ExcludeFrozenAssets,
     FixedRateOfForeignAsset, ReserveAssetFilter, XcmFungibleFeeHandler, MAX_ASSETS,
 };
+use pallet_xc_asset_config::FrozenXcAssets;
 
 parameter_types! {
     pub RelayNetwork: Option<NetworkId> = Some(NetworkId::Rococo);
@@ -64,6 +67,10 @@ parameter_types! {
     [GlobalConsensus(RelayNetwork::get().unwrap()), Parachain(ParachainInfo::parachain_id().into())].into();
     pub const ShibuyaLocation: Location = Here.into_location();
     pub DummyCheckingAccount: AccountId = PolkadotXcm::check_account();
+    /// Ethereum network whose assets are bridged into the ecosystem (e.g. via Snowbridge).
+    pub EthereumNetwork: NetworkId = NetworkId::Ethereum { chain_id: 11155111 };
+    /// Asset Hub acts as the reserve for the bridged Ethereum assets.
+    pub AssetHubLocation: Location = Location::new(1, [Parachain(1000)]);
 }
 
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
@@ -81,6 +88,17 @@ pub type LocationToAccountId = (
     HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
+/// Type for specifying how remote chains (relay chain, sibling parachains) convert a `Location` into an `AccountId`.
+/// Used to compute accounts derived from the local accounts on remote chains.
+pub type RemoteLocationToAccountId = (
+    // This chain, as seen from the relay chain, converts to its sovereign account.
+    ChildParachainConvertsVia<ParaId, AccountId>,
+    // This chain, as seen from a sibling parachain, converts to its sovereign account.
+    SiblingParachainConvertsVia<polkadot_parachain::primitives::Sibling, AccountId>,
+    // Accounts of this chain convert to the hash of their location description.
+    HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+);
+
 /// Means for transacting the native currency on this chain.
 pub type CurrencyTransactor = FungibleAdapter<
     // Use this currency:
@@ -99,8 +117,11 @@ pub type CurrencyTransactor = FungibleAdapter<
 pub type FungiblesTransactor = FungiblesAdapter<
     // Use this fungibles implementation:
     Assets,
-    // Use this currency when it is a fungible asset matching the given location or name:
-    ConvertedConcreteId<AssetId, Balance, ShibuyaAssetLocationIdConverter, JustTry>,
+    // Use this currency when it is a fungible asset matching the given location or name, and isn't frozen:
+    ExcludeFrozenAssets<
+        ConvertedConcreteId<AssetId, Balance, ShibuyaAssetLocationIdConverter, JustTry>,
+        FrozenXcAssets<Runtime>,
+    >,
     // Convert an XCM Location into a local account id:
     LocationToAccountId,
     // Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -111,8 +132,28 @@ pub type FungiblesTransactor = FungiblesAdapter<
     DummyCheckingAccount,
 >;
 
+/// Means for transacting non-fungible assets, i.e. items of the registered cross-chain NFT collections.
+pub type NonFungiblesTransactor = NonFungiblesAdapter<
+    // Use this non-fungibles implementation:
+    Uniques,
+    // Use this collection when it's a non-fungible asset matching the registered collection location:
+    ConvertedConcreteId<CollectionId, ItemId, ShibuyaNftCollectionLocationIdConverter, JustTry>,
+    // Convert an XCM Location into a local account id:
+    LocationToAccountId,
+    // Our chain's account ID type (we can't get away without mentioning it explicitly):
+    AccountId,
+    // We don't support teleport so no need to check any assets.
+    NoChecking,
+    // We don't support teleport so no checking account is needed.
+    (),
+>;
+
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
+pub type AssetTransactors = (
+    CurrencyTransactor,
+    FungiblesTransactor,
+    NonFungiblesTransactor,
+);
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
@@ -183,7 +224,10 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    type IsReserve = (
+        ReserveAssetFilter,
+        EthereumAssetReserveFilter<EthereumNetwork, AssetHubLocation>,
+    );
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
@@ -209,9 +253,9 @@ impl xcm_executor::Config for XcmConfig {
     type Aliasers = Nothing;
     type TransactionalProcessor = FrameTransactionalProcessor;
 
-    type HrmpNewChannelOpenRequestHandler = ();
-    type HrmpChannelAcceptedHandler = ();
-    type HrmpChannelClosingHandler = ();
+    type HrmpNewChannelOpenRequestHandler = HrmpManager;
+    type HrmpChannelAcceptedHandler = HrmpManager;
+    type HrmpChannelClosingHandler = HrmpManager;
     type XcmRecorder = PolkadotXcm;
 }
 
@@ -220,12 +264,15 @@ pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, R
 
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
-pub type XcmRouter = (
-    // Two routers - use UMP to communicate with the relay chain:
-    cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
-    // ..and XCMP to communicate with the sibling chains.
-    XcmpQueue,
-);
+pub type XcmRouter = pallet_xcm_monitor::MonitoredRouter<
+    Runtime,
+    (
+        // Two routers - use UMP to communicate with the relay chain:
+        cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
+        // ..and XCMP to communicate with the sibling chains.
+        XcmpQueue,
+    ),
+>;
 
 impl pallet_xcm::Config for Runtime {
     const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
@@ -308,8 +355,12 @@ impl orml_xtokens::Config for Runtime {
     type MaxAssetsForTransfer = MaxAssetsForTransfer;
     // Default impl. Refer to `orml-xtokens` docs for more details.
     type MinXcmFee = DisabledParachainFee;
-    type LocationsFilter = Everything;
-    type ReserveProvider = AbsoluteAndRelativeReserveProvider<ShibuyaLocationAbsolute>;
+    type LocationsFilter = ReserveTransferFilter;
+    type ReserveProvider = EthereumAwareReserveProvider<
+        EthereumNetwork,
+        AssetHubLocation,
+        AbsoluteAndRelativeReserveProvider<ShibuyaLocationAbsolute>,
+    >;
     type RateLimiter = ();
     type RateLimiterId = ();
 }
@@ -20,11 +20,17 @@ use super::Runtime;
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
-use pallet_contracts::chain_extension::RegisteredChainExtension;
-
-// Following impls defines chain extension IDs.
-impl RegisteredChainExtension<Runtime> for AssetsExtension<Runtime> {
-    const ID: u16 = 02;
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
+pub use pallet_chain_extension_price_aggregator::PriceAggregatorExtension;
+pub use pallet_chain_extension_xcm::XcmExtension;
+
+astar_primitives::chain_extensions! {
+    Runtime;
+    pub type ShidenChainExtensions = {
+        Assets => AssetsExtension<Runtime>,
+        PriceAggregator => PriceAggregatorExtension<Runtime>,
+        DappStaking => DappStakingExtension<Runtime>,
+        // Contracts can only execute the messages `pallet-xcm` allows to be executed.
+        Xcm => XcmExtension<Runtime, <Runtime as pallet_xcm::Config>::XcmExecuteFilter>,
+    };
 }
-
-pub type ShidenChainExtensions<Runtime> = AssetsExtension<Runtime>;
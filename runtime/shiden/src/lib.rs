@@ -80,18 +80,25 @@ use astar_primitives::{
         AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, EraNumber,
         PeriodNumber, RankedTier, SmartContract, TierSlots as TierSlotsFunc,
     },
-    evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler},
+    evm::{BlockGasUsed, EVMFungibleAdapterWrapper, EvmRevertCodeHandler, ToBlockAuthor},
     governance::OracleMembershipInst,
-    oracle::{CurrencyAmount, CurrencyId, DummyCombineData, Price},
-    xcm::AssetLocationIdConverter,
-    Address, AssetId, BlockNumber, Hash, Header, Nonce, UnfreezeChainOnFailedMigration,
+    oracle::{
+        CurrencyAmount, CurrencyId, DummyCombineData, OracleMembershipMode, Price,
+        StalePriceBehavior,
+    },
+    xcm::{AssetLocationIdConverter, NftCollectionLocationIdConverter},
+    Address, AssetId, BlockNumber, CollectionId, Hash, Header, ItemId, Nonce,
+    UnfreezeChainOnFailedMigration,
 };
 pub use astar_primitives::{AccountId, Balance, Signature};
+use block_diagnostics_runtime_api::{BlockDiagnostics, ExtrinsicWeight};
+use event_index_runtime_api::{EventCategory, IndexedEvent};
+use unified_trace_runtime_api::{EthereumTransactionRecord, EvmLogRecord, ExtrinsicTrace};
 
 pub use pallet_dapp_staking::TierThreshold;
 pub use pallet_inflation::InflationParameters;
 
-pub use crate::precompiles::WhitelistedCalls;
+pub use crate::precompiles::{LockdropCalls, ShidenPrecompileInterfaces, WhitelistedCalls};
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
 
 #[cfg(any(feature = "std", test))]
@@ -112,6 +119,8 @@ mod weights;
 pub mod xcm_config;
 
 pub type ShidenAssetLocationIdConverter = AssetLocationIdConverter<AssetId, XcAssetConfig>;
+pub type ShidenNftCollectionLocationIdConverter =
+    NftCollectionLocationIdConverter<CollectionId, XcAssetConfig>;
 
 pub use precompiles::{ShidenPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX};
 pub type Precompiles = ShidenPrecompiles<Runtime, ShidenAssetLocationIdConverter>;
@@ -241,12 +250,54 @@ impl Contains<RuntimeCall> for BaseFilter {
     fn contains(call: &RuntimeCall) -> bool {
         match call {
             // Filter permission-less assets creation/destroying.
-            // Custom asset's `id` should fit in `u32` as not to mix with service assets.
+            // Custom asset's `id` should fit in `u32` as not to mix with service assets,
+            // and mustn't belong to the range reserved for the cross-chain assets.
             RuntimeCall::Assets(method) => match method {
-                pallet_assets::Call::create { id, .. } => *id < (u32::MAX as AssetId).into(),
+                pallet_assets::Call::create { id, .. } => {
+                    *id < (u32::MAX as AssetId).into()
+                        && XcAssetConfig::is_local_asset_id_allowed(id.0)
+                }
 
                 _ => true,
             },
+            // Filter transfers of amounts below the asset's minimum cross-chain transfer amount,
+            // since such dust would be lost on the destination.
+            RuntimeCall::PolkadotXcm(method) => match method {
+                pallet_xcm::Call::reserve_transfer_assets { assets, .. }
+                | pallet_xcm::Call::limited_reserve_transfer_assets { assets, .. }
+                | pallet_xcm::Call::transfer_assets { assets, .. }
+                | pallet_xcm::Call::transfer_assets_using_type_and_then { assets, .. } => {
+                    XcAssetConfig::versioned_assets_meet_min_xcm_amount(assets)
+                }
+                _ => true,
+            },
+            // Filter transfers of amounts below the asset's minimum cross-chain transfer amount,
+            // since such dust would be lost on the destination.
+            RuntimeCall::XTokens(method) => match method {
+                orml_xtokens::Call::transfer {
+                    currency_id,
+                    amount,
+                    ..
+                }
+                | orml_xtokens::Call::transfer_with_fee {
+                    currency_id,
+                    amount,
+                    ..
+                } => XcAssetConfig::meets_min_xcm_amount(*currency_id, *amount),
+                orml_xtokens::Call::transfer_multicurrencies { currencies, .. } => {
+                    currencies.iter().all(|(currency_id, amount)| {
+                        XcAssetConfig::meets_min_xcm_amount(*currency_id, *amount)
+                    })
+                }
+                orml_xtokens::Call::transfer_multiasset { asset, .. }
+                | orml_xtokens::Call::transfer_multiasset_with_fee { asset, .. } => {
+                    XcAssetConfig::versioned_asset_meets_min_xcm_amount(asset)
+                }
+                orml_xtokens::Call::transfer_multiassets { assets, .. } => {
+                    XcAssetConfig::versioned_assets_meet_min_xcm_amount(assets)
+                }
+                _ => true,
+            },
             // These modules are not allowed to be called by transactions:
             // To leave collator just shutdown it, next session funds will be released
             // Other modules should works:
@@ -423,6 +474,7 @@ impl TierSlotsFunc for ShidenTierSlots {
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 50 * SDN;
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(0);
 }
 
 impl pallet_dapp_staking::Config for Runtime {
@@ -439,6 +491,7 @@ impl pallet_dapp_staking::Config for Runtime {
     type Observers = Inflation;
     type AccountCheck = AccountCheck;
     type TierSlots = ShidenTierSlots;
+    type SponsorshipPool = ();
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<3>;
@@ -450,6 +503,10 @@ impl pallet_dapp_staking::Config for Runtime {
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    // Owner loyalty bonus is disabled.
+    type OwnerLoyaltyPeriods = ConstU32<0>;
+    type OwnerLoyaltyMinimumStake = MinimumStakingAmount;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = weights::pallet_dapp_staking::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = DAppStakingBenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -684,6 +741,38 @@ impl pallet_assets::Config for Runtime {
     type BenchmarkHelper = astar_primitives::benchmarks::AssetsBenchmarkHelper;
 }
 
+parameter_types! {
+    pub const UniquesCollectionDeposit: Balance = 1 * SDN;
+    pub const UniquesItemDeposit: Balance = deposit(1, 48);
+    pub const UniquesMetadataDepositBase: Balance = deposit(1, 129);
+    pub const UniquesAttributeDepositBase: Balance = deposit(1, 0);
+    pub const UniquesDepositPerByte: Balance = deposit(0, 1);
+    pub const UniquesStringLimit: u32 = 128;
+    pub const UniquesKeyLimit: u32 = 32;
+    pub const UniquesValueLimit: u32 = 64;
+}
+
+impl pallet_uniques::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = CollectionId;
+    type ItemId = ItemId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = UniquesCollectionDeposit;
+    type ItemDeposit = UniquesItemDeposit;
+    type MetadataDepositBase = UniquesMetadataDepositBase;
+    type AttributeDepositBase = UniquesAttributeDepositBase;
+    type DepositPerByte = UniquesDepositPerByte;
+    type StringLimit = UniquesStringLimit;
+    type KeyLimit = UniquesKeyLimit;
+    type ValueLimit = UniquesValueLimit;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
 parameter_types! {
     pub const MinVestedTransfer: Balance = 1 * SDN;
     pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
@@ -733,7 +822,7 @@ impl pallet_contracts::Config for Runtime {
     type CallStack = [pallet_contracts::Frame<Self>; 5];
     type WeightPrice = pallet_transaction_payment::Pallet<Self>;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-    type ChainExtension = ShidenChainExtensions<Self>;
+    type ChainExtension = ShidenChainExtensions;
     type Schedule = Schedule;
     type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
     type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
@@ -842,6 +931,12 @@ parameter_types! {
     pub MinBaseFeePerGas: U256 = U256::from(8_000_000_000_u128);
     pub MaxBaseFeePerGas: U256 = U256::from(800_000_000_000_u128);
     pub StepLimitRatio: Perquintill = Perquintill::from_rational(5_u128, 100_000);
+    pub const EvmBaseFeeAdjustmentMode: pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode =
+        pallet_dynamic_evm_base_fee::BaseFeeAdjustmentMode::PriceBased;
+    /// Target gas usage of a block, aligned with the target block fullness of native transactions.
+    pub TargetBlockGas: U256 = U256::from(TargetBlockFullness::get() * BlockGasLimit::get().low_u64());
+    /// Share of the EVM base fee which is burned, the rest goes to collators.
+    pub DefaultEvmBaseFeeBurnRatio: Perbill = Perbill::from_percent(80);
 }
 
 /// Simple wrapper for fetching current native transaction fee weight fee multiplier.
@@ -860,6 +955,12 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type AdjustmentMode = EvmBaseFeeAdjustmentMode;
+    type BlockGasUsed = BlockGasUsed<Runtime>;
+    type TargetBlockGas = TargetBlockGas;
+    type DefaultBaseFeeBurnRatio = DefaultEvmBaseFeeBurnRatio;
+    type OnBaseFeeChanged = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -924,7 +1025,11 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesType = Precompiles;
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
-    type OnChargeTransaction = EVMFungibleAdapterWrapper<Balances, DealWithFees, CollatorRewardPot>;
+    type OnChargeTransaction = EVMFungibleAdapterWrapper<
+        Balances,
+        pallet_dynamic_evm_base_fee::BaseFeeSplitter<Runtime, Balances, CollatorRewardPot>,
+        ToBlockAuthor<Runtime, Balances, CollatorRewardPot>,
+    >;
     type BlockGasLimit = BlockGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
@@ -934,6 +1039,14 @@ impl pallet_evm::Config for Runtime {
     type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
 }
 
+impl astar_precompile_benchmarks::Config for Runtime {
+    type WeightInfo = astar_precompile_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
+impl astar_chain_extension_benchmarks::Config for Runtime {
+    type WeightInfo = astar_chain_extension_benchmarks::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
 }
@@ -956,6 +1069,10 @@ impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxBatchSize = ConstU32<32>;
+    type AssetRegistrar = astar_primitives::xcm::AssetsRegistrar<Runtime, TreasuryAccountId>;
+    type MaxReservedRanges = ConstU32<16>;
+    type CollectionId = CollectionId;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
@@ -1092,18 +1209,46 @@ impl pallet_proxy::Config for Runtime {
 
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::SDN;
+    // Native currency must always be tracked.
+    pub TrackedCurrencies: sp_runtime::BoundedVec<CurrencyId, ConstU32<4>> =
+        sp_runtime::BoundedVec::truncate_from(vec![CurrencyId::SDN, CurrencyId::DOT, CurrencyId::ETH]);
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = DAYS;
-}
+    pub const PriceOutlierThreshold: Perbill = Perbill::from_percent(50);
+    // Native price is considered stale if no valid value is received for 6 hours.
+    pub const PriceStalenessThreshold: BlockNumber = 6 * HOURS;
+    // Report the stale native price as an error, so dApp staking preserves its tier configuration.
+    pub const OnStalePrice: StalePriceBehavior = StalePriceBehavior::Error;
+}
+
+/// Complete set of the oracle feeders, including the struck out ones.
+pub type OracleFeeders = astar_primitives::oracle::OracleMembers<
+    OracleMembership,
+    ActiveCollators,
+    OracleMembershipSource,
+>;
 
 impl pallet_price_aggregator::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<4>;
+    type TrackedCurrencies = TrackedCurrencies;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    // ~20 minutes
+    type TwapWindow = ConstU32<100>;
+    // ~30 minutes
+    type PriceHistoryLength = ConstU32<300>;
+    type OutlierThreshold = PriceOutlierThreshold;
+    type StalenessThreshold = PriceStalenessThreshold;
+    type OnStalePrice = OnStalePrice;
+    type MaxOutlierStrikes = ConstU32<10>;
+    type OnStruckOut = ();
+    type Feeders = OracleFeeders;
+    type ReinstateOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1125,6 +1270,19 @@ parameter_types! {
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 }
 
+parameter_types! {
+    // Oracle feeders are managed manually, via the oracle membership pallet.
+    pub const OracleMembershipSource: OracleMembershipMode = OracleMembershipMode::Manual;
+}
+
+/// Active collators of the current session, used to derive the oracle feeder set.
+pub struct ActiveCollators;
+impl Get<Vec<AccountId>> for ActiveCollators {
+    fn get() -> Vec<AccountId> {
+        pallet_session::Validators::<Runtime>::get()
+    }
+}
+
 impl orml_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnNewData = PriceAggregator;
@@ -1136,7 +1294,7 @@ impl orml_oracle::Config for Runtime {
     #[cfg(feature = "runtime-benchmarks")]
     type Members = OracleMembershipWrapper;
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type Members = OracleMembership;
+    type Members = pallet_price_aggregator::ExcludeStruckOut<Runtime, OracleFeeders>;
     type MaxHasDispatchedSize = ConstU32<8>;
     type WeightInfo = weights::orml_oracle::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
@@ -1231,6 +1389,7 @@ construct_runtime!(
         Aura: pallet_aura = 43,
         AuraExt: cumulus_pallet_aura_ext = 44,
 
+        Uniques: pallet_uniques = 47,
         XcmpQueue: cumulus_pallet_xcmp_queue = 50,
         PolkadotXcm: pallet_xcm = 51,
         CumulusXcm: cumulus_pallet_xcm = 52,
@@ -1293,7 +1452,10 @@ pub type Executive = frame_executive::Executive<
 pub type Migrations = (Unreleased, Permanent);
 
 /// Unreleased migrations. Add new ones here:
-pub type Unreleased = ();
+pub type Unreleased = (
+    pallet_price_aggregator::migration::versioned_migrations::V1ToV2<Runtime>,
+    pallet_xc_asset_config::migrations::versioned::V3ToV4<Runtime>,
+);
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
 pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
@@ -1384,6 +1546,7 @@ mod benches {
         [xcm_benchmarks_fungible, XcmFungible]
         [pallet_price_aggregator, PriceAggregator]
         [orml_oracle, Oracle]
+        [astar_precompile_benchmarks, PrecompileBenchmarks::<Runtime>]
     );
 }
 
@@ -1876,6 +2039,139 @@ impl_runtime_apis! {
         }
     }
 
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block> for Runtime {
+        fn base_fee_per_gas() -> U256 {
+            pallet_dynamic_evm_base_fee::BaseFeePerGas::<Runtime>::get()
+        }
+
+        fn projected_base_fee_per_gas(blocks: u32) -> Vec<U256> {
+            DynamicEvmBaseFee::projected_base_fee_per_gas(blocks)
+        }
+    }
+
+    impl price_aggregator_runtime_api::PriceApi<Block> for Runtime {
+        fn price(currency: CurrencyId) -> Option<price_aggregator_runtime_api::AggregatedPrice> {
+            PriceAggregator::aggregated_price(currency)
+        }
+
+        fn prices() -> Vec<(CurrencyId, price_aggregator_runtime_api::AggregatedPrice)> {
+            PriceAggregator::aggregated_prices()
+        }
+
+        fn price_at(currency: CurrencyId, block: BlockNumber) -> Option<(BlockNumber, Price)> {
+            PriceAggregator::price_at(currency, block)
+        }
+
+        fn price_history(currency: CurrencyId) -> Vec<(BlockNumber, Price)> {
+            PriceAggregator::price_history(currency)
+        }
+    }
+
+    impl xc_asset_config_runtime_api::XcAssetConfigApi<Block, AssetId> for Runtime {
+        fn registered_assets() -> Vec<(AssetId, VersionedLocation, u128, bool)> {
+            XcAssetConfig::registered_assets()
+        }
+
+        fn registered_asset(asset_id: AssetId) -> Option<(AssetId, VersionedLocation, u128, bool)> {
+            XcAssetConfig::registered_asset(asset_id)
+        }
+    }
+
+    impl unified_trace_runtime_api::UnifiedTraceApi<Block, RuntimeEvent> for Runtime {
+        fn extrinsic_trace(extrinsic_index: u32) -> ExtrinsicTrace<RuntimeEvent> {
+            let statuses = pallet_ethereum::CurrentTransactionStatuses::<Runtime>::get().unwrap_or_default();
+            let receipts = pallet_ethereum::CurrentReceipts::<Runtime>::get().unwrap_or_default();
+
+            let mut trace = ExtrinsicTrace::new(extrinsic_index);
+            for (event_index, record) in System::read_events_no_consensus().enumerate() {
+                if record.phase != frame_system::Phase::ApplyExtrinsic(extrinsic_index) {
+                    continue;
+                }
+                let event_index = event_index as u32;
+
+                match &record.event {
+                    RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => {
+                        trace.evm_logs.push(EvmLogRecord { event_index, log: log.clone() });
+                    }
+                    RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed { transaction_hash, .. }) => {
+                        if let Some(idx) = statuses.iter().position(|status| status.transaction_hash == *transaction_hash) {
+                            trace.ethereum_transactions.push(EthereumTransactionRecord {
+                                event_index,
+                                status: statuses[idx].clone(),
+                                receipt: receipts.get(idx).cloned(),
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+
+                trace.events.push((event_index, record.event));
+            }
+
+            trace
+        }
+    }
+
+    impl block_diagnostics_runtime_api::BlockDiagnosticsApi<Block> for Runtime {
+        fn block_diagnostics(max_extrinsics: u32) -> BlockDiagnostics {
+            let extrinsics = System::read_events_no_consensus()
+                .filter_map(|record| match (record.phase, &record.event) {
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicSuccess { dispatch_info }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, true)),
+                    (
+                        frame_system::Phase::ApplyExtrinsic(index),
+                        RuntimeEvent::System(frame_system::Event::ExtrinsicFailed { dispatch_info, .. }),
+                    ) => Some(ExtrinsicWeight::new(index, dispatch_info, false)),
+                    _ => None,
+                })
+                .collect();
+
+            BlockDiagnostics::new(
+                System::block_weight(),
+                RuntimeBlockWeights::get().max_block,
+                extrinsics,
+                max_extrinsics,
+            )
+        }
+    }
+
+    impl event_index_runtime_api::EventIndexApi<Block, AccountId> for Runtime {
+        fn indexed_events() -> Vec<IndexedEvent<AccountId>> {
+            System::read_events_no_consensus()
+                .enumerate()
+                .filter_map(|(event_index, record)| {
+                    let (category, accounts) = match &record.event {
+                        RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, to, .. })
+                        | RuntimeEvent::Assets(pallet_assets::Event::Transferred { from, to, .. }) => {
+                            (EventCategory::Transfer, vec![from.clone(), to.clone()])
+                        }
+                        RuntimeEvent::DappStaking(event) => (EventCategory::DappStaking, event.accounts()),
+                        RuntimeEvent::XTokens(orml_xtokens::Event::TransferredAssets { sender, .. }) => {
+                            (EventCategory::Xcm, vec![sender.clone()])
+                        }
+                        RuntimeEvent::PolkadotXcm(_)
+                        | RuntimeEvent::XcmpQueue(_)
+                        | RuntimeEvent::XTokens(_) => (EventCategory::Xcm, Vec::new()),
+                        _ => return None,
+                    };
+
+                    Some(IndexedEvent {
+                        event_index: event_index as u32,
+                        extrinsic_index: match record.phase {
+                            frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+                            _ => None,
+                        },
+                        category,
+                        accounts,
+                        event: record.event.encode(),
+                    })
+                })
+                .collect()
+        }
+    }
+
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
         fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
             if !matches!(xcm_version, xcm::v3::VERSION | xcm::v4::VERSION) {
@@ -1908,7 +2204,8 @@ impl_runtime_apis! {
             }
             // for foreign assets with “units per second” configurations
             else {
-                let versioned_location = VersionedLocation::V4(asset_id.0);
+                // xc-asset-config stores locations in the latest XCM version
+                let versioned_location = VersionedLocation::from(asset_id.0);
 
                 match pallet_xc_asset_config::AssetLocationUnitsPerSecond::<Runtime>::get(versioned_location) {
                     Some(units_per_sec) => {
@@ -1949,9 +2246,7 @@ impl_runtime_apis! {
         }
 
         fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-            vec![
-                sp_genesis_builder::PresetId::from("development"),
-            ]
+            genesis_config::preset_names()
         }
     }
 
@@ -1964,6 +2259,7 @@ impl_runtime_apis! {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
             use frame_support::traits::StorageInfoTrait;
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use astar_precompile_benchmarks::Pallet as PrecompileBenchmarks;
             use frame_system_benchmarking::Pallet as SystemBench;
             use baseline::Pallet as BaselineBench;
 
@@ -1992,6 +2288,7 @@ impl_runtime_apis! {
             use xcm_builder::MintLocation;
             use astar_primitives::benchmarks::XcmBenchmarkHelper;
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use astar_precompile_benchmarks::Pallet as PrecompileBenchmarks;
 
             pub struct TestDeliveryHelper;
             impl xcm_builder::EnsureDelivery for TestDeliveryHelper {
@@ -2152,6 +2449,7 @@ impl_runtime_apis! {
                             RuntimeOrigin::root(),
                             Box::new(TransactAssetLocation::get().into_versioned()),
                             TransactAssetId::get(),
+                            None,
                         )
                     );
 
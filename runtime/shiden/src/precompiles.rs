@@ -18,25 +18,43 @@
 
 //! The Shiden Network EVM precompiles. This can be compiled with ``#[no_std]`, ready for Wasm.
 
-use crate::RuntimeCall;
+use crate::{
+    MaxAdditionalFields, RandomnessCollectiveFlip, Runtime, RuntimeCall,
+    ShidenAssetLocationIdConverter, MILLISECS_PER_BLOCK,
+};
 use astar_primitives::precompiles::DispatchFilterValidate;
-use frame_support::pallet_prelude::ConstU32;
-use frame_support::{parameter_types, traits::Contains};
+use frame_support::pallet_prelude::{ConstU32, ConstU64};
+use frame_support::{
+    parameter_types,
+    traits::{Contains, Get},
+};
 use pallet_evm_precompile_assets_erc20::Erc20AssetsPrecompileSet;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
-use pallet_evm_precompile_dapp_staking::DappStakingV3Precompile;
+use pallet_evm_precompile_dapp_staking::{DappStakingV3Precompile, DappStakingV3PrecompileCall};
 use pallet_evm_precompile_dispatch::Dispatch;
-use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
+use pallet_evm_precompile_dispatch_lockdrop::{DispatchLockdrop, DispatchLockdropCall};
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_identity::{IdentityPrecompile, IdentityPrecompileCall};
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_price_aggregator::{
+    PriceAggregatorPrecompile, PriceAggregatorPrecompileCall,
+};
+use pallet_evm_precompile_randomness::{RandomnessPrecompile, RandomnessPrecompileCall};
+use pallet_evm_precompile_registry::{
+    PrecompileInterface, PrecompileInterfaces, PrecompileRegistry, PrecompileRegistryCall,
+};
+use pallet_evm_precompile_runtime_info::{RuntimeInfoPrecompile, RuntimeInfoPrecompileCall};
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
-use pallet_evm_precompile_sr25519::Sr25519Precompile;
-use pallet_evm_precompile_substrate_ecdsa::SubstrateEcdsaPrecompile;
-use pallet_evm_precompile_xcm::XcmPrecompile;
+use pallet_evm_precompile_sr25519::{Sr25519Precompile, Sr25519PrecompileCall};
+use pallet_evm_precompile_substrate_ecdsa::{
+    SubstrateEcdsaPrecompile, SubstrateEcdsaPrecompileCall,
+};
+use pallet_evm_precompile_xcm::{XcmPrecompile, XcmPrecompileCall};
 use precompile_utils::precompile_set::*;
-use sp_std::fmt::Debug;
+use sp_core::H160;
+use sp_std::{fmt::Debug, vec::Vec};
 
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
@@ -74,9 +92,9 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     fn contains(t: &RuntimeCall) -> bool {
         match t {
             RuntimeCall::Utility(pallet_utility::Call::batch { calls })
-            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => calls
-                .iter()
-                .all(|call| WhitelistedLockdropCalls::contains(call)),
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().all(|call| LockdropCalls::contains(call))
+            }
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::unbond_and_unstake { .. }) => true,
             RuntimeCall::DappStaking(pallet_dapp_staking::Call::withdraw_unbonded { .. }) => true,
             RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => true,
@@ -88,6 +106,74 @@ impl Contains<RuntimeCall> for WhitelistedLockdropCalls {
     }
 }
 
+/// Calls allowed to pass through dispatch-lockdrop precompile
+pub type LockdropCalls = WhitelistedLockdropCalls;
+
+/// Interfaces of the Shiden runtime precompiles, reported by the registry precompile.
+///
+/// UPDATE this once new precompile is added, or its interface is changed.
+pub struct ShidenPrecompileInterfaces;
+
+impl PrecompileInterfaces for ShidenPrecompileInterfaces {
+    fn interface(address: H160) -> Option<PrecompileInterface> {
+        // All the precompiles with an interface are at the low addresses
+        if address != H160::from_low_u64_be(address.to_low_u64_be()) {
+            return None;
+        }
+
+        let (version, selectors) = match address.to_low_u64_be() {
+            20481 => (3, DappStakingV3PrecompileCall::<Runtime>::selectors()),
+            20482 => (1, Sr25519PrecompileCall::<Runtime>::selectors()),
+            20483 => (1, SubstrateEcdsaPrecompileCall::<Runtime>::selectors()),
+            20484 => (
+                2,
+                XcmPrecompileCall::<Runtime, ShidenAssetLocationIdConverter>::selectors(),
+            ),
+            20487 => (
+                1,
+                DispatchLockdropCall::<
+                    Runtime,
+                    DispatchFilterValidate<RuntimeCall, LockdropCalls>,
+                    ConstU32<8>,
+                >::selectors(),
+            ),
+            20489 => (
+                1,
+                PriceAggregatorPrecompileCall::<Runtime, ConstU64<MILLISECS_PER_BLOCK>>::selectors(
+                ),
+            ),
+            20495 => (
+                1,
+                PrecompileRegistryCall::<Runtime, ShidenPrecompileInterfaces>::selectors(),
+            ),
+            20496 => (
+                1,
+                RandomnessPrecompileCall::<Runtime, RandomnessCollectiveFlip>::selectors(),
+            ),
+            20497 => (
+                1,
+                RuntimeInfoPrecompileCall::<Runtime, ShidenEnabledPrecompiles>::selectors(),
+            ),
+            20500 => (
+                1,
+                IdentityPrecompileCall::<Runtime, MaxAdditionalFields>::selectors(),
+            ),
+            _ => return None,
+        };
+
+        Some(PrecompileInterface::new(version, selectors))
+    }
+}
+
+/// Addresses of the Shiden runtime precompiles, reported by the runtime info precompile.
+pub struct ShidenEnabledPrecompiles;
+impl Get<Vec<H160>> for ShidenEnabledPrecompiles {
+    fn get() -> Vec<H160> {
+        ShidenPrecompiles::<Runtime, ShidenAssetLocationIdConverter>::used_addresses_h160()
+            .collect()
+    }
+}
+
 /// The PrecompileSet installed in the Shiden runtime.
 #[precompile_utils::precompile_name_from_address]
 pub type ShidenPrecompilesSetAt<R, C> = (
@@ -141,14 +227,38 @@ pub type ShidenPrecompilesSetAt<R, C> = (
     // precompiles address
     PrecompileAt<
         AddressU64<20487>,
-        DispatchLockdrop<
-            R,
-            DispatchFilterValidate<RuntimeCall, WhitelistedLockdropCalls>,
-            ConstU32<8>,
-        >,
+        DispatchLockdrop<R, DispatchFilterValidate<RuntimeCall, LockdropCalls>, ConstU32<8>>,
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    // Skipping 20488 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20489>,
+        PriceAggregatorPrecompile<R, ConstU64<MILLISECS_PER_BLOCK>>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    // Skipping 20490 to 20494 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20495>,
+        PrecompileRegistry<R, ShidenPrecompileInterfaces>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20496>,
+        RandomnessPrecompile<R, RandomnessCollectiveFlip>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<20497>,
+        RuntimeInfoPrecompile<R, ShidenEnabledPrecompiles>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    // Skipping 20498 and 20499 to make sure all network have consistent precompiles address
+    PrecompileAt<
+        AddressU64<20500>,
+        IdentityPrecompile<R, MaxAdditionalFields>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type ShidenPrecompiles<R, C> = PrecompileSetBuilder<
@@ -157,7 +267,7 @@ pub type ShidenPrecompiles<R, C> = PrecompileSetBuilder<
         // Skip precompiles if out of range.
         PrecompilesInRangeInclusive<
             // We take range as last precompile index, UPDATE this once new prcompile is added
-            (AddressU64<1>, AddressU64<20487>),
+            (AddressU64<1>, AddressU64<20500>),
             ShidenPrecompilesSetAt<R, C>,
         >,
         // Prefixed precompile sets (XC20)
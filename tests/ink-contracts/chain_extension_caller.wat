@@ -0,0 +1,35 @@
+;; Forwards the call input to a chain extension, and returns its return value as `Result<u32, ()>`.
+;; The input is the `u32` chain extension `func_id`, followed by the chain extension input.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "call_chain_extension" (func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) input buffer length
+	;; [4, 8) output buffer length
+	;; [11, 16) return value, `Ok` variant index followed by the `u32` chain extension result
+	;; [16, 4112) input buffer
+	;; [8192, 12288) output buffer
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(i32.store (i32.const 0) (i32.const 4096))
+		(call $seal_input (i32.const 16) (i32.const 0))
+
+		(i32.store (i32.const 4) (i32.const 4096))
+		(i32.store
+			(i32.const 12)
+			(call $call_chain_extension
+				(i32.load (i32.const 16))
+				(i32.const 20)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 8192)
+				(i32.const 4)
+			)
+		)
+
+		(call $seal_return (i32.const 0) (i32.const 11) (i32.const 5))
+	)
+)
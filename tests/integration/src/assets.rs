@@ -19,6 +19,9 @@
 use crate::setup::*;
 
 use astar_primitives::evm::EVM_REVERT_CODE;
+use frame_support::traits::fungibles::{
+    metadata::Inspect as MetadataInspect, roles::Inspect as RolesInspect, Inspect,
+};
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
 
 #[test]
@@ -79,3 +82,40 @@ fn asset_create_fails_if_account_code_is_non_empty() {
         );
     });
 }
+
+#[test]
+fn register_asset_location_with_metadata_creates_asset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = 19;
+        let asset_location = xcm::v4::Location::parent().into_versioned();
+        let metadata = pallet_xc_asset_config::AssetMetadata {
+            name: b"Relay Token".to_vec(),
+            symbol: b"RLY".to_vec(),
+            decimals: 10,
+            min_balance: 1_000,
+        };
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            asset_id,
+            Some(metadata),
+        ));
+
+        // Both the local asset & the location mapping must exist
+        assert_eq!(
+            pallet_xc_asset_config::AssetIdToLocation::<Runtime>::get(asset_id),
+            Some(asset_location)
+        );
+        assert_eq!(Assets::owner(asset_id), Some(TreasuryAccountId::get()));
+        assert_eq!(Assets::minimum_balance(asset_id), 1_000);
+        assert_eq!(Assets::name(asset_id), b"Relay Token".to_vec());
+        assert_eq!(Assets::symbol(asset_id), b"RLY".to_vec());
+        assert_eq!(Assets::decimals(asset_id), 10);
+
+        // EVM revert code is set for the created asset, same as for any other asset
+        assert!(pallet_evm::AccountCodes::<Runtime>::contains_key(
+            &Runtime::asset_id_to_address(asset_id)
+        ));
+    })
+}
@@ -0,0 +1,86 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use block_diagnostics_runtime_api::runtime_decl_for_block_diagnostics_api::BlockDiagnosticsApiV1;
+use frame_support::dispatch::{DispatchClass, DispatchInfo};
+use sp_runtime::DispatchError;
+
+#[test]
+fn block_diagnostics_reports_heaviest_extrinsics() {
+    new_test_ext().execute_with(|| {
+        System::reset_events();
+        System::note_finished_initialize();
+
+        let light = Weight::from_parts(1_000_000, 1_000);
+        let heavy_ref_time = Weight::from_parts(500_000_000, 1_000);
+        let heavy_proof_size = Weight::from_parts(1_000_000, 500_000);
+
+        // 3 extrinsics, with the 2nd one failing
+        System::note_applied_extrinsic(
+            &Ok(().into()),
+            DispatchInfo {
+                weight: light,
+                ..Default::default()
+            },
+        );
+        System::note_applied_extrinsic(
+            &Err(DispatchError::BadOrigin.into()),
+            DispatchInfo {
+                weight: heavy_ref_time,
+                class: DispatchClass::Operational,
+                ..Default::default()
+            },
+        );
+        System::note_applied_extrinsic(
+            &Ok(().into()),
+            DispatchInfo {
+                weight: heavy_proof_size,
+                ..Default::default()
+            },
+        );
+
+        let diagnostics = Runtime::block_diagnostics(2);
+        assert_eq!(diagnostics.extrinsics_count, 3);
+        assert_eq!(diagnostics.consumed, System::block_weight());
+        assert_eq!(
+            diagnostics.max_block,
+            <Runtime as frame_system::Config>::BlockWeights::get().max_block
+        );
+
+        // only the two heaviest extrinsics are reported, the lightest one is left out
+        assert_eq!(diagnostics.heaviest_extrinsics.len(), 2);
+        assert!(diagnostics
+            .heaviest_extrinsics
+            .iter()
+            .all(|extrinsic| extrinsic.extrinsic_index != 0));
+
+        let failed = diagnostics
+            .heaviest_extrinsics
+            .iter()
+            .find(|extrinsic| extrinsic.extrinsic_index == 1)
+            .expect("Heavy extrinsic must be reported.");
+        assert!(!failed.success);
+        assert_eq!(failed.class, DispatchClass::Operational);
+        assert_eq!(failed.weight, heavy_ref_time);
+
+        // no extrinsics requested
+        assert!(Runtime::block_diagnostics(0).heaviest_extrinsics.is_empty());
+    });
+}
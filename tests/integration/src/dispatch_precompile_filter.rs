@@ -205,3 +205,41 @@ fn test_incorrect_dispatch_info_fails() {
         );
     })
 }
+
+#[test]
+#[cfg(feature = "shibuya")]
+fn lockdrop_filter_accepts_calls_whitelisted_by_governance() {
+    use parity_scale_codec::Encode;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let remark_call = RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: b"hello".to_vec(),
+        });
+        let batch_call = RuntimeCall::Utility(UtilityCall::batch {
+            calls: vec![remark_call.clone()],
+        });
+        assert!(!LockdropCalls::contains(&remark_call));
+        assert!(!LockdropCalls::contains(&batch_call));
+
+        // Whitelist the call by its pallet & call indices
+        let encoded_call = remark_call.encode();
+        assert_ok!(LockdropCallWhitelist::allow_call(
+            RuntimeOrigin::root(),
+            encoded_call[0],
+            encoded_call[1]
+        ));
+        assert!(LockdropCalls::contains(&remark_call));
+        assert!(LockdropCalls::contains(&batch_call));
+
+        // Statically whitelisted calls are still allowed
+        let withdraw_call = RuntimeCall::DappStaking(DappStakingCall::Call::withdraw_unbonded {});
+        assert!(LockdropCalls::contains(&withdraw_call));
+
+        assert_ok!(LockdropCallWhitelist::disallow_call(
+            RuntimeOrigin::root(),
+            encoded_call[0],
+            encoded_call[1]
+        ));
+        assert!(!LockdropCalls::contains(&remark_call));
+    });
+}
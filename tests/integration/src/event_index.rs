@@ -0,0 +1,60 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use event_index_runtime_api::{runtime_decl_for_event_index_api::EventIndexApiV1, EventCategory};
+use parity_scale_codec::Encode;
+
+#[test]
+fn event_index_reports_transfers() {
+    new_test_ext().execute_with(|| {
+        System::reset_events();
+        System::note_finished_initialize();
+
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            BOB.into(),
+            1_000
+        ));
+        // not a notable event, must be left out
+        assert_ok!(System::remark_with_event(
+            RuntimeOrigin::signed(ALICE),
+            b"remark".to_vec()
+        ));
+
+        let transfer_event = RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+            from: ALICE,
+            to: BOB,
+            amount: 1_000,
+        });
+
+        let indexed_events = Runtime::indexed_events();
+        assert_eq!(indexed_events.len(), 1);
+
+        let indexed_event = &indexed_events[0];
+        assert_eq!(indexed_event.extrinsic_index, Some(0));
+        assert_eq!(indexed_event.category, EventCategory::Transfer);
+        assert_eq!(indexed_event.accounts, vec![ALICE, BOB]);
+        assert_eq!(indexed_event.event, transfer_event.encode());
+        assert_eq!(
+            System::events()[indexed_event.event_index as usize].event,
+            transfer_event
+        );
+    });
+}
@@ -68,7 +68,7 @@ fn evm_fees_work() {
             Balances::total_issuance()
         );
 
-        // 3. Deposit the tip
+        // 3. Deposit the tip, block author is unknown so it falls back to the reward pot
         let issuance = Balances::total_issuance();
         let pot = Balances::free_balance(&pot_account);
         <EvmFeeHandler as OnChargeEVMTransaction<Runtime>>::pay_priority_fee(calculated_tip);
@@ -84,3 +84,45 @@ fn evm_fees_work() {
         );
     })
 }
+
+#[test]
+fn evm_tip_is_paid_to_block_author() {
+    new_test_ext().execute_with(|| {
+        let address = H160::repeat_byte(0xbe);
+        let mapped_address =
+            <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address);
+        Balances::make_free_balance_be(&mapped_address, 1_000_000_000_000_000);
+
+        type EvmFeeHandler = <Runtime as pallet_evm::Config>::OnChargeTransaction;
+
+        // Make ALICE the author of the current block
+        pallet_session::Validators::<Runtime>::put(vec![ALICE]);
+        assert_eq!(pallet_authorship::Pallet::<Runtime>::author(), Some(ALICE));
+
+        let (base_fee, tip, init_fee) = (500, 100, 1000);
+        let already_withdrawn = <EvmFeeHandler as OnChargeEVMTransaction<Runtime>>::withdraw_fee(
+            &address,
+            U256::from(init_fee),
+        )
+        .expect("Account is funded, must succeed.");
+        let calculated_tip =
+            <EvmFeeHandler as OnChargeEVMTransaction<Runtime>>::correct_and_deposit_fee(
+                &address,
+                U256::from(base_fee + tip),
+                U256::from(base_fee),
+                already_withdrawn,
+            );
+
+        let pot_account = PotId::get().into_account_truncating();
+        let pot = Balances::free_balance(&pot_account);
+        let author_balance = Balances::free_balance(&ALICE);
+        <EvmFeeHandler as OnChargeEVMTransaction<Runtime>>::pay_priority_fee(calculated_tip);
+
+        assert_eq!(author_balance + tip, Balances::free_balance(&ALICE));
+        assert_eq!(
+            pot,
+            Balances::free_balance(&pot_account),
+            "Pot should not receive the tip."
+        );
+    })
+}
@@ -35,6 +35,9 @@ mod dispatch_precompile_filter;
 #[cfg(feature = "shibuya")]
 mod unified_accounts;
 
+#[cfg(feature = "shibuya")]
+mod unified_identity;
+
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod dapp_staking;
 
@@ -50,8 +53,29 @@ mod evm_tracing;
 #[cfg(any(feature = "shibuya", feature = "astar"))]
 mod governance;
 
+#[cfg(any(feature = "shibuya", feature = "shiden"))]
+mod xcm_chain_extension;
+
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod xcm_api;
 
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod fees;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod unified_trace;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod block_diagnostics;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod event_index;
+
+#[cfg(feature = "shibuya")]
+mod reserve_transfer_filter;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod min_xcm_amount;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod precompile_registry;
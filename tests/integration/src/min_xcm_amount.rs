@@ -0,0 +1,126 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use frame_support::traits::Contains;
+use xcm::{
+    v4::{Asset, Junction, Location, WeightLimit},
+    VersionedAssets,
+};
+
+const ASSET_ID: u128 = 5;
+const MIN_XCM_AMOUNT: u128 = 1_000;
+
+fn asset_location() -> Location {
+    Location::new(1, Junction::Parachain(2000))
+}
+
+fn dest() -> Location {
+    Location::new(
+        1,
+        [
+            Junction::Parachain(2000),
+            Junction::AccountId32 {
+                network: None,
+                id: [1; 32],
+            },
+        ],
+    )
+}
+
+fn register_asset_with_min_xcm_amount() {
+    assert_ok!(XcAssetConfig::register_asset_location(
+        RuntimeOrigin::root(),
+        Box::new(asset_location().into_versioned()),
+        ASSET_ID,
+        None
+    ));
+    assert_ok!(XcAssetConfig::set_min_xcm_amount(
+        RuntimeOrigin::root(),
+        ASSET_ID,
+        Some(MIN_XCM_AMOUNT)
+    ));
+}
+
+fn xtokens_transfer_call(amount: u128) -> RuntimeCall {
+    RuntimeCall::XTokens(orml_xtokens::Call::transfer {
+        currency_id: ASSET_ID,
+        amount,
+        dest: Box::new(dest().into()),
+        dest_weight_limit: WeightLimit::Unlimited,
+    })
+}
+
+fn pallet_xcm_transfer_call(amount: u128) -> RuntimeCall {
+    RuntimeCall::PolkadotXcm(pallet_xcm::Call::transfer_assets {
+        dest: Box::new(Location::new(1, Junction::Parachain(2000)).into()),
+        beneficiary: Box::new(
+            Location::new(
+                0,
+                Junction::AccountId32 {
+                    network: None,
+                    id: [1; 32],
+                },
+            )
+            .into(),
+        ),
+        assets: Box::new(VersionedAssets::from(Asset::from((
+            asset_location(),
+            amount,
+        )))),
+        fee_asset_item: 0,
+        weight_limit: WeightLimit::Unlimited,
+    })
+}
+
+#[test]
+fn transfers_below_min_xcm_amount_are_filtered() {
+    new_test_ext().execute_with(|| {
+        register_asset_with_min_xcm_amount();
+
+        // `orml-xtokens` transfers
+        assert!(!BaseFilter::contains(&xtokens_transfer_call(
+            MIN_XCM_AMOUNT - 1
+        )));
+        assert!(BaseFilter::contains(&xtokens_transfer_call(MIN_XCM_AMOUNT)));
+
+        // `pallet-xcm` transfers
+        assert!(!BaseFilter::contains(&pallet_xcm_transfer_call(
+            MIN_XCM_AMOUNT - 1
+        )));
+        assert!(BaseFilter::contains(&pallet_xcm_transfer_call(
+            MIN_XCM_AMOUNT
+        )));
+    });
+}
+
+#[test]
+fn transfers_of_any_amount_are_allowed_without_min_xcm_amount() {
+    new_test_ext().execute_with(|| {
+        register_asset_with_min_xcm_amount();
+        assert_ok!(XcAssetConfig::set_min_xcm_amount(
+            RuntimeOrigin::root(),
+            ASSET_ID,
+            None
+        ));
+
+        assert!(BaseFilter::contains(&xtokens_transfer_call(1)));
+        assert!(BaseFilter::contains(&pallet_xcm_transfer_call(1)));
+    });
+}
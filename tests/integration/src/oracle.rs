@@ -30,7 +30,10 @@ fn price_submission_works() {
 
         // 0. Need to set limit block to something sensible, otherwise we'll waste time on many redundant iterations
         let limit_block = 10;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         // 1. Submit a price for a valid asset - the native currency
         let price_1 = Price::from_rational(15, 100);
@@ -50,7 +53,7 @@ fn price_submission_works() {
         run_for_blocks(1);
         let expected_average = (price_1 + price_2) * Price::from_rational(1, 2);
         assert_eq!(
-            IntermediateValueAggregator::<Runtime>::get().average(),
+            IntermediateValueAggregator::<Runtime>::get(native_currency_id).average(),
             expected_average
         );
 
@@ -82,7 +85,10 @@ fn price_submission_works() {
 
         // 5. Run until next limit block without any transactions, don't expect any changes
         let limit_block = limit_block * 2;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         run_to_block(limit_block + 1);
         assert_eq!(PriceAggregator::average_price(), expected_moving_average);
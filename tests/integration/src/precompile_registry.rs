@@ -0,0 +1,71 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use fp_evm::IsPrecompileResult;
+use pallet_evm::PrecompileSet;
+use pallet_evm_precompile_registry::PrecompileInterfaces;
+
+#[cfg(feature = "astar")]
+type RuntimePrecompileInterfaces = AstarPrecompileInterfaces;
+#[cfg(feature = "shiden")]
+type RuntimePrecompileInterfaces = ShidenPrecompileInterfaces;
+#[cfg(feature = "shibuya")]
+type RuntimePrecompileInterfaces = ShibuyaPrecompileInterfaces;
+
+/// Address of the registry precompile, same on all the networks.
+const REGISTRY_ADDRESS: u64 = 20495;
+
+fn is_active_precompile(address: H160) -> bool {
+    matches!(
+        PrecompilesValue::get().is_precompile(address, u64::MAX),
+        IsPrecompileResult::Answer {
+            is_precompile: true,
+            ..
+        }
+    )
+}
+
+#[test]
+fn registry_precompile_is_active_and_reports_itself() {
+    ExtBuilder::default().build().execute_with(|| {
+        let registry = H160::from_low_u64_be(REGISTRY_ADDRESS);
+        assert!(is_active_precompile(registry));
+
+        let interface = RuntimePrecompileInterfaces::interface(registry)
+            .expect("Registry exposes its own interface");
+        assert_eq!(interface.version, 1);
+        assert!(!interface.selectors.is_empty());
+    });
+}
+
+#[test]
+fn precompile_interfaces_only_reported_for_active_precompiles() {
+    ExtBuilder::default().build().execute_with(|| {
+        for index in 1..=REGISTRY_ADDRESS {
+            let address = H160::from_low_u64_be(index);
+            if RuntimePrecompileInterfaces::interface(address).is_some() {
+                assert!(
+                    is_active_precompile(address),
+                    "Interface reported for inactive precompile {}",
+                    index
+                );
+            }
+        }
+    });
+}
@@ -0,0 +1,98 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use frame_support::traits::Contains;
+use pallet_reserve_transfer_filter::FilterMode;
+use xcm::{
+    v4::{Asset, Junction, Location, WeightLimit},
+    VersionedAssets, VersionedLocation,
+};
+
+fn reserve_transfer_call(dest: Location) -> RuntimeCall {
+    RuntimeCall::PolkadotXcm(pallet_xcm::Call::limited_reserve_transfer_assets {
+        dest: Box::new(dest.into()),
+        beneficiary: Box::new(
+            Location::new(
+                0,
+                Junction::AccountId32 {
+                    network: None,
+                    id: [1; 32],
+                },
+            )
+            .into(),
+        ),
+        assets: Box::new(VersionedAssets::from(Asset::from((
+            Location::here(),
+            1_000_u128,
+        )))),
+        fee_asset_item: 0,
+        weight_limit: WeightLimit::Unlimited,
+    })
+}
+
+#[test]
+fn reserve_transfers_allowed_to_all_destinations_by_default() {
+    new_test_ext().execute_with(|| {
+        assert!(BaseFilter::contains(&reserve_transfer_call(Location::new(
+            1,
+            Junction::Parachain(2000)
+        ))));
+        assert!(ReserveTransferFilter::contains(&Location::parent()));
+    });
+}
+
+#[test]
+fn reserve_transfers_filtered_by_whitelist() {
+    new_test_ext().execute_with(|| {
+        let allowed_dest = Location::new(1, Junction::Parachain(2000));
+        let disallowed_dest = Location::new(1, Junction::Parachain(3000));
+
+        assert_ok!(ReserveTransferFilter::set_filter_mode(
+            RuntimeOrigin::root(),
+            FilterMode::Whitelist
+        ));
+        assert_ok!(ReserveTransferFilter::allow_destination(
+            RuntimeOrigin::root(),
+            Box::new(VersionedLocation::from(allowed_dest.clone()))
+        ));
+
+        // `pallet-xcm` transfers
+        assert!(BaseFilter::contains(&reserve_transfer_call(
+            allowed_dest.clone()
+        )));
+        assert!(!BaseFilter::contains(&reserve_transfer_call(
+            disallowed_dest.clone()
+        )));
+
+        // `orml-xtokens` transfers, destination includes the beneficiary
+        let beneficiary = Junction::AccountId32 {
+            network: None,
+            id: [1; 32],
+        };
+        assert!(ReserveTransferFilter::contains(&Location::new(
+            1,
+            [Junction::Parachain(2000), beneficiary.clone()]
+        )));
+        assert!(!ReserveTransferFilter::contains(&Location::new(
+            1,
+            [Junction::Parachain(3000), beneficiary]
+        )));
+    });
+}
@@ -0,0 +1,59 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use pallet_identity::{legacy::IdentityInfo, Data};
+use unified_identity_runtime_api::runtime_decl_for_unified_identity_api::UnifiedIdentityApiV1;
+
+#[test]
+fn unified_identity_exposes_evm_mappings() {
+    new_test_ext().execute_with(|| {
+        // no identity & no mappings
+        let unified_identity = Runtime::identity_of(ALICE);
+        assert_eq!(unified_identity.account_id, ALICE);
+        assert_eq!(unified_identity.evm_address, None);
+        assert!(unified_identity.secondary_evm_addresses.is_empty());
+        assert!(unified_identity.identity.is_none());
+        assert_eq!(Runtime::identity_of_evm_address(alith()), None);
+
+        // set identity & connect evm address
+        let info = IdentityInfo {
+            display: Data::Raw(b"Alice".to_vec().try_into().unwrap()),
+            ..Default::default()
+        };
+        assert_ok!(Identity::set_identity(
+            RuntimeOrigin::signed(ALICE),
+            Box::new(info.clone())
+        ));
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let unified_identity = Runtime::identity_of(ALICE);
+        assert_eq!(unified_identity.evm_address, Some(alith()));
+        assert_eq!(
+            unified_identity.identity.expect("Identity was set.").info,
+            info
+        );
+
+        // evm address resolves to the same identity
+        assert_eq!(
+            Runtime::identity_of_evm_address(alith()),
+            Some(unified_identity)
+        );
+    });
+}
@@ -0,0 +1,85 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use frame_support::dispatch::DispatchInfo;
+use sp_core::H256;
+use unified_trace_runtime_api::{
+    runtime_decl_for_unified_trace_api::UnifiedTraceApiV1, EvmLogRecord,
+};
+
+#[test]
+fn extrinsic_trace_correlates_substrate_events_and_evm_logs() {
+    new_test_ext().execute_with(|| {
+        System::reset_events();
+        System::note_finished_initialize();
+
+        // 1st extrinsic - native transfer & EVM log
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            MultiAddress::Id(BOB),
+            UNIT,
+        ));
+        let log = pallet_evm::Log {
+            address: H160::repeat_byte(0x01),
+            topics: vec![H256::repeat_byte(0x02)],
+            data: vec![1, 2, 3],
+        };
+        System::deposit_event(pallet_evm::Event::<Runtime>::Log { log: log.clone() });
+        System::note_applied_extrinsic(&Ok(().into()), DispatchInfo::default());
+
+        // 2nd extrinsic - native transfer only
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            MultiAddress::Id(ALICE),
+            UNIT,
+        ));
+        System::note_applied_extrinsic(&Ok(().into()), DispatchInfo::default());
+
+        let events_count = System::events().len() as u32;
+
+        // Verify 1st extrinsic trace
+        let trace = Runtime::extrinsic_trace(0);
+        assert_eq!(trace.extrinsic_index, 0);
+        assert!(trace.events.iter().any(|(_, event)| matches!(
+            event,
+            RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, .. }) if *from == ALICE
+        )));
+        assert_eq!(trace.evm_logs.len(), 1);
+        let EvmLogRecord {
+            event_index,
+            log: traced_log,
+        } = trace.evm_logs[0].clone();
+        assert_eq!(traced_log, log);
+        assert!(trace.events.iter().any(|(index, _)| *index == event_index));
+        assert!(trace.ethereum_transactions.is_empty());
+
+        // Verify 2nd extrinsic trace
+        let trace = Runtime::extrinsic_trace(1);
+        assert!(trace.evm_logs.is_empty());
+        assert!(trace.events.iter().all(|(index, _)| *index < events_count));
+        assert!(trace.events.iter().any(|(_, event)| matches!(
+            event,
+            RuntimeEvent::Balances(pallet_balances::Event::Transfer { from, .. }) if *from == BOB
+        )));
+
+        // Non-existing extrinsic has an empty trace
+        assert!(Runtime::extrinsic_trace(2).events.is_empty());
+    });
+}
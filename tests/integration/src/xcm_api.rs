@@ -25,7 +25,7 @@ use xcm::{
         Asset as XcmAsset, AssetId as XcmAssetId, Fungibility, Junction, Junctions::*, Location,
         Xcm, VERSION as V_4,
     },
-    VersionedLocation, VersionedXcm,
+    VersionedAssetId, VersionedLocation, VersionedXcm,
 };
 use xcm_runtime_apis::dry_run::runtime_decl_for_dry_run_api::DryRunApiV1;
 use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1;
@@ -48,6 +48,7 @@ fn prepare_asset(asset_id: u128, location: VersionedLocation, units_per_second:
         RuntimeOrigin::root(),
         Box::new(location.clone()),
         asset_id.into(),
+        None,
     ));
 
     // 3. Maybe set the units per second
@@ -153,6 +154,33 @@ fn query_weight_to_asset_fee_is_ok() {
     })
 }
 
+#[test]
+fn payment_api_supports_older_xcm_version() {
+    new_test_ext().execute_with(|| {
+        // Register the payable asset using an older XCM version location
+        let units_per_second = 1_000_000_000_000;
+        let v3_location = xcm::v3::MultiLocation::parent();
+        prepare_asset(
+            1,
+            VersionedLocation::V3(v3_location),
+            Some(units_per_second),
+        );
+        let v3_asset_id = VersionedAssetId::V3(xcm::v3::AssetId::Concrete(v3_location));
+
+        // Acceptable assets are returned in the requested version
+        let assets = Runtime::query_acceptable_payment_assets(xcm::v3::VERSION)
+            .expect("Must return at least native currency.");
+        assert_eq!(assets.len(), 2);
+        assert!(assets.contains(&v3_asset_id));
+
+        // Fee can be queried using the older version asset Id
+        let weight = Weight::from_parts(1_000_000_000, 1_000_000);
+        let fee = Runtime::query_weight_to_asset_fee(weight, v3_asset_id)
+            .expect("Must return fee for payable asset.");
+        assert_eq!(fee, XcAssetConfig::weight_to_fee(weight, units_per_second));
+    })
+}
+
 #[test]
 fn query_xcm_weight_is_ok() {
     new_test_ext().execute_with(|| {
@@ -287,3 +315,52 @@ fn xcm_recorder_configuration_is_ok() {
         );
     })
 }
+
+#[test]
+fn bridged_ethereum_asset_is_supported() {
+    use frame_support::traits::ContainsPair;
+
+    new_test_ext().execute_with(|| {
+        let ethereum_asset_location = Location::new(
+            2,
+            [
+                Junction::GlobalConsensus(xcm_config::EthereumNetwork::get()),
+                Junction::AccountKey20 {
+                    network: None,
+                    key: [7; 20],
+                },
+            ],
+        );
+        let units_per_second = 1_000_000_000_000;
+        prepare_asset(
+            1,
+            ethereum_asset_location.clone().into_versioned(),
+            Some(units_per_second),
+        );
+
+        // 1. Bridged asset can be used to pay for the execution
+        let weight = Weight::from_parts(1_000_000_000, 1_000_000);
+        let fee = Runtime::query_weight_to_asset_fee(
+            weight,
+            XcmAssetId(ethereum_asset_location.clone()).into(),
+        )
+        .expect("Must return fee for payable bridged asset.");
+        assert_eq!(fee, XcAssetConfig::weight_to_fee(weight, units_per_second));
+
+        // 2. Only Asset Hub is trusted as the reserve of the bridged asset
+        type IsReserve = <xcm_config::XcmConfig as xcm_executor::Config>::IsReserve;
+        let asset = XcmAsset {
+            id: XcmAssetId(ethereum_asset_location),
+            fun: Fungibility::Fungible(1_000),
+        };
+        assert!(IsReserve::contains(
+            &asset,
+            &xcm_config::AssetHubLocation::get()
+        ));
+        assert!(!IsReserve::contains(&asset, &Location::parent()));
+        assert!(!IsReserve::contains(
+            &asset,
+            &Location::new(1, Junction::Parachain(2000))
+        ));
+    })
+}
@@ -0,0 +1,81 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use astar_primitives::chain_extensions::ChainExtensionId;
+use parity_scale_codec::Encode;
+use xcm::{
+    v4::{
+        Instruction::{DepositAsset, WithdrawAsset},
+        Junction,
+        Junctions::Here,
+        WildAsset::AllCounted,
+        Xcm,
+    },
+    VersionedXcm,
+};
+use xcm_chain_extension_types::{Command, Outcome};
+
+const CHAIN_EXTENSION_CALLER: &'static str = "chain_extension_caller";
+
+/// Execute the given message via the XCM chain extension, from the given contract.
+fn execute_via_chain_extension(contract_id: AccountId32, message: Xcm<RuntimeCall>) -> u32 {
+    let func_id = (ChainExtensionId::Xcm as u32) << 16 | Command::Execute as u32;
+    call_wasm_contract_method::<u32>(
+        ALICE,
+        contract_id,
+        [
+            func_id.encode(),
+            VersionedXcm::from(message).encode(),
+            Weight::from_parts(1_000_000_000, 64 * 1024).encode(),
+        ]
+        .concat(),
+    )
+}
+
+#[test]
+fn messages_filtered_by_pallet_xcm_cannot_be_executed() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CHAIN_EXTENSION_CALLER);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            contract_id.clone().into(),
+            1_000 * UNIT,
+        ));
+        let bob_balance = Balances::free_balance(&BOB);
+
+        // Transfer of the contract funds, not allowed by the runtime `XcmExecuteFilter`
+        let message = Xcm(vec![
+            WithdrawAsset((Here, 100 * UNIT).into()),
+            DepositAsset {
+                assets: AllCounted(1).into(),
+                beneficiary: Junction::AccountId32 {
+                    network: None,
+                    id: BOB.into(),
+                }
+                .into(),
+            },
+        ]);
+        assert_eq!(
+            execute_via_chain_extension(contract_id, message),
+            Outcome::Filtered as u32
+        );
+        assert_eq!(Balances::free_balance(&BOB), bob_balance);
+    });
+}
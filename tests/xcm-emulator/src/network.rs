@@ -0,0 +1,100 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Emulated network, consisting of the `Westend` relay chain and two `Shibuya` parachains.
+//!
+//! Both parachains run the actual `Shibuya` runtime, with the genesis state of the `development` preset.
+
+use emulated_integration_tests_common::build_genesis_storage;
+use frame_support::traits::OnInitialize;
+use sp_runtime::Storage;
+use westend_emulated_chain::Westend;
+use xcm_emulator::{decl_test_networks, decl_test_parachains};
+
+pub use xcm_emulator::TestExt;
+
+/// Para Id of the first `Shibuya` instance.
+pub const SHIBUYA_A_ID: u32 = 2000;
+/// Para Id of the second `Shibuya` instance.
+pub const SHIBUYA_B_ID: u32 = 2001;
+
+/// Genesis storage of the `Shibuya` parachain with the specified `para_id`.
+fn shibuya_genesis(para_id: u32) -> Storage {
+    let preset = shibuya_runtime::genesis_config::get_preset(&"development".into())
+        .expect("Development preset must exist.");
+    let mut genesis: shibuya_runtime::RuntimeGenesisConfig = serde_json::from_slice(&preset)
+        .expect("Development preset must be a valid genesis config.");
+    genesis.parachain_info.parachain_id = para_id.into();
+    // Destination XCM versions aren't discovered upfront, so fallback to the current one.
+    genesis.polkadot_xcm.safe_xcm_version = Some(xcm::prelude::XCM_VERSION);
+
+    build_genesis_storage(
+        &genesis,
+        shibuya_runtime::WASM_BINARY.expect("WASM binary was not built, please build it!"),
+    )
+}
+
+decl_test_parachains! {
+    pub struct ShibuyaA {
+        genesis = shibuya_genesis(SHIBUYA_A_ID),
+        on_init = {
+            shibuya_runtime::AuraExt::on_initialize(1);
+        },
+        runtime = shibuya_runtime,
+        core = {
+            XcmpMessageHandler: shibuya_runtime::XcmpQueue,
+            LocationToAccountId: shibuya_runtime::xcm_config::LocationToAccountId,
+            ParachainInfo: shibuya_runtime::ParachainInfo,
+            MessageOrigin: cumulus_primitives_core::AggregateMessageOrigin,
+        },
+        pallets = {
+            PolkadotXcm: shibuya_runtime::PolkadotXcm,
+            Balances: shibuya_runtime::Balances,
+            Assets: shibuya_runtime::Assets,
+        }
+    },
+    pub struct ShibuyaB {
+        genesis = shibuya_genesis(SHIBUYA_B_ID),
+        on_init = {
+            shibuya_runtime::AuraExt::on_initialize(1);
+        },
+        runtime = shibuya_runtime,
+        core = {
+            XcmpMessageHandler: shibuya_runtime::XcmpQueue,
+            LocationToAccountId: shibuya_runtime::xcm_config::LocationToAccountId,
+            ParachainInfo: shibuya_runtime::ParachainInfo,
+            MessageOrigin: cumulus_primitives_core::AggregateMessageOrigin,
+        },
+        pallets = {
+            PolkadotXcm: shibuya_runtime::PolkadotXcm,
+            Balances: shibuya_runtime::Balances,
+            Assets: shibuya_runtime::Assets,
+        }
+    },
+}
+
+decl_test_networks! {
+    pub struct ShibuyaMockNet {
+        relay_chain = Westend,
+        parachains = vec![
+            ShibuyaA,
+            ShibuyaB,
+        ],
+        bridge = ()
+    },
+}
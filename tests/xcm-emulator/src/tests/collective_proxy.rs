@@ -0,0 +1,87 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{network::*, tests::*};
+
+use frame_support::traits::Get;
+use shibuya_runtime::{
+    Balances, CommunityTreasuryAccountId, Runtime, RuntimeCall, RuntimeEvent, SBY,
+};
+
+#[test]
+fn collective_proxy_remote_execution_works() {
+    let proxied_call = RuntimeCall::Utility(pallet_utility::Call::batch {
+        calls: vec![RuntimeCall::System(frame_system::Call::remark {
+            remark: b"Remote community council call".to_vec(),
+        })],
+    });
+
+    relay_transact_as_root(
+        SHIBUYA_B_ID,
+        RuntimeCall::CollectiveProxy(pallet_collective_proxy::Call::execute_call {
+            call: Box::new(proxied_call),
+        }),
+    );
+
+    // Call is dispatched on behalf of the community treasury account.
+    ShibuyaBPara::execute_with(|| {
+        assert!(shibuya_event_deposited(|event| matches!(
+            event,
+            RuntimeEvent::CollectiveProxy(
+                pallet_collective_proxy::Event::CollectiveProxyExecuted { result: Ok(()) }
+            )
+        )));
+        assert!(shibuya_event_deposited(|event| matches!(
+            event,
+            RuntimeEvent::Utility(pallet_utility::Event::BatchCompleted)
+        )));
+    });
+}
+
+#[test]
+fn collective_proxy_remote_execution_respects_call_filter() {
+    let community_treasury = CommunityTreasuryAccountId::get();
+    let treasury_balance =
+        ShibuyaBPara::execute_with(|| Balances::free_balance(&community_treasury));
+
+    // Transfers aren't allowed to be dispatched via the collective proxy.
+    let proxied_call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: bob().into(),
+        value: 10 * SBY,
+    });
+
+    relay_transact_as_root(
+        SHIBUYA_B_ID,
+        RuntimeCall::CollectiveProxy(pallet_collective_proxy::Call::execute_call {
+            call: Box::new(proxied_call),
+        }),
+    );
+
+    ShibuyaBPara::execute_with(|| {
+        assert!(shibuya_event_deposited(|event| matches!(
+            event,
+            RuntimeEvent::CollectiveProxy(
+                pallet_collective_proxy::Event::CollectiveProxyExecuted { result: Err(error) }
+            ) if *error == frame_system::Error::<Runtime>::CallFiltered.into()
+        )));
+        assert_eq!(
+            Balances::free_balance(&community_treasury),
+            treasury_balance
+        );
+    });
+}
@@ -0,0 +1,78 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::network::*;
+
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use parity_scale_codec::Encode;
+use shibuya_runtime::{AccountId, RuntimeCall, RuntimeEvent};
+use sp_keyring::Sr25519Keyring;
+use xcm::prelude::*;
+use xcm_executor::traits::ConvertLocation;
+
+mod collective_proxy;
+mod reserve_transfer;
+mod transact;
+
+pub(crate) fn alice() -> AccountId {
+    Sr25519Keyring::Alice.to_account_id()
+}
+
+pub(crate) fn bob() -> AccountId {
+    Sr25519Keyring::Bob.to_account_id()
+}
+
+/// Account into which the `location` is converted on the `Shibuya` parachain.
+pub(crate) fn shibuya_account_of(location: Location) -> AccountId {
+    shibuya_runtime::xcm_config::LocationToAccountId::convert_location(&location)
+        .expect("Location must be convertible into an account.")
+}
+
+/// `true` if an event matching the `predicate` was deposited on the `Shibuya` parachain, `false` otherwise.
+///
+/// Must be called from within the parachain externalities.
+pub(crate) fn shibuya_event_deposited(predicate: impl Fn(&RuntimeEvent) -> bool) -> bool {
+    shibuya_runtime::System::events()
+        .iter()
+        .any(|record| predicate(&record.event))
+}
+
+/// Send the `call` from the relay chain to the specified `Shibuya` parachain,
+/// to be dispatched there with the `Root` origin.
+pub(crate) fn relay_transact_as_root(para_id: u32, call: RuntimeCall) {
+    let require_weight_at_most = call.get_dispatch_info().weight;
+
+    // No need to pay for the execution time since parachain is configured to allow unpaid execution from parents.
+    WestendRelay::execute_with(|| {
+        assert_ok!(westend_runtime::XcmPallet::send_xcm(
+            Here,
+            Parachain(para_id),
+            Xcm(vec![
+                UnpaidExecution {
+                    weight_limit: Unlimited,
+                    check_origin: None,
+                },
+                Transact {
+                    origin_kind: OriginKind::Superuser,
+                    require_weight_at_most,
+                    call: call.encode().into(),
+                },
+            ]),
+        ));
+    });
+}
@@ -0,0 +1,162 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{network::*, tests::*};
+
+use frame_support::assert_ok;
+use pallet_xc_asset_config::AssetMetadata;
+use shibuya_runtime::{Balances, PolkadotXcm, RuntimeOrigin, XcAssetConfig, SBY};
+use xcm::prelude::*;
+
+/// Id under which the native currency of the parachain A is registered on the parachain B.
+const SHIBUYA_A_ASSET_ID: u128 = 1_000;
+
+/// Register the native currency of the parachain A as a payment asset on the parachain B.
+fn register_shibuya_a_asset_on_b() {
+    let asset_location = Location::new(1, [Parachain(SHIBUYA_A_ID)]);
+
+    ShibuyaBPara::execute_with(|| {
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            SHIBUYA_A_ASSET_ID,
+            Some(AssetMetadata {
+                name: b"Shibuya A".to_vec(),
+                symbol: b"SBYA".to_vec(),
+                decimals: 18,
+                min_balance: 1,
+            }),
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            1_000_000_000_000,
+        ));
+    });
+}
+
+#[test]
+fn reserve_transfer_to_sibling_works() {
+    register_shibuya_a_asset_on_b();
+
+    let amount = 100 * SBY;
+    let sibling_b_account = shibuya_account_of(Location::new(1, [Parachain(SHIBUYA_B_ID)]));
+
+    // Alice transfers the native currency of the parachain A to Bob on the parachain B.
+    ShibuyaAPara::execute_with(|| {
+        let alice_balance = Balances::free_balance(&alice());
+        let sibling_b_balance = Balances::free_balance(&sibling_b_account);
+
+        let assets: Assets = (Here, amount).into();
+        assert_ok!(PolkadotXcm::limited_reserve_transfer_assets(
+            RuntimeOrigin::signed(alice()),
+            Box::new(Location::new(1, [Parachain(SHIBUYA_B_ID)]).into_versioned()),
+            Box::new(
+                Location::new(
+                    0,
+                    [AccountId32 {
+                        network: None,
+                        id: bob().into(),
+                    }]
+                )
+                .into_versioned()
+            ),
+            Box::new(assets.into()),
+            0,
+            Unlimited,
+        ));
+
+        // Transferred amount is held by the sovereign account of the parachain B.
+        assert!(Balances::free_balance(&alice()) <= alice_balance - amount);
+        assert_eq!(
+            Balances::free_balance(&sibling_b_account),
+            sibling_b_balance + amount
+        );
+    });
+
+    // Bob receives the derivative asset, reduced by the execution fee.
+    ShibuyaBPara::execute_with(|| {
+        let received = shibuya_runtime::Assets::balance(SHIBUYA_A_ASSET_ID, bob());
+        assert!(received > 0 && received < amount);
+    });
+}
+
+#[test]
+fn reserve_transfer_back_to_reserve_works() {
+    register_shibuya_a_asset_on_b();
+
+    let amount = 100 * SBY;
+    let sibling_b_account = shibuya_account_of(Location::new(1, [Parachain(SHIBUYA_B_ID)]));
+
+    // Initial transfer, so the parachain B holds some derivative assets.
+    let sibling_b_balance = ShibuyaAPara::execute_with(|| {
+        let sibling_b_balance = Balances::free_balance(&sibling_b_account);
+
+        let assets: Assets = (Here, amount).into();
+        assert_ok!(PolkadotXcm::limited_reserve_transfer_assets(
+            RuntimeOrigin::signed(alice()),
+            Box::new(Location::new(1, [Parachain(SHIBUYA_B_ID)]).into_versioned()),
+            Box::new(
+                Location::new(
+                    0,
+                    [AccountId32 {
+                        network: None,
+                        id: bob().into(),
+                    }]
+                )
+                .into_versioned()
+            ),
+            Box::new(assets.into()),
+            0,
+            Unlimited,
+        ));
+
+        sibling_b_balance
+    });
+
+    // Bob sends half of the derivative assets back to Alice, on the reserve chain.
+    let returned = amount / 2;
+    ShibuyaBPara::execute_with(|| {
+        let assets: Assets = (Location::new(1, [Parachain(SHIBUYA_A_ID)]), returned).into();
+        assert_ok!(PolkadotXcm::limited_reserve_transfer_assets(
+            RuntimeOrigin::signed(bob()),
+            Box::new(Location::new(1, [Parachain(SHIBUYA_A_ID)]).into_versioned()),
+            Box::new(
+                Location::new(
+                    0,
+                    [AccountId32 {
+                        network: None,
+                        id: alice().into(),
+                    }]
+                )
+                .into_versioned()
+            ),
+            Box::new(assets.into()),
+            0,
+            Unlimited,
+        ));
+    });
+
+    // Reserve releases the assets from the sovereign account of the parachain B.
+    ShibuyaAPara::execute_with(|| {
+        assert_eq!(
+            Balances::free_balance(&sibling_b_account),
+            sibling_b_balance + amount - returned
+        );
+    });
+}
@@ -0,0 +1,128 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{network::*, tests::*};
+
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use pallet_evm::{AddressMapping, FeeCalculator};
+use parity_scale_codec::Encode;
+use shibuya_runtime::{
+    Balances, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SBY,
+};
+use sp_core::{H160, U256};
+use xcm::prelude::*;
+
+#[test]
+fn remote_transact_from_sibling_works() {
+    // Account which represents Alice from the parachain A, on the parachain B.
+    let alice_derived_account = shibuya_account_of(Location::new(
+        1,
+        [
+            Parachain(SHIBUYA_A_ID),
+            AccountId32 {
+                network: None,
+                id: alice().into(),
+            },
+        ],
+    ));
+
+    // Derived account pays for the execution on the parachain B.
+    ShibuyaBPara::execute_with(|| {
+        assert_ok!(Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            alice_derived_account.clone().into(),
+            1_000 * SBY,
+        ));
+    });
+
+    let remark = RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: b"Hello from the sibling".to_vec(),
+    });
+    let fees: Asset = (Here, 100 * SBY).into();
+
+    ShibuyaAPara::execute_with(|| {
+        assert_ok!(PolkadotXcm::send(
+            RuntimeOrigin::signed(alice()),
+            Box::new(Location::new(1, [Parachain(SHIBUYA_B_ID)]).into_versioned()),
+            Box::new(VersionedXcm::from(Xcm(vec![
+                WithdrawAsset(fees.clone().into()),
+                BuyExecution {
+                    fees,
+                    weight_limit: Unlimited,
+                },
+                Transact {
+                    origin_kind: OriginKind::SovereignAccount,
+                    require_weight_at_most: remark.get_dispatch_info().weight,
+                    call: remark.encode().into(),
+                },
+            ]))),
+        ));
+    });
+
+    // Remark is dispatched on behalf of the derived account.
+    ShibuyaBPara::execute_with(|| {
+        assert!(shibuya_event_deposited(|event| matches!(
+            event,
+            RuntimeEvent::System(frame_system::Event::Remarked { sender, .. })
+                if *sender == alice_derived_account
+        )));
+    });
+}
+
+#[test]
+fn evm_call_from_relay_works() {
+    let source = H160::repeat_byte(0x01);
+    let target = H160::repeat_byte(0x02);
+    let value = 10 * SBY;
+
+    let source_account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(source);
+    let target_account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(target);
+
+    // Fund the EVM source account & prepare the call, priced according to the current base fee.
+    let evm_call = ShibuyaBPara::execute_with(|| {
+        assert_ok!(Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            source_account.into(),
+            1_000 * SBY,
+        ));
+
+        let (base_fee, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+        RuntimeCall::EVM(pallet_evm::Call::call {
+            source,
+            target,
+            input: Vec::new(),
+            value: U256::from(value),
+            gas_limit: 100_000,
+            max_fee_per_gas: base_fee,
+            max_priority_fee_per_gas: None,
+            nonce: None,
+            access_list: Vec::new(),
+        })
+    });
+
+    // Relay chain, as the superuser, executes the EVM call on the parachain.
+    relay_transact_as_root(SHIBUYA_B_ID, evm_call);
+
+    ShibuyaBPara::execute_with(|| {
+        assert!(shibuya_event_deposited(|event| matches!(
+            event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { address }) if *address == target
+        )));
+        assert_eq!(Balances::free_balance(&target_account), value);
+    });
+}
@@ -231,6 +231,7 @@ where
         origin.clone(),
         Box::new(asset_location.clone().into().into_versioned()),
         asset_id.into(),
+        None,
     )?;
 
     // set the units per second for XCM cost
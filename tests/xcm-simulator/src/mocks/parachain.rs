@@ -361,6 +361,10 @@ impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxBatchSize = ConstU32<32>;
+    type AssetRegistrar = ();
+    type MaxReservedRanges = ConstU32<16>;
+    type CollectionId = u128;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Runtime>;
 }
 
@@ -702,6 +706,7 @@ impl pallet_dapp_staking::BenchmarkHelper<MockSmartContract, AccountId>
 
 parameter_types! {
     pub const BaseNativeCurrencyPrice: FixedU128 = FixedU128::from_rational(5, 100);
+    pub const OwnerLoyaltyBonus: Perbill = Perbill::from_percent(0);
 }
 
 impl pallet_dapp_staking::Config for Runtime {
@@ -718,6 +723,7 @@ impl pallet_dapp_staking::Config for Runtime {
     type Observers = ();
     type AccountCheck = DummyAccountCheck;
     type TierSlots = astar_primitives::dapp_staking::StandardTierSlots;
+    type SponsorshipPool = ();
     type BaseNativeCurrencyPrice = BaseNativeCurrencyPrice;
     type EraRewardSpanLength = ConstU32<1>;
     type RewardRetentionInPeriods = ConstU32<2>;
@@ -729,6 +735,9 @@ impl pallet_dapp_staking::Config for Runtime {
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
     type RankingEnabled = ConstBool<true>;
+    type OwnerLoyaltyPeriods = ConstU32<0>;
+    type OwnerLoyaltyMinimumStake = ConstU128<0>;
+    type OwnerLoyaltyBonus = OwnerLoyaltyBonus;
     type WeightInfo = ();
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;